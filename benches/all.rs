@@ -2,6 +2,7 @@
 
 extern crate test;
 extern crate rand;
+extern crate alga;
 extern crate nalgebra as na;
 extern crate ncollide;
 
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 use rand::Rng;
-use na::{Vector3, Point2, Point3};
-use ncollide::shape::{TriMesh, TriMesh3};
+use na::{self, Isometry3, Vector3, Point2, Point3};
+use ncollide::shape::{TriMesh, TriMesh3, Compound, Compound3, Cuboid, ShapeHandle};
 
 pub fn generate_trimesh_around_origin<R: Rng>(rng: &mut R) -> TriMesh3<f32> {
     let pts     = (0 .. 3000).map(|_| rng.gen::<Point3<f32>>() * 3.0).collect();
@@ -11,3 +11,14 @@ pub fn generate_trimesh_around_origin<R: Rng>(rng: &mut R) -> TriMesh3<f32> {
 
     TriMesh::new(Arc::new(pts), Arc::new(indices), Some(Arc::new(uvs)), Some(Arc::new(normals)))
 }
+
+pub fn generate_compound_around_origin<R: Rng>(rng: &mut R) -> Compound3<f32> {
+    let shapes = (0 .. 500).map(|_| {
+        let pos  = Isometry3::new(rng.gen::<Vector3<f32>>() * 3.0, na::zero());
+        let cube = Cuboid::new(rng.gen::<Vector3<f32>>() * 0.1);
+
+        (pos, ShapeHandle::new(cube))
+    }).collect();
+
+    Compound::new(shapes)
+}
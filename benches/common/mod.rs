@@ -1,7 +1,8 @@
 pub use self::unref::unref;
 pub use self::default_gen::generate;
 pub use self::generators::{
-    generate_trimesh_around_origin
+    generate_trimesh_around_origin,
+    generate_compound_around_origin
 };
 
 mod unref;
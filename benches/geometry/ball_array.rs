@@ -0,0 +1,42 @@
+use test::Bencher;
+use test;
+use na::{self, Isometry3, Point3, Vector3};
+use ncollide::shape::{Ball, BallArray};
+use ncollide::query::{Ray, RayCast};
+
+fn balls() -> Vec<(Point3<f32>, f32)> {
+    (0 .. 1024).map(|i| {
+        let t = i as f32;
+        (Point3::new(10.0 * t.sin(), 10.0 * t.cos(), 0.01 * t), 0.1 + 0.001 * t)
+    }).collect()
+}
+
+#[bench]
+fn bench_ray_cast_all_ball_array(bh: &mut Bencher) {
+    let balls = balls();
+    let array = BallArray::new(balls.iter().map(|b| b.0).collect(), balls.iter().map(|b| b.1).collect());
+    let ray   = Ray::new(Point3::new(-20.0, 0.1, 2.0), Vector3::new(1.0, 0.05, 0.0));
+
+    bh.iter(|| test::black_box(array.ray_cast_all(&ray, true)))
+}
+
+// Same as above, with a `Vec` of positioned balls.
+#[bench]
+fn bench_ray_cast_all_ball_vec(bh: &mut Bencher) {
+    let balls: Vec<(Isometry3<f32>, Ball<f32>)> =
+        balls().iter().map(|b| (Isometry3::new(b.0.coords, na::zero()), Ball::new(b.1))).collect();
+    let ray = Ray::new(Point3::new(-20.0, 0.1, 2.0), Vector3::new(1.0, 0.05, 0.0));
+
+    bh.iter(|| {
+        test::black_box(balls.iter().map(|b| b.1.toi_and_normal_with_ray(&b.0, &ray, true)).collect::<Vec<_>>())
+    })
+}
+
+#[bench]
+fn bench_closest_to_point_ball_array(bh: &mut Bencher) {
+    let balls = balls();
+    let array = BallArray::new(balls.iter().map(|b| b.0).collect(), balls.iter().map(|b| b.1).collect());
+    let pt    = Point3::new(1.0, 2.0, 3.0);
+
+    bh.iter(|| test::black_box(array.closest_to_point(&pt)))
+}
@@ -0,0 +1,88 @@
+use test::Bencher;
+use test;
+use na::{self, Isometry3, Point3, Vector3};
+use ncollide::bounding_volume::{self, AABB};
+use ncollide::partitioning::{BVT, BVTNode, BVTVisitor, BinaryPartition};
+use ncollide::query::{Ray, RayInterferencesCollector};
+use ncollide::shape::Ball;
+
+type Leaves = Vec<(usize, AABB<Point3<f32>>)>;
+
+// 100k small balls scattered on a helix.
+fn leaves() -> Leaves {
+    let ball = Ball::new(0.05f32);
+
+    (0 .. 100000usize).map(|i| {
+        let t   = i as f32 * 0.01;
+        let pos = Isometry3::new(Vector3::new(10.0 * t.sin(), 10.0 * t.cos(), 0.01 * t), na::zero());
+
+        (i, bounding_volume::aabb(&ball, &pos))
+    }).collect()
+}
+
+// The former layout of the `BVT`: each node is boxed separately, and built recursively.
+fn boxed_tree(depth: usize, leaves: Leaves) -> BVTNode<usize, AABB<Point3<f32>>> {
+    let (bv, partition) = BVT::median_partitioner::<Point3<f32>>(depth, leaves);
+
+    match partition {
+        BinaryPartition::Part(b)            => BVTNode::Leaf(bv, b),
+        BinaryPartition::Parts(left, right) => {
+            let left  = boxed_tree(depth + 1, left);
+            let right = boxed_tree(depth + 1, right);
+
+            BVTNode::Internal(bv, Box::new(left), Box::new(right))
+        }
+    }
+}
+
+// The traversal of the former layout.
+fn visit_boxed<Vis: BVTVisitor<usize, AABB<Point3<f32>>>>(node: &BVTNode<usize, AABB<Point3<f32>>>, visitor: &mut Vis) {
+    match *node {
+        BVTNode::Internal(ref bv, ref left, ref right) => {
+            if visitor.visit_internal(bv) {
+                visit_boxed(left, visitor);
+                visit_boxed(right, visitor);
+            }
+        },
+        BVTNode::Leaf(ref bv, ref b) => visitor.visit_leaf(b, bv)
+    }
+}
+
+fn ray() -> Ray<Point3<f32>> {
+    Ray::new(Point3::new(-20.0, 0.1, 2.0), Vector3::new(1.0, 0.05, 0.0))
+}
+
+// Both layouts hold exactly the same tree so that only the memory layout differs.
+#[bench]
+fn bench_bvt_ray_interferences_100k(bh: &mut Bencher) {
+    let bvt = BVT::from_root::<Point3<f32>>(boxed_tree(0, leaves())).unwrap();
+    let ray = ray();
+
+    bh.iter(|| {
+        let mut hits = Vec::new();
+
+        {
+            let mut visitor = RayInterferencesCollector::new(&ray, &mut hits);
+            bvt.visit(&mut visitor);
+        }
+
+        test::black_box(hits)
+    })
+}
+
+#[bench]
+fn bench_boxed_bvt_ray_interferences_100k(bh: &mut Bencher) {
+    let root = boxed_tree(0, leaves());
+    let ray  = ray();
+
+    bh.iter(|| {
+        let mut hits = Vec::new();
+
+        {
+            let mut visitor = RayInterferencesCollector::new(&ray, &mut hits);
+            visit_boxed(&root, &mut visitor);
+        }
+
+        test::black_box(hits)
+    })
+}
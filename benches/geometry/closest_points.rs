@@ -0,0 +1,74 @@
+use test::Bencher;
+use test;
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{ConvexHull, Cuboid};
+use ncollide::query::{self, QuerySettings};
+use ncollide::query::algorithms::johnson_simplex::JohnsonSimplex;
+use ncollide::query::closest_points_internal;
+
+// Pairs of boxes separated by about ten times their size.
+fn far_apart_cuboids() -> Vec<(Isometry3<f32>, Isometry3<f32>)> {
+    (0 .. 128).map(|i| {
+        let t  = i as f32;
+        let m1 = Isometry3::new(Vector3::new(t.sin(), t.cos(), 0.0), Vector3::new(0.1 * t, 0.2, 0.3));
+        let m2 = Isometry3::new(Vector3::new(10.0 + t.cos(), 10.0 * t.sin(), 5.0), Vector3::new(0.3, 0.1 * t, 0.2));
+
+        (m1, m2)
+    }).collect()
+}
+
+#[bench]
+fn bench_closest_points_far_apart_cuboids(bh: &mut Bencher) {
+    let cube  = Cuboid::new(Vector3::new(1.0f32, 0.5, 0.25));
+    let pairs = far_apart_cuboids();
+    let mut i = 0;
+
+    bh.iter(|| {
+        i = (i + 1) & (pairs.len() - 1);
+        test::black_box(query::closest_points(&pairs[i].0, &cube, &pairs[i].1, &cube, 100.0))
+    })
+}
+
+// Same as above, without bounding sphere warm-starting of the GJK algorithm.
+#[bench]
+fn bench_closest_points_far_apart_cuboids_cold_start(bh: &mut Bencher) {
+    let cube     = Cuboid::new(Vector3::new(1.0f32, 0.5, 0.25));
+    let pairs    = far_apart_cuboids();
+    let settings = QuerySettings::default();
+    let mut i    = 0;
+
+    bh.iter(|| {
+        i = (i + 1) & (pairs.len() - 1);
+        test::black_box(closest_points_internal::support_map_against_support_map_with_params(
+            &pairs[i].0, &cube, &pairs[i].1, &cube, 100.0, &mut JohnsonSimplex::new_w_tls(), None, &settings))
+    })
+}
+
+// A box-shaped convex hull offset from the origin of its local frame. Its bounding sphere center
+// is not its origin so, unlike for a cuboid, the warm-start direction given by the bounding
+// spheres differs from the default one given by the positions of the shapes.
+fn offset_hull() -> ConvexHull<Point3<f32>> {
+    let mut pts = Vec::new();
+
+    for i in 0 .. 8 {
+        let x = if i & 1 == 0 { -1.0 } else { 1.0 };
+        let y = if i & 2 == 0 { 2.5 } else { 3.5 };
+        let z = if i & 4 == 0 { -0.25 } else { 0.25 };
+
+        pts.push(Point3::new(x, y, z));
+    }
+
+    ConvexHull::new(pts)
+}
+
+#[bench]
+fn bench_closest_points_far_apart_offset_hulls(bh: &mut Bencher) {
+    let hull  = offset_hull();
+    let pairs = far_apart_cuboids();
+    let mut i = 0;
+
+    bh.iter(|| {
+        i = (i + 1) & (pairs.len() - 1);
+        test::black_box(query::closest_points(&pairs[i].0, &hull, &pairs[i].1, &hull, 100.0))
+    })
+}
@@ -1 +1,5 @@
 mod contacts;
+mod closest_points;
+mod ball_array;
+mod bvt;
+mod transformed;
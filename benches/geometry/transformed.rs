@@ -0,0 +1,43 @@
+use test::Bencher;
+use test;
+use alga::general::Id;
+use na::{Isometry3, Vector3};
+use ncollide::shape::{Cuboid, SupportMap, Transformed};
+
+// 10k query directions, for a single cuboid pose.
+fn directions() -> Vec<Vector3<f32>> {
+    (0 .. 10000).map(|i| {
+        let t = i as f32;
+        Vector3::new(t.sin(), t.cos(), (0.3 * t).sin())
+    }).collect()
+}
+
+fn pose() -> Isometry3<f32> {
+    Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.3, 0.4, 0.5))
+}
+
+#[bench]
+fn bench_support_map_cuboid_at_pose(bh: &mut Bencher) {
+    let cuboid = Cuboid::new(Vector3::new(1.0f32, 2.0, 3.0));
+    let m      = pose();
+    let dirs   = directions();
+
+    bh.iter(|| {
+        for dir in dirs.iter() {
+            let _ = test::black_box(cuboid.support_point(&m, dir));
+        }
+    })
+}
+
+// Same as above, with the world-space axes of the cuboid precomputed.
+#[bench]
+fn bench_support_map_transformed_cuboid(bh: &mut Bencher) {
+    let cuboid = Transformed::new(Cuboid::new(Vector3::new(1.0f32, 2.0, 3.0)), pose());
+    let dirs   = directions();
+
+    bh.iter(|| {
+        for dir in dirs.iter() {
+            let _ = test::black_box(cuboid.support_point(&Id::new(), dir));
+        }
+    })
+}
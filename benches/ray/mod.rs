@@ -1,12 +1,12 @@
 use rand::IsaacRng;
 use test::Bencher;
 use test;
-use na::Id;
+use na::{Id, Isometry3};
 use ncollide::bounding_volume::{AABB3, BoundingSphere3};
 use ncollide::shape::{Ball3, Cuboid3, Capsule3, Cone3, Cylinder3, TriMesh3, Segment3, Triangle3,
-                      Convex3};
+                      Convex3, Compound3};
 use ncollide::ray::{RayCast, Ray3};
-use common::{unref, generate, generate_trimesh_around_origin};
+use common::{unref, generate, generate_trimesh_around_origin, generate_compound_around_origin};
 
 #[path="../common/macros.rs"]
 #[macro_use] mod macros;
@@ -62,3 +62,9 @@ bench_method_gen!(bench_ray_against_trimesh_with_normal_uv, toi_and_normal_and_u
                   id: Id = generate,
                   ray: Ray3<f32> = generate,
                   solid: bool = generate);
+
+bench_method_gen!(bench_ray_against_compound_with_normal, toi_and_normal_with_ray,
+                  c: Compound3<f32> = generate_compound_around_origin,
+                  m: Isometry3<f32> = generate,
+                  ray: Ray3<f32> = generate,
+                  solid: bool = generate);
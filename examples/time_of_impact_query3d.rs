@@ -27,7 +27,7 @@ fn main() {
     let toi_wont_touch = query::time_of_impact(&ball_pos_wont_touch, &ball_vel1, &ball,
                                                &cuboid_pos,          &box_vel1,  &cuboid);
 
-    assert_eq!(toi_intersecting, Some(0.0));
-    assert!(toi_will_touch.is_some() && toi_will_touch.unwrap() > 0.0);
+    assert_eq!(toi_intersecting.map(|r| r.toi), Some(0.0));
+    assert!(toi_will_touch.is_some() && toi_will_touch.unwrap().toi > 0.0);
     assert_eq!(toi_wont_touch, None);
 }
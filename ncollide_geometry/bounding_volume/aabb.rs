@@ -2,7 +2,7 @@
 
 use na;
 
-use math::Point;
+use math::{Point, Isometry};
 use utils;
 use bounding_volume::{BoundingVolume, HasBoundingVolume};
 
@@ -15,8 +15,20 @@ pub fn aabb<P, M, G: ?Sized>(g: &G, m: &M) -> AABB<P>
     g.bounding_volume(m)
 }
 
+/// Trait of shapes having a simple closed-form AABB in their local coordinate frame.
+///
+/// The local AABB transformed by the relative position of two shapes gives a conservative
+/// approximation of the region of one shape overlapped by the other, e.g., to select the cells
+/// of a heightfield or the triangles of a mesh.
+pub trait HasLocalAABB<P: Point> {
+    /// The tightest AABB of `self` in its local coordinate frame, i.e., with an identity
+    /// transformation.
+    fn local_aabb(&self) -> AABB<P>;
+}
+
 /// An Axis Aligned Bounding Box.
 #[derive(Debug, PartialEq, Clone, RustcEncodable, RustcDecodable)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 pub struct AABB<P> {
     mins: P,
     maxs: P
@@ -61,6 +73,22 @@ impl<P: Point> AABB<P> {
     pub fn half_extents(&self) -> P::Vector {
         (self.maxs - self.mins) / na::convert(2.0f64)
     }
+
+    /// Enlarges this AABB so it also contains the point `pt`.
+    #[inline]
+    pub fn merge_point(&mut self, pt: &P) {
+        self.mins = na::inf(&self.mins, pt);
+        self.maxs = na::sup(&self.maxs, pt);
+    }
+
+    /// The smallest AABB containing this AABB transformed by `m`.
+    #[inline]
+    pub fn transform_by<M: Isometry<P>>(&self, m: &M) -> AABB<P> {
+        let center          = m.transform_point(&self.center());
+        let ws_half_extents = m.absolute_rotate_vector(&self.half_extents());
+
+        AABB::new(center + (-ws_half_extents), center + ws_half_extents)
+    }
 }
 
 // XXX: we should not keep this
@@ -96,6 +124,13 @@ impl<P: Point> BoundingVolume<P> for AABB<P> {
         na::partial_ge(&self.maxs, &other.maxs)
     }
 
+    #[inline]
+    fn contains_relative(&self, other: &AABB<P>, eps: P::Real) -> bool {
+        let scale = na::norm(&self.center().coordinates()) + na::norm(&self.half_extents());
+
+        self.loosened(eps * scale).contains(other)
+    }
+
     #[inline]
     fn merge(&mut self, other: &AABB<P>) {
         self.mins = na::inf(&self.mins, &other.mins);
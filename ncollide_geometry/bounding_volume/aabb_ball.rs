@@ -1,6 +1,6 @@
 use alga::linear::Translation;
 use utils;
-use bounding_volume::{HasBoundingVolume, AABB};
+use bounding_volume::{HasBoundingVolume, HasLocalAABB, AABB};
 use shape::Ball;
 use math::{Point, Isometry};
 
@@ -16,3 +16,10 @@ impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, AABB<P>> for Ball<P::Real> {
         ball_aabb(&P::from_coordinates(m.translation().to_vector()), self.radius())
     }
 }
+
+impl<P: Point> HasLocalAABB<P> for Ball<P::Real> {
+    #[inline]
+    fn local_aabb(&self) -> AABB<P> {
+        ball_aabb(&P::origin(), self.radius())
+    }
+}
@@ -1,5 +1,5 @@
 use alga::linear::Translation;
-use bounding_volume::{HasBoundingVolume, AABB};
+use bounding_volume::{HasBoundingVolume, HasLocalAABB, AABB};
 use shape::Cuboid;
 use math::{Point, Isometry};
 
@@ -12,3 +12,12 @@ impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, AABB<P>> for Cuboid<P::Vecto
         AABB::new(center + -ws_half_extents, center + ws_half_extents)
     }
 }
+
+impl<P: Point> HasLocalAABB<P> for Cuboid<P::Vector> {
+    #[inline]
+    fn local_aabb(&self) -> AABB<P> {
+        let half_extents = *self.half_extents();
+
+        AABB::new(P::origin() + -half_extents, P::origin() + half_extents)
+    }
+}
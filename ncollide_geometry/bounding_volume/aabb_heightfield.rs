@@ -0,0 +1,21 @@
+use bounding_volume::{HasBoundingVolume, HasLocalAABB, AABB};
+use shape::HeightField;
+use math::{Point, Isometry};
+
+impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, AABB<P>> for HeightField<P::Real> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> AABB<P> {
+        let local_aabb      = self.local_aabb::<P>();
+        let center          = m.transform_point(&local_aabb.center());
+        let ws_half_extents = m.absolute_rotate_vector(&local_aabb.half_extents());
+
+        AABB::new(center + -ws_half_extents, center + ws_half_extents)
+    }
+}
+
+impl<P: Point> HasLocalAABB<P> for HeightField<P::Real> {
+    #[inline]
+    fn local_aabb(&self) -> AABB<P> {
+        HeightField::local_aabb(self)
+    }
+}
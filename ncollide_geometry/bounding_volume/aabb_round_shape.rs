@@ -0,0 +1,13 @@
+use bounding_volume::{BoundingVolume, HasBoundingVolume, AABB};
+use shape::RoundShape;
+use math::{Point, Isometry};
+
+impl<P, M, S> HasBoundingVolume<M, AABB<P>> for RoundShape<S, P::Real>
+    where P: Point,
+          M: Isometry<P>,
+          S: HasBoundingVolume<M, AABB<P>> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> AABB<P> {
+        self.inner().bounding_volume(m).loosened(self.border_radius())
+    }
+}
@@ -0,0 +1,13 @@
+use bounding_volume::{self, HasBoundingVolume, AABB};
+use shape::{Scaled, SupportMap};
+use math::{Point, Isometry};
+
+impl<P, M, S> HasBoundingVolume<M, AABB<P>> for Scaled<S, P::Vector>
+    where P: Point,
+          M: Isometry<P>,
+          S: SupportMap<P, M> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> AABB<P> {
+        bounding_volume::support_map_aabb(m, self)
+    }
+}
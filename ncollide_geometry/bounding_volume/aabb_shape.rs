@@ -1,6 +1,6 @@
 use bounding_volume::{HasBoundingVolume, AABB};
 use math::{Point, Isometry};
-use shape::Shape;
+use shape::{Shape, ShapeHandle};
 
 impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, AABB<P>> for Shape<P, M> {
     #[inline]
@@ -8,3 +8,10 @@ impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, AABB<P>> for Shape<P, M> {
         self.aabb(m)
     }
 }
+
+impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, AABB<P>> for ShapeHandle<P, M> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> AABB<P> {
+        self.aabb(m)
+    }
+}
@@ -1,6 +1,7 @@
-use bounding_volume::{HasBoundingVolume, AABB};
+use utils;
+use bounding_volume::{HasBoundingVolume, HasLocalAABB, AABB};
 use bounding_volume;
-use shape::{Cone, Cylinder, Capsule};
+use shape::{Cone, Cylinder, Capsule, Ellipsoid};
 use shape::{Triangle, Segment};
 use math::{Point, Isometry};
 
@@ -25,6 +26,52 @@ impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, AABB<P>> for Capsule<P::Real
     }
 }
 
+impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, AABB<P>> for Ellipsoid<P::Vector> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> AABB<P> {
+        bounding_volume::support_map_aabb(m, self)
+    }
+}
+
+// The local AABB of a shape symmetric wrt. the origin, with the given half-extents along the `y`
+// axis and any other axis.
+fn symmetric_local_aabb<P: Point>(half_height: P::Real, radius: P::Real) -> AABB<P> {
+    let mut half_extents: P::Vector = utils::repeat(radius);
+    half_extents[1] = half_height;
+
+    AABB::new(P::origin() + -half_extents, P::origin() + half_extents)
+}
+
+impl<P: Point> HasLocalAABB<P> for Cone<P::Real> {
+    #[inline]
+    fn local_aabb(&self) -> AABB<P> {
+        symmetric_local_aabb(self.half_height(), self.radius())
+    }
+}
+
+impl<P: Point> HasLocalAABB<P> for Cylinder<P::Real> {
+    #[inline]
+    fn local_aabb(&self) -> AABB<P> {
+        symmetric_local_aabb(self.half_height(), self.radius())
+    }
+}
+
+impl<P: Point> HasLocalAABB<P> for Capsule<P::Real> {
+    #[inline]
+    fn local_aabb(&self) -> AABB<P> {
+        symmetric_local_aabb(self.half_height() + self.radius(), self.radius())
+    }
+}
+
+impl<P: Point> HasLocalAABB<P> for Ellipsoid<P::Vector> {
+    #[inline]
+    fn local_aabb(&self) -> AABB<P> {
+        let radii = *self.radii();
+
+        AABB::new(P::origin() + -radii, P::origin() + radii)
+    }
+}
+
 impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, AABB<P>> for Triangle<P> {
     #[inline]
     fn bounding_volume(&self, m: &M) -> AABB<P> {
@@ -1,11 +1,17 @@
 use na;
 use shape::SupportMap;
-use bounding_volume::AABB;
+use bounding_volume::{AABB, BoundingVolume, HasBoundingVolume};
 use math::{Point, Isometry};
 
 
 
 /// Computes the AABB of an support mapped shape.
+///
+/// The AABB is exact: it is bounded by the support points of `i` along each positive and negative
+/// coordinate axis. This is the simplest way of implementing `HasBoundingVolume<M, AABB<P>>` for a
+/// custom support-mapped shape, i.e., by calling `support_map_aabb(m, self)`. No blanket
+/// implementation is provided since it would conflict with the cheaper analytic bounding volumes
+/// of shapes like `Ball` and `Cuboid`.
 pub fn support_map_aabb<P, M, G>(m: &M, i: &G) -> AABB<P>
         where P: Point,
               M: Isometry<P>,
@@ -44,3 +50,25 @@ pub fn point_cloud_aabb<P: Point, M: Isometry<P>>(m: &M, pts: &[P]) -> (P, P) {
 
     (min, max)
 }
+
+/// Computes the AABB enclosing a set of transformed shapes.
+///
+/// Returns `None` if `shapes` is empty.
+pub fn scene_aabb<'a, P, M, G: ?Sized, I>(shapes: I) -> Option<AABB<P>>
+    where P: Point,
+          M: 'a,
+          G: 'a + HasBoundingVolume<M, AABB<P>>,
+          I: IntoIterator<Item = (&'a M, &'a G)> {
+    let mut res: Option<AABB<P>> = None;
+
+    for (m, shape) in shapes {
+        let aabb = shape.bounding_volume(m);
+
+        match res {
+            Some(ref mut scene) => scene.merge(&aabb),
+            None                => res = Some(aabb)
+        }
+    }
+
+    res
+}
@@ -0,0 +1,13 @@
+use bounding_volume::{HasBoundingVolume, AABB};
+use shape::WithLocalPose;
+use math::{Point, Isometry};
+
+impl<P, M, S> HasBoundingVolume<M, AABB<P>> for WithLocalPose<S, M>
+    where P: Point,
+          M: Isometry<P>,
+          S: HasBoundingVolume<M, AABB<P>> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> AABB<P> {
+        self.shape().bounding_volume(&self.shape_transform(m))
+    }
+}
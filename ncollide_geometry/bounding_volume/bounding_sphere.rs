@@ -17,8 +17,24 @@ pub fn bounding_sphere<P, M, G: ?Sized>(g: &G, m: &M) -> BoundingSphere<P>
     g.bounding_volume(m)
 }
 
+/// Trait of shapes having a bounding sphere independent from their position.
+///
+/// The bounding sphere of such a shape under any transformation is obtained in constant time by
+/// transforming its local bounding sphere with `BoundingSphere::transform_by(...)`. Thus, the
+/// local bounding sphere of a static shape queried under many transformations can be computed
+/// only once.
+pub trait HasLocalBoundingSphere<P: Point> {
+    /// The bounding sphere of `self` in its local coordinate frame, i.e., with an identity
+    /// transformation.
+    fn local_bounding_sphere(&self) -> BoundingSphere<P>;
+}
+
 /// A Bounding Sphere.
 #[derive(Debug, PartialEq, Clone, RustcEncodable, RustcDecodable)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-serialize", serde(bound(
+    serialize   = "P: ::serde::Serialize, P::Real: ::serde::Serialize",
+    deserialize = "P: ::serde::Deserialize<'de>, P::Real: ::serde::Deserialize<'de>")))]
 pub struct BoundingSphere<P: Point> {
     center: P,
     radius: P::Real
@@ -27,13 +43,33 @@ pub struct BoundingSphere<P: Point> {
 impl<P> BoundingSphere<P>
     where P: Point {
     /// Creates a new bounding sphere.
+    ///
+    /// The radius must be positive or null.
     pub fn new(center: P, radius: P::Real) -> BoundingSphere<P> {
+        debug_assert!(radius >= na::zero(), "A bounding sphere radius must be positive or null.");
+
         BoundingSphere {
             center: center,
             radius: radius
         }
     }
 
+    /// Creates the bounding sphere of a set of bounding spheres.
+    ///
+    /// The spheres are merged incrementally, in order, starting from the first one. The result
+    /// contains every sphere but is not necessarily the smallest one. Fails if `spheres` is empty.
+    pub fn of_spheres(spheres: &[BoundingSphere<P>]) -> BoundingSphere<P> {
+        assert!(!spheres.is_empty(), "Cannot compute the bounding sphere of an empty set of spheres.");
+
+        let mut res = spheres[0].clone();
+
+        for sphere in spheres[1 ..].iter() {
+            res.merge(sphere)
+        }
+
+        res
+    }
+
     /// The bounding sphere center.
     #[inline]
     pub fn center(&self) -> &P {
@@ -77,6 +113,13 @@ impl<P: Point> BoundingVolume<P> for BoundingSphere<P> {
         distance + other.radius <= self.radius
     }
 
+    #[inline]
+    fn contains_relative(&self, other: &BoundingSphere<P>, eps: P::Real) -> bool {
+        let scale = na::norm(&self.center.coordinates()) + self.radius;
+
+        self.loosened(eps * scale).contains(other)
+    }
+
     #[inline]
     fn merge(&mut self, other: &BoundingSphere<P>) {
         let mut dir = *other.center() - *self.center();
@@ -1,14 +1,21 @@
-use bounding_volume::{HasBoundingVolume, BoundingSphere};
+use bounding_volume::{HasBoundingVolume, HasLocalBoundingSphere, BoundingSphere};
 use shape::Ball;
 use math::{Point, Isometry};
 
 
-impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, BoundingSphere<P>> for Ball<P::Real> {
+impl<P: Point> HasLocalBoundingSphere<P> for Ball<P::Real> {
     #[inline]
-    fn bounding_volume(&self, m: &M) -> BoundingSphere<P> {
-        let center = m.translate_point(&P::origin());
+    fn local_bounding_sphere(&self) -> BoundingSphere<P> {
+        let center = P::origin();
         let radius = self.radius();
 
         BoundingSphere::new(center, radius)
     }
 }
+
+impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, BoundingSphere<P>> for Ball<P::Real> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> BoundingSphere<P> {
+        self.local_bounding_sphere().transform_by(m)
+    }
+}
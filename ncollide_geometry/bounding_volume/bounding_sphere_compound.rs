@@ -1,14 +1,13 @@
-use bounding_volume::{BoundingVolume, BoundingSphere, HasBoundingVolume};
+use bounding_volume::{BoundingVolume, BoundingSphere, HasBoundingVolume, HasLocalBoundingSphere};
 use shape::Compound;
 use math::{Point, Isometry};
 
 
-impl<P, M, M2> HasBoundingVolume<M2, BoundingSphere<P>> for Compound<P, M>
-    where P:  Point,
-          M:  Isometry<P>,
-          M2: Isometry<P> {
+impl<P, M> HasLocalBoundingSphere<P> for Compound<P, M>
+    where P: Point,
+          M: Isometry<P> {
     #[inline]
-    fn bounding_volume(&self, m: &M2) -> BoundingSphere<P> {
+    fn local_bounding_sphere(&self) -> BoundingSphere<P> {
         let shapes = self.shapes();
 
         let mut res = shapes[0].1.bounding_sphere(&shapes[0].0);
@@ -17,6 +16,16 @@ impl<P, M, M2> HasBoundingVolume<M2, BoundingSphere<P>> for Compound<P, M>
             res.merge(&s.bounding_sphere(t));
         }
 
-        BoundingSphere::new(m.transform_point(res.center()), res.radius())
+        res
+    }
+}
+
+impl<P, M, M2> HasBoundingVolume<M2, BoundingSphere<P>> for Compound<P, M>
+    where P:  Point,
+          M:  Isometry<P>,
+          M2: Isometry<P> {
+    #[inline]
+    fn bounding_volume(&self, m: &M2) -> BoundingSphere<P> {
+        self.local_bounding_sphere().transform_by(m)
     }
 }
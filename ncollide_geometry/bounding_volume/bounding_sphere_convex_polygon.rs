@@ -0,0 +1,21 @@
+use bounding_volume::{BoundingSphere, HasBoundingVolume, HasLocalBoundingSphere};
+use bounding_volume;
+use shape::ConvexPolygon;
+use math::{Point, Isometry};
+
+
+impl<P: Point> HasLocalBoundingSphere<P> for ConvexPolygon<P> {
+    #[inline]
+    fn local_bounding_sphere(&self) -> BoundingSphere<P> {
+        let (center, radius) = bounding_volume::point_cloud_bounding_sphere(self.points());
+
+        BoundingSphere::new(center, radius)
+    }
+}
+
+impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, BoundingSphere<P>> for ConvexPolygon<P> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> BoundingSphere<P> {
+        self.local_bounding_sphere().transform_by(m)
+    }
+}
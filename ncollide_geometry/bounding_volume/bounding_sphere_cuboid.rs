@@ -1,16 +1,23 @@
 use na;
-use bounding_volume::{HasBoundingVolume, BoundingSphere};
+use bounding_volume::{HasBoundingVolume, HasLocalBoundingSphere, BoundingSphere};
 use shape::Cuboid;
 use math::{Point, Isometry};
 
 
 
-impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, BoundingSphere<P>> for Cuboid<P::Vector> {
+impl<P: Point> HasLocalBoundingSphere<P> for Cuboid<P::Vector> {
     #[inline]
-    fn bounding_volume(&self, m: &M) -> BoundingSphere<P> {
-        let center = m.translate_point(&P::origin());
+    fn local_bounding_sphere(&self) -> BoundingSphere<P> {
+        let center = P::origin();
         let radius = na::norm(self.half_extents());
 
         BoundingSphere::new(center, radius)
     }
 }
+
+impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, BoundingSphere<P>> for Cuboid<P::Vector> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> BoundingSphere<P> {
+        self.local_bounding_sphere().transform_by(m)
+    }
+}
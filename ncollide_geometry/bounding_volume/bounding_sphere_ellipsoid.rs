@@ -0,0 +1,21 @@
+use bounding_volume::{HasBoundingVolume, HasLocalBoundingSphere, BoundingSphere};
+use shape::Ellipsoid;
+use math::{Point, Isometry};
+
+
+impl<P: Point> HasLocalBoundingSphere<P> for Ellipsoid<P::Vector> {
+    #[inline]
+    fn local_bounding_sphere(&self) -> BoundingSphere<P> {
+        let center = P::origin();
+        let radius = self.max_radius();
+
+        BoundingSphere::new(center, radius)
+    }
+}
+
+impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, BoundingSphere<P>> for Ellipsoid<P::Vector> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> BoundingSphere<P> {
+        self.local_bounding_sphere().transform_by(m)
+    }
+}
@@ -0,0 +1,20 @@
+use na;
+use bounding_volume::{HasBoundingVolume, HasLocalBoundingSphere, BoundingSphere};
+use shape::HeightField;
+use math::{Point, Isometry};
+
+impl<P: Point> HasLocalBoundingSphere<P> for HeightField<P::Real> {
+    #[inline]
+    fn local_bounding_sphere(&self) -> BoundingSphere<P> {
+        let aabb = self.local_aabb::<P>();
+
+        BoundingSphere::new(aabb.center(), na::norm(&aabb.half_extents()))
+    }
+}
+
+impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, BoundingSphere<P>> for HeightField<P::Real> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> BoundingSphere<P> {
+        self.local_bounding_sphere().transform_by(m)
+    }
+}
@@ -1,18 +1,34 @@
-use bounding_volume::{BoundingSphere, HasBoundingVolume};
+use bounding_volume::{BoundingSphere, HasBoundingVolume, HasLocalBoundingSphere};
 use bounding_volume;
 use shape::{BaseMesh, BaseMeshElement, TriMesh, Polyline};
 use math::{Point, Isometry};
 
 
+impl<P, I, E> HasLocalBoundingSphere<P> for BaseMesh<P, I, E>
+    where P: Point,
+          E: BaseMeshElement<I, P> {
+    #[inline]
+    fn local_bounding_sphere(&self) -> BoundingSphere<P> {
+        let (center, radius) = bounding_volume::point_cloud_bounding_sphere(&self.vertices()[..]);
+
+        BoundingSphere::new(center, radius)
+    }
+}
+
 impl<P, M, I, E> HasBoundingVolume<M, BoundingSphere<P>> for BaseMesh<P, I, E>
     where P: Point,
           M: Isometry<P>,
           E: BaseMeshElement<I, P> {
     #[inline]
     fn bounding_volume(&self, m: &M) -> BoundingSphere<P> {
-        let (center, radius) = bounding_volume::point_cloud_bounding_sphere(&self.vertices()[..]);
+        self.local_bounding_sphere().transform_by(m)
+    }
+}
 
-        BoundingSphere::new(m.transform_point(&center), radius)
+impl<P: Point> HasLocalBoundingSphere<P> for TriMesh<P> {
+    #[inline]
+    fn local_bounding_sphere(&self) -> BoundingSphere<P> {
+        self.base_mesh().local_bounding_sphere()
     }
 }
 
@@ -23,6 +39,13 @@ impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, BoundingSphere<P>> for TriMe
     }
 }
 
+impl<P: Point> HasLocalBoundingSphere<P> for Polyline<P> {
+    #[inline]
+    fn local_bounding_sphere(&self) -> BoundingSphere<P> {
+        self.base_mesh().local_bounding_sphere()
+    }
+}
+
 impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, BoundingSphere<P>> for Polyline<P> {
     #[inline]
     fn bounding_volume(&self, m: &M) -> BoundingSphere<P> {
@@ -0,0 +1,13 @@
+use bounding_volume::{BoundingVolume, HasBoundingVolume, BoundingSphere};
+use shape::RoundShape;
+use math::{Point, Isometry};
+
+impl<P, M, S> HasBoundingVolume<M, BoundingSphere<P>> for RoundShape<S, P::Real>
+    where P: Point,
+          M: Isometry<P>,
+          S: HasBoundingVolume<M, BoundingSphere<P>> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> BoundingSphere<P> {
+        self.inner().bounding_volume(m).loosened(self.border_radius())
+    }
+}
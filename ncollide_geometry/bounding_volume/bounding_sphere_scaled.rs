@@ -0,0 +1,18 @@
+use na;
+use bounding_volume::{HasBoundingVolume, BoundingSphere};
+use shape::Scaled;
+use math::{Point, Isometry};
+
+impl<P, M, S> HasBoundingVolume<M, BoundingSphere<P>> for Scaled<S, P::Vector>
+    where P: Point,
+          M: Isometry<P>,
+          S: HasBoundingVolume<M, BoundingSphere<P>> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> BoundingSphere<P> {
+        let one: M = na::one();
+        let local  = self.inner().bounding_volume(&one);
+        let center = P::from_coordinates(self.scale_vector(&local.center().coordinates()));
+
+        BoundingSphere::new(center, local.radius() * self.max_scale()).transform_by(m)
+    }
+}
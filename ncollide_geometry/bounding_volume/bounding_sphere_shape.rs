@@ -1,6 +1,6 @@
 use bounding_volume::{HasBoundingVolume, BoundingSphere};
 use math::{Point, Isometry};
-use shape::Shape;
+use shape::{Shape, ShapeHandle};
 
 
 impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, BoundingSphere<P>> for Shape<P, M> {
@@ -9,3 +9,10 @@ impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, BoundingSphere<P>> for Shape
         self.bounding_sphere(m)
     }
 }
+
+impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, BoundingSphere<P>> for ShapeHandle<P, M> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> BoundingSphere<P> {
+        self.bounding_sphere(m)
+    }
+}
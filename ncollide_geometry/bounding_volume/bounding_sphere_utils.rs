@@ -2,7 +2,9 @@ use alga::general::Real;
 
 use na;
 use utils;
-use math::Point;
+use bounding_volume::{BoundingSphere, BoundingVolume};
+use shape::Shape;
+use math::{Point, Isometry};
 
 /// Computes the bounding sphere of a set of point, given its center.
 // FIXME: return a bounding sphere?
@@ -30,3 +32,27 @@ pub fn point_cloud_bounding_sphere<P>(pts: &[P]) -> (P, P::Real)
     where P: Point {
     point_cloud_bounding_sphere_with_center(pts, utils::center(pts))
 }
+
+/// Computes a bounding sphere of a set of positioned shapes.
+///
+/// The bounding spheres of the shapes are merged incrementally, in order. The result contains
+/// every shape but is not necessarily the smallest one. Returns a sphere with a zero radius
+/// centered at the origin if `shapes` is empty.
+pub fn bounding_sphere_of_shapes<P, M>(shapes: &[(&Shape<P, M>, M)]) -> BoundingSphere<P>
+    where P: Point,
+          M: Isometry<P> {
+    let mut it = shapes.iter();
+
+    match it.next() {
+        Some(&(shape, ref m)) => {
+            let mut res = shape.bounding_sphere(m);
+
+            for &(shape, ref m) in it {
+                res.merge(&shape.bounding_sphere(m))
+            }
+
+            res
+        },
+        None => BoundingSphere::new(P::origin(), na::zero())
+    }
+}
@@ -0,0 +1,13 @@
+use bounding_volume::{HasBoundingVolume, BoundingSphere};
+use shape::WithLocalPose;
+use math::{Point, Isometry};
+
+impl<P, M, S> HasBoundingVolume<M, BoundingSphere<P>> for WithLocalPose<S, M>
+    where P: Point,
+          M: Isometry<P>,
+          S: HasBoundingVolume<M, BoundingSphere<P>> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> BoundingSphere<P> {
+        self.shape().bounding_volume(&self.shape_transform(m))
+    }
+}
@@ -23,6 +23,15 @@ pub trait BoundingVolume<P: Point> {
     /// Checks if this bounding volume contains another one.
     fn contains(&self, &Self)   -> bool;
 
+    /// Checks if this bounding volume contains another one, up to a tolerance relative to its size
+    /// and position.
+    ///
+    /// This absorbs the rounding errors of `merged`. By default, `eps` is an absolute tolerance.
+    fn contains_relative(&self, other: &Self, eps: P::Real) -> bool
+        where Self: Sized {
+        self.loosened(eps).contains(other)
+    }
+
     /// Merges this bounding volume with another one. The merge is done in-place.
     fn merge(&mut self, &Self);
 
@@ -3,16 +3,19 @@
 #[doc(inline)]
 pub use bounding_volume::bounding_volume::{HasBoundingVolume, BoundingVolume};
 #[doc(inline)]
-pub use bounding_volume::aabb::{AABB, aabb};
+pub use bounding_volume::aabb::{AABB, HasLocalAABB, aabb};
 #[doc(inline)]
-pub use bounding_volume::bounding_sphere::{BoundingSphere, bounding_sphere};
+pub use bounding_volume::obb::{OBB, obb};
+#[doc(inline)]
+pub use bounding_volume::bounding_sphere::{BoundingSphere, HasLocalBoundingSphere, bounding_sphere};
 
-pub use bounding_volume::aabb_utils::{support_map_aabb, point_cloud_aabb};
+pub use bounding_volume::aabb_utils::{support_map_aabb, point_cloud_aabb, scene_aabb};
 pub use bounding_volume::aabb_ball::ball_aabb;
-pub use bounding_volume::bounding_sphere_utils::{point_cloud_bounding_sphere_with_center, point_cloud_bounding_sphere};
+pub use bounding_volume::bounding_sphere_utils::{point_cloud_bounding_sphere_with_center, point_cloud_bounding_sphere,
+                                                 bounding_sphere_of_shapes};
 pub use bounding_volume::bounding_volume_bvt::BoundingVolumeInterferencesCollector;
 
-use na::{Point2, Point3};
+use na::{Point2, Point3, Isometry2, Isometry3};
 
 #[doc(hidden)]
 pub mod bounding_volume;
@@ -25,11 +28,20 @@ mod aabb_support_map;
 mod aabb_ball;
 mod aabb_plane;
 mod aabb_convex;
+mod aabb_convex_polygon;
 mod aabb_compound;
 mod aabb_mesh;
+mod aabb_heightfield;
+mod aabb_with_local_pose;
+mod aabb_round_shape;
+mod aabb_scaled;
 mod aabb_utils;
 mod aabb_shape;
 
+#[doc(hidden)]
+pub mod obb;
+mod obb_cuboid;
+
 #[doc(hidden)]
 pub mod bounding_sphere;
 mod bounding_sphere_cuboid;
@@ -37,12 +49,18 @@ mod bounding_sphere_cone;
 mod bounding_sphere_ball;
 mod bounding_sphere_cylinder;
 mod bounding_sphere_capsule;
+mod bounding_sphere_ellipsoid;
 mod bounding_sphere_plane;
 mod bounding_sphere_convex;
+mod bounding_sphere_convex_polygon;
 mod bounding_sphere_compound;
 mod bounding_sphere_triangle;
 mod bounding_sphere_segment;
 mod bounding_sphere_mesh;
+mod bounding_sphere_heightfield;
+mod bounding_sphere_with_local_pose;
+mod bounding_sphere_round_shape;
+mod bounding_sphere_scaled;
 mod bounding_sphere_utils;
 mod bounding_sphere_shape;
 
@@ -60,3 +78,8 @@ pub type AABB2<N> = AABB<Point2<N>>;
 pub type BoundingSphere3<N> = BoundingSphere<Point3<N>>;
 /// A 3D AABB.
 pub type AABB3<N> = AABB<Point3<N>>;
+
+/// A 2D OBB.
+pub type OBB2<N> = OBB<Point2<N>, Isometry2<N>>;
+/// A 3D OBB.
+pub type OBB3<N> = OBB<Point3<N>, Isometry3<N>>;
@@ -0,0 +1,216 @@
+//! Oriented Bounding Box.
+
+use approx::ApproxEq;
+use alga::general::Real;
+use alga::linear::Translation;
+use na;
+use bounding_volume::{BoundingVolume, HasBoundingVolume};
+use utils;
+use math::{Point, Isometry};
+
+/// Computes the oriented bounding box of a shape `g` transformed by `m`.
+///
+/// Same as `g.bounding_volume(m)`.
+pub fn obb<P, M, G: ?Sized>(g: &G, m: &M) -> OBB<P, M>
+    where P: Point,
+          G: HasBoundingVolume<M, OBB<P, M>> {
+    g.bounding_volume(m)
+}
+
+/// An Oriented Bounding Box.
+///
+/// This is the box centered at the origin with the half extents `half_extents`, transformed by
+/// `position`. Thus, it bounds exactly a cuboid placed by any isometry.
+#[derive(Clone)]
+pub struct OBB<P: Point, M> {
+    position:     M,
+    half_extents: P::Vector
+}
+
+impl<P: Point, M: Isometry<P>> OBB<P, M> {
+    /// Creates a new OBB from its position and half extents.
+    ///
+    /// Each component of `half_extents` must be positive or null.
+    pub fn new(position: M, half_extents: P::Vector) -> OBB<P, M> {
+        for i in 0 .. na::dimension::<P::Vector>() {
+            assert!(half_extents[i] >= na::zero(), "The half extents of an OBB must be positive.");
+        }
+
+        OBB {
+            position:     position,
+            half_extents: half_extents
+        }
+    }
+
+    /// The transformation applied to the box centered at the origin to obtain this OBB.
+    #[inline]
+    pub fn position(&self) -> &M {
+        &self.position
+    }
+
+    /// The half extents of this OBB, along its own axes.
+    #[inline]
+    pub fn half_extents(&self) -> &P::Vector {
+        &self.half_extents
+    }
+
+    /// Tests whether a world-space point lies inside of this OBB.
+    #[inline]
+    pub fn contains_point(&self, pt: &P) -> bool {
+        let local = self.position.inverse_transform_point(pt);
+
+        (0 .. na::dimension::<P::Vector>()).all(|i| local[i].abs() <= self.half_extents[i])
+    }
+
+    // The `i`-th world-space axis of this OBB.
+    #[inline]
+    fn axis(&self, i: usize) -> P::Vector {
+        let mut axis = na::zero::<P::Vector>();
+        axis[i] = na::one();
+
+        self.position.rotate_vector(&axis)
+    }
+
+    // Half the length of the projection of this OBB on `axis`, times the norm of `axis`.
+    fn radius_along(&self, axis: &P::Vector) -> P::Real {
+        let local   = self.position.inverse_rotate_vector(axis);
+        let mut res = na::zero::<P::Real>();
+
+        for i in 0 .. na::dimension::<P::Vector>() {
+            res += local[i].abs() * self.half_extents[i]
+        }
+
+        res
+    }
+
+    // The `corner`-th corner of this OBB, in the local space of `frame`.
+    fn corner_in(&self, corner: usize, frame: &M) -> P {
+        let mut pt = P::from_coordinates(self.half_extents);
+
+        for k in 0 .. na::dimension::<P::Vector>() {
+            if corner & (1 << k) != 0 {
+                pt[k] = -pt[k]
+            }
+        }
+
+        frame.inverse_transform_point(&self.position.transform_point(&pt))
+    }
+
+    // Whether the projections of both OBBs on `axis` are disjoint.
+    #[inline]
+    fn separated_along(&self, other: &OBB<P, M>, dpos: &P::Vector, axis: &P::Vector) -> bool {
+        na::dot(dpos, axis).abs() > self.radius_along(axis) + other.radius_along(axis)
+    }
+}
+
+impl<P: Point, M: Isometry<P>> BoundingVolume<P> for OBB<P, M> {
+    #[inline]
+    fn center(&self) -> P {
+        self.position.transform_point(&P::origin())
+    }
+
+    /// Tests the separating axes of both OBBs, i.e., their own axes and, in 3D, the cross products
+    /// of those axes.
+    fn intersects(&self, other: &OBB<P, M>) -> bool {
+        let dpos = other.center() - self.center();
+        let dim  = na::dimension::<P::Vector>();
+
+        for i in 0 .. dim {
+            if self.separated_along(other, &dpos, &self.axis(i)) ||
+               self.separated_along(other, &dpos, &other.axis(i)) {
+                return false;
+            }
+        }
+
+        if dim == 3 {
+            let eps = P::Real::default_epsilon();
+
+            // The cross products of nearly parallel axes are skipped: the axes themselves are
+            // already tested.
+            for i in 0 .. dim {
+                let a1 = self.axis(i);
+
+                for j in 0 .. dim {
+                    let axis = utils::cross3(&a1, &other.axis(j));
+
+                    if na::norm_squared(&axis) > eps && self.separated_along(other, &dpos, &axis) {
+                        return false;
+                    }
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Tests the corners of `other`, up to a tolerance relative to the size of this OBB that
+    /// compensates the rounding errors of the change of frame.
+    fn contains(&self, other: &OBB<P, M>) -> bool {
+        let tol = P::Real::default_epsilon().sqrt() * na::norm(&self.half_extents);
+        let dim = na::dimension::<P::Vector>();
+
+        (0usize .. 1 << dim).all(|corner| {
+            let pt = other.corner_in(corner, &self.position);
+            (0 .. dim).all(|i| pt[i].abs() <= self.half_extents[i] + tol)
+        })
+    }
+
+    #[inline]
+    fn merge(&mut self, other: &OBB<P, M>) {
+        *self = self.merged(other)
+    }
+
+    /// The result keeps the orientation of `self`: it is the smallest OBB with this orientation
+    /// containing both OBBs. If `M` cannot represent the translation of its center, e.g., if `M`
+    /// is the identity, the result is the smallest such OBB with the same center as `self`.
+    fn merged(&self, other: &OBB<P, M>) -> OBB<P, M> {
+        let mut mins = P::from_coordinates(-self.half_extents);
+        let mut maxs = P::from_coordinates(self.half_extents);
+
+        for corner in 0usize .. 1 << na::dimension::<P::Vector>() {
+            let pt = other.corner_in(corner, &self.position);
+            mins = na::inf(&mins, &pt);
+            maxs = na::sup(&maxs, &pt);
+        }
+
+        let shift = self.position.rotate_vector(&na::center(&mins, &maxs).coordinates());
+
+        match M::Translation::from_vector(shift) {
+            Some(shift) => OBB {
+                position:     self.position.append_translation(&shift),
+                half_extents: (maxs - mins) / na::convert(2.0f64)
+            },
+            None => OBB {
+                position:     self.position.clone(),
+                half_extents: na::sup(&-mins.coordinates(), &maxs.coordinates())
+            }
+        }
+    }
+
+    #[inline]
+    fn loosen(&mut self, amount: P::Real) {
+        *self = self.loosened(amount)
+    }
+
+    #[inline]
+    fn loosened(&self, amount: P::Real) -> OBB<P, M> {
+        assert!(amount >= na::zero(), "The loosening margin must be positive.");
+
+        OBB {
+            position:     self.position.clone(),
+            half_extents: self.half_extents + utils::repeat(amount)
+        }
+    }
+
+    #[inline]
+    fn tighten(&mut self, amount: P::Real) {
+        *self = self.tightened(amount)
+    }
+
+    #[inline]
+    fn tightened(&self, amount: P::Real) -> OBB<P, M> {
+        assert!(amount >= na::zero(), "The tightening margin must be positive.");
+
+        OBB::new(self.position.clone(), self.half_extents - utils::repeat(amount))
+    }
+}
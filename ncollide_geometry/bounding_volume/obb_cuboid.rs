@@ -0,0 +1,10 @@
+use bounding_volume::{HasBoundingVolume, OBB};
+use shape::Cuboid;
+use math::{Point, Isometry};
+
+impl<P: Point, M: Isometry<P>> HasBoundingVolume<M, OBB<P, M>> for Cuboid<P::Vector> {
+    #[inline]
+    fn bounding_volume(&self, m: &M) -> OBB<P, M> {
+        OBB::new(m.clone(), *self.half_extents())
+    }
+}
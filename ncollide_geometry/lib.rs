@@ -17,8 +17,16 @@ extern crate alga;
 extern crate nalgebra as na;
 extern crate ncollide_math as math;
 extern crate ncollide_utils as utils;
+#[cfg(feature = "arbitrary")]
+extern crate quickcheck;
+#[cfg(feature = "serde-serialize")]
+extern crate serde;
+#[cfg(feature = "serde-serialize")]
+#[macro_use]
+extern crate serde_derive;
 
 pub mod shape;
 pub mod bounding_volume;
 pub mod partitioning;
 pub mod query;
+pub mod volumetric;
@@ -2,23 +2,57 @@
 
 use std::collections::BinaryHeap;
 
-use alga::general::Real;
+use approx::ApproxEq;
+use rustc_serialize::{Encodable, Encoder, Decodable, Decoder};
+#[cfg(feature = "serde-serialize")]
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+#[cfg(feature = "serde-serialize")]
+use serde::ser::{SerializeStruct, SerializeTupleVariant};
+#[cfg(feature = "serde-serialize")]
+use serde::de::Error;
+use num::{Zero, Bounded};
+use alga::general::{Real, Id};
 use na;
 use partitioning::{BVTVisitor, BVTTVisitor, BVTCostFn};
-use bounding_volume::BoundingVolume;
+use bounding_volume::{BoundingVolume, AABB, BoundingSphere};
+use query::PointQuery;
 use utils::data::ref_with_cost::RefWithCost;
 use utils;
 use math::Point;
 
 
 /// A Bounding Volume Tree.
-#[derive(Clone, RustcEncodable, RustcDecodable)]
+///
+/// The nodes are stored contiguously, in depth-first order, to make the traversals cache-friendly.
+/// A tree with AABB or bounding sphere bounding volumes can be decoded, e.g., to reload a tree
+/// built beforehand. The decoding fails if the decoded tree is not valid. The tree is encoded as
+/// its root `BVTNode`. With the `serde-serialize` feature, the same holds for serde.
+#[derive(Clone)]
 pub struct BVT<B, BV> {
-    tree: Option<BVTNode<B, BV>>
+    // The root is the first node. The left child of an internal node is the next node.
+    nodes: Vec<FlatNode<B, BV>>
+}
+
+#[derive(Clone)]
+enum FlatNode<B, BV> {
+    // The bounding volume, and the indices of the left and right children.
+    Internal(BV, usize, usize),
+    Leaf(BV, B)
+}
+
+impl<B, BV> FlatNode<B, BV> {
+    #[inline]
+    fn bounding_volume(&self) -> &BV {
+        match *self {
+            FlatNode::Internal(ref bv, _, _) => bv,
+            FlatNode::Leaf(ref bv, _)        => bv
+        }
+    }
 }
 
-/// A node of the bounding volume tree.
+/// A node of a bounding volume tree built explicitly, see `BVT::from_root`.
 #[derive(Clone, RustcEncodable, RustcDecodable)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
 pub enum BVTNode<B, BV> {
     // XXX: give a faster access to the BV
     /// An internal node.
@@ -42,15 +76,14 @@ impl<B, BV> BVT<B, BV> {
       (leaves:      Vec<(B, BV)>,
        partitioner: &mut F)
           -> BVT<B, BV> {
-        if leaves.len() == 0 {
-            BVT {
-                tree: None
-            }
+        let mut nodes = Vec::new();
+
+        if leaves.len() != 0 {
+            let _ = Self::_new_with_partitioner(0, leaves, partitioner, &mut nodes);
         }
-        else {
-            BVT {
-                tree: Some(Self::_new_with_partitioner(0, leaves, partitioner))
-            }
+
+        BVT {
+            nodes: nodes
         }
     }
 
@@ -59,62 +92,531 @@ impl<B, BV> BVT<B, BV> {
     /// This will traverse the whole tree and call the visitor `.visit_internal(...)` (resp.
     /// `.visit_leaf(...)`) method on each internal (resp. leaf) node.
     pub fn visit<Vis: BVTVisitor<B, BV>>(&self, visitor: &mut Vis) {
-        match self.tree {
-            Some(ref t) => t.visit(visitor),
-            None        => { }
+        if !self.nodes.is_empty() {
+            self.visit_node(0, visitor)
         }
     }
 
     /// Visits the bounding volume traversal tree implicitely formed with `other`.
     pub fn visit_bvtt<Vis: BVTTVisitor<B, BV>>(&self, other: &BVT<B, BV>, visitor: &mut Vis) {
-        match (&self.tree, &other.tree) {
-            (&Some(ref ta), &Some(ref tb)) => ta.visit_bvtt(tb, visitor),
-            _ => { }
+        if !self.nodes.is_empty() && !other.nodes.is_empty() {
+            self.visit_bvtt_nodes(0, other, 0, visitor)
         }
     }
 
     // FIXME: really return a ref to B ?
-    /// Performs a best-fist-search on the tree.
+    /// Performs a best-first search on the tree.
     ///
     /// Returns the content of the leaf with the smallest associated cost, and a result of
-    /// user-defined type.
+    /// user-defined type. The nodes are visited by increasing cost of their bounding volumes, and
+    /// the subtrees with a bounding volume cost greater than the best leaf cost found so far are
+    /// skipped.
     pub fn best_first_search<'a, N, BFS>(&'a self, algorithm: &mut BFS) -> Option<(&'a B, BFS::UserData)>
         where N:   Real,
               BFS: BVTCostFn<N, B, BV> {
-        match self.tree {
-            Some(ref t) => t.best_first_search(algorithm),
-            None        => None
+        let mut queue: BinaryHeap<RefWithCost<'a, N, FlatNode<B, BV>>> = BinaryHeap::new();
+        let mut best_cost = N::max_value();
+        let mut result    = None;
+
+        match self.nodes.first() {
+            Some(root) => match algorithm.compute_bv_cost(root.bounding_volume()) {
+                Some(cost) => queue.push(RefWithCost::new(root, cost)),
+                None       => return None
+            },
+            None => return None
+        }
+
+        loop {
+            match queue.pop() {
+                Some(node) => {
+                    if -node.cost >= best_cost {
+                        break; // solution found.
+                    }
+
+                    match *node.object {
+                        FlatNode::Internal(_, left, right) => {
+                            for child in [ left, right ].iter() {
+                                let child = &self.nodes[*child];
+
+                                match algorithm.compute_bv_cost(child.bounding_volume()) {
+                                    Some(cost) => {
+                                        if cost < best_cost {
+                                            queue.push(RefWithCost::new(child, -cost))
+                                        }
+                                    },
+                                    None => { }
+                                }
+                            }
+                        },
+                        FlatNode::Leaf(_, ref b) => {
+                            match algorithm.compute_b_cost(b) {
+                                Some((candidate_cost, candidate_result)) => {
+                                    if candidate_cost < best_cost {
+                                        best_cost = candidate_cost;
+                                        result    = Some((b, candidate_result));
+                                    }
+                                }
+                                None => { }
+                            }
+                        }
+                    }
+                }
+                None => break,
+            }
         }
+
+        result
+    }
+
+    /// Computes the `k` leaves with the bounding volumes closest to `point`.
+    ///
+    /// The distance to a leaf is the distance from `point` to its bounding volume, i.e., zero if
+    /// this bounding volume contains `point`. The leaves are sorted by increasing distance. All the
+    /// leaves are returned if this tree has less than `k` of them.
+    pub fn k_nearest<'a, P>(&'a self, point: &P, k: usize) -> Vec<(&'a B, P::Real)>
+        where P:  Point,
+              BV: PointQuery<P, Id> {
+        if k == 0 || self.nodes.is_empty() {
+            return Vec::new();
+        }
+
+        // Max-heap of the `k` best leaves found so far, and min-heap of the nodes to visit.
+        let mut best:  BinaryHeap<RefWithCost<'a, P::Real, B>>              = BinaryHeap::new();
+        let mut queue: BinaryHeap<RefWithCost<'a, P::Real, FlatNode<B, BV>>> = BinaryHeap::new();
+
+        let dist = |node: &FlatNode<B, BV>| node.bounding_volume().distance_to_point(&Id::new(), point, true);
+
+        queue.push(RefWithCost::new(&self.nodes[0], -dist(&self.nodes[0])));
+
+        while let Some(node) = queue.pop() {
+            let lower_bound = -node.cost;
+
+            if best.len() == k && lower_bound >= best.peek().unwrap().cost {
+                break; // no remaining node can improve the k-th best leaf.
+            }
+
+            match *node.object {
+                FlatNode::Internal(_, left, right) => {
+                    for child in [ left, right ].iter() {
+                        let child = &self.nodes[*child];
+                        let cost  = dist(child);
+
+                        if best.len() < k || cost < best.peek().unwrap().cost {
+                            queue.push(RefWithCost::new(child, -cost))
+                        }
+                    }
+                },
+                FlatNode::Leaf(_, ref b) => {
+                    best.push(RefWithCost::new(b, lower_bound));
+
+                    if best.len() > k {
+                        let _ = best.pop();
+                    }
+                }
+            }
+        }
+
+        best.into_sorted_vec().into_iter().map(|leaf| (leaf.object, leaf.cost)).collect()
     }
 
     /// Reference to the bounding volume of the tree root.
     pub fn root_bounding_volume<'r>(&'r self) -> Option<&'r BV> {
-        match self.tree {
-            Some(ref n) => {
-                match *n {
-                    BVTNode::Internal(ref bv, _, _) => Some(bv),
-                    BVTNode::Leaf(ref bv, _) => Some(bv)
+        self.nodes.first().map(|n| n.bounding_volume())
+    }
+
+    /// Computes the depth of this tree.
+    pub fn depth(&self) -> usize {
+        if self.nodes.is_empty() {
+            0
+        }
+        else {
+            self.node_depth(0)
+        }
+    }
+
+    /// Consumes this tree and returns its leaves, with their bounding volumes.
+    ///
+    /// The leaves are returned in depth-first order, the left child of each internal node first.
+    /// This is generally not the order they were given to the constructor in.
+    pub fn into_leaves(self) -> Vec<(B, BV)> {
+        // The nodes are already in depth-first order.
+        self.nodes.into_iter().filter_map(|n| {
+            match n {
+                FlatNode::Leaf(bv, b) => Some((b, bv)),
+                FlatNode::Internal(..) => None
+            }
+        }).collect()
+    }
+
+    /// Recomputes the bounding volumes of this tree without changing its topology.
+    ///
+    /// The bounding volume of each leaf is replaced by `new_bv` applied to its content, and the
+    /// bounding volume of each internal node by the merge of those of its children. This is much
+    /// cheaper than rebuilding the tree but the result degrades if the leaves moved too much.
+    pub fn refit<P, F>(&mut self, new_bv: &mut F)
+        where P:  Point,
+              BV: BoundingVolume<P>,
+              F:  FnMut(&B) -> BV {
+        for node in self.nodes.iter_mut() {
+            if let FlatNode::Leaf(ref mut bv, ref b) = *node {
+                *bv = new_bv(b)
+            }
+        }
+
+        self.merge_internal_nodes();
+    }
+
+    // Replaces the bounding volume of each internal node by the merge of those of its children.
+    fn merge_internal_nodes<P>(&mut self)
+        where P:  Point,
+              BV: BoundingVolume<P> {
+        // The children of a node are stored after it.
+        for i in (0 .. self.nodes.len()).rev() {
+            let merged = match self.nodes[i] {
+                FlatNode::Internal(_, left, right) => {
+                    self.nodes[left].bounding_volume().merged(self.nodes[right].bounding_volume())
+                },
+                FlatNode::Leaf(_, _) => continue
+            };
+
+            if let FlatNode::Internal(ref mut bv, _, _) = self.nodes[i] {
+                *bv = merged
+            }
+        }
+    }
+
+    /// Recomputes the bounding volumes of some leaves of this tree, and of their ancestors only.
+    ///
+    /// The bounding volume of each leaf for which `new_bv` returns `Some` is replaced. The other
+    /// leaves are left unchanged, as well as the internal nodes none of the descendants of which
+    /// changed. Returns the number of leaves updated.
+    pub fn refit_partially<P, F>(&mut self, new_bv: &mut F) -> usize
+        where P:  Point,
+              BV: BoundingVolume<P>,
+              F:  FnMut(&B) -> Option<BV> {
+        let mut changed  = vec![ false; self.nodes.len() ];
+        let mut nchanged = 0;
+
+        // The children of a node are stored after it.
+        for i in (0 .. self.nodes.len()).rev() {
+            let (left, right) = match self.nodes[i] {
+                FlatNode::Internal(_, left, right) => {
+                    if !changed[left] && !changed[right] {
+                        continue;
+                    }
+
+                    (left, right)
+                },
+                FlatNode::Leaf(ref mut bv, ref b) => {
+                    if let Some(new) = new_bv(b) {
+                        *bv        = new;
+                        changed[i] = true;
+                        nchanged   = nchanged + 1;
+                    }
+
+                    continue;
+                }
+            };
+
+            let merged = self.nodes[left].bounding_volume().merged(self.nodes[right].bounding_volume());
+            changed[i] = true;
+
+            if let FlatNode::Internal(ref mut bv, _, _) = self.nodes[i] {
+                *bv = merged
+            }
+        }
+
+        nchanged
+    }
+
+    /// Creates a tree from its root node.
+    ///
+    /// Returns `None` if the bounding volume of an internal node of `root` does not contain those
+    /// of its children, up to rounding errors (see `BVT::is_valid`).
+    pub fn from_root<P>(root: BVTNode<B, BV>) -> Option<BVT<B, BV>>
+        where P:  Point,
+              BV: BoundingVolume<P> {
+        if root.is_valid() {
+            Some(BVT::from_valid_root(root))
+        }
+        else {
+            None
+        }
+    }
+
+    fn from_valid_root(root: BVTNode<B, BV>) -> BVT<B, BV> {
+        let mut nodes = Vec::new();
+        let _ = root.flatten(&mut nodes);
+
+        BVT {
+            nodes: nodes
+        }
+    }
+
+    /// Checks that the bounding volume of each internal node contains those of its children.
+    ///
+    /// The containment is checked up to a tolerance relative to the size and position of the
+    /// parent bounding volume, so that the merge of the children is always valid.
+    pub fn is_valid<P>(&self) -> bool
+        where P:  Point,
+              BV: BoundingVolume<P> {
+        self.nodes.iter().all(|n| {
+            match *n {
+                FlatNode::Internal(ref bv, left, right) => {
+                    contains_children(bv, self.nodes[left].bounding_volume(), self.nodes[right].bounding_volume())
+                },
+                FlatNode::Leaf(_, _) => true
+            }
+        })
+    }
+
+    fn visit_node<Vis: BVTVisitor<B, BV>>(&self, i: usize, visitor: &mut Vis) {
+        match self.nodes[i] {
+            FlatNode::Internal(ref bv, left, right) => {
+                if visitor.visit_internal(bv) {
+                    self.visit_node(left, visitor);
+                    self.visit_node(right, visitor);
                 }
             },
-            None => None
+            FlatNode::Leaf(ref bv, ref b) => {
+                visitor.visit_leaf(b, bv);
+            }
         }
     }
 
-    /// Computes the depth of this tree.
-    pub fn depth(&self) -> usize {
-        match self.tree {
-            Some(ref n) => n.depth(),
-            None        => 0
+    fn visit_bvtt_nodes<Vis: BVTTVisitor<B, BV>>(&self, i: usize, other: &BVT<B, BV>, j: usize, visitor: &mut Vis) {
+        match (&self.nodes[i], &other.nodes[j]) {
+            (&FlatNode::Internal(ref bva, la, ra), &FlatNode::Internal(ref bvb, lb, rb)) => {
+                if visitor.visit_internal_internal(bva, bvb) {
+                    self.visit_bvtt_nodes(la, other, lb, visitor);
+                    self.visit_bvtt_nodes(la, other, rb, visitor);
+                    self.visit_bvtt_nodes(ra, other, lb, visitor);
+                    self.visit_bvtt_nodes(ra, other, rb, visitor);
+                }
+            },
+            (&FlatNode::Internal(ref bva, la, ra), &FlatNode::Leaf(ref bvb, ref bb)) => {
+                if visitor.visit_internal_leaf(bva, bb, bvb) {
+                    self.visit_bvtt_nodes(la, other, j, visitor);
+                    self.visit_bvtt_nodes(ra, other, j, visitor);
+                }
+            },
+            (&FlatNode::Leaf(ref bva, ref ba), &FlatNode::Internal(ref bvb, lb, rb)) => {
+                if visitor.visit_leaf_internal(ba, bva, bvb) {
+                    self.visit_bvtt_nodes(i, other, lb, visitor);
+                    self.visit_bvtt_nodes(i, other, rb, visitor);
+                }
+            },
+            (&FlatNode::Leaf(ref bva, ref ba), &FlatNode::Leaf(ref bvb, ref bb)) => {
+                visitor.visit_leaf_leaf(ba, bva, bb, bvb)
+            }
+        }
+    }
+
+    fn node_depth(&self, i: usize) -> usize {
+        match self.nodes[i] {
+            FlatNode::Internal(_, left, right) => 1 + na::max(self.node_depth(left), self.node_depth(right)),
+            FlatNode::Leaf(_, _) => 1
+        }
+    }
+}
+
+impl<B: Encodable, BV: Encodable> BVT<B, BV> {
+    // Encodes the subtree rooted at the i-th node like the equivalent `BVTNode`.
+    fn encode_node<S: Encoder>(&self, i: usize, s: &mut S) -> Result<(), S::Error> {
+        s.emit_enum("BVTNode", |s| {
+            match self.nodes[i] {
+                FlatNode::Internal(ref bv, left, right) => {
+                    s.emit_enum_variant("Internal", 0, 3, |s| {
+                        try!(s.emit_enum_variant_arg(0, |s| bv.encode(s)));
+                        try!(s.emit_enum_variant_arg(1, |s| self.encode_node(left, s)));
+                        s.emit_enum_variant_arg(2, |s| self.encode_node(right, s))
+                    })
+                },
+                FlatNode::Leaf(ref bv, ref b) => {
+                    s.emit_enum_variant("Leaf", 1, 2, |s| {
+                        try!(s.emit_enum_variant_arg(0, |s| bv.encode(s)));
+                        s.emit_enum_variant_arg(1, |s| b.encode(s))
+                    })
+                }
+            }
+        })
+    }
+}
+
+impl<B: Encodable, BV: Encodable> Encodable for BVT<B, BV> {
+    fn encode<S: Encoder>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.emit_struct("BVT", 1, |s| {
+            s.emit_struct_field("tree", 0, |s| {
+                s.emit_option(|s| {
+                    if self.nodes.is_empty() {
+                        s.emit_option_none()
+                    }
+                    else {
+                        s.emit_option_some(|s| self.encode_node(0, s))
+                    }
+                })
+            })
+        })
+    }
+}
+
+impl<B: Decodable, BV: Decodable> BVT<B, BV> {
+    // Decodes a tree encoded as its root `BVTNode`, and checks that it is valid.
+    fn decode_valid<P, D>(d: &mut D) -> Result<BVT<B, BV>, D::Error>
+        where P:  Point,
+              BV: BoundingVolume<P>,
+              D:  Decoder {
+        let tree: Option<BVTNode<B, BV>> = try!(d.read_struct("BVT", 1, |d| {
+            d.read_struct_field("tree", 0, Decodable::decode)
+        }));
+
+        match tree {
+            Some(root) => {
+                if root.is_valid::<P>() {
+                    Ok(BVT::from_valid_root(root))
+                }
+                else {
+                    Err(d.error("Invalid BVT: a bounding volume does not contain those of its children."))
+                }
+            },
+            None => Ok(BVT { nodes: Vec::new() })
         }
     }
 }
 
+impl<B: Decodable, P: Point + Decodable> Decodable for BVT<B, AABB<P>> {
+    fn decode<D: Decoder>(d: &mut D) -> Result<BVT<B, AABB<P>>, D::Error> {
+        BVT::decode_valid::<P, D>(d)
+    }
+}
+
+impl<B: Decodable, P: Point + Decodable> Decodable for BVT<B, BoundingSphere<P>>
+    where P::Real: Decodable {
+    fn decode<D: Decoder>(d: &mut D) -> Result<BVT<B, BoundingSphere<P>>, D::Error> {
+        BVT::decode_valid::<P, D>(d)
+    }
+}
+
+// Serializes the subtree rooted at the `id`-th node of `bvt` like the equivalent `BVTNode`.
+#[cfg(feature = "serde-serialize")]
+struct SerializedNode<'a, B: 'a, BV: 'a> {
+    bvt: &'a BVT<B, BV>,
+    id:  usize
+}
+
+#[cfg(feature = "serde-serialize")]
+impl<'a, B: Serialize, BV: Serialize> Serialize for SerializedNode<'a, B, BV> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        match self.bvt.nodes[self.id] {
+            FlatNode::Internal(ref bv, left, right) => {
+                let mut node = try!(s.serialize_tuple_variant("BVTNode", 0, "Internal", 3));
+                try!(node.serialize_field(bv));
+                try!(node.serialize_field(&SerializedNode { bvt: self.bvt, id: left }));
+                try!(node.serialize_field(&SerializedNode { bvt: self.bvt, id: right }));
+                node.end()
+            },
+            FlatNode::Leaf(ref bv, ref b) => {
+                let mut node = try!(s.serialize_tuple_variant("BVTNode", 1, "Leaf", 2));
+                try!(node.serialize_field(bv));
+                try!(node.serialize_field(b));
+                node.end()
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde-serialize")]
+impl<B: Serialize, BV: Serialize> Serialize for BVT<B, BV> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        let tree = if self.nodes.is_empty() {
+            None
+        }
+        else {
+            Some(SerializedNode { bvt: self, id: 0 })
+        };
+
+        let mut bvt = try!(s.serialize_struct("BVT", 1));
+        try!(bvt.serialize_field("tree", &tree));
+        bvt.end()
+    }
+}
+
+// A tree serialized as its root `BVTNode`, not checked yet.
+#[cfg(feature = "serde-serialize")]
+#[derive(Deserialize)]
+#[serde(rename = "BVT")]
+struct DeserializedBVT<B, BV> {
+    tree: Option<BVTNode<B, BV>>
+}
+
+#[cfg(feature = "serde-serialize")]
+impl<B, BV> BVT<B, BV> {
+    // Checks that a deserialized tree is valid.
+    fn from_deserialized<'de, P, D>(bvt: DeserializedBVT<B, BV>) -> Result<BVT<B, BV>, D::Error>
+        where P:  Point,
+              BV: BoundingVolume<P>,
+              D:  Deserializer<'de> {
+        match bvt.tree {
+            Some(root) => {
+                if root.is_valid::<P>() {
+                    Ok(BVT::from_valid_root(root))
+                }
+                else {
+                    Err(D::Error::custom("Invalid BVT: a bounding volume does not contain those of its children."))
+                }
+            },
+            None => Ok(BVT { nodes: Vec::new() })
+        }
+    }
+}
+
+#[cfg(feature = "serde-serialize")]
+impl<'de, B, P> Deserialize<'de> for BVT<B, AABB<P>>
+    where B: Deserialize<'de>,
+          P: Point + Deserialize<'de> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<BVT<B, AABB<P>>, D::Error> {
+        let bvt = try!(DeserializedBVT::deserialize(d));
+        BVT::from_deserialized::<P, D>(bvt)
+    }
+}
+
+#[cfg(feature = "serde-serialize")]
+impl<'de, B, P> Deserialize<'de> for BVT<B, BoundingSphere<P>>
+    where B: Deserialize<'de>,
+          P: Point + Deserialize<'de>,
+          P::Real: Deserialize<'de> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<BVT<B, BoundingSphere<P>>, D::Error> {
+        let bvt = try!(DeserializedBVT::deserialize(d));
+        BVT::from_deserialized::<P, D>(bvt)
+    }
+}
+
 impl<B, BV> BVT<B, BV> {
     /// Creates a balanced `BVT`.
+    ///
+    /// Panics if the center of the bounding volume of a leaf has an infinite or NaN component,
+    /// since those bounding volumes cannot be sorted.
+    ///
+    /// The bounding volume of each internal node is the merge of those of its children. Unlike
+    /// the merge of the bounding volumes of the leaves of a subtree, it always contains the
+    /// bounding volumes of the intermediate nodes, e.g., for OBBs with different orientations.
     pub fn new_balanced<P>(leaves: Vec<(B, BV)>) -> BVT<B, BV>
         where P:  Point,
               BV: BoundingVolume<P> + Clone {
-        BVT::new_with_partitioner(leaves, &mut Self::median_partitioner)
+        for (i, leaf) in leaves.iter().enumerate() {
+            assert!(utils::is_finite_point(&leaf.1.center()),
+                    "Cannot build a BVT: the bounding volume of the leaf {} is not finite.", i);
+        }
+
+        // The bounding volumes of the internal nodes are set once their children are built.
+        let mut res = BVT::new_with_partitioner(leaves, &mut |depth, leaves: Vec<(B, BV)>| {
+            let bv = leaves[0].1.clone();
+            (bv, Self::median_partition(depth, leaves, &mut |_, bv: &BV| bv.center()))
+        });
+
+        res.merge_internal_nodes();
+
+        res
     }
 
     /// Construction function for a kdree to be used with `BVT::new_with_partitioner`.
@@ -125,9 +627,25 @@ impl<B, BV> BVT<B, BV> {
         if leaves.len() == 0 {
             panic!("Cannot build a tree without leaves.");
         }
+
+        let mut bounding_bounding_volume = leaves[0].1.clone();
+
+        for leaf in leaves[1 ..].iter() {
+            bounding_bounding_volume.merge(&leaf.1);
+        }
+
+        (bounding_bounding_volume, Self::median_partition(depth, leaves, center))
+    }
+
+    // Splits the leaves at their median center along an axis depending on `depth`.
+    fn median_partition<P, F: FnMut(&B, &BV) -> P>(depth: usize, leaves: Vec<(B, BV)>, center: &mut F)
+                                                   -> BinaryPartition<B, BV>
+        where P: Point {
+        if leaves.len() == 0 {
+            panic!("Cannot build a tree without leaves.");
+        }
         else if leaves.len() == 1 {
-            let (b, bv) = leaves.into_iter().next().unwrap();
-            (bv, BinaryPartition::Part(b))
+            BinaryPartition::Part(leaves.into_iter().next().unwrap().0)
         }
         else {
             let sep_axis = depth % na::dimension::<P::Vector>();
@@ -145,13 +663,10 @@ impl<B, BV> BVT<B, BV> {
             // build the partitions
             let mut right = Vec::new();
             let mut left  = Vec::new();
-            let mut bounding_bounding_volume = leaves[0].1.clone();
 
             let mut insert_left = false;
 
             for (b, bv) in leaves.into_iter() {
-                bounding_bounding_volume.merge(&bv);
-
                 let pos = (*center)(&b, &bv)[sep_axis];
 
                 if pos < median || (pos == median && insert_left) {
@@ -172,7 +687,7 @@ impl<B, BV> BVT<B, BV> {
                 right.push(left.pop().unwrap());
             }
 
-            (bounding_bounding_volume, BinaryPartition::Parts(left, right))
+            BinaryPartition::Parts(left, right)
         }
     }
 
@@ -183,136 +698,248 @@ impl<B, BV> BVT<B, BV> {
         Self::median_partitioner_with_centers(depth, leaves, &mut |_, bv| bv.center())
     }
 
+    // Pushes the subtree built from `leaves` to `nodes`, in depth-first order, and returns the
+    // index of its root.
     fn _new_with_partitioner<F: FnMut(usize, Vec<(B, BV)>) -> (BV, BinaryPartition<B, BV>)>
-                             (depth: usize, leaves: Vec<(B, BV)>, partitioner: &mut F) -> BVTNode<B, BV> {
+                             (depth: usize, leaves: Vec<(B, BV)>, partitioner: &mut F,
+                              nodes: &mut Vec<FlatNode<B, BV>>) -> usize {
         let (bv, partitions) = partitioner(depth, leaves);
+        let id = nodes.len();
 
         match partitions {
-            BinaryPartition::Part(b)            => BVTNode::Leaf(bv, b),
+            BinaryPartition::Part(b)            => nodes.push(FlatNode::Leaf(bv, b)),
             BinaryPartition::Parts(left, right) => {
-                let left  = Self::_new_with_partitioner(depth + 1, left, partitioner);
-                let right = Self::_new_with_partitioner(depth + 1, right, partitioner);
-                BVTNode::Internal(bv, Box::new(left), Box::new(right))
+                // The children indices are set once they are built.
+                nodes.push(FlatNode::Internal(bv, 0, 0));
+                let left  = Self::_new_with_partitioner(depth + 1, left, partitioner, nodes);
+                let right = Self::_new_with_partitioner(depth + 1, right, partitioner, nodes);
+
+                if let FlatNode::Internal(_, ref mut l, ref mut r) = nodes[id] {
+                    *l = left;
+                    *r = right;
+                }
             }
         }
+
+        id
     }
 }
 
-impl<B, BV> BVTNode<B, BV> {
-    /// The bounding volume of this node.
-    #[inline]
-    pub fn bounding_volume<'a>(&'a self) -> &'a BV {
-        match *self {
-            BVTNode::Internal(ref bv, _, _) => bv,
-            BVTNode::Leaf(ref bv, _)        => bv
+impl<B, P: Point> BVT<B, AABB<P>> {
+    /// Creates a `BVT` with the surface area heuristic.
+    ///
+    /// This is slower to build than `BVT::new_balanced` but generally gives faster ray casts and
+    /// proximity queries when the leaves have very different sizes, e.g., the triangles of a
+    /// mesh. Panics if the center of the AABB of a leaf has an infinite or NaN component.
+    pub fn new_with_sah(leaves: Vec<(B, AABB<P>)>) -> BVT<B, AABB<P>> {
+        for (i, leaf) in leaves.iter().enumerate() {
+            assert!(utils::is_finite_point(&leaf.1.center()),
+                    "Cannot build a BVT: the bounding volume of the leaf {} is not finite.", i);
         }
+
+        BVT::new_with_partitioner(leaves, &mut Self::sah_partitioner)
     }
 
-    fn visit<Vis: BVTVisitor<B, BV>>(&self, visitor: &mut Vis) {
-        match *self {
-            BVTNode::Internal(ref bv, ref left, ref right) => {
-                if visitor.visit_internal(bv) {
-                    left.visit(visitor);
-                    right.visit(visitor);
+    /// Construction function for a binned surface area heuristic to be used with
+    /// `BVT::new_with_partitioner`.
+    ///
+    /// The leaves are binned by the position of their centers along the axis where those centers
+    /// are the most spread. They are split between two bins so that the sum of the surface areas
+    /// of the two children, weighted by their number of leaves, is minimal.
+    pub fn sah_partitioner(_: usize, leaves: Vec<(B, AABB<P>)>) -> (AABB<P>, BinaryPartition<B, AABB<P>>) {
+        if leaves.len() == 0 {
+            panic!("Cannot build a tree without leaves.");
+        }
+        else if leaves.len() == 1 {
+            let (b, bv) = leaves.into_iter().next().unwrap();
+            return (bv, BinaryPartition::Part(b));
+        }
+
+        let mut bounding_bounding_volume = leaves[0].1.clone();
+        let mut center_mins = leaves[0].1.center();
+        let mut center_maxs = center_mins;
+
+        for &(_, ref bv) in leaves.iter() {
+            let center = bv.center();
+
+            bounding_bounding_volume.merge(bv);
+            center_mins = na::inf(&center_mins, &center);
+            center_maxs = na::sup(&center_maxs, &center);
+        }
+
+        let spread   = center_maxs - center_mins;
+        let mut axis = 0;
+
+        for i in 1 .. na::dimension::<P::Vector>() {
+            if spread[i] > spread[axis] {
+                axis = i;
+            }
+        }
+
+        // All the centers are at the same place: split arbitrarily.
+        if spread[axis].is_zero() {
+            let mut left  = leaves;
+            let mid       = left.len() / 2;
+            let right     = left.split_off(mid);
+
+            return (bounding_bounding_volume, BinaryPartition::Parts(left, right));
+        }
+
+        let nbins: usize = 16;
+        let bin_of = |bv: &AABB<P>| {
+            let pos: P::Real = (bv.center()[axis] - center_mins[axis]) / spread[axis] * na::convert(nbins as f64);
+
+            // NOTE: `pos` is finite and non-negative.
+            let mut bin = 0;
+
+            while bin + 1 < nbins && na::convert::<f64, P::Real>((bin + 1) as f64) <= pos {
+                bin = bin + 1;
+            }
+
+            bin
+        };
+
+        let mut bins: Vec<(usize, Option<AABB<P>>)> = (0 .. nbins).map(|_| (0, None)).collect();
+
+        for &(_, ref bv) in leaves.iter() {
+            let bin = &mut bins[bin_of(bv)];
+
+            bin.0 = bin.0 + 1;
+            bin.1 = Some(match bin.1.take() {
+                Some(aabb) => aabb.merged(bv),
+                None       => bv.clone()
+            });
+        }
+
+        // The cost of the leaves of the bins on the right of each split.
+        let mut right_costs = vec![ na::zero::<P::Real>(); nbins ];
+        let mut count       = 0;
+        let mut aabb: Option<AABB<P>> = None;
+
+        for i in (1 .. nbins).rev() {
+            accumulate_bin(&mut count, &mut aabb, &bins[i]);
+            right_costs[i] = aabb.as_ref().map(|bv| half_surface(bv) * na::convert(count as f64)).unwrap_or(na::zero());
+        }
+
+        // Sweep from the left to find the cheapest split between non-empty children.
+        let mut best_split = 0;
+        let mut best_cost  = P::Real::max_value();
+        let mut count      = 0;
+        let mut aabb: Option<AABB<P>> = None;
+
+        for i in 1 .. nbins {
+            accumulate_bin(&mut count, &mut aabb, &bins[i - 1]);
+
+            if count != 0 && count != leaves.len() {
+                let left_cost = half_surface(aabb.as_ref().unwrap()) * na::convert(count as f64);
+                let cost      = left_cost + right_costs[i];
+
+                if cost < best_cost {
+                    best_cost  = cost;
+                    best_split = i;
                 }
-            },
-            BVTNode::Leaf(ref bv, ref b) => {
-                visitor.visit_leaf(b, bv);
             }
         }
+
+        let mut left  = Vec::new();
+        let mut right = Vec::new();
+
+        for (b, bv) in leaves.into_iter() {
+            if bin_of(&bv) < best_split {
+                left.push((b, bv))
+            }
+            else {
+                right.push((b, bv))
+            }
+        }
+
+        (bounding_bounding_volume, BinaryPartition::Parts(left, right))
     }
+}
 
-    fn visit_bvtt<Vis: BVTTVisitor<B, BV>>(&self, other: &BVTNode<B, BV>, visitor: &mut Vis) {
-        match (self, other) {
-            (&BVTNode::Internal(ref bva, ref la, ref ra), &BVTNode::Internal(ref bvb, ref lb, ref rb)) => {
-                if visitor.visit_internal_internal(bva, bvb) {
-                    la.visit_bvtt(&**lb, visitor);
-                    la.visit_bvtt(&**rb, visitor);
-                    ra.visit_bvtt(&**lb, visitor);
-                    ra.visit_bvtt(&**rb, visitor);
-                }
-            },
-            (&BVTNode::Internal(ref bva, ref la, ref ra), &BVTNode::Leaf(ref bvb, ref bb)) => {
-                if visitor.visit_internal_leaf(bva, bb, bvb) {
-                    la.visit_bvtt(other, visitor);
-                    ra.visit_bvtt(other, visitor);
-                }
-            },
-            (&BVTNode::Leaf(ref bva, ref ba), &BVTNode::Internal(ref bvb, ref lb, ref rb)) => {
-                if visitor.visit_leaf_internal(ba, bva, bvb) {
-                    self.visit_bvtt(&**lb, visitor);
-                    self.visit_bvtt(&**rb, visitor);
-                }
-            },
-            (&BVTNode::Leaf(ref bva, ref ba), &BVTNode::Leaf(ref bvb, ref bb)) => {
-                visitor.visit_leaf_leaf(ba, bva, bb, bvb)
+// Adds the leaves of a bin to the number of leaves and the AABB of a child.
+fn accumulate_bin<P: Point>(count: &mut usize, aabb: &mut Option<AABB<P>>, bin: &(usize, Option<AABB<P>>)) {
+    if let Some(ref bin_aabb) = bin.1 {
+        *count = *count + bin.0;
+        *aabb  = Some(match aabb.take() {
+            Some(aabb) => aabb.merged(bin_aabb),
+            None       => bin_aabb.clone()
+        });
+    }
+}
+
+// Half of the surface area of an AABB, i.e., half of its perimeter in 2D.
+fn half_surface<P: Point>(aabb: &AABB<P>) -> P::Real {
+    let extents = *aabb.maxs() - *aabb.mins();
+    let dim     = na::dimension::<P::Vector>();
+    let mut res = na::zero::<P::Real>();
+
+    for i in 0 .. dim {
+        let mut facet = na::one::<P::Real>();
+
+        for j in 0 .. dim {
+            if j != i {
+                facet = facet * extents[j];
             }
         }
+
+        res = res + facet;
     }
 
-    fn best_first_search<'a, N, BFS>(&'a self, algorithm: &mut BFS) -> Option<(&'a B, BFS::UserData)>
-        where N:   Real,
-              BFS: BVTCostFn<N, B, BV> {
-        let mut queue: BinaryHeap<RefWithCost<'a, N, BVTNode<B, BV>>> = BinaryHeap::new();
-        let mut best_cost = N::max_value();
-        let mut result    = None;
+    res
+}
 
-        match algorithm.compute_bv_cost(self.bounding_volume()) {
-            Some(cost) => queue.push(RefWithCost::new(self, cost)),
-            None       => return None
+impl<B, BV> BVTNode<B, BV> {
+    /// The bounding volume of this node.
+    #[inline]
+    pub fn bounding_volume<'a>(&'a self) -> &'a BV {
+        match *self {
+            BVTNode::Internal(ref bv, _, _) => bv,
+            BVTNode::Leaf(ref bv, _)        => bv
         }
+    }
 
-        loop {
-            match queue.pop() {
-                Some(node) => {
-                    if -node.cost >= best_cost {
-                        break; // solution found.
-                    }
+    // Pushes this subtree to `nodes`, in depth-first order, and returns the index of its root.
+    fn flatten(self, nodes: &mut Vec<FlatNode<B, BV>>) -> usize {
+        let id = nodes.len();
 
-                    match *node.object {
-                        BVTNode::Internal(_, ref left, ref right) => {
-                            match algorithm.compute_bv_cost(left.bounding_volume()) {
-                                Some(lcost) => {
-                                    if lcost < best_cost {
-                                        queue.push(RefWithCost::new(&**left, -lcost))
-                                    }
-                                },
-                                None => { }
-                            }
+        match self {
+            BVTNode::Internal(bv, left, right) => {
+                nodes.push(FlatNode::Internal(bv, 0, 0));
+                let left  = left.flatten(nodes);
+                let right = right.flatten(nodes);
 
-                            match algorithm.compute_bv_cost(right.bounding_volume()) {
-                                Some(rcost) => {
-                                    if rcost < best_cost {
-                                        queue.push(RefWithCost::new(&**right, -rcost))
-                                    }
-                                },
-                                None => { }
-                            }
-                        },
-                        BVTNode::Leaf(_, ref b) => {
-                            match algorithm.compute_b_cost(b) {
-                                Some((candidate_cost, candidate_result)) => {
-                                    if candidate_cost < best_cost {
-                                        best_cost = candidate_cost;
-                                        result    = Some((b, candidate_result));
-                                    }
-                                }
-                                None => { }
-                            }
-                        }
-                    }
+                if let FlatNode::Internal(_, ref mut l, ref mut r) = nodes[id] {
+                    *l = left;
+                    *r = right;
                 }
-                None => break,
-            }
+            },
+            BVTNode::Leaf(bv, b) => nodes.push(FlatNode::Leaf(bv, b))
         }
 
-        result
+        id
     }
 
-    fn depth(&self) -> usize {
+    /// Checks that the bounding volume of each internal node of this subtree contains those of
+    /// its children.
+    pub fn is_valid<P>(&self) -> bool
+        where P:  Point,
+              BV: BoundingVolume<P> {
         match *self {
-            BVTNode::Internal(_, ref left, ref right) => 1 + na::max(left.depth(), right.depth()),
-            BVTNode::Leaf(_, _) => 1
+            BVTNode::Internal(ref bv, ref left, ref right) => {
+                contains_children(bv, left.bounding_volume(), right.bounding_volume()) &&
+                left.is_valid() && right.is_valid()
+            },
+            BVTNode::Leaf(_, _) => true
         }
     }
 }
+
+// Checks, up to rounding errors, that `bv` contains the bounding volumes `left` and `right`.
+fn contains_children<P, BV>(bv: &BV, left: &BV, right: &BV) -> bool
+    where P:  Point,
+          BV: BoundingVolume<P> {
+    let eps = P::Real::default_epsilon().sqrt();
+
+    bv.contains_relative(left, eps) && bv.contains_relative(right, eps)
+}
@@ -1,4 +1,8 @@
 /// Trait implemented by cost functions used by the best-first search on a `BVT`.
+///
+/// The cost of a bounding volume must be a lower bound of the costs of all the objects it
+/// contains, otherwise the search may miss the best object. Returning `None` prunes the bounding
+/// volume or the object.
 pub trait BVTCostFn<N, B, BV> {
     /// User-defined data attached to each BVT leaf.
     type UserData;
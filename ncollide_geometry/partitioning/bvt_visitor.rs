@@ -1,5 +1,9 @@
+use std::cmp::Ordering;
 use std::marker::PhantomData;
-use bounding_volume::BoundingVolume;
+use alga::general::Id;
+use na;
+use bounding_volume::{BoundingVolume, AABB, BoundingSphere};
+use query::PointQuery;
 use math::Point;
 
 /// Visitor of Bounding Volume Trees.
@@ -49,3 +53,226 @@ impl<'a, P, B, BV> BVTVisitor<B, BV> for BoundingVolumeInterferencesCollector<'a
         }
     }
 }
+
+/// Bounding Volume Tree visitor collecting the `k` leaves with the bounding volumes closest to a
+/// point.
+///
+/// The distance to a leaf is the distance from the point to its bounding volume, i.e., zero if
+/// the bounding volume contains the point. The subtrees farther than the `k`-th closest leaf found
+/// so far are pruned. The buffer is cleared on creation, and then contains the leaves found so
+/// far with their distances, sorted by increasing distance.
+///
+/// A visitor traverses the tree depth-first. `BVT::k_nearest` and `DBVT::k_nearest` give the same
+/// result with a best-first search, visiting the nodes by increasing distance, which prunes more
+/// subtrees.
+pub struct KNearestNeighborsVisitor<'a, P: 'a + Point, B: 'a> {
+    point:     &'a P,
+    k:         usize,
+    collector: &'a mut Vec<(B, P::Real)>
+}
+
+impl<'a, P: Point, B> KNearestNeighborsVisitor<'a, P, B> {
+    /// Creates a new `KNearestNeighborsVisitor`.
+    #[inline]
+    pub fn new(point: &'a P, k: usize, buffer: &'a mut Vec<(B, P::Real)>) -> KNearestNeighborsVisitor<'a, P, B> {
+        buffer.clear();
+
+        KNearestNeighborsVisitor {
+            point:     point,
+            k:         k,
+            collector: buffer
+        }
+    }
+
+    // Whether a leaf at the distance `dist` would be one of the `k` closest found so far.
+    #[inline]
+    fn is_candidate(&self, dist: P::Real) -> bool {
+        self.collector.len() < self.k || self.collector.last().map_or(false, |last| dist < last.1)
+    }
+}
+
+impl<'a, P, B, BV> BVTVisitor<B, BV> for KNearestNeighborsVisitor<'a, P, B>
+    where P:  Point,
+          B:  Clone,
+          BV: PointQuery<P, Id> {
+    #[inline]
+    fn visit_internal(&mut self, bv: &BV) -> bool {
+        self.is_candidate(bv.distance_to_point(&Id::new(), self.point, true))
+    }
+
+    #[inline]
+    fn visit_leaf(&mut self, b: &B, bv: &BV) {
+        let dist = bv.distance_to_point(&Id::new(), self.point, true);
+
+        if self.is_candidate(dist) {
+            // The leaves at the same distance keep the order they were found in.
+            let pos = self.collector.binary_search_by(|&(_, d)| {
+                if d <= dist { Ordering::Less } else { Ordering::Greater }
+            }).unwrap_or_else(|pos| pos);
+
+            self.collector.insert(pos, (b.clone(), dist));
+            self.collector.truncate(self.k);
+        }
+    }
+}
+
+/// Bounding Volume Tree visitor collecting the leaves with a bounding volume at a distance
+/// smaller than or equal to a given radius from a point.
+///
+/// The buffer is cleared on creation, like the one of `KNearestNeighborsVisitor`.
+pub struct PointsWithinRadiusCollector<'a, P: 'a + Point, B: 'a> {
+    point:     &'a P,
+    radius:    P::Real,
+    collector: &'a mut Vec<B>
+}
+
+impl<'a, P: Point, B> PointsWithinRadiusCollector<'a, P, B> {
+    /// Creates a new `PointsWithinRadiusCollector`.
+    #[inline]
+    pub fn new(point: &'a P, radius: P::Real, buffer: &'a mut Vec<B>) -> PointsWithinRadiusCollector<'a, P, B> {
+        buffer.clear();
+
+        PointsWithinRadiusCollector {
+            point:     point,
+            radius:    radius,
+            collector: buffer
+        }
+    }
+}
+
+impl<'a, P, B, BV> BVTVisitor<B, BV> for PointsWithinRadiusCollector<'a, P, B>
+    where P:  Point,
+          B:  Clone,
+          BV: PointQuery<P, Id> {
+    #[inline]
+    fn visit_internal(&mut self, bv: &BV) -> bool {
+        bv.distance_to_point(&Id::new(), self.point, true) <= self.radius
+    }
+
+    #[inline]
+    fn visit_leaf(&mut self, b: &B, bv: &BV) {
+        if bv.distance_to_point(&Id::new(), self.point, true) <= self.radius {
+            self.collector.push(b.clone())
+        }
+    }
+}
+
+/// Bounding Volume Tree visitor collecting interferences with a convex region, e.g., a camera
+/// frustum.
+///
+/// The region is the intersection of a set of half-spaces, each given by a point on its boundary
+/// and its outward normal, i.e., it contains the points `pt` such that
+/// `na::dot(&(pt - origin), &normal) <= 0` for each half-space. A bounding volume is rejected if it
+/// lies entirely outside of one of the half-spaces. This test is conservative: a bounding volume
+/// close to an edge or a corner of the region may be collected without intersecting it.
+pub struct HalfSpacesInterferencesCollector<'a, P: 'a + Point, B: 'a> {
+    half_spaces: &'a [(P, P::Vector)],
+    collector:   &'a mut Vec<B>
+}
+
+impl<'a, P: Point, B> HalfSpacesInterferencesCollector<'a, P, B> {
+    /// Creates a new `HalfSpacesInterferencesCollector`.
+    #[inline]
+    pub fn new(half_spaces: &'a [(P, P::Vector)], buffer: &'a mut Vec<B>) -> HalfSpacesInterferencesCollector<'a, P, B> {
+        HalfSpacesInterferencesCollector {
+            half_spaces: half_spaces,
+            collector:   buffer
+        }
+    }
+
+    fn intersects_aabb(&self, aabb: &AABB<P>) -> bool {
+        self.half_spaces.iter().all(|&(ref origin, ref normal)| {
+            // The corner of the AABB the deepest inside of the half-space.
+            let mut corner = *aabb.maxs();
+
+            for i in 0 .. na::dimension::<P::Vector>() {
+                if normal[i] > na::zero() {
+                    corner[i] = aabb.mins()[i];
+                }
+            }
+
+            na::dot(&(corner - *origin), normal) <= na::zero()
+        })
+    }
+
+    fn intersects_bounding_sphere(&self, bs: &BoundingSphere<P>) -> bool {
+        self.half_spaces.iter().all(|&(ref origin, ref normal)| {
+            na::dot(&(*bs.center() - *origin), normal) <= bs.radius() * na::norm(normal)
+        })
+    }
+}
+
+impl<'a, P: Point, B: Clone> BVTVisitor<B, AABB<P>> for HalfSpacesInterferencesCollector<'a, P, B> {
+    #[inline]
+    fn visit_internal(&mut self, bv: &AABB<P>) -> bool {
+        self.intersects_aabb(bv)
+    }
+
+    #[inline]
+    fn visit_leaf(&mut self, b: &B, bv: &AABB<P>) {
+        if self.intersects_aabb(bv) {
+            self.collector.push(b.clone())
+        }
+    }
+}
+
+impl<'a, P: Point, B: Clone> BVTVisitor<B, BoundingSphere<P>> for HalfSpacesInterferencesCollector<'a, P, B> {
+    #[inline]
+    fn visit_internal(&mut self, bv: &BoundingSphere<P>) -> bool {
+        self.intersects_bounding_sphere(bv)
+    }
+
+    #[inline]
+    fn visit_leaf(&mut self, b: &B, bv: &BoundingSphere<P>) {
+        if self.intersects_bounding_sphere(bv) {
+            self.collector.push(b.clone())
+        }
+    }
+}
+
+/// Bounding Volume Tree visitor running two visitors during a single traversal.
+///
+/// A subtree is pruned only if both visitors prune it, and each leaf is shown to both visitors.
+/// Thus, a visitor may be shown the nodes of a subtree it pruned itself. The results are the same
+/// as with two separate traversals as long as each visitor tests the leaves it is shown, like the
+/// `BoundingVolumeInterferencesCollector` and the `RayInterferencesCollector` do.
+pub struct ZipVisitor<V1, V2> {
+    first:  V1,
+    second: V2
+}
+
+impl<V1, V2> ZipVisitor<V1, V2> {
+    /// Creates a visitor running both `first` and `second`.
+    #[inline]
+    pub fn new(first: V1, second: V2) -> ZipVisitor<V1, V2> {
+        ZipVisitor {
+            first:  first,
+            second: second
+        }
+    }
+
+    /// Gets the wrapped visitors.
+    #[inline]
+    pub fn unwrap(self) -> (V1, V2) {
+        (self.first, self.second)
+    }
+}
+
+impl<B, BV, V1, V2> BVTVisitor<B, BV> for ZipVisitor<V1, V2>
+    where V1: BVTVisitor<B, BV>,
+          V2: BVTVisitor<B, BV> {
+    #[inline]
+    fn visit_internal(&mut self, bv: &BV) -> bool {
+        // Both visitors must be consulted, even if the first one already wants to go deeper.
+        let first  = self.first.visit_internal(bv);
+        let second = self.second.visit_internal(bv);
+
+        first || second
+    }
+
+    #[inline]
+    fn visit_leaf(&mut self, b: &B, bv: &BV) {
+        self.first.visit_leaf(b, bv);
+        self.second.visit_leaf(b, bv);
+    }
+}
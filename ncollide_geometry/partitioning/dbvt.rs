@@ -4,10 +4,15 @@ use std::cell::RefCell;
 use std::rc::Rc;
 use std::ptr;
 use std::mem;
+use std::collections::BinaryHeap;
+use alga::general::{Real, Id};
 use utils::data::owned_allocation_cache::OwnedAllocationCache;
+use utils::data::ref_with_cost::RefWithCost;
 use na;
 use bounding_volume::BoundingVolume;
+use query::PointQuery;
 use partitioning::bvt_visitor::BVTVisitor;
+use partitioning::bvt_cost_fn::BVTCostFn;
 use math::Point;
 
 
@@ -35,6 +40,18 @@ impl<P, B, BV> DBVT<P, B, BV> {
             len:   0
         }
     }
+
+    /// The number of leaves of this tree.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether this tree has no leaf.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
 }
 
 impl<P, B, BV> DBVT<P, B, BV>
@@ -77,6 +94,142 @@ impl<P, B, BV> DBVT<P, B, BV>
         self.len = self.len + 1;
     }
 
+    /// Updates the bounding volume of a leaf of this tree.
+    ///
+    /// Nothing is done if the current bounding volume of `leaf` still contains `bv`. Otherwise,
+    /// the leaf is removed, its bounding volume is replaced by `bv` enlarged by `margin`, and it is
+    /// inserted back. The enlarged bounding volume lets objects move slightly without any tree
+    /// update. Returns `true` if the leaf was reinserted. Fails if `leaf` is not in this tree.
+    pub fn refit(&mut self, leaf: &mut Rc<RefCell<DBVTLeaf<P, B, BV>>>, bv: BV, margin: P::Real) -> bool {
+        if leaf.borrow().bounding_volume.contains(&bv) {
+            return false;
+        }
+
+        self.remove(leaf);
+
+        {
+            let mut bleaf = leaf.borrow_mut();
+            bleaf.bounding_volume = bv.loosened(margin);
+            bleaf.center          = bleaf.bounding_volume.center();
+        }
+
+        self.insert(leaf.clone());
+
+        true
+    }
+
+    /// Performs a best-first-search on this tree.
+    ///
+    /// This is the same search as `BVT::best_first_search`. Returns a copy of the content of the
+    /// best leaf and the result computed for it, or `None` if no leaf has a cost.
+    pub fn best_first_search<N, BFS>(&self, algorithm: &mut BFS) -> Option<(B, BFS::UserData)>
+        where N:   Real,
+              BFS: BVTCostFn<N, B, BV> {
+        let mut queue: BinaryHeap<RefWithCost<N, DBVTNode<P, B, BV>>> = BinaryHeap::new();
+        let mut best_cost = N::max_value();
+        let mut result    = None;
+
+        match self.tree {
+            Some(ref root) => match root.bv_cost(algorithm) {
+                Some(cost) => queue.push(RefWithCost::new(root, -cost)),
+                None       => return None
+            },
+            None => return None
+        }
+
+        while let Some(node) = queue.pop() {
+            if -node.cost >= best_cost {
+                break; // solution found.
+            }
+
+            match *node.object {
+                DBVTNode::Internal(ref i) => {
+                    for child in [ &i.left, &i.right ].iter() {
+                        if let Some(cost) = child.bv_cost(algorithm) {
+                            if cost < best_cost {
+                                queue.push(RefWithCost::new(*child, -cost))
+                            }
+                        }
+                    }
+                },
+                DBVTNode::Leaf(ref l) => {
+                    let bl = l.borrow();
+
+                    if let Some((candidate_cost, candidate_result)) = algorithm.compute_b_cost(&bl.object) {
+                        if candidate_cost < best_cost {
+                            best_cost = candidate_cost;
+                            result    = Some((bl.object.clone(), candidate_result));
+                        }
+                    }
+                },
+                DBVTNode::Invalid => unreachable!()
+            }
+        }
+
+        result
+    }
+
+    /// Computes the `k` leaves with the bounding volumes closest to `point`.
+    ///
+    /// This is the same best-first search as `BVT::k_nearest`. Returns a copy of the content of
+    /// those leaves with their distances, sorted by increasing distance.
+    pub fn k_nearest(&self, point: &P, k: usize) -> Vec<(B, P::Real)>
+        where BV: PointQuery<P, Id> {
+        let root = match self.tree {
+            Some(ref root) if k != 0 => root,
+            _                        => return Vec::new()
+        };
+
+        // Max-heap of the `k` best leaves found so far, and min-heap of the nodes to visit.
+        let mut best:  BinaryHeap<RefWithCost<P::Real, DBVTNode<P, B, BV>>> = BinaryHeap::new();
+        let mut queue: BinaryHeap<RefWithCost<P::Real, DBVTNode<P, B, BV>>> = BinaryHeap::new();
+
+        let dist = |node: &DBVTNode<P, B, BV>| {
+            match *node {
+                DBVTNode::Internal(ref i) => i.bounding_volume.distance_to_point(&Id::new(), point, true),
+                DBVTNode::Leaf(ref l)     => l.borrow().bounding_volume.distance_to_point(&Id::new(), point, true),
+                DBVTNode::Invalid         => unreachable!()
+            }
+        };
+
+        queue.push(RefWithCost::new(root, -dist(root)));
+
+        while let Some(node) = queue.pop() {
+            let lower_bound = -node.cost;
+
+            if best.len() == k && lower_bound >= best.peek().unwrap().cost {
+                break; // no remaining node can improve the k-th best leaf.
+            }
+
+            match *node.object {
+                DBVTNode::Internal(ref i) => {
+                    for child in [ &i.left, &i.right ].iter() {
+                        let cost = dist(*child);
+
+                        if best.len() < k || cost < best.peek().unwrap().cost {
+                            queue.push(RefWithCost::new(*child, -cost))
+                        }
+                    }
+                },
+                DBVTNode::Leaf(_) => {
+                    best.push(RefWithCost::new(node.object, lower_bound));
+
+                    if best.len() > k {
+                        let _ = best.pop();
+                    }
+                },
+                DBVTNode::Invalid => unreachable!()
+            }
+        }
+
+        best.into_sorted_vec().into_iter().map(|leaf| {
+            match *leaf.object {
+                DBVTNode::Leaf(ref l) => (l.borrow().object.clone(), leaf.cost),
+                _                     => unreachable!()
+            }
+        }).collect()
+    }
+
     /// Traverses this tree using an object implementing the `BVTVisitor`trait.
     ///
     /// This will traverse the whole tree and call the visitor `.visit_internal(...)` (resp.
@@ -306,6 +459,14 @@ impl<P, BV, B> DBVTNode<P, B, BV>
     where P:  Point,
           BV: 'static + BoundingVolume<P>,
           B: 'static {
+    fn bv_cost<N, BFS: BVTCostFn<N, B, BV>>(&self, algorithm: &mut BFS) -> Option<N> {
+        match *self {
+            DBVTNode::Internal(ref i) => algorithm.compute_bv_cost(&i.bounding_volume),
+            DBVTNode::Leaf(ref l)     => algorithm.compute_bv_cost(&l.borrow().bounding_volume),
+            DBVTNode::Invalid         => unreachable!()
+        }
+    }
+
     fn sqdist_to(&self, to: &P) -> P::Real {
         match *self {
             DBVTNode::Internal(ref i) => na::distance_squared(&i.center, to),
@@ -2,8 +2,11 @@
 
 pub use partitioning::dbvt::{DBVT, DBVTLeaf};
 pub use partitioning::bvt::{BVT, BinaryPartition, BVTNode};
+pub use partitioning::octree::Octree;
 #[doc(inline)]
-pub use partitioning::bvt_visitor::{BVTVisitor, BoundingVolumeInterferencesCollector};
+pub use partitioning::bvt_visitor::{BVTVisitor, BoundingVolumeInterferencesCollector, ZipVisitor,
+                                    KNearestNeighborsVisitor, PointsWithinRadiusCollector,
+                                    HalfSpacesInterferencesCollector};
 #[doc(inline)]
 pub use partitioning::bvtt_visitor::BVTTVisitor;
 #[doc(inline)]
@@ -11,6 +14,7 @@ pub use partitioning::bvt_cost_fn::BVTCostFn;
 
 mod dbvt;
 mod bvt;
+mod octree;
 
 #[doc(hidden)]
 pub mod bvt_visitor;
@@ -0,0 +1,223 @@
+//! A loose octree.
+
+use na;
+use bounding_volume::{BoundingVolume, AABB};
+use partitioning::BVTVisitor;
+use math::Point;
+
+struct OctreeNode<P: Point> {
+    // The cell of this node, loosened by half its size on each side.
+    loose:    AABB<P>,
+    children: Option<usize>, // Index of the first of the 2^dim children.
+    objects:  Vec<usize>
+}
+
+struct OctreeObject<P: Point, T> {
+    aabb: AABB<P>,
+    data: T,
+    node: usize
+}
+
+/// A loose octree, i.e., a quadtree in 2D.
+///
+/// The space covered by the root bounding box is recursively subdivided into cells of equal
+/// sizes. Each object is stored in the deepest cell containing its center and larger than the
+/// object. Cells are loose: the bounding volume of each node is its cell enlarged twice so
+/// that it encloses all the objects the node contains even if they straddle the cell boundaries.
+/// Objects lying outside of the root bounding box are stored by the root.
+///
+/// Unlike the BVT, the tree structure does not depend on the objects, so objects can be inserted
+/// and removed at a constant cost.
+pub struct Octree<P: Point, T> {
+    nodes:     Vec<OctreeNode<P>>,
+    objects:   Vec<Option<OctreeObject<P, T>>>,
+    free:      Vec<usize>,
+    max_depth: usize,
+    // The root bounding volume enlarged to enclose all the objects inserted so far.
+    root_bv:   AABB<P>
+}
+
+impl<P: Point, T> Octree<P, T> {
+    /// Creates a new empty octree subdividing the given bounding box at most `max_depth` times.
+    pub fn new(bounds: AABB<P>, max_depth: usize) -> Octree<P, T> {
+        let root = OctreeNode {
+            loose:    loosened_cell(&bounds),
+            children: None,
+            objects:  Vec::new()
+        };
+        let root_bv = root.loose.clone();
+
+        Octree {
+            nodes:     vec![root],
+            objects:   Vec::new(),
+            free:      Vec::new(),
+            max_depth: max_depth,
+            root_bv:   root_bv
+        }
+    }
+
+    /// The number of objects stored by this octree.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.objects.len() - self.free.len()
+    }
+
+    /// Inserts an object with the given bounding box and returns its handle.
+    pub fn insert(&mut self, aabb: AABB<P>, data: T) -> usize {
+        let dim     = na::dimension::<P::Vector>();
+        let center  = aabb.center();
+        let extents = aabb.half_extents();
+        let mut id  = 0;
+
+        if self.nodes[0].loose.contains(&aabb) {
+            for _ in 0 .. self.max_depth {
+                // The object fits in the loose bounds of the child cell containing its center iff
+                // it is not larger than this child cell.
+                let cell       = cell(&self.nodes[id].loose);
+                let child_half = cell.half_extents() * na::convert::<f64, P::Real>(0.5);
+                let center_in  = (0 .. dim).all(|i| center[i] >= cell.mins()[i] && center[i] <= cell.maxs()[i]);
+
+                if !center_in || (0 .. dim).any(|i| extents[i] > child_half[i]) {
+                    break;
+                }
+
+                let first = self.subdivide(id);
+                let mut child = 0;
+
+                for i in 0 .. dim {
+                    if center[i] >= cell.center()[i] {
+                        child |= 1 << i;
+                    }
+                }
+
+                id = first + child;
+            }
+        }
+        else {
+            self.root_bv.merge(&aabb);
+        }
+
+        let object = OctreeObject { aabb: aabb, data: data, node: id };
+        let handle = match self.free.pop() {
+            Some(handle) => {
+                self.objects[handle] = Some(object);
+                handle
+            },
+            None => {
+                self.objects.push(Some(object));
+                self.objects.len() - 1
+            }
+        };
+
+        self.nodes[id].objects.push(handle);
+
+        handle
+    }
+
+    /// Removes the object with the given handle and returns its data, if it exists.
+    pub fn remove(&mut self, handle: usize) -> Option<T> {
+        let object = match self.objects.get_mut(handle) {
+            Some(object) => object.take(),
+            None         => None
+        };
+
+        object.map(|object| {
+            let objects = &mut self.nodes[object.node].objects;
+            let pos     = objects.iter().position(|h| *h == handle).unwrap();
+            let _       = objects.swap_remove(pos);
+
+            self.free.push(handle);
+
+            object.data
+        })
+    }
+
+    /// The bounding box and data of the object with the given handle, if it exists.
+    #[inline]
+    pub fn get(&self, handle: usize) -> Option<(&AABB<P>, &T)> {
+        match self.objects.get(handle) {
+            Some(&Some(ref object)) => Some((&object.aabb, &object.data)),
+            _                       => None
+        }
+    }
+
+    /// Traverses this octree using an object implementing the `BVTVisitor` trait.
+    ///
+    /// The loose bounding volume of each node is given to `visitor.visit_internal(...)`. If it
+    /// returns `true`, the objects of this node are given to `visitor.visit_leaf(...)` and its
+    /// children are visited too.
+    pub fn visit<Vis: BVTVisitor<T, AABB<P>>>(&self, visitor: &mut Vis) {
+        if visitor.visit_internal(&self.root_bv) {
+            self.visit_node(0, visitor)
+        }
+    }
+
+    fn visit_node<Vis: BVTVisitor<T, AABB<P>>>(&self, id: usize, visitor: &mut Vis) {
+        let node = &self.nodes[id];
+
+        for handle in node.objects.iter() {
+            if let Some(ref object) = self.objects[*handle] {
+                visitor.visit_leaf(&object.data, &object.aabb)
+            }
+        }
+
+        if let Some(first) = node.children {
+            for child in first .. first + (1 << na::dimension::<P::Vector>()) {
+                if visitor.visit_internal(&self.nodes[child].loose) {
+                    self.visit_node(child, visitor)
+                }
+            }
+        }
+    }
+
+    // Creates the children of the given node if needed, and returns the index of the first one.
+    fn subdivide(&mut self, id: usize) -> usize {
+        if let Some(first) = self.nodes[id].children {
+            return first;
+        }
+
+        let dim   = na::dimension::<P::Vector>();
+        let cell  = cell(&self.nodes[id].loose);
+        let first = self.nodes.len();
+
+        for child in 0 .. 1 << dim {
+            let mut mins = *cell.mins();
+            let mut maxs = *cell.maxs();
+            let center   = cell.center();
+
+            for i in 0 .. dim {
+                if child & (1 << i) == 0 {
+                    maxs[i] = center[i];
+                }
+                else {
+                    mins[i] = center[i];
+                }
+            }
+
+            self.nodes.push(OctreeNode {
+                loose:    loosened_cell(&AABB::new(mins, maxs)),
+                children: None,
+                objects:  Vec::new()
+            });
+        }
+
+        self.nodes[id].children = Some(first);
+
+        first
+    }
+}
+
+// Loosens a cell by half its size on each side.
+fn loosened_cell<P: Point>(cell: &AABB<P>) -> AABB<P> {
+    let half_extents = cell.half_extents();
+
+    AABB::new(*cell.mins() + (-half_extents), *cell.maxs() + half_extents)
+}
+
+// The cell of a node from its loose bounding volume.
+fn cell<P: Point>(loose: &AABB<P>) -> AABB<P> {
+    let half_extents = loose.half_extents() * na::convert::<f64, P::Real>(0.5);
+    let center       = loose.center();
+
+    AABB::new(center + (-half_extents), center + half_extents)
+}
@@ -1,7 +1,6 @@
 //! The Gilbert–Johnson–Keerthi distance algorithm.
 
 use num::{Zero, Bounded};
-use approx::ApproxEq;
 
 use alga::general::{Id, Real};
 use alga::linear::NormedSpace;
@@ -9,7 +8,7 @@ use na;
 
 use shape::{SupportMap, AnnotatedPoint, AnnotatedMinkowskiSum, MinkowskiSum, Reflection};
 use query::algorithms::simplex::Simplex;
-use query::Proximity;
+use query::{Proximity, QuerySettings};
 use query::{ray_internal, Ray};
 use math::{Point, Isometry};
 
@@ -27,6 +26,16 @@ pub enum GJKResult<P, V> {
     NoIntersection(V)
 }
 
+/// Termination status of the GJK algorithm.
+#[derive(RustcEncodable, RustcDecodable, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GJKStatus {
+    /// The algorithm terminated before exceeding its maximum number of iterations.
+    Converged,
+    /// The algorithm was interrupted after `QuerySettings::max_iterations` iterations. Its result
+    /// is the last estimate computed and may be inaccurate.
+    MaxIterationsReached
+}
+
 /// Computes the closest points between two convex shapes unsing the GJK
 /// algorithm.
 ///
@@ -36,12 +45,14 @@ pub enum GJKResult<P, V> {
 /// * `simplex` - the simplex to be used by the GJK algorithm. It must be already initialized
 ///               with at least one point on the shapes CSO. See
 ///               `minkowski_sum::cso_support_point` to compute such point.
-pub fn closest_points<P, M, S, G1: ?Sized, G2: ?Sized>(m1:      &M,
-                                                       g1:      &G1,
-                                                       m2:      &M,
-                                                       g2:      &G2,
-                                                       simplex: &mut S)
-                                                       -> Option<(P, P)>
+/// * `settings` - the numerical tolerances of the GJK algorithm.
+pub fn closest_points<P, M, S, G1: ?Sized, G2: ?Sized>(m1:       &M,
+                                                       g1:       &G1,
+                                                       m2:       &M,
+                                                       g2:       &G2,
+                                                       simplex:  &mut S,
+                                                       settings: &QuerySettings<P::Real>)
+                                                       -> (Option<(P, P)>, GJKStatus)
     where P:  Point,
           S:  Simplex<AnnotatedPoint<P>>,
           G1: SupportMap<P, M>,
@@ -49,7 +60,9 @@ pub fn closest_points<P, M, S, G1: ?Sized, G2: ?Sized>(m1:      &M,
     let reflect2 = Reflection::new(g2);
     let cso      = AnnotatedMinkowskiSum::new(m1, g1, m2, &reflect2);
 
-    project_origin(&Id::new(), &cso, simplex).map(|p| (*p.orig1(), -*p.orig2()))
+    let (proj, status) = project_origin(&Id::new(), &cso, simplex, settings);
+
+    (proj.map(|p| (*p.orig1(), -*p.orig2())), status)
 }
 
 /// Computes the closest points between two convex shapes unsing the GJK algorithm.
@@ -60,13 +73,15 @@ pub fn closest_points<P, M, S, G1: ?Sized, G2: ?Sized>(m1:      &M,
 /// * `simplex` - the simplex to be used by the GJK algorithm. It must be already initialized
 ///               with at least one point on the shapes CSO. See `minkowski_sum::cso_support_point`
 ///               to compute such point.
+/// * `settings` - the numerical tolerances of the GJK algorithm.
 pub fn closest_points_with_max_dist<P, M, S, G1: ?Sized, G2: ?Sized>(m1:       &M,
                                                                      g1:       &G1,
                                                                      m2:       &M,
                                                                      g2:       &G2,
                                                                      max_dist: P::Real,
-                                                                     simplex:  &mut S)
-                                                                     -> GJKResult<(P, P), P::Vector>
+                                                                     simplex:  &mut S,
+                                                                     settings: &QuerySettings<P::Real>)
+                                                                     -> (GJKResult<(P, P), P::Vector>, GJKStatus)
     where P:  Point,
           S:  Simplex<AnnotatedPoint<P>>,
           G1: SupportMap<P, M>,
@@ -74,12 +89,15 @@ pub fn closest_points_with_max_dist<P, M, S, G1: ?Sized, G2: ?Sized>(m1:       &
     let reflect2 = Reflection::new(g2);
     let cso      = AnnotatedMinkowskiSum::new(m1, g1, m2, &reflect2);
 
-    match project_origin_with_max_dist(&Id::new(), &cso, max_dist, true, simplex) {
+    let (res, status) = project_origin_with_max_dist(&Id::new(), &cso, max_dist, true, simplex, settings);
+    let res = match res {
         GJKResult::Projection(p)       => GJKResult::Projection((*p.orig1(), -*p.orig2())),
         GJKResult::Intersection        => GJKResult::Intersection,
         GJKResult::NoIntersection(dir) => GJKResult::NoIntersection(dir),
         GJKResult::Proximity(_)        => unreachable!()
-    }
+    };
+
+    (res, status)
 }
 
 /// Computes the exact distance separating two convex shapes unsing the GJK.
@@ -91,8 +109,10 @@ pub fn closest_points_with_max_dist<P, M, S, G1: ?Sized, G2: ?Sized>(m1:       &
 /// * `simplex` - the simplex to be used by the GJK algorithm. It must be already initialized
 ///               with at least one point on the shapes CSO. See `minkowski_sum::cso_support_point`
 ///               to compute such point.
-pub fn distance<P, M, S, G1: ?Sized, G2: ?Sized>(m1: &M, g1: &G1, m2: &M, g2: &G2, simplex: &mut S)
-                                                 -> P::Real
+/// * `settings` - the numerical tolerances of the GJK algorithm.
+pub fn distance<P, M, S, G1: ?Sized, G2: ?Sized>(m1: &M, g1: &G1, m2: &M, g2: &G2, simplex: &mut S,
+                                                 settings: &QuerySettings<P::Real>)
+                                                 -> (P::Real, GJKStatus)
     where P:  Point,
           S:  Simplex<P>,
           G1: SupportMap<P, M>,
@@ -100,10 +120,13 @@ pub fn distance<P, M, S, G1: ?Sized, G2: ?Sized>(m1: &M, g1: &G1, m2: &M, g2: &G
     let reflect2 = Reflection::new(g2);
     let cso      = MinkowskiSum::new(m1, g1, m2, &reflect2);
 
-    match project_origin(&Id::new(), &cso, simplex) {
+    let (proj, status) = project_origin(&Id::new(), &cso, simplex, settings);
+    let dist = match proj {
         Some(c) => na::norm(&c.coordinates()),
         None    => na::zero()
-    }
+    };
+
+    (dist, status)
 }
 
 /// Computes the closest points between two convex shapes unsing the GJK algorithm.
@@ -114,13 +137,15 @@ pub fn distance<P, M, S, G1: ?Sized, G2: ?Sized>(m1: &M, g1: &G1, m2: &M, g2: &G
 /// * `simplex` - the simplex to be used by the GJK algorithm. It must be already initialized
 ///               with at least one point on the shapes CSO. See `minkowski_sum::cso_support_point`
 ///               to compute such point.
+/// * `settings` - the numerical tolerances of the GJK algorithm.
 pub fn proximity<P, M, S, G1: ?Sized, G2: ?Sized>(m1:       &M,
                                                   g1:       &G1,
                                                   m2:       &M,
                                                   g2:       &G2,
                                                   max_dist: P::Real,
-                                                  simplex:  &mut S)
-                                                  -> (Proximity, P::Vector)
+                                                  simplex:  &mut S,
+                                                  settings: &QuerySettings<P::Real>)
+                                                  -> (Proximity, P::Vector, GJKStatus)
     where P:  Point,
           S:  Simplex<AnnotatedPoint<P>>,
           G1: SupportMap<P, M>,
@@ -128,11 +153,11 @@ pub fn proximity<P, M, S, G1: ?Sized, G2: ?Sized>(m1:       &M,
     let reflect2 = Reflection::new(g2);
     let cso      = AnnotatedMinkowskiSum::new(m1, g1, m2, &reflect2);
 
-    match project_origin_with_max_dist(&Id::new(), &cso, max_dist, false, simplex) {
-        GJKResult::NoIntersection(data) => (Proximity::Disjoint, data),
-        GJKResult::Proximity(data)      => (Proximity::WithinMargin, data),
-        GJKResult::Intersection         => (Proximity::Intersecting, na::zero()),
-        GJKResult::Projection(_)        => unreachable!()
+    match project_origin_with_max_dist(&Id::new(), &cso, max_dist, false, simplex, settings) {
+        (GJKResult::NoIntersection(data), status) => (Proximity::Disjoint, data, status),
+        (GJKResult::Proximity(data), status)      => (Proximity::WithinMargin, data, status),
+        (GJKResult::Intersection, status)         => (Proximity::Intersecting, na::zero(), status),
+        (GJKResult::Projection(_), _)             => unreachable!()
     }
 }
 
@@ -145,7 +170,9 @@ pub fn proximity<P, M, S, G1: ?Sized, G2: ?Sized>(m1:       &M,
 /// * shape - the shape to project the origin on
 /// * simplex - the simplex to be used by the GJK algorithm. It must be already initialized
 ///             with at least one point on the shape boundary.
-pub fn project_origin<P, M, S, G: ?Sized>(m: &M, shape: &G, simplex: &mut S) -> Option<P>
+/// * settings - the numerical tolerances of the GJK algorithm.
+pub fn project_origin<P, M, S, G: ?Sized>(m: &M, shape: &G, simplex: &mut S, settings: &QuerySettings<P::Real>)
+                                          -> (Option<P>, GJKStatus)
     where P: Point,
           S: Simplex<P>,
           G: SupportMap<P, M> {
@@ -153,20 +180,19 @@ pub fn project_origin<P, M, S, G: ?Sized>(m: &M, shape: &G, simplex: &mut S) ->
     let mut proj      = simplex.project_origin_and_reduce();
     let mut max_bound = na::norm_squared(&proj.coordinates());
 
-    let _eps       = P::Real::default_epsilon();
-    let _eps_tol   = _eps * na::convert(100.0f64);
-    let _eps_rel   = _eps.sqrt();
+    let _eps_tol   = settings.contact_tolerance;
+    let _eps_rel   = settings.gjk_tolerance;
     let _dimension = na::dimension::<P::Vector>();
 
-    loop {
+    for _ in 0 .. settings.max_iterations {
         if simplex.dimension() == _dimension || max_bound <= _eps_tol /* * simplex.max_sq_len()*/ {
-            return None // point inside of the cso
+            return (None, GJKStatus::Converged) // point inside of the cso
         }
 
         let support_point = shape.support_point(m, &-proj.coordinates());
 
         if max_bound - na::dot(&proj.coordinates(), &support_point.coordinates()) <= _eps_rel * max_bound {
-            return Some(proj) // the distance found has a good enough precision
+            return (Some(proj), GJKStatus::Converged) // the distance found has a good enough precision
         }
 
         simplex.add_point(support_point);
@@ -180,9 +206,11 @@ pub fn project_origin<P, M, S, G: ?Sized>(m: &M, shape: &G, simplex: &mut S) ->
         max_bound = na::norm_squared(&proj.coordinates());
 
         if max_bound >= old_max_bound {
-            return Some(old_proj) // upper bounds inconsistencies
+            return (Some(old_proj), GJKStatus::Converged) // upper bounds inconsistencies
         }
     }
+
+    (Some(proj), GJKStatus::MaxIterationsReached)
 }
 
 /*
@@ -201,12 +229,17 @@ pub fn project_origin<P, M, S, G: ?Sized>(m: &M, shape: &G, simplex: &mut S) ->
 /// `GJKResult::Proximity(sep_axis)` where `sep_axis` is a separating axis. If `false` the gjk will
 /// compute the exact distance and return `GJKResult::Projection(point)` if the origin is closer
 /// than `max_dist` but not inside `shape`.
+/// * settings - the numerical tolerances of the GJK algorithm.
+///
+/// If the maximum number of iterations is reached, the last projection is returned as if the
+/// algorithm converged.
 pub fn project_origin_with_max_dist<P, M, S, G: ?Sized>(m:          &M,
                                                         shape:      &G,
                                                         max_dist:   P::Real,
                                                         exact_dist: bool,
-                                                        simplex:    &mut S)
-                                                        -> GJKResult<P, P::Vector>
+                                                        simplex:    &mut S,
+                                                        settings:   &QuerySettings<P::Real>)
+                                                        -> (GJKResult<P, P::Vector>, GJKStatus)
     where P: Point,
           S: Simplex<P>,
           G: SupportMap<P, M> {
@@ -214,14 +247,15 @@ pub fn project_origin_with_max_dist<P, M, S, G: ?Sized>(m:          &M,
     let mut proj      = simplex.project_origin_and_reduce();
     let mut max_bound = na::norm_squared(&proj.coordinates());
 
-    let _eps       = P::Real::default_epsilon();
-    let _eps_tol   = _eps * na::convert(100.0f64);
-    let _eps_rel   = _eps.sqrt();
+    let _eps_tol   = settings.contact_tolerance;
+    let _eps_rel   = settings.gjk_tolerance;
     let _dimension = na::dimension::<P::Vector>();
 
-    loop {
+    let converged = GJKStatus::Converged;
+
+    for _ in 0 .. settings.max_iterations {
         if simplex.dimension() == _dimension || max_bound <= _eps_tol /* * simplex.max_sq_len()*/ {
-            return GJKResult::Intersection // point inside of the cso
+            return (GJKResult::Intersection, converged) // point inside of the cso
         }
 
         let support_point = shape.support_point(m, &-proj.coordinates());
@@ -230,15 +264,15 @@ pub fn project_origin_with_max_dist<P, M, S, G: ?Sized>(m:          &M,
 
         // FIXME: find a way to avoid the sqrt here
         if min_bound > max_dist * na::norm(&proj.coordinates()) {
-            return GJKResult::NoIntersection(proj.coordinates());
+            return (GJKResult::NoIntersection(proj.coordinates()), converged);
         }
 
         if max_bound - min_bound <= _eps_rel * max_bound {
             if exact_dist {
-                return GJKResult::Projection(proj) // the distance found has a good enough precision
+                return (GJKResult::Projection(proj), converged) // the distance found has a good enough precision
             }
             else {
-                return GJKResult::Proximity(proj.coordinates())
+                return (GJKResult::Proximity(proj.coordinates()), converged)
             }
         }
 
@@ -254,33 +288,43 @@ pub fn project_origin_with_max_dist<P, M, S, G: ?Sized>(m:          &M,
 
         if max_bound >= old_max_bound {
             if exact_dist {
-                return GJKResult::Projection(old_proj) // upper bounds inconsistencies
+                return (GJKResult::Projection(old_proj), converged) // upper bounds inconsistencies
             }
             else {
-                return GJKResult::Proximity(old_proj.coordinates())
+                return (GJKResult::Proximity(old_proj.coordinates()), converged)
             }
         }
 
         if !exact_dist && min_bound > na::zero() && max_bound <= max_dist * max_dist {
-            return GJKResult::Proximity(old_proj.coordinates())
+            return (GJKResult::Proximity(old_proj.coordinates()), converged)
         }
     }
+
+    if exact_dist {
+        (GJKResult::Projection(proj), GJKStatus::MaxIterationsReached)
+    }
+    else {
+        (GJKResult::Proximity(proj.coordinates()), GJKStatus::MaxIterationsReached)
+    }
 }
 
 /// Casts a ray on a support map using the GJK algorithm.
-pub fn cast_ray<P, M, S, G: ?Sized>(m:       &M,
-                                    shape:   &G,
-                                    simplex: &mut S,
-                                    ray:     &Ray<P>)
-                                    -> Option<(P::Real, P::Vector)>
+///
+/// If the maximum number of iterations is reached, the last lower bound of the time of impact is
+/// returned.
+pub fn cast_ray<P, M, S, G: ?Sized>(m:        &M,
+                                    shape:    &G,
+                                    simplex:  &mut S,
+                                    ray:      &Ray<P>,
+                                    settings: &QuerySettings<P::Real>)
+                                    -> (Option<(P::Real, P::Vector)>, GJKStatus)
     where P: Point,
           M: Isometry<P>,
           S: Simplex<P>,
           G: SupportMap<P, M> {
     let mut ltoi: P::Real = na::zero();
 
-    let _eps     = P::Real::default_epsilon();
-    let _eps_tol = _eps * na::convert(100.0f64);
+    let _eps_tol   = settings.contact_tolerance;
     let _dimension = na::dimension::<P::Vector>();
 
     // initialization
@@ -295,12 +339,9 @@ pub fn cast_ray<P, M, S, G: ?Sized>(m:       &M,
 
     let mut ldir = dir;
     // FIXME: this converges in more than 100 iterations… something is wrong here…
-    let mut niter = 0usize;
-    loop {
-        niter = niter + 1;
-
+    for _ in 0 .. settings.max_iterations {
         if dir.normalize_mut().is_zero() {
-            return Some((ltoi, ldir))
+            return (Some((ltoi, ldir)), GJKStatus::Converged)
         }
 
         let support_point = shape.support_point(m, &dir);
@@ -313,9 +354,20 @@ pub fn cast_ray<P, M, S, G: ?Sized>(m:       &M,
         //          < 0        |  > 0  | New lower bound, move the origin.
         //          > 0        |  < 0  | Miss. No intersection.
         //          > 0        |  > 0  | New higher bound.
-        match ray_internal::plane_toi_with_ray(&support_point, &dir, &curr_ray) {
+        let dir_dot_ray = na::dot(&dir, &ray.dir);
+
+        // A ray parallel to the support plane is neither clipped nor rejected by it. `dir` is unit
+        // but `ray.dir` may not be, so the threshold is scaled by its norm.
+        let toi = if dir_dot_ray.abs() > settings.parallel_eps * na::norm(&ray.dir) {
+            ray_internal::plane_toi_with_ray(&support_point, &dir, &curr_ray)
+        }
+        else {
+            Some(na::zero())
+        };
+
+        match toi {
             Some(t) => {
-                if na::dot(&dir, &ray.dir) < na::zero() && t > _eps_tol {
+                if dir_dot_ray < na::zero() && t > _eps_tol {
                     // new lower bound
                     ldir = dir;
                     ltoi = ltoi + t;
@@ -329,9 +381,9 @@ pub fn cast_ray<P, M, S, G: ?Sized>(m:       &M,
                 }
             },
             None => {
-                if na::dot(&dir, &ray.dir) > na::zero() {
+                if dir_dot_ray > na::zero() {
                     // miss
-                    return None
+                    return (None, GJKStatus::Converged)
                 }
             }
         }
@@ -342,19 +394,21 @@ pub fn cast_ray<P, M, S, G: ?Sized>(m:       &M,
         let max_bound = na::norm_squared(&proj);
 
         if simplex.dimension() == _dimension {
-            return Some((ltoi, ldir))
+            return (Some((ltoi, ldir)), GJKStatus::Converged)
         }
         else if max_bound <= _eps_tol * simplex.max_sq_len() {
             // Return ldir: the last projection plane is tangeant to the intersected surface.
-            return Some((ltoi, ldir))
+            return (Some((ltoi, ldir)), GJKStatus::Converged)
         }
         else if max_bound >= old_max_bound {
             // use dir instead of proj since this situations means that the new projection is less
             // accurate than the last one (which is stored on dir).
-            return Some((ltoi, dir))
+            return (Some((ltoi, dir)), GJKStatus::Converged)
         }
 
         old_max_bound = max_bound;
         dir           = -proj;
     }
+
+    (Some((ltoi, ldir)), GJKStatus::MaxIterationsReached)
 }
@@ -372,6 +372,11 @@ impl<P: Point> Simplex<P> for JohnsonSimplex<P> {
         self.points.len() - 1
     }
 
+    #[inline]
+    fn point(&self, i: usize) -> &P {
+        &self.points[i]
+    }
+
     #[inline]
     fn max_sq_len(&self) -> P::Real {
         let mut max_sq_len = na::zero();
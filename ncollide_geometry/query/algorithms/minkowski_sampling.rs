@@ -6,17 +6,37 @@ use alga::general::Id;
 use alga::linear::{Translation, NormedSpace};
 use na;
 
-use shape::{MinkowskiSum, AnnotatedPoint, Reflection};
+use shape::{self, MinkowskiSum, AnnotatedPoint, Reflection};
 use shape::SupportMap;
-use query::algorithms::gjk;
+use query::algorithms::gjk::{self, GJKStatus};
 use query::algorithms::simplex::Simplex;
+use query::QuerySettings;
 use math::{Point, Vector, Isometry};
 
 
 /// Computes the closest points between two implicit inter-penetrating shapes. Returns None if the
 /// shapes are not in penetration. This can be used as a fallback algorithm for the GJK algorithm.
+///
+/// The returned status is the one of the GJK run on the shifted shapes.
 pub fn closest_points<P, M, S, G1: ?Sized, G2: ?Sized>(
-                      m1: &M, g1: &G1, m2: &M, g2: &G2, simplex: &mut S) -> Option<(P, P, P::Vector)>
+                      m1: &M, g1: &G1, m2: &M, g2: &G2, simplex: &mut S) -> (Option<(P, P, P::Vector)>, GJKStatus)
+    where P:  Point,
+          M:  Isometry<P>,
+          S:  Simplex<AnnotatedPoint<P>>,
+          G1: SupportMap<P, M>,
+          G2: SupportMap<P, M> {
+    closest_points_with_settings(m1, g1, m2, g2, simplex, &QuerySettings::default())
+}
+
+/// Computes the closest points between two implicit inter-penetrating shapes, using the given
+/// tolerances.
+///
+/// The shapes are moved apart by their approximate penetration depth plus
+/// `settings.penetration_shift` before running the GJK algorithm with `settings`.
+pub fn closest_points_with_settings<P, M, S, G1: ?Sized, G2: ?Sized>(
+                                    m1: &M, g1: &G1, m2: &M, g2: &G2, simplex: &mut S,
+                                    settings: &QuerySettings<P::Real>)
+                                    -> (Option<(P, P, P::Vector)>, GJKStatus)
     where P:  Point,
           M:  Isometry<P>,
           S:  Simplex<AnnotatedPoint<P>>,
@@ -39,14 +59,25 @@ pub fn closest_points<P, M, S, G1: ?Sized, G2: ?Sized>(
         }
     });
 
-    let extra_shift = na::convert(0.01f64); // FIXME: do not hard-code the extra shift?
+    let extra_shift = settings.penetration_shift;
     let shift       = best_dir * (min_dist + extra_shift);
 
     let tm2 = m2.append_translation(&M::Translation::from_vector(shift).unwrap());
 
     simplex.modify_pnts(&|pt| pt.translate_2(&(-shift)));
 
-    match gjk::closest_points(m1, g1, &tm2, g2, simplex) {
+    let (mut res, mut status) = gjk::closest_points(m1, g1, &tm2, g2, simplex, settings);
+
+    if res.is_none() {
+        // The shifted simplex may be degenerate, e.g., when both shapes are coincident. Restart
+        // from a single point.
+        simplex.reset(shape::cso_support_point(m1, g1, &tm2, g2, -best_dir));
+        let (new_res, new_status) = gjk::closest_points(m1, g1, &tm2, g2, simplex, settings);
+        res    = new_res;
+        status = new_status;
+    }
+
+    let res = match res {
         None => None, // panic!("Internal error: the origin was inside of the Simplex during phase 1."),
         Some((p1, p2)) => {
             // NOTE: at this point, p1 must *not* be concidered as a good contact point for the
@@ -101,13 +132,28 @@ pub fn closest_points<P, M, S, G1: ?Sized, G2: ?Sized>(
                 None
             }
         }
-    }
+    };
+
+    (res, status)
 }
 
 /// Projects the origin on a support-mapped shape.
 ///
-/// The origin is assumed to be inside of the shape.
-pub fn project_origin<P, M, S, G>(m: &M, g: &G, simplex: &mut S) -> Option<P>
+/// The origin is assumed to be inside of the shape. The returned status is the one of the GJK run
+/// on the shifted shape.
+pub fn project_origin<P, M, S, G>(m: &M, g: &G, simplex: &mut S) -> (Option<P>, GJKStatus)
+    where P: Point,
+          M: Isometry<P>,
+          S: Simplex<P>,
+          G: SupportMap<P, M> {
+    project_origin_with_settings(m, g, simplex, &QuerySettings::default())
+}
+
+/// Projects the origin on a support-mapped shape, using the given tolerances.
+///
+/// See `closest_points_with_settings`.
+pub fn project_origin_with_settings<P, M, S, G>(m: &M, g: &G, simplex: &mut S, settings: &QuerySettings<P::Real>)
+                                                -> (Option<P>, GJKStatus)
     where P: Point,
           M: Isometry<P>,
           S: Simplex<P>,
@@ -126,14 +172,24 @@ pub fn project_origin<P, M, S, G>(m: &M, g: &G, simplex: &mut S) -> Option<P>
         }
     });
 
-    let extra_shift = na::convert(0.01f64); // FIXME: do not hard-code the extra shift?
+    let extra_shift = settings.penetration_shift;
     let shift       = best_dir * (min_dist + extra_shift);
 
     let tm = m.append_translation(&M::Translation::from_vector(-shift).unwrap());
 
     simplex.modify_pnts(&|pt| *pt = *pt + (-shift));
 
-    match gjk::project_origin(&tm, g, simplex) {
+    let (mut res, mut status) = gjk::project_origin(&tm, g, simplex, settings);
+
+    if res.is_none() {
+        // The shifted simplex may be degenerate. Restart from a single point.
+        simplex.reset(g.support_point(&tm, &-best_dir));
+        let (new_res, new_status) = gjk::project_origin(&tm, g, simplex, settings);
+        res    = new_res;
+        status = new_status;
+    }
+
+    let res = match res {
         None => None, // panic!("Internal error: the origin was inside of the Simplex during phase 1."),
         Some(p) => {
             let mut normal = -p.coordinates();
@@ -149,5 +205,7 @@ pub fn project_origin<P, M, S, G>(m: &M, g: &G, simplex: &mut S) -> Option<P>
                 None
             }
         }
-    }
+    };
+
+    (res, status)
 }
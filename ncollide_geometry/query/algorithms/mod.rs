@@ -1,5 +1,6 @@
 //! Algorithms needed for distance and penetration depth computation.
 pub mod simplex;
 pub mod johnson_simplex;
+pub mod voronoi_simplex;
 pub mod gjk;
 pub mod minkowski_sampling;
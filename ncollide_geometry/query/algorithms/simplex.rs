@@ -25,6 +25,9 @@ pub trait Simplex<P: Point> {
     /// Dimension of the simplex. A simplex with `n` must be a `n - 1`-dimensional simplex.
     fn dimension(&self) -> usize;
 
+    /// The i-th point of the simplex.
+    fn point(&self, i: usize) -> &P;
+
     /// The maximum among the simplex point squared lengths.
     fn max_sq_len(&self) -> P::Real;
 
@@ -0,0 +1,358 @@
+//! Simplex using the Voronoi regions of its features to compute the projection of the origin on
+//! the simplex.
+
+use num::Zero;
+use na;
+use utils;
+use query::algorithms::simplex::Simplex;
+use math::Point;
+
+/// Simplex using the Voronoi regions of its features to compute the projection of the origin on
+/// the simplex.
+///
+/// This is a simplex of at most four points usable in 2D and 3D only. Contrary to the
+/// `JohnsonSimplex`, it does not need any precomputed recursion template, and it keeps the
+/// barycentric coordinates of the last projection of the origin. Its points are added with
+/// `add_point`, the origin is projected with `project_origin`, and the points with a zero
+/// barycentric coordinate on this projection are removed with `reduce`. The methods of the
+/// `Simplex` trait are implemented too, allowing this simplex to be used by the GJK algorithm.
+///
+/// The Voronoi region containing the origin is selected with exact sign tests, without any
+/// tolerance. Thus, the points of a degenerate simplex, e.g., a triangle with aligned points,
+/// are not all given a meaningful barycentric coordinate: the projection is then the one on the
+/// closest non-degenerate sub-simplex, and the other points have a zero coordinate. For nearly
+/// degenerate simplices, the projection remains accurate but the barycentric coordinates may be
+/// affected by large rounding errors.
+#[derive(Clone)]
+pub struct VoronoiSimplex<P: Point> {
+    points: Vec<P>,
+    coords: Vec<P::Real>
+}
+
+impl<P: Point> VoronoiSimplex<P> {
+    /// Creates a new, empty, Voronoi simplex.
+    ///
+    /// Fails if the dimension of `P` is not 2 or 3.
+    pub fn new() -> VoronoiSimplex<P> {
+        let dim = na::dimension::<P::Vector>();
+        assert!(dim == 2 || dim == 3, "The Voronoi simplex is only implemented in 2D and 3D.");
+
+        VoronoiSimplex {
+            points: Vec::with_capacity(dim + 1),
+            coords: Vec::with_capacity(dim + 1)
+        }
+    }
+
+    /// The number of points of this simplex.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Whether this simplex has no point.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// The points of this simplex.
+    #[inline]
+    pub fn points(&self) -> &[P] {
+        &self.points[..]
+    }
+
+    /// The barycentric coordinates of the last projection of the origin.
+    ///
+    /// The i-th coordinate is associated to the i-th point of this simplex. This is empty if no
+    /// projection was computed since the last modification of the simplex.
+    #[inline]
+    pub fn barycentric_coordinates(&self) -> &[P::Real] {
+        &self.coords[..]
+    }
+
+    /// Adds a point to this simplex.
+    ///
+    /// Fails if the simplex already has `dimension + 1` points.
+    #[inline]
+    pub fn add_point(&mut self, pt: P) {
+        assert!(self.points.len() <= na::dimension::<P::Vector>(), "The Voronoi simplex is already full.");
+        self.points.push(pt);
+        self.coords.clear();
+    }
+
+    /// Projects the origin on this simplex and records the barycentric coordinates of the
+    /// projection.
+    ///
+    /// The simplex itself is unchanged. Fails if the simplex is empty.
+    pub fn project_origin(&mut self) -> P {
+        self.coords = match self.points.len() {
+            0 => panic!("Cannot project the origin on an empty simplex."),
+            1 => vec![ na::one() ],
+            2 => segment_coords(&self.points[0], &self.points[1]).to_vec(),
+            3 => triangle_coords(&self.points[0], &self.points[1], &self.points[2]).to_vec(),
+            _ => tetrahedron_coords(&self.points[0], &self.points[1], &self.points[2], &self.points[3]).to_vec()
+        };
+
+        let mut proj = P::origin();
+
+        for (pt, coord) in self.points.iter().zip(self.coords.iter()) {
+            proj.axpy(*coord, pt);
+        }
+
+        proj
+    }
+
+    /// Removes the points with a zero barycentric coordinate on the last projection of the origin.
+    ///
+    /// The remaining points form the smallest sub-simplex containing this projection. Does nothing
+    /// if no projection was computed since the last modification of the simplex.
+    pub fn reduce(&mut self) {
+        if self.coords.len() != self.points.len() {
+            return;
+        }
+
+        let mut i = 0;
+
+        while i < self.points.len() {
+            if self.coords[i].is_zero() {
+                let _ = self.points.remove(i);
+                let _ = self.coords.remove(i);
+            }
+            else {
+                i = i + 1;
+            }
+        }
+    }
+}
+
+impl<P: Point> Simplex<P> for VoronoiSimplex<P> {
+    #[inline]
+    fn reset(&mut self, pt: P) {
+        self.points.clear();
+        self.coords.clear();
+        self.points.push(pt);
+    }
+
+    #[inline]
+    fn add_point(&mut self, pt: P) {
+        VoronoiSimplex::add_point(self, pt)
+    }
+
+    #[inline]
+    fn project_origin_and_reduce(&mut self) -> P {
+        let res = VoronoiSimplex::project_origin(self);
+        self.reduce();
+
+        res
+    }
+
+    #[inline]
+    fn project_origin(&mut self) -> P {
+        VoronoiSimplex::project_origin(self)
+    }
+
+    #[inline]
+    fn contains_point(&self, pt: &P) -> bool {
+        self.points.iter().any(|v| pt == v)
+    }
+
+    #[inline]
+    fn dimension(&self) -> usize {
+        self.points.len() - 1
+    }
+
+    #[inline]
+    fn point(&self, i: usize) -> &P {
+        &self.points[i]
+    }
+
+    #[inline]
+    fn max_sq_len(&self) -> P::Real {
+        let mut max_sq_len = na::zero();
+
+        for p in self.points.iter() {
+            let norm = na::norm_squared(&p.coordinates());
+
+            if norm > max_sq_len {
+                max_sq_len = norm
+            }
+        }
+
+        max_sq_len
+    }
+
+    #[inline]
+    fn modify_pnts(&mut self, f: &Fn(&mut P)) {
+        for pt in self.points.iter_mut() {
+            f(pt)
+        }
+    }
+}
+
+// The barycentric coordinates of the projection of the origin on the segment `[a, b]`.
+fn segment_coords<P: Point>(a: &P, b: &P) -> [P::Real; 2] {
+    let _0: P::Real = na::zero();
+    let _1: P::Real = na::one();
+    let ab = *b - *a;
+    let d1 = -na::dot(&ab, &a.coordinates());
+    let d2 = na::dot(&ab, &b.coordinates());
+
+    if d1 <= _0 {
+        [ _1, _0 ]
+    }
+    else if d2 <= _0 {
+        [ _0, _1 ]
+    }
+    else {
+        let t = d1 / (d1 + d2);
+        [ _1 - t, t ]
+    }
+}
+
+// The barycentric coordinates of the projection of the origin on the triangle `abc`.
+//
+// This only uses dot products and thus works in any dimension.
+fn triangle_coords<P: Point>(a: &P, b: &P, c: &P) -> [P::Real; 3] {
+    let _0: P::Real = na::zero();
+    let _1: P::Real = na::one();
+
+    let ab = *b - *a;
+    let ac = *c - *a;
+
+    // Vertex `a`.
+    let d1 = -na::dot(&ab, &a.coordinates());
+    let d2 = -na::dot(&ac, &a.coordinates());
+
+    if d1 <= _0 && d2 <= _0 {
+        return [ _1, _0, _0 ]
+    }
+
+    // Vertex `b`.
+    let d3 = -na::dot(&ab, &b.coordinates());
+    let d4 = -na::dot(&ac, &b.coordinates());
+
+    if d3 >= _0 && d4 <= d3 {
+        return [ _0, _1, _0 ]
+    }
+
+    // Vertex `c`.
+    let d5 = -na::dot(&ab, &c.coordinates());
+    let d6 = -na::dot(&ac, &c.coordinates());
+
+    if d6 >= _0 && d5 <= d6 {
+        return [ _0, _0, _1 ]
+    }
+
+    // Edge `ab`.
+    let vc = d1 * d4 - d3 * d2;
+
+    if vc <= _0 && d1 >= _0 && d3 <= _0 {
+        let uv = segment_coords(a, b);
+        return [ uv[0], uv[1], _0 ]
+    }
+
+    // Edge `ac`.
+    let vb = d5 * d2 - d1 * d6;
+
+    if vb <= _0 && d2 >= _0 && d6 <= _0 {
+        let uw = segment_coords(a, c);
+        return [ uw[0], _0, uw[1] ]
+    }
+
+    // Edge `bc`.
+    let va = d3 * d6 - d5 * d4;
+
+    if va <= _0 && d4 - d3 >= _0 && d5 - d6 >= _0 {
+        let vw = segment_coords(b, c);
+        return [ _0, vw[0], vw[1] ]
+    }
+
+    let total = va + vb + vc;
+
+    if total <= _0 {
+        // Degenerate triangle: project on the closest edge.
+        let edges = [ (0, 1), (0, 2), (1, 2) ];
+        let pts   = [ a, b, c ];
+        let mut best = [ _1, _0, _0 ];
+        let mut best_sqdist = na::norm_squared(&a.coordinates());
+
+        for &(i, j) in edges.iter() {
+            let uv     = segment_coords(pts[i], pts[j]);
+            let proj   = pts[i].coordinates() * uv[0] + pts[j].coordinates() * uv[1];
+            let sqdist = na::norm_squared(&proj);
+
+            if sqdist < best_sqdist {
+                best        = [ _0, _0, _0 ];
+                best[i]     = uv[0];
+                best[j]     = uv[1];
+                best_sqdist = sqdist;
+            }
+        }
+
+        return best;
+    }
+
+    let v = vb / total;
+    let w = vc / total;
+
+    [ _1 - v - w, v, w ]
+}
+
+// The barycentric coordinates of the projection of the origin on the tetrahedron `abcd`.
+//
+// This uses cross products and thus works in 3D only.
+fn tetrahedron_coords<P: Point>(a: &P, b: &P, c: &P, d: &P) -> [P::Real; 4] {
+    let _0: P::Real = na::zero();
+    let pts = [ a, b, c, d ];
+    // Each face, with the index of the opposite vertex.
+    let faces = [ ([ 0, 1, 2 ], 3), ([ 0, 1, 3 ], 2), ([ 0, 2, 3 ], 1), ([ 1, 2, 3 ], 0) ];
+
+    let ab  = *b - *a;
+    let ac  = *c - *a;
+    let ad  = *d - *a;
+    let vol = na::dot(&utils::cross3(&ab, &ac), &ad);
+
+    let mut best        = None;
+    let mut best_sqdist = _0;
+
+    for &(face, opposite) in faces.iter() {
+        let p0 = pts[face[0]];
+        let p1 = pts[face[1]];
+        let p2 = pts[face[2]];
+        let n  = utils::cross3(&(*p1 - *p0), &(*p2 - *p0));
+
+        // The origin lies outside of this face iff it is not on the side of the opposite vertex.
+        // Every face is tested if the tetrahedron is degenerate.
+        let origin_side   = -na::dot(&n, &p0.coordinates());
+        let opposite_side = na::dot(&n, &(*pts[opposite] - *p0));
+
+        if vol.is_zero() || origin_side * opposite_side < _0 {
+            let uvw    = triangle_coords(p0, p1, p2);
+            let proj   = p0.coordinates() * uvw[0] + p1.coordinates() * uvw[1] + p2.coordinates() * uvw[2];
+            let sqdist = na::norm_squared(&proj);
+
+            if best.is_none() || sqdist < best_sqdist {
+                let mut coords = [ _0, _0, _0, _0 ];
+                coords[face[0]] = uvw[0];
+                coords[face[1]] = uvw[1];
+                coords[face[2]] = uvw[2];
+
+                best        = Some(coords);
+                best_sqdist = sqdist;
+            }
+        }
+    }
+
+    match best {
+        Some(coords) => coords,
+        None         => {
+            // The origin is inside of the tetrahedron.
+            let ao = -a.coordinates();
+            let vb = na::dot(&utils::cross3(&ao, &ac), &ad) / vol;
+            let vc = na::dot(&utils::cross3(&ab, &ao), &ad) / vol;
+            let vd = na::dot(&utils::cross3(&ab, &ac), &ao) / vol;
+
+            [ na::one::<P::Real>() - vb - vc - vd, vb, vc, vd ]
+        }
+    }
+}
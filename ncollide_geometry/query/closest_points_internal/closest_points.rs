@@ -0,0 +1,28 @@
+use shape::FeatureId;
+
+/// Closest points information.
+#[derive(Debug, PartialEq, Clone, RustcEncodable, RustcDecodable)]
+pub enum ClosestPoints<P> {
+    /// The two objects are intersecting.
+    Intersecting,
+    /// The two objects are non-intersecting but closer than a given distance.
+    ///
+    /// The points are the closest points on the first and the second object. The optional pair
+    /// of features identifies, on each object, the witness feature containing its closest point.
+    /// It can be used to seed a contact manifold without searching the shapes features again.
+    WithinMargin(P, P, Option<(FeatureId, FeatureId)>),
+    /// The two objects are non-intersecting and further than a given distance.
+    Disjoint
+}
+
+impl<P> ClosestPoints<P> {
+    /// Swaps the roles of the first and the second object.
+    #[inline]
+    pub fn flip(self) -> ClosestPoints<P> {
+        match self {
+            ClosestPoints::WithinMargin(p1, p2, features) =>
+                ClosestPoints::WithinMargin(p2, p1, features.map(|(f1, f2)| (f2, f1))),
+            other => other
+        }
+    }
+}
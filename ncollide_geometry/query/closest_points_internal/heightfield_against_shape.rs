@@ -0,0 +1,52 @@
+use na;
+use bounding_volume::BoundingVolume;
+use shape::{Shape, HeightField};
+use query::ClosestPoints;
+use query::closest_points_internal;
+use math::{Point, Isometry};
+
+/// Closest points between a heightfield and any other shape.
+///
+/// Only the triangles of the cells intersecting the AABB of `g2`, loosened by `margin`, are
+/// tested. The witness features of the heightfield follow the convention of its point projection,
+/// see `HeightField::triangle_feature_id`.
+pub fn heightfield_against_shape<P, M>(m1: &M, g1: &HeightField<P::Real>,
+                                       m2: &M, g2: &Shape<P, M>,
+                                       margin: P::Real)
+                                       -> ClosestPoints<P>
+    where P: Point,
+          M: Isometry<P> {
+    assert!(margin >= na::zero(), "The closest points margin must be positive or null.");
+
+    let ls_m2    = na::inverse(m1) * m2.clone();
+    let ls_aabb2 = g2.aabb(&ls_m2).loosened(margin);
+
+    let best = g1.closest_triangle_in_local_aabb(&ls_aabb2, |_, triangle| {
+        match closest_points_internal::closest_points_internal(m1, triangle, m2, g2, margin) {
+            ClosestPoints::Disjoint                       => None,
+            ClosestPoints::Intersecting                   => Some((na::zero(), ClosestPoints::Intersecting)),
+            ClosestPoints::WithinMargin(p1, p2, features) => {
+                Some((na::distance(&p1, &p2), ClosestPoints::WithinMargin(p1, p2, features)))
+            }
+        }
+    });
+
+    match best {
+        Some((id, _, ClosestPoints::WithinMargin(p1, p2, features))) => {
+            let features = features.map(|(f1, f2)| (g1.triangle_feature_id(id, f1), f2));
+            ClosestPoints::WithinMargin(p1, p2, features)
+        },
+        Some((_, _, res)) => res,
+        None              => ClosestPoints::Disjoint
+    }
+}
+
+/// Closest points between a shape and a heightfield.
+pub fn shape_against_heightfield<P, M>(m1: &M, g1: &Shape<P, M>,
+                                       m2: &M, g2: &HeightField<P::Real>,
+                                       margin: P::Real)
+                                       -> ClosestPoints<P>
+    where P: Point,
+          M: Isometry<P> {
+    heightfield_against_shape(m2, g2, m1, g1, margin).flip()
+}
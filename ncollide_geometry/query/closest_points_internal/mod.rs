@@ -0,0 +1,20 @@
+//! Implementation details of the `closest_points` function.
+
+pub use self::closest_points::ClosestPoints;
+pub use self::ball_against_ball::ball_against_ball;
+pub use self::segment_against_segment::segment_segment_closest;
+pub use self::support_map_against_support_map::support_map_against_support_map;
+pub use self::support_map_against_support_map::support_map_against_support_map_with_params;
+pub use self::plane_against_support_map::{plane_against_support_map, support_map_against_plane};
+pub use self::shape_against_shape::shape_against_shape as closest_points_internal;
+pub use self::composite_shape_against_shape::{composite_shape_against_shape, shape_against_composite_shape};
+pub use self::heightfield_against_shape::{heightfield_against_shape, shape_against_heightfield};
+
+mod closest_points;
+mod ball_against_ball;
+mod segment_against_segment;
+mod support_map_against_support_map;
+mod plane_against_support_map;
+mod shape_against_shape;
+mod composite_shape_against_shape;
+mod heightfield_against_shape;
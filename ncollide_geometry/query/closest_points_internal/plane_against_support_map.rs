@@ -0,0 +1,51 @@
+use alga::linear::Translation;
+use na;
+
+use query::ClosestPoints;
+use shape::{SupportMap, Plane, FeatureId};
+use math::{Point, Isometry};
+
+/// Closest points between a plane and a support-mapped shape (Cuboid, ConvexHull, etc.)
+///
+/// The witness feature of the plane is `FeatureId::Face(0)`.
+pub fn plane_against_support_map<P, M, G: ?Sized>(mplane: &M, plane: &Plane<P::Vector>,
+                                                  mother: &M, other: &G,
+                                                  margin: P::Real)
+                                                  -> ClosestPoints<P>
+    where P: Point,
+          M: Isometry<P>,
+          G: SupportMap<P, M> {
+    assert!(margin >= na::zero(), "The closest points margin must be positive or null.");
+
+    let plane_normal = mplane.rotate_vector(plane.normal());
+    let plane_center = P::from_coordinates(mplane.translation().to_vector());
+    let deepest      = other.support_point(mother, &-plane_normal);
+
+    let distance = na::dot(&plane_normal, &(plane_center - deepest));
+
+    if distance >= -margin {
+        if distance >= na::zero() {
+            ClosestPoints::Intersecting
+        }
+        else {
+            let feature = other.support_feature_id(mother, &[ deepest ]);
+
+            ClosestPoints::WithinMargin(deepest + plane_normal * distance, deepest,
+                                        Some((FeatureId::Face(0), feature)))
+        }
+    }
+    else {
+        ClosestPoints::Disjoint
+    }
+}
+
+/// Closest points between a support-mapped shape (Cuboid, ConvexHull, etc.) and a plane.
+pub fn support_map_against_plane<P, M, G: ?Sized>(mother: &M, other: &G,
+                                                  mplane: &M, plane: &Plane<P::Vector>,
+                                                  margin: P::Real)
+                                                  -> ClosestPoints<P>
+    where P: Point,
+          M: Isometry<P>,
+          G: SupportMap<P, M> {
+    plane_against_support_map(mplane, plane, mother, other, margin).flip()
+}
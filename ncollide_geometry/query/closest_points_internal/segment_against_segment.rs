@@ -0,0 +1,75 @@
+use approx::ApproxEq;
+use alga::general::Real;
+use na;
+use math::Point;
+
+/// Closest points between the segments `[a1, a2]` and `[b1, b2]`.
+///
+/// The first point lies on the first segment and the second point on the second segment. If the
+/// segments are parallel and overlap, they have infinitely many pairs of closest points and the
+/// one closest to `a1` is returned. A segment with a zero length is handled as a single point.
+pub fn segment_segment_closest<P: Point>(a1: &P, a2: &P, b1: &P, b2: &P) -> (P, P) {
+    let _0: P::Real = na::zero();
+    let _1: P::Real = na::one();
+    let eps         = P::Real::default_epsilon();
+
+    let da = *a2 - *a1;
+    let db = *b2 - *b1;
+    let r  = *a1 - *b1;
+
+    let a = na::norm_squared(&da);
+    let e = na::norm_squared(&db);
+    let f = na::dot(&db, &r);
+
+    let s;
+    let t;
+
+    if a <= eps && e <= eps {
+        // Both segments are points.
+        s = _0;
+        t = _0;
+    }
+    else if a <= eps {
+        // The first segment is a point.
+        s = _0;
+        t = clamp(f / e, _0, _1);
+    }
+    else {
+        let c = na::dot(&da, &r);
+
+        if e <= eps {
+            // The second segment is a point.
+            t = _0;
+            s = clamp(-c / a, _0, _1);
+        }
+        else {
+            let b     = na::dot(&da, &db);
+            let denom = a * e - b * b;
+
+            // Closest point to the second segment's line, or `a1` if the segments are parallel.
+            let s_line = if denom > eps * a * e { clamp((b * f - c * e) / denom, _0, _1) } else { _0 };
+            let t_line = (b * s_line + f) / e;
+
+            // Clamp to the second segment and move the first point accordingly.
+            if t_line < _0 {
+                t = _0;
+                s = clamp(-c / a, _0, _1);
+            }
+            else if t_line > _1 {
+                t = _1;
+                s = clamp((b - c) / a, _0, _1);
+            }
+            else {
+                t = t_line;
+                s = s_line;
+            }
+        }
+    }
+
+    (*a1 + da * s, *b1 + db * t)
+}
+
+#[inline]
+fn clamp<N: Real>(x: N, min: N, max: N) -> N {
+    x.max(min).min(max)
+}
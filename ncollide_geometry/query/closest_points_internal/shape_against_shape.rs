@@ -0,0 +1,67 @@
+use alga::linear::Translation;
+use na;
+use math::{Point, Isometry};
+use shape::{Shape, Ball, Plane, HeightField};
+use query::{ClosestPoints, QuerySettings};
+use query::algorithms::johnson_simplex::JohnsonSimplex;
+use query::closest_points_internal;
+
+/// Computes the pair of closest points between two shapes.
+///
+/// Returns `ClosestPoints::Disjoint` if the objects are separated by a distance greater than
+/// `margin`. When available, the witness features of both shapes are reported as well. The
+/// bounding spheres of two support-mapped shapes are used to skip the GJK algorithm if they are
+/// separated by more than `margin`, and to warm-start it if they are far apart.
+pub fn shape_against_shape<P, M>(m1: &M, g1: &Shape<P, M>,
+                                 m2: &M, g2: &Shape<P, M>,
+                                 margin: P::Real)
+                                 -> ClosestPoints<P>
+    where P: Point,
+          M: Isometry<P> {
+    if let (Some(b1), Some(b2)) = (g1.as_shape::<Ball<P::Real>>(),
+                                   g2.as_shape::<Ball<P::Real>>()) {
+        let p1 = P::from_coordinates(m1.translation().to_vector());
+        let p2 = P::from_coordinates(m2.translation().to_vector());
+
+        closest_points_internal::ball_against_ball(&p1, b1, &p2, b2, margin)
+    }
+    else if let (Some(p1), Some(s2)) = (g1.as_shape::<Plane<P::Vector>>(), g2.as_support_map()) {
+        closest_points_internal::plane_against_support_map(m1, p1, m2, s2, margin)
+    }
+    else if let (Some(s1), Some(p2)) = (g1.as_support_map(), g2.as_shape::<Plane<P::Vector>>()) {
+        closest_points_internal::support_map_against_plane(m1, s1, m2, p2, margin)
+    }
+    else if let (Some(s1), Some(s2)) = (g1.as_support_map(), g2.as_support_map()) {
+        // Shapes far apart relative to their sizes are either beyond the margin, or have their
+        // closest points roughly along the line joining their bounding sphere centers.
+        let bs1   = g1.bounding_sphere(m1);
+        let bs2   = g2.bounding_sphere(m2);
+        let delta = *bs1.center() - *bs2.center();
+        let gap   = na::norm(&delta) - bs1.radius() - bs2.radius();
+
+        if gap > margin {
+            ClosestPoints::Disjoint
+        }
+        else {
+            let init_dir = if gap > bs1.radius() + bs2.radius() { Some(delta) } else { None };
+
+            closest_points_internal::support_map_against_support_map_with_params(
+                m1, s1, m2, s2, margin, &mut JohnsonSimplex::new_w_tls(), init_dir, &QuerySettings::default())
+        }
+    }
+    else if let Some(h1) = g1.as_shape::<HeightField<P::Real>>() {
+        closest_points_internal::heightfield_against_shape(m1, h1, m2, g2, margin)
+    }
+    else if let Some(h2) = g2.as_shape::<HeightField<P::Real>>() {
+        closest_points_internal::shape_against_heightfield(m1, g1, m2, h2, margin)
+    }
+    else if let Some(c1) = g1.as_composite_shape() {
+        closest_points_internal::composite_shape_against_shape(m1, c1, m2, g2, margin)
+    }
+    else if let Some(c2) = g2.as_composite_shape() {
+        closest_points_internal::shape_against_composite_shape(m1, g1, m2, c2, margin)
+    }
+    else {
+        panic!("No algorithm known to compute the closest points between the given pair of shapes.")
+    }
+}
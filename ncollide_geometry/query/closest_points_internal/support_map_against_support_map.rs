@@ -0,0 +1,89 @@
+use alga::linear::Translation;
+use na;
+
+use shape::{self, SupportMap, AnnotatedPoint};
+use query::algorithms::gjk::{self, GJKResult};
+use query::algorithms::simplex::Simplex;
+use query::algorithms::johnson_simplex::JohnsonSimplex;
+use query::{ClosestPoints, QuerySettings};
+use math::{Point, Isometry};
+
+
+/// Closest points between support-mapped shapes (`Cuboid`, `ConvexHull`, etc.)
+pub fn support_map_against_support_map<P, M, G1: ?Sized, G2: ?Sized>(
+                                       m1:     &M,
+                                       g1:     &G1,
+                                       m2:     &M,
+                                       g2:     &G2,
+                                       margin: P::Real)
+                                       -> ClosestPoints<P>
+    where P:  Point,
+          M:  Isometry<P>,
+          G1: SupportMap<P, M>,
+          G2: SupportMap<P, M> {
+    support_map_against_support_map_with_params(m1, g1, m2, g2, margin, &mut JohnsonSimplex::new_w_tls(), None,
+                                                &QuerySettings::default())
+}
+
+/// Closest points between support-mapped shapes (`Cuboid`, `ConvexHull`, etc.)
+///
+/// This allows a more fine grained control other the underlying GJK algorigtm. The witness
+/// features are identified from the points of the terminal GJK simplex.
+pub fn support_map_against_support_map_with_params<P, M, S, G1: ?Sized, G2: ?Sized>(
+                                                   m1:       &M,
+                                                   g1:       &G1,
+                                                   m2:       &M,
+                                                   g2:       &G2,
+                                                   margin:   P::Real,
+                                                   simplex:  &mut S,
+                                                   init_dir: Option<P::Vector>,
+                                                   settings: &QuerySettings<P::Real>)
+                                                   -> ClosestPoints<P>
+    where P:  Point,
+          M:  Isometry<P>,
+          S:  Simplex<AnnotatedPoint<P>>,
+          G1: SupportMap<P, M>,
+          G2: SupportMap<P, M> {
+    assert!(margin >= na::zero(), "The closest points margin must be positive or null.");
+
+    let mut dir =
+        match init_dir {
+            // The CSO `g1 - g2` is centered around this point.
+            None      => m1.translation().to_vector() - m2.translation().to_vector(),
+            Some(dir) => dir
+        };
+
+    if dir == na::zero() {
+        dir[0] = na::one();
+    }
+
+    simplex.reset(shape::cso_support_point(m1, g1, m2, g2, dir));
+
+    match gjk::closest_points_with_max_dist(m1, g1, m2, g2, margin, simplex, settings).0 {
+        GJKResult::Projection((p1, p2)) => {
+            // The points of the terminal simplex span the witness features.
+            let mut pts1 = Vec::new();
+            let mut pts2 = Vec::new();
+
+            for i in 0 .. simplex.dimension() + 1 {
+                let pt1 = *simplex.point(i).orig1();
+                let pt2 = -*simplex.point(i).orig2();
+
+                if !pts1.contains(&pt1) {
+                    pts1.push(pt1);
+                }
+
+                if !pts2.contains(&pt2) {
+                    pts2.push(pt2);
+                }
+            }
+
+            let features = (g1.support_feature_id(m1, &pts1[..]), g2.support_feature_id(m2, &pts2[..]));
+
+            ClosestPoints::WithinMargin(p1, p2, Some(features))
+        },
+        GJKResult::NoIntersection(_) => ClosestPoints::Disjoint,
+        GJKResult::Intersection      => ClosestPoints::Intersecting,
+        GJKResult::Proximity(_)      => unreachable!()
+    }
+}
@@ -0,0 +1,192 @@
+//! Contact manifolds persistent across successive collision detection steps.
+
+use std::mem;
+
+use na;
+use query::Contact;
+use shape::FeatureId;
+use math::Point;
+
+/// A contact of a persistent manifold, together with the features it lies on.
+#[derive(PartialEq, Clone)]
+pub struct TrackedContact<P: Point, D> {
+    /// The geometric description of the contact.
+    pub contact: Contact<P>,
+    /// The features of the first and second shape the contact lies on.
+    pub features: (FeatureId, FeatureId),
+    /// Number of manifold updates this contact survived to. This is zero for a new contact.
+    pub age: usize,
+    /// User-defined data carried over as long as this contact is matched, e.g., an accumulated
+    /// impulse for warm-starting.
+    pub data: D
+}
+
+/// The features compared to match the contacts of a persistent manifold.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FeatureMatching {
+    /// The contacts must lie on the same features of both shapes.
+    Both,
+    /// The contacts must lie on the same feature of the first shape.
+    First,
+    /// The contacts must lie on the same feature of the second shape.
+    ///
+    /// This is useful to keep matching the contacts between a composite shape and a convex shape
+    /// moving from one part of the composite shape to another, e.g., a box sliding across the
+    /// edge shared by two triangles of a mesh.
+    Second
+}
+
+impl FeatureMatching {
+    /// Whether two contacts lying on the given pairs of features may match.
+    ///
+    /// Contacts with a compared feature equal to `FeatureId::Unknown` never match.
+    #[inline]
+    pub fn matches(&self, features1: &(FeatureId, FeatureId), features2: &(FeatureId, FeatureId)) -> bool {
+        let first  = features1.0 != FeatureId::Unknown && features1.0 == features2.0;
+        let second = features1.1 != FeatureId::Unknown && features1.1 == features2.1;
+
+        match *self {
+            FeatureMatching::Both   => first && second,
+            FeatureMatching::First  => first,
+            FeatureMatching::Second => second
+        }
+    }
+}
+
+/// A set of contacts matched against those of the previous collision detection step.
+///
+/// Two contacts match if they lie on the same pair of features and their contact points on the
+/// first shape are closer than the matching distance threshold. A matched contact keeps the user
+/// data of the contact it replaces while unmatched contacts of the previous step are discarded.
+/// The manifold may be configured to compare the features of only one of the shapes.
+#[derive(PartialEq, Clone)]
+pub struct PersistentManifold<P: Point, D> {
+    threshold: P::Real,
+    matching:  FeatureMatching,
+    contacts:  Vec<TrackedContact<P, D>>,
+    buffer:    Vec<TrackedContact<P, D>>
+}
+
+impl<P: Point, D: Default> PersistentManifold<P, D> {
+    /// Creates a new empty manifold.
+    ///
+    /// The matching distance `threshold` must be positive.
+    pub fn new(threshold: P::Real) -> PersistentManifold<P, D> {
+        PersistentManifold::new_with_matching(threshold, FeatureMatching::Both)
+    }
+
+    /// Creates a new empty manifold comparing the features selected by `matching`.
+    ///
+    /// The matching distance `threshold` must be positive.
+    pub fn new_with_matching(threshold: P::Real, matching: FeatureMatching) -> PersistentManifold<P, D> {
+        assert!(threshold >= na::zero(), "The matching distance threshold must be positive.");
+
+        PersistentManifold {
+            threshold: threshold,
+            matching:  matching,
+            contacts:  Vec::new(),
+            buffer:    Vec::new()
+        }
+    }
+
+    /// The distance under which two contacts on the same features are matched.
+    #[inline]
+    pub fn threshold(&self) -> P::Real {
+        self.threshold
+    }
+
+    /// The features compared to match the contacts of this manifold.
+    #[inline]
+    pub fn matching(&self) -> FeatureMatching {
+        self.matching
+    }
+
+    /// The contacts of this manifold.
+    #[inline]
+    pub fn contacts(&self) -> &[TrackedContact<P, D>] {
+        &self.contacts[..]
+    }
+
+    /// Mutable reference to the contacts of this manifold, e.g., to modify their user data.
+    #[inline]
+    pub fn contacts_mut(&mut self) -> &mut [TrackedContact<P, D>] {
+        &mut self.contacts[..]
+    }
+
+    /// The number of contacts of this manifold.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.contacts.len()
+    }
+
+    /// Whether this manifold contains no contact.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.contacts.is_empty()
+    }
+
+    /// Removes all the contacts of this manifold.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.contacts.clear()
+    }
+
+    /// Replaces the contacts of this manifold by `new_contacts`, each with the pair of features it
+    /// lies on.
+    ///
+    /// Each new contact is matched with the closest unmatched previous contact on the same
+    /// features, if the distance between their contact points on the first shape is smaller than
+    /// the threshold. A matched contact inherits the previous contact user data and age. Otherwise,
+    /// its user data is initialized to `D::default()`. Contacts with `FeatureId::Unknown`
+    /// compared features are never matched. Returns the number of matched contacts.
+    pub fn update(&mut self, new_contacts: &[(Contact<P>, (FeatureId, FeatureId))]) -> usize {
+        let sq_threshold = self.threshold * self.threshold;
+        let mut num_matched = 0;
+
+        mem::swap(&mut self.contacts, &mut self.buffer);
+        self.contacts.clear();
+
+        for &(ref contact, features) in new_contacts.iter() {
+            let mut best        = None;
+            let mut best_sqdist = sq_threshold;
+
+            for (i, old) in self.buffer.iter().enumerate() {
+                if self.matching.matches(&features, &old.features) {
+                    let sqdist = na::distance_squared(&old.contact.world1, &contact.world1);
+
+                    if sqdist <= best_sqdist {
+                        best_sqdist = sqdist;
+                        best        = Some(i);
+                    }
+                }
+            }
+
+            match best {
+                Some(i) => {
+                    let old = self.buffer.swap_remove(i);
+
+                    num_matched = num_matched + 1;
+                    self.contacts.push(TrackedContact {
+                        contact:  contact.clone(),
+                        features: features,
+                        age:      old.age + 1,
+                        data:     old.data
+                    })
+                },
+                None => {
+                    self.contacts.push(TrackedContact {
+                        contact:  contact.clone(),
+                        features: features,
+                        age:      0,
+                        data:     D::default()
+                    })
+                }
+            }
+        }
+
+        // Discard the stale contacts.
+        self.buffer.clear();
+
+        num_matched
+    }
+}
@@ -2,7 +2,7 @@ use num::Zero;
 
 use alga::general::Real;
 use alga::linear::FiniteDimVectorSpace;
-use na;
+use na::{self, Unit};
 use math::Point;
 use query::Contact;
 use shape::Ball;
@@ -31,7 +31,7 @@ pub fn ball_against_ball<P>(center1: &P, b1: &Ball<P::Real>,
         Some(Contact::new(
                 *center1 + normal * r1,
                 *center2 + (-normal * r2),
-                normal,
+                Unit::new_unchecked(normal),
                 (sum_radius - distance_squared.sqrt())))
     }
     else {
@@ -0,0 +1,54 @@
+use num::Zero;
+
+use approx::ApproxEq;
+use na::{self, Unit};
+use query::{self, Contact};
+use shape::{Ball, Capsule};
+use math::{Point, Isometry};
+
+/// Contact between a ball and a capsule.
+///
+/// The contact is computed from the closest point of the capsule core segment to the ball center.
+/// If the ball center lies on this segment, the ball is pushed out along the local `x` axis of the
+/// capsule.
+pub fn ball_against_capsule<P, M>(center: &P, ball: &Ball<P::Real>,
+                                  mcapsule: &M, capsule: &Capsule<P::Real>,
+                                  prediction: P::Real)
+                                  -> Option<Contact<P>>
+    where P: Point,
+          M: Isometry<P> {
+    let (a, b)     = capsule.segment::<P>();
+    let (a, b)     = (mcapsule.transform_point(&a), mcapsule.transform_point(&b));
+    let (q, _)     = query::segment_segment_closest(&a, &b, center, center);
+    let sum_radius = ball.radius() + capsule.radius();
+
+    let (normal, dist) = match Unit::try_new_and_get(q - *center, P::Real::default_epsilon()) {
+        Some(res) => res,
+        None      => {
+            let mut x = P::Vector::zero();
+            x[0] = na::one();
+
+            (Unit::new_normalize(mcapsule.rotate_vector(&x)), na::zero())
+        }
+    };
+
+    if sum_radius - dist < -prediction {
+        return None;
+    }
+
+    let c1 = *center + *normal * ball.radius();
+    let c2 = q + *normal * -capsule.radius();
+
+    Some(Contact::new(c1, c2, normal, sum_radius - dist))
+}
+
+/// Contact between a capsule and a ball.
+#[inline]
+pub fn capsule_against_ball<P, M>(mcapsule: &M, capsule: &Capsule<P::Real>,
+                                  center: &P, ball: &Ball<P::Real>,
+                                  prediction: P::Real)
+                                  -> Option<Contact<P>>
+    where P: Point,
+          M: Isometry<P> {
+    ball_against_capsule(center, ball, mcapsule, capsule, prediction).map(|mut c| { c.flip(); c })
+}
@@ -0,0 +1,76 @@
+use alga::general::Real;
+use na::{self, Unit};
+use query::Contact;
+use shape::{Ball, Cuboid};
+use math::{Point, Isometry};
+
+/// Contact between a ball and a cuboid.
+///
+/// The closest point of the cuboid to the ball center is obtained by clamping this center to the
+/// cuboid. If the center lies inside of the cuboid, or exactly on its boundary, the ball is pushed
+/// out of the cuboid through the face closest to it.
+pub fn ball_against_cuboid<P, M>(center: &P, ball: &Ball<P::Real>,
+                                 mcuboid: &M, cuboid: &Cuboid<P::Vector>,
+                                 prediction: P::Real)
+                                 -> Option<Contact<P>>
+    where P: Point,
+          M: Isometry<P> {
+    let ls_center    = mcuboid.inverse_transform_point(center);
+    let half_extents = cuboid.half_extents();
+    let mut proj     = ls_center;
+
+    for i in 0 .. na::dimension::<P::Vector>() {
+        proj[i] = ls_center[i].max(-half_extents[i]).min(half_extents[i]);
+    }
+
+    let mut normal: P::Vector = na::zero();
+    let depth;
+
+    if proj == ls_center {
+        // The center is inside of the cuboid, or on its boundary: select the closest face.
+        let mut best_axis = 0;
+        let mut best_dist = half_extents[0] - ls_center[0].abs();
+
+        for i in 1 .. na::dimension::<P::Vector>() {
+            let dist = half_extents[i] - ls_center[i].abs();
+
+            if dist < best_dist {
+                best_axis = i;
+                best_dist = dist;
+            }
+        }
+
+        let sign: P::Real = if ls_center[best_axis] < na::zero() { -na::one::<P::Real>() } else { na::one() };
+
+        proj[best_axis]   = half_extents[best_axis] * sign;
+        normal[best_axis] = -sign;
+        depth             = ball.radius() + best_dist;
+    }
+    else {
+        let (n, dist) = Unit::new_and_get(proj - ls_center);
+
+        if ball.radius() - dist < -prediction {
+            return None;
+        }
+
+        normal = n.unwrap();
+        depth  = ball.radius() - dist;
+    }
+
+    let normal = mcuboid.rotate_vector(&normal);
+    let c1     = *center + normal * ball.radius();
+    let c2     = mcuboid.transform_point(&proj);
+
+    Some(Contact::new(c1, c2, Unit::new_unchecked(normal), depth))
+}
+
+/// Contact between a cuboid and a ball.
+#[inline]
+pub fn cuboid_against_ball<P, M>(mcuboid: &M, cuboid: &Cuboid<P::Vector>,
+                                 center: &P, ball: &Ball<P::Real>,
+                                 prediction: P::Real)
+                                 -> Option<Contact<P>>
+    where P: Point,
+          M: Isometry<P> {
+    ball_against_cuboid(center, ball, mcuboid, cuboid, prediction).map(|mut c| { c.flip(); c })
+}
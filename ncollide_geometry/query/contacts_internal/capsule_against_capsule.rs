@@ -0,0 +1,123 @@
+use num::Zero;
+
+use approx::ApproxEq;
+use alga::general::Real;
+use alga::linear::FiniteDimInnerSpace;
+use na::{self, Unit};
+use query::{self, Contact};
+use shape::Capsule;
+use math::{Point, Isometry};
+
+/// Contact between two capsules.
+///
+/// The contact is computed from the closest points of the core segments of the capsules. See
+/// `capsule_against_capsule_manifold` to obtain two contacts for parallel capsules.
+pub fn capsule_against_capsule<P, M>(m1: &M, c1: &Capsule<P::Real>,
+                                     m2: &M, c2: &Capsule<P::Real>,
+                                     prediction: P::Real)
+                                     -> Option<Contact<P>>
+    where P: Point,
+          M: Isometry<P> {
+    let (a1, a2) = world_segment(m1, c1);
+    let (b1, b2) = world_segment(m2, c2);
+    let (pa, pb) = query::segment_segment_closest(&a1, &a2, &b1, &b2);
+
+    closest_points_contact(m1, c1, m2, c2, &pa, &pb, prediction)
+}
+
+/// Contact manifold between two capsules.
+///
+/// If the core segments of the capsules are parallel and overlap once projected on each other,
+/// two contacts are produced, at both ends of the overlap. Otherwise, the single contact given by
+/// `capsule_against_capsule` is produced, if any. The contacts are appended to `out`.
+pub fn capsule_against_capsule_manifold<P, M>(m1: &M, c1: &Capsule<P::Real>,
+                                              m2: &M, c2: &Capsule<P::Real>,
+                                              prediction: P::Real,
+                                              out: &mut Vec<Contact<P>>)
+    where P: Point,
+          M: Isometry<P> {
+    let (a1, a2) = world_segment(m1, c1);
+    let (b1, b2) = world_segment(m2, c2);
+
+    // Angular tolerance of the detection of parallel segments.
+    let eps   = P::Real::default_epsilon().sqrt();
+    let len1  = c1.half_height() * na::convert(2.0f64);
+    let axis1 = (a2 - a1) / len1;
+    let axis2 = na::normalize(&(b2 - b1));
+
+    if na::dot(&axis1, &axis2).abs() >= na::one::<P::Real>() - eps {
+        // The extremal abscissae of the second segment along the first one.
+        let tb1 = na::dot(&(b1 - a1), &axis1);
+        let tb2 = na::dot(&(b2 - a1), &axis1);
+        let min = tb1.min(tb2).max(na::zero());
+        let max = tb1.max(tb2).min(len1);
+
+        if max - min > eps * len1 {
+            for t in [ min, max ].iter() {
+                let pa      = a1 + axis1 * *t;
+                let (pb, _) = query::segment_segment_closest(&b1, &b2, &pa, &pa);
+
+                if let Some(c) = closest_points_contact(m1, c1, m2, c2, &pa, &pb, prediction) {
+                    out.push(c)
+                }
+            }
+
+            return;
+        }
+    }
+
+    if let Some(c) = capsule_against_capsule(m1, c1, m2, c2, prediction) {
+        out.push(c)
+    }
+}
+
+// The world-space core segment of a capsule.
+fn world_segment<P: Point, M: Isometry<P>>(m: &M, capsule: &Capsule<P::Real>) -> (P, P) {
+    let (a, b) = capsule.segment::<P>();
+
+    (m.transform_point(&a), m.transform_point(&b))
+}
+
+// The contact between two capsules, given the closest points `pa` and `pb` of their core segments.
+fn closest_points_contact<P, M>(m1: &M, c1: &Capsule<P::Real>,
+                                m2: &M, c2: &Capsule<P::Real>,
+                                pa: &P, pb: &P,
+                                prediction: P::Real)
+                                -> Option<Contact<P>>
+    where P: Point,
+          M: Isometry<P> {
+    let sum_radius = c1.radius() + c2.radius();
+
+    let (normal, dist) = match Unit::try_new_and_get(*pb - *pa, P::Real::default_epsilon()) {
+        Some(res) => res,
+        None      => {
+            // The core segments intersect: push the second capsule away from the first one,
+            // orthogonally to the axis of the first one.
+            let mut y = P::Vector::zero();
+            y[1] = na::one();
+
+            let axis   = m1.rotate_vector(&y);
+            let dpos   = m2.transform_point(&P::origin()) - m1.transform_point(&P::origin());
+            let ortho  = dpos - axis * na::dot(&dpos, &axis);
+            let normal = match Unit::try_new(ortho, P::Real::default_epsilon()) {
+                Some(n) => n,
+                None    => {
+                    let mut res = axis;
+                    P::Vector::orthonormal_subspace_basis(&[ axis ], |b| { res = *b; false });
+                    Unit::new_unchecked(res)
+                }
+            };
+
+            (normal, na::zero())
+        }
+    };
+
+    if sum_radius - dist < -prediction {
+        return None;
+    }
+
+    let w1 = *pa + *normal * c1.radius();
+    let w2 = *pb + *normal * -c2.radius();
+
+    Some(Contact::new(w1, w2, normal, sum_radius - dist))
+}
@@ -1,7 +1,7 @@
 use na;
 use partitioning::BoundingVolumeInterferencesCollector;
 use bounding_volume::BoundingVolume;
-use shape::{Shape, CompositeShape};
+use shape::{Shape, CompositeShape, FeatureId};
 use query::Contact;
 use query::contacts_internal;
 use math::{Point, Isometry};
@@ -122,3 +122,67 @@ pub fn shape_against_composite_shape<P, M, G2: ?Sized>(m1: &M, g1: &Shape<P, M>,
 
     res
 }
+
+/// Contacts between each part of a composite shape (`Mesh`, `Compound`) and any other shape,
+/// identified by the features they lie on.
+///
+/// One contact is appended to `out` for each part of `g1` in contact with `g2`. The feature on
+/// the composite shape is the part index wrapped in `FeatureId::Face`, e.g., the index of a
+/// triangle of a mesh. The feature on `g2` is identified by `SupportMap::support_feature_id`
+/// from the contact point on `g2`, or is `FeatureId::Unknown` if `g2` is not a support map.
+pub fn composite_shape_against_shape_with_features<P, M, G1: ?Sized>(
+                                                   m1: &M, g1: &G1,
+                                                   m2: &M, g2: &Shape<P, M>,
+                                                   prediction: P::Real,
+                                                   out: &mut Vec<(Contact<P>, (FeatureId, FeatureId))>)
+    where P:  Point,
+          M:  Isometry<P>,
+          G1: CompositeShape<P, M> {
+    let ls_m2    = na::inverse(m1) * m2.clone();
+    let ls_aabb2 = g2.aabb(&ls_m2).loosened(prediction);
+
+    let mut interferences = Vec::new();
+
+    {
+        let mut visitor = BoundingVolumeInterferencesCollector::new(&ls_aabb2, &mut interferences);
+        g1.bvt().visit(&mut visitor);
+    }
+
+    // Report the parts in increasing order, independently of the BVT traversal.
+    interferences.sort();
+
+    for i in interferences.into_iter() {
+        g1.map_part_at(i, &mut |_, part| {
+            if let Some(c) = contacts_internal::contact_internal(m1, part, m2, g2, prediction) {
+                let feature2 = match g2.as_support_map() {
+                    Some(sm) => sm.support_feature_id(m2, &[c.world2]),
+                    None     => FeatureId::Unknown
+                };
+
+                out.push((c, (FeatureId::Face(i), feature2)));
+            }
+        });
+    }
+}
+
+/// Contacts between any shape and each part of a composite shape (`Mesh`, `Compound`),
+/// identified by the features they lie on.
+///
+/// This is `composite_shape_against_shape_with_features` with the roles of the shapes swapped.
+pub fn shape_against_composite_shape_with_features<P, M, G2: ?Sized>(
+                                                   m1: &M, g1: &Shape<P, M>,
+                                                   m2: &M, g2: &G2,
+                                                   prediction: P::Real,
+                                                   out: &mut Vec<(Contact<P>, (FeatureId, FeatureId))>)
+    where P:  Point,
+          M:  Isometry<P>,
+          G2: CompositeShape<P, M> {
+    let curr_len = out.len();
+
+    composite_shape_against_shape_with_features(m2, g2, m1, g1, prediction, out);
+
+    for &mut (ref mut c, ref mut features) in out[curr_len ..].iter_mut() {
+        c.flip();
+        *features = (features.1, features.0);
+    }
+}
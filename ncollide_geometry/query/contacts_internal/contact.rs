@@ -1,8 +1,14 @@
 use std::mem;
-use math::Point;
+use rustc_serialize::{Encodable, Encoder, Decodable, Decoder};
+use na::Unit;
+use math::{Point, Isometry};
 
 /// Geometric description of a contact.
-#[derive(Debug, PartialEq, Clone, RustcEncodable, RustcDecodable)]
+///
+/// Every contact algorithm follows the same convention: the normal points from the first object
+/// toward the second one, and the depth is positive when the objects are penetrating, negative if
+/// they are separated. Thus, `world2` is approximately equal to `world1 - normal * depth`.
+#[derive(Debug, PartialEq, Clone)]
 pub struct Contact<P: Point> {
     /// Position of the contact on the first object. The position is expressed in world space.
     pub world1: P,
@@ -10,17 +16,17 @@ pub struct Contact<P: Point> {
     /// Position of the contact on the second object. The position is expressed in world space.
     pub world2: P,
 
-    /// Contact normal
-    pub normal: P::Vector,
+    /// Contact normal, i.e., the unit vector pointing from the first object toward the second one.
+    pub normal: Unit<P::Vector>,
 
-    /// Penetration depth
+    /// Penetration depth. It is negative if the objects are not penetrating.
     pub depth:  P::Real
 }
 
 impl<P: Point> Contact<P> {
     /// Creates a new contact.
     #[inline]
-    pub fn new(world1: P, world2: P, normal: P::Vector, depth: P::Real) -> Contact<P> {
+    pub fn new(world1: P, world2: P, normal: Unit<P::Vector>, depth: P::Real) -> Contact<P> {
         Contact {
             world1: world1,
             world2: world2,
@@ -37,4 +43,86 @@ impl<P: Point> Contact<P> {
         mem::swap(&mut self.world1, &mut self.world2);
         self.normal = -self.normal;
     }
+
+    /// Expresses the contact points in the local space of their respective objects.
+    ///
+    /// The objects are at the positions `m1` and `m2`. The normal is left in world space.
+    #[inline]
+    pub fn to_local<M: Isometry<P>>(&self, m1: &M, m2: &M) -> LocalContact<P> {
+        LocalContact::new(m1.inverse_transform_point(&self.world1),
+                          m2.inverse_transform_point(&self.world2),
+                          self.normal,
+                          self.depth)
+    }
+}
+
+/// Geometric description of a contact with points expressed in the local space of each object.
+///
+/// This follows the same conventions as `Contact`. The contact normal is expressed in world space.
+#[derive(Debug, PartialEq, Clone)]
+pub struct LocalContact<P: Point> {
+    /// Position of the contact on the first object, expressed in the local space of this object.
+    pub local1: P,
+
+    /// Position of the contact on the second object, expressed in the local space of this object.
+    pub local2: P,
+
+    /// Contact normal, i.e., the unit vector pointing from the first object toward the second one.
+    /// The normal is expressed in world space.
+    pub normal: Unit<P::Vector>,
+
+    /// Penetration depth. It is negative if the objects are not penetrating.
+    pub depth:  P::Real
+}
+
+impl<P: Point> LocalContact<P> {
+    /// Creates a new contact with points expressed in local space.
+    #[inline]
+    pub fn new(local1: P, local2: P, normal: Unit<P::Vector>, depth: P::Real) -> LocalContact<P> {
+        LocalContact {
+            local1: local1,
+            local2: local2,
+            normal: normal,
+            depth:  depth
+        }
+    }
+
+    /// Expresses the contact points in world space, the objects being at the positions `m1` and
+    /// `m2`.
+    #[inline]
+    pub fn to_world<M: Isometry<P>>(&self, m1: &M, m2: &M) -> Contact<P> {
+        Contact::new(m1.transform_point(&self.local1),
+                     m2.transform_point(&self.local2),
+                     self.normal,
+                     self.depth)
+    }
+}
+
+// The normal is serialized as a plain vector and normalized again when deserialized.
+impl<P: Point + Encodable> Encodable for Contact<P>
+    where P::Vector: Encodable,
+          P::Real:   Encodable {
+    fn encode<S: Encoder>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.emit_struct("Contact", 4, |s| {
+            try!(s.emit_struct_field("world1", 0, |s| self.world1.encode(s)));
+            try!(s.emit_struct_field("world2", 1, |s| self.world2.encode(s)));
+            try!(s.emit_struct_field("normal", 2, |s| self.normal.as_ref().encode(s)));
+            s.emit_struct_field("depth", 3, |s| self.depth.encode(s))
+        })
+    }
+}
+
+impl<P: Point + Decodable> Decodable for Contact<P>
+    where P::Vector: Decodable,
+          P::Real:   Decodable {
+    fn decode<D: Decoder>(d: &mut D) -> Result<Contact<P>, D::Error> {
+        d.read_struct("Contact", 4, |d| {
+            let world1 = try!(d.read_struct_field("world1", 0, Decodable::decode));
+            let world2 = try!(d.read_struct_field("world2", 1, Decodable::decode));
+            let normal = try!(d.read_struct_field("normal", 2, P::Vector::decode));
+            let depth  = try!(d.read_struct_field("depth", 3, Decodable::decode));
+
+            Ok(Contact::new(world1, world2, Unit::new_normalize(normal), depth))
+        })
+    }
 }
@@ -0,0 +1,311 @@
+use std::cmp::Ordering;
+use num::Zero;
+
+use approx::ApproxEq;
+use alga::general::Real;
+use alga::linear::FiniteDimInnerSpace;
+use na::{self, Point2, Vector2, Unit};
+use shape::ConvexHull;
+use query::algorithms::gjk::GJKResult;
+use query::algorithms::johnson_simplex::JohnsonSimplex;
+use query::{contacts_internal, Contact, QuerySettings};
+use utils;
+use math::{Point, Isometry};
+
+/// Contact manifold between two convex hulls.
+///
+/// The contact normal is computed by GJK, or by its fallback algorithm if the hulls penetrate.
+/// Then, the features of both hulls supporting this normal are clipped against each other in the
+/// plane orthogonal to the normal, producing one contact per vertex of the clipped feature. If one
+/// of the features is a single vertex, or if the features are crossing edges, a single contact is
+/// produced. In dimensions higher than 3, the contact given by GJK is the only one produced.
+///
+/// If both hulls have faces (edges in 2D) parallel up to an angular tolerance, the contact normal
+/// is replaced by the normal of the face of the first hull, and the whole overlap of both faces is
+/// used: the result does not depend on the slight tilt of the normal given by GJK.
+///
+/// The contacts are appended to `out`. They all have the same normal and depth. At most
+/// `QuerySettings::default().max_manifold_points` contacts are produced.
+pub fn convex_hull_against_convex_hull<P, M>(m1: &M, h1: &ConvexHull<P>,
+                                             m2: &M, h2: &ConvexHull<P>,
+                                             prediction: P::Real,
+                                             out: &mut Vec<Contact<P>>)
+    where P: Point,
+          M: Isometry<P> {
+    convex_hull_against_convex_hull_with_settings(m1, h1, m2, h2, prediction, &QuerySettings::default(), out)
+}
+
+/// Contact manifold between two convex hulls, with at most `settings.max_manifold_points` contacts.
+///
+/// See `convex_hull_against_convex_hull`. The GJK algorithm uses the tolerances of `settings`,
+/// and the contacts in excess from the clipping are discarded by `reduce_contact_manifold`.
+pub fn convex_hull_against_convex_hull_with_settings<P, M>(m1: &M, h1: &ConvexHull<P>,
+                                                           m2: &M, h2: &ConvexHull<P>,
+                                                           prediction: P::Real,
+                                                           settings: &QuerySettings<P::Real>,
+                                                           out: &mut Vec<Contact<P>>)
+    where P: Point,
+          M: Isometry<P> {
+    let mut simplex = JohnsonSimplex::new_w_tls();
+    let contact     = match contacts_internal::support_map_against_support_map_with_params(
+        m1, h1, m2, h2, prediction, &mut simplex, None, settings) {
+        GJKResult::Projection(contact) => contact,
+        _                              => return
+    };
+
+    // Angular tolerance of the detection of the features parallel to the plane of contact.
+    let eps: P::Real = na::convert(1.0e-3f64);
+    let mut normal   = *contact.normal;
+    let dim          = na::dimension::<P::Vector>();
+
+    if dim > 3 {
+        out.push(contact);
+        return;
+    }
+
+    // The normal given by GJK may be tilted enough for the features of parallel faces to collapse
+    // to an edge or a vertex. Thus, the faces are searched with a looser tolerance first.
+    let loose = eps.sqrt();
+    let n1    = face_normal(&h1.support_feature(m1, &normal, loose)[..], &normal);
+    let n2    = face_normal(&h2.support_feature(m2, &-normal, loose)[..], &-normal);
+
+    if let (Some(n1), Some(n2)) = (n1, n2) {
+        if -na::dot(&n1, &n2) >= eps.cos() {
+            normal = n1;
+        }
+    }
+
+    let contact_normal = Unit::new_unchecked(normal);
+    let f1             = h1.support_feature(m1, &normal, eps);
+    let f2             = h2.support_feature(m2, &-normal, eps);
+
+    // A vertex feature is the exact witness point: the penetration fallback of GJK does not
+    // always return contact points lying on the hull boundaries.
+    if f2.len() == 1 {
+        let world1 = f2[0] + normal * contact.depth;
+        out.push(Contact::new(world1, f2[0], contact_normal, contact.depth));
+        return;
+    }
+
+    if f1.len() == 1 {
+        let world2 = f1[0] + normal * -contact.depth;
+        out.push(Contact::new(f1[0], world2, contact_normal, contact.depth));
+        return;
+    }
+
+    let mut basis = Vec::with_capacity(2);
+    P::Vector::orthonormal_subspace_basis(&[normal], |b| { basis.push(*b); true });
+
+    let offset1 = na::dot(&f1[0].coordinates(), &normal);
+    let offset2 = na::dot(&f2[0].coordinates(), &normal);
+
+    let project = |pts: &[P]| -> Vec<Point2<P::Real>> {
+        pts.iter().map(|pt| {
+            let y = if dim == 3 { na::dot(&pt.coordinates(), &basis[1]) } else { na::zero() };
+            Point2::new(na::dot(&pt.coordinates(), &basis[0]), y)
+        }).collect()
+    };
+
+    let p1 = project(&f1[..]);
+    let p2 = project(&f2[..]);
+    let mut clipped = Vec::new();
+
+    if dim == 2 || (p1.len() == 2 && p2.len() == 2) {
+        // Both features are segments: they overlap only if they are parallel.
+        let d1 = p1[1] - p1[0];
+        let d2 = p2[1] - p2[0];
+
+        if dim == 2 || (d1.x * d2.y - d1.y * d2.x).abs() <= eps * na::norm(&d1) * na::norm(&d2) {
+            let dir = if dim == 2 { Vector2::x() } else { d1 / na::norm(&d1) };
+            let (min1, max1) = interval(&p1[..], &dir);
+            let (min2, max2) = interval(&p2[..], &dir);
+            let min = min1.max(min2);
+            let max = max1.min(max2);
+
+            if min <= max {
+                // The line supporting the first segment, in the contact plane.
+                let origin = p1[0] + dir * -na::dot(&p1[0].coords, &dir);
+
+                clipped.push(origin + dir * min);
+
+                if max - min > eps * (max1 - min1) {
+                    clipped.push(origin + dir * max);
+                }
+            }
+        }
+    }
+    else {
+        // Clip the segment or polygon with the fewest vertices against the other polygon.
+        let (subject, clip) = if p1.len() < p2.len() { (p1, p2) } else { (p2, p1) };
+        let subject = if subject.len() > 2 { sort_ccw(subject) } else { subject };
+
+        clipped = clip_polygon(subject, &sort_ccw(clip)[..]);
+    }
+
+    if clipped.is_empty() {
+        out.push(contact);
+    }
+    else {
+        let mut contacts = Vec::with_capacity(clipped.len());
+
+        for pt in clipped.iter() {
+            // The world-space points on each feature, from their coordinates on the contact plane.
+            let mut tangent = basis[0] * pt.x;
+
+            if dim == 3 {
+                tangent = tangent + basis[1] * pt.y;
+            }
+
+            let world1 = P::from_coordinates(tangent + normal * offset1);
+            let world2 = P::from_coordinates(tangent + normal * offset2);
+
+            contacts.push(Contact::new(world1, world2, contact_normal, offset1 - offset2));
+        }
+
+        contacts_internal::reduce_contact_manifold(&mut contacts, settings.max_manifold_points);
+        out.extend(contacts);
+    }
+}
+
+// The unit normal, oriented toward `dir`, of the face spanned by coplanar points. Returns `None`
+// if the points do not span a face, i.e., an edge in 2D or a polygon in 3D.
+fn face_normal<P: Point>(pts: &[P], dir: &P::Vector) -> Option<P::Vector> {
+    let dim = na::dimension::<P::Vector>();
+
+    if pts.len() < dim {
+        return None;
+    }
+
+    // The points spanning the largest edge and triangle avoid the near-zero cross products
+    // between the edges of nearly aligned vertices.
+    let a      = pts[0];
+    let mut ab = na::zero::<P::Vector>();
+
+    for pt in pts[1 ..].iter() {
+        if na::norm_squared(&(*pt - a)) > na::norm_squared(&ab) {
+            ab = *pt - a
+        }
+    }
+
+    let eps      = P::Real::default_epsilon().sqrt();
+    let sqlen_ab = na::norm_squared(&ab);
+
+    if sqlen_ab.is_zero() {
+        return None;
+    }
+
+    let normal = if dim == 2 {
+        let mut res = ab;
+        res[0] = -ab[1];
+        res[1] = ab[0];
+
+        res
+    }
+    else {
+        let mut res = na::zero::<P::Vector>();
+
+        for pt in pts[1 ..].iter() {
+            let cross = utils::cross3(&ab, &(*pt - a));
+
+            if na::norm_squared(&cross) > na::norm_squared(&res) {
+                res = cross
+            }
+        }
+
+        if na::norm_squared(&res) <= eps * eps * sqlen_ab * sqlen_ab {
+            return None;
+        }
+
+        res
+    };
+
+    let normal = na::normalize(&normal);
+
+    if na::dot(&normal, dir) < na::zero() {
+        Some(-normal)
+    }
+    else {
+        Some(normal)
+    }
+}
+
+// The extremal abscissae of points along a direction.
+fn interval<N: Real>(pts: &[Point2<N>], dir: &Vector2<N>) -> (N, N) {
+    let mut min = na::dot(&pts[0].coords, dir);
+    let mut max = min;
+
+    for pt in pts[1 ..].iter() {
+        let x = na::dot(&pt.coords, dir);
+        min = min.min(x);
+        max = max.max(x);
+    }
+
+    (min, max)
+}
+
+// Sorts the vertices of a convex polygon counterclockwise. A NaN angle, e.g., from non-finite
+// vertices, compares equal to any other instead of panicking.
+fn sort_ccw<N: Real>(mut pts: Vec<Point2<N>>) -> Vec<Point2<N>> {
+    let sum    = pts.iter().fold(na::zero::<Vector2<N>>(), |c, pt| c + pt.coords);
+    let center = Point2::from_coordinates(sum / na::convert::<f64, N>(pts.len() as f64));
+
+    pts.sort_by(|a, b| {
+        let angle_a = (a.y - center.y).atan2(a.x - center.x);
+        let angle_b = (b.y - center.y).atan2(b.x - center.x);
+
+        angle_a.partial_cmp(&angle_b).unwrap_or(Ordering::Equal)
+    });
+
+    pts
+}
+
+// Sutherland-Hodgman clipping of a polygon, or a segment, by a counterclockwise convex polygon.
+fn clip_polygon<N: Real>(subject: Vec<Point2<N>>, clip: &[Point2<N>]) -> Vec<Point2<N>> {
+    let mut res = subject;
+
+    for i in 0 .. clip.len() {
+        if res.is_empty() {
+            break;
+        }
+
+        let a    = clip[i];
+        let b    = clip[(i + 1) % clip.len()];
+        let side = |pt: &Point2<N>| (b.x - a.x) * (pt.y - a.y) - (b.y - a.y) * (pt.x - a.x);
+
+        let input = res;
+        res       = Vec::with_capacity(input.len() + 1);
+
+        // A segment is clipped as a degenerate polygon with two vertices: only handle its single
+        // edge once.
+        let num_edges = if input.len() == 2 { 1 } else { input.len() };
+
+        if input.len() == 1 {
+            if side(&input[0]) >= na::zero() {
+                res.push(input[0])
+            }
+            continue;
+        }
+
+        for j in 0 .. num_edges {
+            let p = input[j];
+            let q = input[(j + 1) % input.len()];
+            let sp = side(&p);
+            let sq = side(&q);
+
+            if sp >= na::zero() {
+                res.push(p)
+            }
+
+            if (sp >= na::zero()) != (sq >= na::zero()) {
+                let t = sp / (sp - sq);
+                res.push(p + (q - p) * t)
+            }
+
+            if num_edges == 1 && sq >= na::zero() {
+                res.push(q)
+            }
+        }
+    }
+
+    res
+}
@@ -0,0 +1,217 @@
+use num::Zero;
+
+use approx::ApproxEq;
+use alga::general::Real;
+use na::{self, Unit};
+use query::{contacts_internal, Contact};
+use shape::{Cuboid, Capsule};
+use math::{Point, Isometry};
+
+/// Contact between a cuboid and a capsule.
+///
+/// The contact is computed from the point of the capsule core segment closest to the cuboid, and
+/// its projection on the cuboid. Thus, the contacts with the edges and the corners of the cuboid
+/// are exact. If the core segment touches the cuboid, the contact is computed by GJK or by its
+/// penetration fallback instead. See `cuboid_against_capsule_manifold` to obtain two contacts for a
+/// capsule lying flat against a face of the cuboid.
+pub fn cuboid_against_capsule<P, M>(m1: &M, cuboid: &Cuboid<P::Vector>,
+                                    m2: &M, capsule: &Capsule<P::Real>,
+                                    prediction: P::Real)
+                                    -> Option<Contact<P>>
+    where P: Point,
+          M: Isometry<P> {
+    let mut contacts = Vec::new();
+
+    cuboid_capsule_contacts(m1, cuboid, m2, capsule, prediction, false, &mut contacts);
+
+    contacts.pop()
+}
+
+/// Contact between a capsule and a cuboid.
+#[inline]
+pub fn capsule_against_cuboid<P, M>(m1: &M, capsule: &Capsule<P::Real>,
+                                    m2: &M, cuboid: &Cuboid<P::Vector>,
+                                    prediction: P::Real)
+                                    -> Option<Contact<P>>
+    where P: Point,
+          M: Isometry<P> {
+    cuboid_against_capsule(m2, cuboid, m1, capsule, prediction).map(|mut c| { c.flip(); c })
+}
+
+/// Contact manifold between a cuboid and a capsule.
+///
+/// If the core segment of the capsule is parallel to the face of the cuboid closest to it, two
+/// contacts are produced, at both ends of the part of the segment facing this face. Otherwise, the
+/// single contact given by `cuboid_against_capsule` is produced, if any. The contacts are appended
+/// to `out`.
+pub fn cuboid_against_capsule_manifold<P, M>(m1: &M, cuboid: &Cuboid<P::Vector>,
+                                             m2: &M, capsule: &Capsule<P::Real>,
+                                             prediction: P::Real,
+                                             out: &mut Vec<Contact<P>>)
+    where P: Point,
+          M: Isometry<P> {
+    cuboid_capsule_contacts(m1, cuboid, m2, capsule, prediction, true, out)
+}
+
+/// Contact manifold between a capsule and a cuboid.
+pub fn capsule_against_cuboid_manifold<P, M>(m1: &M, capsule: &Capsule<P::Real>,
+                                             m2: &M, cuboid: &Cuboid<P::Vector>,
+                                             prediction: P::Real,
+                                             out: &mut Vec<Contact<P>>)
+    where P: Point,
+          M: Isometry<P> {
+    let curr_len = out.len();
+
+    cuboid_capsule_contacts(m2, cuboid, m1, capsule, prediction, true, out);
+
+    for c in out[curr_len ..].iter_mut() {
+        c.flip()
+    }
+}
+
+fn cuboid_capsule_contacts<P, M>(m1: &M, cuboid: &Cuboid<P::Vector>,
+                                 m2: &M, capsule: &Capsule<P::Real>,
+                                 prediction: P::Real,
+                                 manifold: bool,
+                                 out: &mut Vec<Contact<P>>)
+    where P: Point,
+          M: Isometry<P> {
+    // The core segment of the capsule, in the local space of the cuboid.
+    let (a, b) = capsule.segment::<P>();
+    let a      = m1.inverse_transform_point(&m2.transform_point(&a));
+    let b      = m1.inverse_transform_point(&m2.transform_point(&b));
+    let dir    = b - a;
+
+    let he  = cuboid.half_extents();
+    let dim = na::dimension::<P::Vector>();
+    let t   = closest_parameter(&a, &dir, he);
+    let pt  = a + dir * t;
+
+    let (normal, dist) = match Unit::try_new_and_get(pt - clamp(&pt, he), P::Real::default_epsilon()) {
+        Some(res) => res,
+        None      => {
+            if let Some(c) = contacts_internal::support_map_against_support_map(m1, cuboid, m2, capsule, prediction) {
+                out.push(c)
+            }
+
+            return;
+        }
+    };
+
+    if capsule.radius() - dist < -prediction {
+        return;
+    }
+
+    let push_contact = |out: &mut Vec<Contact<P>>, pt: &P| {
+        let proj  = clamp(pt, he);
+        let depth = capsule.radius() - na::distance(pt, &proj);
+        let world_normal = Unit::new_unchecked(m1.rotate_vector(&*normal));
+        let world1 = m1.transform_point(&proj);
+        let world2 = m1.transform_point(&(*pt + *normal * -capsule.radius()));
+
+        out.push(Contact::new(world1, world2, world_normal, depth))
+    };
+
+    if manifold {
+        let outside: Vec<usize> = (0 .. dim).filter(|&i| pt[i].abs() > he[i]).collect();
+        let eps = P::Real::default_epsilon().sqrt();
+
+        if outside.len() == 1 && dir[outside[0]].abs() <= eps * na::norm(&dir) {
+            // The segment is parallel to a face: clip it to the slab orthogonal to this face.
+            let face     = outside[0];
+            let mut tmin = P::Real::zero();
+            let mut tmax = na::one::<P::Real>();
+
+            for j in (0 .. dim).filter(|&j| j != face) {
+                if dir[j].is_zero() {
+                    continue;
+                }
+
+                let t1 = (-he[j] - a[j]) / dir[j];
+                let t2 = (he[j] - a[j]) / dir[j];
+
+                tmin = tmin.max(t1.min(t2));
+                tmax = tmax.min(t1.max(t2));
+            }
+
+            if tmax - tmin > eps {
+                push_contact(out, &(a + dir * tmin));
+                push_contact(out, &(a + dir * tmax));
+
+                return;
+            }
+        }
+    }
+
+    push_contact(out, &pt)
+}
+
+// The projection of a point on a cuboid centered at the origin.
+fn clamp<P: Point>(pt: &P, half_extents: &P::Vector) -> P {
+    let mut res = *pt;
+
+    for i in 0 .. na::dimension::<P::Vector>() {
+        res[i] = pt[i].max(-half_extents[i]).min(half_extents[i]);
+    }
+
+    res
+}
+
+// The parameter `t` in `[0, 1]` of the point `a + dir * t` of a segment closest to a cuboid
+// centered at the origin.
+//
+// The squared distance to the cuboid is a convex piecewise quadratic function of `t`, with
+// breakpoints where the segment crosses the planes of the faces of the cuboid. The pieces are
+// visited in order and the function is minimized exactly on each one: by convexity, the first
+// minimum found before the end of its piece is the global one.
+fn closest_parameter<P: Point>(a: &P, dir: &P::Vector, half_extents: &P::Vector) -> P::Real {
+    let dim         = na::dimension::<P::Vector>();
+    let _0: P::Real = na::zero();
+    let _1: P::Real = na::one();
+    let _2: P::Real = na::convert(2.0f64);
+    let mut start   = _0;
+
+    loop {
+        // The piece ends at the next crossing of the plane of a face.
+        let mut end = _1;
+
+        for i in 0 .. dim {
+            if !dir[i].is_zero() {
+                for bound in [ -half_extents[i], half_extents[i] ].iter() {
+                    let t = (*bound - a[i]) / dir[i];
+
+                    if t > start && t < end {
+                        end = t
+                    }
+                }
+            }
+        }
+
+        let mid     = (start + end) / _2;
+        let mut num = _0;
+        let mut den = _0;
+
+        // On this piece, the squared distance only involves the faces the segment lies outside of.
+        for i in 0 .. dim {
+            let x = a[i] + dir[i] * mid;
+
+            if x > half_extents[i] {
+                num = num + (a[i] - half_extents[i]) * dir[i];
+                den = den + dir[i] * dir[i];
+            }
+            else if x < -half_extents[i] {
+                num = num + (a[i] + half_extents[i]) * dir[i];
+                den = den + dir[i] * dir[i];
+            }
+        }
+
+        // A constant piece is part of the minimum of the function.
+        let t = if den.is_zero() { start } else { (-num / den).max(start).min(end) };
+
+        if t < end || end >= _1 {
+            return t;
+        }
+
+        start = end;
+    }
+}
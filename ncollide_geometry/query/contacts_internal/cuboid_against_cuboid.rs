@@ -0,0 +1,55 @@
+use na;
+use shape::{Cuboid, ConvexHull};
+use query::{contacts_internal, Contact, QuerySettings};
+use math::{Point, Isometry};
+
+/// Contact manifold between two cuboids.
+///
+/// The cuboids are clipped against each other as convex hulls of their vertices, see
+/// `convex_hull_against_convex_hull`. In particular, two cuboids stacked flat have one contact
+/// per corner of the overlap of their faces. The contacts are appended to `out`.
+pub fn cuboid_against_cuboid_manifold<P, M>(m1: &M, c1: &Cuboid<P::Vector>,
+                                            m2: &M, c2: &Cuboid<P::Vector>,
+                                            prediction: P::Real,
+                                            out: &mut Vec<Contact<P>>)
+    where P: Point,
+          M: Isometry<P> {
+    cuboid_against_cuboid_manifold_with_settings(m1, c1, m2, c2, prediction, &QuerySettings::default(), out)
+}
+
+/// Contact manifold between two cuboids, with at most `settings.max_manifold_points` contacts.
+///
+/// See `convex_hull_against_convex_hull_with_settings`.
+pub fn cuboid_against_cuboid_manifold_with_settings<P, M>(m1: &M, c1: &Cuboid<P::Vector>,
+                                                          m2: &M, c2: &Cuboid<P::Vector>,
+                                                          prediction: P::Real,
+                                                          settings: &QuerySettings<P::Real>,
+                                                          out: &mut Vec<Contact<P>>)
+    where P: Point,
+          M: Isometry<P> {
+    let h1 = ConvexHull::new(cuboid_vertices::<P>(c1));
+    let h2 = ConvexHull::new(cuboid_vertices::<P>(c2));
+
+    contacts_internal::convex_hull_against_convex_hull_with_settings(m1, &h1, m2, &h2, prediction, settings, out)
+}
+
+// The vertices of a cuboid in its local space.
+fn cuboid_vertices<P: Point>(cuboid: &Cuboid<P::Vector>) -> Vec<P> {
+    let dim     = na::dimension::<P::Vector>();
+    let he      = cuboid.half_extents();
+    let mut res = Vec::with_capacity(1 << dim);
+
+    for i in 0 .. 1usize << dim {
+        let mut vertex = *he;
+
+        for k in 0 .. dim {
+            if i & (1 << k) == 0 {
+                vertex[k] = -vertex[k]
+            }
+        }
+
+        res.push(P::from_coordinates(vertex))
+    }
+
+    res
+}
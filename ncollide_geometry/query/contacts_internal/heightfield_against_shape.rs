@@ -0,0 +1,92 @@
+use na::{self, Unit};
+use bounding_volume::BoundingVolume;
+use shape::{Shape, HeightField};
+use query::Contact;
+use query::contacts_internal;
+use math::{Point, Isometry};
+
+/// Best contact between a heightfield and any other shape.
+///
+/// Only the triangles of the cells intersecting the AABB of `g2`, loosened by `prediction`, are
+/// tested. If the heightfield has smooth normals, the contact normal is replaced by the normal
+/// interpolated at the contact point on the heightfield.
+pub fn heightfield_against_shape<P, M>(m1: &M, g1: &HeightField<P::Real>,
+                                       m2: &M, g2: &Shape<P, M>,
+                                       prediction: P::Real)
+                                       -> Option<Contact<P>>
+    where P: Point,
+          M: Isometry<P> {
+    let ls_m2    = na::inverse(m1) * m2.clone();
+    let ls_aabb2 = g2.aabb(&ls_m2).loosened(prediction);
+
+    let mut res = None::<Contact<P>>;
+
+    let mut best_id = 0;
+
+    g1.map_triangles_in_local_aabb(&ls_aabb2, &mut |id, triangle| {
+        if let Some(c) = contacts_internal::contact_internal(m1, triangle, m2, g2, prediction) {
+            let replace = match res {
+                Some(ref cbest) => c.depth > cbest.depth,
+                None            => true
+            };
+
+            if replace {
+                res     = Some(c);
+                best_id = id;
+            }
+        }
+    });
+
+    if g1.has_smooth_normals() {
+        for c in res.iter_mut() {
+            smooth_heightfield_contact(m1, g1, best_id, c, true);
+        }
+    }
+
+    res
+}
+
+/// Best contact between a shape and a heightfield.
+pub fn shape_against_heightfield<P, M>(m1: &M, g1: &Shape<P, M>,
+                                       m2: &M, g2: &HeightField<P::Real>,
+                                       prediction: P::Real)
+                                       -> Option<Contact<P>>
+    where P: Point,
+          M: Isometry<P> {
+    let mut res = heightfield_against_shape(m2, g2, m1, g1, prediction);
+
+    for c in res.iter_mut() {
+        c.flip()
+    }
+
+    res
+}
+
+/// Replaces the normal of a contact with a heightfield by the smooth normal of the heightfield.
+///
+/// The normal is interpolated at the contact point on the triangle `id` of the heightfield `hf`
+/// at the position `m`, and oriented like the original normal. If `hf_first` is `true`, the
+/// heightfield is the first object of the contact, otherwise it is the second one. The depth is
+/// preserved and the contact point on the other object is moved so that `world2` stays equal to
+/// `world1 - normal * depth`.
+pub fn smooth_heightfield_contact<P, M>(m: &M, hf: &HeightField<P::Real>, id: usize,
+                                        c: &mut Contact<P>, hf_first: bool)
+    where P: Point,
+          M: Isometry<P> {
+    let pt         = if hf_first { c.world1 } else { c.world2 };
+    let ls_pt      = m.inverse_transform_point(&pt);
+    let mut normal = m.rotate_vector(&hf.normal_at_point(id, &ls_pt));
+
+    if na::dot(&normal, &*c.normal) < na::zero() {
+        normal = -normal;
+    }
+
+    c.normal = Unit::new_unchecked(normal);
+
+    if hf_first {
+        c.world2 = c.world1 + (-normal * c.depth);
+    }
+    else {
+        c.world1 = c.world2 + normal * c.depth;
+    }
+}
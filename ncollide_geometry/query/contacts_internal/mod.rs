@@ -1,18 +1,52 @@
 //! Implementation details of the `contact` and `contacts` functions.
 
-pub use self::contact::Contact;
+pub use self::contact::{Contact, LocalContact};
 pub use self::ball_against_ball::ball_against_ball;
 pub use self::support_map_against_support_map::support_map_against_support_map;
-pub use self::support_map_against_support_map::support_map_against_support_map_with_params;
-pub use self::plane_against_support_map::{plane_against_support_map, support_map_against_plane};
+pub use self::support_map_against_support_map::{support_map_against_support_map_with_params,
+                                                support_map_against_support_map_with_debug,
+                                                ContactDebug};
+pub use self::plane_against_support_map::{plane_against_support_map, support_map_against_plane,
+                                          plane_against_shape_manifold, shape_against_plane_manifold};
+pub use self::plane_against_ball::{plane_against_ball, ball_against_plane};
+pub use self::ball_against_cuboid::{ball_against_cuboid, cuboid_against_ball};
+pub use self::ball_against_capsule::{ball_against_capsule, capsule_against_ball};
+pub use self::capsule_against_capsule::{capsule_against_capsule, capsule_against_capsule_manifold};
+pub use self::cuboid_against_capsule::{cuboid_against_capsule, capsule_against_cuboid,
+                                       cuboid_against_capsule_manifold, capsule_against_cuboid_manifold};
+pub use self::cuboid_against_cuboid::{cuboid_against_cuboid_manifold,
+                                      cuboid_against_cuboid_manifold_with_settings};
+pub use self::convex_hull_against_convex_hull::{convex_hull_against_convex_hull,
+                                               convex_hull_against_convex_hull_with_settings};
+pub use self::reduce_contact_manifold::reduce_contact_manifold;
 pub use self::shape_against_shape::shape_against_shape as contact_internal;
-pub use self::composite_shape_against_shape::{composite_shape_against_shape, shape_against_composite_shape};
+pub use self::shape_against_shape::shape_against_shape_local as contact_local_internal;
+pub use self::composite_shape_against_shape::{composite_shape_against_shape, shape_against_composite_shape,
+                                              composite_shape_against_shape_with_features,
+                                              shape_against_composite_shape_with_features};
+pub use self::heightfield_against_shape::{heightfield_against_shape, shape_against_heightfield,
+                                          smooth_heightfield_contact};
+pub use self::trimesh_against_shape::{trimesh_against_shape, shape_against_trimesh,
+                                      trimesh_against_shape_with_features,
+                                      trimesh_internal_edges_correction};
+pub use self::trimesh_against_trimesh::{trimesh_against_trimesh, trimesh_against_trimesh_with_features};
 // pub use self::generate_contact_manifold::generate_contact_manifold;
 
 mod contact;
 mod ball_against_ball;
 mod support_map_against_support_map;
 mod plane_against_support_map;
+mod plane_against_ball;
+mod ball_against_cuboid;
+mod ball_against_capsule;
+mod capsule_against_capsule;
+mod cuboid_against_capsule;
+mod cuboid_against_cuboid;
+mod convex_hull_against_convex_hull;
+mod reduce_contact_manifold;
 mod shape_against_shape;
 mod composite_shape_against_shape;
+mod heightfield_against_shape;
+mod trimesh_against_shape;
+mod trimesh_against_trimesh;
 // mod generate_contact_manifold;
@@ -0,0 +1,43 @@
+use alga::linear::Translation;
+use na::{self, Unit};
+use query::Contact;
+use shape::{Ball, Plane};
+use math::{Point, Isometry};
+
+/// Contact between a plane and a ball.
+///
+/// The depth is the radius of the ball minus the signed distance from its center to the plane. A
+/// ball resting exactly on the plane has a contact with a zero depth.
+#[inline]
+pub fn plane_against_ball<P, M>(mplane: &M, plane: &Plane<P::Vector>,
+                                center: &P, ball: &Ball<P::Real>,
+                                prediction: P::Real)
+                                -> Option<Contact<P>>
+    where P: Point,
+          M: Isometry<P> {
+    let plane_normal = mplane.rotate_vector(plane.normal());
+    let plane_center = P::from_coordinates(mplane.translation().to_vector());
+    let distance     = na::dot(&plane_normal, &(*center - plane_center));
+    let depth        = ball.radius() - distance;
+
+    if depth >= -prediction {
+        let c1 = *center + (-plane_normal * distance);
+        let c2 = *center + (-plane_normal * ball.radius());
+
+        Some(Contact::new(c1, c2, Unit::new_unchecked(plane_normal), depth))
+    }
+    else {
+        None
+    }
+}
+
+/// Contact between a ball and a plane.
+#[inline]
+pub fn ball_against_plane<P, M>(center: &P, ball: &Ball<P::Real>,
+                                mplane: &M, plane: &Plane<P::Vector>,
+                                prediction: P::Real)
+                                -> Option<Contact<P>>
+    where P: Point,
+          M: Isometry<P> {
+    plane_against_ball(mplane, plane, center, ball, prediction).map(|mut c| { c.flip(); c })
+}
@@ -1,7 +1,7 @@
 use alga::linear::Translation;
-use na;
-use query::Contact;
-use shape::{SupportMap, Plane};
+use na::{self, Unit};
+use query::{contacts_internal, Contact};
+use shape::{Shape, SupportMap, Plane, Cuboid, ConvexHull, ConvexPolygon, Triangle, Segment};
 use math::{Point, Isometry};
 
 /// Contact between a plane and a support-mapped shape (Cuboid, ConvexHull, etc.)
@@ -21,7 +21,7 @@ pub fn plane_against_support_map<P, M, G: ?Sized>(mplane: &M, plane: &Plane<P::V
     if distance > -prediction {
         let c1 = deepest + plane_normal * distance;
 
-        Some(Contact::new(c1, deepest, plane_normal, distance))
+        Some(Contact::new(c1, deepest, Unit::new_unchecked(plane_normal), distance))
     }
     else {
         None
@@ -38,3 +38,113 @@ pub fn support_map_against_plane<P, M, G: ?Sized>(mother: &M, other: &G,
           G: SupportMap<P, M> {
     plane_against_support_map(mplane, plane, mother, other, prediction).map(|mut c| { c.flip(); c })
 }
+
+/// Contact manifold between a plane and a shape.
+///
+/// Each vertex of a polytope, i.e., a cuboid, a convex hull, a convex polygon, a triangle or a
+/// segment, that penetrates the plane or is closer to it than `prediction` gives a contact. The
+/// manifold is then reduced to at most `2^(dim - 1)` contacts, e.g., four in 3D, covering as much
+/// of it as possible. Any other support-mapped shape gives the single contact of
+/// `plane_against_support_map`. The contacts are appended to `out`. Returns `false` if `other`
+/// is not support-mapped.
+pub fn plane_against_shape_manifold<P, M>(mplane: &M, plane: &Plane<P::Vector>,
+                                          mother: &M, other: &Shape<P, M>,
+                                          prediction: P::Real,
+                                          out: &mut Vec<Contact<P>>)
+                                          -> bool
+    where P: Point,
+          M: Isometry<P> {
+    let plane_normal = mplane.rotate_vector(plane.normal());
+    let plane_center = P::from_coordinates(mplane.translation().to_vector());
+    let mut contacts = Vec::new();
+
+    let is_polytope = polytope_vertices(other, &mut |vertex| {
+        let vertex   = mother.transform_point(vertex);
+        let distance = na::dot(&plane_normal, &(plane_center - vertex));
+
+        if distance > -prediction {
+            let c1 = vertex + plane_normal * distance;
+
+            contacts.push(Contact::new(c1, vertex, Unit::new_unchecked(plane_normal), distance))
+        }
+    });
+
+    if !is_polytope {
+        return match other.as_support_map() {
+            Some(sm) => {
+                out.extend(plane_against_support_map(mplane, plane, mother, sm, prediction).into_iter());
+                true
+            },
+            None => false
+        }
+    }
+
+    contacts_internal::reduce_contact_manifold(&mut contacts, 1 << (na::dimension::<P::Vector>() - 1));
+    out.extend(contacts.into_iter());
+
+    true
+}
+
+/// Contact manifold between a shape and a plane.
+pub fn shape_against_plane_manifold<P, M>(mother: &M, other: &Shape<P, M>,
+                                          mplane: &M, plane: &Plane<P::Vector>,
+                                          prediction: P::Real,
+                                          out: &mut Vec<Contact<P>>)
+                                          -> bool
+    where P: Point,
+          M: Isometry<P> {
+    let curr_len = out.len();
+    let res      = plane_against_shape_manifold(mplane, plane, mother, other, prediction, out);
+
+    for c in out[curr_len ..].iter_mut() {
+        c.flip()
+    }
+
+    res
+}
+
+// Calls `f` on each vertex of a polytope, in its local space. Returns `false` if `g` is not a
+// polytope.
+fn polytope_vertices<P, M, F>(g: &Shape<P, M>, f: &mut F) -> bool
+    where P: Point,
+          M: Isometry<P>,
+          F: FnMut(&P) {
+    if let Some(c) = g.as_shape::<Cuboid<P::Vector>>() {
+        let dim = na::dimension::<P::Vector>();
+
+        // The `i`-th bit of the index of a corner tells if it is on the positive side along `i`.
+        for id in 0 .. 1usize << dim {
+            let mut pt = P::origin();
+
+            for i in 0 .. dim {
+                pt[i] = if id & (1 << i) != 0 { c.half_extents()[i] } else { -c.half_extents()[i] };
+            }
+
+            f(&pt)
+        }
+    }
+    else if let Some(h) = g.as_shape::<ConvexHull<P>>() {
+        for pt in h.points().iter() {
+            f(pt)
+        }
+    }
+    else if let Some(p) = g.as_shape::<ConvexPolygon<P>>() {
+        for pt in p.points().iter() {
+            f(pt)
+        }
+    }
+    else if let Some(t) = g.as_shape::<Triangle<P>>() {
+        f(t.a());
+        f(t.b());
+        f(t.c())
+    }
+    else if let Some(s) = g.as_shape::<Segment<P>>() {
+        f(s.a());
+        f(s.b())
+    }
+    else {
+        return false;
+    }
+
+    true
+}
@@ -0,0 +1,85 @@
+use na;
+use utils;
+use query::Contact;
+use math::Point;
+
+/// Keeps at most `max_points` contacts of a manifold, chosen to cover as much of it as possible.
+///
+/// The deepest contact is kept first, then the contact farthest from it. In 3D, each other kept
+/// contact is the one that increases the most the area of the polygon formed by the contacts
+/// already kept, and the selection stops early if no remaining contact increases this area. The
+/// areas are measured on the contact points on the first shape, projected on the plane orthogonal
+/// to the normal of the deepest contact. In 2D, at most those two contacts are kept.
+pub fn reduce_contact_manifold<P: Point>(contacts: &mut Vec<Contact<P>>, max_points: usize) {
+    assert!(max_points > 0, "A contact manifold must be allowed to contain at least one point.");
+
+    if contacts.len() <= max_points {
+        return;
+    }
+
+    let mut deepest = 0;
+
+    for (i, c) in contacts.iter().enumerate() {
+        if c.depth > contacts[deepest].depth {
+            deepest = i;
+        }
+    }
+
+    let mut kept = vec![ deepest ];
+
+    if max_points > 1 {
+        let origin       = contacts[deepest].world1;
+        let mut farthest = deepest;
+        let mut max_sqd  = na::zero();
+
+        for (i, c) in contacts.iter().enumerate() {
+            let sqd = na::distance_squared(&c.world1, &origin);
+
+            if sqd > max_sqd {
+                farthest = i;
+                max_sqd  = sqd;
+            }
+        }
+
+        if farthest != deepest {
+            kept.push(farthest);
+        }
+    }
+
+    let normal = *contacts[deepest].normal;
+
+    while na::dimension::<P::Vector>() == 3 && kept.len() > 1 && kept.len() < max_points {
+        // The best candidate, its area gain, and where to insert it on the polygon. The polygon is
+        // kept counterclockwise around the normal: only the candidates on the outer side of an
+        // edge increase its area.
+        let mut best = None;
+
+        for (i, c) in contacts.iter().enumerate() {
+            if kept.contains(&i) {
+                continue;
+            }
+
+            for j in 0 .. kept.len() {
+                let a    = &contacts[kept[j]].world1;
+                let b    = &contacts[kept[(j + 1) % kept.len()]].world1;
+                let gain = na::dot(&utils::cross3(&(*b - *a), &(c.world1 - *a)), &normal);
+
+                let replace = match best {
+                    Some((_, best_gain, _)) => gain > best_gain,
+                    None                    => gain > na::zero()
+                };
+
+                if replace {
+                    best = Some((i, gain, j + 1));
+                }
+            }
+        }
+
+        match best {
+            Some((i, _, pos)) => kept.insert(pos, i),
+            None              => break
+        }
+    }
+
+    *contacts = kept.iter().map(|&i| contacts[i].clone()).collect();
+}
@@ -1,12 +1,28 @@
 use alga::linear::Translation;
 use math::{Point, Isometry};
-use shape::{Shape, Ball, Plane};
+use shape::{Shape, Ball, Cuboid, Capsule, Plane, HeightField, TriMesh};
 use query::contacts_internal;
-use query::contacts_internal::Contact;
+use query::contacts_internal::{Contact, LocalContact};
+
+/// Computes one contact point between two shapes, with points expressed in the local space of
+/// each shape.
+///
+/// This is the same as `shape_against_shape` followed by `Contact::to_local`: the normal remains
+/// in world space.
+pub fn shape_against_shape_local<P, M>(m1: &M, g1: &Shape<P, M>,
+                                       m2: &M, g2: &Shape<P, M>,
+                                       prediction: P::Real)
+                                       -> Option<LocalContact<P>>
+    where P: Point,
+          M: Isometry<P> {
+    shape_against_shape(m1, g1, m2, g2, prediction).map(|c| c.to_local(m1, m2))
+}
 
 /// Computes one contact point between two shapes.
 ///
-/// Returns `None` if the objects are separated by a distance greater than `prediction`.
+/// Returns `None` if the objects are separated by a distance greater than `prediction`. Whatever
+/// the algorithm selected for the given pair of shapes, the contact normal points from `g1` toward
+/// `g2` and the depth is positive iff the shapes are penetrating.
 pub fn shape_against_shape<P, M>(m1: &M, g1: &Shape<P, M>,
                                  m2: &M, g2: &Shape<P, M>,
                                  prediction: P::Real)
@@ -20,6 +36,45 @@ pub fn shape_against_shape<P, M>(m1: &M, g1: &Shape<P, M>,
 
         contacts_internal::ball_against_ball(&p1, b1, &p2, b2, prediction)
     }
+    else if let (Some(p1), Some(b2)) = (g1.as_shape::<Plane<P::Vector>>(), g2.as_shape::<Ball<P::Real>>()) {
+        let c2 = P::from_coordinates(m2.translation().to_vector());
+
+        contacts_internal::plane_against_ball(m1, p1, &c2, b2, prediction)
+    }
+    else if let (Some(b1), Some(p2)) = (g1.as_shape::<Ball<P::Real>>(), g2.as_shape::<Plane<P::Vector>>()) {
+        let c1 = P::from_coordinates(m1.translation().to_vector());
+
+        contacts_internal::ball_against_plane(&c1, b1, m2, p2, prediction)
+    }
+    else if let (Some(b1), Some(c2)) = (g1.as_shape::<Ball<P::Real>>(), g2.as_shape::<Cuboid<P::Vector>>()) {
+        let c1 = P::from_coordinates(m1.translation().to_vector());
+
+        contacts_internal::ball_against_cuboid(&c1, b1, m2, c2, prediction)
+    }
+    else if let (Some(c1), Some(b2)) = (g1.as_shape::<Cuboid<P::Vector>>(), g2.as_shape::<Ball<P::Real>>()) {
+        let c2 = P::from_coordinates(m2.translation().to_vector());
+
+        contacts_internal::cuboid_against_ball(m1, c1, &c2, b2, prediction)
+    }
+    else if let (Some(b1), Some(c2)) = (g1.as_shape::<Ball<P::Real>>(), g2.as_shape::<Capsule<P::Real>>()) {
+        let c1 = P::from_coordinates(m1.translation().to_vector());
+
+        contacts_internal::ball_against_capsule(&c1, b1, m2, c2, prediction)
+    }
+    else if let (Some(c1), Some(b2)) = (g1.as_shape::<Capsule<P::Real>>(), g2.as_shape::<Ball<P::Real>>()) {
+        let c2 = P::from_coordinates(m2.translation().to_vector());
+
+        contacts_internal::capsule_against_ball(m1, c1, &c2, b2, prediction)
+    }
+    else if let (Some(c1), Some(c2)) = (g1.as_shape::<Capsule<P::Real>>(), g2.as_shape::<Capsule<P::Real>>()) {
+        contacts_internal::capsule_against_capsule(m1, c1, m2, c2, prediction)
+    }
+    else if let (Some(c1), Some(c2)) = (g1.as_shape::<Cuboid<P::Vector>>(), g2.as_shape::<Capsule<P::Real>>()) {
+        contacts_internal::cuboid_against_capsule(m1, c1, m2, c2, prediction)
+    }
+    else if let (Some(c1), Some(c2)) = (g1.as_shape::<Capsule<P::Real>>(), g2.as_shape::<Cuboid<P::Vector>>()) {
+        contacts_internal::capsule_against_cuboid(m1, c1, m2, c2, prediction)
+    }
     else if let (Some(p1), Some(s2)) = (g1.as_shape::<Plane<P::Vector>>(), g2.as_support_map()) {
         contacts_internal::plane_against_support_map(m1, p1, m2, s2, prediction)
     }
@@ -29,6 +84,21 @@ pub fn shape_against_shape<P, M>(m1: &M, g1: &Shape<P, M>,
     else if let (Some(s1), Some(s2)) = (g1.as_support_map(), g2.as_support_map()) {
         contacts_internal::support_map_against_support_map(m1, s1, m2, s2, prediction)
     }
+    else if let Some(h1) = g1.as_shape::<HeightField<P::Real>>() {
+        contacts_internal::heightfield_against_shape(m1, h1, m2, g2, prediction)
+    }
+    else if let Some(h2) = g2.as_shape::<HeightField<P::Real>>() {
+        contacts_internal::shape_against_heightfield(m1, g1, m2, h2, prediction)
+    }
+    else if let (Some(t1), Some(t2)) = (g1.as_shape::<TriMesh<P>>(), g2.as_shape::<TriMesh<P>>()) {
+        contacts_internal::trimesh_against_trimesh(m1, t1, m2, t2, prediction)
+    }
+    else if let Some(t1) = g1.as_shape::<TriMesh<P>>() {
+        contacts_internal::trimesh_against_shape(m1, t1, m2, g2, prediction)
+    }
+    else if let Some(t2) = g2.as_shape::<TriMesh<P>>() {
+        contacts_internal::shape_against_trimesh(m1, g1, m2, t2, prediction)
+    }
     else if let Some(c1) = g1.as_composite_shape() {
         contacts_internal::composite_shape_against_shape(m1, c1, m2, g2, prediction)
     }
@@ -1,17 +1,47 @@
 use num::Zero;
 
 use alga::linear::{NormedSpace, Translation};
-use na;
-use shape::{self, SupportMap, AnnotatedPoint};
-use query::algorithms::gjk::GJKResult;
+use na::{self, Unit};
+use shape::{self, SupportMap, AnnotatedPoint, FeatureId};
+use query::algorithms::gjk::{GJKResult, GJKStatus};
 use query::algorithms::gjk;
 use query::algorithms::minkowski_sampling;
 use query::algorithms::simplex::Simplex;
 use query::algorithms::johnson_simplex::JohnsonSimplex;
-use query::Contact;
+use query::{Contact, QuerySettings};
 use math::{Point, Isometry};
 
 
+/// Diagnostic data about the computation of a contact between support-mapped shapes.
+///
+/// See `support_map_against_support_map_with_debug`.
+#[derive(Debug, Clone)]
+pub struct ContactDebug<P: Point> {
+    /// The unit direction from the first shape toward the second one found by the algorithm.
+    ///
+    /// This is the contact normal if a contact is found. If the shapes are farther than the
+    /// prediction distance, this is the first separating axis found by GJK, which is not
+    /// necessarily orthogonal to the closest features. It is zero if no direction could be
+    /// found, e.g. because the shapes touch.
+    pub dir: P::Vector,
+    /// The points of the terminal GJK simplex, on the Minkowski difference of the first shape and
+    /// the second one.
+    ///
+    /// If the shapes penetrate, this is the simplex containing the origin found before running the
+    /// penetration fallback algorithm.
+    pub simplex: Vec<P>,
+    /// The witness features of the first and the second shape.
+    ///
+    /// They are identified from the points of the terminal GJK simplex, or from the contact points
+    /// if the penetration fallback algorithm was used.
+    pub features: (FeatureId, FeatureId),
+    /// Whether the shapes penetrate, in which case the contact was computed by the penetration
+    /// fallback algorithm instead of GJK.
+    pub penetration_fallback: bool,
+    /// The termination status of the GJK algorithm.
+    pub status: GJKStatus
+}
+
 /// Contact between support-mapped shapes (`Cuboid`, `ConvexHull`, etc.)
 pub fn support_map_against_support_map<P, M, G1: ?Sized, G2: ?Sized>(
                                        m1:         &M,
@@ -25,7 +55,7 @@ pub fn support_map_against_support_map<P, M, G1: ?Sized, G2: ?Sized>(
           G1: SupportMap<P, M>,
           G2: SupportMap<P, M> {
     match support_map_against_support_map_with_params(
-        m1, g1, m2, g2, prediction, &mut JohnsonSimplex::new_w_tls(), None) {
+        m1, g1, m2, g2, prediction, &mut JohnsonSimplex::new_w_tls(), None, &QuerySettings::default()) {
         GJKResult::Projection(c)     => Some(c),
         GJKResult::NoIntersection(_) => None,
         GJKResult::Intersection      => unreachable!(),
@@ -43,13 +73,65 @@ pub fn support_map_against_support_map_with_params<P, M, S, G1: ?Sized, G2: ?Siz
                                                    g2:         &G2,
                                                    prediction: P::Real,
                                                    simplex:    &mut S,
-                                                   init_dir:   Option<P::Vector>)
+                                                   init_dir:   Option<P::Vector>,
+                                                   settings:   &QuerySettings<P::Real>)
                                                    -> GJKResult<Contact<P>, P::Vector>
     where P:  Point,
           M:  Isometry<P>,
           S:  Simplex<AnnotatedPoint<P>>,
           G1: SupportMap<P, M>,
           G2: SupportMap<P, M> {
+    contact_with_debug(m1, g1, m2, g2, prediction, simplex, init_dir, settings, None)
+}
+
+/// Contact between support-mapped shapes (`Cuboid`, `ConvexHull`, etc.), with diagnostic data.
+///
+/// This computes the same contact as `support_map_against_support_map`, and reports how it was
+/// found by the underlying GJK and penetration fallback algorithms. This is meant to debug
+/// unexpected contacts, and is slower than `support_map_against_support_map`.
+pub fn support_map_against_support_map_with_debug<P, M, G1: ?Sized, G2: ?Sized>(
+                                                  m1:         &M,
+                                                  g1:         &G1,
+                                                  m2:         &M,
+                                                  g2:         &G2,
+                                                  prediction: P::Real)
+                                                  -> (Option<Contact<P>>, ContactDebug<P>)
+    where P:  Point,
+          M:  Isometry<P>,
+          G1: SupportMap<P, M>,
+          G2: SupportMap<P, M> {
+    let mut debug = ContactDebug {
+        dir:                  na::zero(),
+        simplex:              Vec::new(),
+        features:             (FeatureId::Unknown, FeatureId::Unknown),
+        penetration_fallback: false,
+        status:               GJKStatus::Converged
+    };
+
+    let res = contact_with_debug(m1, g1, m2, g2, prediction, &mut JohnsonSimplex::new_w_tls(), None,
+                                 &QuerySettings::default(), Some(&mut debug));
+
+    match res {
+        GJKResult::Projection(c) => (Some(c), debug),
+        _                        => (None, debug)
+    }
+}
+
+fn contact_with_debug<P, M, S, G1: ?Sized, G2: ?Sized>(m1:         &M,
+                                                       g1:         &G1,
+                                                       m2:         &M,
+                                                       g2:         &G2,
+                                                       prediction: P::Real,
+                                                       simplex:    &mut S,
+                                                       init_dir:   Option<P::Vector>,
+                                                       settings:   &QuerySettings<P::Real>,
+                                                       mut debug:  Option<&mut ContactDebug<P>>)
+                                                       -> GJKResult<Contact<P>, P::Vector>
+    where P:  Point,
+          M:  Isometry<P>,
+          S:  Simplex<AnnotatedPoint<P>>,
+          G1: SupportMap<P, M>,
+          G2: SupportMap<P, M> {
     let mut dir =
         match init_dir {
             // FIXME: or m2.translation - m1.translation ?
@@ -63,7 +145,27 @@ pub fn support_map_against_support_map_with_params<P, M, S, G1: ?Sized, G2: ?Siz
 
     simplex.reset(shape::cso_support_point(m1, g1, m2, g2, dir));
 
-    match gjk::closest_points_with_max_dist(m1, g1, m2, g2, prediction, simplex) {
+    let (res, status) = gjk::closest_points_with_max_dist(m1, g1, m2, g2, prediction, simplex, settings);
+
+    if let Some(ref mut debug) = debug {
+        let mut pts1 = Vec::new();
+        let mut pts2 = Vec::new();
+
+        debug.simplex.clear();
+
+        for i in 0 .. simplex.dimension() + 1 {
+            let pt = simplex.point(i);
+
+            debug.simplex.push(*pt.point());
+            pts1.push(*pt.orig1());
+            pts2.push(-*pt.orig2());
+        }
+
+        debug.features = (g1.support_feature_id(m1, &pts1[..]), g2.support_feature_id(m2, &pts2[..]));
+        debug.status   = status;
+    }
+
+    match res {
         GJKResult::Projection((p1, p2)) => {
             let p1p2 = p2 - p1;
             let sqn  = na::norm_squared(&p1p2);
@@ -72,20 +174,39 @@ pub fn support_map_against_support_map_with_params<P, M, S, G1: ?Sized, G2: ?Siz
                 let mut normal = p1p2;
                 let depth      = normal.normalize_mut();
 
-                return GJKResult::Projection(Contact::new(p1, p2, normal, -depth));
+                if let Some(ref mut debug) = debug {
+                    debug.dir = normal;
+                }
+
+                return GJKResult::Projection(Contact::new(p1, p2, Unit::new_unchecked(normal), -depth));
+            }
+        },
+        GJKResult::NoIntersection(dir) => {
+            // `dir` points from the second shape toward the first one.
+            if let Some(ref mut debug) = debug {
+                if !dir.is_zero() {
+                    debug.dir = -na::normalize(&dir);
+                }
             }
+
+            return GJKResult::NoIntersection(dir)
         },
-        GJKResult::NoIntersection(dir) => return GJKResult::NoIntersection(dir),
         GJKResult::Intersection        => { }, // fallback
         GJKResult::Proximity(_)        => unreachable!()
     }
 
     // The point is inside of the CSO: use the fallback algorithm
-    match minkowski_sampling::closest_points(m1, g1, m2, g2, simplex) {
+    match minkowski_sampling::closest_points_with_settings(m1, g1, m2, g2, simplex, settings).0 {
         Some((p1, p2, normal)) => {
             let depth = na::dot(&(p1 - p2), &normal);
 
-            GJKResult::Projection(Contact::new(p1, p2, normal, depth))
+            if let Some(ref mut debug) = debug {
+                debug.dir                  = normal;
+                debug.features             = (g1.support_feature_id(m1, &[ p1 ]), g2.support_feature_id(m2, &[ p2 ]));
+                debug.penetration_fallback = true;
+            }
+
+            GJKResult::Projection(Contact::new(p1, p2, Unit::new_unchecked(normal), depth))
         }
         None => GJKResult::NoIntersection(na::zero()) // panic!("Both GJK and fallback algorithm failed.")
     }
@@ -0,0 +1,235 @@
+use num::Zero;
+use alga::general::Real;
+use approx::ApproxEq;
+use na::{self, Unit};
+use shape::{Shape, TriMesh, FeatureId};
+use query::Contact;
+use query::contacts_internal;
+use utils;
+use math::{Point, Isometry};
+
+/// Best contact between a triangle mesh and any other shape, with internal edges correction.
+///
+/// See `trimesh_against_shape_with_features` for the correction of the contact normals.
+pub fn trimesh_against_shape<P, M>(m1: &M, g1: &TriMesh<P>,
+                                   m2: &M, g2: &Shape<P, M>,
+                                   prediction: P::Real)
+                                   -> Option<Contact<P>>
+    where P: Point,
+          M: Isometry<P> {
+    let mut contacts = Vec::new();
+    let mut res      = None::<Contact<P>>;
+
+    trimesh_against_shape_with_features(m1, g1, m2, g2, prediction, &mut contacts);
+
+    for (c, _) in contacts.into_iter() {
+        let replace = match res {
+            Some(ref cbest) => c.depth > cbest.depth,
+            None            => true
+        };
+
+        if replace {
+            res = Some(c)
+        }
+    }
+
+    res
+}
+
+/// Best contact between any shape and a triangle mesh, with internal edges correction.
+pub fn shape_against_trimesh<P, M>(m1: &M, g1: &Shape<P, M>,
+                                   m2: &M, g2: &TriMesh<P>,
+                                   prediction: P::Real)
+                                   -> Option<Contact<P>>
+    where P: Point,
+          M: Isometry<P> {
+    let mut res = trimesh_against_shape(m2, g2, m1, g1, prediction);
+
+    for c in res.iter_mut() {
+        c.flip()
+    }
+
+    res
+}
+
+/// Contacts between each triangle of a mesh and any other shape, with internal edges correction.
+///
+/// The contacts are identified as by `composite_shape_against_shape_with_features`. A contact
+/// located on an edge shared by two triangles must have a normal lying in the cone spanned by the
+/// normals of both triangles if this edge is convex, or equal to the normal of the triangle in
+/// contact otherwise. An internal edge is not a real feature of the surface of the mesh: a normal
+/// outside of this cone would make a body sliding on the mesh catch on the edge. Such a normal is
+/// snapped to the closest normal of the cone and the contact depth is recomputed along it. The
+/// contacts located on the boundary edges of the mesh or inside of a triangle are left unchanged.
+/// The correction is performed in 3D only.
+pub fn trimesh_against_shape_with_features<P, M>(m1: &M, g1: &TriMesh<P>,
+                                                 m2: &M, g2: &Shape<P, M>,
+                                                 prediction: P::Real,
+                                                 out: &mut Vec<(Contact<P>, (FeatureId, FeatureId))>)
+    where P: Point,
+          M: Isometry<P> {
+    let curr_len = out.len();
+
+    contacts_internal::composite_shape_against_shape_with_features(m1, g1, m2, g2, prediction, out);
+
+    let contacts: Vec<_> = out.drain(curr_len ..).collect();
+
+    for (c, features) in contacts.into_iter() {
+        let i = match features.0 {
+            FeatureId::Face(i) => i,
+            _                  => unreachable!()
+        };
+
+        if let Some(corrected) = trimesh_internal_edges_correction(m1, g1, i, m2, g2, prediction, &c, features.1) {
+            out.push((corrected.0, (features.0, corrected.1)))
+        }
+    }
+}
+
+/// Corrects the normal of a contact between the i-th triangle of a mesh and any other shape
+/// located on internal edges of the mesh.
+///
+/// `feature2` is the feature of `g2` the contact lies on. See
+/// `trimesh_against_shape_with_features` for the correction. Returns the corrected contact with
+/// its feature on `g2`, or `None` if the corrected contact is farther than `prediction`. The
+/// contact is returned unchanged in 2D.
+pub fn trimesh_internal_edges_correction<P, M>(m1: &M, g1: &TriMesh<P>, i: usize,
+                                               m2: &M, g2: &Shape<P, M>,
+                                               prediction: P::Real,
+                                               c: &Contact<P>, feature2: FeatureId)
+                                               -> Option<(Contact<P>, FeatureId)>
+    where P: Point,
+          M: Isometry<P> {
+    if na::dimension::<P::Vector>() != 3 {
+        return Some((c.clone(), feature2));
+    }
+
+    let normal = internal_edges_normal(m1, g1, i, c);
+
+    if na::dot(&*normal, &*c.normal) >= na::one::<P::Real>() - P::Real::default_epsilon().sqrt() {
+        return Some((c.clone(), feature2));
+    }
+
+    // Recompute the contact along the corrected normal. The plane of every triangle adjacent
+    // to the internal edges passes through the contact point on the mesh. The contact point
+    // on `g2` is moved along the normal up to the supporting plane of `g2`.
+    let (world2, feature2) = match g2.as_support_map() {
+        Some(sm) => {
+            let support = sm.support_point(m2, &-*normal);
+            let world2  = c.world2 + *normal * na::dot(&(support - c.world2), &*normal);
+
+            (world2, sm.support_feature_id(m2, &[world2]))
+        },
+        None => (c.world2, feature2)
+    };
+
+    let depth = na::dot(&(c.world1 - world2), &*normal);
+
+    if depth >= -prediction {
+        let world1 = world2 + *normal * depth;
+        Some((Contact::new(world1, world2, normal, depth), feature2))
+    }
+    else {
+        None
+    }
+}
+
+// The normal of the contact `c` with the i-th triangle of `mesh`, restricted to the valid normal
+// cones of the internal edges the contact point lies on.
+fn internal_edges_normal<P, M>(m: &M, mesh: &TriMesh<P>, i: usize, c: &Contact<P>) -> Unit<P::Vector>
+    where P: Point,
+          M: Isometry<P> {
+    let idx = mesh.indices()[i];
+    let vs  = mesh.vertices();
+    let pts = [ m.transform_point(&vs[idx.x]), m.transform_point(&vs[idx.y]), m.transform_point(&vs[idx.z]) ];
+
+    let face_normal = match Unit::try_new(utils::cross3(&(pts[1] - pts[0]), &(pts[2] - pts[0])), P::Real::default_epsilon()) {
+        Some(n) => n,
+        None    => return c.normal
+    };
+
+    // Orient the triangle normals toward the side of the contact.
+    let sign = if na::dot(&*face_normal, &*c.normal) < na::zero() { -na::one::<P::Real>() } else { na::one() };
+    let face = *face_normal * sign;
+
+    let mut size = P::Real::zero();
+
+    for j in 0 .. 3 {
+        size = size.max(na::norm(&(pts[(j + 1) % 3] - pts[j])));
+    }
+
+    let tol         = P::Real::default_epsilon().sqrt() * size;
+    let mut normal  = *c.normal;
+    let mut on_edge = false;
+
+    for j in 0 .. 3 {
+        let a = pts[j];
+        let b = pts[(j + 1) % 3];
+
+        if segment_distance(&c.world1, &a, &b) > tol {
+            continue;
+        }
+
+        let k = match mesh.adjacent_triangles(i)[j] {
+            Some(k) => k,
+            // Boundary edges are real features of the mesh.
+            None    => return c.normal
+        };
+
+        on_edge = true;
+
+        let kidx = mesh.indices()[k];
+        let kpts = [ m.transform_point(&vs[kidx.x]), m.transform_point(&vs[kidx.y]), m.transform_point(&vs[kidx.z]) ];
+        let opposite = (0 .. 3).find(|&l| kidx[l] != idx[j] && kidx[l] != idx[(j + 1) % 3]).unwrap();
+
+        let neighbor_normal = match Unit::try_new(utils::cross3(&(kpts[1] - kpts[0]), &(kpts[2] - kpts[0])),
+                                                  P::Real::default_epsilon()) {
+            Some(n) => *n,
+            None    => { normal = face; continue }
+        };
+
+        // Both triangles have the same orientation iff they traverse their shared edge in
+        // opposite directions.
+        let consistent = (0 .. 3).any(|l| kidx[l] == idx[(j + 1) % 3] && kidx[(l + 1) % 3] == idx[j]);
+        let neighbor   = if consistent { neighbor_normal * sign } else { neighbor_normal * -sign };
+
+        // The edge is convex iff the neighbor bends away from the side of the contact.
+        if na::dot(&(kpts[opposite] - a), &face) >= -tol {
+            normal = face;
+            continue;
+        }
+
+        let edge    = na::normalize(&(b - a));
+        let proj    = normal - edge * na::dot(&normal, &edge);
+        let span    = na::dot(&utils::cross3(&face, &neighbor), &edge);
+        let in_cone = na::dot(&utils::cross3(&face, &proj), &edge) * span >= na::zero() &&
+                      na::dot(&utils::cross3(&proj, &neighbor), &edge) * span >= na::zero() &&
+                      na::dot(&proj, &(face + neighbor)) > na::zero();
+
+        if !in_cone {
+            normal = if na::dot(&normal, &face) >= na::dot(&normal, &neighbor) { face } else { neighbor };
+        }
+    }
+
+    if on_edge {
+        Unit::try_new(normal, P::Real::default_epsilon()).unwrap_or(c.normal)
+    }
+    else {
+        c.normal
+    }
+}
+
+// The distance from `pt` to the segment `[a, b]`.
+fn segment_distance<P: Point>(pt: &P, a: &P, b: &P) -> P::Real {
+    let ab    = *b - *a;
+    let sqlen = na::norm_squared(&ab);
+
+    let t = if sqlen.is_zero() {
+        na::zero()
+    }
+    else {
+        (na::dot(&(*pt - *a), &ab) / sqlen).max(na::zero()).min(na::one())
+    };
+
+    na::distance(pt, &(*a + ab * t))
+}
@@ -0,0 +1,213 @@
+use num::Zero;
+use alga::general::Real;
+use approx::ApproxEq;
+use na::{self, Unit};
+use partitioning::BVTTVisitor;
+use bounding_volume::{BoundingVolume, AABB};
+use shape::{TriMesh, Triangle, FeatureId};
+use query::Contact;
+use query::contacts_internal;
+use utils;
+use math::{Point, Isometry};
+
+/// Best contact between two triangle meshes.
+pub fn trimesh_against_trimesh<P, M>(m1: &M, g1: &TriMesh<P>,
+                                     m2: &M, g2: &TriMesh<P>,
+                                     prediction: P::Real)
+                                     -> Option<Contact<P>>
+    where P: Point,
+          M: Isometry<P> {
+    let mut contacts = Vec::new();
+    let mut res      = None::<Contact<P>>;
+
+    trimesh_against_trimesh_with_features(m1, g1, m2, g2, prediction, &mut contacts);
+
+    for (c, _) in contacts.into_iter() {
+        let replace = match res {
+            Some(ref cbest) => c.depth > cbest.depth,
+            None            => true
+        };
+
+        if replace {
+            res = Some(c)
+        }
+    }
+
+    res
+}
+
+/// Contacts between each pair of triangles of two meshes.
+///
+/// The BVTs of both meshes are traversed simultaneously and one contact is appended to `out` for
+/// each pair of triangles closer than `prediction`. The contacts are identified by the indices of
+/// the triangles wrapped in `FeatureId::Face`, and are sorted by these indices. The edge-edge and
+/// vertex-face contacts are computed by GJK/EPA on the triangle pair. Two overlapping coplanar
+/// triangles, for which the penetration direction is undefined, are in contact at the centroid of
+/// their intersection, with the normal of the triangle of `g1` and a zero depth.
+pub fn trimesh_against_trimesh_with_features<P, M>(m1: &M, g1: &TriMesh<P>,
+                                                   m2: &M, g2: &TriMesh<P>,
+                                                   prediction: P::Real,
+                                                   out: &mut Vec<(Contact<P>, (FeatureId, FeatureId))>)
+    where P: Point,
+          M: Isometry<P> {
+    let ls_m2 = na::inverse(m1) * m2.clone();
+
+    let mut pairs = Vec::new();
+
+    {
+        let mut visitor = TriangleInterferencesCollector {
+            ls_m2:      &ls_m2,
+            prediction: prediction,
+            pairs:      &mut pairs
+        };
+
+        g1.bvt().visit_bvtt(g2.bvt(), &mut visitor);
+    }
+
+    // Report the pairs in increasing order, independently of the BVTT traversal.
+    pairs.sort();
+
+    for (i, j) in pairs.into_iter() {
+        let tri1 = g1.triangle_at(i);
+        let tri2 = g2.triangle_at(j);
+
+        let contact = match coplanar_triangles_contact(m1, &tri1, m2, &tri2) {
+            Some(c) => Some(c),
+            None    => contacts_internal::contact_internal(m1, &tri1, m2, &tri2, prediction)
+        };
+
+        if let Some(c) = contact {
+            out.push((c, (FeatureId::Face(i), FeatureId::Face(j))));
+        }
+    }
+}
+
+// Collects the pairs of triangles with intersecting AABBs. The AABBs of the second mesh are
+// transformed into the local space of the first one.
+struct TriangleInterferencesCollector<'a, P: Point, M: 'a> {
+    ls_m2:      &'a M,
+    prediction: P::Real,
+    pairs:      &'a mut Vec<(usize, usize)>
+}
+
+impl<'a, P, M> TriangleInterferencesCollector<'a, P, M>
+    where P: Point,
+          M: Isometry<P> {
+    #[inline]
+    fn intersects(&self, bv1: &AABB<P>, bv2: &AABB<P>) -> bool {
+        bv1.intersects(&bv2.transform_by(self.ls_m2).loosened(self.prediction))
+    }
+}
+
+impl<'a, P, M> BVTTVisitor<usize, AABB<P>> for TriangleInterferencesCollector<'a, P, M>
+    where P: Point,
+          M: Isometry<P> {
+    #[inline]
+    fn visit_internal_internal(&mut self, bv1: &AABB<P>, bv2: &AABB<P>) -> bool {
+        self.intersects(bv1, bv2)
+    }
+
+    #[inline]
+    fn visit_leaf_leaf(&mut self, i: &usize, bv1: &AABB<P>, j: &usize, bv2: &AABB<P>) {
+        if self.intersects(bv1, bv2) {
+            self.pairs.push((*i, *j))
+        }
+    }
+
+    #[inline]
+    fn visit_internal_leaf(&mut self, bv1: &AABB<P>, _: &usize, bv2: &AABB<P>) -> bool {
+        self.intersects(bv1, bv2)
+    }
+
+    #[inline]
+    fn visit_leaf_internal(&mut self, _: &usize, bv1: &AABB<P>, bv2: &AABB<P>) -> bool {
+        self.intersects(bv1, bv2)
+    }
+}
+
+// The contact between two overlapping coplanar triangles, or `None` if the triangles are not
+// coplanar. Those are degenerate for EPA. Returns `None` too if the coplanar triangles do not
+// overlap: their distance is then well-defined.
+fn coplanar_triangles_contact<P, M>(m1: &M, tri1: &Triangle<P>, m2: &M, tri2: &Triangle<P>) -> Option<Contact<P>>
+    where P: Point,
+          M: Isometry<P> {
+    if na::dimension::<P::Vector>() != 3 {
+        return None;
+    }
+
+    let pts1 = [ m1.transform_point(tri1.a()), m1.transform_point(tri1.b()), m1.transform_point(tri1.c()) ];
+    let pts2 = [ m2.transform_point(tri2.a()), m2.transform_point(tri2.b()), m2.transform_point(tri2.c()) ];
+
+    let n1 = match Unit::try_new(utils::cross3(&(pts1[1] - pts1[0]), &(pts1[2] - pts1[0])), P::Real::default_epsilon()) {
+        Some(n) => n,
+        None    => return None
+    };
+    let n2 = match Unit::try_new(utils::cross3(&(pts2[1] - pts2[0]), &(pts2[2] - pts2[0])), P::Real::default_epsilon()) {
+        Some(n) => n,
+        None    => return None
+    };
+
+    let mut size = P::Real::zero();
+
+    for k in 0 .. 3 {
+        size = size.max(na::norm(&(pts1[(k + 1) % 3] - pts1[k])));
+        size = size.max(na::norm(&(pts2[(k + 1) % 3] - pts2[k])));
+    }
+
+    let tol = P::Real::default_epsilon().sqrt();
+    let cos = na::dot(&*n1, &*n2);
+
+    if cos.abs() < na::one::<P::Real>() - tol {
+        return None;
+    }
+
+    for pt in pts2.iter() {
+        if na::dot(&(*pt - pts1[0]), &*n1).abs() > tol * size {
+            return None;
+        }
+    }
+
+    // Clip the second triangle, projected on the plane of the first one, by the edges of the
+    // first triangle.
+    let mut polygon: Vec<P> = pts2.iter().map(|pt| *pt + *n1 * -na::dot(&(*pt - pts1[0]), &*n1)).collect();
+    let mut clipped = Vec::with_capacity(6);
+
+    for k in 0 .. 3 {
+        let a    = pts1[k];
+        let side = utils::cross3(&*n1, &(pts1[(k + 1) % 3] - a));
+
+        for l in 0 .. polygon.len() {
+            let p  = polygon[l];
+            let q  = polygon[(l + 1) % polygon.len()];
+            let dp = na::dot(&(p - a), &side);
+            let dq = na::dot(&(q - a), &side);
+
+            if dp >= na::zero() {
+                clipped.push(p);
+            }
+
+            if (dp >= na::zero()) != (dq >= na::zero()) {
+                clipped.push(p + (q - p) * (dp / (dp - dq)));
+            }
+        }
+
+        ::std::mem::swap(&mut polygon, &mut clipped);
+        clipped.clear();
+
+        if polygon.is_empty() {
+            return None;
+        }
+    }
+
+    let mut center = P::origin();
+
+    for pt in polygon.iter() {
+        center = center + pt.coordinates();
+    }
+
+    let world1 = P::from_coordinates(center.coordinates() / na::convert(polygon.len() as f64));
+    let world2 = world1 + *n1 * (na::dot(&(pts2[0] - world1), &*n2) / cos);
+    let depth  = na::dot(&(world1 - world2), &*n1);
+
+    Some(Contact::new(world1, world2, n1, depth))
+}
@@ -4,7 +4,7 @@ pub use self::ball_against_ball::ball_against_ball;
 pub use self::support_map_against_support_map::support_map_against_support_map;
 pub use self::support_map_against_support_map::support_map_against_support_map_with_params;
 pub use self::plane_against_support_map::{plane_against_support_map, support_map_against_plane};
-pub use self::shape_against_shape::shape_against_shape as distance;
+pub use self::shape_against_shape::{shape_against_shape as distance, distance_checked};
 pub use self::composite_shape_against_shape::{composite_shape_against_shape, shape_against_composite_shape};
 
 mod ball_against_ball;
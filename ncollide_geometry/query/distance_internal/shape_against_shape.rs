@@ -1,6 +1,9 @@
+use approx::ApproxEq;
+use alga::general::Real;
 use alga::linear::Translation;
-use math::{Point, Isometry};
-use shape::{Shape, Ball, Plane};
+use na;
+use math::{self, Point, Isometry};
+use shape::{self, Shape, Ball, Plane};
 use query::distance_internal;
 
 /// Computes the minimum distance separating two shapes.
@@ -35,3 +38,39 @@ pub fn shape_against_shape<P, M>(m1: &M, g1: &Shape<P, M>, m2: &M, g2: &Shape<P,
         panic!("No algorithm known to compute a contact point between the given pair of shapes.")
     }
 }
+
+/// Computes the minimum distance separating two shapes, checking that it does not depend on their
+/// order and that the support-mapped shapes are convex in debug mode.
+///
+/// With debug assertions enabled, this panics if a support-mapped shape fails
+/// `shape::check_support_map_convexity`, since the distance computed by GJK would be meaningless.
+/// The distance is then computed a second time with the shapes swapped, and this panics if both
+/// results differ by more than the square root of the machine epsilon, or if one of them is
+/// negative. Otherwise, this is the same as calling `distance` on the original order.
+pub fn distance_checked<P, M>(m1: &M, g1: &Shape<P, M>, m2: &M, g2: &Shape<P, M>) -> P::Real
+    where P:  Point,
+          M:  Isometry<P> {
+    if cfg!(debug_assertions) {
+        if let Some(Err(dir)) = g1.as_support_map().map(|s1| shape::check_support_map_convexity(m1, s1)) {
+            panic!("The support map of the first shape appears non-convex in direction {}.", dir)
+        }
+
+        if let Some(Err(dir)) = g2.as_support_map().map(|s2| shape::check_support_map_convexity(m2, s2)) {
+            panic!("The support map of the second shape appears non-convex in direction {}.", dir)
+        }
+    }
+
+    let res = shape_against_shape(m1, g1, m2, g2);
+
+    if cfg!(debug_assertions) {
+        let swapped = shape_against_shape(m2, g2, m1, g1);
+        let tol     = P::Real::default_epsilon().sqrt();
+
+        assert!(res >= na::zero() && swapped >= na::zero(),
+                "The distance between two shapes must not be negative: {} and {} (swapped).", res, swapped);
+        assert!(math::approx_eq(res, swapped, tol, tol),
+                "The distance between two shapes must not depend on their order: {} and {} (swapped).", res, swapped);
+    }
+
+    res
+}
@@ -6,6 +6,7 @@ use query::algorithms::gjk;
 use query::algorithms::simplex::Simplex;
 use query::algorithms::johnson_simplex::JohnsonSimplex;
 use shape::{self, SupportMap};
+use query::QuerySettings;
 use math::{Point, Isometry};
 
 
@@ -17,7 +18,8 @@ pub fn support_map_against_support_map<P, M, G1: ?Sized, G2: ?Sized>(m1: &M, g1:
           M:  Isometry<P>,
           G1: SupportMap<P, M>,
           G2: SupportMap<P, M> {
-    support_map_against_support_map_with_params(m1, g1, m2, g2, &mut JohnsonSimplex::new_w_tls(), None)
+    support_map_against_support_map_with_params(m1, g1, m2, g2, &mut JohnsonSimplex::new_w_tls(), None,
+                                                &QuerySettings::default())
 }
 
 /// Distance between support-mapped shapes.
@@ -29,7 +31,8 @@ pub fn support_map_against_support_map_with_params<P, M, S, G1: ?Sized, G2: ?Siz
                                                    m2:       &M,
                                                    g2:       &G2,
                                                    simplex:  &mut S,
-                                                   init_dir: Option<P::Vector>)
+                                                   init_dir: Option<P::Vector>,
+                                                   settings: &QuerySettings<P::Real>)
                                                    -> P::Real
     where P:  Point,
           M:  Isometry<P>,
@@ -49,5 +52,5 @@ pub fn support_map_against_support_map_with_params<P, M, S, G1: ?Sized, G2: ?Siz
 
     simplex.reset(*shape::cso_support_point(m1, g1, m2, g2, dir).point());
 
-    gjk::distance(m1, g1, m2, g2, simplex)
+    gjk::distance(m1, g1, m2, g2, simplex, settings).0
 }
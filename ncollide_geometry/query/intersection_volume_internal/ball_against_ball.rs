@@ -0,0 +1,59 @@
+use alga::general::Real;
+use na;
+use math::Point;
+use shape::Ball;
+
+/// Volume of the intersection of two balls, i.e., its area in 2D.
+///
+/// Returns `None` if the balls are neither 2- nor 3-dimensional.
+pub fn ball_against_ball<P>(center1: &P, b1: &Ball<P::Real>,
+                            center2: &P, b2: &Ball<P::Real>)
+                            -> Option<P::Real>
+    where P: Point {
+    let dim = na::dimension::<P::Vector>();
+
+    if dim != 2 && dim != 3 {
+        return None;
+    }
+
+    let r1 = b1.radius();
+    let r2 = b2.radius();
+    let d  = na::distance(center1, center2);
+    let pi = P::Real::pi();
+
+    if d >= r1 + r2 {
+        Some(na::zero())
+    }
+    else if d <= (r1 - r2).abs() {
+        // One ball contains the other.
+        let r = r1.min(r2);
+
+        if dim == 2 {
+            Some(pi * r * r)
+        }
+        else {
+            Some(na::convert::<f64, P::Real>(4.0 / 3.0) * pi * r * r * r)
+        }
+    }
+    else if dim == 2 {
+        // Two circular segments. The cosines are clamped since rounding errors may push them out
+        // of [-1, 1] when a ball is nearly inside of the other.
+        let _1: P::Real   = na::one();
+        let _2: P::Real   = na::convert(2.0f64);
+        let _0_5: P::Real = na::convert(0.5f64);
+        let a1   = ((d * d + r1 * r1 - r2 * r2) / (_2 * d * r1)).max(-_1).min(_1).acos();
+        let a2   = ((d * d + r2 * r2 - r1 * r1) / (_2 * d * r2)).max(-_1).min(_1).acos();
+        let kite = ((-d + r1 + r2) * (d + r1 - r2) * (d - r1 + r2) * (d + r1 + r2)).sqrt();
+
+        Some(r1 * r1 * a1 + r2 * r2 * a2 - _0_5 * kite)
+    }
+    else {
+        // Two spherical caps.
+        let _2: P::Real  = na::convert(2.0f64);
+        let _3: P::Real  = na::convert(3.0f64);
+        let _12: P::Real = na::convert(12.0f64);
+        let h            = r1 + r2 - d;
+
+        Some(pi * h * h * (d * d + _2 * d * (r1 + r2) - _3 * (r1 - r2) * (r1 - r2)) / (_12 * d))
+    }
+}
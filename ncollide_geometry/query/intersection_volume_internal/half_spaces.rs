@@ -0,0 +1,217 @@
+use std::cmp::Ordering;
+
+use approx::ApproxEq;
+use alga::general::Real;
+use na;
+use bounding_volume::AABB;
+use utils;
+use math::Point;
+
+/// Volume of the intersection of `region` with a set of half-spaces, i.e., its area in 2D.
+///
+/// Each half-space is given by a point on its boundary and its outward normal, i.e., it contains
+/// the points `pt` such that `na::dot(&(pt - origin), &normal) <= 0`. The intersection is
+/// computed exactly, up to rounding errors, by clipping `region` with each half-space. Returns
+/// `None` if the half-spaces are neither 2- nor 3-dimensional.
+pub fn half_spaces_intersection_volume<P>(region: &AABB<P>, half_spaces: &[(P, P::Vector)]) -> Option<P::Real>
+    where P: Point {
+    match na::dimension::<P::Vector>() {
+        2 => {
+            let mut polygon = aabb_polygon(region);
+
+            for &(ref origin, ref normal) in half_spaces.iter() {
+                polygon = clip_polygon(&polygon, origin, normal, &mut Vec::new());
+
+                if polygon.len() < 3 {
+                    return Some(na::zero());
+                }
+            }
+
+            Some(polygon_area(&polygon))
+        },
+        3 => {
+            let mut faces = aabb_faces(region);
+
+            for &(ref origin, ref normal) in half_spaces.iter() {
+                faces = clip_polyhedron(&faces, origin, normal);
+
+                if faces.len() < 4 {
+                    return Some(na::zero());
+                }
+            }
+
+            Some(polyhedron_volume(&faces))
+        },
+        _ => None
+    }
+}
+
+// The corners of a 2D AABB, counterclockwise.
+fn aabb_polygon<P: Point>(aabb: &AABB<P>) -> Vec<P> {
+    let (mins, maxs) = (aabb.mins(), aabb.maxs());
+    let corner = |x, y| {
+        let mut pt = *mins;
+        pt[0] = x;
+        pt[1] = y;
+        pt
+    };
+
+    vec![ corner(mins[0], mins[1]), corner(maxs[0], mins[1]), corner(maxs[0], maxs[1]), corner(mins[0], maxs[1]) ]
+}
+
+// The six faces of a 3D AABB.
+fn aabb_faces<P: Point>(aabb: &AABB<P>) -> Vec<Vec<P>> {
+    // The `i`-th bit of the index of a corner tells if it is on the `maxs` side along the axis `i`.
+    let corners: Vec<P> = (0 .. 8).map(|id| {
+        let mut pt = *aabb.mins();
+
+        for i in 0 .. 3 {
+            if id & (1 << i) != 0 {
+                pt[i] = aabb.maxs()[i];
+            }
+        }
+
+        pt
+    }).collect();
+
+    let faces = [ [ 0, 2, 6, 4 ], [ 1, 3, 7, 5 ], [ 0, 1, 5, 4 ], [ 2, 3, 7, 6 ], [ 0, 1, 3, 2 ], [ 4, 5, 7, 6 ] ];
+
+    faces.iter().map(|f| f.iter().map(|i| corners[*i]).collect()).collect()
+}
+
+// Sutherland-Hodgman clipping of a convex polygon by a half-space. The points created on the
+// boundary of the half-space, or already on it, are pushed to `on_boundary`.
+fn clip_polygon<P: Point>(polygon: &[P], origin: &P, normal: &P::Vector, on_boundary: &mut Vec<P>) -> Vec<P> {
+    let mut res = Vec::with_capacity(polygon.len() + 1);
+
+    for i in 0 .. polygon.len() {
+        let curr   = polygon[i];
+        let next   = polygon[(i + 1) % polygon.len()];
+        let d_curr = na::dot(&(curr - *origin), normal);
+        let d_next = na::dot(&(next - *origin), normal);
+
+        if d_curr <= na::zero() {
+            res.push(curr);
+
+            if d_curr == na::zero() {
+                on_boundary.push(curr);
+            }
+        }
+
+        if (d_curr < na::zero() && d_next > na::zero()) || (d_curr > na::zero() && d_next < na::zero()) {
+            let inter = curr + (next - curr) * (d_curr / (d_curr - d_next));
+
+            res.push(inter);
+            on_boundary.push(inter);
+        }
+    }
+
+    res
+}
+
+// Clips a convex polyhedron given by its faces by a half-space, closing it with a new face on
+// the boundary of the half-space.
+fn clip_polyhedron<P: Point>(faces: &[Vec<P>], origin: &P, normal: &P::Vector) -> Vec<Vec<P>> {
+    let mut on_boundary = Vec::new();
+    let mut res: Vec<Vec<P>> = faces.iter()
+                                    .map(|f| clip_polygon(f, origin, normal, &mut on_boundary))
+                                    .filter(|f| f.len() >= 3)
+                                    .collect();
+
+    // Remove the duplicate points, each of them is shared by two faces.
+    let eps = <P::Real as ApproxEq>::default_epsilon().sqrt();
+    let mut cap: Vec<P> = Vec::with_capacity(on_boundary.len() / 2);
+
+    for pt in on_boundary.into_iter() {
+        if !cap.iter().any(|p| na::distance_squared(p, &pt) <= eps * eps) {
+            cap.push(pt)
+        }
+    }
+
+    if cap.len() >= 3 {
+        // Sort the points by angle around their barycenter.
+        let center = barycenter(&cap);
+        let u      = any_orthogonal::<P>(normal);
+        let v      = utils::cross3(normal, &u);
+        let angle  = |pt: &P| {
+            let dpt = *pt - center;
+            na::dot(&dpt, &v).atan2(na::dot(&dpt, &u))
+        };
+
+        cap.sort_by(|a, b| angle(a).partial_cmp(&angle(b)).unwrap_or(Ordering::Equal));
+        res.retain(|f| !is_on_plane(f, origin, normal, eps));
+        res.push(cap);
+    }
+
+    res
+}
+
+// Volume of a convex polyhedron given by its faces, as a sum of pyramids with a common apex.
+fn polyhedron_volume<P: Point>(faces: &[Vec<P>]) -> P::Real {
+    let all_points: Vec<P> = faces.iter().flat_map(|f| f.iter().cloned()).collect();
+    let apex               = barycenter(&all_points);
+    let _6: P::Real        = na::convert(6.0f64);
+    let mut res            = na::zero::<P::Real>();
+
+    for face in faces.iter() {
+        // Newell's method computes twice the area times the unit normal.
+        let mut area_normal = na::zero::<P::Vector>();
+
+        for i in 0 .. face.len() {
+            let a = face[i].coordinates();
+            let b = face[(i + 1) % face.len()].coordinates();
+
+            area_normal = area_normal + utils::cross3(&a, &b);
+        }
+
+        let double_area = na::norm(&area_normal);
+
+        if double_area != na::zero() {
+            let height = na::dot(&(face[0] - apex), &area_normal).abs() / double_area;
+
+            res = res + double_area * height / _6;
+        }
+    }
+
+    res
+}
+
+fn polygon_area<P: Point>(polygon: &[P]) -> P::Real {
+    let mut res = na::zero::<P::Real>();
+
+    for i in 0 .. polygon.len() {
+        let a = &polygon[i];
+        let b = &polygon[(i + 1) % polygon.len()];
+
+        res = res + a[0] * b[1] - a[1] * b[0];
+    }
+
+    res.abs() * na::convert(0.5f64)
+}
+
+fn is_on_plane<P: Point>(face: &[P], origin: &P, normal: &P::Vector, eps: P::Real) -> bool {
+    face.iter().all(|pt| na::dot(&(*pt - *origin), normal).abs() <= eps)
+}
+
+fn barycenter<P: Point>(points: &[P]) -> P {
+    let mut res = na::zero::<P::Vector>();
+
+    for pt in points.iter() {
+        res = res + pt.coordinates();
+    }
+
+    P::from_coordinates(res / na::convert(points.len() as f64))
+}
+
+fn any_orthogonal<P: Point>(v: &P::Vector) -> P::Vector {
+    let mut axis = na::zero::<P::Vector>();
+
+    if v[0].abs() < v[1].abs() {
+        axis[0] = na::one::<P::Real>();
+    }
+    else {
+        axis[1] = na::one::<P::Real>();
+    }
+
+    na::normalize(&utils::cross3(v, &axis))
+}
@@ -0,0 +1,11 @@
+//! Implementation details of the `intersection_volume` function.
+
+pub use self::ball_against_ball::ball_against_ball;
+pub use self::half_spaces::half_spaces_intersection_volume;
+pub use self::point_query_against_point_query::{point_query_against_point_query, sampled_volume};
+pub use self::shape_against_shape::shape_against_shape as intersection_volume;
+
+mod ball_against_ball;
+mod half_spaces;
+mod point_query_against_point_query;
+mod shape_against_shape;
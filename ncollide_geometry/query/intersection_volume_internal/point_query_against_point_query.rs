@@ -0,0 +1,62 @@
+use na;
+use bounding_volume::AABB;
+use query::PointQuery;
+use math::{Point, Isometry};
+
+/// Approximates the volume of the intersection of two shapes inside of `region`.
+///
+/// The volume is estimated by testing if the centers of the cells of a regular grid subdividing
+/// `region` are inside of both shapes. The grid has `nsubdiv` cells along each dimension, so
+/// the cost of this estimation grows exponentially with the dimension. `region` should tightly
+/// bound the intersection, e.g., be the intersection of the AABBs of both shapes.
+pub fn point_query_against_point_query<P, M>(m1:      &M,
+                                             g1:      &PointQuery<P, M>,
+                                             m2:      &M,
+                                             g2:      &PointQuery<P, M>,
+                                             region:  &AABB<P>,
+                                             nsubdiv: usize)
+                                             -> P::Real
+    where P: Point,
+          M: Isometry<P> {
+    sampled_volume(region, nsubdiv, |pt| g1.contains_point(m1, pt) && g2.contains_point(m2, pt))
+}
+
+/// Approximates the volume of the points of `region` for which `inside` returns `true`.
+///
+/// The centers of the cells of a regular grid subdividing `region`, with `nsubdiv` cells along
+/// each dimension, are tested with `inside`.
+pub fn sampled_volume<P, F>(region: &AABB<P>, nsubdiv: usize, mut inside: F) -> P::Real
+    where P: Point,
+          F: FnMut(&P) -> bool {
+    assert!(nsubdiv > 0, "The sampling grid must have at least one cell along each dimension.");
+
+    let dim           = na::dimension::<P::Vector>();
+    let extents       = *region.maxs() - *region.mins();
+    let fsubdiv       = na::convert::<f64, P::Real>(nsubdiv as f64);
+    let _0_5: P::Real = na::convert(0.5f64);
+    let ncells        = (0 .. dim).fold(1usize, |n, _| n * nsubdiv);
+
+    let mut cell_volume = na::one::<P::Real>();
+    for i in 0 .. dim {
+        cell_volume = cell_volume * extents[i] / fsubdiv;
+    }
+
+    let mut ninside = 0usize;
+
+    for cell in 0 .. ncells {
+        let mut pt   = *region.mins();
+        let mut rest = cell;
+
+        for i in 0 .. dim {
+            let k = na::convert::<f64, P::Real>((rest % nsubdiv) as f64);
+            pt[i] = pt[i] + (k + _0_5) * extents[i] / fsubdiv;
+            rest  = rest / nsubdiv;
+        }
+
+        if inside(&pt) {
+            ninside = ninside + 1;
+        }
+    }
+
+    na::convert::<f64, P::Real>(ninside as f64) * cell_volume
+}
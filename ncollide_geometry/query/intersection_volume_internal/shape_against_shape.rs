@@ -0,0 +1,171 @@
+use approx::ApproxEq;
+use alga::general::Real;
+use alga::linear::Translation;
+use na::{self, Unit};
+use bounding_volume::{BoundingVolume, AABB};
+use shape::{Shape, Ball, Cuboid, ConvexHull, ConvexPolygon, Triangle};
+use query::{intersection_volume_internal, point_internal};
+use utils;
+use math::{Point, Isometry};
+
+/// Computes the volume of the intersection of two shapes, i.e., its area in 2D.
+///
+/// The result is exact, up to rounding errors, for two balls, and for two convex polytopes
+/// described by a set of half-spaces, i.e., cuboids, triangles, convex polygons and convex hulls
+/// in 2D, and cuboids and convex hulls in 3D. Otherwise, the volume is approximated by sampling a
+/// regular grid on the intersection of the AABBs of both shapes. Closed 3D triangle meshes and
+/// 2D polylines are then considered as solids, see `point_internal::shape_contains_point`.
+/// Returns `None` if a shape is not handled by those methods, i.e., does not support point
+/// queries.
+pub fn shape_against_shape<P, M>(m1: &M, g1: &Shape<P, M>, m2: &M, g2: &Shape<P, M>) -> Option<P::Real>
+    where P: Point,
+          M: Isometry<P> {
+    let aabb1 = g1.aabb(m1);
+    let aabb2 = g2.aabb(m2);
+
+    if !aabb1.intersects(&aabb2) {
+        return Some(na::zero());
+    }
+
+    let region = AABB::new(na::sup(aabb1.mins(), aabb2.mins()), na::inf(aabb1.maxs(), aabb2.maxs()));
+
+    if let (Some(b1), Some(b2)) = (g1.as_shape::<Ball<P::Real>>(), g2.as_shape::<Ball<P::Real>>()) {
+        let p1 = P::from_coordinates(m1.translation().to_vector());
+        let p2 = P::from_coordinates(m2.translation().to_vector());
+
+        if let Some(volume) = intersection_volume_internal::ball_against_ball(&p1, b1, &p2, b2) {
+            return Some(volume);
+        }
+    }
+
+    if let (Some(mut hs1), Some(hs2)) = (half_spaces(m1, g1), half_spaces(m2, g2)) {
+        hs1.extend(hs2.into_iter());
+
+        if let Some(volume) = intersection_volume_internal::half_spaces_intersection_volume(&region, &hs1) {
+            return Some(volume);
+        }
+    }
+
+    // The shapes supporting the inside test do not depend on the tested point.
+    let center = region.center();
+
+    if point_internal::shape_contains_point(m1, g1, &center).is_none() ||
+       point_internal::shape_contains_point(m2, g2, &center).is_none() {
+        return None;
+    }
+
+    let nsubdiv = match na::dimension::<P::Vector>() {
+        2 => 128,
+        3 => 32,
+        _ => 8
+    };
+
+    Some(intersection_volume_internal::sampled_volume(&region, nsubdiv, |pt| {
+        point_internal::shape_contains_point(m1, g1, pt) == Some(true) &&
+        point_internal::shape_contains_point(m2, g2, pt) == Some(true)
+    }))
+}
+
+// The half-spaces, with their outward normals, the intersection of which is the given shape.
+fn half_spaces<P, M>(m: &M, g: &Shape<P, M>) -> Option<Vec<(P, P::Vector)>>
+    where P: Point,
+          M: Isometry<P> {
+    let dim = na::dimension::<P::Vector>();
+
+    let local = if let Some(c) = g.as_shape::<Cuboid<P::Vector>>() {
+        let mut res = Vec::with_capacity(2 * dim);
+
+        for i in 0 .. dim {
+            let mut normal = na::zero::<P::Vector>();
+            normal[i] = na::one::<P::Real>();
+
+            res.push((P::from_coordinates(normal * c.half_extents()[i]), normal));
+            res.push((P::from_coordinates(-normal * c.half_extents()[i]), -normal));
+        }
+
+        res
+    }
+    else if dim == 2 {
+        if let Some(p) = g.as_shape::<ConvexPolygon<P>>() {
+            polygon_half_spaces(p)
+        }
+        else if let Some(t) = g.as_shape::<Triangle<P>>() {
+            match ConvexPolygon::try_from_points(&[ *t.a(), *t.b(), *t.c() ]) {
+                Some(p) => polygon_half_spaces(&p),
+                None    => point_half_spaces(t.a())
+            }
+        }
+        else if let Some(h) = g.as_shape::<ConvexHull<P>>() {
+            match ConvexPolygon::try_from_points(h.points()) {
+                Some(p) => polygon_half_spaces(&p),
+                None    => point_half_spaces(&h.points()[0])
+            }
+        }
+        else {
+            return None;
+        }
+    }
+    else if dim == 3 {
+        if let Some(h) = g.as_shape::<ConvexHull<P>>() {
+            polyhedron_half_spaces(h.points())
+        }
+        else {
+            return None;
+        }
+    }
+    else {
+        return None;
+    };
+
+    Some(local.into_iter().map(|(pt, n)| (m.transform_point(&pt), m.rotate_vector(&n))).collect())
+}
+
+fn polygon_half_spaces<P: Point>(polygon: &ConvexPolygon<P>) -> Vec<(P, P::Vector)> {
+    (0 .. polygon.points().len()).map(|i| (polygon.points()[i], polygon.edge_normal(i))).collect()
+}
+
+// The half-spaces of a degenerate shape with a single point.
+fn point_half_spaces<P: Point>(pt: &P) -> Vec<(P, P::Vector)> {
+    let mut res = Vec::new();
+
+    for i in 0 .. na::dimension::<P::Vector>() {
+        let mut normal = na::zero::<P::Vector>();
+        normal[i] = na::one::<P::Real>();
+
+        res.push((*pt, normal));
+        res.push((*pt, -normal));
+    }
+
+    res
+}
+
+// The supporting planes of the faces of the convex hull of a 3D point cloud. The coplanar
+// triangles of the hull give a single half-space.
+fn polyhedron_half_spaces<P: Point>(points: &[P]) -> Vec<(P, P::Vector)> {
+    let faces = utils::point_cloud_hull_faces(points);
+
+    if faces.is_empty() {
+        // The hull is flat: its volume is zero.
+        return match points.first() {
+            Some(pt) => point_half_spaces(pt),
+            None     => Vec::new()
+        };
+    }
+
+    let eps = <P::Real as ApproxEq>::default_epsilon().sqrt();
+    let mut res: Vec<(P, P::Vector)> = Vec::with_capacity(faces.len());
+
+    for f in faces.iter() {
+        let a = points[f.x];
+
+        if let Some(normal) = Unit::try_new(utils::cross3(&(points[f.y] - a), &(points[f.z] - a)), na::zero()) {
+            let normal = normal.unwrap();
+
+            if !res.iter().any(|&(_, ref other)| na::dot(other, &normal) >= na::one::<P::Real>() - eps) {
+                res.push((a, normal))
+            }
+        }
+    }
+
+    res
+}
@@ -1,29 +1,51 @@
 //! Non-persistant pairwise geometric queries.
 
 #[doc(inline)]
-pub use self::contacts_internal::Contact;
+pub use self::query_settings::QuerySettings;
+#[doc(inline)]
+pub use self::contacts_internal::{Contact, LocalContact};
 #[doc(inline)]
 pub use self::contacts_internal::contact_internal as contact;
 #[doc(inline)]
+pub use self::contacts_internal::contact_local_internal as contact_local;
+#[doc(inline)]
+pub use self::contact_manifold::{PersistentManifold, TrackedContact, FeatureMatching};
+#[doc(inline)]
 pub use self::proximity_internal::Proximity;
 #[doc(inline)]
 pub use self::proximity_internal::proximity_internal as proximity;
 #[doc(inline)]
-pub use self::distance_internal::distance;
+pub use self::closest_points_internal::ClosestPoints;
+#[doc(inline)]
+pub use self::closest_points_internal::closest_points_internal as closest_points;
+#[doc(inline)]
+pub use self::closest_points_internal::segment_segment_closest;
+#[doc(inline)]
+pub use self::distance_internal::{distance, distance_checked};
+#[doc(inline)]
+pub use self::intersection_volume_internal::intersection_volume;
 #[doc(inline)]
-pub use self::time_of_impact_internal::time_of_impact;
+pub use self::time_of_impact_internal::{time_of_impact, time_of_impact_with_settings, shape_cast,
+                                        shape_cast_with_settings, bvt_time_of_impact, bvt_shape_cast, TOIResult,
+                                        TOIStatus, nonlinear_time_of_impact, RigidMotion, ConstantVelocityMotion};
 #[doc(inline)]
 pub use self::ray_internal::{Ray, Ray2, Ray3,
                              RayIntersection, RayIntersection2, RayIntersection3,
                              RayCast, RayInterferencesCollector,
                              RayIntersectionCostFn};
 #[doc(inline)]
-pub use self::point_internal::{PointProjection, PointQuery, PointInterferencesCollector, RichPointQuery};
+pub use self::point_internal::{PointProjection, PointQuery, PointInterferencesCollector, RichPointQuery,
+                               PointProjectionCostFn, bvt_project_point, signed_distance,
+                               SignedDistanceField};
 
 pub mod algorithms;
 pub mod contacts_internal;
+pub mod contact_manifold;
 pub mod distance_internal;
+pub mod closest_points_internal;
 pub mod proximity_internal;
+pub mod intersection_volume_internal;
 pub mod time_of_impact_internal;
 pub mod ray_internal;
 pub mod point_internal;
+mod query_settings;
@@ -2,19 +2,31 @@
 
 #[doc(inline)]
 pub use self::point_query::{PointQuery, PointProjection, RichPointQuery};
-pub use self::point_bvt::PointInterferencesCollector;
+pub use self::point_bvt::{PointInterferencesCollector, PointProjectionCostFn, bvt_project_point};
+pub use self::point_mesh::PointProjectionInfo;
+pub use self::point_composite_shape::{composite_shape_project_point, composite_shape_project_point_with_part,
+                                      composite_shape_contains_point};
+pub use self::point_shape::shape_contains_point;
+pub use self::signed_distance_field::{signed_distance, SignedDistanceField};
 
 #[doc(hidden)]
 pub mod point_query;
 mod point_plane;
 mod point_ball;
 mod point_cuboid;
+mod point_capsule;
+mod point_convex_polygon;
 mod point_aabb;
 mod point_bounding_sphere;
 mod point_support_map;
 mod point_segment;
 mod point_triangle;
+mod point_composite_shape;
 mod point_compound;
 mod point_mesh;
+mod point_heightfield;
+mod point_round_shape;
+mod point_with_local_pose;
 mod point_shape;
 mod point_bvt;
+mod signed_distance_field;
@@ -2,6 +2,7 @@ use num::{Bounded, Zero};
 use na;
 use query::{PointQuery, PointProjection};
 use bounding_volume::AABB;
+use shape::FeatureId;
 use math::{Point, Isometry};
 
 impl<P: Point, M: Isometry<P>> PointQuery<P, M> for AABB<P> {
@@ -15,12 +16,30 @@ impl<P: Point, M: Isometry<P>> PointQuery<P, M> for AABB<P> {
         let inside = shift == na::zero();
 
         if !inside || solid {
-            PointProjection::new(inside, *pt + m.rotate_vector(&shift))
+            // The point lies on the sides of the AABB it is not strictly between.
+            let mut fixed = 0;
+            let mut signs = 0;
+
+            for i in 0 .. na::dimension::<P::Vector>() {
+                if mins_pt[i] >= na::zero() {
+                    fixed |= 1 << i;
+
+                    if self.mins()[i] != self.maxs()[i] {
+                        signs |= 1 << i;
+                    }
+                }
+                else if pt_maxs[i] >= na::zero() {
+                    fixed |= 1 << i;
+                }
+            }
+
+            PointProjection::new_with_feature(inside, *pt + m.rotate_vector(&shift), feature_id::<P>(fixed, signs))
         }
         else {
             let _max: P::Real = Bounded::max_value();
-            let mut best    = -_max;
-            let mut best_id = 0isize;
+            let mut best         = -_max;
+            let mut best_axis    = 0;
+            let mut best_on_mins = false;
 
             for i in 0 .. na::dimension::<P::Vector>() {
                 let mins_pt_i = mins_pt[i];
@@ -28,26 +47,33 @@ impl<P: Point, M: Isometry<P>> PointQuery<P, M> for AABB<P> {
 
                 if mins_pt_i < pt_maxs_i {
                     if pt_maxs[i] > best {
-                        best_id = i as isize;
-                        best    = pt_maxs_i
+                        best_axis    = i;
+                        best_on_mins = false;
+                        best         = pt_maxs_i
                     }
                 }
                 else if mins_pt_i > best {
-                    best_id = -(i as isize);
-                    best    = mins_pt_i
+                    best_axis    = i;
+                    best_on_mins = true;
+                    best         = mins_pt_i
                 }
             }
 
             let mut shift: P::Vector = na::zero();
+            let fixed = 1 << best_axis;
+            let signs;
 
-            if best_id < 0 {
-                shift[(-best_id) as usize] = best;
+            if best_on_mins {
+                shift[best_axis] = best;
+                // The side of a flat AABB is meaningless.
+                signs = if self.mins()[best_axis] != self.maxs()[best_axis] { fixed } else { 0 };
             }
             else {
-                shift[best_id as usize] = -best;
+                shift[best_axis] = -best;
+                signs = 0;
             }
 
-            PointProjection::new(inside, *pt + m.rotate_vector(&shift))
+            PointProjection::new_with_feature(inside, *pt + m.rotate_vector(&shift), feature_id::<P>(fixed, signs))
         }
     }
 
@@ -80,3 +106,20 @@ impl<P: Point, M: Isometry<P>> PointQuery<P, M> for AABB<P> {
         true
     }
 }
+
+// The feature of an AABB lying on the sides of the axes in `fixed`.
+//
+// The identifiers follow the convention of `Cuboid::support_feature_id`: an axis bit of `signs` is
+// set if the feature lies on the side of `mins`.
+fn feature_id<P: Point>(fixed: usize, signs: usize) -> FeatureId {
+    let dim  = na::dimension::<P::Vector>();
+    let free = !fixed & ((1 << dim) - 1);
+
+    match free.count_ones() as usize {
+        0                 => FeatureId::Vertex(signs),
+        n if n == dim     => FeatureId::Unknown,
+        n if n == dim - 1 => FeatureId::Face((free << dim) | signs),
+        1                 => FeatureId::Edge((free << dim) | signs),
+        _                 => FeatureId::Unknown
+    }
+}
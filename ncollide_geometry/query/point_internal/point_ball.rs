@@ -2,7 +2,7 @@ use alga::general::Real;
 use na;
 
 use query::{PointQuery, PointProjection};
-use shape::Ball;
+use shape::{Ball, FeatureId};
 use math::{Point, Isometry};
 
 impl<P: Point, M: Isometry<P>> PointQuery<P, M> for Ball<P::Real> {
@@ -17,9 +17,9 @@ impl<P: Point, M: Isometry<P>> PointQuery<P, M> for Ball<P::Real> {
             PointProjection::new(true, *pt)
         }
         else {
-            let ls_proj = P::origin() + ls_pt.coordinates() / distance_squared.sqrt();
+            let ls_proj = P::origin() + ls_pt.coordinates() * (self.radius() / distance_squared.sqrt());
 
-            PointProjection::new(inside, m.translate_point(&ls_proj))
+            PointProjection::new_with_feature(inside, m.translate_point(&ls_proj), FeatureId::Face(0))
         }
     }
 
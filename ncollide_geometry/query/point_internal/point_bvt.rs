@@ -1,9 +1,65 @@
 use alga::general::Id;
-use partitioning::BVTVisitor;
-use query::PointQuery;
+use na;
+use partitioning::{BVT, BVTCostFn, BVTVisitor};
+use query::{PointQuery, PointProjection};
 use math::Point;
 
-// FIXME: add a point cost fn.
+/// Projects a point on the closest object of a scene stored in a bounding volume tree.
+///
+/// The leaves of `bvt` are visited by increasing distance from `point` to their bounding volumes,
+/// and `project` is called to compute the projection of `point` on the object identified by each
+/// candidate leaf. The subtrees with a bounding volume farther than the best projection found so
+/// far are skipped. Thus, the bounding volumes must enclose the objects they are associated with.
+///
+/// Returns the leaf of the closest object and the projection of `point` on it, or `None` if the
+/// tree is empty.
+pub fn bvt_project_point<'a, P, B, BV, F>(bvt: &'a BVT<B, BV>, point: &P, project: F)
+                                          -> Option<(&'a B, PointProjection<P>)>
+    where P:  Point,
+          BV: PointQuery<P, Id>,
+          F:  FnMut(&B) -> PointProjection<P> {
+    let mut cost_fn = PointProjectionCostFn::new(point, project);
+
+    bvt.best_first_search(&mut cost_fn)
+}
+
+/// A search that selects the object closest to a given point.
+///
+/// The distance to an object is the distance from the point to its projection computed by a
+/// user-defined closure. This is the search performed by `bvt_project_point`.
+pub struct PointProjectionCostFn<'a, P: 'a, F> {
+    point:   &'a P,
+    project: F
+}
+
+impl<'a, P, F> PointProjectionCostFn<'a, P, F> {
+    /// Creates a new search of the object closest to `point`, projected with `project`.
+    pub fn new(point: &'a P, project: F) -> PointProjectionCostFn<'a, P, F> {
+        PointProjectionCostFn {
+            point:   point,
+            project: project
+        }
+    }
+}
+
+impl<'a, P, B, BV, F> BVTCostFn<P::Real, B, BV> for PointProjectionCostFn<'a, P, F>
+    where P:  Point,
+          BV: PointQuery<P, Id>,
+          F:  FnMut(&B) -> PointProjection<P> {
+    type UserData = PointProjection<P>;
+
+    #[inline]
+    fn compute_bv_cost(&mut self, bv: &BV) -> Option<P::Real> {
+        Some(bv.distance_to_point(&Id::new(), self.point, true))
+    }
+
+    #[inline]
+    fn compute_b_cost(&mut self, b: &B) -> Option<(P::Real, PointProjection<P>)> {
+        let proj = (self.project)(b);
+
+        Some((na::distance(self.point, &proj.point), proj))
+    }
+}
 
 /// Bounding Volume Tree visitor collecting nodes that may contain a given point.
 pub struct PointInterferencesCollector<'a, P: 'a, B: 'a> {
@@ -0,0 +1,54 @@
+use num::Zero;
+use alga::general::Real;
+use na;
+
+use query::{PointQuery, PointProjection};
+use shape::{Capsule, FeatureId};
+use math::{Point, Isometry};
+
+impl<P: Point, M: Isometry<P>> PointQuery<P, M> for Capsule<P::Real> {
+    /// Projects a point on this capsule.
+    ///
+    /// The feature of the projection is `FeatureId::Face(0)` for the cylindrical part,
+    /// `FeatureId::Face(1)` for the bottom half-ball and `FeatureId::Face(2)` for the top
+    /// half-ball, as for the faces of a `Cylinder`.
+    #[inline]
+    fn project_point(&self, m: &M, pt: &P, solid: bool) -> PointProjection<P> {
+        let ls_pt = m.inverse_transform_point(pt);
+        let h     = self.half_height();
+        let y     = ls_pt[1].max(-h).min(h);
+
+        let mut closest = P::origin();
+        closest[1] = y;
+
+        let dpt    = ls_pt - closest;
+        let dist   = na::norm(&dpt);
+        let inside = dist <= self.radius();
+
+        if inside && solid {
+            return PointProjection::new(true, *pt);
+        }
+
+        let dir = if dist.is_zero() {
+            // The point is on the axis: any direction orthogonal to it is valid.
+            let mut dir = na::zero::<P::Vector>();
+            dir[0] = na::one();
+            dir
+        }
+        else {
+            dpt / dist
+        };
+
+        let feature = if ls_pt[1] <= -h {
+            FeatureId::Face(1)
+        }
+        else if ls_pt[1] >= h {
+            FeatureId::Face(2)
+        }
+        else {
+            FeatureId::Face(0)
+        };
+
+        PointProjection::new_with_feature(inside, m.transform_point(&(closest + dir * self.radius())), feature)
+    }
+}
@@ -0,0 +1,118 @@
+use std::marker::PhantomData;
+
+use alga::general::Id;
+use na;
+use query::{PointQuery, PointProjection};
+use bounding_volume::AABB;
+use shape::CompositeShape;
+use partitioning::{BVTCostFn, BVTVisitor};
+use math::{Point, Isometry};
+
+
+/// Projects a point on a transformed composite shape.
+///
+/// If `solid` is `false`, this might return a projection on the boundary of a sub-shape lying
+/// inside of another one.
+pub fn composite_shape_project_point<P, M, G: ?Sized>(m: &M, g: &G, point: &P, solid: bool) -> PointProjection<P>
+    where P: Point,
+          M: Isometry<P>,
+          G: CompositeShape<P, M> {
+    composite_shape_project_point_with_part(m, g, point, solid).0
+}
+
+/// Projects a point on a transformed composite shape, also returning the index of the part the
+/// point is projected on.
+///
+/// The feature of the projection is the feature of this part, as identified by its own shape.
+pub fn composite_shape_project_point_with_part<P, M, G: ?Sized>(m: &M, g: &G, point: &P, solid: bool)
+                                                                -> (PointProjection<P>, usize)
+    where P: Point,
+          M: Isometry<P>,
+          G: CompositeShape<P, M> {
+    let ls_pt = m.inverse_transform_point(point);
+    let mut cost_fn = CompositeShapePointProjCostFn { shape: g, point: &ls_pt, solid: solid, _marker: PhantomData };
+
+    let (mut proj, part) = g.bvt().best_first_search(&mut cost_fn).unwrap().1;
+    proj.point = m.transform_point(&proj.point);
+
+    (proj, part)
+}
+
+/// Tests whether a transformed composite shape contains a point.
+pub fn composite_shape_contains_point<P, M, G: ?Sized>(m: &M, g: &G, point: &P) -> bool
+    where P: Point,
+          M: Isometry<P>,
+          G: CompositeShape<P, M> {
+    let ls_pt = m.inverse_transform_point(point);
+    let mut test = PointContainementTest { shape: g, point: &ls_pt, found: false, _marker: PhantomData };
+
+    g.bvt().visit(&mut test);
+
+    test.found
+}
+
+
+/*
+ * Costs function.
+ */
+struct CompositeShapePointProjCostFn<'a, P: 'a + Point, M, G: ?Sized + 'a> {
+    shape:   &'a G,
+    point:   &'a P,
+    solid:   bool,
+    _marker: PhantomData<M>
+}
+
+impl<'a, P, M, G: ?Sized> BVTCostFn<P::Real, usize, AABB<P>> for CompositeShapePointProjCostFn<'a, P, M, G>
+    where P: Point,
+          M: Isometry<P>,
+          G: CompositeShape<P, M> {
+    type UserData = (PointProjection<P>, usize);
+
+    #[inline]
+    fn compute_bv_cost(&mut self, aabb: &AABB<P>) -> Option<P::Real> {
+        Some(aabb.distance_to_point(&Id::new(), self.point, true))
+    }
+
+    #[inline]
+    fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, (PointProjection<P>, usize))> {
+        let mut res = None;
+
+        self.shape.map_part_at(*b, &mut |objm, obj| {
+            let proj = obj.project_point(objm, self.point, self.solid);
+
+            res = Some((na::distance(self.point, &proj.point), (proj, *b)));
+        });
+
+        res
+    }
+}
+
+/*
+ * Visitor.
+ */
+/// Bounding Volume Tree visitor collecting nodes that may contain a given point.
+struct PointContainementTest<'a, P: 'a + Point, M, G: ?Sized + 'a> {
+    shape:   &'a G,
+    point:   &'a P,
+    found:   bool,
+    _marker: PhantomData<M>
+}
+
+impl<'a, P, M, G: ?Sized> BVTVisitor<usize, AABB<P>> for PointContainementTest<'a, P, M, G>
+    where P: Point,
+          M: Isometry<P>,
+          G: CompositeShape<P, M> {
+    #[inline]
+    fn visit_internal(&mut self, bv: &AABB<P>) -> bool {
+        !self.found && bv.contains_point(&Id::new(), self.point)
+    }
+
+    #[inline]
+    fn visit_leaf(&mut self, b: &usize, bv: &AABB<P>) {
+        if !self.found && bv.contains_point(&Id::new(), self.point) {
+            self.shape.map_part_at(*b, &mut |objm, obj| {
+                self.found = obj.contains_point(objm, self.point)
+            })
+        }
+    }
+}
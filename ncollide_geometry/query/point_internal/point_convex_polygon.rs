@@ -0,0 +1,63 @@
+use na;
+use shape::{ConvexPolygon, FeatureId};
+use query::{PointQuery, PointProjection};
+use math::{Point, Isometry};
+
+
+impl<P: Point, M: Isometry<P>> PointQuery<P, M> for ConvexPolygon<P> {
+    fn project_point(&self, m: &M, pt: &P, solid: bool) -> PointProjection<P> {
+        let ls_pt = m.inverse_transform_point(pt);
+
+        if solid && self.contains_local_point(&ls_pt) {
+            return PointProjection::new(true, *pt);
+        }
+
+        // Project on the closest edge.
+        let mut best_sqdist  = na::zero();
+        let mut best_proj    = ls_pt;
+        let mut best_feature = FeatureId::Unknown;
+
+        for i in 0 .. self.points().len() {
+            let (a, b) = self.edge(i);
+            let ab     = *b - *a;
+            let t      = na::clamp(na::dot(&ab, &(ls_pt - *a)) / na::norm_squared(&ab), na::zero(), na::one());
+            let proj   = *a + ab * t;
+            let sqdist = na::distance_squared(&proj, &ls_pt);
+
+            if i == 0 || sqdist < best_sqdist {
+                best_sqdist  = sqdist;
+                best_proj    = proj;
+                // The `i`-th vertex is the first endpoint of the `i`-th edge.
+                best_feature = if t == na::zero() {
+                    FeatureId::Vertex(i)
+                }
+                else if t == na::one() {
+                    FeatureId::Vertex((i + 1) % self.points().len())
+                }
+                else {
+                    FeatureId::Edge(i)
+                };
+            }
+        }
+
+        PointProjection::new_with_feature(self.contains_local_point(&ls_pt), m.transform_point(&best_proj), best_feature)
+    }
+
+    #[inline]
+    fn contains_point(&self, m: &M, pt: &P) -> bool {
+        self.contains_local_point(&m.inverse_transform_point(pt))
+    }
+}
+
+impl<P: Point> ConvexPolygon<P> {
+    // A point is inside of the polygon iff it is on the left of each edge.
+    fn contains_local_point(&self, pt: &P) -> bool {
+        (0 .. self.points().len()).all(|i| {
+            let (a, b) = self.edge(i);
+            let ab     = *b - *a;
+            let ap     = *pt - *a;
+
+            ab[0] * ap[1] - ab[1] * ap[0] >= na::zero()
+        })
+    }
+}
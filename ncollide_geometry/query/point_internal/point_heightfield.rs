@@ -0,0 +1,62 @@
+use alga::general::{Id, Real};
+use na;
+use query::{PointQuery, PointProjection};
+use shape::HeightField;
+use bounding_volume::AABB;
+use math::{Point, Isometry};
+
+
+impl<P: Point, M: Isometry<P>> PointQuery<P, M> for HeightField<P::Real> {
+    /// Projects a point on the closest triangle of this heightfield.
+    ///
+    /// As for triangle meshes, a heightfield is a surface: a point is inside of it only if it
+    /// lies on one of its triangles, whatever the value of `solid`. The feature of the projection
+    /// is `FeatureId::Face(id)` for the interior of the triangle with the identifier `id`,
+    /// `FeatureId::Edge(3 * id + k)` for its `k`-th edge, and `FeatureId::Vertex(i * ncols + j)`
+    /// for the grid node at the `i`-th row and `j`-th column. Panics if `P` is not 3-dimensional.
+    fn project_point(&self, m: &M, pt: &P, solid: bool) -> PointProjection<P> {
+        assert!(na::dimension::<P::Vector>() == 3, "The point projection on a heightfield is only defined in 3D.");
+
+        let ls_pt = m.inverse_transform_point(pt);
+
+        // The closest grid node gives an upper bound of the distance to the surface. Only the
+        // cells intersecting the AABB of the ball with this radius have to be tested.
+        let radius   = na::distance(&ls_pt, &closest_node(self, &ls_pt));
+        let mut mins = ls_pt;
+        let mut maxs = ls_pt;
+
+        for i in 0 .. 3 {
+            mins[i] = mins[i] - radius;
+            maxs[i] = maxs[i] + radius;
+        }
+
+        let best = self.closest_triangle_in_local_aabb(&AABB::new(mins, maxs), |_, triangle| {
+            let proj = triangle.project_point(&Id::new(), &ls_pt, solid);
+
+            Some((na::distance(&ls_pt, &proj.point), proj))
+        });
+
+        let (id, _, mut proj) = best.expect("The closest node of a heightfield must lie on one of its cells.");
+        proj.feature = self.triangle_feature_id(id, proj.feature);
+        proj.point   = m.transform_point(&proj.point);
+
+        proj
+    }
+}
+
+// The grid node of `hf` closest to the vertical line through the 3D point `pt`.
+fn closest_node<P: Point>(hf: &HeightField<P::Real>, pt: &P) -> P {
+    let _0_5: P::Real = na::convert(0.5f64);
+
+    let closest_index = |coord: P::Real, extent: P::Real, num_cells: usize| {
+        let num_cells_n: P::Real = na::convert(num_cells as f64);
+        let index = ((coord / extent + _0_5) * num_cells_n).max(na::zero()).min(num_cells_n);
+
+        na::try_convert::<P::Real, f64>(index).unwrap().round() as usize
+    };
+
+    let i = closest_index(pt[2], hf.scale().z, hf.num_cells_z());
+    let j = closest_index(pt[0], hf.scale().x, hf.num_cells_x());
+
+    hf.node_at(i, j)
+}
@@ -1,7 +1,7 @@
 use alga::general::Id;
 use na;
 use query::{PointQuery, PointProjection, RichPointQuery};
-use shape::{BaseMesh, BaseMeshElement, Segment, TriMesh, Polyline};
+use shape::{BaseMesh, BaseMeshElement, Segment, Triangle, TriMesh, Polyline, FeatureId};
 use bounding_volume::AABB;
 use partitioning::{BVTCostFn, BVTVisitor};
 use math::{Point, Isometry};
@@ -42,7 +42,37 @@ impl<P, M, I, E> RichPointQuery<P, M> for BaseMesh<P, I, E>
         let ls_pt = m.inverse_transform_point(point);
         let mut cost_fn = BaseMeshPointProjCostFn { mesh: self, point: &ls_pt };
 
-        let (mut proj, extra_info) = self.bvt().best_first_search(&mut cost_fn).unwrap().1;
+        let (mut proj, mut extra_info) = self.bvt().best_first_search(&mut cost_fn).unwrap().1;
+
+        // Points projecting on a feature shared by several elements (e.g. the common edge of two
+        // triangles) are consistently attributed to the element with the smallest index. Only
+        // edges and vertices can be shared, so the interior of a face needs no second traversal.
+        let shared = match proj.feature {
+            FeatureId::Face(_) => false,
+            _                  => true
+        };
+
+        if shared {
+            let mut tie_break = SmallestIndexTieBreak {
+                mesh:     self,
+                point:    &ls_pt,
+                distance: na::distance(&ls_pt, &proj.point),
+                best:     None
+            };
+
+            self.bvt().visit(&mut tie_break);
+
+            if let Some((index, tied_proj, tied_extra_info)) = tie_break.best {
+                if index < extra_info.element_index {
+                    proj = tied_proj;
+                    extra_info = PointProjectionInfo {
+                        element_index:           index,
+                        barycentric_coordinates: tied_extra_info
+                    };
+                }
+            }
+        }
+
         proj.point = m.transform_point(&proj.point);
 
         (proj, extra_info)
@@ -84,8 +114,55 @@ impl<'a, P, I, E> BVTCostFn<P::Real, usize, AABB<P>> for BaseMeshPointProjCostFn
 }
 
 /*
- * Visitor.
+ * Visitors.
  */
+/// Bounding Volume Tree visitor finding the element with the smallest index among those at a given
+/// distance from a point.
+struct SmallestIndexTieBreak<'a, P: 'a + Point, I: 'a, E: 'a + RichPointQuery<P, Id>> {
+    mesh:     &'a BaseMesh<P, I, E>,
+    point:    &'a P,
+    distance: P::Real,
+    best:     Option<(usize, PointProjection<P>, E::ExtraInfo)>
+}
+
+impl<'a, P, I, E> SmallestIndexTieBreak<'a, P, I, E>
+    where P: Point,
+          E: BaseMeshElement<I, P> + RichPointQuery<P, Id> {
+    #[inline]
+    fn is_tied(&self, distance: P::Real) -> bool {
+        distance <= self.distance || relative_eq!(distance, self.distance)
+    }
+}
+
+impl<'a, P, I, E> BVTVisitor<usize, AABB<P>> for SmallestIndexTieBreak<'a, P, I, E>
+    where P: Point,
+          E: BaseMeshElement<I, P> + RichPointQuery<P, Id> {
+    #[inline]
+    fn visit_internal(&mut self, bv: &AABB<P>) -> bool {
+        let distance = bv.distance_to_point(&Id::new(), self.point, true);
+        self.is_tied(distance)
+    }
+
+    #[inline]
+    fn visit_leaf(&mut self, b: &usize, bv: &AABB<P>) {
+        if let Some((best, _, _)) = self.best {
+            if best < *b {
+                return;
+            }
+        }
+
+        if self.is_tied(bv.distance_to_point(&Id::new(), self.point, true)) {
+            let (proj, extra_info) = self.mesh
+                .element_at(*b)
+                .project_point_with_extra_info(&Id::new(), self.point, true);
+
+            if self.is_tied(na::distance(self.point, &proj.point)) {
+                self.best = Some((*b, proj, extra_info));
+            }
+        }
+    }
+}
+
 /// Bounding Volume Tree visitor collecting nodes that may contain a given point.
 struct PointContainementTest<'a, P: 'a + Point, I: 'a, E: 'a> {
     mesh:  &'a BaseMesh<P, I, E>,
@@ -134,7 +211,8 @@ pub struct PointProjectionInfo<C> {
 impl<P: Point, M: Isometry<P>> PointQuery<P, M> for TriMesh<P> {
     #[inline]
     fn project_point(&self, m: &M, point: &P, solid: bool) -> PointProjection<P> {
-        self.base_mesh().project_point(m, point, solid)
+        let (projection, _) = self.project_point_with_extra_info(m, point, solid);
+        projection
     }
 
     #[inline]
@@ -148,6 +226,32 @@ impl<P: Point, M: Isometry<P>> PointQuery<P, M> for TriMesh<P> {
     }
 }
 
+impl<P: Point, M: Isometry<P>> RichPointQuery<P, M> for TriMesh<P> {
+    type ExtraInfo = PointProjectionInfo<<Triangle<P> as RichPointQuery<P, M>>::ExtraInfo>;
+
+    /// Projects a point on this mesh, also returning the index of the closest triangle.
+    ///
+    /// The triangles are traversed in best-first order using the mesh BVT. The feature of the
+    /// projection is `FeatureId::Face(i)` for the interior of the `i`-th triangle,
+    /// `FeatureId::Vertex(j)` for the `j`-th vertex of the mesh, and `FeatureId::Edge(3 * i + k)`
+    /// for the `k`-th edge of the `i`-th triangle, as identified by `Triangle::support_feature_id`.
+    #[inline]
+    fn project_point_with_extra_info(&self, m: &M, point: &P, solid: bool)
+        -> (PointProjection<P>, Self::ExtraInfo)
+    {
+        let (mut proj, extra_info) = self.base_mesh().project_point_with_extra_info(m, point, solid);
+        let i = extra_info.element_index;
+
+        proj.feature = match proj.feature {
+            FeatureId::Vertex(k) => FeatureId::Vertex(self.indices()[i][k]),
+            FeatureId::Edge(k)   => FeatureId::Edge(3 * i + k),
+            FeatureId::Face(_)   => FeatureId::Face(i),
+            FeatureId::Unknown   => FeatureId::Unknown
+        };
+
+        (proj, extra_info)
+    }
+}
 
 impl<P: Point, M: Isometry<P>> PointQuery<P, M> for Polyline<P> {
     #[inline]
@@ -170,10 +274,23 @@ impl<P: Point, M: Isometry<P>> PointQuery<P, M> for Polyline<P> {
 impl<P: Point, M: Isometry<P>> RichPointQuery<P, M> for Polyline<P> {
     type ExtraInfo = PointProjectionInfo<<Segment<P> as RichPointQuery<P, M>>::ExtraInfo>;
 
+    /// Projects a point on this polyline, also returning the index of the closest segment.
+    ///
+    /// The feature of the projection is `FeatureId::Edge(i)` for the interior of the `i`-th
+    /// segment, and `FeatureId::Vertex(j)` for the `j`-th vertex of the polyline.
     #[inline]
     fn project_point_with_extra_info(&self, m: &M, point: &P, solid: bool)
         -> (PointProjection<P>, Self::ExtraInfo)
     {
-        self.base_mesh().project_point_with_extra_info(m, point, solid)
+        let (mut proj, extra_info) = self.base_mesh().project_point_with_extra_info(m, point, solid);
+        let i = extra_info.element_index;
+
+        proj.feature = match proj.feature {
+            FeatureId::Vertex(k) => FeatureId::Vertex(self.indices()[i][k]),
+            FeatureId::Edge(_)   => FeatureId::Edge(i),
+            _                    => FeatureId::Unknown
+        };
+
+        (proj, extra_info)
     }
 }
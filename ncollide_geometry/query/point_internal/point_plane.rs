@@ -1,6 +1,6 @@
 use na;
 use query::{PointQuery, PointProjection};
-use shape::Plane;
+use shape::{Plane, FeatureId};
 use math::{Point, Isometry};
 
 impl<P: Point, M: Isometry<P>> PointQuery<P, M> for Plane<P::Vector> {
@@ -15,7 +15,7 @@ impl<P: Point, M: Isometry<P>> PointQuery<P, M> for Plane<P::Vector> {
             PointProjection::new(true, *pt)
         }
         else {
-            PointProjection::new(inside, *pt + (-*self.normal() * d))
+            PointProjection::new_with_feature(inside, *pt + (-*self.normal() * d), FeatureId::Face(0))
         }
     }
 
@@ -1,4 +1,5 @@
 use na;
+use shape::FeatureId;
 use math::Point;
 
 /// Description of the projection of a point on a shape.
@@ -7,14 +8,26 @@ pub struct PointProjection<P: Point> {
     pub is_inside: bool,
     /// The projection result.
     pub point: P,
+    /// The feature of the shape the point was projected on.
+    ///
+    /// Its meaning is the same as for `SupportMap::support_feature_id`, e.g., a vertex, an edge
+    /// or a face of a cuboid. This is `FeatureId::Unknown` if the shape does not identify its
+    /// features, or if the point is inside of a solid shape since it is then its own projection.
+    pub feature: FeatureId
 }
 
 impl<P: Point> PointProjection<P> {
-    /// Initializes a new `PointProjection`.
+    /// Initializes a new `PointProjection` with an unknown feature.
     pub fn new(is_inside: bool, point: P) -> PointProjection<P> {
+        PointProjection::new_with_feature(is_inside, point, FeatureId::Unknown)
+    }
+
+    /// Initializes a new `PointProjection` on the given feature.
+    pub fn new_with_feature(is_inside: bool, point: P, feature: FeatureId) -> PointProjection<P> {
         PointProjection {
             is_inside: is_inside,
-            point:     point
+            point:     point,
+            feature:   feature
         }
     }
 }
@@ -44,6 +57,14 @@ pub trait PointQuery<P: Point, M> {
     fn contains_point(&self, m: &M, pt: &P) -> bool {
         self.project_point(m, pt, false).is_inside
     }
+
+    /// Computes the signed distances between `self` transformed by `m` and each point of `pts`.
+    ///
+    /// A distance is negative if its point is inside of `self`.
+    #[inline]
+    fn distances_to_points(&self, m: &M, pts: &[P]) -> Vec<P::Real> {
+        pts.iter().map(|pt| self.distance_to_point(m, pt, false)).collect()
+    }
 }
 
 /// Returns shape-specific info in addition to generic projection information
@@ -0,0 +1,51 @@
+use na::{self, Unit};
+use approx::ApproxEq;
+use shape::{RoundShape, SupportMap};
+use query::{PointQuery, PointProjection};
+use query::algorithms::johnson_simplex::JohnsonSimplex;
+use query::point_internal::point_support_map;
+use math::{Point, Isometry};
+
+impl<P, M, S> PointQuery<P, M> for RoundShape<S, P::Real>
+    where P: Point,
+          M: Isometry<P>,
+          S: SupportMap<P, M> + PointQuery<P, M> {
+    /// Projects a point on this shape.
+    ///
+    /// The point is projected on the inner shape first, then moved by the border radius away
+    /// from it. The feature of the projection is the feature of the inner shape it is rounded
+    /// from.
+    fn project_point(&self, m: &M, pt: &P, solid: bool) -> PointProjection<P> {
+        let radius = self.border_radius();
+        let proj   = self.inner().project_point(m, pt, solid);
+
+        if !proj.is_inside {
+            let dist = na::distance(pt, &proj.point);
+
+            if dist <= radius && solid {
+                return PointProjection::new(true, *pt);
+            }
+
+            // The normal at the projection points from the inner shape toward the point. If the
+            // point is on the inner shape boundary, this normal is unknown.
+            match Unit::try_new(*pt - proj.point, P::Real::default_epsilon()) {
+                Some(normal) => PointProjection::new_with_feature(dist <= radius, proj.point + *normal * radius,
+                                                                  proj.feature),
+                None         => point_support_map::support_map_point_projection(
+                    m, self, &mut JohnsonSimplex::<P>::new_w_tls(), pt, solid)
+            }
+        }
+        else if solid {
+            proj
+        }
+        else {
+            // The closest point of the border is beyond the closest point of the inner shape
+            // boundary. If the point is on this boundary, the normal there is unknown.
+            match Unit::try_new(proj.point - *pt, P::Real::default_epsilon()) {
+                Some(normal) => PointProjection::new_with_feature(true, proj.point + *normal * radius, proj.feature),
+                None         => point_support_map::support_map_point_projection(
+                    m, self, &mut JohnsonSimplex::<P>::new_w_tls(), pt, solid)
+            }
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use na;
-use shape::Segment;
+use shape::{Segment, FeatureId};
 use query::{PointQuery, PointProjection, RichPointQuery};
 use math::{Point, Isometry};
 
@@ -30,16 +30,19 @@ impl<P: Point, M: Isometry<P>> RichPointQuery<P, M> for Segment<P> {
 
         let proj;
         let position_on_segment;
+        let feature;
 
         if ab_ap <= na::zero() {
             // Voronoï region of vertex 'a'.
             position_on_segment = na::zero();
             proj = m.transform_point(self.a());
+            feature = FeatureId::Vertex(0);
         }
         else if ab_ap >= sqnab {
             // Voronoï region of vertex 'b'.
             position_on_segment = na::one();
             proj = m.transform_point(self.b());
+            feature = FeatureId::Vertex(1);
         }
         else {
             assert!(sqnab != na::zero());
@@ -47,11 +50,12 @@ impl<P: Point, M: Isometry<P>> RichPointQuery<P, M> for Segment<P> {
             // Voronoï region of the segment interior.
             position_on_segment = ab_ap / sqnab;
             proj = m.transform_point(&(*self.a() + ab * position_on_segment));
+            feature = FeatureId::Edge(0);
         }
 
         // FIXME: is this acceptable?
         let inside = relative_eq!(proj, *pt);
 
-        (PointProjection::new(inside, proj), position_on_segment)
+        (PointProjection::new_with_feature(inside, proj, feature), position_on_segment)
     }
 }
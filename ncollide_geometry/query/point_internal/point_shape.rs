@@ -1,5 +1,7 @@
+use alga::general::Real;
+use na;
 use math::{Point, Isometry};
-use shape::Shape;
+use shape::{Shape, ShapeHandle, TriMesh, Polyline, Compound};
 use query::{PointQuery, PointProjection};
 
 
@@ -26,4 +28,69 @@ impl<P, M> PointQuery<P, M> for Shape<P, M>
             .expect("No PointQuery implementation for the underlying shape.")
             .contains_point(m, pt)
     }
+
+    #[inline]
+    fn distances_to_points(&self, m: &M, pts: &[P]) -> Vec<P::Real> {
+        self.as_point_query()
+            .expect("No PointQuery implementation for the underlying shape.")
+            .distances_to_points(m, pts)
+    }
+}
+
+impl<P, M> PointQuery<P, M> for ShapeHandle<P, M>
+    where P: Point,
+          M: Isometry<P> {
+    #[inline]
+    fn project_point(&self, m: &M, pt: &P, solid: bool) -> PointProjection<P> {
+        (**self).project_point(m, pt, solid)
+    }
+
+    #[inline]
+    fn distance_to_point(&self, m: &M, pt: &P, solid: bool) -> P::Real {
+        (**self).distance_to_point(m, pt, solid)
+    }
+
+    #[inline]
+    fn contains_point(&self, m: &M, pt: &P) -> bool {
+        (**self).contains_point(m, pt)
+    }
+
+    #[inline]
+    fn distances_to_points(&self, m: &M, pts: &[P]) -> Vec<P::Real> {
+        (**self).distances_to_points(m, pts)
+    }
+}
+
+/// Tests if a point is inside of the volume bounded by a shape transformed by `m`.
+///
+/// Contrary to `PointQuery::contains_point`, a 3D `TriMesh` or a 2D `Polyline` is the boundary of
+/// a solid here: a point is inside of it if its winding number is greater than one half. The parts
+/// of a `Compound` are tested the same way. Returns `None` if the shape, or one of its parts, does
+/// not support point queries.
+pub fn shape_contains_point<P, M>(m: &M, g: &Shape<P, M>, pt: &P) -> Option<bool>
+    where P: Point,
+          M: Isometry<P> {
+    let dim  = na::dimension::<P::Vector>();
+    let half = na::convert::<f64, P::Real>(0.5);
+
+    if let (3, Some(mesh)) = (dim, g.as_shape::<TriMesh<P>>()) {
+        Some(mesh.winding_number(&m.inverse_transform_point(pt)).abs() > half)
+    }
+    else if let (2, Some(polyline)) = (dim, g.as_shape::<Polyline<P>>()) {
+        Some(polyline.winding_number(&m.inverse_transform_point(pt)).abs() > half)
+    }
+    else if let Some(compound) = g.as_shape::<Compound<P, M>>() {
+        for &(ref pm, ref part) in compound.shapes().iter() {
+            match shape_contains_point(&(m.clone() * pm.clone()), part.as_ref(), pt) {
+                Some(true)  => return Some(true),
+                Some(false) => { },
+                None        => return None
+            }
+        }
+
+        Some(false)
+    }
+    else {
+        g.as_point_query().map(|q| q.contains_point(m, pt))
+    }
 }
@@ -1,11 +1,13 @@
+use approx::ApproxEq;
+use alga::general::Real;
 use alga::linear::Translation;
 
 use query::algorithms::gjk;
 use query::algorithms::minkowski_sampling;
 use query::algorithms::simplex::Simplex;
 use query::algorithms::johnson_simplex::JohnsonSimplex;
-use query::{PointQuery, PointProjection};
-use shape::{SupportMap, Cylinder, Cone, Capsule, ConvexHull};
+use query::{PointQuery, PointProjection, QuerySettings};
+use shape::{SupportMap, Cylinder, Cone, ConvexHull, Ellipsoid};
 use math::{Point, Isometry};
 
 /// Projects a point on a shape using the GJK algorithm.
@@ -25,7 +27,7 @@ pub fn support_map_point_projection<P, M, S, G>(m:       &M,
 
     simplex.reset(support_point);
 
-    match gjk::project_origin(&m, shape, simplex) {
+    match gjk::project_origin(&m, shape, simplex, &QuerySettings::default()).0 {
         Some(p) => {
             PointProjection::new(false, p + point.coordinates())
         },
@@ -36,7 +38,7 @@ pub fn support_map_point_projection<P, M, S, G>(m:       &M,
             // FIXME: we use the Minkowski Sampling for now, but this should be changed for the EPA
             // in the future.
             if !solid {
-                match minkowski_sampling::project_origin(&m, shape, simplex) {
+                match minkowski_sampling::project_origin(&m, shape, simplex).0 {
                     Some(p) => proj = p + point.coordinates(),
                     None    => proj = *point
                 }
@@ -51,20 +53,32 @@ pub fn support_map_point_projection<P, M, S, G>(m:       &M,
 }
 
 impl<P: Point, M: Isometry<P>> PointQuery<P, M> for Cylinder<P::Real> {
+    /// Projects a point on this cylinder.
+    ///
+    /// The feature of the projection is identified by `SupportMap::support_feature_id`.
     #[inline]
     fn project_point(&self, m: &M, point: &P, solid: bool) -> PointProjection<P> {
-        support_map_point_projection(m, self, &mut JohnsonSimplex::<P>::new_w_tls(), point, solid)
+        let mut proj = support_map_point_projection(m, self, &mut JohnsonSimplex::<P>::new_w_tls(), point, solid);
+        proj.feature = self.support_feature_id(m, &[ proj.point ]);
+
+        proj
     }
 }
 
 impl<P: Point, M: Isometry<P>> PointQuery<P, M> for Cone<P::Real> {
+    /// Projects a point on this cone.
+    ///
+    /// The feature of the projection is identified by `SupportMap::support_feature_id`.
     #[inline]
     fn project_point(&self, m: &M, point: &P, solid: bool) -> PointProjection<P> {
-        support_map_point_projection(m, self, &mut JohnsonSimplex::<P>::new_w_tls(), point, solid)
+        let mut proj = support_map_point_projection(m, self, &mut JohnsonSimplex::<P>::new_w_tls(), point, solid);
+        proj.feature = self.support_feature_id(m, &[ proj.point ]);
+
+        proj
     }
 }
 
-impl<P: Point, M: Isometry<P>> PointQuery<P, M> for Capsule<P::Real> {
+impl<P: Point, M: Isometry<P>> PointQuery<P, M> for Ellipsoid<P::Vector> {
     #[inline]
     fn project_point(&self, m: &M, point: &P, solid: bool) -> PointProjection<P> {
         support_map_point_projection(m, self, &mut JohnsonSimplex::<P>::new_w_tls(), point, solid)
@@ -72,8 +86,19 @@ impl<P: Point, M: Isometry<P>> PointQuery<P, M> for Capsule<P::Real> {
 }
 
 impl<P: Point, M: Isometry<P>> PointQuery<P, M> for ConvexHull<P> {
+    /// Projects a point on this convex polytope.
+    ///
+    /// The feature of the projection is identified by `ConvexHull::support_feature_id_toward`,
+    /// in the direction from the projection to the point, or from the point to the projection if
+    /// the point is inside. It is unknown if the point is on the boundary, or inside of this
+    /// polytope with `solid` set to `true`.
     #[inline]
     fn project_point(&self, m: &M, point: &P, solid: bool) -> PointProjection<P> {
-        support_map_point_projection(m, self, &mut JohnsonSimplex::<P>::new_w_tls(), point, solid)
+        let mut proj = support_map_point_projection(m, self, &mut JohnsonSimplex::<P>::new_w_tls(), point, solid);
+        let dir      = if proj.is_inside { proj.point - *point } else { *point - proj.point };
+
+        proj.feature = self.support_feature_id_toward(m, &dir, P::Real::default_epsilon().sqrt());
+
+        proj
     }
 }
@@ -1,17 +1,17 @@
 use na;
-use shape::Triangle;
+use shape::{Triangle, FeatureId};
 use query::{PointQuery, PointProjection, RichPointQuery};
 use math::{Point, Isometry};
 
 #[inline]
-fn compute_result<P: Point>(pt: &P, proj: P) -> PointProjection<P> {
+fn compute_result<P: Point>(pt: &P, proj: P, feature: FeatureId) -> PointProjection<P> {
    if na::dimension::<P::Vector>() == 2 {
-       PointProjection::new(*pt == proj, proj)
+       PointProjection::new_with_feature(*pt == proj, proj, feature)
    }
    else {
        // FIXME: is this acceptable to assume the point is inside of the triangle if it is close
        // enough?
-       PointProjection::new(relative_eq!(proj, *pt), proj)
+       PointProjection::new_with_feature(relative_eq!(proj, *pt), proj, feature)
    }
 }
 
@@ -57,7 +57,7 @@ impl<P: Point, M: Isometry<P>> RichPointQuery<P, M> for Triangle<P> {
 
         if d1 <= na::zero() && d2 <= na::zero() {
             // Voronoï region of `a`.
-            return (compute_result(pt, m.transform_point(&a)), ());
+            return (compute_result(pt, m.transform_point(&a), FeatureId::Vertex(0)), ());
         }
 
         let bp = p - b;
@@ -66,14 +66,14 @@ impl<P: Point, M: Isometry<P>> RichPointQuery<P, M> for Triangle<P> {
 
         if d3 >= na::zero() && d4 <= d3 {
             // Voronoï region of `b`.
-            return (compute_result(pt, m.transform_point(&b)), ());
+            return (compute_result(pt, m.transform_point(&b), FeatureId::Vertex(1)), ());
         }
 
         let vc = d1 * d4 - d3 * d2;
         if vc <= na::zero() && d1 >= na::zero() && d3 <= na::zero() {
             // Voronoï region of `ab`.
             let v = d1 / (d1 - d3);
-            return (compute_result(pt, m.transform_point(&(a + ab * v))), ());
+            return (compute_result(pt, m.transform_point(&(a + ab * v)), FeatureId::Edge(0)), ());
         }
 
         let cp = p - c;
@@ -82,7 +82,7 @@ impl<P: Point, M: Isometry<P>> RichPointQuery<P, M> for Triangle<P> {
 
         if d6 >= na::zero() && d5 <= d6 {
             // Voronoï region of `c`.
-            return (compute_result(pt, m.transform_point(&c)), ());
+            return (compute_result(pt, m.transform_point(&c), FeatureId::Vertex(2)), ());
         }
 
         let vb = d5 * d2 - d1 * d6;
@@ -90,14 +90,14 @@ impl<P: Point, M: Isometry<P>> RichPointQuery<P, M> for Triangle<P> {
         if vb <= na::zero() && d2 >= na::zero() && d6 <= na::zero() {
             // Voronoï region of `ac`.
             let w = d2 / (d2 - d6);
-            return (compute_result(pt, m.transform_point(&(a + ac * w))), ());
+            return (compute_result(pt, m.transform_point(&(a + ac * w)), FeatureId::Edge(2)), ());
         }
 
         let va = d3 * d6 - d5 * d4;
         if va <= na::zero() && d4 - d3 >= na::zero() && d5 - d6 >= na::zero() {
             // Voronoï region of `bc`.
             let w = (d4 - d3) / ((d4 - d3) + (d5 - d6));
-            return (compute_result(pt, m.transform_point(&(b + (c - b) * w))), ());
+            return (compute_result(pt, m.transform_point(&(b + (c - b) * w)), FeatureId::Edge(1)), ());
         }
 
         // Voronoï region of the face.
@@ -106,7 +106,7 @@ impl<P: Point, M: Isometry<P>> RichPointQuery<P, M> for Triangle<P> {
             let v = vb * denom;
             let w = vc * denom;
 
-            return (compute_result(pt, m.transform_point(&(a + ab * v + ac * w))), ());
+            return (compute_result(pt, m.transform_point(&(a + ab * v + ac * w)), FeatureId::Face(0)), ());
         }
         else {
             // Special treatement if we work in 2d because in this case we really are inside of the
@@ -124,33 +124,38 @@ impl<P: Point, M: Isometry<P>> RichPointQuery<P, M> for Triangle<P> {
 
                 let bc = c - b;
                 let d_ab = na::norm_squared(&ap) - (na::norm_squared(&ab) * v * v);
-                let d_ac = na::norm_squared(&ap) - (na::norm_squared(&ac) * u * u);
-                let d_bc = na::norm_squared(&bp) - (na::norm_squared(&bc) * w * w);
+                let d_ac = na::norm_squared(&ap) - (na::norm_squared(&ac) * w * w);
+                let d_bc = na::norm_squared(&bp) - (na::norm_squared(&bc) * u * u);
 
                 let proj;
+                let feature;
 
                 if d_ab < d_ac {
                     if d_ab < d_bc {
                         // ab
                         proj = m.transform_point(&(a + ab * v));
+                        feature = FeatureId::Edge(0);
                     }
                     else {
                         // bc
                         proj = m.transform_point(&(b + bc * u));
+                        feature = FeatureId::Edge(1);
                     }
                 }
                 else {
                     if d_ac < d_bc {
                         // ac
                         proj = m.transform_point(&(a + ac * w));
+                        feature = FeatureId::Edge(2);
                     }
                     else {
                         // bc
                         proj = m.transform_point(&(b + bc * u));
+                        feature = FeatureId::Edge(1);
                     }
                 }
 
-                (PointProjection::new(true, proj), ())
+                (PointProjection::new_with_feature(true, proj, feature), ())
             }
         }
     }
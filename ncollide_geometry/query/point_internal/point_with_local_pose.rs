@@ -0,0 +1,23 @@
+use shape::WithLocalPose;
+use query::{PointQuery, PointProjection};
+use math::{Point, Isometry};
+
+impl<P, M, S> PointQuery<P, M> for WithLocalPose<S, M>
+    where P: Point,
+          M: Isometry<P>,
+          S: PointQuery<P, M> {
+    #[inline]
+    fn project_point(&self, m: &M, point: &P, solid: bool) -> PointProjection<P> {
+        self.shape().project_point(&self.shape_transform(m), point, solid)
+    }
+
+    #[inline]
+    fn distance_to_point(&self, m: &M, point: &P, solid: bool) -> P::Real {
+        self.shape().distance_to_point(&self.shape_transform(m), point, solid)
+    }
+
+    #[inline]
+    fn contains_point(&self, m: &M, point: &P) -> bool {
+        self.shape().contains_point(&self.shape_transform(m), point)
+    }
+}
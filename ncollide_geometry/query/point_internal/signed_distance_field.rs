@@ -0,0 +1,264 @@
+use std::collections::HashMap;
+
+use alga::general::Real;
+use na;
+use bounding_volume::AABB;
+use shape::Shape;
+use query::{PointQuery, point_internal};
+use math::{Point, Isometry};
+
+/// Computes the signed distance between a point and a shape transformed by `m`.
+///
+/// The distance is negative if the point is inside of the shape. The inside of the shape is
+/// given by `point_internal::shape_contains_point`: a 3D triangle mesh or a 2D polyline is the
+/// boundary of a solid, and a compound is the union of its parts. The magnitude of the distance
+/// is the distance to the boundary of the shape, or of one of its parts for a compound.
+pub fn signed_distance<P, M>(m: &M, g: &Shape<P, M>, pt: &P) -> P::Real
+    where P: Point,
+          M: Isometry<P> {
+    let dist = g.distance_to_point(m, pt, false);
+
+    match point_internal::shape_contains_point(m, g, pt) {
+        Some(true)  => -dist.abs(),
+        Some(false) => dist.abs(),
+        None        => dist
+    }
+}
+
+enum Samples<N> {
+    Dense(Vec<N>),
+    Sparse(HashMap<usize, N>)
+}
+
+/// The signed distances to a shape sampled on a regular grid.
+///
+/// The samples are located at `origin + cell_size * (i0, i1, ...)` where `ik` ranges from `0` to
+/// `num_samples()[k] - 1`. A dense field stores every sample, while a sparse field only stores
+/// the samples with a distance closer to zero than a given bandwidth.
+pub struct SignedDistanceField<P: Point> {
+    origin:      P,
+    cell_size:   P::Real,
+    num_samples: Vec<usize>,
+    samples:     Samples<P::Real>
+}
+
+impl<P: Point> SignedDistanceField<P> {
+    /// Samples the signed distances to `g` transformed by `m` on every node of a grid covering
+    /// `region`, with the given spacing.
+    pub fn new<M>(m: &M, g: &Shape<P, M>, region: &AABB<P>, cell_size: P::Real) -> SignedDistanceField<P>
+        where M: Isometry<P> {
+        let mut res = SignedDistanceField::empty(region, cell_size, Samples::Dense(Vec::new()));
+        let len     = res.num_samples.iter().fold(1, |n, k| n * *k);
+        let mut values = Vec::with_capacity(len);
+
+        for i in 0 .. len {
+            let pt = res.point_at_linear_index(i);
+            values.push(signed_distance(m, g, &pt));
+        }
+
+        res.samples = Samples::Dense(values);
+
+        res
+    }
+
+    /// Samples the signed distances closer to zero than `bandwidth` to `g` transformed by `m` on
+    /// a grid covering `region`, with the given spacing.
+    ///
+    /// Since the distance to the shape varies by at most `cell_size` between two consecutive
+    /// samples, the samples known to be out of the band are not computed at all.
+    pub fn new_sparse<M>(m: &M, g: &Shape<P, M>, region: &AABB<P>, cell_size: P::Real, bandwidth: P::Real)
+                         -> SignedDistanceField<P>
+        where M: Isometry<P> {
+        assert!(bandwidth >= na::zero(), "The bandwidth must be positive.");
+
+        let mut res    = SignedDistanceField::empty(region, cell_size, Samples::Sparse(HashMap::new()));
+        let mut values = HashMap::new();
+        let len        = res.num_samples.iter().fold(1, |n, k| n * *k);
+        let nfirst     = res.num_samples[0];
+        let mut i      = 0;
+
+        while i < len {
+            let pt   = res.point_at_linear_index(i);
+            let dist = signed_distance(m, g, &pt);
+
+            if dist.abs() <= bandwidth {
+                let _ = values.insert(i, dist);
+                i = i + 1;
+            }
+            else {
+                // Jump over the next samples along the first axis that cannot be in the band.
+                let nskip    = ((dist.abs() - bandwidth) / cell_size).ceil();
+                let nskip    = na::try_convert::<P::Real, f64>(nskip).unwrap() as usize;
+                let next_row = (i / nfirst + 1) * nfirst;
+
+                i = ::std::cmp::min(i + ::std::cmp::max(nskip, 1), next_row);
+            }
+        }
+
+        res.samples = Samples::Sparse(values);
+
+        res
+    }
+
+    fn empty(region: &AABB<P>, cell_size: P::Real, samples: Samples<P::Real>) -> SignedDistanceField<P> {
+        assert!(cell_size > na::zero(), "The grid cell size must be strictly positive.");
+
+        let extents     = *region.maxs() - *region.mins();
+        let num_samples = (0 .. na::dimension::<P::Vector>()).map(|i| {
+            na::try_convert::<P::Real, f64>((extents[i] / cell_size).ceil()).unwrap() as usize + 1
+        }).collect();
+
+        SignedDistanceField {
+            origin:      *region.mins(),
+            cell_size:   cell_size,
+            num_samples: num_samples,
+            samples:     samples
+        }
+    }
+
+    /// The position of the sample with all its indices equal to zero.
+    #[inline]
+    pub fn origin(&self) -> &P {
+        &self.origin
+    }
+
+    /// The distance between two consecutive samples along each axis.
+    #[inline]
+    pub fn cell_size(&self) -> P::Real {
+        self.cell_size
+    }
+
+    /// The number of samples along each axis.
+    #[inline]
+    pub fn num_samples(&self) -> &[usize] {
+        &self.num_samples[..]
+    }
+
+    /// Whether only the samples close to the surface of the shape are stored.
+    #[inline]
+    pub fn is_sparse(&self) -> bool {
+        match self.samples {
+            Samples::Dense(_)  => false,
+            Samples::Sparse(_) => true
+        }
+    }
+
+    /// The number of samples actually stored.
+    #[inline]
+    pub fn num_stored_samples(&self) -> usize {
+        match self.samples {
+            Samples::Dense(ref values)  => values.len(),
+            Samples::Sparse(ref values) => values.len()
+        }
+    }
+
+    /// The position of the sample with the given indices.
+    pub fn point_at(&self, indices: &[usize]) -> P {
+        let mut res = self.origin;
+
+        for i in 0 .. indices.len() {
+            res[i] = res[i] + na::convert::<f64, P::Real>(indices[i] as f64) * self.cell_size;
+        }
+
+        res
+    }
+
+    /// The signed distance at the sample with the given indices.
+    ///
+    /// Returns `None` if the indices are out of the grid, or if this field is sparse and the
+    /// sample is out of its band.
+    pub fn value_at(&self, indices: &[usize]) -> Option<P::Real> {
+        assert!(indices.len() == self.num_samples.len(), "One index per dimension must be given.");
+
+        let mut id     = 0;
+        let mut stride = 1;
+
+        for i in 0 .. indices.len() {
+            if indices[i] >= self.num_samples[i] {
+                return None;
+            }
+
+            id     = id + indices[i] * stride;
+            stride = stride * self.num_samples[i];
+        }
+
+        self.value_at_linear_index(id)
+    }
+
+    /// Interpolates the signed distance at `pt` from the samples at the corners of its cell.
+    ///
+    /// Returns `None` if `pt` is out of the grid, or if this field is sparse and one of the
+    /// corners of its cell is out of its band.
+    pub fn interpolate(&self, pt: &P) -> Option<P::Real> {
+        let dim         = self.num_samples.len();
+        let mut weights = na::zero::<P::Vector>();
+        let mut base    = 0;
+        let mut stride  = 1;
+
+        for i in 0 .. dim {
+            let x = (pt[i] - self.origin[i]) / self.cell_size;
+
+            if x < na::zero() || x > na::convert((self.num_samples[i] - 1) as f64) {
+                return None;
+            }
+
+            // The last sample is the upper corner of the last cell.
+            let k = ::std::cmp::min(na::try_convert::<P::Real, f64>(x.floor()).unwrap() as usize,
+                                    self.num_samples[i].saturating_sub(2));
+
+            weights[i] = x - na::convert(k as f64);
+            base       = base + k * stride;
+            stride     = stride * self.num_samples[i];
+        }
+
+        let mut res = na::zero::<P::Real>();
+
+        for c in 0 .. 1usize << dim {
+            let mut weight = na::one::<P::Real>();
+            let mut id     = base;
+            let mut stride = 1;
+
+            for i in 0 .. dim {
+                if c & (1 << i) != 0 {
+                    weight = weight * weights[i];
+                    id     = id + stride;
+                }
+                else {
+                    weight = weight * (na::one::<P::Real>() - weights[i]);
+                }
+
+                stride = stride * self.num_samples[i];
+            }
+
+            // A zero weight may correspond to a corner out of the grid.
+            if weight != na::zero() {
+                match self.value_at_linear_index(id) {
+                    Some(value) => res = res + value * weight,
+                    None        => return None
+                }
+            }
+        }
+
+        Some(res)
+    }
+
+    fn value_at_linear_index(&self, id: usize) -> Option<P::Real> {
+        match self.samples {
+            Samples::Dense(ref values)  => values.get(id).cloned(),
+            Samples::Sparse(ref values) => values.get(&id).cloned()
+        }
+    }
+
+    fn point_at_linear_index(&self, id: usize) -> P {
+        let mut res  = self.origin;
+        let mut rest = id;
+
+        for i in 0 .. self.num_samples.len() {
+            let k = rest % self.num_samples[i];
+            rest  = rest / self.num_samples[i];
+            res[i] = res[i] + na::convert::<f64, P::Real>(k as f64) * self.cell_size;
+        }
+
+        res
+    }
+}
@@ -4,6 +4,9 @@ use query::Proximity;
 use shape::Ball;
 
 /// Proximity between balls.
+///
+/// The result depends on the distance between the centers of the balls, compared to the sum of
+/// their radii. Balls touching exactly are `Intersecting`.
 #[inline]
 pub fn ball_against_ball<P>(center1: &P, b1: &Ball<P::Real>,
                             center2: &P, b2: &Ball<P::Real>,
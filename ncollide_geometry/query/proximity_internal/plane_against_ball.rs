@@ -0,0 +1,47 @@
+use alga::linear::Translation;
+use na;
+
+use query::Proximity;
+use shape::{Ball, Plane};
+use math::{Point, Isometry};
+
+/// Proximity between a plane and a ball.
+///
+/// The result depends on the signed distance from the center of the ball to the plane, compared
+/// to the ball radius. A ball touching the plane exactly is `Intersecting`.
+#[inline]
+pub fn plane_against_ball<P, M>(mplane: &M, plane: &Plane<P::Vector>,
+                                center: &P, ball: &Ball<P::Real>,
+                                margin: P::Real)
+                                -> Proximity
+    where P: Point,
+          M: Isometry<P> {
+    assert!(margin >= na::zero(), "The proximity margin must be positive or null.");
+
+    let plane_normal = mplane.rotate_vector(plane.normal());
+    let plane_center = P::from_coordinates(mplane.translation().to_vector());
+    let distance     = na::dot(&plane_normal, &(*center - plane_center));
+
+    if distance <= ball.radius() + margin {
+        if distance <= ball.radius() {
+            Proximity::Intersecting
+        }
+        else {
+            Proximity::WithinMargin
+        }
+    }
+    else {
+        Proximity::Disjoint
+    }
+}
+
+/// Proximity between a ball and a plane.
+#[inline]
+pub fn ball_against_plane<P, M>(center: &P, ball: &Ball<P::Real>,
+                                mplane: &M, plane: &Plane<P::Vector>,
+                                margin: P::Real)
+                                -> Proximity
+    where P: Point,
+          M: Isometry<P> {
+    plane_against_ball(mplane, plane, center, ball, margin)
+}
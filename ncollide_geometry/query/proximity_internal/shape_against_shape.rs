@@ -18,6 +18,16 @@ pub fn shape_against_shape<P, M>(m1: &M, g1: &Shape<P, M>,
 
         proximity_internal::ball_against_ball(&p1, b1, &p2, b2, margin)
     }
+    else if let (Some(p1), Some(b2)) = (g1.as_shape::<Plane<P::Vector>>(), g2.as_shape::<Ball<P::Real>>()) {
+        let c2 = P::from_coordinates(m2.translation().to_vector());
+
+        proximity_internal::plane_against_ball(m1, p1, &c2, b2, margin)
+    }
+    else if let (Some(b1), Some(p2)) = (g1.as_shape::<Ball<P::Real>>(), g2.as_shape::<Plane<P::Vector>>()) {
+        let c1 = P::from_coordinates(m1.translation().to_vector());
+
+        proximity_internal::ball_against_plane(&c1, b1, m2, p2, margin)
+    }
     else if let (Some(p1), Some(s2)) = (g1.as_shape::<Plane<P::Vector>>(), g2.as_support_map()) {
         proximity_internal::plane_against_support_map(m1, p1, m2, s2, margin)
     }
@@ -5,7 +5,7 @@ use shape::{self, SupportMap, AnnotatedPoint};
 use query::algorithms::gjk;
 use query::algorithms::simplex::Simplex;
 use query::algorithms::johnson_simplex::JohnsonSimplex;
-use query::Proximity;
+use query::{Proximity, QuerySettings};
 use math::{Point, Isometry};
 
 
@@ -21,7 +21,8 @@ pub fn support_map_against_support_map<P, M, G1: ?Sized, G2: ?Sized>(
           M:  Isometry<P>,
           G1: SupportMap<P, M>,
           G2: SupportMap<P, M> {
-    support_map_against_support_map_with_params(m1, g1, m2, g2, margin, &mut JohnsonSimplex::new_w_tls(), None).0
+    support_map_against_support_map_with_params(m1, g1, m2, g2, margin, &mut JohnsonSimplex::new_w_tls(), None,
+                                                &QuerySettings::default()).0
 }
 
 /// Proximity between support-mapped shapes (`Cuboid`, `ConvexHull`, etc.)
@@ -34,7 +35,8 @@ pub fn support_map_against_support_map_with_params<P, M, S, G1: ?Sized, G2: ?Siz
                                                    g2:       &G2,
                                                    margin:   P::Real,
                                                    simplex:  &mut S,
-                                                   init_dir: Option<P::Vector>)
+                                                   init_dir: Option<P::Vector>,
+                                                   settings: &QuerySettings<P::Real>)
                                                    -> (Proximity, P::Vector)
     where P:  Point,
           M:  Isometry<P>,
@@ -56,5 +58,7 @@ pub fn support_map_against_support_map_with_params<P, M, S, G1: ?Sized, G2: ?Siz
 
     simplex.reset(shape::cso_support_point(m1, g1, m2, g2, dir));
 
-    gjk::proximity(m1, g1, m2, g2, margin, simplex)
+    let (proximity, dir, _) = gjk::proximity(m1, g1, m2, g2, margin, simplex, settings);
+
+    (proximity, dir)
 }
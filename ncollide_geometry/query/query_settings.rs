@@ -0,0 +1,76 @@
+//! Numerical tolerances of the geometric queries.
+
+use alga::general::Real;
+use na;
+
+/// Numerical tolerances used by the geometric queries.
+///
+/// The default values match shapes with dimensions close to the unit. Simulations with very large
+/// or very small coordinates may need to scale them to remain robust.
+#[derive(Debug, PartialEq, Clone, Copy, RustcEncodable, RustcDecodable)]
+pub struct QuerySettings<N> {
+    /// Relative precision at which the GJK algorithm terminates.
+    ///
+    /// The GJK stops as soon as the gap between its upper and lower bounds of the squared
+    /// distance is smaller than `gjk_tolerance` times the upper bound. Defaults to the square root
+    /// of the machine epsilon.
+    pub gjk_tolerance: N,
+    /// Squared distance under which two shapes are considered touching.
+    ///
+    /// Defaults to 100 times the machine epsilon.
+    pub contact_tolerance: N,
+    /// Magnitude of the cosine of the angle between two vectors under which they are considered
+    /// orthogonal, e.g., a ray parallel to a plane. Non-unit vectors are normalized first.
+    ///
+    /// Defaults to zero.
+    pub parallel_eps: N,
+    /// Maximum number of iterations of the GJK algorithm.
+    ///
+    /// Once reached, the GJK returns its current estimate together with
+    /// `GJKStatus::MaxIterationsReached`. Defaults to 1000, which is far more than what
+    /// well-conditioned shapes need.
+    pub max_iterations: usize,
+    /// Maximum number of points of a contact manifold computed by clipping.
+    ///
+    /// The extra points are discarded by `contacts_internal::reduce_contact_manifold`. Defaults
+    /// to 4, which is enough to keep resting contacts stable.
+    pub max_manifold_points: usize,
+    /// Extra distance by which penetrating shapes are moved apart before running the GJK
+    /// algorithm again to compute their penetration depth.
+    ///
+    /// See `algorithms::minkowski_sampling`. It must be large compared to `gjk_tolerance` times
+    /// the dimensions of the shapes. Defaults to 0.01.
+    pub penetration_shift: N
+}
+
+impl<N: Real> QuerySettings<N> {
+    /// Creates a new set of query tolerances.
+    ///
+    /// All the tolerances must be positive or null, and `max_iterations` must not be zero. The
+    /// maximum number of manifold points is set to 4, and the penetration shift to 0.01.
+    #[inline]
+    pub fn new(gjk_tolerance: N, contact_tolerance: N, parallel_eps: N, max_iterations: usize) -> QuerySettings<N> {
+        assert!(gjk_tolerance >= na::zero(), "The GJK tolerance must be positive or null.");
+        assert!(contact_tolerance >= na::zero(), "The contact tolerance must be positive or null.");
+        assert!(parallel_eps >= na::zero(), "The parallelism epsilon must be positive or null.");
+        assert!(max_iterations > 0, "The maximum number of iterations must not be zero.");
+
+        QuerySettings {
+            gjk_tolerance:       gjk_tolerance,
+            contact_tolerance:   contact_tolerance,
+            parallel_eps:        parallel_eps,
+            max_iterations:      max_iterations,
+            max_manifold_points: 4,
+            penetration_shift:   na::convert(0.01f64)
+        }
+    }
+}
+
+impl<N: Real> Default for QuerySettings<N> {
+    #[inline]
+    fn default() -> QuerySettings<N> {
+        let eps = N::default_epsilon();
+
+        QuerySettings::new(eps.sqrt(), eps * na::convert(100.0f64), na::zero(), 1000)
+    }
+}
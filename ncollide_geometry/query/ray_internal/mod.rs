@@ -4,6 +4,8 @@ pub use self::ray::{Ray, RayCast, RayIntersection};
 pub use self::ray_plane::plane_toi_with_ray;
 pub use self::ray_triangle::triangle_ray_intersection;
 pub use self::ray_support_map::implicit_toi_and_normal_with_ray;
+pub use self::ray_composite_shape::{composite_shape_toi_with_ray, composite_shape_toi_and_normal_with_ray,
+                                    composite_shape_toi_all_with_ray};
 pub use self::ray_ball::ball_toi_with_ray;
 pub use self::ray_bvt::{RayIntersectionCostFn, RayInterferencesCollector};
 
@@ -13,13 +15,25 @@ use na::{Point2, Vector2, Point3, Vector3};
 pub mod ray;
 mod ray_plane;
 mod ray_ball;
+mod ray_capsule;
+mod ray_cone;
+mod ray_cylinder;
+mod ray_ellipsoid;
 mod ray_cuboid;
+mod ray_convex_polygon;
 mod ray_aabb;
 mod ray_bounding_sphere;
+mod ray_obb;
 mod ray_support_map;
 mod ray_triangle;
+mod ray_composite_shape;
 mod ray_compound;
 mod ray_mesh;
+mod ray_heightfield;
+mod ray_with_local_pose;
+mod ray_round_shape;
+mod ray_scaled;
+mod ray_transformed;
 mod ray_shape;
 mod ray_bvt;
 
@@ -1,7 +1,15 @@
 //! Traits and structure needed to cast rays.
 
-use na::Point2;
+use std::ops::{Mul, MulAssign, Div, DivAssign};
 
+use num::Zero;
+
+use approx::ApproxEq;
+use alga::linear::InnerSpace;
+use na::{self, Point2, Unit};
+
+use utils;
+use shape::FeatureId;
 use math::{Point, Vector, Isometry};
 
 /// A Ray.
@@ -40,48 +48,101 @@ impl<P: Point> Ray<P> {
     pub fn translate_by(&self, v: P::Vector) -> Self {
         Self::new(self.origin + v, self.dir)
     }
+
+    /// Tests whether neither the origin nor the direction of this ray has an infinite or NaN
+    /// component.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        utils::is_finite_point(&self.origin) && utils::is_finite_vector(&self.dir)
+    }
 }
 
 /// Structure containing the result of a successful ray cast.
 pub struct RayIntersection<V: Vector> {
     /// The time of impact of the ray with the object.  The exact contact point can be computed
     /// with: `origin + dir * toi` where `origin` is the origin of the ray; `dir` is its direction and
-    /// `toi` is the value of this field.
+    /// `toi` is the value of this field. See `RayIntersection::point`.
     pub toi:    V::Real,
 
-    /// The normal at the intersection point.
+    /// The unit normal at the intersection point.
     ///
-    /// If the `toi` is exactly zero, the normal might not be reliable.
-    pub normal: V,
+    /// The normal faces the ray origin, i.e., it is never in the direction of the ray. Thus, it is
+    /// the outward normal of the shape if the ray enters it, and the inward normal if the ray
+    /// leaves it, e.g., if the ray starts inside of a non-solid shape or hits the back face of a
+    /// triangle. Use `RayCast::toi_and_oriented_normal_with_ray` to get the outward normal
+    /// instead. If the `toi` is exactly zero, the normal might not be reliable. If the ray starts
+    /// inside of a solid shape, the normal is opposite to the ray direction.
+    pub normal: Unit<V>,
 
     /// The textures coordinates at the intersection point.  This is an `Option` because some shape
     /// do not support texture coordinates.
-    pub uvs:    Option<Point2<V::Real>>
+    pub uvs:    Option<Point2<V::Real>>,
+
+    /// The feature of the shape hit by the ray.
+    ///
+    /// Its meaning is the same as for `SupportMap::support_feature_id`, e.g., the face of a cuboid
+    /// or the index of the triangle of a triangle mesh. This is `FeatureId::Unknown` if the shape
+    /// does not identify its features, if the ray hits an edge or vertex of a shape identifying
+    /// its faces only, or if the ray starts inside of a solid shape.
+    pub feature: FeatureId
 }
 
 impl<V: Vector> RayIntersection<V> {
     #[inline]
     /// Creates a new `RayIntersection`.
-    pub fn new_with_uvs(toi: V::Real, normal: V, uvs: Option<Point2<V::Real>>) -> RayIntersection<V> {
+    pub fn new_with_uvs(toi: V::Real, normal: Unit<V>, uvs: Option<Point2<V::Real>>) -> RayIntersection<V> {
         RayIntersection {
-            toi:    toi,
-            normal: normal,
-            uvs:    uvs
+            toi:     toi,
+            normal:  normal,
+            uvs:     uvs,
+            feature: FeatureId::Unknown
         }
     }
 
     #[inline]
     /// Creates a new `RayIntersection`.
-    pub fn new(toi: V::Real, normal: V) -> RayIntersection<V> {
+    pub fn new(toi: V::Real, normal: Unit<V>) -> RayIntersection<V> {
         RayIntersection {
-            toi:    toi,
-            normal: normal,
-            uvs:    None
+            toi:     toi,
+            normal:  normal,
+            uvs:     None,
+            feature: FeatureId::Unknown
         }
     }
+
+    /// Creates the `RayIntersection` of a ray starting inside of a solid shape.
+    ///
+    /// The time of impact is zero and the normal is opposite to the ray direction `dir`. Returns
+    /// `None` if `dir` is zero.
+    #[inline]
+    pub fn new_inside(dir: &V) -> Option<RayIntersection<V>> {
+        Unit::try_new(-*dir, V::Real::default_epsilon()).map(|n| RayIntersection::new(na::zero(), n))
+    }
+
+    /// The world-space point of the ray at the time of impact, i.e., `ray.origin + ray.dir * toi`.
+    ///
+    /// `ray` must be the ray this intersection was computed with. This is the point where the ray
+    /// enters the shape as seen by the ray cast, e.g., the point on the dilated surface of a shape
+    /// with a collision margin, not on the surface of the inner shape.
+    #[inline]
+    pub fn point<P>(&self, ray: &Ray<P>) -> P
+        // The bounds on `V` are implied by `Point<Vector = V>` but must be restated.
+        where P: Point<Vector = V>,
+              V: InnerSpace<Real = P::Real> + Mul<P::Real, Output = V> + MulAssign<P::Real> +
+                 Div<P::Real, Output = V> + DivAssign<P::Real> {
+        ray.origin + ray.dir * self.toi
+    }
 }
 
 /// Traits of objects which can be transformed and tested for intersection with a ray.
+///
+/// A ray that has an infinite or NaN component once expressed in the local space of the shape,
+/// e.g., because of a non-finite direction or transformation, never hits it: the ray casts return
+/// `None`.
+///
+/// The boundary of a shape is part of it. A ray starting on the boundary of a shape hits it with a
+/// time of impact equal to zero, except if `solid` is `false` and the ray goes through the shape:
+/// like a ray starting inside of the shape, it then hits the point where it leaves the shape.
 pub trait RayCast<P: Point, M> {
     /// Computes the time of impact between this transform shape and a ray.
     fn toi_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<P::Real> {
@@ -92,6 +153,52 @@ pub trait RayCast<P: Point, M> {
     #[inline]
     fn toi_and_normal_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<RayIntersection<P::Vector>>;
 
+    /// Computes the time of impact, and normal between this transformed shape and a ray, if the
+    /// time of impact is smaller than or equal to `max_toi`.
+    #[inline]
+    fn toi_and_normal_with_ray_and_max_toi(&self, m: &M, ray: &Ray<P>, max_toi: P::Real, solid: bool)
+                                           -> Option<RayIntersection<P::Vector>> {
+        self.toi_and_normal_with_ray(m, ray, solid).and_then(|inter| {
+            if inter.toi <= max_toi { Some(inter) } else { None }
+        })
+    }
+
+    /// Computes the time of impact, and normal between this transformed shape and a ray, with a
+    /// choice of the normal orientation.
+    ///
+    /// If `flip_to_face_ray` is `true`, the normal faces the ray origin, as the one returned by
+    /// `toi_and_normal_with_ray`. Otherwise, the normal points outward from the shape, even if
+    /// the ray leaves the shape. The outward side of a triangle is the one its vertices are seen
+    /// counterclockwise from. If the ray starts inside of a solid shape, the normal is opposite to
+    /// the ray direction in both cases.
+    ///
+    /// By default, a ray starting inside of the shape is detected with a solid cast: only then is
+    /// the shape cast again to find where the ray leaves it, with a flipped normal. This is
+    /// correct for shapes with an interior.
+    #[inline]
+    fn toi_and_oriented_normal_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool, flip_to_face_ray: bool)
+                                        -> Option<RayIntersection<P::Vector>> {
+        if flip_to_face_ray || solid {
+            return self.toi_and_normal_with_ray(m, ray, solid);
+        }
+
+        // If the ray starts outside of the shape, the solid cast hits the same point.
+        let entry = self.toi_and_normal_with_ray(m, ray, true);
+
+        if entry.as_ref().map_or(true, |inter| !inter.toi.is_zero()) {
+            return entry;
+        }
+
+        self.toi_and_normal_with_ray(m, ray, false).map(|mut inter| {
+            if !inter.toi.is_zero() {
+                // The ray leaves the shape.
+                inter.normal = -inter.normal;
+            }
+
+            inter
+        })
+    }
+
     /// Computes time of impact, normal, and texture coordinates (uv) between this transformed
     /// shape and a ray.
     #[inline]
@@ -104,4 +211,28 @@ pub trait RayCast<P: Point, M> {
     fn intersects_ray(&self, m: &M, ray: &Ray<P>) -> bool {
         self.toi_with_ray(m, ray, true).is_some()
     }
+
+    /// Computes the time of impact and normal between this transformed shape and each ray of
+    /// `rays`.
+    ///
+    /// The i-th element of the result is the intersection with the i-th ray, if any. The rays are
+    /// cast sequentially: meshes and compounds already traverse their stored BVT for each ray
+    /// without rebuilding anything, and this crate has no parallel implementation.
+    #[inline]
+    fn cast_rays(&self, m: &M, rays: &[Ray<P>], solid: bool) -> Vec<Option<RayIntersection<P::Vector>>> {
+        rays.iter().map(|ray| self.toi_and_normal_with_ray(m, ray, solid)).collect()
+    }
+
+    /// Same as `cast_rays`, but also returns the world-space hit point of each ray.
+    ///
+    /// The hit points are computed by `RayIntersection::point`.
+    #[inline]
+    fn cast_rays_with_points(&self, m: &M, rays: &[Ray<P>], solid: bool) -> Vec<Option<(RayIntersection<P::Vector>, P)>> {
+        rays.iter().map(|ray| {
+            self.toi_and_normal_with_ray(m, ray, solid).map(|inter| {
+                let pt = inter.point(ray);
+                (inter, pt)
+            })
+        }).collect()
+    }
 }
@@ -1,11 +1,13 @@
 use std::mem;
 use num::{Zero, Bounded};
 
+use approx::ApproxEq;
 use alga::general::Real;
-use na::{self, Point2};
+use na::{self, Point2, Unit};
 
 use query::{Ray, RayCast, RayIntersection};
 use bounding_volume::AABB;
+use shape::FeatureId;
 use math::{Point, Isometry};
 
 
@@ -13,6 +15,10 @@ impl<P: Point, M: Isometry<P>> RayCast<P, M> for AABB<P> {
     fn toi_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<P::Real> {
         let ls_ray = ray.inverse_transform_by(m);
 
+        if !ls_ray.is_finite() {
+            return None;
+        }
+
         let mut tmin: P::Real = na::zero();
         let mut tmax: P::Real = Bounded::max_value();
 
@@ -54,7 +60,23 @@ impl<P: Point, M: Isometry<P>> RayCast<P, M> for AABB<P> {
     fn toi_and_normal_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<RayIntersection<P::Vector>> {
         let ls_ray = ray.inverse_transform_by(m);
 
-        ray_aabb(self, &ls_ray, solid).map(|(t, n, _)| RayIntersection::new(t, m.rotate_vector(&n)))
+        if !ls_ray.is_finite() {
+            return None;
+        }
+
+        do_toi_and_oriented_normal_with_ray(m, self, &ls_ray, solid, true)
+    }
+
+    #[inline]
+    fn toi_and_oriented_normal_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool, flip_to_face_ray: bool)
+                                        -> Option<RayIntersection<P::Vector>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        if !ls_ray.is_finite() {
+            return None;
+        }
+
+        do_toi_and_oriented_normal_with_ray(m, self, &ls_ray, solid, flip_to_face_ray)
     }
 
     fn toi_and_normal_and_uv_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<RayIntersection<P::Vector>> {
@@ -62,6 +84,20 @@ impl<P: Point, M: Isometry<P>> RayCast<P, M> for AABB<P> {
     }
 }
 
+fn do_toi_and_oriented_normal_with_ray<M, P>(m: &M, aabb: &AABB<P>, ls_ray: &Ray<P>, solid: bool,
+                                              flip_to_face_ray: bool)
+                                              -> Option<RayIntersection<P::Vector>>
+    where P: Point,
+          M: Isometry<P> {
+    ray_aabb(aabb, ls_ray, solid, flip_to_face_ray).and_then(|(t, n, _, feature)| {
+        Unit::try_new(m.rotate_vector(&n), P::Real::default_epsilon()).map(|n| {
+            let mut inter = RayIntersection::new(t, n);
+            inter.feature = feature;
+            inter
+        })
+    })
+}
+
 fn do_toi_and_normal_and_uv_with_ray<M, P>(m: &M, aabb: &AABB<P>, ray: &Ray<P>, solid: bool)
                                            -> Option<RayIntersection<P::Vector>>
     where P: Point,
@@ -72,27 +108,59 @@ fn do_toi_and_normal_and_uv_with_ray<M, P>(m: &M, aabb: &AABB<P>, ray: &Ray<P>,
     else {
         let ls_ray = ray.inverse_transform_by(m);
 
-        ray_aabb(aabb, &ls_ray, solid).map(|(t, n, s)| {
+        if !ls_ray.is_finite() {
+            return None;
+        }
+
+        ray_aabb(aabb, &ls_ray, solid, true).and_then(|(t, n, s, feature)| {
             let pt    = ls_ray.origin + ls_ray.dir * t;
             let dpt   = pt - *aabb.mins();
             let scale = *aabb.maxs() - *aabb.mins();
             let id    = na::abs(&s);
-            let gs_n  = m.rotate_vector(&n);
+            let gs_n  = match Unit::try_new(m.rotate_vector(&n), P::Real::default_epsilon()) {
+                Some(gs_n) => gs_n,
+                None       => return None
+            };
 
-            if id == 1 {
-                RayIntersection::new_with_uvs(t, gs_n, Some(Point2::new(dpt[1] / scale[1], dpt[2] / scale[2])))
+            let uvs = if id == 1 {
+                Point2::new(dpt[1] / scale[1], dpt[2] / scale[2])
             }
             else if id == 2 {
-                RayIntersection::new_with_uvs(t, gs_n, Some(Point2::new(dpt[2] / scale[2], dpt[0] / scale[0])))
+                Point2::new(dpt[2] / scale[2], dpt[0] / scale[0])
             }
             else {
-                RayIntersection::new_with_uvs(t, gs_n, Some(Point2::new(dpt[0] / scale[0], dpt[1] / scale[1])))
-            }
+                Point2::new(dpt[0] / scale[0], dpt[1] / scale[1])
+            };
+
+            let mut inter = RayIntersection::new_with_uvs(t, gs_n, Some(uvs));
+            inter.feature = feature;
+            Some(inter)
         })
     }
 }
 
-fn ray_aabb<P>(aabb: &AABB<P>, ray: &Ray<P>, solid: bool) -> Option<(P::Real, P::Vector, isize)>
+// The identifier of the face of `aabb` on the given side, with the same convention as
+// `Cuboid::support_feature_id`. The axis of the side is `|side| - 1`, and `side > 0` for the
+// face of the `mins()`, i.e., on the negative side of the axis.
+fn face_feature_id<P: Point>(aabb: &AABB<P>, side: isize) -> FeatureId {
+    let dim  = na::dimension::<P::Vector>();
+    let axis = (side.abs() - 1) as usize;
+    let free = ((1usize << dim) - 1) & !(1 << axis);
+
+    // The sign along a flattened axis is meaningless.
+    if side > 0 && aabb.mins()[axis] != aabb.maxs()[axis] {
+        FeatureId::Face((free << dim) | (1 << axis))
+    }
+    else {
+        FeatureId::Face(free << dim)
+    }
+}
+
+// The returned normal is not normalized if the ray hits an edge or a vertex, or starts inside of a
+// solid AABB. The feature is unknown in those cases. If the ray leaves a non-solid AABB, the
+// normal faces the ray origin if `flip_to_face_ray` is `true`, and points outward otherwise.
+fn ray_aabb<P>(aabb: &AABB<P>, ray: &Ray<P>, solid: bool, flip_to_face_ray: bool)
+               -> Option<(P::Real, P::Vector, isize, FeatureId)>
     where P: Point {
     let mut tmax: P::Real = Bounded::max_value();
     let mut tmin: P::Real = -tmax;
@@ -111,17 +179,16 @@ fn ray_aabb<P>(aabb: &AABB<P>, ray: &Ray<P>, solid: bool) -> Option<(P::Real, P:
         else {
             let _1: P::Real = na::one();
             let denom = _1 / ray.dir[i];
-            let flip_sides;
             let mut inter_with_near_plane = (aabb.mins()[i] - ray.origin[i]) * denom;
             let mut inter_with_far_plane  = (aabb.maxs()[i] - ray.origin[i]) * denom;
 
-            if inter_with_near_plane > inter_with_far_plane {
-                flip_sides = true;
+            // Test the direction instead of comparing the intersections so that the sides of a flat
+            // AABB (with `mins()[i] == maxs()[i]`) are still identified correctly.
+            let flip_sides = denom < na::zero();
+
+            if flip_sides {
                 mem::swap(&mut inter_with_near_plane, &mut inter_with_far_plane)
             }
-            else {
-                flip_sides = false;
-            }
 
             if inter_with_near_plane > tmin {
                 tmin      = inter_with_near_plane;
@@ -147,14 +214,19 @@ fn ray_aabb<P>(aabb: &AABB<P>, ray: &Ray<P>, solid: bool) -> Option<(P::Real, P:
         }
     }
 
+    if tmax < na::zero() {
+        // the box is behind the ray
+        return None;
+    }
+
     if tmin < na::convert(0.0f64) {
         // the ray starts inside of the box
         if solid {
-            Some((na::zero(), na::zero(), far_side))
+            Some((na::zero(), -ray.dir, far_side, FeatureId::Unknown))
         }
         else {
-            if far_diag {
-                Some((tmax, -na::normalize(&ray.dir), far_side))
+            let mut res = if far_diag {
+                (tmax, -ray.dir, far_side, FeatureId::Unknown)
             }
             else {
                 let mut normal = na::zero::<P::Vector>();
@@ -166,13 +238,19 @@ fn ray_aabb<P>(aabb: &AABB<P>, ray: &Ray<P>, solid: bool) -> Option<(P::Real, P:
                     normal[(far_side - 1) as usize] = na::one::<P::Real>();
                 }
 
-                Some((tmax, normal, far_side))
+                (tmax, normal, far_side, face_feature_id(aabb, far_side))
+            };
+
+            if !flip_to_face_ray && !tmax.is_zero() {
+                res.1 = -res.1;
             }
+
+            Some(res)
         }
     }
     else {
         if near_diag {
-            Some((tmin, -na::normalize(&ray.dir), near_side))
+            Some((tmin, -ray.dir, near_side, FeatureId::Unknown))
         }
         else {
             let mut normal = na::zero::<P::Vector>();
@@ -183,7 +261,7 @@ fn ray_aabb<P>(aabb: &AABB<P>, ray: &Ray<P>, solid: bool) -> Option<(P::Real, P:
             else {
                 normal[(near_side - 1) as usize] = -na::one::<P::Real>();
             }
-            Some((tmin, normal, near_side))
+            Some((tmin, normal, near_side, face_feature_id(aabb, near_side)))
         }
     }
 }
@@ -1,8 +1,9 @@
 use alga::general::Real;
-use na::{self, Point2};
+use na::{self, Point2, Unit};
 
 use query::{Ray, RayCast, RayIntersection};
-use shape::Ball;
+use shape::{Ball, FeatureId};
+use utils;
 use math::{Point, Vector, Isometry};
 
 
@@ -33,11 +34,41 @@ impl<P: Point, M: Isometry<P>> RayCast<P, M> for Ball<P::Real> {
         let center = m.translate_point(&P::origin());
         let (inside, inter) = ball_toi_with_ray(&center, self.radius(), ray, solid);
 
-        inter.map(|n| {
+        inter.and_then(|n| {
+            if inside && solid {
+                // The ray origin is inside of the filled ball.
+                return RayIntersection::new_inside(&ray.dir);
+            }
+
+            let pos    = ray.origin + ray.dir * n - center;
+            let normal = Unit::new_normalize(pos);
+
+            let mut inter = RayIntersection::new(n, if inside { -normal } else { normal });
+            inter.feature = FeatureId::Face(0);
+
+            Some(inter)
+        })
+    }
+
+    #[inline]
+    fn toi_and_oriented_normal_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool, flip_to_face_ray: bool)
+                                        -> Option<RayIntersection<P::Vector>> {
+        let center = m.translate_point(&P::origin());
+        let (inside, inter) = ball_toi_with_ray(&center, self.radius(), ray, solid);
+
+        inter.and_then(|n| {
+            if inside && solid {
+                // The ray origin is inside of the filled ball.
+                return RayIntersection::new_inside(&ray.dir);
+            }
+
             let pos    = ray.origin + ray.dir * n - center;
-            let normal = na::normalize(&pos);
+            let normal = Unit::new_normalize(pos);
+
+            let mut inter = RayIntersection::new(n, if inside && flip_to_face_ray { -normal } else { normal });
+            inter.feature = FeatureId::Face(0);
 
-            RayIntersection::new(n, if inside { -normal } else { normal })
+            Some(inter)
         })
     }
 
@@ -46,17 +77,30 @@ impl<P: Point, M: Isometry<P>> RayCast<P, M> for Ball<P::Real> {
         let center = m.translate_point(&P::origin());
         let (inside, inter) = ball_toi_with_ray(&center, self.radius(), ray, solid);
 
-        inter.map(|n| {
+        inter.and_then(|n| {
+            if inside && solid {
+                // The ray origin is inside of the filled ball.
+                return RayIntersection::new_inside(&ray.dir);
+            }
+
             let pos    = ray.origin + ray.dir * n - center;
-            let normal = na::normalize(&pos);
-            let uv     = ball_uv(&normal);
+            let normal = Unit::new_normalize(pos);
+            let uv     = ball_uv(normal.as_ref());
+
+            let mut inter = RayIntersection::new_with_uvs(n, if inside { -normal } else { normal }, uv);
+            inter.feature = FeatureId::Face(0);
 
-            RayIntersection::new_with_uvs(n, if inside { -normal } else { normal }, uv)
+            Some(inter)
         })
     }
 }
 
 /// Computes the time of impact of a ray on a ball.
+///
+/// The returned boolean indicates whether the ray origin is inside of the ball. In that case, the
+/// time of impact is zero if `solid` is `true`, and the time of impact with the exit point of the
+/// ray otherwise. A ray tangent to the ball is considered to hit it at its single contact point.
+/// A ray or a center with an infinite or NaN component never hits the ball.
 #[inline]
 pub fn ball_toi_with_ray<P>(center: &P,
                             radius: P::Real,
@@ -64,6 +108,10 @@ pub fn ball_toi_with_ray<P>(center: &P,
                             solid:  bool)
                             -> (bool, Option<P::Real>)
     where P: Point {
+    if !ray.is_finite() || !utils::is_finite_point(center) {
+        return (false, None);
+    }
+
     let dcenter = ray.origin - *center;
 
     let a = na::norm_squared(&ray.dir);
@@ -74,7 +122,10 @@ pub fn ball_toi_with_ray<P>(center: &P,
         (false, None)
     }
     else {
-        let delta = b * b - a * c;
+        // Equivalent to `b * b - a * c`, but much more accurate for rays nearly tangent to the
+        // ball (which have a single root).
+        let perp  = dcenter - ray.dir * (b / a);
+        let delta = a * (radius * radius - na::norm_squared(&perp));
 
         if delta < na::zero() {
             // no solution
@@ -1,10 +1,22 @@
 use alga::general::Id;
 
-use query::{Ray, RayCast, RayIntersection};
+use query::{Ray, RayCast, RayIntersection, ray_internal};
 use shape::Ball;
 use bounding_volume::BoundingSphere;
 use math::{Point, Isometry};
 
+impl<P: Point> BoundingSphere<P> {
+    /// The parameter at which `ray` enters this bounding sphere, or zero if its origin is inside.
+    ///
+    /// This is the smallest non-negative parameter of the points of the ray inside of this
+    /// bounding sphere, i.e., a lower bound of the time of impact of the ray with any object
+    /// contained by this bounding sphere. Thus, it is suitable as the priority of a node on a
+    /// best-first traversal of a BVT. Returns `None` if the ray misses this bounding sphere.
+    #[inline]
+    pub fn ray_entry_toi(&self, ray: &Ray<P>) -> Option<P::Real> {
+        ray_internal::ball_toi_with_ray(self.center(), self.radius(), ray, true).1
+    }
+}
 
 impl<P: Point, M: Isometry<P>> RayCast<P, M> for BoundingSphere<P> {
     #[inline]
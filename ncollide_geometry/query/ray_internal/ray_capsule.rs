@@ -0,0 +1,150 @@
+use num::{Zero, Bounded};
+
+use alga::general::Real;
+use na::{self, Unit};
+
+use query::{Ray, RayCast, RayIntersection};
+use shape::{Capsule, FeatureId};
+use math::{Point, Isometry};
+
+
+/// The interval of parameters of the points of the line supporting `ray` that are at a distance
+/// smaller than `radius` from the origin.
+#[inline]
+pub fn ball_interval<P: Point>(origin: &P::Vector, dir: &P::Vector, radius: P::Real)
+                               -> Option<(P::Real, P::Real)> {
+    let a = na::norm_squared(dir);
+
+    if a.is_zero() {
+        if na::norm_squared(origin) <= radius * radius {
+            let inf: P::Real = Bounded::max_value();
+            return Some((-inf, inf));
+        }
+        else {
+            return None;
+        }
+    }
+
+    let b     = na::dot(origin, dir);
+    let perp  = *origin - *dir * (b / a);
+    let delta = a * (radius * radius - na::norm_squared(&perp));
+
+    if delta < na::zero() {
+        None
+    }
+    else {
+        // A null `delta` means the ray is tangent: both bounds are the same grazing point.
+        let sqrt_delta = delta.sqrt();
+        Some(((-b - sqrt_delta) / a, (-b + sqrt_delta) / a))
+    }
+}
+
+/// The smallest interval containing both `i1` and `i2`.
+#[inline]
+pub fn merge_intervals<N: Real>(i1: Option<(N, N)>, i2: Option<(N, N)>) -> Option<(N, N)> {
+    match (i1, i2) {
+        (Some((min1, max1)), Some((min2, max2))) => Some((min1.min(min2), max1.max(max2))),
+        (Some(i1), None) => Some(i1),
+        (None, i2)       => i2
+    }
+}
+
+/// The interval of parameters of the points of the line supporting `ls_ray` that are inside of
+/// `capsule`.
+fn capsule_interval<P: Point>(capsule: &Capsule<P::Real>, ls_ray: &Ray<P>) -> Option<(P::Real, P::Real)> {
+    let h = capsule.half_height();
+    let r = capsule.radius();
+
+    // The two caps. Because the capsule is convex, the union of the intervals of its parts is
+    // itself an interval.
+    let mut shift: P::Vector = na::zero();
+    shift[1] = h;
+
+    let mut interval = merge_intervals(
+        ball_interval::<P>(&(ls_ray.origin.coordinates() - shift), &ls_ray.dir, r),
+        ball_interval::<P>(&(ls_ray.origin.coordinates() + shift), &ls_ray.dir, r));
+
+    // The body is the intersection of an infinite cylinder and a slab.
+    let mut proj_origin = ls_ray.origin.coordinates();
+    let mut proj_dir    = ls_ray.dir;
+    proj_origin[1] = na::zero();
+    proj_dir[1]    = na::zero();
+
+    if let Some((cmin, cmax)) = ball_interval::<P>(&proj_origin, &proj_dir, r) {
+        let slab =
+            if ls_ray.dir[1].is_zero() {
+                if ls_ray.origin[1].abs() <= h {
+                    Some((cmin, cmax))
+                }
+                else {
+                    None
+                }
+            }
+            else {
+                let t1 = (-h - ls_ray.origin[1]) / ls_ray.dir[1];
+                let t2 = (h - ls_ray.origin[1]) / ls_ray.dir[1];
+                let (smin, smax) = if t1 <= t2 { (t1, t2) } else { (t2, t1) };
+
+                let tmin = cmin.max(smin);
+                let tmax = cmax.min(smax);
+
+                if tmin <= tmax {
+                    Some((tmin, tmax))
+                }
+                else {
+                    None
+                }
+            };
+
+        interval = merge_intervals(interval, slab);
+    }
+
+    interval
+}
+
+impl<P: Point, M: Isometry<P>> RayCast<P, M> for Capsule<P::Real> {
+    /// Computes the time of impact and normal of a ray with this capsule.
+    ///
+    /// If the ray origin is inside of the capsule, the time of impact is zero if `solid` is
+    /// `true` (with a normal opposite to the ray direction). Otherwise, it is the time of impact with the exit point and the
+    /// normal points toward the inside of the capsule.
+    fn toi_and_normal_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<RayIntersection<P::Vector>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        if !ls_ray.is_finite() {
+            return None;
+        }
+
+        let (tmin, tmax) = match capsule_interval(self, &ls_ray) {
+            Some(interval) => interval,
+            None           => return None
+        };
+
+        if tmax < na::zero() {
+            // The capsule is behind the ray.
+            return None;
+        }
+
+        let inside = tmin <= na::zero();
+
+        if inside && solid {
+            return RayIntersection::new_inside(&ray.dir);
+        }
+
+        let toi = if inside { tmax } else { tmin };
+
+        // The normal is directed from the closest point of the capsule axis to the impact point.
+        let mut normal = (ls_ray.origin + ls_ray.dir * toi).coordinates();
+        let h          = self.half_height();
+
+        normal[1] = if normal[1] > h { normal[1] - h } else if normal[1] < -h { normal[1] + h } else { na::zero() };
+
+        let normal = Unit::new_normalize(m.rotate_vector(&normal));
+
+        // The boundary of a capsule is a single smooth surface.
+        let mut inter = RayIntersection::new(toi, if inside { -normal } else { normal });
+        inter.feature = FeatureId::Face(0);
+
+        Some(inter)
+    }
+}
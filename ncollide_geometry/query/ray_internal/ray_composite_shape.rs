@@ -0,0 +1,177 @@
+use std::marker::PhantomData;
+use std::cmp::Ordering;
+
+use approx::ApproxEq;
+use alga::general::{Id, Real};
+use na::{self, Unit};
+use bounding_volume::AABB;
+use shape::CompositeShape;
+use partitioning::BVTCostFn;
+use query::{Ray, RayCast, RayIntersection};
+use query::ray_internal::RayInterferencesCollector;
+use math::{Point, Isometry};
+
+
+/// Computes the time of impact between a ray and a transformed composite shape.
+///
+/// The BVT of the composite shape is traversed in best-first order, using the entry distance of
+/// the ray into each bounding volume: only the parts with a bounding volume closer than the best
+/// hit found so far are tested.
+pub fn composite_shape_toi_with_ray<P, M, G: ?Sized>(m: &M, g: &G, ray: &Ray<P>, solid: bool) -> Option<P::Real>
+    where P: Point,
+          M: Isometry<P>,
+          G: CompositeShape<P, M> {
+    let ls_ray = ray.inverse_transform_by(m);
+
+    if !ls_ray.is_finite() {
+        return None;
+    }
+
+    let mut cost_fn = CompositeShapeRayToiCostFn { shape: g, ray: &ls_ray, solid: solid, _marker: PhantomData };
+
+    g.bvt().best_first_search(&mut cost_fn).map(|(_, res)| res)
+}
+
+/// Computes the time of impact and normal between a ray and a transformed composite shape,
+/// together with the index of the part hit first.
+pub fn composite_shape_toi_and_normal_with_ray<P, M, G: ?Sized>(m: &M, g: &G, ray: &Ray<P>, solid: bool)
+                                                                -> Option<(usize, RayIntersection<P::Vector>)>
+    where P: Point,
+          M: Isometry<P>,
+          G: CompositeShape<P, M> {
+    let ls_ray = ray.inverse_transform_by(m);
+
+    if !ls_ray.is_finite() {
+        return None;
+    }
+
+    let mut cost_fn = CompositeShapeRayToiAndNormalCostFn { shape: g, ray: &ls_ray, solid: solid, _marker: PhantomData };
+
+    g.bvt().best_first_search(&mut cost_fn).map(|(_, (part, mut res))| {
+        res.normal = Unit::new_unchecked(m.rotate_vector(&res.normal)); (part, res)
+    })
+}
+
+/// Computes all the intersections between a ray and a transformed composite shape, together with
+/// the index of the part hit by each one.
+///
+/// The intersections are sorted by increasing time of impact. Intersections with almost equal
+/// times of impact on parts sharing a vertex, see `CompositeShape::parts_share_vertex`, e.g., when
+/// the ray hits an edge shared by two triangles, are reported only once, with the smallest part
+/// index. Distinct parts merely touching each other, e.g., in a compound, are all reported.
+pub fn composite_shape_toi_all_with_ray<P, M, G: ?Sized>(m: &M, g: &G, ray: &Ray<P>, solid: bool)
+                                                         -> Vec<(usize, RayIntersection<P::Vector>)>
+    where P: Point,
+          M: Isometry<P>,
+          G: CompositeShape<P, M> {
+    let ls_ray = ray.inverse_transform_by(m);
+
+    if !ls_ray.is_finite() {
+        return Vec::new();
+    }
+
+    let mut parts = Vec::new();
+
+    {
+        let mut visitor = RayInterferencesCollector::new(&ls_ray, &mut parts);
+        g.bvt().visit(&mut visitor);
+    }
+
+    let mut hits: Vec<(usize, RayIntersection<P::Vector>)> = Vec::new();
+
+    for part in parts.into_iter() {
+        g.map_part_at(part, &mut |objm, obj| {
+            if let Some(mut inter) = obj.toi_and_normal_with_ray(objm, &ls_ray, solid) {
+                inter.normal = Unit::new_unchecked(m.rotate_vector(&inter.normal));
+                hits.push((part, inter))
+            }
+        });
+    }
+
+    hits.sort_by(|a, b| a.1.toi.partial_cmp(&b.1.toi).unwrap_or(Ordering::Equal).then(a.0.cmp(&b.0)));
+
+    let eps     = P::Real::default_epsilon().sqrt();
+    let mut res = Vec::with_capacity(hits.len());
+
+    for hit in hits.into_iter() {
+        let duplicate = {
+            let mut kept = res.iter().rev().take_while(|kept: &&(usize, RayIntersection<P::Vector>)| {
+                hit.1.toi - kept.1.toi <= eps * na::one::<P::Real>().max(kept.1.toi.abs())
+            });
+
+            kept.any(|kept| g.parts_share_vertex(kept.0, hit.0))
+        };
+
+        if !duplicate {
+            res.push(hit)
+        }
+    }
+
+    res
+}
+
+/*
+ * Costs functions.
+ */
+struct CompositeShapeRayToiCostFn<'a, P: 'a + Point, M, G: ?Sized + 'a> {
+    shape:   &'a G,
+    ray:     &'a Ray<P>,
+    solid:   bool,
+    _marker: PhantomData<M>
+}
+
+impl<'a, P, M, G: ?Sized> BVTCostFn<P::Real, usize, AABB<P>> for CompositeShapeRayToiCostFn<'a, P, M, G>
+    where P: Point,
+          M: Isometry<P>,
+          G: CompositeShape<P, M> {
+    type UserData = P::Real;
+    #[inline]
+    fn compute_bv_cost(&mut self, aabb: &AABB<P>) -> Option<P::Real> {
+        // The entry distance into the AABB, zero if the ray starts inside of it, is a lower bound
+        // of the hits with the parts it contains. Its exit distance, given by a non-solid cast,
+        // is not.
+        aabb.toi_with_ray(&Id::new(), self.ray, true)
+    }
+
+    #[inline]
+    fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, P::Real)> {
+        let mut res = None;
+
+        self.shape.map_part_at(*b, &mut |objm, obj| {
+            res = obj.toi_with_ray(objm, self.ray, self.solid).map(|toi| (toi, toi))
+        });
+
+        res
+    }
+}
+
+struct CompositeShapeRayToiAndNormalCostFn<'a, P: 'a + Point, M, G: ?Sized + 'a> {
+    shape:   &'a G,
+    ray:     &'a Ray<P>,
+    solid:   bool,
+    _marker: PhantomData<M>
+}
+
+impl<'a, P, M, G: ?Sized> BVTCostFn<P::Real, usize, AABB<P>> for CompositeShapeRayToiAndNormalCostFn<'a, P, M, G>
+    where P: Point,
+          M: Isometry<P>,
+          G: CompositeShape<P, M> {
+    type UserData = (usize, RayIntersection<P::Vector>);
+
+    #[inline]
+    fn compute_bv_cost(&mut self, aabb: &AABB<P>) -> Option<P::Real> {
+        // Solid cast for a lower bound, see `CompositeShapeRayToiCostFn`.
+        aabb.toi_with_ray(&Id::new(), self.ray, true)
+    }
+
+    #[inline]
+    fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, (usize, RayIntersection<P::Vector>))> {
+        let mut res = None;
+
+        self.shape.map_part_at(*b, &mut |objm, obj| {
+            res = obj.toi_and_normal_with_ray(objm, self.ray, self.solid).map(|inter| (inter.toi, (*b, inter)))
+        });
+
+        res
+    }
+}
@@ -0,0 +1,191 @@
+use num::{Zero, Bounded};
+
+use approx::ApproxEq;
+use alga::general::Real;
+use na::{self, Unit};
+
+use query::{Ray, RayCast, RayIntersection};
+use query::ray_internal::ray_capsule::merge_intervals;
+use shape::{Cone, SupportMap};
+use math::{Point, Isometry};
+
+
+/// Intersects the interval `[tmin, tmax]` with the slab `[smin, smax]`.
+#[inline]
+fn clip_interval<N: Real>(tmin: N, tmax: N, smin: N, smax: N) -> Option<(N, N)> {
+    let tmin = tmin.max(smin);
+    let tmax = tmax.min(smax);
+
+    if tmin <= tmax {
+        Some((tmin, tmax))
+    }
+    else {
+        None
+    }
+}
+
+/// The interval of parameters of the points of the line supporting `ls_ray` that are inside of
+/// `cone`.
+fn cone_interval<P: Point>(cone: &Cone<P::Real>, ls_ray: &Ray<P>) -> Option<(P::Real, P::Real)> {
+    let h   = cone.half_height();
+    let _2: P::Real = na::convert(2.0f64);
+    let inf: P::Real = Bounded::max_value();
+
+    // The slab between the base plane and the apex.
+    let (smin, smax) =
+        if ls_ray.dir[1].is_zero() {
+            if ls_ray.origin[1].abs() <= h {
+                (-inf, inf)
+            }
+            else {
+                return None;
+            }
+        }
+        else {
+            let t1 = (-h - ls_ray.origin[1]) / ls_ray.dir[1];
+            let t2 = (h - ls_ray.origin[1]) / ls_ray.dir[1];
+
+            if t1 <= t2 { (t1, t2) } else { (t2, t1) }
+        };
+
+    // The infinite double cone `|x_perp|² <= k² (h - y)²`. Inside of the slab, only the nappe
+    // containing the cone remains.
+    let k  = cone.radius() / (h * _2);
+    let k2 = k * k;
+
+    let mut perp_origin = ls_ray.origin.coordinates();
+    let mut perp_dir    = ls_ray.dir;
+    perp_origin[1] = na::zero();
+    perp_dir[1]    = na::zero();
+
+    let apex_dist = h - ls_ray.origin[1];
+    let a = na::norm_squared(&perp_dir) - k2 * ls_ray.dir[1] * ls_ray.dir[1];
+    let b = na::dot(&perp_origin, &perp_dir) + k2 * apex_dist * ls_ray.dir[1];
+    let c = na::norm_squared(&perp_origin) - k2 * apex_dist * apex_dist;
+
+    if a.is_zero() {
+        // The ray is parallel to the lateral surface: `2 b t + c <= 0`.
+        if b.is_zero() {
+            if c <= na::zero() { clip_interval(-inf, inf, smin, smax) } else { None }
+        }
+        else {
+            let t = -c / (b * _2);
+
+            if b > na::zero() {
+                clip_interval(-inf, t, smin, smax)
+            }
+            else {
+                clip_interval(t, inf, smin, smax)
+            }
+        }
+    }
+    else {
+        let delta = b * b - a * c;
+
+        if a > na::zero() {
+            if delta < na::zero() {
+                None
+            }
+            else {
+                // A null `delta` means a single tangent root, e.g., a ray grazing the apex.
+                let sqrt_delta = delta.sqrt();
+                clip_interval((-b - sqrt_delta) / a, (-b + sqrt_delta) / a, smin, smax)
+            }
+        }
+        else {
+            // The line crosses both nappes.
+            if delta < na::zero() {
+                clip_interval(-inf, inf, smin, smax)
+            }
+            else {
+                let sqrt_delta = delta.sqrt();
+                let t1 = (-b + sqrt_delta) / a;
+                let t2 = (-b - sqrt_delta) / a;
+
+                merge_intervals(clip_interval(-inf, t1, smin, smax), clip_interval(t2, inf, smin, smax))
+            }
+        }
+    }
+}
+
+/// The outward normal of `cone` at the point `pt` of its boundary.
+///
+/// On the rim, i.e., at the points at a distance smaller than a tolerance from both the base and
+/// the lateral surface, this is the normalized sum of the normals of both surfaces.
+fn cone_normal<P: Point>(cone: &Cone<P::Real>, pt: &P) -> P::Vector {
+    let h   = cone.half_height();
+    let _2: P::Real = na::convert(2.0f64);
+    let k   = cone.radius() / (h * _2);
+    let tol = P::Real::default_epsilon().sqrt() * h.max(cone.radius());
+
+    let mut perp = pt.coordinates();
+    perp[1] = na::zero();
+
+    let perp_norm = na::norm(&perp);
+
+    // Distances to the base plane and to the lateral surface.
+    let base_dist    = (pt[1] + h).abs();
+    let lateral_dist = (perp_norm - k * (h - pt[1])).abs() / (na::one::<P::Real>() + k * k).sqrt();
+
+    let mut base_normal: P::Vector = na::zero();
+    base_normal[1] = -na::one::<P::Real>();
+
+    if perp_norm.is_zero() {
+        // At the apex or at the center of the base.
+        return if base_dist <= lateral_dist { base_normal } else { -base_normal };
+    }
+
+    let mut lateral_normal = perp / perp_norm;
+    lateral_normal[1] = k;
+    lateral_normal    = na::normalize(&lateral_normal);
+
+    if base_dist <= tol && lateral_dist <= tol {
+        na::normalize(&(base_normal + lateral_normal))
+    }
+    else if base_dist <= lateral_dist {
+        base_normal
+    }
+    else {
+        lateral_normal
+    }
+}
+
+impl<P: Point, M: Isometry<P>> RayCast<P, M> for Cone<P::Real> {
+    /// Computes the time of impact and normal of a ray with this cone.
+    ///
+    /// If the ray origin is inside of the cone, the time of impact is zero if `solid` is `true`
+    /// (with a normal opposite to the ray direction). Otherwise, it is the time of impact with the exit point and the normal
+    /// points toward the inside of the cone.
+    fn toi_and_normal_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<RayIntersection<P::Vector>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        if !ls_ray.is_finite() {
+            return None;
+        }
+
+        let (tmin, tmax) = match cone_interval(self, &ls_ray) {
+            Some(interval) => interval,
+            None           => return None
+        };
+
+        if tmax < na::zero() {
+            // The cone is behind the ray.
+            return None;
+        }
+
+        let inside = tmin <= na::zero();
+
+        if inside && solid {
+            return RayIntersection::new_inside(&ray.dir);
+        }
+
+        let toi    = if inside { tmax } else { tmin };
+        let normal = Unit::new_unchecked(m.rotate_vector(&cone_normal(self, &(ls_ray.origin + ls_ray.dir * toi))));
+
+        let mut inter = RayIntersection::new(toi, if inside { -normal } else { normal });
+        // The face, rim or apex hit, with the same tolerance as the normal.
+        inter.feature = self.support_feature_id(m, &[ ray.origin + ray.dir * toi ]);
+
+        Some(inter)
+    }
+}
@@ -0,0 +1,81 @@
+use num::Bounded;
+use approx::ApproxEq;
+use na::{self, Unit};
+
+use query::{Ray, RayCast, RayIntersection};
+use shape::{ConvexPolygon, FeatureId};
+use math::{self, Point, Vector, Isometry};
+
+
+impl<P: Point, M: Isometry<P>> RayCast<P, M> for ConvexPolygon<P> {
+    fn toi_and_normal_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<RayIntersection<P::Vector>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        if !ls_ray.is_finite() {
+            return None;
+        }
+
+        let mut tmin: P::Real = na::zero();
+        let mut tmax: P::Real = Bounded::max_value();
+        let mut near_normal   = None;
+        let mut far_normal    = None;
+        let mut inside        = true;
+
+        // Clip the ray by the half-plane of each edge.
+        for i in 0 .. self.points().len() {
+            let normal = self.edge_normal(i);
+            let num    = na::dot(&normal, &(*self.edge(i).0 - ls_ray.origin));
+            let denom  = na::dot(&normal, &ls_ray.dir);
+
+            if num < na::zero() {
+                inside = false;
+            }
+
+            let parallel_tol = P::Real::default_epsilon() * na::norm(&normal) * na::norm(&ls_ray.dir);
+
+            if math::approx_eq(denom, na::zero(), parallel_tol, na::zero()) {
+                if num < na::zero() {
+                    return None;
+                }
+            }
+            else {
+                let t = num / denom;
+
+                if denom < na::zero() {
+                    if t > tmin {
+                        tmin        = t;
+                        near_normal = Some((normal, i));
+                    }
+                }
+                else if t < tmax {
+                    tmax       = t;
+                    far_normal = Some((normal, i));
+                }
+            }
+
+            if tmin > tmax {
+                return None;
+            }
+        }
+
+        if inside {
+            if solid {
+                RayIntersection::new_inside(&ray.dir)
+            }
+            else {
+                far_normal.map(|(n, i)| edge_intersection(tmax, -Unit::new_unchecked(m.rotate_vector(&n)), i))
+            }
+        }
+        else {
+            near_normal.map(|(n, i)| edge_intersection(tmin, Unit::new_unchecked(m.rotate_vector(&n)), i))
+        }
+    }
+}
+
+// The intersection of a ray with the `i`-th edge of a convex polygon.
+fn edge_intersection<V: Vector>(toi: V::Real, normal: Unit<V>, i: usize) -> RayIntersection<V> {
+    let mut inter = RayIntersection::new(toi, normal);
+    inter.feature = FeatureId::Edge(i);
+
+    inter
+}
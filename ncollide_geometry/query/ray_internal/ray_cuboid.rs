@@ -19,6 +19,14 @@ impl<P: Point, M: Isometry<P>> RayCast<P, M> for Cuboid<P::Vector> {
         AABB::new(dl, ur).toi_and_normal_with_ray(m, ray, solid)
     }
 
+    #[inline]
+    fn toi_and_oriented_normal_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool, flip_to_face_ray: bool)
+                                        -> Option<RayIntersection<P::Vector>> {
+        let dl = P::from_coordinates(-*self.half_extents());
+        let ur = P::from_coordinates(*self.half_extents());
+        AABB::new(dl, ur).toi_and_oriented_normal_with_ray(m, ray, solid, flip_to_face_ray)
+    }
+
     #[inline]
     fn toi_and_normal_and_uv_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<RayIntersection<P::Vector>> {
         let dl = P::from_coordinates(-*self.half_extents());
@@ -0,0 +1,128 @@
+use num::{Zero, Bounded};
+
+use approx::ApproxEq;
+use alga::general::Real;
+use na::{self, Unit};
+
+use query::{Ray, RayCast, RayIntersection};
+use query::ray_internal::ray_capsule::ball_interval;
+use shape::{Cylinder, SupportMap};
+use math::{Point, Isometry};
+
+
+/// The interval of parameters of the points of the line supporting `ls_ray` that are inside of
+/// `cylinder`.
+fn cylinder_interval<P: Point>(cylinder: &Cylinder<P::Real>, ls_ray: &Ray<P>) -> Option<(P::Real, P::Real)> {
+    let h = cylinder.half_height();
+
+    // The intersection of an infinite cylinder and a slab.
+    let mut proj_origin = ls_ray.origin.coordinates();
+    let mut proj_dir    = ls_ray.dir;
+    proj_origin[1] = na::zero();
+    proj_dir[1]    = na::zero();
+
+    let (cmin, cmax) = match ball_interval::<P>(&proj_origin, &proj_dir, cylinder.radius()) {
+        Some(interval) => interval,
+        None           => return None
+    };
+
+    let (smin, smax) =
+        if ls_ray.dir[1].is_zero() {
+            if ls_ray.origin[1].abs() <= h {
+                let inf: P::Real = Bounded::max_value();
+                (-inf, inf)
+            }
+            else {
+                return None;
+            }
+        }
+        else {
+            let t1 = (-h - ls_ray.origin[1]) / ls_ray.dir[1];
+            let t2 = (h - ls_ray.origin[1]) / ls_ray.dir[1];
+
+            if t1 <= t2 { (t1, t2) } else { (t2, t1) }
+        };
+
+    let tmin = cmin.max(smin);
+    let tmax = cmax.min(smax);
+
+    if tmin <= tmax {
+        Some((tmin, tmax))
+    }
+    else {
+        None
+    }
+}
+
+/// The outward normal of `cylinder` at the point `pt` of its boundary.
+///
+/// On the rims, i.e., at the points at a distance smaller than a tolerance from both a cap and the
+/// lateral surface, this is the normalized sum of the normals of both surfaces.
+fn cylinder_normal<P: Point>(cylinder: &Cylinder<P::Real>, pt: &P) -> P::Vector {
+    let h   = cylinder.half_height();
+    let r   = cylinder.radius();
+    let tol = P::Real::default_epsilon().sqrt() * h.max(r);
+
+    let mut perp = pt.coordinates();
+    perp[1] = na::zero();
+
+    let perp_norm = na::norm(&perp);
+
+    // Distances to the closest cap and to the lateral surface.
+    let cap_dist     = (pt[1].abs() - h).abs();
+    let lateral_dist = (perp_norm - r).abs();
+
+    let mut cap_normal: P::Vector = na::zero();
+    cap_normal[1] = if pt[1] < na::zero() { -na::one::<P::Real>() } else { na::one() };
+
+    if cap_dist <= tol && lateral_dist <= tol {
+        na::normalize(&(cap_normal + perp / perp_norm))
+    }
+    else if cap_dist <= lateral_dist || perp_norm.is_zero() {
+        cap_normal
+    }
+    else {
+        perp / perp_norm
+    }
+}
+
+impl<P: Point, M: Isometry<P>> RayCast<P, M> for Cylinder<P::Real> {
+    /// Computes the time of impact and normal of a ray with this cylinder.
+    ///
+    /// If the ray origin is inside of the cylinder, the time of impact is zero if `solid` is
+    /// `true` (with a normal opposite to the ray direction). Otherwise, it is the time of impact
+    /// with the exit point and the normal points toward the inside of the cylinder.
+    fn toi_and_normal_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<RayIntersection<P::Vector>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        if !ls_ray.is_finite() {
+            return None;
+        }
+
+        let (tmin, tmax) = match cylinder_interval(self, &ls_ray) {
+            Some(interval) => interval,
+            None           => return None
+        };
+
+        if tmax < na::zero() {
+            // The cylinder is behind the ray.
+            return None;
+        }
+
+        let inside = tmin <= na::zero();
+
+        if inside && solid {
+            return RayIntersection::new_inside(&ray.dir);
+        }
+
+        let toi    = if inside { tmax } else { tmin };
+        let normal = cylinder_normal(self, &(ls_ray.origin + ls_ray.dir * toi));
+        let normal = Unit::new_unchecked(m.rotate_vector(&normal));
+
+        let mut inter = RayIntersection::new(toi, if inside { -normal } else { normal });
+        // The face or rim hit, with the same tolerance as the normal.
+        inter.feature = self.support_feature_id(m, &[ ray.origin + ray.dir * toi ]);
+
+        Some(inter)
+    }
+}
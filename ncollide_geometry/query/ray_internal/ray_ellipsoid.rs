@@ -0,0 +1,69 @@
+use na::{self, Unit};
+
+use query::{ray_internal, Ray, RayCast, RayIntersection};
+use shape::{Ellipsoid, FeatureId};
+use math::{Point, Isometry};
+
+
+/// Transforms a local-space ray into the space where `ellipsoid` is the unit ball.
+#[inline]
+fn unit_ball_space_ray<P: Point>(ellipsoid: &Ellipsoid<P::Vector>, ls_ray: &Ray<P>) -> Ray<P> {
+    let mut origin = ls_ray.origin;
+    let mut dir    = ls_ray.dir;
+
+    for i in 0 .. na::dimension::<P::Vector>() {
+        origin[i] = origin[i] / ellipsoid.radii()[i];
+        dir[i]    = dir[i] / ellipsoid.radii()[i];
+    }
+
+    Ray::new(origin, dir)
+}
+
+impl<P: Point, M: Isometry<P>> RayCast<P, M> for Ellipsoid<P::Vector> {
+    #[inline]
+    fn toi_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<P::Real> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        if !ls_ray.is_finite() {
+            return None;
+        }
+
+        let us_ray = unit_ball_space_ray(self, &ls_ray);
+
+        // The scaling preserves the ray parameter, so the toi is unchanged.
+        ray_internal::ball_toi_with_ray(&P::origin(), na::one(), &us_ray, solid).1
+    }
+
+    #[inline]
+    fn toi_and_normal_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<RayIntersection<P::Vector>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        if !ls_ray.is_finite() {
+            return None;
+        }
+
+        let us_ray = unit_ball_space_ray(self, &ls_ray);
+        let (inside, inter) = ray_internal::ball_toi_with_ray(&P::origin(), na::one(), &us_ray, solid);
+
+        inter.and_then(|toi| {
+            if inside && solid {
+                // The ray origin is inside of the filled ellipsoid.
+                return RayIntersection::new_inside(&ray.dir);
+            }
+
+            // The normal is the gradient of `sum((x_i / r_i)²)`.
+            let mut normal = (us_ray.origin + us_ray.dir * toi).coordinates();
+
+            for i in 0 .. na::dimension::<P::Vector>() {
+                normal[i] = normal[i] / self.radii()[i];
+            }
+
+            let normal = Unit::new_normalize(m.rotate_vector(&normal));
+
+            let mut inter = RayIntersection::new(toi, if inside { -normal } else { normal });
+            inter.feature = FeatureId::Face(0);
+
+            Some(inter)
+        })
+    }
+}
@@ -0,0 +1,86 @@
+use std::mem;
+use num::{Bounded, Zero};
+
+use alga::general::Real;
+use na::{self, Unit, Vector3};
+
+use query::{ray_internal, Ray, RayCast, RayIntersection};
+use shape::HeightField;
+use bounding_volume::AABB;
+use math::{Point, Isometry};
+
+impl<P: Point, M: Isometry<P>> RayCast<P, M> for HeightField<P::Real> {
+    fn toi_and_normal_with_ray(&self, m: &M, ray: &Ray<P>, _: bool) -> Option<RayIntersection<P::Vector>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        if !ls_ray.is_finite() || na::norm_squared(&ls_ray.dir).is_zero() {
+            return None;
+        }
+
+        // Only test the cells covered by the part of the ray inside of the heightfield AABB.
+        let aabb     = self.local_aabb::<P>();
+        let mut tmin = na::zero::<P::Real>();
+        let mut tmax = P::Real::max_value();
+
+        for i in 0 .. 3 {
+            if ls_ray.dir[i].is_zero() {
+                if ls_ray.origin[i] < aabb.mins()[i] || ls_ray.origin[i] > aabb.maxs()[i] {
+                    return None;
+                }
+            }
+            else {
+                let mut t1 = (aabb.mins()[i] - ls_ray.origin[i]) / ls_ray.dir[i];
+                let mut t2 = (aabb.maxs()[i] - ls_ray.origin[i]) / ls_ray.dir[i];
+
+                if t1 > t2 {
+                    mem::swap(&mut t1, &mut t2);
+                }
+
+                tmin = tmin.max(t1);
+                tmax = tmax.min(t2);
+
+                if tmin > tmax {
+                    return None;
+                }
+            }
+        }
+
+        let start    = ls_ray.origin + ls_ray.dir * tmin;
+        let end      = ls_ray.origin + ls_ray.dir * tmax;
+        let mut mins = start;
+        let mut maxs = start;
+
+        for i in 0 .. 3 {
+            mins[i] = start[i].min(end[i]);
+            maxs[i] = start[i].max(end[i]);
+        }
+
+        let mut res = None::<(RayIntersection<P::Vector>, usize, Vector3<P::Real>)>;
+
+        self.map_triangles_in_local_aabb(&AABB::new(mins, maxs), &mut |id, triangle| {
+            if let Some((inter, bcoords)) = ray_internal::triangle_ray_intersection(triangle.a(), triangle.b(),
+                                                                                     triangle.c(), &ls_ray) {
+                let closer = match res {
+                    Some((ref best, _, _)) => inter.toi < best.toi,
+                    None                   => true
+                };
+
+                if closer {
+                    res = Some((inter, id, bcoords))
+                }
+            }
+        });
+
+        res.map(|(mut inter, id, bcoords)| {
+            let mut normal = self.normal_at::<P>(id, &bcoords);
+
+            // Keep the normal on the side of the heightfield the ray comes from.
+            if na::dot(&normal, &*inter.normal) < na::zero() {
+                normal = -normal;
+            }
+
+            inter.normal = Unit::new_unchecked(m.rotate_vector(&normal));
+            inter
+        })
+    }
+}
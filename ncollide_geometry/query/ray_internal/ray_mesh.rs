@@ -1,12 +1,12 @@
-use std::ops::Index;
+use std::ops::{Index, Add, Mul};
 use num::Zero;
 
 use alga::general::Id;
 use alga::linear::NormedSpace;
-use na::{self, Point2, Vector3};
+use na::{self, Point2, Vector3, Unit};
 
 use query::{ray_internal, Ray, RayCast, RayIntersection};
-use shape::{BaseMesh, BaseMeshElement, TriMesh, Polyline};
+use shape::{BaseMesh, BaseMeshElement, TriMesh, Polyline, FeatureId};
 use bounding_volume::AABB;
 use partitioning::BVTCostFn;
 use math::{Point, Isometry};
@@ -21,6 +21,10 @@ impl<P, M, I, E> RayCast<P, M> for BaseMesh<P, I, E>
     fn toi_with_ray(&self, m: &M, ray: &Ray<P>, _: bool) -> Option<P::Real> {
         let ls_ray = ray.inverse_transform_by(m);
 
+        if !ls_ray.is_finite() {
+            return None;
+        }
+
         let mut cost_fn = BaseMeshRayToiCostFn { mesh: self, ray: &ls_ray };
 
         self.bvt().best_first_search(&mut cost_fn).map(|(_, res)| res)
@@ -30,10 +34,33 @@ impl<P, M, I, E> RayCast<P, M> for BaseMesh<P, I, E>
     fn toi_and_normal_with_ray(&self, m: &M, ray: &Ray<P>, _: bool) -> Option<RayIntersection<P::Vector>> {
         let ls_ray = ray.inverse_transform_by(m);
 
-        let mut cost_fn = BaseMeshRayToiAndNormalCostFn { mesh: self, ray: &ls_ray };
+        if !ls_ray.is_finite() {
+            return None;
+        }
+
+        let mut cost_fn = BaseMeshRayToiAndNormalCostFn { mesh: self, ray: &ls_ray, flip_to_face_ray: true };
+
+        self.bvt().best_first_search(&mut cost_fn).map(|(best, mut res)| {
+            res.normal  = Unit::new_unchecked(m.rotate_vector(&res.normal));
+            res.feature = mesh_feature_id(res.feature, *best);
+            res
+        })
+    }
+
+    #[inline]
+    fn toi_and_oriented_normal_with_ray(&self, m: &M, ray: &Ray<P>, _: bool, flip_to_face_ray: bool)
+                                        -> Option<RayIntersection<P::Vector>> {
+        let ls_ray = ray.inverse_transform_by(m);
+
+        if !ls_ray.is_finite() {
+            return None;
+        }
 
-        self.bvt().best_first_search(&mut cost_fn).map(|(_, mut res)| {
-            res.normal = m.rotate_vector(&res.normal);
+        let mut cost_fn = BaseMeshRayToiAndNormalCostFn { mesh: self, ray: &ls_ray, flip_to_face_ray: flip_to_face_ray };
+
+        self.bvt().best_first_search(&mut cost_fn).map(|(best, mut res)| {
+            res.normal  = Unit::new_unchecked(m.rotate_vector(&res.normal));
+            res.feature = mesh_feature_id(res.feature, *best);
             res
         })
     }
@@ -45,6 +72,10 @@ impl<P, M, I, E> RayCast<P, M> for BaseMesh<P, I, E>
 
         let ls_ray = ray.inverse_transform_by(m);
 
+        if !ls_ray.is_finite() {
+            return None;
+        }
+
         let mut cost_fn = BaseMeshRayToiAndNormalAndUVsCostFn { mesh: self, ray: &ls_ray };
         let cast = self.bvt().best_first_search(&mut cost_fn);
 
@@ -66,10 +97,8 @@ impl<P, M, I, E> RayCast<P, M> for BaseMesh<P, I, E>
                 let uvy = uv1.y * uv.x + uv2.y * uv.y + uv3.y * uv.z;
 
                 // XXX: this interpolation should be done on the two other ray cast too!
-                match *self.normals() {
-                    None         => {
-                        Some(RayIntersection::new_with_uvs(toi, m.rotate_vector(&n), Some(Point2::new(uvx, uvy))))
-                    },
+                let normal = match *self.normals() {
+                    None         => n,
                     Some(ref ns) => {
                         let n1 = &ns[idx[0]];
                         let n2 = &ns[idx[1]];
@@ -78,23 +107,37 @@ impl<P, M, I, E> RayCast<P, M> for BaseMesh<P, I, E>
                         let mut n123 = *n1 * uv.x + *n2 * uv.y + *n3 * uv.z;
 
                         if n123.normalize_mut().is_zero() {
-                            Some(RayIntersection::new_with_uvs(toi, m.rotate_vector(&n), Some(Point2::new(uvx, uvy))))
+                            n
                         }
                         else {
                             if na::dot(&n123, &ls_ray.dir) > na::zero() {
-                                Some(RayIntersection::new_with_uvs(toi, -m.rotate_vector(&n123), Some(Point2::new(uvx, uvy))))
+                                -Unit::new_unchecked(n123)
                             }
                             else {
-                                Some(RayIntersection::new_with_uvs(toi, m.rotate_vector(&n123), Some(Point2::new(uvx, uvy))))
+                                Unit::new_unchecked(n123)
                             }
                         }
                     }
-                }
+                };
+
+                let mut res = RayIntersection::new_with_uvs(toi, Unit::new_unchecked(m.rotate_vector(&normal)),
+                                                            Some(Point2::new(uvx, uvy)));
+                res.feature = mesh_feature_id(inter.0.feature, *best);
+
+                Some(res)
             }
         }
     }
 }
 
+// The feature of a mesh corresponding to the feature `feature` of its `i`-th element.
+fn mesh_feature_id(feature: FeatureId, i: usize) -> FeatureId {
+    match feature {
+        FeatureId::Face(_) => FeatureId::Face(i),
+        FeatureId::Edge(_) => FeatureId::Edge(i),
+        _                  => FeatureId::Unknown
+    }
+}
 
 /*
  * Costs functions.
@@ -121,8 +164,9 @@ impl<'a, P, I, E> BVTCostFn<P::Real, usize, AABB<P>> for BaseMeshRayToiCostFn<'a
 }
 
 struct BaseMeshRayToiAndNormalCostFn<'a, P: 'a + Point, I: 'a, E: 'a> {
-    mesh:  &'a BaseMesh<P, I, E>,
-    ray:   &'a Ray<P>
+    mesh:             &'a BaseMesh<P, I, E>,
+    ray:              &'a Ray<P>,
+    flip_to_face_ray: bool
 }
 
 impl<'a, P, I, E> BVTCostFn<P::Real, usize, AABB<P>> for BaseMeshRayToiAndNormalCostFn<'a, P, I, E>
@@ -137,7 +181,8 @@ impl<'a, P, I, E> BVTCostFn<P::Real, usize, AABB<P>> for BaseMeshRayToiAndNormal
 
     #[inline]
     fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, RayIntersection<P::Vector>)> {
-        self.mesh.element_at(*b).toi_and_normal_with_ray(&Id::new(), self.ray, true).map(|inter| (inter.toi, inter))
+        self.mesh.element_at(*b).toi_and_oriented_normal_with_ray(&Id::new(), self.ray, true, self.flip_to_face_ray)
+            .map(|inter| (inter.toi, inter))
     }
 }
 
@@ -186,12 +231,58 @@ impl<P: Point, M: Isometry<P>> RayCast<P, M> for TriMesh<P> {
         self.base_mesh().toi_and_normal_with_ray(m, ray, solid)
     }
 
+    #[inline]
+    fn toi_and_oriented_normal_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool, flip_to_face_ray: bool)
+                                        -> Option<RayIntersection<P::Vector>> {
+        self.base_mesh().toi_and_oriented_normal_with_ray(m, ray, solid, flip_to_face_ray)
+    }
+
     #[inline]
     fn toi_and_normal_and_uv_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<RayIntersection<P::Vector>> {
         self.base_mesh().toi_and_normal_and_uv_with_ray(m, ray, solid)
     }
 }
 
+impl<P: Point> TriMesh<P> {
+    /// Computes the intersection of a ray with this mesh and interpolates a per-vertex attribute
+    /// at the intersection point.
+    ///
+    /// The i-th element of `attributes` is the attribute of the i-th vertex of this mesh. The
+    /// attribute returned alongside the intersection is the combination of the attributes of the
+    /// three vertices of the triangle hit, weighted by the barycentric coordinates of the
+    /// intersection point. Fails if `attributes` does not have as many elements as this mesh has
+    /// vertices, or if this mesh is not three-dimensional.
+    pub fn toi_and_attr_with_ray<M, A>(&self, m: &M, ray: &Ray<P>, attributes: &[A])
+                                       -> Option<(RayIntersection<P::Vector>, A)>
+        where M: Isometry<P>,
+              A: Copy + Add<A, Output = A> + Mul<P::Real, Output = A> {
+        assert!(attributes.len() == self.vertices().len(),
+                "There must be as many attributes as mesh vertices.");
+        assert!(na::dimension::<P::Vector>() == 3,
+                "Attributes can only be interpolated on a three-dimensional mesh.");
+
+        let ls_ray = ray.inverse_transform_by(m);
+
+        if !ls_ray.is_finite() {
+            return None;
+        }
+
+        let mesh        = self.base_mesh();
+        let mut cost_fn = BaseMeshRayToiAndNormalAndUVsCostFn { mesh: mesh, ray: &ls_ray };
+
+        mesh.bvt().best_first_search(&mut cost_fn).map(|(best, (mut inter, bcoords))| {
+            let idx  = &mesh.indices()[*best];
+            let attr = attributes[idx.x] * bcoords.x +
+                       attributes[idx.y] * bcoords.y +
+                       attributes[idx.z] * bcoords.z;
+
+            inter.normal = Unit::new_unchecked(m.rotate_vector(&inter.normal));
+
+            (inter, attr)
+        })
+    }
+}
+
 impl<P: Point, M: Isometry<P>> RayCast<P, M> for Polyline<P> {
     #[inline]
     fn toi_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<P::Real> {
@@ -203,6 +294,12 @@ impl<P: Point, M: Isometry<P>> RayCast<P, M> for Polyline<P> {
         self.base_mesh().toi_and_normal_with_ray(m, ray, solid)
     }
 
+    #[inline]
+    fn toi_and_oriented_normal_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool, flip_to_face_ray: bool)
+                                        -> Option<RayIntersection<P::Vector>> {
+        self.base_mesh().toi_and_oriented_normal_with_ray(m, ray, solid, flip_to_face_ray)
+    }
+
     #[inline]
     fn toi_and_normal_and_uv_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<RayIntersection<P::Vector>> {
         self.base_mesh().toi_and_normal_and_uv_with_ray(m, ray, solid)
@@ -0,0 +1,21 @@
+use query::{Ray, RayCast, RayIntersection};
+use shape::Cuboid;
+use bounding_volume::OBB;
+use math::{Point, Isometry};
+
+impl<P: Point, M: Isometry<P>> RayCast<P, M> for OBB<P, M> {
+    #[inline]
+    fn toi_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<P::Real> {
+        Cuboid::new(*self.half_extents()).toi_with_ray(&(m.clone() * self.position().clone()), ray, solid)
+    }
+
+    #[inline]
+    fn toi_and_normal_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<RayIntersection<P::Vector>> {
+        Cuboid::new(*self.half_extents()).toi_and_normal_with_ray(&(m.clone() * self.position().clone()), ray, solid)
+    }
+
+    #[inline]
+    fn intersects_ray(&self, m: &M, ray: &Ray<P>) -> bool {
+        Cuboid::new(*self.half_extents()).intersects_ray(&(m.clone() * self.position().clone()), ray)
+    }
+}
@@ -1,7 +1,7 @@
-use na;
+use na::{self, Unit};
 
 use query::{Ray, RayCast, RayIntersection};
-use shape::Plane;
+use shape::{Plane, FeatureId};
 use math::{Point, Isometry};
 
 /// Computes the toi of a ray with a plane described by its center and normal.
@@ -23,13 +23,17 @@ impl<P: Point, M: Isometry<P>> RayCast<P, M> for Plane<P::Vector> {
     fn toi_and_normal_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<RayIntersection<P::Vector>> {
         let ls_ray = ray.inverse_transform_by(m);
 
+        if !ls_ray.is_finite() {
+            return None;
+        }
+
         let dpos = -ls_ray.origin;
 
         let dot_normal_dpos = na::dot(self.normal(), &dpos.coordinates());
 
         if solid && dot_normal_dpos > na::zero() {
             // The ray is inside of the solid half-space.
-            return Some(RayIntersection::new(na::zero(), na::zero()))
+            return RayIntersection::new_inside(&ray.dir)
         }
 
         let t = dot_normal_dpos / na::dot(self.normal(), &ls_ray.dir);
@@ -37,7 +41,10 @@ impl<P: Point, M: Isometry<P>> RayCast<P, M> for Plane<P::Vector> {
         if t >= na::zero() {
             let n = if dot_normal_dpos > na::zero() { -*self.normal() } else { *self.normal() };
 
-            Some(RayIntersection::new(t, m.rotate_vector(&n)))
+            let mut inter = RayIntersection::new(t, Unit::new_unchecked(m.rotate_vector(&n)));
+            inter.feature = FeatureId::Face(0);
+
+            Some(inter)
         }
         else {
             None
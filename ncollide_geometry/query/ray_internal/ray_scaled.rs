@@ -0,0 +1,18 @@
+use shape::{Scaled, SupportMap};
+use query::{Ray, RayCast, RayIntersection};
+use query::algorithms::johnson_simplex::JohnsonSimplex;
+use query::ray_internal;
+use math::{Point, Isometry};
+
+impl<P, M, S> RayCast<P, M> for Scaled<S, P::Vector>
+    where P: Point,
+          M: Isometry<P>,
+          S: SupportMap<P, M> {
+    fn toi_and_normal_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<RayIntersection<P::Vector>> {
+        if !ray.is_finite() {
+            return None;
+        }
+
+        ray_internal::implicit_toi_and_normal_with_ray(m, self, &mut JohnsonSimplex::<P>::new_w_tls(), ray, solid)
+    }
+}
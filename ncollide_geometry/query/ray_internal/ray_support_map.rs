@@ -1,14 +1,15 @@
 use num::Zero;
 
+use approx::ApproxEq;
 use alga::general::Id;
-use na;
+use na::{self, Unit};
 
 use query::algorithms::gjk;
 use query::algorithms::simplex::Simplex;
 use query::algorithms::johnson_simplex::JohnsonSimplex;
-use query::{Ray, RayCast, RayIntersection};
-use shape::{SupportMap, MinkowskiSum, Segment, Cylinder, Cone, Capsule, ConvexHull};
-use math::{Point, Isometry};
+use query::{Ray, RayCast, RayIntersection, QuerySettings};
+use shape::{SupportMap, MinkowskiSum, Segment, ConvexHull, FeatureId};
+use math::{Point, Vector, Isometry};
 
 
 /// Cast a ray on a shape using the GJK algorithm.
@@ -22,7 +23,8 @@ pub fn implicit_toi_and_normal_with_ray<P, M, S, G: ?Sized>(m:       &M,
           M: Isometry<P>,
           S: Simplex<P>,
           G: SupportMap<P, M> {
-    let inter = gjk::cast_ray(m, shape, simplex, ray);
+    let settings = QuerySettings::default();
+    let inter    = gjk::cast_ray(m, shape, simplex, ray, &settings).0;
 
     if !solid {
         match inter {
@@ -32,70 +34,44 @@ pub fn implicit_toi_and_normal_with_ray<P, M, S, G: ?Sized>(m:       &M,
                     // the ray is inside of the shape.
                     let ndir    = na::normalize(&ray.dir);
                     let supp    = shape.support_point(m, &ndir);
-                    let shift   = na::dot(&(supp - ray.origin), &ndir) + na::convert(0.001f64);
-                    let new_ray = Ray::new(ray.origin + ndir * shift, -ray.dir);
+                    // The shift is expressed in units of the ray parameter, like the time of
+                    // impact of the reversed ray it is compared with.
+                    let shift   = (na::dot(&(supp - ray.origin), &ndir) + na::convert(0.001f64)) /
+                                  na::norm(&ray.dir);
+                    let new_ray = Ray::new(ray.origin + ray.dir * shift, -ray.dir);
 
                     // FIXME: replace by? : simplex.translate_by(&(ray.origin - new_ray.origin));
                     simplex.reset(supp + (-new_ray.origin.coordinates()));
 
-                    gjk::cast_ray(m, shape, simplex, &new_ray).map(|(toi, normal)| {
-                        RayIntersection::new(shift - toi, normal)
+                    // The normal of the reversed ray hit points outward: flip it so that it
+                    // faces the ray origin, like for the other shapes.
+                    gjk::cast_ray(m, shape, simplex, &new_ray, &settings).0.and_then(|(toi, normal)| {
+                        unit_normal_intersection(shift - toi, -normal)
                     })
                 }
                 else {
-                    Some(RayIntersection::new(toi, normal))
+                    unit_normal_intersection(toi, normal)
                 }
             }
         }
     }
     else {
-        inter.map(|(toi, normal)| RayIntersection::new(toi, normal))
-    }
-}
-
-impl<P, M> RayCast<P, M> for Cylinder<P::Real>
-    where P: Point,
-          M: Isometry<P> {
-    fn toi_and_normal_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<RayIntersection<P::Vector>> {
-        let ls_ray = ray.inverse_transform_by(m);
-
-        implicit_toi_and_normal_with_ray(&Id::new(), self,
-                                         &mut JohnsonSimplex::<P>::new_w_tls(), &ls_ray,
-                                         solid).map(|mut res| {
-            res.normal = m.rotate_vector(&res.normal);
-            res
-        })
-    }
-}
-
-impl<P, M> RayCast<P, M> for Cone<P::Real>
-    where P: Point,
-          M: Isometry<P> {
-    fn toi_and_normal_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<RayIntersection<P::Vector>> {
-        let ls_ray = ray.inverse_transform_by(m);
-
-        implicit_toi_and_normal_with_ray(&Id::new(), self,
-                                         &mut JohnsonSimplex::<P>::new_w_tls(), &ls_ray,
-                                         solid).map(|mut res| {
-            res.normal = m.rotate_vector(&res.normal);
-            res
+        inter.and_then(|(toi, normal)| {
+            if toi.is_zero() {
+                // the ray is inside of the shape.
+                RayIntersection::new_inside(&ray.dir)
+            }
+            else {
+                unit_normal_intersection(toi, normal)
+            }
         })
     }
 }
 
-impl<P, M> RayCast<P, M> for Capsule<P::Real>
-    where P: Point,
-          M: Isometry<P> {
-    fn toi_and_normal_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<RayIntersection<P::Vector>> {
-        let ls_ray = ray.inverse_transform_by(m);
-
-        implicit_toi_and_normal_with_ray(&Id::new(), self,
-                                         &mut JohnsonSimplex::<P>::new_w_tls(), &ls_ray,
-                                         solid).map(|mut res| {
-            res.normal = m.rotate_vector(&res.normal);
-            res
-        })
-    }
+// The normals computed by GJK are not always normalized.
+#[inline]
+fn unit_normal_intersection<V: Vector>(toi: V::Real, normal: V) -> Option<RayIntersection<V>> {
+    Unit::try_new(normal, V::Real::default_epsilon()).map(|n| RayIntersection::new(toi, n))
 }
 
 impl<P, M> RayCast<P, M> for ConvexHull<P>
@@ -104,10 +80,14 @@ impl<P, M> RayCast<P, M> for ConvexHull<P>
     fn toi_and_normal_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<RayIntersection<P::Vector>> {
         let ls_ray = ray.inverse_transform_by(m);
 
+        if !ls_ray.is_finite() {
+            return None;
+        }
+
         implicit_toi_and_normal_with_ray(&Id::new(), self,
                                          &mut JohnsonSimplex::<P>::new_w_tls(), &ls_ray,
                                          solid).map(|mut res| {
-            res.normal = m.rotate_vector(&res.normal);
+            res.normal = Unit::new_unchecked(m.rotate_vector(&res.normal));
             res
         })
     }
@@ -120,10 +100,15 @@ impl<P, M> RayCast<P, M> for Segment<P>
         // XXX: optimize if na::dimension::<P>() == 2
         let ls_ray = ray.inverse_transform_by(m);
 
+        if !ls_ray.is_finite() {
+            return None;
+        }
+
         implicit_toi_and_normal_with_ray(&Id::new(), self,
                                          &mut JohnsonSimplex::<P>::new_w_tls(), &ls_ray,
                                          solid).map(|mut res| {
-            res.normal = m.rotate_vector(&res.normal);
+            res.normal  = Unit::new_unchecked(m.rotate_vector(&res.normal));
+            res.feature = FeatureId::Edge(0);
             res
         })
     }
@@ -138,10 +123,14 @@ impl<'a, P, M, M2, G1: ?Sized, G2: ?Sized> RayCast<P, M2> for MinkowskiSum<'a, M
         // XXX: optimize if na::dimension::<P>() == 2
         let ls_ray = ray.inverse_transform_by(m);
 
+        if !ls_ray.is_finite() {
+            return None;
+        }
+
         implicit_toi_and_normal_with_ray(&Id::new(), self,
                                          &mut JohnsonSimplex::<P>::new_w_tls(), &ls_ray,
                                          solid).map(|mut res| {
-            res.normal = m.rotate_vector(&res.normal);
+            res.normal = Unit::new_unchecked(m.rotate_vector(&res.normal));
             res
         })
     }
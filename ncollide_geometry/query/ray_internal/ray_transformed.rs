@@ -0,0 +1,36 @@
+use alga::general::Id;
+use na::Unit;
+use shape::Transformed;
+use query::{Ray, RayCast, RayIntersection};
+use math::{Point, Isometry};
+
+impl<P, M, S> RayCast<P, Id> for Transformed<P, M, S>
+    where P: Point,
+          M: Isometry<P>,
+          S: RayCast<P, Id> {
+    #[inline]
+    fn toi_with_ray(&self, _: &Id, ray: &Ray<P>, solid: bool) -> Option<P::Real> {
+        self.shape().toi_with_ray(&Id::new(), &self.local_ray(ray), solid)
+    }
+
+    #[inline]
+    fn toi_and_normal_with_ray(&self, _: &Id, ray: &Ray<P>, solid: bool) -> Option<RayIntersection<P::Vector>> {
+        self.shape().toi_and_normal_with_ray(&Id::new(), &self.local_ray(ray), solid).map(|mut inter| {
+            inter.normal = Unit::new_unchecked(self.world_vector(&inter.normal));
+            inter
+        })
+    }
+
+    #[inline]
+    fn toi_and_normal_and_uv_with_ray(&self, _: &Id, ray: &Ray<P>, solid: bool) -> Option<RayIntersection<P::Vector>> {
+        self.shape().toi_and_normal_and_uv_with_ray(&Id::new(), &self.local_ray(ray), solid).map(|mut inter| {
+            inter.normal = Unit::new_unchecked(self.world_vector(&inter.normal));
+            inter
+        })
+    }
+
+    #[inline]
+    fn intersects_ray(&self, _: &Id, ray: &Ray<P>) -> bool {
+        self.shape().intersects_ray(&Id::new(), &self.local_ray(ray))
+    }
+}
@@ -1,13 +1,15 @@
 use num::Zero;
 
+use approx::ApproxEq;
+
 use alga::general::{Real, Id};
-use na::{self, Vector3};
+use na::{self, Vector3, Unit};
 
 use query::algorithms::johnson_simplex::JohnsonSimplex;
 use query::{Ray, RayCast, RayIntersection};
 use query::ray_internal;
-use shape::Triangle;
-use math::{Point, Isometry};
+use shape::{Triangle, FeatureId};
+use math::{self, Point, Isometry};
 
 use utils;
 
@@ -16,6 +18,10 @@ impl<P: Point, M: Isometry<P>> RayCast<P, M> for Triangle<P> {
     fn toi_and_normal_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<RayIntersection<P::Vector>> {
         let ls_ray = ray.inverse_transform_by(m);
 
+        if !ls_ray.is_finite() {
+            return None;
+        }
+
         let res = if na::dimension::<P::Vector>() == 3 {
             triangle_ray_intersection(self.a(), self.b(), self.c(), &ls_ray).map(|(r, _)| r)
         }
@@ -26,7 +32,45 @@ impl<P: Point, M: Isometry<P>> RayCast<P, M> for Triangle<P> {
                                                            solid)
         };
 
-        res.map(|mut r| { r.normal = m.rotate_vector(&r.normal); r })
+        res.map(|mut r| { r.normal = Unit::new_unchecked(m.rotate_vector(&r.normal)); r })
+    }
+
+    #[inline]
+    fn toi_and_oriented_normal_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool, flip_to_face_ray: bool)
+                                        -> Option<RayIntersection<P::Vector>> {
+        if flip_to_face_ray {
+            return self.toi_and_normal_with_ray(m, ray, solid);
+        }
+
+        if na::dimension::<P::Vector>() == 3 {
+            self.toi_and_normal_with_ray(m, ray, solid).map(|mut inter| {
+                // The normal of a back face hit faces the ray, not the outward side.
+                let n = m.rotate_vector(&utils::cross3(&(*self.b() - *self.a()), &(*self.c() - *self.a())));
+
+                if na::dot(&*inter.normal, &n) < na::zero() {
+                    inter.normal = -inter.normal;
+                }
+
+                inter
+            })
+        }
+        else {
+            // As for any shape with an interior, the solid cast tells if the ray starts inside.
+            let entry = self.toi_and_normal_with_ray(m, ray, true);
+
+            if solid || entry.as_ref().map_or(true, |inter| !inter.toi.is_zero()) {
+                return entry;
+            }
+
+            self.toi_and_normal_with_ray(m, ray, false).map(|mut inter| {
+                if !inter.toi.is_zero() {
+                    // The ray leaves the triangle.
+                    inter.normal = -inter.normal;
+                }
+
+                inter
+            })
+        }
     }
 }
 
@@ -43,8 +87,10 @@ pub fn triangle_ray_intersection<P: Point>(a: &P, b: &P, c: &P, ray: &Ray<P>)
     let n = utils::cross3(&ab, &ac);
     let d = na::dot(&n, &ray.dir);
 
-    // the normal and the ray direction are parallel
-    if d.is_zero() {
+    // the normal and the ray direction are orthogonal, i.e., the ray is parallel to the triangle
+    let parallel_tol = P::Real::default_epsilon() * na::norm(&n) * na::norm(&ray.dir);
+
+    if math::approx_eq(d, na::zero(), parallel_tol, na::zero()) {
         return None;
     }
 
@@ -57,7 +103,10 @@ pub fn triangle_ray_intersection<P: Point>(a: &P, b: &P, c: &P, ray: &Ray<P>)
         return None;
     }
 
-    let d = d.abs();
+    // If the ray origin is on the plane of the triangle, `t` is zero and the face hit is given by
+    // the sign of `d` only.
+    let back_face = d > na::zero();
+    let d         = d.abs();
 
     //
     // intersection: compute barycentric coordinates
@@ -69,7 +118,7 @@ pub fn triangle_ray_intersection<P: Point>(a: &P, b: &P, c: &P, ray: &Ray<P>)
     let toi;
     let normal;
 
-    if t < na::zero() {
+    if back_face {
         v = -na::dot(&ac, &e);
 
         if v < na::zero() || v > d {
@@ -83,8 +132,8 @@ pub fn triangle_ray_intersection<P: Point>(a: &P, b: &P, c: &P, ray: &Ray<P>)
         }
 
         let invd = na::one::<P::Real>() / d;
-        toi      = -t * invd;
-        normal   = -na::normalize(&n);
+        toi      = t.abs() * invd;
+        normal   = -Unit::new_normalize(n);
         v        = v * invd;
         w        = w * invd;
     }
@@ -103,10 +152,13 @@ pub fn triangle_ray_intersection<P: Point>(a: &P, b: &P, c: &P, ray: &Ray<P>)
 
         let invd = na::one::<P::Real>() / d;
         toi      = t * invd;
-        normal   = na::normalize(&n);
+        normal   = Unit::new_normalize(n);
         v        = v * invd;
         w        = w * invd;
     }
 
-    Some((RayIntersection::new(toi, normal), Vector3::new(-v - w + na::one(), v, w)))
+    let mut inter = RayIntersection::new(toi, normal);
+    inter.feature = FeatureId::Face(0);
+
+    Some((inter, Vector3::new(-v - w + na::one(), v, w)))
 }
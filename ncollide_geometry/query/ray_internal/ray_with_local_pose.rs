@@ -0,0 +1,28 @@
+use shape::WithLocalPose;
+use query::{Ray, RayCast, RayIntersection};
+use math::{Point, Isometry};
+
+impl<P, M, S> RayCast<P, M> for WithLocalPose<S, M>
+    where P: Point,
+          M: Isometry<P>,
+          S: RayCast<P, M> {
+    #[inline]
+    fn toi_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<P::Real> {
+        self.shape().toi_with_ray(&self.shape_transform(m), ray, solid)
+    }
+
+    #[inline]
+    fn toi_and_normal_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<RayIntersection<P::Vector>> {
+        self.shape().toi_and_normal_with_ray(&self.shape_transform(m), ray, solid)
+    }
+
+    #[inline]
+    fn toi_and_normal_and_uv_with_ray(&self, m: &M, ray: &Ray<P>, solid: bool) -> Option<RayIntersection<P::Vector>> {
+        self.shape().toi_and_normal_and_uv_with_ray(&self.shape_transform(m), ray, solid)
+    }
+
+    #[inline]
+    fn intersects_ray(&self, m: &M, ray: &Ray<P>) -> bool {
+        self.shape().intersects_ray(&self.shape_transform(m), ray)
+    }
+}
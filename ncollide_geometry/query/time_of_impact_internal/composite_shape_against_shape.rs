@@ -4,7 +4,8 @@ use math::{Point, Isometry};
 use bounding_volume::AABB;
 use partitioning::BVTCostFn;
 use shape::{Shape, CompositeShape};
-use query::{time_of_impact_internal, Ray, RayCast};
+use query::{time_of_impact_internal, Ray, RayCast, QuerySettings};
+use query::algorithms::gjk::GJKStatus;
 
 /// Time Of Impact of a composite shape with any other shape, under translational movement.
 pub fn composite_shape_against_shape<P, M, G1: ?Sized>(m1: &M, vel1: &P::Vector, g1: &G1,
@@ -13,9 +14,9 @@ pub fn composite_shape_against_shape<P, M, G1: ?Sized>(m1: &M, vel1: &P::Vector,
     where P:  Point,
           M:  Isometry<P>,
           G1: CompositeShape<P, M> {
-    let mut cost_fn = CompositeShapeAgainstAnyTOICostFn::new(m1, vel1, g1, m2, vel2, g2);
+    let settings = QuerySettings::default();
 
-    g1.bvt().best_first_search(&mut cost_fn).map(|(_, res)| res)
+    composite_shape_against_shape_with_settings(m1, vel1, g1, m2, vel2, g2, &settings).map(|(toi, _)| toi)
 }
 
 /// Time Of Impact of any shape with a composite shape, under translational movement.
@@ -28,6 +29,32 @@ pub fn shape_against_composite_shape<P, M, G2: ?Sized>(m1: &M, vel1: &P::Vector,
     composite_shape_against_shape(m2, vel2, g2, m1, vel1, g1)
 }
 
+/// Time Of Impact of a composite shape with any other shape, under translational movement,
+/// together with the termination status of the GJK ray cast with the part hit first.
+pub fn composite_shape_against_shape_with_settings<P, M, G1: ?Sized>(m1: &M, vel1: &P::Vector, g1: &G1,
+                                                                     m2: &M, vel2: &P::Vector, g2: &Shape<P, M>,
+                                                                     settings: &QuerySettings<P::Real>)
+                                                                     -> Option<(P::Real, GJKStatus)>
+    where P:  Point,
+          M:  Isometry<P>,
+          G1: CompositeShape<P, M> {
+    let mut cost_fn = CompositeShapeAgainstAnyTOICostFn::new(m1, vel1, g1, m2, vel2, g2, settings);
+
+    g1.bvt().best_first_search(&mut cost_fn).map(|(_, res)| res)
+}
+
+/// Time Of Impact of any shape with a composite shape, under translational movement, together with
+/// the termination status of the GJK ray cast with the part hit first.
+pub fn shape_against_composite_shape_with_settings<P, M, G2: ?Sized>(m1: &M, vel1: &P::Vector, g1: &Shape<P, M>,
+                                                                     m2: &M, vel2: &P::Vector, g2: &G2,
+                                                                     settings: &QuerySettings<P::Real>)
+                                                                     -> Option<(P::Real, GJKStatus)>
+    where P:  Point,
+          M:  Isometry<P>,
+          G2: CompositeShape<P, M> {
+    composite_shape_against_shape_with_settings(m2, vel2, g2, m1, vel1, g1, settings)
+}
+
 struct CompositeShapeAgainstAnyTOICostFn<'a, P: 'a + Point, M: 'a, G1: ?Sized + 'a> {
     msum_shift:  P::Vector,
     msum_margin: P::Vector,
@@ -38,14 +65,17 @@ struct CompositeShapeAgainstAnyTOICostFn<'a, P: 'a + Point, M: 'a, G1: ?Sized +
     g1:   &'a G1,
     m2:   &'a M,
     vel2: &'a P::Vector,
-    g2:   &'a Shape<P, M>
+    g2:   &'a Shape<P, M>,
+
+    settings: &'a QuerySettings<P::Real>
 }
 
 impl<'a, P, M, G1: ?Sized> CompositeShapeAgainstAnyTOICostFn<'a, P, M, G1>
     where P:  Point,
           M:  Isometry<P>,
           G1: CompositeShape<P, M> {
-    pub fn new(m1: &'a M, vel1: &'a P::Vector, g1: &'a G1, m2: &'a M, vel2: &'a P::Vector, g2: &'a Shape<P, M>)
+    pub fn new(m1: &'a M, vel1: &'a P::Vector, g1: &'a G1, m2: &'a M, vel2: &'a P::Vector, g2: &'a Shape<P, M>,
+               settings: &'a QuerySettings<P::Real>)
         -> CompositeShapeAgainstAnyTOICostFn<'a, P, M, G1> {
 
         let ls_m2 = na::inverse(m1) * m2.clone();
@@ -60,7 +90,8 @@ impl<'a, P, M, G1: ?Sized> CompositeShapeAgainstAnyTOICostFn<'a, P, M, G1>
             g1:          g1,
             m2:          m2,
             vel2:        vel2,
-            g2:          g2
+            g2:          g2,
+            settings:    settings
         }
     }
 }
@@ -70,7 +101,7 @@ for CompositeShapeAgainstAnyTOICostFn<'a, P, M, G1>
     where P:  Point,
           M:  Isometry<P>,
           G1: CompositeShape<P, M> {
-    type UserData = P::Real;
+    type UserData = (P::Real, GJKStatus);
 
     #[inline]
     fn compute_bv_cost(&mut self, bv: &AABB<P>) -> Option<P::Real> {
@@ -83,12 +114,13 @@ for CompositeShapeAgainstAnyTOICostFn<'a, P, M, G1>
     }
 
     #[inline]
-    fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, P::Real)> {
+    fn compute_b_cost(&mut self, b: &usize) -> Option<(P::Real, (P::Real, GJKStatus))> {
         let mut res = None;
 
         self.g1.map_transformed_part_at(*b, self.m1, &mut |m1, g1|
-            res = time_of_impact_internal::time_of_impact(m1, self.vel1, g1, self.m2, self.vel2, self.g2)
-                  .map(|toi| (toi, toi))
+            res = time_of_impact_internal::shape_against_shape_with_settings(m1, self.vel1, g1, self.m2, self.vel2,
+                                                                             self.g2, self.settings)
+                  .map(|res| (res.0, res))
         );
 
         res
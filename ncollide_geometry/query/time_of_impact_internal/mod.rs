@@ -1,13 +1,23 @@
 //! Implementation details of the `time_of_impact` function.
 
 pub use self::ball_against_ball::ball_against_ball;
-pub use self::support_map_against_support_map::support_map_against_support_map;
+pub use self::support_map_against_support_map::{support_map_against_support_map,
+                                                support_map_against_support_map_with_settings};
 pub use self::plane_against_support_map::{plane_against_support_map, support_map_against_plane};
-pub use self::shape_against_shape::shape_against_shape as time_of_impact;
-pub use self::composite_shape_against_shape::{composite_shape_against_shape, shape_against_composite_shape};
+pub use self::shape_against_shape::{shape_against_shape, shape_against_shape_with_settings};
+pub use self::time_of_impact::{time_of_impact, time_of_impact_with_settings, shape_cast, shape_cast_with_settings,
+                               TOIResult, TOIStatus};
+pub use self::composite_shape_against_shape::{composite_shape_against_shape, shape_against_composite_shape,
+                                              composite_shape_against_shape_with_settings,
+                                              shape_against_composite_shape_with_settings};
+pub use self::time_of_impact_bvt::{bvt_time_of_impact, bvt_shape_cast};
+pub use self::nonlinear_time_of_impact::{nonlinear_time_of_impact, RigidMotion, ConstantVelocityMotion};
 
 mod ball_against_ball;
 mod support_map_against_support_map;
 mod plane_against_support_map;
 mod shape_against_shape;
 mod composite_shape_against_shape;
+mod time_of_impact;
+mod time_of_impact_bvt;
+mod nonlinear_time_of_impact;
@@ -0,0 +1,184 @@
+use num::Zero;
+
+use alga::general::Real;
+use na::{self, Unit, Point2, Point3, Vector2, Vector3, Isometry2, Isometry3};
+use math::{Point, Isometry};
+use shape::Shape;
+use query::{self, QuerySettings, TOIResult, TOIStatus};
+
+/// The rigid motion of a shape, used by `query::nonlinear_time_of_impact`.
+///
+/// The linear velocity of the origin of the local frame of the shape must be constant.
+pub trait RigidMotion<P: Point, M> {
+    /// The position of the shape at the time `t`.
+    fn position_at_time(&self, t: P::Real) -> M;
+
+    /// The linear velocity of the origin of the local frame of the shape.
+    fn linear_velocity(&self) -> P::Vector;
+
+    /// An upper bound of the norm of the angular velocity of the shape around the origin of its
+    /// local frame.
+    fn angular_speed(&self) -> P::Real;
+}
+
+/// A rigid motion with constant linear and angular velocities.
+///
+/// The shape rotates around the origin of its local frame, e.g., its center of mass, while this
+/// origin moves along a straight line.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ConstantVelocityMotion<M, V, A> {
+    /// The position of the shape at time zero.
+    pub start:  M,
+    /// The linear velocity of the origin of the local frame of the shape.
+    pub linvel: V,
+    /// The angular velocity of the shape: an angle in 2D, and a scaled rotation axis in 3D.
+    pub angvel: A
+}
+
+impl<M, V, A> ConstantVelocityMotion<M, V, A> {
+    /// Creates a new motion starting at `start` with the given linear and angular velocities.
+    #[inline]
+    pub fn new(start: M, linvel: V, angvel: A) -> ConstantVelocityMotion<M, V, A> {
+        ConstantVelocityMotion {
+            start:  start,
+            linvel: linvel,
+            angvel: angvel
+        }
+    }
+}
+
+impl<N: Real> RigidMotion<Point2<N>, Isometry2<N>> for ConstantVelocityMotion<Isometry2<N>, Vector2<N>, N> {
+    #[inline]
+    fn position_at_time(&self, t: N) -> Isometry2<N> {
+        let mut res = Isometry2::new(self.start.translation.vector + self.linvel * t, self.angvel * t);
+        res.rotation = res.rotation * self.start.rotation;
+        res
+    }
+
+    #[inline]
+    fn linear_velocity(&self) -> Vector2<N> {
+        self.linvel
+    }
+
+    #[inline]
+    fn angular_speed(&self) -> N {
+        self.angvel.abs()
+    }
+}
+
+impl<N: Real> RigidMotion<Point3<N>, Isometry3<N>> for ConstantVelocityMotion<Isometry3<N>, Vector3<N>, Vector3<N>> {
+    #[inline]
+    fn position_at_time(&self, t: N) -> Isometry3<N> {
+        let mut res = Isometry3::new(self.start.translation.vector + self.linvel * t, self.angvel * t);
+        res.rotation = res.rotation * self.start.rotation;
+        res
+    }
+
+    #[inline]
+    fn linear_velocity(&self) -> Vector3<N> {
+        self.linvel
+    }
+
+    #[inline]
+    fn angular_speed(&self) -> N {
+        na::norm(&self.angvel)
+    }
+}
+
+/// Computes the smallest time of impact of two shapes under rigid motions with both linear and
+/// angular velocities, together with the contact normal and points at this time.
+///
+/// This uses conservative advancement: the shapes are moved forward by steps that are guaranteed
+/// not to be larger than the time they need to close the gap between them, given the bounds of
+/// their velocities. Thus, unlike `query::time_of_impact`, fast-rotating shapes cannot tunnel
+/// through each other. This works for any pair of shapes supported by `query::distance`, e.g.,
+/// support-mapped shapes and meshes.
+///
+/// Returns `None` if the shapes do not touch before `max_toi`. The status of the result is
+/// `TOIStatus::OutOfIterations` if the maximum number of iterations of the default
+/// `QuerySettings` is reached first.
+pub fn nonlinear_time_of_impact<P, M, M1, M2>(motion1: &M1, g1: &Shape<P, M>,
+                                              motion2: &M2, g2: &Shape<P, M>,
+                                              max_toi: P::Real)
+                                              -> Option<TOIResult<P>>
+    where P:  Point,
+          M:  Isometry<P>,
+          M1: RigidMotion<P, M>,
+          M2: RigidMotion<P, M> {
+    let settings = QuerySettings::<P::Real>::default();
+    let eps      = settings.gjk_tolerance.sqrt();
+
+    // Bounds of the distance between the points of each shape and its rotation center.
+    let radius1 = rotation_radius(&motion1.position_at_time(na::zero()), g1);
+    let radius2 = rotation_radius(&motion2.position_at_time(na::zero()), g2);
+    let angular_bound = motion1.angular_speed() * radius1 + motion2.angular_speed() * radius2;
+    let linvel = motion1.linear_velocity() - motion2.linear_velocity();
+
+    let mut toi    = na::zero::<P::Real>();
+    let mut status = TOIStatus::Converged;
+    let mut niter  = 0;
+
+    loop {
+        let m1       = motion1.position_at_time(toi);
+        let m2       = motion2.position_at_time(toi);
+        let distance = query::distance(&m1, g1, &m2, g2);
+
+        if distance <= eps {
+            if toi.is_zero() {
+                status = TOIStatus::Penetrating;
+            }
+
+            break;
+        }
+
+        if niter == settings.max_iterations {
+            status = TOIStatus::OutOfIterations;
+            break;
+        }
+
+        niter = niter + 1;
+
+        // The normal pointing from the first shape toward the second.
+        let normal = match query::contact(&m1, g1, &m2, g2, distance + eps) {
+            Some(c) => c.normal,
+            None    => match Unit::try_new(linvel, na::zero()) {
+                Some(dir) => dir,
+                None      => return None
+            }
+        };
+
+        let approach_speed = na::dot(&linvel, &*normal) + angular_bound;
+
+        if approach_speed <= na::zero() {
+            // The shapes are moving apart.
+            return None;
+        }
+
+        toi = toi + distance / approach_speed;
+
+        if toi > max_toi {
+            return None;
+        }
+    }
+
+    let m1 = motion1.position_at_time(toi);
+    let m2 = motion2.position_at_time(toi);
+    let distance = query::distance(&m1, g1, &m2, g2);
+
+    query::contact(&m1, g1, &m2, g2, distance + eps).map(|c| {
+        TOIResult {
+            toi:      toi,
+            normal:   c.normal,
+            witness1: c.world1,
+            witness2: c.world2,
+            status:   status
+        }
+    })
+}
+
+// The largest distance between a point of `g` and the origin of its local frame.
+fn rotation_radius<P: Point, M: Isometry<P>>(m: &M, g: &Shape<P, M>) -> P::Real {
+    let bs = g.bounding_sphere(m);
+
+    na::distance(bs.center(), &m.translate_point(&P::origin())) + bs.radius()
+}
@@ -1,6 +1,8 @@
 use alga::general::Id;
 use shape::{SupportMap, Reflection, MinkowskiSum};
-use query::{Ray, RayCast};
+use query::{Ray, QuerySettings};
+use query::algorithms::gjk::{self, GJKStatus};
+use query::algorithms::johnson_simplex::JohnsonSimplex;
 use math::{Point, Isometry};
 
 
@@ -8,6 +10,26 @@ use math::{Point, Isometry};
 pub fn support_map_against_support_map<P, M, G1: ?Sized, G2: ?Sized>(m1: &M, vel1: &P::Vector, g1: &G1,
                                                                      m2: &M, vel2: &P::Vector, g2: &G2)
                                                                      -> Option<P::Real>
+    where P:  Point,
+          M:  Isometry<P>,
+          G1: SupportMap<P, M>,
+          G2: SupportMap<P, M> {
+    let settings = QuerySettings::default();
+
+    support_map_against_support_map_with_settings(m1, vel1, g1, m2, vel2, g2, &settings).map(|(toi, _)| toi)
+}
+
+/// Time of impacts between two support-mapped shapes under translational movement, together with
+/// the termination status of the GJK ray cast on their Minkowski difference.
+pub fn support_map_against_support_map_with_settings<P, M, G1: ?Sized, G2: ?Sized>(
+    m1:       &M,
+    vel1:     &P::Vector,
+    g1:       &G1,
+    m2:       &M,
+    vel2:     &P::Vector,
+    g2:       &G2,
+    settings: &QuerySettings<P::Real>)
+    -> Option<(P::Real, GJKStatus)>
     where P:  Point,
           M:  Isometry<P>,
           G1: SupportMap<P, M>,
@@ -15,6 +37,13 @@ pub fn support_map_against_support_map<P, M, G1: ?Sized, G2: ?Sized>(m1: &M, vel
     let vel = *vel1 - *vel2;
     let rg2 = Reflection::new(g2);
     let cso = MinkowskiSum::new(m1, g1, m2, &rg2);
+    let ray = Ray::new(P::origin(), -vel);
+
+    if !ray.is_finite() {
+        return None;
+    }
+
+    let (inter, status) = gjk::cast_ray(&Id::new(), &cso, &mut JohnsonSimplex::<P>::new_w_tls(), &ray, settings);
 
-    cso.toi_with_ray(&Id::new(), &Ray::new(P::origin(), -vel), true)
+    inter.map(|(toi, _)| (toi, status))
 }
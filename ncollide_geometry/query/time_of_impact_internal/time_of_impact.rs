@@ -0,0 +1,150 @@
+use num::Zero;
+
+use alga::general::Real;
+use alga::linear::Translation;
+use na::{self, Unit};
+use math::{Point, Isometry};
+use shape::Shape;
+use query::{self, QuerySettings};
+use query::time_of_impact_internal;
+use query::algorithms::gjk::GJKStatus;
+
+/// The termination status of a time of impact computation.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TOIStatus {
+    /// The shapes are already touching or penetrating at their initial positions.
+    Penetrating,
+    /// The shapes touch at the computed time of impact.
+    Converged,
+    /// The time of impact algorithm reached its maximum number of iterations before converging:
+    /// the computed time of impact is a lower bound, and the shapes may still be separated at this
+    /// time.
+    OutOfIterations
+}
+
+/// The result of a time of impact computation.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TOIResult<P: Point> {
+    /// The time of impact.
+    pub toi:      P::Real,
+    /// The contact normal at the time of impact, pointing from the first shape toward the second.
+    pub normal:   Unit<P::Vector>,
+    /// The contact point on the first shape, in world space, at the time of impact.
+    pub witness1: P,
+    /// The contact point on the second shape, in world space, at the time of impact.
+    pub witness2: P,
+    /// The termination status of the computation.
+    pub status:   TOIStatus
+}
+
+/// Computes the smallest time of impact of two shapes under translational movement, together with
+/// the contact normal and points at this time.
+///
+/// The normal and witness points are computed by a contact query with both shapes moved to their
+/// positions at the time of impact. Returns `None` if the shapes never touch.
+pub fn time_of_impact<P, M>(m1: &M, vel1: &P::Vector, g1: &Shape<P, M>,
+                            m2: &M, vel2: &P::Vector, g2: &Shape<P, M>)
+                            -> Option<TOIResult<P>>
+    where P: Point,
+          M: Isometry<P> {
+    time_of_impact_with_settings(m1, vel1, g1, m2, vel2, g2, &QuerySettings::default())
+}
+
+/// Computes the smallest time of impact of two shapes under translational movement, using the
+/// given numerical tolerances and iteration budget.
+///
+/// See `time_of_impact`. The status is `TOIStatus::OutOfIterations` if the GJK ray cast computing
+/// the time of impact reaches `settings.max_iterations`.
+pub fn time_of_impact_with_settings<P, M>(m1: &M, vel1: &P::Vector, g1: &Shape<P, M>,
+                                          m2: &M, vel2: &P::Vector, g2: &Shape<P, M>,
+                                          settings: &QuerySettings<P::Real>)
+                                          -> Option<TOIResult<P>>
+    where P: Point,
+          M: Isometry<P> {
+    time_of_impact_internal::shape_against_shape_with_settings(m1, vel1, g1, m2, vel2, g2, settings)
+        .and_then(|(toi, status)| contact_at_toi(m1, vel1, g1, m2, vel2, g2, toi, status, settings))
+}
+
+/// Sweeps the shape `g1` along the linear velocity `vel1` against the static shape `g2`, and
+/// computes the first time of impact together with the contact normal and points at this time.
+///
+/// This is the linear shape cast, i.e., the generalization of a ray cast to a moving shape: the
+/// motion of `g1` is `m1` translated by `vel1 * t` for `t` in `[0, max_toi]`. Returns `None` if
+/// `g1` does not hit `g2` during this motion.
+pub fn shape_cast<P, M>(m1: &M, vel1: &P::Vector, g1: &Shape<P, M>,
+                        m2: &M, g2: &Shape<P, M>,
+                        max_toi: P::Real)
+                        -> Option<TOIResult<P>>
+    where P: Point,
+          M: Isometry<P> {
+    shape_cast_with_settings(m1, vel1, g1, m2, g2, max_toi, &QuerySettings::default())
+}
+
+/// Sweeps the shape `g1` along the linear velocity `vel1` against the static shape `g2`, using the
+/// given numerical tolerances and iteration budget.
+///
+/// See `shape_cast` and `time_of_impact_with_settings`.
+pub fn shape_cast_with_settings<P, M>(m1: &M, vel1: &P::Vector, g1: &Shape<P, M>,
+                                      m2: &M, g2: &Shape<P, M>,
+                                      max_toi: P::Real,
+                                      settings: &QuerySettings<P::Real>)
+                                      -> Option<TOIResult<P>>
+    where P: Point,
+          M: Isometry<P> {
+    let vel2 = na::zero();
+
+    time_of_impact_internal::shape_against_shape_with_settings(m1, vel1, g1, m2, &vel2, g2, settings)
+        .and_then(|(toi, status)| {
+            if toi > max_toi {
+                None
+            }
+            else {
+                contact_at_toi(m1, vel1, g1, m2, &vel2, g2, toi, status, settings)
+            }
+        })
+}
+
+// The result of a time of impact computation, given the time of impact and the termination status
+// of the algorithm that computed it.
+fn contact_at_toi<P, M>(m1: &M, vel1: &P::Vector, g1: &Shape<P, M>,
+                        m2: &M, vel2: &P::Vector, g2: &Shape<P, M>,
+                        toi: P::Real, gjk_status: GJKStatus,
+                        settings: &QuerySettings<P::Real>)
+                        -> Option<TOIResult<P>>
+    where P: Point,
+          M: Isometry<P> {
+    let m1 = m1.append_translation(&M::Translation::from_vector(*vel1 * toi).unwrap());
+    let m2 = m2.append_translation(&M::Translation::from_vector(*vel2 * toi).unwrap());
+
+    let status = if toi.is_zero() {
+        TOIStatus::Penetrating
+    }
+    else if gjk_status == GJKStatus::MaxIterationsReached {
+        TOIStatus::OutOfIterations
+    }
+    else {
+        TOIStatus::Converged
+    };
+
+    // The shapes touch if the algorithm converged. Otherwise, their distance is bounded by the
+    // largest distance between the points of their bounding spheres.
+    let prediction = if status == TOIStatus::OutOfIterations {
+        let bs1 = g1.bounding_sphere(&m1);
+        let bs2 = g2.bounding_sphere(&m2);
+
+        na::distance(bs1.center(), bs2.center()) + bs1.radius() + bs2.radius()
+    }
+    else {
+        settings.gjk_tolerance.sqrt()
+    };
+
+    query::contact(&m1, g1, &m2, g2, prediction).map(|c| {
+        TOIResult {
+            toi:      toi,
+            normal:   c.normal,
+            witness1: c.world1,
+            witness2: c.world2,
+            status:   status
+        }
+    })
+}
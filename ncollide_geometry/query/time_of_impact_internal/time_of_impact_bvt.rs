@@ -0,0 +1,104 @@
+use num::Bounded;
+
+use alga::general::Id;
+use math::{Point, Isometry};
+use bounding_volume::BoundingVolume;
+use partitioning::{BVT, BVTCostFn};
+use shape::{Shape, ShapeHandle};
+use query::{self, Ray, RayCast, TOIResult};
+
+/// Computes the earliest time of impact of a moving shape with the static objects of a scene
+/// stored in a bounding volume tree.
+///
+/// The shape `g` is at the position `m` at time zero and moves with the velocity `vel`. The leaves
+/// of `bvt` are visited by increasing time of entry of the bounding sphere of `g` into their
+/// bounding volumes, and `toi` is called to compute the time of impact of `g` with the object
+/// identified by each candidate leaf, e.g., using `query::time_of_impact`. The subtrees entered
+/// after the best time of impact found so far are skipped. Thus, the bounding volumes must enclose
+/// the objects they are associated with, and these objects must not move.
+///
+/// Returns the leaf of the first object hit and the time of impact with it, or `None` if no object
+/// is hit.
+pub fn bvt_time_of_impact<'a, P, M, B, BV, F>(bvt: &'a BVT<B, BV>,
+                                              m: &M, vel: &P::Vector, g: &Shape<P, M>,
+                                              toi: F)
+                                              -> Option<(&'a B, TOIResult<P>)>
+    where P:  Point,
+          M:  Isometry<P>,
+          BV: BoundingVolume<P> + RayCast<P, Id>,
+          F:  FnMut(&B) -> Option<TOIResult<P>> {
+    bvt_toi(bvt, m, vel, g, Bounded::max_value(), toi)
+}
+
+/// Sweeps a shape along a linear velocity through the static shapes of a scene stored in a
+/// bounding volume tree, and computes the first shape hit.
+///
+/// This is the linear shape cast of `query::shape_cast` against a whole scene: the shape `g` is at
+/// the position `m` at time zero, moves with the velocity `vel`, and stops at the time `max_toi`.
+/// The function `shape` gives the position and the shape identified by a leaf of `bvt`, and the
+/// bounding volume of a leaf must enclose its shape at this position.
+///
+/// Returns the leaf of the first shape hit and the time of impact with it, or `None` if no shape
+/// is hit before `max_toi`.
+pub fn bvt_shape_cast<'a, P, M, B, BV, F>(bvt: &'a BVT<B, BV>,
+                                          m: &M, vel: &P::Vector, g: &Shape<P, M>,
+                                          max_toi: P::Real,
+                                          mut shape: F)
+                                          -> Option<(&'a B, TOIResult<P>)>
+    where P:  Point,
+          M:  Isometry<P>,
+          BV: BoundingVolume<P> + RayCast<P, Id>,
+          F:  FnMut(&B) -> (M, ShapeHandle<P, M>) {
+    bvt_toi(bvt, m, vel, g, max_toi, |b| {
+        let (m2, g2) = shape(b);
+        query::shape_cast(m, vel, g, &m2, g2.as_ref(), max_toi)
+    })
+}
+
+fn bvt_toi<'a, P, M, B, BV, F>(bvt: &'a BVT<B, BV>,
+                               m: &M, vel: &P::Vector, g: &Shape<P, M>,
+                               max_toi: P::Real,
+                               toi: F)
+                               -> Option<(&'a B, TOIResult<P>)>
+    where P:  Point,
+          M:  Isometry<P>,
+          BV: BoundingVolume<P> + RayCast<P, Id>,
+          F:  FnMut(&B) -> Option<TOIResult<P>> {
+    let bs = g.bounding_sphere(m);
+    let mut cost_fn = BVTTOICostFn {
+        ray:     Ray::new(*bs.center(), *vel),
+        radius:  bs.radius(),
+        max_toi: max_toi,
+        toi:     toi
+    };
+
+    bvt.best_first_search(&mut cost_fn)
+}
+
+struct BVTTOICostFn<P: Point, F> {
+    ray:     Ray<P>,
+    radius:  P::Real,
+    max_toi: P::Real,
+    toi:     F
+}
+
+impl<P, B, BV, F> BVTCostFn<P::Real, B, BV> for BVTTOICostFn<P, F>
+    where P:  Point,
+          BV: BoundingVolume<P> + RayCast<P, Id>,
+          F:  FnMut(&B) -> Option<TOIResult<P>> {
+    type UserData = TOIResult<P>;
+
+    #[inline]
+    fn compute_bv_cost(&mut self, bv: &BV) -> Option<P::Real> {
+        // The bounding sphere of the moving shape enters the enlarged bounding volume when its
+        // center does.
+        bv.loosened(self.radius).toi_with_ray(&Id::new(), &self.ray, true).and_then(|toi| {
+            if toi > self.max_toi { None } else { Some(toi) }
+        })
+    }
+
+    #[inline]
+    fn compute_b_cost(&mut self, b: &B) -> Option<(P::Real, TOIResult<P>)> {
+        (self.toi)(b).map(|res| (res.toi, res))
+    }
+}
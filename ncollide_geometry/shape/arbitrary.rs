@@ -0,0 +1,44 @@
+//! Random generation of non-degenerate shapes for property-based testing.
+
+use quickcheck::{Arbitrary, Gen};
+use alga::general::Real;
+use na;
+
+use shape::{Ball, Cuboid, Cone, Capsule};
+use math::Vector;
+
+// A strictly positive dimension not smaller than 0.1.
+fn arbitrary_dimension<N: Real + Arbitrary, G: Gen>(g: &mut G) -> N {
+    na::convert::<f64, N>(0.1) + N::arbitrary(g).abs()
+}
+
+impl<N: Real + Arbitrary> Arbitrary for Ball<N> {
+    fn arbitrary<G: Gen>(g: &mut G) -> Ball<N> {
+        Ball::new(arbitrary_dimension(g))
+    }
+}
+
+impl<V: Vector + Arbitrary> Arbitrary for Cuboid<V>
+    where V::Real: Arbitrary {
+    fn arbitrary<G: Gen>(g: &mut G) -> Cuboid<V> {
+        let mut half_extents = V::zero();
+
+        for i in 0 .. na::dimension::<V>() {
+            half_extents[i] = arbitrary_dimension(g);
+        }
+
+        Cuboid::new(half_extents)
+    }
+}
+
+impl<N: Real + Arbitrary> Arbitrary for Cone<N> {
+    fn arbitrary<G: Gen>(g: &mut G) -> Cone<N> {
+        Cone::new(arbitrary_dimension(g), arbitrary_dimension(g))
+    }
+}
+
+impl<N: Real + Arbitrary> Arbitrary for Capsule<N> {
+    fn arbitrary<G: Gen>(g: &mut G) -> Capsule<N> {
+        Capsule::new(arbitrary_dimension(g), arbitrary_dimension(g))
+    }
+}
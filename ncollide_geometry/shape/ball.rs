@@ -1,11 +1,19 @@
+use std::hash::{Hash, Hasher};
+
 use alga::general::Real;
 use na;
 
-use shape::SupportMap;
+use shape::{SupportMap, FeatureId};
+use utils;
+use volumetric;
 use math::{Point, Isometry};
 
 /// A Ball shape.
-#[derive(PartialEq, Debug, Clone, RustcEncodable, RustcDecodable)]
+///
+/// Balls are compared and hashed on the exact representation of their radius, given by
+/// `utils::real_bits`. This is intended for cache keys: balls with slightly different radii are
+/// not equal.
+#[derive(Debug, Clone, RustcEncodable, RustcDecodable)]
 pub struct Ball<N> {
     radius: N
 }
@@ -15,6 +23,7 @@ impl<N: Real> Ball<N> {
     #[inline]
     pub fn new(radius: N) -> Ball<N> {
         assert!(radius > N::zero(), "A ball radius must be strictly positive.");
+        debug_assert!(utils::is_finite(radius), "A ball radius must be finite.");
 
         Ball {
             radius: radius
@@ -26,6 +35,34 @@ impl<N: Real> Ball<N> {
     pub fn radius(&self) -> N {
         self.radius
     }
+
+    /// The ball center in local space, i.e., the origin.
+    #[inline]
+    pub fn center<P: Point>(&self) -> P {
+        P::origin()
+    }
+
+    /// The ball volume in the dimension of `P`, see `volumetric::ball_volume`.
+    #[inline]
+    pub fn volume<P: Point>(&self) -> N {
+        volumetric::ball_volume(self.radius, na::dimension::<P::Vector>())
+    }
+}
+
+impl<N: Real> PartialEq for Ball<N> {
+    #[inline]
+    fn eq(&self, other: &Ball<N>) -> bool {
+        utils::real_bits(self.radius) == utils::real_bits(other.radius)
+    }
+}
+
+impl<N: Real> Eq for Ball<N> { }
+
+impl<N: Real> Hash for Ball<N> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        utils::hash_real(self.radius, state)
+    }
 }
 
 impl<P: Point, M: Isometry<P>> SupportMap<P, M> for Ball<P::Real> {
@@ -33,4 +70,10 @@ impl<P: Point, M: Isometry<P>> SupportMap<P, M> for Ball<P::Real> {
     fn support_point(&self, m: &M, dir: &P::Vector) -> P {
         m.translate_point(&P::origin()) + na::normalize(dir) * self.radius()
     }
+
+    #[inline]
+    fn support_feature_id(&self, _: &M, _: &[P]) -> FeatureId {
+        // The whole boundary of a ball is a single curved surface.
+        FeatureId::Face(0)
+    }
 }
@@ -0,0 +1,115 @@
+use na::{self, Unit};
+
+use query::{Ray, RayIntersection};
+use query::ray_internal;
+use utils;
+use math::Point;
+
+/// A set of balls stored as a structure of arrays.
+///
+/// The centers and the radii of the balls are stored in two contiguous arrays. This makes bulk
+/// queries on a large number of balls more cache-friendly than on a `Vec` of positioned `Ball`.
+/// The i-th ball is identified by its index `i` in both arrays.
+#[derive(PartialEq, Debug, Clone, RustcEncodable, RustcDecodable)]
+pub struct BallArray<P: Point> {
+    centers: Vec<P>,
+    radii:   Vec<P::Real>
+}
+
+impl<P: Point> BallArray<P> {
+    /// Creates a new set of balls from their centers and radii.
+    ///
+    /// Fails if both arrays do not have the same length or if a radius is not strictly positive.
+    pub fn new(centers: Vec<P>, radii: Vec<P::Real>) -> BallArray<P> {
+        assert!(centers.len() == radii.len(), "There must be as many ball centers as radii.");
+        assert!(radii.iter().all(|r| *r > na::zero()), "A ball radius must be strictly positive.");
+        debug_assert!(radii.iter().all(|r| utils::is_finite(*r)), "A ball radius must be finite.");
+
+        BallArray {
+            centers: centers,
+            radii:   radii
+        }
+    }
+
+    /// Creates an empty set of balls.
+    pub fn empty() -> BallArray<P> {
+        BallArray::new(Vec::new(), Vec::new())
+    }
+
+    /// Adds a ball to this set.
+    ///
+    /// Fails if `radius` is not strictly positive.
+    pub fn push(&mut self, center: P, radius: P::Real) {
+        assert!(radius > na::zero(), "A ball radius must be strictly positive.");
+        debug_assert!(utils::is_finite(radius), "A ball radius must be finite.");
+
+        self.centers.push(center);
+        self.radii.push(radius)
+    }
+
+    /// The number of balls of this set.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.centers.len()
+    }
+
+    /// Whether this set contains no ball.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.centers.is_empty()
+    }
+
+    /// The centers of the balls.
+    #[inline]
+    pub fn centers(&self) -> &[P] {
+        &self.centers[..]
+    }
+
+    /// The radii of the balls.
+    #[inline]
+    pub fn radii(&self) -> &[P::Real] {
+        &self.radii[..]
+    }
+
+    /// Casts a ray on each ball of this set.
+    ///
+    /// The i-th element of the result is the intersection of the ray with the i-th ball, with the
+    /// same conventions as the `RayCast` implementation of `Ball`.
+    pub fn ray_cast_all(&self, ray: &Ray<P>, solid: bool) -> Vec<Option<RayIntersection<P::Vector>>> {
+        self.centers.iter().zip(self.radii.iter()).map(|(center, radius)| {
+            let (inside, inter) = ray_internal::ball_toi_with_ray(center, *radius, ray, solid);
+
+            inter.and_then(|toi| {
+                if inside && solid {
+                    return RayIntersection::new_inside(&ray.dir);
+                }
+
+                let normal = Unit::new_normalize(ray.origin + ray.dir * toi - *center);
+
+                Some(RayIntersection::new(toi, if inside { -normal } else { normal }))
+            })
+        }).collect()
+    }
+
+    /// The index of the ball with the boundary closest to `point`.
+    ///
+    /// The distance to a ball containing `point` is negative, so the ball penetrated the most
+    /// deeply is returned if `point` is inside of several balls. Fails if this set is empty.
+    pub fn closest_to_point(&self, point: &P) -> usize {
+        assert!(!self.is_empty(), "Cannot find the closest ball of an empty set.");
+
+        let mut best      = 0;
+        let mut best_dist = na::distance(point, &self.centers[0]) - self.radii[0];
+
+        for i in 1 .. self.centers.len() {
+            let dist = na::distance(point, &self.centers[i]) - self.radii[i];
+
+            if dist < best_dist {
+                best      = i;
+                best_dist = dist;
+            }
+        }
+
+        best
+    }
+}
@@ -3,10 +3,15 @@
 use std::sync::Arc;
 use std::marker::PhantomData;
 
+use rustc_serialize::{Encodable, Encoder, Decodable, Decoder};
+#[cfg(feature = "serde-serialize")]
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+#[cfg(feature = "serde-serialize")]
+use serde::de::Error;
 use alga::general::Id;
 use na::Point2;
-use partitioning::BVT;
-use bounding_volume::{self, HasBoundingVolume, AABB};
+use partitioning::{BVT, BVTVisitor};
+use bounding_volume::{self, BoundingVolume, HasBoundingVolume, AABB};
 use math::Point;
 
 
@@ -17,9 +22,12 @@ pub trait BaseMeshElement<I, P> {
 }
 
 /// A mesh generic wrt. the contained mesh elements characterized by vertices.
+///
+/// All the data of a mesh are shared between its clones: cloning it is cheap. A mesh is encoded
+/// with its BVT so that decoding it does not require to build the BVT again.
 pub struct BaseMesh<P: Point, I, E> {
-    bvt:      BVT<usize, AABB<P>>,
-    bvs:      Vec<AABB<P>>,
+    bvt:      Arc<BVT<usize, AABB<P>>>,
+    bvs:      Arc<Vec<AABB<P>>>,
     vertices: Arc<Vec<P>>,
     indices:  Arc<Vec<I>>,
     uvs:      Option<Arc<Vec<Point2<P::Real>>>>,
@@ -72,11 +80,11 @@ impl<P, I, E> BaseMesh<P, I, E>
             }
         }
 
-        let bvt = BVT::new_balanced(leaves);
+        let bvt = BVT::new_with_sah(leaves);
 
         BaseMesh {
-            bvt:      bvt,
-            bvs:      bvs,
+            bvt:      Arc::new(bvt),
+            bvs:      Arc::new(bvs),
             vertices: vertices,
             indices:  indices,
             uvs:      uvs,
@@ -86,6 +94,217 @@ impl<P, I, E> BaseMesh<P, I, E>
     }
 }
 
+impl<P, I, E> Encodable for BaseMesh<P, I, E>
+    where P:                Point + Encodable,
+          P::Vector:        Encodable,
+          Point2<P::Real>:  Encodable,
+          I:                Encodable {
+    fn encode<S: Encoder>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.emit_struct("BaseMesh", 6, |s| {
+            try!(s.emit_struct_field("bvt", 0, |s| self.bvt.encode(s)));
+            try!(s.emit_struct_field("bvs", 1, |s| self.bvs.encode(s)));
+            try!(s.emit_struct_field("vertices", 2, |s| self.vertices.encode(s)));
+            try!(s.emit_struct_field("indices", 3, |s| self.indices.encode(s)));
+            try!(s.emit_struct_field("uvs", 4, |s| self.uvs.encode(s)));
+            s.emit_struct_field("normals", 5, |s| self.normals.encode(s))
+        })
+    }
+}
+
+impl<P, I, E> Decodable for BaseMesh<P, I, E>
+    where P:                Point + Decodable,
+          P::Vector:        Decodable,
+          Point2<P::Real>:  Decodable + Send + Sync,
+          I:                Decodable + Send + Sync {
+    fn decode<D: Decoder>(d: &mut D) -> Result<BaseMesh<P, I, E>, D::Error> {
+        let mesh = try!(d.read_struct("BaseMesh", 6, |d| {
+            Ok(BaseMesh {
+                bvt:      try!(d.read_struct_field("bvt", 0, Decodable::decode)),
+                bvs:      try!(d.read_struct_field("bvs", 1, Decodable::decode)),
+                vertices: try!(d.read_struct_field("vertices", 2, Decodable::decode)),
+                indices:  try!(d.read_struct_field("indices", 3, Decodable::decode)),
+                uvs:      try!(d.read_struct_field("uvs", 4, Decodable::decode)),
+                normals:  try!(d.read_struct_field("normals", 5, Decodable::decode)),
+                elt:      PhantomData
+            })
+        }));
+
+        if let Err(msg) = mesh.check_decoded() {
+            return Err(d.error(msg));
+        }
+
+        Ok(mesh)
+    }
+}
+
+#[cfg(feature = "serde-serialize")]
+#[derive(Serialize)]
+#[serde(rename = "BaseMesh")]
+struct SerializedBaseMesh<'a, P: 'a, I: 'a, V: 'a, UV: 'a> {
+    bvt:      &'a BVT<usize, AABB<P>>,
+    bvs:      &'a [AABB<P>],
+    vertices: &'a [P],
+    indices:  &'a [I],
+    uvs:      Option<&'a [UV]>,
+    normals:  Option<&'a [V]>
+}
+
+#[cfg(feature = "serde-serialize")]
+#[derive(Deserialize)]
+#[serde(rename = "BaseMesh", bound(deserialize = "P:  Point + Deserialize<'de>,
+                                                  I:  Deserialize<'de>,
+                                                  V:  Deserialize<'de>,
+                                                  UV: Deserialize<'de>"))]
+struct DeserializedBaseMesh<P, I, V, UV> {
+    bvt:      BVT<usize, AABB<P>>,
+    bvs:      Vec<AABB<P>>,
+    vertices: Vec<P>,
+    indices:  Vec<I>,
+    uvs:      Option<Vec<UV>>,
+    normals:  Option<Vec<V>>
+}
+
+#[cfg(feature = "serde-serialize")]
+impl<P, I, E> Serialize for BaseMesh<P, I, E>
+    where P:               Point + Serialize,
+          P::Vector:       Serialize,
+          Point2<P::Real>: Serialize,
+          I:               Serialize {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        let mesh = SerializedBaseMesh {
+            bvt:      &*self.bvt,
+            bvs:      &self.bvs[..],
+            vertices: &self.vertices[..],
+            indices:  &self.indices[..],
+            uvs:      self.uvs.as_ref().map(|uvs| &uvs[..]),
+            normals:  self.normals.as_ref().map(|ns| &ns[..])
+        };
+
+        mesh.serialize(s)
+    }
+}
+
+#[cfg(feature = "serde-serialize")]
+impl<'de, P, I, E> Deserialize<'de> for BaseMesh<P, I, E>
+    where P:               Point + Deserialize<'de>,
+          P::Vector:       Deserialize<'de>,
+          Point2<P::Real>: Deserialize<'de>,
+          I:               Deserialize<'de> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<BaseMesh<P, I, E>, D::Error> {
+        let mesh: DeserializedBaseMesh<P, I, P::Vector, Point2<P::Real>> = try!(Deserialize::deserialize(d));
+        let mesh = BaseMesh {
+            bvt:      Arc::new(mesh.bvt),
+            bvs:      Arc::new(mesh.bvs),
+            vertices: Arc::new(mesh.vertices),
+            indices:  Arc::new(mesh.indices),
+            uvs:      mesh.uvs.map(Arc::new),
+            normals:  mesh.normals.map(Arc::new),
+            elt:      PhantomData
+        };
+
+        match mesh.check_decoded() {
+            Ok(())   => Ok(mesh),
+            Err(msg) => Err(D::Error::custom(msg))
+        }
+    }
+}
+
+impl<P: Point, I, E> BaseMesh<P, I, E> {
+    // Checks that the data of a decoded mesh are consistent. The vertex indices are checked by
+    // the concrete meshes.
+    fn check_decoded(&self) -> Result<(), &'static str> {
+        if self.bvs.len() != self.indices.len() {
+            return Err("Invalid mesh: there must be one bounding volume per element.");
+        }
+
+        if self.uvs.iter().any(|uvs| uvs.len() != self.vertices.len()) {
+            return Err("Invalid mesh: there must be one texture coordinate per vertex.");
+        }
+
+        if self.normals.iter().any(|ns| ns.len() != self.vertices.len()) {
+            return Err("Invalid mesh: there must be one normal per vertex.");
+        }
+
+        let mut leaves = LeafIndicesChecker { len: self.indices.len(), valid: true };
+        self.bvt.visit(&mut leaves);
+
+        if !leaves.valid {
+            return Err("Invalid mesh: a leaf of the BVT is not the index of an element.");
+        }
+
+        Ok(())
+    }
+}
+
+// Checks that all the leaves of a BVT are smaller than `len`.
+struct LeafIndicesChecker {
+    len:   usize,
+    valid: bool
+}
+
+impl<BV> BVTVisitor<usize, BV> for LeafIndicesChecker {
+    #[inline]
+    fn visit_internal(&mut self, _: &BV) -> bool {
+        self.valid
+    }
+
+    #[inline]
+    fn visit_leaf(&mut self, b: &usize, _: &BV) {
+        if *b >= self.len {
+            self.valid = false
+        }
+    }
+}
+
+impl<P, I, E> BaseMesh<P, I, E>
+    where P: Point,
+          E: BaseMeshElement<I, P> + HasBoundingVolume<Id, AABB<P>> {
+    /// Mutable access to the vertices of this mesh.
+    ///
+    /// The vertices are copied first if they are shared with other meshes, e.g., with clones of
+    /// this mesh. `.refit(...)` must be called after the vertices are modified so that the
+    /// bounding volumes of the mesh elements take their new positions into account.
+    #[inline]
+    pub fn vertices_mut(&mut self) -> &mut [P] {
+        &mut Arc::make_mut(&mut self.vertices)[..]
+    }
+
+    /// Updates the bounding volumes of the mesh elements and of the BVT after their vertices moved.
+    ///
+    /// Only elements that are no longer contained by their current bounding volume are updated:
+    /// their bounding volume is replaced by their AABB enlarged by `margin`. Then, only the BVT
+    /// nodes above them are updated. The topology of the BVT is not changed so its quality
+    /// degrades if the mesh is deformed too much. Returns the number of elements updated.
+    pub fn refit(&mut self, margin: P::Real) -> usize {
+        let mut changed = vec![ false; self.bvs.len() ];
+
+        {
+            let vs  = &self.vertices[..];
+            let bvs = Arc::make_mut(&mut self.bvs);
+
+            for (i, is) in self.indices.iter().enumerate() {
+                let element: E = BaseMeshElement::new_with_vertices_and_indices(vs, is);
+                let bv         = bounding_volume::aabb(&element, &Id::new());
+
+                if !bvs[i].contains(&bv) {
+                    bvs[i]     = bv.loosened(margin);
+                    changed[i] = true;
+                }
+            }
+        }
+
+        if !changed.iter().any(|c| *c) {
+            return 0;
+        }
+
+        let bvs = &self.bvs;
+
+        Arc::make_mut(&mut self.bvt).refit_partially(&mut |i: &usize| {
+            if changed[*i] { Some(bvs[*i].clone()) } else { None }
+        })
+    }
+}
+
 impl<P, I, E> BaseMesh<P, I, E>
     where P: Point {
     /// The vertices of this mesh.
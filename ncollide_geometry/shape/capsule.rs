@@ -1,15 +1,23 @@
 //! Support mapping based Capsule shape.
 
+use std::hash::{Hash, Hasher};
+use std::ops::{Mul, MulAssign, Div, DivAssign};
+
 use num::Signed;
 
 use alga::general::Real;
 use na;
 
 use shape::SupportMap;
+use utils;
 use math::{Point, Isometry};
 
 /// SupportMap description of a capsule shape with its principal axis aligned with the `y` axis.
-#[derive(PartialEq, Debug, Clone, RustcEncodable, RustcDecodable)]
+///
+/// Capsules are compared and hashed on the exact representation of their dimensions, given by
+/// `utils::real_bits`. This is intended for cache keys: capsules with slightly different
+/// dimensions are not equal.
+#[derive(Debug, Clone, RustcEncodable, RustcDecodable)]
 pub struct Capsule<N> {
     half_height: N,
     radius:      N,
@@ -23,6 +31,8 @@ impl<N: Real> Capsule<N> {
     /// * `radius` - radius of the rounded part of the capsule.
     pub fn new(half_height: N, radius: N) -> Capsule<N> {
         assert!(half_height.is_positive() && radius.is_positive());
+        debug_assert!(utils::is_finite(half_height) && utils::is_finite(radius),
+                      "The capsule dimensions must be finite.");
 
         Capsule {
             half_height: half_height,
@@ -41,6 +51,42 @@ impl<N: Real> Capsule<N> {
     pub fn radius(&self) -> N {
         self.radius
     }
+
+    /// The two endpoints of the segment at the core of this capsule, in local space.
+    ///
+    /// Those are the centers of the rounded parts, at `-half_height` and `half_height` along the
+    /// `y` axis.
+    #[inline]
+    pub fn segment<P>(&self) -> (P, P)
+        // The bounds on `P::Vector` are implied by `Point<Real = N>` but must be restated.
+        where P: Point<Real = N>,
+              P::Vector: Mul<N, Output = P::Vector> + MulAssign<N> + Div<N, Output = P::Vector> + DivAssign<N> {
+        let mut a = P::origin();
+        let mut b = P::origin();
+
+        a[1] = -self.half_height;
+        b[1] = self.half_height;
+
+        (a, b)
+    }
+}
+
+impl<N: Real> PartialEq for Capsule<N> {
+    #[inline]
+    fn eq(&self, other: &Capsule<N>) -> bool {
+        utils::real_bits(self.half_height) == utils::real_bits(other.half_height) &&
+        utils::real_bits(self.radius) == utils::real_bits(other.radius)
+    }
+}
+
+impl<N: Real> Eq for Capsule<N> { }
+
+impl<N: Real> Hash for Capsule<N> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        utils::hash_real(self.half_height, state);
+        utils::hash_real(self.radius, state)
+    }
 }
 
 impl<P: Point, M: Isometry<P>> SupportMap<P, M> for Capsule<P::Real> {
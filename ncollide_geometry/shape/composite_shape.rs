@@ -8,11 +8,27 @@ use shape::Shape;
 /// A composite shape is composed of several shapes. Typically, it is a convex decomposition of
 /// a concave shape.
 pub trait CompositeShape<P: Point, M> {
+    /// The number of sub-shapes of this composite shape.
+    fn len(&self) -> usize;
+    /// Whether this composite shape has no sub-shape.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
     /// Applies a function to each sub-shape of this concave shape.
     fn map_part_at(&self, usize, &mut FnMut(&M, &Shape<P, M>));
     /// Applies a transformation matrix and a function to each sub-shape of this concave
     /// shape.
     fn map_transformed_part_at(&self, usize, m: &M, &mut FnMut(&M, &Shape<P, M>));
+    /// Whether the two sub-shapes identified by the given indices share a vertex by construction,
+    /// e.g., two triangles of a mesh with a common edge.
+    ///
+    /// A ray going through such a shared vertex or edge hits both sub-shapes at the same point.
+    /// Defaults to `false`.
+    #[inline]
+    fn parts_share_vertex(&self, _: usize, _: usize) -> bool {
+        false
+    }
 
     // FIXME: the following two methods really are not generic enough.
     /// Gets the AABB of the shape identified by the index `i`.
@@ -3,23 +3,26 @@
 //!
 
 use std::ops::Mul;
+use std::sync::Arc;
 
 use na;
 
 use bounding_volume::{AABB, BoundingVolume};
 use partitioning::BVT;
-use shape::{CompositeShape, ShapeHandle, Shape};
+use shape::{CompositeShape, ShapeHandle, Shape, WithMargin, FeatureId};
 use math::{Point, Isometry};
 
 /// A compound shape with an aabb bounding volume.
 ///
 /// A compound shape is a shape composed of the union of several simpler shape. This is
 /// the main way of creating a concave shape from convex parts. Each parts can have its own
-/// delta transformation to shift or rotate it with regard to the other shapes.
+/// delta transformation to shift or rotate it with regard to the other shapes. The parts and the
+/// BVT are shared between the clones of a compound shape: cloning it is cheap.
 pub struct Compound<P: Point, M> {
-    shapes:  Vec<(M, ShapeHandle<P, M>)>,
-    bvt:     BVT<usize, AABB<P>>,
-    bvs:     Vec<AABB<P>>
+    shapes:  Arc<Vec<(M, ShapeHandle<P, M>)>>,
+    bvt:     Arc<BVT<usize, AABB<P>>>,
+    bvs:     Arc<Vec<AABB<P>>>,
+    margin:  P::Real
 }
 
 impl<P: Point, M: Clone> Clone for Compound<P, M> {
@@ -27,12 +30,18 @@ impl<P: Point, M: Clone> Clone for Compound<P, M> {
         Compound {
             shapes: self.shapes.clone(),
             bvt:    self.bvt.clone(),
-            bvs:    self.bvs.clone()
+            bvs:    self.bvs.clone(),
+            margin: self.margin
         }
     }
 }
 
 impl<P: Point, M: Isometry<P>> Compound<P, M> {
+    /// Builds a new compound shape from parts positioned by any type convertible to `M`, e.g., a `Pose`.
+    pub fn from_poses<I: Into<M>>(shapes: Vec<(I, ShapeHandle<P, M>)>) -> Compound<P, M> {
+        Compound::new(shapes.into_iter().map(|(m, s)| (m.into(), s)).collect())
+    }
+
     /// Builds a new compound shape.
     pub fn new(shapes: Vec<(M, ShapeHandle<P, M>)>) -> Compound<P, M> {
         let mut bvs    = Vec::new();
@@ -49,13 +58,37 @@ impl<P: Point, M: Isometry<P>> Compound<P, M> {
         let bvt = BVT::new_balanced(leaves);
 
         Compound {
-            shapes: shapes,
-            bvt:    bvt,
-            bvs:    bvs
+            shapes: Arc::new(shapes),
+            bvt:    Arc::new(bvt),
+            bvs:    Arc::new(bvs),
+            margin: na::zero()
         }
     }
 }
 
+impl<P: Point, M: 'static + Send + Sync + Isometry<P>> Compound<P, M> {
+    /// Builds a new compound shape with every support-mapped part inflated by a collision margin.
+    ///
+    /// Each support-mapped part is wrapped into a `WithMargin` shape, the other parts are left
+    /// unchanged. The contacts with this compound are thus generated a distance `margin` before
+    /// the parts actually touch, and their penetration depth is relative to the inflated surface.
+    pub fn new_with_margin(shapes: Vec<(M, ShapeHandle<P, M>)>, margin: P::Real) -> Compound<P, M> {
+        let shapes = shapes.into_iter().map(|(m, s)| {
+            if s.is_support_map() {
+                (m, ShapeHandle::new(WithMargin::new(s, margin)))
+            }
+            else {
+                (m, s)
+            }
+        }).collect();
+
+        let mut res = Compound::new(shapes);
+        res.margin  = margin;
+
+        res
+    }
+}
+
 impl<P: Point, M> Compound<P, M> {
     /// The shapes of this compound shape.
     #[inline]
@@ -63,6 +96,14 @@ impl<P: Point, M> Compound<P, M> {
         &self.shapes[..]
     }
 
+    /// The collision margin added to the support-mapped parts of this compound shape.
+    ///
+    /// This is zero unless this compound shape has been built by `Compound::new_with_margin`.
+    #[inline]
+    pub fn margin(&self) -> P::Real {
+        self.margin
+    }
+
     /// The optimization structure used by this compound shape.
     #[inline]
     pub fn bvt(&self) -> &BVT<usize, AABB<P>> {
@@ -80,11 +121,48 @@ impl<P: Point, M> Compound<P, M> {
     pub fn aabb_at(&self, i: usize) -> &AABB<P> {
         &self.bvs[i]
     }
+
+    /// The identifier on this compound of the feature `feature` of its `i`-th part.
+    ///
+    /// The feature with the index `k` on the `i`-th part has the index `k * n + i` on this
+    /// compound, `n` being its number of parts. An unknown feature stays unknown.
+    #[inline]
+    pub fn part_feature_id(&self, i: usize, feature: FeatureId) -> FeatureId {
+        let n = self.shapes.len();
+
+        match feature {
+            FeatureId::Vertex(k) => FeatureId::Vertex(k * n + i),
+            FeatureId::Edge(k)   => FeatureId::Edge(k * n + i),
+            FeatureId::Face(k)   => FeatureId::Face(k * n + i),
+            FeatureId::Unknown   => FeatureId::Unknown
+        }
+    }
+
+    /// The index of the part of this compound owning the feature `feature`, and the identifier
+    /// of this feature on the part itself.
+    ///
+    /// This is the inverse of `part_feature_id`. Returns `None` for an unknown feature.
+    #[inline]
+    pub fn subshape_feature_id(&self, feature: FeatureId) -> Option<(usize, FeatureId)> {
+        let n = self.shapes.len();
+
+        match feature {
+            FeatureId::Vertex(k) => Some((k % n, FeatureId::Vertex(k / n))),
+            FeatureId::Edge(k)   => Some((k % n, FeatureId::Edge(k / n))),
+            FeatureId::Face(k)   => Some((k % n, FeatureId::Face(k / n))),
+            FeatureId::Unknown   => None
+        }
+    }
 }
 
 impl<P, M> CompositeShape<P, M> for Compound<P, M>
     where P: Point,
           M: Clone + Mul<M, Output = M> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.shapes().len()
+    }
+
     #[inline(always)]
     fn map_part_at(&self, i: usize, f: &mut FnMut(&M, &Shape<P, M>)) {
         let &(ref m, ref g) = &self.shapes()[i];
@@ -1,15 +1,24 @@
 //! Support mapping based Cone shape.
 
+use std::hash::{Hash, Hasher};
+
 use num::{Signed, Zero};
 
+use approx::ApproxEq;
 use alga::general::Real;
 use alga::linear::NormedSpace;
 use na;
-use shape::SupportMap;
+use shape::{SupportMap, FeatureId};
+use utils;
+use volumetric;
 use math::{Point, Isometry};
 
 /// SupportMap description of a cylinder shape with its principal axis aligned with the `y` axis.
-#[derive(PartialEq, Debug, Clone, RustcEncodable, RustcDecodable)]
+///
+/// Cones are compared and hashed on the exact representation of their dimensions, given by
+/// `utils::real_bits`. This is intended for cache keys: cones with slightly different dimensions
+/// are not equal.
+#[derive(Debug, Clone, RustcEncodable, RustcDecodable)]
 pub struct Cone<N> {
     half_height: N,
     radius:      N,
@@ -23,6 +32,8 @@ impl<N: Real> Cone<N> {
     /// * `radius` - the length of the cone along all other axis.
     pub fn new(half_height: N, radius: N) -> Cone<N> {
         assert!(half_height.is_positive() && radius.is_positive());
+        debug_assert!(utils::is_finite(half_height) && utils::is_finite(radius),
+                      "The cone dimensions must be finite.");
 
         Cone {
             half_height: half_height,
@@ -41,8 +52,54 @@ impl<N: Real> Cone<N> {
     pub fn radius(&self) -> N {
         self.radius
     }
+
+    /// Sets the cone half length along the `y` axis.
+    ///
+    /// Fails if `half_height` is not positive. Bounding volumes are not stored by the cone: those
+    /// computed before this call must be recomputed by the user.
+    #[inline]
+    pub fn set_half_height(&mut self, half_height: N) {
+        assert!(half_height.is_positive(), "The cone half height must be positive.");
+        debug_assert!(utils::is_finite(half_height), "The cone dimensions must be finite.");
+        self.half_height = half_height
+    }
+
+    /// Sets the radius of the cone along all but the `y` axis.
+    ///
+    /// Fails if `radius` is not positive. Bounding volumes are not stored by the cone: those
+    /// computed before this call must be recomputed by the user.
+    #[inline]
+    pub fn set_radius(&mut self, radius: N) {
+        assert!(radius.is_positive(), "The cone radius must be positive.");
+        debug_assert!(utils::is_finite(radius), "The cone dimensions must be finite.");
+        self.radius = radius
+    }
+
+    /// The cone volume in the dimension of `P`, see `volumetric::cone_volume`.
+    #[inline]
+    pub fn volume<P: Point>(&self) -> N {
+        volumetric::cone_volume(self.half_height, self.radius, na::dimension::<P::Vector>())
+    }
+}
+
+
+impl<N: Real> PartialEq for Cone<N> {
+    #[inline]
+    fn eq(&self, other: &Cone<N>) -> bool {
+        utils::real_bits(self.half_height) == utils::real_bits(other.half_height) &&
+        utils::real_bits(self.radius) == utils::real_bits(other.radius)
+    }
 }
 
+impl<N: Real> Eq for Cone<N> { }
+
+impl<N: Real> Hash for Cone<N> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        utils::hash_real(self.half_height, state);
+        utils::hash_real(self.radius, state)
+    }
+}
 
 impl<P: Point, M: Isometry<P>> SupportMap<P, M> for Cone<P::Real> {
     #[inline]
@@ -75,4 +132,63 @@ impl<P: Point, M: Isometry<P>> SupportMap<P, M> for Cone<P::Real> {
 
         m.transform_point(&P::from_coordinates(vres))
     }
+
+    /// Identifies the feature of this cone containing all the given points.
+    ///
+    /// The lateral surface is `FeatureId::Face(0)`, the base `FeatureId::Face(1)`, the rim
+    /// between them `FeatureId::Edge(0)`, and the apex `FeatureId::Vertex(0)`. A point belongs to a
+    /// surface if its distance to this surface is smaller than a tolerance proportional to the
+    /// cone dimensions.
+    fn support_feature_id(&self, m: &M, pts: &[P]) -> FeatureId {
+        let h   = self.half_height();
+        let k   = self.radius() / (h + h);
+        let tol = P::Real::default_epsilon().sqrt() * h.max(self.radius());
+
+        // The bit 0 (resp. 1, 2) is set if all the points are on the lateral surface (resp. the
+        // base, the apex).
+        let mut mask = 0b111;
+
+        for pt in pts.iter() {
+            let local_pt = m.inverse_transform_point(pt);
+            let mut perp = local_pt.coordinates();
+            perp[1] = na::zero();
+
+            let perp_norm    = na::norm(&perp);
+            let lateral_dist = (perp_norm - k * (h - local_pt[1])).abs() / (na::one::<P::Real>() + k * k).sqrt();
+            let mut pt_mask  = 0;
+
+            if lateral_dist <= tol {
+                pt_mask |= 0b001;
+            }
+
+            if (local_pt[1] + h).abs() <= tol {
+                pt_mask |= 0b010;
+            }
+
+            if perp_norm <= tol && (local_pt[1] - h).abs() <= tol {
+                pt_mask |= 0b100;
+            }
+
+            mask &= pt_mask;
+        }
+
+        if pts.is_empty() {
+            FeatureId::Unknown
+        }
+        else if mask & 0b100 != 0 {
+            FeatureId::Vertex(0)
+        }
+        else if mask == 0b011 {
+            FeatureId::Edge(0)
+        }
+        else if mask == 0b010 {
+            FeatureId::Face(1)
+        }
+        else if mask == 0b001 {
+            FeatureId::Face(0)
+        }
+        else {
+            FeatureId::Unknown
+        }
+    }
 }
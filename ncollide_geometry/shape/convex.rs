@@ -1,6 +1,9 @@
+use approx::ApproxEq;
+use alga::general::Real;
+use na::{self, Unit};
 use utils;
 
-use shape::SupportMap;
+use shape::{SupportMap, FeatureId};
 use math::{Point, Isometry};
 
 #[derive(PartialEq, Debug, Clone, RustcEncodable, RustcDecodable)]
@@ -29,6 +32,83 @@ impl<P> ConvexHull<P> {
     }
 }
 
+impl<P: Point> ConvexHull<P> {
+    /// The points of this transformed convex polytope forming its feature supporting the
+    /// direction `dir`.
+    ///
+    /// Those are the points `p` such that the angle between `dir` and the segment joining `p` to
+    /// the support point in the direction `dir` differs from a right angle by less than `eps`
+    /// (in radians). The support point is always the first point returned. Thus, a single point
+    /// is returned for a vertex, two for an edge, and more for a face.
+    pub fn support_feature<M: Isometry<P>>(&self, m: &M, dir: &P::Vector, eps: P::Real) -> Vec<P> {
+        let local_dir = na::normalize(&m.inverse_rotate_vector(dir));
+        let best_pt   = utils::point_cloud_support_point(&local_dir, self.points());
+        let sin_eps   = eps.sin();
+        let mut res   = vec![m.transform_point(&best_pt)];
+
+        for pt in self.points.iter() {
+            let dpt = best_pt - *pt;
+
+            if *pt != best_pt && na::dot(&dpt, &local_dir) <= na::norm(&dpt) * sin_eps {
+                res.push(m.transform_point(pt))
+            }
+        }
+
+        res
+    }
+
+    /// The identifier of the feature of this transformed convex polytope supporting the
+    /// direction `dir`.
+    ///
+    /// The feature is made of the points returned by `support_feature` with the same `eps`. It
+    /// is identified by the indices of its points, `n` being the number of points of this convex
+    /// polytope: `FeatureId::Vertex(i)` for the `i`-th point, `FeatureId::Edge(i * n + j)` for an
+    /// edge with the two smallest point indices `i < j`, and `FeatureId::Face((i * n + j) * n + k)`
+    /// for a face where `k` is the smallest index of a point not aligned with the points `i` and
+    /// `j`. Returns `FeatureId::Unknown` if `dir` is zero.
+    pub fn support_feature_id_toward<M: Isometry<P>>(&self, m: &M, dir: &P::Vector, eps: P::Real) -> FeatureId {
+        let local_dir = match Unit::try_new(m.inverse_rotate_vector(dir), P::Real::default_epsilon()) {
+            Some(local_dir) => local_dir.unwrap(),
+            None            => return FeatureId::Unknown
+        };
+
+        let mut best = 0;
+
+        for (i, pt) in self.points.iter().enumerate() {
+            if na::dot(&pt.coordinates(), &local_dir) > na::dot(&self.points[best].coordinates(), &local_dir) {
+                best = i;
+            }
+        }
+
+        let best_pt = self.points[best];
+        let sin_eps = eps.sin();
+        let feature: Vec<usize> = (0 .. self.points.len()).filter(|i| {
+            let dpt = best_pt - self.points[*i];
+            *i == best || na::dot(&dpt, &local_dir) <= na::norm(&dpt) * sin_eps
+        }).collect();
+
+        let n = self.points.len();
+
+        if feature.len() == 1 {
+            return FeatureId::Vertex(feature[0]);
+        }
+
+        let (i, j) = (feature[0], feature[1]);
+        let u      = self.points[j] - self.points[i];
+
+        for k in feature[2 ..].iter() {
+            let v    = self.points[*k] - self.points[i];
+            let perp = v - u * (na::dot(&u, &v) / na::dot(&u, &u));
+
+            if na::norm(&perp) > na::norm(&v) * sin_eps {
+                return FeatureId::Face((i * n + j) * n + *k);
+            }
+        }
+
+        FeatureId::Edge(i * n + j)
+    }
+}
+
 
 impl<P: Point, M: Isometry<P>> SupportMap<P, M> for ConvexHull<P> {
     #[inline]
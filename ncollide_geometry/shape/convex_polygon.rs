@@ -0,0 +1,129 @@
+use std::cmp::Ordering;
+
+use na;
+use utils;
+
+use shape::SupportMap;
+use math::{Point, Isometry};
+
+#[derive(PartialEq, Debug, Clone, RustcEncodable, RustcDecodable)]
+/// A 2D convex polygon given by its vertices in counterclockwise order.
+///
+/// Unlike `ConvexHull`, the vertices are assumed to already describe a convex polygon so that the
+/// queries can iterate on its edges.
+pub struct ConvexPolygon<P> {
+    points: Vec<P>
+}
+
+impl<P: Point> ConvexPolygon<P> {
+    /// Creates a convex polygon from its vertices given in counterclockwise order.
+    ///
+    /// The points must be 2-dimensional. At least three points must be given and they are assumed
+    /// to form a convex polygon. This last requirement is checked in debug mode only.
+    pub fn from_ccw_points(points: &[P]) -> ConvexPolygon<P> {
+        assert!(na::dimension::<P::Vector>() == 2, "A convex polygon must be 2-dimensional.");
+        assert!(points.len() >= 3, "A convex polygon must have at least three vertices.");
+
+        let res = ConvexPolygon {
+            points: points.to_vec()
+        };
+
+        debug_assert!(res.is_ccw_convex(), "The polygon vertices must be convex and counterclockwise.");
+
+        res
+    }
+
+    /// Creates the convex polygon which is the convex hull of a 2D point cloud.
+    ///
+    /// The hull is computed with Andrew's monotone chain algorithm using the exact orientation
+    /// predicate `utils::orient2d`, so that nearly collinear points do not break its convexity.
+    /// Duplicate points and points lying on the edges of the hull are discarded. Returns `None` if
+    /// all the points are collinear.
+    pub fn try_from_points(points: &[P]) -> Option<ConvexPolygon<P>> {
+        assert!(na::dimension::<P::Vector>() == 2, "A convex polygon must be 2-dimensional.");
+
+        let mut sorted = points.to_vec();
+        sorted.sort_by(|a, b| {
+            (a[0], a[1]).partial_cmp(&(b[0], b[1])).expect("The points must not contain NaN.")
+        });
+        sorted.dedup();
+
+        if sorted.len() < 3 {
+            return None;
+        }
+
+        // The lower hull from left to right, then the upper hull from right to left.
+        let mut hull: Vec<P> = Vec::with_capacity(sorted.len() + 1);
+
+        for pass in 0 .. 2 {
+            let start = hull.len();
+
+            for i in 0 .. sorted.len() {
+                let pt = if pass == 0 { sorted[i] } else { sorted[sorted.len() - 1 - i] };
+
+                while hull.len() >= start + 2 &&
+                      utils::orient2d(&hull[hull.len() - 2], &hull[hull.len() - 1], &pt) != Ordering::Greater {
+                    let _ = hull.pop();
+                }
+
+                hull.push(pt);
+            }
+
+            // The last point is the first one of the other half.
+            let _ = hull.pop();
+        }
+
+        if hull.len() < 3 {
+            None
+        }
+        else {
+            Some(ConvexPolygon { points: hull })
+        }
+    }
+
+    /// The vertices of this convex polygon, in counterclockwise order.
+    #[inline]
+    pub fn points(&self) -> &[P] {
+        &self.points[..]
+    }
+
+    /// The vertices of the `i`-th edge of this polygon, which joins the `i`-th vertex to the next one.
+    #[inline]
+    pub fn edge(&self, i: usize) -> (&P, &P) {
+        (&self.points[i], &self.points[(i + 1) % self.points.len()])
+    }
+
+    /// The outward unit normal of the `i`-th edge of this polygon.
+    #[inline]
+    pub fn edge_normal(&self, i: usize) -> P::Vector {
+        let (a, b) = self.edge(i);
+        let ab     = *b - *a;
+        let mut n  = na::zero::<P::Vector>();
+
+        n[0] = ab[1];
+        n[1] = -ab[0];
+
+        na::normalize(&n)
+    }
+
+    fn is_ccw_convex(&self) -> bool {
+        let len = self.points.len();
+
+        (0 .. len).all(|i| {
+            let e1 = self.points[(i + 1) % len] - self.points[i];
+            let e2 = self.points[(i + 2) % len] - self.points[(i + 1) % len];
+
+            e1[0] * e2[1] - e1[1] * e2[0] >= na::zero()
+        })
+    }
+}
+
+impl<P: Point, M: Isometry<P>> SupportMap<P, M> for ConvexPolygon<P> {
+    #[inline]
+    fn support_point(&self, m: &M, dir: &P::Vector) -> P {
+        let local_dir = m.inverse_rotate_vector(dir);
+        let best_pt   = utils::point_cloud_support_point(&local_dir, self.points());
+
+        m.transform_point(&best_pt)
+    }
+}
@@ -1,30 +1,49 @@
 //! Support mapping based Cuboid shape.
 
+use std::hash::{Hash, Hasher};
+
 use num::Zero;
 
 use na;
-use shape::SupportMap;
+use shape::{SupportMap, FeatureId};
+use utils;
+use volumetric;
 use math::{Point, Vector, Isometry};
 
 /// Shape of a box.
-#[derive(PartialEq, Debug, Clone, RustcEncodable, RustcDecodable)]
+///
+/// Boxes are compared and hashed on the exact representation of their half-extents, given by
+/// `utils::real_bits`. This is intended for cache keys: boxes with slightly different
+/// half-extents are not equal.
+#[derive(Debug, Clone, RustcEncodable, RustcDecodable)]
 pub struct Cuboid<V> {
     half_extents: V,
 }
 
 impl<V: Vector> Cuboid<V> {
     /// Creates a new box from its half-extents. Half-extents are the box half-width along each
-    /// axis. Each half-extent must be greater than 0.04.
+    /// axis. Each half-extent must be positive.
+    ///
+    /// A zero half-extent gives a flat box, e.g., a finite rectangle in 3D. Its two faces
+    /// orthogonal to the flattened axis are coincident and have opposite normals.
     #[inline]
     pub fn new(half_extents: V) -> Cuboid<V> {
         for i in 0 .. na::dimension::<V>() {
             assert!(half_extents[i] >= V::Real::zero());
         }
 
+        debug_assert!(utils::is_finite_vector(&half_extents), "The cuboid half-extents must be finite.");
+
         Cuboid {
             half_extents: half_extents
         }
     }
+
+    /// The box volume, see `volumetric::cuboid_volume`.
+    #[inline]
+    pub fn volume(&self) -> V::Real {
+        volumetric::cuboid_volume(&self.half_extents)
+    }
 }
 
 impl<V> Cuboid<V> {
@@ -35,6 +54,26 @@ impl<V> Cuboid<V> {
     }
 }
 
+impl<V: Vector> PartialEq for Cuboid<V> {
+    #[inline]
+    fn eq(&self, other: &Cuboid<V>) -> bool {
+        (0 .. na::dimension::<V>()).all(|i| {
+            utils::real_bits(self.half_extents[i]) == utils::real_bits(other.half_extents[i])
+        })
+    }
+}
+
+impl<V: Vector> Eq for Cuboid<V> { }
+
+impl<V: Vector> Hash for Cuboid<V> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for i in 0 .. na::dimension::<V>() {
+            utils::hash_real(self.half_extents[i], state)
+        }
+    }
+}
+
 impl<P: Point, M: Isometry<P>> SupportMap<P, M> for Cuboid<P::Vector> {
     #[inline]
     fn support_point(&self, m: &M, dir: &P::Vector) -> P {
@@ -50,4 +89,46 @@ impl<P: Point, M: Isometry<P>> SupportMap<P, M> for Cuboid<P::Vector> {
 
         m.transform_point(&P::from_coordinates(res))
     }
+
+    /// Identifies the feature of this cuboid containing all the given points.
+    ///
+    /// The `i`-th bit of a vertex identifier is set if the vertex lies on the negative side of the
+    /// `i`-th axis. An edge or face identifier is `(free << dim) | signs` where `free` is the mask
+    /// of the axes the feature spans and `signs` the common sign bits of its vertices. The bit of
+    /// an axis with a zero half-extent is never set.
+    fn support_feature_id(&self, m: &M, pts: &[P]) -> FeatureId {
+        let dim = na::dimension::<P::Vector>();
+
+        if pts.is_empty() {
+            return FeatureId::Unknown;
+        }
+
+        let mut and_mask = !0usize;
+        let mut or_mask  = 0usize;
+
+        for pt in pts.iter() {
+            let local_pt = m.inverse_transform_point(pt);
+            let mut mask = 0usize;
+
+            for i in 0 .. dim {
+                // The sign along a flattened axis is meaningless.
+                if local_pt[i] < P::Real::zero() && !self.half_extents[i].is_zero() {
+                    mask |= 1 << i;
+                }
+            }
+
+            and_mask &= mask;
+            or_mask  |= mask;
+        }
+
+        let free = and_mask ^ or_mask;
+        let id   = (free << dim) | and_mask;
+
+        match free.count_ones() as usize {
+            0                 => FeatureId::Vertex(and_mask),
+            n if n == dim - 1 => FeatureId::Face(id),
+            1                 => FeatureId::Edge(id),
+            _                 => FeatureId::Unknown
+        }
+    }
 }
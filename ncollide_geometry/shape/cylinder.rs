@@ -4,10 +4,12 @@
 
 use num::{Signed, Zero};
 
+use approx::ApproxEq;
 use alga::general::Real;
 use alga::linear::NormedSpace;
 use na;
-use shape::SupportMap;
+use shape::{SupportMap, FeatureId};
+use utils;
 use math::{Point, Isometry};
 
 /// SupportMap description of a cylinder shape with its principal axis aligned with the `y` axis.
@@ -25,6 +27,8 @@ impl<N: Real> Cylinder<N> {
     /// * `radius` - the length of the cylinder along all other axis.
     pub fn new(half_height: N, radius: N) -> Cylinder<N> {
         assert!(half_height.is_positive() && radius.is_positive());
+        debug_assert!(utils::is_finite(half_height) && utils::is_finite(radius),
+                      "The cylinder dimensions must be finite.");
 
         Cylinder {
             half_height: half_height,
@@ -43,6 +47,28 @@ impl<N: Real> Cylinder<N> {
     pub fn radius(&self) -> N {
         self.radius
     }
+
+    /// Sets the cylinder half length along the `y` axis.
+    ///
+    /// Fails if `half_height` is not positive. Bounding volumes are not stored by the cylinder: those
+    /// computed before this call must be recomputed by the user.
+    #[inline]
+    pub fn set_half_height(&mut self, half_height: N) {
+        assert!(half_height.is_positive(), "The cylinder half height must be positive.");
+        debug_assert!(utils::is_finite(half_height), "The cylinder dimensions must be finite.");
+        self.half_height = half_height
+    }
+
+    /// Sets the radius of the cylinder along all but the `y` axis.
+    ///
+    /// Fails if `radius` is not positive. Bounding volumes are not stored by the cylinder: those
+    /// computed before this call must be recomputed by the user.
+    #[inline]
+    pub fn set_radius(&mut self, radius: N) {
+        assert!(radius.is_positive(), "The cylinder radius must be positive.");
+        debug_assert!(utils::is_finite(radius), "The cylinder dimensions must be finite.");
+        self.radius = radius
+    }
 }
 
 
@@ -71,4 +97,55 @@ impl<P: Point, M: Isometry<P>> SupportMap<P, M> for Cylinder<P::Real> {
 
         m.transform_point(&P::from_coordinates(vres))
     }
+
+    /// Identifies the feature of this cylinder containing all the given points.
+    ///
+    /// The lateral surface is `FeatureId::Face(0)`, the bottom and top caps `FeatureId::Face(1)`
+    /// and `FeatureId::Face(2)`, and the rims between the lateral surface and the bottom and top
+    /// caps `FeatureId::Edge(0)` and `FeatureId::Edge(1)`. A point belongs to a surface if its
+    /// distance to this surface is smaller than a tolerance proportional to the cylinder
+    /// dimensions.
+    fn support_feature_id(&self, m: &M, pts: &[P]) -> FeatureId {
+        let h   = self.half_height();
+        let tol = P::Real::default_epsilon().sqrt() * h.max(self.radius());
+
+        // The bit 0 (resp. 1, 2) is set if all the points are on the lateral surface (resp. the
+        // bottom cap, the top cap).
+        let mut mask = 0b111;
+
+        for pt in pts.iter() {
+            let local_pt = m.inverse_transform_point(pt);
+            let mut perp = local_pt.coordinates();
+            perp[1] = na::zero();
+
+            let mut pt_mask = 0;
+
+            if (na::norm(&perp) - self.radius()).abs() <= tol {
+                pt_mask |= 0b001;
+            }
+
+            if (local_pt[1] + h).abs() <= tol {
+                pt_mask |= 0b010;
+            }
+
+            if (local_pt[1] - h).abs() <= tol {
+                pt_mask |= 0b100;
+            }
+
+            mask &= pt_mask;
+        }
+
+        if pts.is_empty() {
+            return FeatureId::Unknown;
+        }
+
+        match mask {
+            0b011 => FeatureId::Edge(0),
+            0b101 => FeatureId::Edge(1),
+            0b001 => FeatureId::Face(0),
+            0b010 => FeatureId::Face(1),
+            0b100 => FeatureId::Face(2),
+            _     => FeatureId::Unknown
+        }
+    }
 }
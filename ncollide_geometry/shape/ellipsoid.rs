@@ -0,0 +1,86 @@
+//! Support mapping based Ellipsoid shape.
+
+use num::Zero;
+
+use na;
+use shape::{SupportMap, FeatureId};
+use utils;
+use math::{Point, Vector, Isometry};
+
+/// SupportMap description of an ellipsoid centered at the origin with its axes aligned with the
+/// coordinate axes.
+#[derive(PartialEq, Debug, Clone, RustcEncodable, RustcDecodable)]
+pub struct Ellipsoid<V> {
+    radii: V
+}
+
+impl<V: Vector> Ellipsoid<V> {
+    /// Creates a new ellipsoid from its radii along each coordinate axis.
+    ///
+    /// Each radius must be strictly positive.
+    #[inline]
+    pub fn new(radii: V) -> Ellipsoid<V> {
+        for i in 0 .. na::dimension::<V>() {
+            assert!(radii[i] > V::Real::zero(), "An ellipsoid radius must be strictly positive.");
+        }
+
+        debug_assert!(utils::is_finite_vector(&radii), "The ellipsoid radii must be finite.");
+
+        Ellipsoid {
+            radii: radii
+        }
+    }
+
+    /// The largest of this ellipsoid radii.
+    #[inline]
+    pub fn max_radius(&self) -> V::Real {
+        let mut res = self.radii[0];
+
+        for i in 1 .. na::dimension::<V>() {
+            if self.radii[i] > res {
+                res = self.radii[i]
+            }
+        }
+
+        res
+    }
+}
+
+impl<V> Ellipsoid<V> {
+    /// The radii of this ellipsoid along each coordinate axis.
+    #[inline]
+    pub fn radii(&self) -> &V {
+        &self.radii
+    }
+}
+
+impl<P: Point, M: Isometry<P>> SupportMap<P, M> for Ellipsoid<P::Vector> {
+    #[inline]
+    fn support_point(&self, m: &M, dir: &P::Vector) -> P {
+        let local_dir = m.inverse_rotate_vector(dir);
+
+        // The support point is `(R² d) / |R d|` where `R` is the diagonal matrix of the radii.
+        let mut rdir  = local_dir;
+        let mut r2dir = local_dir;
+
+        for i in 0 .. na::dimension::<P::Vector>() {
+            rdir[i]  = rdir[i] * self.radii[i];
+            r2dir[i] = rdir[i] * self.radii[i];
+        }
+
+        let norm = na::norm(&rdir);
+
+        if norm.is_zero() {
+            m.transform_point(&P::origin())
+        }
+        else {
+            m.transform_point(&P::from_coordinates(r2dir / norm))
+        }
+    }
+
+    #[inline]
+    fn support_feature_id(&self, _: &M, _: &[P]) -> FeatureId {
+        // The whole boundary of an ellipsoid is a single curved surface.
+        FeatureId::Face(0)
+    }
+}
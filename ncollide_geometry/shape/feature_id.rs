@@ -0,0 +1,18 @@
+//! Identifiers of the geometric features of a shape.
+
+/// An identifier of a geometric feature (vertex, edge, or face) of a shape.
+///
+/// The meaning of the index wrapped by each variant is shape-dependent. Curved shapes (e.g.
+/// balls) have no discrete features: their whole boundary is identified by the synthetic
+/// "surface" feature `FeatureId::Face(0)`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, RustcEncodable, RustcDecodable)]
+pub enum FeatureId {
+    /// Shape-dependent identifier of a vertex.
+    Vertex(usize),
+    /// Shape-dependent identifier of an edge.
+    Edge(usize),
+    /// Shape-dependent identifier of a face.
+    Face(usize),
+    /// The feature could not be identified.
+    Unknown
+}
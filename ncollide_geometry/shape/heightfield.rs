@@ -0,0 +1,429 @@
+//! Terrain described by a regular grid of heights.
+
+use std::ops::{Mul, MulAssign, Div, DivAssign};
+
+use alga::general::Real;
+use na::{self, DMatrix, Point3, Vector3};
+
+use bounding_volume::AABB;
+use shape::{Triangle, FeatureId};
+use math::{Point, Isometry};
+
+/// A 3D heightfield, i.e., a terrain described by the heights of the nodes of a regular grid.
+///
+/// The grid lies on the `xz` plane and is centered at the origin. The `i`-th row of `heights`
+/// gives the heights of the nodes along the `x` axis at the `i`-th position along the `z` axis.
+/// The grid spans `scale.x` along `x` and `scale.z` along `z`, and each height is multiplied by
+/// `scale.y`. Each cell of the grid is split into two triangles.
+///
+/// By default, the normal of a ray hit or of a contact on the heightfield is the geometric normal
+/// of the triangle involved. With `set_smooth_normals(true)`, it is interpolated instead from the
+/// normals of the grid nodes, each one being the area-weighted average of the normals of the
+/// triangles around the node.
+///
+/// Though its queries are generic wrt. the point type, a heightfield is always 3-dimensional.
+#[derive(PartialEq, Debug, Clone)]
+pub struct HeightField<N: Real> {
+    heights:    DMatrix<N>,
+    scale:      Vector3<N>,
+    min_height: N,
+    max_height: N,
+    // The unit normal of each grid node in row-major order, if smooth normals are enabled.
+    normals:    Option<Vec<Vector3<N>>>
+}
+
+// The bounds on `P::Vector` below are implied by `Point<Real = N>` but must be restated.
+impl<N: Real> HeightField<N> {
+    /// Creates a new heightfield from the heights of the nodes of its grid and its scale.
+    ///
+    /// The grid must have at least two rows and two columns.
+    pub fn new(heights: DMatrix<N>, scale: Vector3<N>) -> HeightField<N> {
+        assert!(heights.nrows() > 1 && heights.ncols() > 1,
+                "A heightfield must have at least two rows and two columns.");
+
+        let mut min_height = heights[(0, 0)] * scale.y;
+        let mut max_height = min_height;
+
+        for i in 0 .. heights.nrows() {
+            for j in 0 .. heights.ncols() {
+                let h = heights[(i, j)] * scale.y;
+
+                min_height = min_height.min(h);
+                max_height = max_height.max(h);
+            }
+        }
+
+        HeightField {
+            heights:    heights,
+            scale:      scale,
+            min_height: min_height,
+            max_height: max_height,
+            normals:    None
+        }
+    }
+
+    /// The heights of the nodes of this heightfield grid, before scaling.
+    #[inline]
+    pub fn heights(&self) -> &DMatrix<N> {
+        &self.heights
+    }
+
+    /// The scale of this heightfield.
+    #[inline]
+    pub fn scale(&self) -> &Vector3<N> {
+        &self.scale
+    }
+
+    /// Whether the normals of this heightfield are interpolated from the normals of its nodes.
+    #[inline]
+    pub fn has_smooth_normals(&self) -> bool {
+        self.normals.is_some()
+    }
+
+    /// Enables or disables the interpolation of the normals of this heightfield.
+    ///
+    /// Enabling it computes the normal of every grid node.
+    pub fn set_smooth_normals(&mut self, smooth: bool) {
+        if !smooth {
+            self.normals = None;
+        }
+        else if self.normals.is_none() {
+            let ncols       = self.heights.ncols();
+            let mut normals = vec![na::zero::<Vector3<N>>(); self.heights.nrows() * ncols];
+
+            for id in 0 .. 2 * self.num_cells_x() * self.num_cells_z() {
+                // The norm of the scaled normal is twice the triangle area.
+                let scaled_normal = self.triangle_at::<Point3<N>>(id).scaled_normal();
+
+                for &(i, j) in self.triangle_nodes(id).iter() {
+                    normals[i * ncols + j] += scaled_normal;
+                }
+            }
+
+            for n in normals.iter_mut() {
+                *n = na::normalize(n);
+            }
+
+            self.normals = Some(normals);
+        }
+    }
+
+    /// The number of cells of this heightfield along the `z` axis.
+    #[inline]
+    pub fn num_cells_z(&self) -> usize {
+        self.heights.nrows() - 1
+    }
+
+    /// The number of cells of this heightfield along the `x` axis.
+    #[inline]
+    pub fn num_cells_x(&self) -> usize {
+        self.heights.ncols() - 1
+    }
+
+    /// The AABB of this heightfield, in its local space.
+    pub fn local_aabb<P>(&self) -> AABB<P>
+        where P: Point<Real = N>,
+              P::Vector: Mul<N, Output = P::Vector> + MulAssign<N> + Div<N, Output = P::Vector> + DivAssign<N> {
+        let _0_5: N = na::convert(0.5f64);
+        let mut mins = P::origin();
+        let mut maxs = P::origin();
+
+        mins[0] = -self.scale.x * _0_5;
+        mins[1] = self.min_height;
+        mins[2] = -self.scale.z * _0_5;
+        maxs[0] = self.scale.x * _0_5;
+        maxs[1] = self.max_height;
+        maxs[2] = self.scale.z * _0_5;
+
+        AABB::new(mins, maxs)
+    }
+
+    /// The position of the node at the `i`-th row and `j`-th column of the grid, in local space.
+    pub fn node_at<P>(&self, i: usize, j: usize) -> P
+        where P: Point<Real = N>,
+              P::Vector: Mul<N, Output = P::Vector> + MulAssign<N> + Div<N, Output = P::Vector> + DivAssign<N> {
+        let _0_5: N = na::convert(0.5f64);
+        let mut res = P::origin();
+
+        res[0] = (na::convert::<f64, N>(j as f64) / na::convert(self.num_cells_x() as f64) - _0_5) * self.scale.x;
+        res[1] = self.heights[(i, j)] * self.scale.y;
+        res[2] = (na::convert::<f64, N>(i as f64) / na::convert(self.num_cells_z() as f64) - _0_5) * self.scale.z;
+
+        res
+    }
+
+    /// The two triangles of the cell at the `i`-th row and `j`-th column of the grid.
+    ///
+    /// Both triangles are counterclockwise when seen from above the heightfield.
+    pub fn triangles_at<P>(&self, i: usize, j: usize) -> (Triangle<P>, Triangle<P>)
+        where P: Point<Real = N>,
+              P::Vector: Mul<N, Output = P::Vector> + MulAssign<N> + Div<N, Output = P::Vector> + DivAssign<N> {
+        let p00 = self.node_at(i, j);
+        let p01 = self.node_at(i, j + 1);
+        let p10 = self.node_at(i + 1, j);
+        let p11 = self.node_at(i + 1, j + 1);
+
+        (Triangle::new(p00, p10, p11), Triangle::new(p00, p11, p01))
+    }
+
+    /// The triangle with the identifier `id`, as given by `map_triangles_in_local_aabb`.
+    pub fn triangle_at<P>(&self, id: usize) -> Triangle<P>
+        where P: Point<Real = N>,
+              P::Vector: Mul<N, Output = P::Vector> + MulAssign<N> + Div<N, Output = P::Vector> + DivAssign<N> {
+        let nodes = self.triangle_nodes(id);
+
+        Triangle::new(self.node_at(nodes[0].0, nodes[0].1),
+                      self.node_at(nodes[1].0, nodes[1].1),
+                      self.node_at(nodes[2].0, nodes[2].1))
+    }
+
+    /// The local-space unit normal at a point of the triangle with the identifier `id`.
+    ///
+    /// The point is given by its barycentric coordinates wrt. the vertices of the triangle. If
+    /// smooth normals are disabled, this is the normal of the triangle.
+    pub fn normal_at<P>(&self, id: usize, bcoords: &Vector3<N>) -> P::Vector
+        where P: Point<Real = N>,
+              P::Vector: Mul<N, Output = P::Vector> + MulAssign<N> + Div<N, Output = P::Vector> + DivAssign<N> {
+        let n = match self.normals {
+            Some(ref normals) => {
+                let ncols = self.heights.ncols();
+                let nodes = self.triangle_nodes(id);
+                let n     = normals[nodes[0].0 * ncols + nodes[0].1] * bcoords.x +
+                            normals[nodes[1].0 * ncols + nodes[1].1] * bcoords.y +
+                            normals[nodes[2].0 * ncols + nodes[2].1] * bcoords.z;
+
+                na::normalize(&n)
+            },
+            None => na::normalize(&self.triangle_at::<Point3<N>>(id).scaled_normal())
+        };
+
+        let mut res = na::zero::<P::Vector>();
+        res[0] = n.x;
+        res[1] = n.y;
+        res[2] = n.z;
+
+        res
+    }
+
+    /// The local-space unit normal at the projection of `pt` on the plane of the triangle with the
+    /// identifier `id`.
+    pub fn normal_at_point<P>(&self, id: usize, pt: &P) -> P::Vector
+        where P: Point<Real = N>,
+              P::Vector: Mul<N, Output = P::Vector> + MulAssign<N> + Div<N, Output = P::Vector> + DivAssign<N> {
+        let triangle = self.triangle_at::<P>(id);
+        let ab       = *triangle.b() - *triangle.a();
+        let ac       = *triangle.c() - *triangle.a();
+        let ap       = *pt - *triangle.a();
+        let d00      = na::dot(&ab, &ab);
+        let d01      = na::dot(&ab, &ac);
+        let d11      = na::dot(&ac, &ac);
+        let d20      = na::dot(&ap, &ab);
+        let d21      = na::dot(&ap, &ac);
+        let denom    = d00 * d11 - d01 * d01;
+        let v        = (d11 * d20 - d01 * d21) / denom;
+        let w        = (d00 * d21 - d01 * d20) / denom;
+
+        self.normal_at::<P>(id, &Vector3::new(N::one() - v - w, v, w))
+    }
+
+    /// The row and column of the grid nodes at the vertices of the triangle with the identifier
+    /// `id`, in the order given by `triangles_at`.
+    pub fn triangle_nodes(&self, id: usize) -> [(usize, usize); 3] {
+        let i = (id / 2) / self.num_cells_x();
+        let j = (id / 2) % self.num_cells_x();
+
+        if id % 2 == 0 {
+            [(i, j), (i + 1, j), (i + 1, j + 1)]
+        }
+        else {
+            [(i, j), (i + 1, j + 1), (i, j + 1)]
+        }
+    }
+
+    /// The feature of this heightfield corresponding to the feature `feature` of the triangle with
+    /// the identifier `id`.
+    ///
+    /// This is `FeatureId::Face(id)` for the interior of the triangle, `FeatureId::Edge(3 * id + k)`
+    /// for its `k`-th edge, and `FeatureId::Vertex(i * ncols + j)` for the grid node at the `i`-th
+    /// row and `j`-th column.
+    pub fn triangle_feature_id(&self, id: usize, feature: FeatureId) -> FeatureId {
+        match feature {
+            FeatureId::Vertex(k) => {
+                let (i, j) = self.triangle_nodes(id)[k];
+                FeatureId::Vertex(i * self.heights.ncols() + j)
+            },
+            FeatureId::Edge(k) => FeatureId::Edge(3 * id + k),
+            FeatureId::Face(_) => FeatureId::Face(id),
+            FeatureId::Unknown => FeatureId::Unknown
+        }
+    }
+
+    /// The triangle of the cells intersecting the given local-space AABB minimizing `f`.
+    ///
+    /// `f` returns the distance of a triangle to the query together with some data, or `None` to
+    /// skip it. The triangle with the smallest identifier is kept in case of tie. Returns the
+    /// identifier, the distance and the data of the best triangle.
+    pub fn closest_triangle_in_local_aabb<P, T, F>(&self, aabb: &AABB<P>, mut f: F) -> Option<(usize, N, T)>
+        where P: Point<Real = N>,
+              P::Vector: Mul<N, Output = P::Vector> + MulAssign<N> + Div<N, Output = P::Vector> + DivAssign<N>,
+              F: FnMut(usize, &Triangle<P>) -> Option<(N, T)> {
+        let mut best = None::<(usize, N, T)>;
+
+        self.map_triangles_in_local_aabb(aabb, &mut |id, triangle| {
+            // Nothing is closer than a triangle at a zero distance.
+            if let Some((_, ref best_dist, _)) = best {
+                if best_dist.is_zero() {
+                    return;
+                }
+            }
+
+            if let Some((dist, data)) = f(id, triangle) {
+                // The identifiers are increasing, so the first triangle is kept in case of tie.
+                let replace = match best {
+                    Some((_, ref best_dist, _)) => dist < *best_dist,
+                    None                        => true
+                };
+
+                if replace {
+                    best = Some((id, dist, data));
+                }
+            }
+        });
+
+        best
+    }
+
+    /// Applies `f` to each triangle of the cells intersecting the given local-space AABB.
+    ///
+    /// The cells are selected from the grid without testing every cell of this heightfield. The
+    /// identifier given to `f` is `2 * (i * self.num_cells_x() + j) + k` for the `k`-th triangle of
+    /// the cell at the `i`-th row and `j`-th column.
+    pub fn map_triangles_in_local_aabb<P>(&self, aabb: &AABB<P>, f: &mut FnMut(usize, &Triangle<P>))
+        where P: Point<Real = N>,
+              P::Vector: Mul<N, Output = P::Vector> + MulAssign<N> + Div<N, Output = P::Vector> + DivAssign<N> {
+        if let Some(((start_i, end_i), (start_j, end_j))) = self.cells_in_local_aabb(aabb) {
+            for i in start_i .. end_i + 1 {
+                for j in start_j .. end_j + 1 {
+                    let id = 2 * (i * self.num_cells_x() + j);
+                    let (t1, t2) = self.triangles_at(i, j);
+
+                    f(id, &t1);
+                    f(id + 1, &t2);
+                }
+            }
+        }
+    }
+
+    /// The triangles of the cells of this heightfield, transformed by `m`, intersecting a
+    /// world-space AABB.
+    ///
+    /// The cells are selected from the AABB of the corners of `aabb` in the local space of this
+    /// heightfield: if `m` has a rotation, some of them may not intersect `aabb` itself. Nothing is
+    /// yielded if `aabb` lies outside of the grid. The triangles are expressed in the local space of
+    /// this heightfield, in the order given by `map_triangles_in_local_aabb`.
+    pub fn triangles_in_aabb<P, M>(&self, m: &M, aabb: &AABB<P>) -> TrianglesInAABB<P>
+        where P: Point<Real = N>,
+              P::Vector: Mul<N, Output = P::Vector> + MulAssign<N> + Div<N, Output = P::Vector> + DivAssign<N>,
+              M: Isometry<P> {
+        let dim       = na::dimension::<P::Vector>();
+        let mut local = None::<AABB<P>>;
+
+        for corner in 0usize .. 1 << dim {
+            let mut pt = *aabb.mins();
+
+            for k in 0 .. dim {
+                if corner & (1 << k) != 0 {
+                    pt[k] = aabb.maxs()[k]
+                }
+            }
+
+            let pt = m.inverse_transform_point(&pt);
+
+            match local {
+                Some(ref mut local) => local.merge_point(&pt),
+                None                => local = Some(AABB::new(pt, pt))
+            }
+        }
+
+        let cells = self.cells_in_local_aabb(&local.unwrap());
+
+        TrianglesInAABB {
+            heightfield: self,
+            cells:       cells.unwrap_or(((1, 0), (0, 0))),
+            curr:        cells.map(|c| ((c.0).0, (c.1).0)).unwrap_or((1, 0)),
+            second:      None
+        }
+    }
+
+    // The ranges of rows and columns of the cells intersecting the given local-space AABB.
+    fn cells_in_local_aabb<P>(&self, aabb: &AABB<P>) -> Option<((usize, usize), (usize, usize))>
+        where P: Point<Real = N>,
+              P::Vector: Mul<N, Output = P::Vector> + MulAssign<N> + Div<N, Output = P::Vector> + DivAssign<N> {
+        let _0_5: N = na::convert(0.5f64);
+
+        if aabb.mins()[1] > self.max_height || aabb.maxs()[1] < self.min_height {
+            return None;
+        }
+
+        // The range of cells covered by the AABB along each axis, if any.
+        let cell_range = |min: N, max: N, extent: N, num_cells: usize| {
+            let num_cells_n: N = na::convert(num_cells as f64);
+            let start = (min / extent + _0_5) * num_cells_n;
+            let end   = (max / extent + _0_5) * num_cells_n;
+
+            if end < na::zero() || start > num_cells_n {
+                None
+            }
+            else {
+                let start = na::try_convert::<N, f64>(start.max(na::zero())).unwrap().floor() as usize;
+                let end   = na::try_convert::<N, f64>(end.min(num_cells_n)).unwrap().floor() as usize;
+
+                Some((start.min(num_cells - 1), end.min(num_cells - 1)))
+            }
+        };
+
+        let range_x = cell_range(aabb.mins()[0], aabb.maxs()[0], self.scale.x, self.num_cells_x());
+        let range_z = cell_range(aabb.mins()[2], aabb.maxs()[2], self.scale.z, self.num_cells_z());
+
+        match (range_z, range_x) {
+            (Some(range_i), Some(range_j)) => Some((range_i, range_j)),
+            _                              => None
+        }
+    }
+}
+
+/// An iterator through the triangles of some cells of a heightfield.
+///
+/// See `HeightField::triangles_in_aabb`.
+pub struct TrianglesInAABB<'a, P: Point> {
+    heightfield: &'a HeightField<P::Real>,
+    // The ranges of rows and columns of the cells to visit.
+    cells:       ((usize, usize), (usize, usize)),
+    // The row and column of the next cell to visit.
+    curr:        (usize, usize),
+    // The second triangle of the last cell visited, if not yielded yet.
+    second:      Option<Triangle<P>>
+}
+
+impl<'a, P: Point> Iterator for TrianglesInAABB<'a, P> {
+    type Item = Triangle<P>;
+
+    fn next(&mut self) -> Option<Triangle<P>> {
+        if let Some(t) = self.second.take() {
+            return Some(t);
+        }
+
+        let ((_, end_i), (start_j, end_j)) = self.cells;
+        let (i, j) = self.curr;
+
+        if i > end_i {
+            return None;
+        }
+
+        let (t1, t2) = self.heightfield.triangles_at(i, j);
+        self.second  = Some(t2);
+        self.curr    = if j == end_j { (i + 1, start_j) } else { (i, j + 1) };
+
+        Some(t1)
+    }
+}
@@ -0,0 +1,39 @@
+use alga::general::{Id, Identity, Multiplicative};
+use alga::linear::Translation;
+use na;
+
+use bounding_volume::{BoundingVolume, AABB};
+use shape::{Shape, Ball, Cuboid};
+use query::{PointQuery, Proximity};
+use query::proximity_internal;
+use math::{Point, Isometry};
+
+/// Tests whether a transformed shape intersects an AABB.
+///
+/// The AABB of the shape is tested first, which is the separating axis test on the axes of the AABB.
+/// The result is exact for balls and support-mapped shapes. Other shapes, e.g., composite shapes,
+/// are only tested through their AABB so the result may be a false positive.
+pub fn intersects_aabb<P, M>(m: &M, shape: &Shape<P, M>, aabb: &AABB<P>) -> bool
+    where P: Point,
+          M: Isometry<P> {
+    if !shape.aabb(m).intersects(aabb) {
+        return false;
+    }
+
+    if let Some(b) = shape.as_shape::<Ball<P::Real>>() {
+        let center = m.translate_point(&P::origin());
+
+        aabb.distance_to_point(&Id::new(), &center, true) <= b.radius()
+    }
+    else if let Some(s) = shape.as_support_map() {
+        let cuboid = Cuboid::new(aabb.half_extents());
+        let maabb  = <M as Identity<Multiplicative>>::identity()
+                         .append_translation(&M::Translation::from_vector(aabb.center().coordinates()).unwrap());
+
+        proximity_internal::support_map_against_support_map(m, s, &maabb, &cuboid, na::zero()) !=
+            Proximity::Disjoint
+    }
+    else {
+        true
+    }
+}
@@ -1,12 +1,15 @@
 //! Collision shapes supported by ncollide.
 
 pub use self::ball::Ball;
+pub use self::ball_array::BallArray;
 pub use self::plane::Plane;
 pub use self::cuboid::Cuboid;
 pub use self::capsule::Capsule;
 pub use self::cone::Cone;
 pub use self::cylinder::Cylinder;
+pub use self::ellipsoid::Ellipsoid;
 pub use self::convex::ConvexHull;
+pub use self::convex_polygon::ConvexPolygon;
 pub use self::minkowski_sum::{MinkowskiSum, AnnotatedMinkowskiSum,
                               CSO, AnnotatedCSO,
                               AnnotatedPoint, cso_support_point};
@@ -15,13 +18,21 @@ pub use self::compound::Compound;
 pub use self::base_mesh::{BaseMesh, BaseMeshElement};
 pub use self::trimesh::TriMesh;
 pub use self::polyline::Polyline;
+pub use self::heightfield::{HeightField, TrianglesInAABB};
 pub use self::segment::Segment;
 pub use self::triangle::Triangle;
 pub use self::torus::Torus;
+pub use self::with_local_pose::WithLocalPose;
+pub use self::with_margin::WithMargin;
+pub use self::round_shape::RoundShape;
+pub use self::scaled::Scaled;
+pub use self::transformed::Transformed;
+pub use self::feature_id::FeatureId;
+pub use self::intersects_aabb::intersects_aabb;
 #[doc(inline)]
 pub use self::composite_shape::CompositeShape;
 #[doc(inline)]
-pub use self::support_map::SupportMap;
+pub use self::support_map::{SupportMap, check_support_map_convexity};
 #[doc(inline)]
 pub use self::shape::{Shape, ShapeHandle};
 
@@ -41,15 +52,28 @@ mod triangle;
 mod base_mesh;
 mod trimesh;
 mod polyline;
+mod heightfield;
 mod ball;
+mod ball_array;
 mod capsule;
 mod cone;
 mod cylinder;
+mod ellipsoid;
 mod reflection;
 mod torus;
+mod with_local_pose;
+mod with_margin;
+mod round_shape;
+mod scaled;
+mod transformed;
+mod feature_id;
 mod compound;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 mod convex;
+mod convex_polygon;
 mod shape_impl;
+mod intersects_aabb;
 
 /*
  *
@@ -57,12 +81,15 @@ mod shape_impl;
  *
  */
 #[doc = "A 2D ball."] pub type Ball2<N> = Ball<N>;
+#[doc = "A 2D set of balls."] pub type BallArray2<N> = BallArray<Point2<N>>;
 #[doc = "A 2D plane."] pub type Plane2<N> = Plane<Vector2<N>>;
 #[doc = "A 2D cuboid."] pub type Cuboid2<N> = Cuboid<Vector2<N>>;
 #[doc = "A 2D capsule."] pub type Capsule2<N> = Capsule<N>;
 #[doc = "A 2D cone."] pub type Cone2<N> = Cone<N>;
 #[doc = "A 2D cylinder."] pub type Cylinder2<N> = Cylinder<N>;
+#[doc = "A 2D ellipse."] pub type Ellipsoid2<N> = Ellipsoid<Vector2<N>>;
 #[doc = "A 2D convex polytope."] pub type ConvexHull2<N> = ConvexHull<Point2<N>>;
+#[doc = "A 2D convex polygon."] pub type ConvexPolygon2<N> = ConvexPolygon<Point2<N>>;
 #[doc = "A 2D segment."] pub type Segment2<N> = Segment<Point2<N>>;
 #[doc = "A 2D triangle."] pub type Triangle2<N> = Triangle<Point2<N>>;
 #[doc = "A 2D polyline."] pub type Polyline2<N> = Polyline<Point2<N>>;
@@ -73,11 +100,13 @@ mod shape_impl;
 #[doc = "A 2D shared dynamic shape handle."] pub type ShapeHandle2<N> = ShapeHandle<Point2<N>, Isometry2<N>>;
 
 #[doc = "A 3D ball."] pub type Ball3<N> = Ball<N>;
+#[doc = "A 3D set of balls."] pub type BallArray3<N> = BallArray<Point3<N>>;
 #[doc = "A 3D plane."] pub type Plane3<N> = Plane<Vector3<N>>;
 #[doc = "A 3D cuboid."] pub type Cuboid3<N> = Cuboid<Vector3<N>>;
 #[doc = "A 3D capsule."] pub type Capsule3<N> = Capsule<N>;
 #[doc = "A 3D cone."] pub type Cone3<N> = Cone<N>;
 #[doc = "A 3D cylinder."] pub type Cylinder3<N> = Cylinder<N>;
+#[doc = "A 3D ellipsoid."] pub type Ellipsoid3<N> = Ellipsoid<Vector3<N>>;
 #[doc = "A 3D convex polytope."] pub type ConvexHull3<N> = ConvexHull<Point3<N>>;
 #[doc = "A 3D segment."] pub type Segment3<N> = Segment<Point3<N>>;
 #[doc = "A 3D triangle."] pub type Triangle3<N> = Triangle<Point3<N>>;
@@ -1,6 +1,7 @@
 //! Support mapping based Plane shape.
 use math::Vector;
 use na;
+use utils;
 
 /// SupportMap description of a plane.
 #[derive(PartialEq, Debug, Clone, RustcEncodable, RustcDecodable)]
@@ -13,6 +14,8 @@ impl<V: Vector> Plane<V> {
     /// Builds a new plane from its center and its normal.
     #[inline]
     pub fn new(normal: V) -> Plane<V> {
+        debug_assert!(utils::is_finite_vector(&normal), "The plane normal must be finite.");
+
         unsafe { Plane::new_normalized(na::normalize(&normal)) }
     }
 }
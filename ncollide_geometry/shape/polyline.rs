@@ -3,6 +3,12 @@
 use std::mem;
 use std::sync::Arc;
 
+use rustc_serialize::{Encodable, Encoder, Decodable, Decoder};
+#[cfg(feature = "serde-serialize")]
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+#[cfg(feature = "serde-serialize")]
+use serde::de::Error;
+use alga::general::Real;
 use na::{self, Point2};
 use partitioning::BVT;
 use bounding_volume::AABB;
@@ -10,6 +16,8 @@ use shape::{Segment, BaseMesh, CompositeShape, Shape};
 use math::{Point, Isometry};
 
 /// Shape commonly known as a 2d line strip or a 3d segment mesh.
+///
+/// The mesh is encoded with its BVT, see `BaseMesh`.
 pub struct Polyline<P: Point> {
     mesh: BaseMesh<P, Point2<usize>, Segment<P>>
 }
@@ -22,6 +30,69 @@ impl<P: Point> Clone for Polyline<P> {
     }
 }
 
+impl<P: Point> Encodable for Polyline<P>
+    where BaseMesh<P, Point2<usize>, Segment<P>>: Encodable {
+    fn encode<S: Encoder>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.emit_struct("Polyline", 1, |s| {
+            s.emit_struct_field("mesh", 0, |s| self.mesh.encode(s))
+        })
+    }
+}
+
+impl<P: Point> Decodable for Polyline<P>
+    where BaseMesh<P, Point2<usize>, Segment<P>>: Decodable {
+    fn decode<D: Decoder>(d: &mut D) -> Result<Polyline<P>, D::Error> {
+        let mesh: BaseMesh<P, Point2<usize>, Segment<P>> = try!(d.read_struct("Polyline", 1, |d| {
+            d.read_struct_field("mesh", 0, Decodable::decode)
+        }));
+
+        match Polyline::from_decoded_mesh(mesh) {
+            Ok(res)  => Ok(res),
+            Err(msg) => Err(d.error(msg))
+        }
+    }
+}
+
+#[cfg(feature = "serde-serialize")]
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Polyline")]
+struct SerializedPolyline<M> {
+    mesh: M
+}
+
+#[cfg(feature = "serde-serialize")]
+impl<P: Point> Serialize for Polyline<P>
+    where BaseMesh<P, Point2<usize>, Segment<P>>: Serialize {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        SerializedPolyline { mesh: &self.mesh }.serialize(s)
+    }
+}
+
+#[cfg(feature = "serde-serialize")]
+impl<'de, P: Point> Deserialize<'de> for Polyline<P>
+    where BaseMesh<P, Point2<usize>, Segment<P>>: Deserialize<'de> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Polyline<P>, D::Error> {
+        let serialized: SerializedPolyline<BaseMesh<P, Point2<usize>, Segment<P>>> = try!(Deserialize::deserialize(d));
+
+        Polyline::from_decoded_mesh(serialized.mesh).map_err(D::Error::custom)
+    }
+}
+
+impl<P: Point> Polyline<P> {
+    // Checks the vertex indices of a decoded mesh.
+    fn from_decoded_mesh(mesh: BaseMesh<P, Point2<usize>, Segment<P>>) -> Result<Polyline<P>, &'static str> {
+        let nvertices = mesh.vertices().len();
+
+        if mesh.indices().iter().any(|idx| (0 .. 2).any(|k| idx[k] >= nvertices)) {
+            return Err("Invalid mesh: a vertex index is out of bounds.");
+        }
+
+        Ok(Polyline {
+            mesh: mesh
+        })
+    }
+}
+
 impl<P: Point> Polyline<P> {
     /// Builds a new mesh.
     pub fn new(vertices: Arc<Vec<P>>,
@@ -85,9 +156,90 @@ impl<P: Point> Polyline<P> {
     pub fn segment_at(&self, i: usize) -> Segment<P> {
         self.mesh.element_at(i)
     }
+
+    /// The winding number of this 2D polyline around a point given in its local space.
+    ///
+    /// This is the sum of the angles of the segments seen from `pt`, divided by `2 * pi`. It is 1
+    /// inside of a counterclockwise closed loop, -1 inside of a clockwise one, and 0 outside.
+    pub fn winding_number(&self, pt: &P) -> P::Real {
+        assert!(na::dimension::<P::Vector>() == 2, "The winding number of a polyline is only defined in 2D.");
+
+        let vertices = &self.vertices()[..];
+        let mut res  = na::zero::<P::Real>();
+
+        for idx in self.indices().iter() {
+            let a = vertices[idx.x] - *pt;
+            let b = vertices[idx.y] - *pt;
+
+            res = res + (a[0] * b[1] - a[1] * b[0]).atan2(na::dot(&a, &b));
+        }
+
+        res / P::Real::two_pi()
+    }
+}
+
+impl<N: Real> Polyline<Point2<N>> {
+    /// The signed area enclosed by this 2D polyline.
+    ///
+    /// The segments of this polyline are assumed to form a closed loop. The area is positive if
+    /// the loop is counterclockwise, and negative if it is clockwise.
+    pub fn signed_area(&self) -> N {
+        let vertices = &self.vertices()[..];
+        let mut area = na::zero::<N>();
+
+        // Shoelace formula.
+        for idx in self.indices().iter() {
+            let a = vertices[idx.x];
+            let b = vertices[idx.y];
+
+            area += a.x * b.y - b.x * a.y;
+        }
+
+        area * na::convert(0.5f64)
+    }
+
+    /// Tests whether this 2D polyline is the boundary of a convex polygon.
+    ///
+    /// The segments of this polyline are assumed to form a closed loop, each segment starting
+    /// where the previous one ends. The polygon is convex if the loop turns in the same direction
+    /// at every vertex, and turns only once around its interior. Aligned consecutive segments are
+    /// allowed. The winding of a convex polyline is given by the sign of its `signed_area`.
+    pub fn is_convex(&self) -> bool {
+        let vertices = &self.vertices()[..];
+        let indices  = &self.indices()[..];
+        let mut sign = na::zero::<N>();
+        let mut turn = na::zero::<N>();
+
+        for i in 0 .. indices.len() {
+            let idx1 = indices[i];
+            let idx2 = indices[(i + 1) % indices.len()];
+            let e1   = vertices[idx1.y] - vertices[idx1.x];
+            let e2   = vertices[idx2.y] - vertices[idx2.x];
+
+            let cross = e1.x * e2.y - e1.y * e2.x;
+
+            if cross * sign < na::zero() {
+                return false;
+            }
+
+            if !cross.is_zero() {
+                sign = cross;
+            }
+
+            turn += cross.atan2(na::dot(&e1, &e2));
+        }
+
+        // A star polygon turns in the same direction at every vertex, but several times.
+        (turn.abs() - N::two_pi()).abs() <= N::default_epsilon().sqrt()
+    }
 }
 
 impl<P: Point, M: Isometry<P>> CompositeShape<P, M> for Polyline<P> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.base_mesh().len()
+    }
+
     #[inline(always)]
     fn map_part_at(&self, i: usize, f: &mut FnMut(&M, &Shape<P, M>)) {
         let one: M = na::one();
@@ -102,6 +254,13 @@ impl<P: Point, M: Isometry<P>> CompositeShape<P, M> for Polyline<P> {
         f(m, &element)
     }
 
+    #[inline]
+    fn parts_share_vertex(&self, i: usize, j: usize) -> bool {
+        let (a, b) = (self.indices()[i], self.indices()[j]);
+
+        a.x == b.x || a.x == b.y || a.y == b.x || a.y == b.y
+    }
+
     #[inline]
     fn aabb_at(&self, i: usize) -> AABB<P> {
         self.bounding_volumes()[i].clone()
@@ -0,0 +1,72 @@
+//! A convex shape with rounded edges and vertices.
+
+use num::Zero;
+use alga::general::Real;
+use approx::ApproxEq;
+use na::{self, Unit};
+
+use shape::{SupportMap, FeatureId};
+use math::{Point, Isometry};
+
+/// A support-mapped shape with a rounded border.
+///
+/// This is the Minkowski sum of the inner shape with a ball of radius `border_radius`: its support
+/// point in a direction is the support point of the inner shape moved by `border_radius` along
+/// this direction. Thus, a `Cuboid` becomes a box with rounded edges and vertices, and a `Segment`
+/// becomes a capsule. `WithMargin` is a round shape wrapping a dynamic `ShapeHandle`.
+#[derive(PartialEq, Debug, Clone, RustcEncodable, RustcDecodable)]
+pub struct RoundShape<S, N> {
+    inner:         S,
+    border_radius: N
+}
+
+impl<S, N: Real> RoundShape<S, N> {
+    /// Creates the shape `inner` with a rounded border of radius `border_radius`.
+    ///
+    /// The border radius must be positive or null.
+    #[inline]
+    pub fn new(inner: S, border_radius: N) -> RoundShape<S, N> {
+        assert!(border_radius >= na::zero(), "The border radius must be positive or null.");
+
+        RoundShape {
+            inner:         inner,
+            border_radius: border_radius
+        }
+    }
+
+    /// The shape without its rounded border.
+    #[inline]
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// The radius of the rounded border.
+    #[inline]
+    pub fn border_radius(&self) -> N {
+        self.border_radius
+    }
+}
+
+impl<P, M, S> SupportMap<P, M> for RoundShape<S, P::Real>
+    where P: Point,
+          M: Isometry<P>,
+          S: SupportMap<P, M> {
+    #[inline]
+    fn support_point(&self, m: &M, dir: &P::Vector) -> P {
+        let support = self.inner.support_point(m, dir);
+
+        if self.border_radius.is_zero() {
+            return support;
+        }
+
+        match Unit::try_new(*dir, P::Real::default_epsilon()) {
+            Some(n) => support + *n * self.border_radius,
+            None    => support
+        }
+    }
+
+    #[inline]
+    fn support_feature_id(&self, m: &M, pts: &[P]) -> FeatureId {
+        self.inner.support_feature_id(m, pts)
+    }
+}
@@ -0,0 +1,116 @@
+//! A support-mapped shape scaled along its local axes.
+
+use na;
+
+use shape::{SupportMap, FeatureId};
+use utils;
+use math::{Point, Vector, Isometry};
+
+/// A support-mapped shape scaled along each axis of its local frame.
+///
+/// The support point of the scaled shape is computed from the support point of the inner shape,
+/// so no scaled copy of the inner shape is needed for the GJK and EPA based queries. The scale
+/// may be non-uniform: a scaled `Ball` is then an ellipsoid. The mass properties of the scaled
+/// shape are not provided by this wrapper and must be computed from the ones of the inner shape,
+/// e.g., the volume is multiplied by the product of the scale factors.
+#[derive(PartialEq, Debug, Clone, RustcEncodable, RustcDecodable)]
+pub struct Scaled<S, V> {
+    inner: S,
+    scale: V
+}
+
+impl<S, V> Scaled<S, V> {
+    /// The shape before scaling.
+    #[inline]
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// The scale factors along each local axis.
+    #[inline]
+    pub fn scale(&self) -> &V {
+        &self.scale
+    }
+}
+
+impl<S, V: Vector> Scaled<S, V> {
+    /// Creates the shape `inner` scaled by `scale[i]` along its `i`-th local axis.
+    ///
+    /// Every scale factor must be strictly positive.
+    #[inline]
+    pub fn new(inner: S, scale: V) -> Scaled<S, V> {
+        for i in 0 .. na::dimension::<V>() {
+            assert!(scale[i] > na::zero(), "The scale factors must be strictly positive.");
+        }
+
+        Scaled {
+            inner: inner,
+            scale: scale
+        }
+    }
+
+    /// Creates the shape `inner` scaled by `scale` along every local axis.
+    #[inline]
+    pub fn new_uniform(inner: S, scale: V::Real) -> Scaled<S, V> {
+        Scaled::new(inner, utils::repeat(scale))
+    }
+
+    /// The largest scale factor.
+    #[inline]
+    pub fn max_scale(&self) -> V::Real {
+        let mut res = self.scale[0];
+
+        for i in 1 .. na::dimension::<V>() {
+            if self.scale[i] > res {
+                res = self.scale[i];
+            }
+        }
+
+        res
+    }
+
+    /// Scales the vector `v`, i.e., multiplies each of its components by the corresponding scale
+    /// factor.
+    #[inline]
+    pub fn scale_vector(&self, v: &V) -> V {
+        let mut res = *v;
+
+        for i in 0 .. na::dimension::<V>() {
+            res[i] = res[i] * self.scale[i];
+        }
+
+        res
+    }
+}
+
+impl<P, M, S> SupportMap<P, M> for Scaled<S, P::Vector>
+    where P: Point,
+          M: Isometry<P>,
+          S: SupportMap<P, M> {
+    #[inline]
+    fn support_point(&self, m: &M, dir: &P::Vector) -> P {
+        // The scaling matrix is diagonal, thus symmetric: the inner shape is queried in the
+        // direction scaled too.
+        let one: M    = na::one();
+        let local_dir = self.scale_vector(&m.inverse_rotate_vector(dir));
+        let support   = self.inner.support_point(&one, &local_dir);
+
+        m.transform_point(&P::from_coordinates(self.scale_vector(&support.coordinates())))
+    }
+
+    #[inline]
+    fn support_feature_id(&self, m: &M, pts: &[P]) -> FeatureId {
+        let one: M = na::one();
+        let inner_pts: Vec<P> = pts.iter().map(|pt| {
+            let mut local = m.inverse_transform_point(pt).coordinates();
+
+            for i in 0 .. na::dimension::<P::Vector>() {
+                local[i] = local[i] / self.scale[i];
+            }
+
+            P::from_coordinates(local)
+        }).collect();
+
+        self.inner.support_feature_id(&one, &inner_pts[..])
+    }
+}
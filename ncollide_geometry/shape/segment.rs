@@ -1,7 +1,7 @@
 //! Definition of the segment shape.
 
 use na::{self, Point2};
-use shape::{SupportMap, BaseMeshElement};
+use shape::{SupportMap, BaseMeshElement, FeatureId};
 use math::{Point, Isometry};
 
 
@@ -58,4 +58,29 @@ impl<P: Point, M: Isometry<P>> SupportMap<P, M> for Segment<P> {
             m.transform_point(self.b())
         }
     }
+
+    /// Identifies the feature of this segment containing all the given points.
+    ///
+    /// The vertices `a` and `b` have the identifiers 0 and 1. The segment itself is the edge 0.
+    fn support_feature_id(&self, m: &M, pts: &[P]) -> FeatureId {
+        let mut mask = 0usize;
+
+        for pt in pts.iter() {
+            let local_pt = m.inverse_transform_point(pt);
+
+            if na::distance_squared(&local_pt, self.a()) <= na::distance_squared(&local_pt, self.b()) {
+                mask |= 1;
+            }
+            else {
+                mask |= 2;
+            }
+        }
+
+        match mask {
+            1 => FeatureId::Vertex(0),
+            2 => FeatureId::Vertex(1),
+            3 => FeatureId::Edge(0),
+            _ => FeatureId::Unknown
+        }
+    }
 }
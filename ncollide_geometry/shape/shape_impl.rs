@@ -1,7 +1,7 @@
-use bounding_volume::{self, AABB, BoundingSphere};
+use bounding_volume::{self, AABB, BoundingSphere, HasBoundingVolume};
 use query::{PointQuery, RayCast};
-use shape::{Shape, Triangle, Segment, Ball, Plane, Cuboid, Cylinder, Cone, ConvexHull, Compound,
-            TriMesh, Polyline, CompositeShape, SupportMap};
+use shape::{Shape, Triangle, Segment, Ball, Plane, Cuboid, Capsule, Cylinder, Cone, ConvexHull, ConvexPolygon, Compound, Ellipsoid,
+            TriMesh, Polyline, HeightField, WithLocalPose, RoundShape, Scaled, CompositeShape, SupportMap};
 use math::{Point, Isometry};
 
 macro_rules! impl_as_support_map(
@@ -81,16 +81,31 @@ impl<P: Point, M: Isometry<P>> Shape<P, M> for Cylinder<P::Real> {
     impl_as_support_map!();
 }
 
+impl<P: Point, M: Isometry<P>> Shape<P, M> for Capsule<P::Real> {
+    impl_shape_common!();
+    impl_as_support_map!();
+}
+
 impl<P: Point, M: Isometry<P>> Shape<P, M> for Cone<P::Real> {
     impl_shape_common!();
     impl_as_support_map!();
 }
 
+impl<P: Point, M: Isometry<P>> Shape<P, M> for Ellipsoid<P::Vector> {
+    impl_shape_common!();
+    impl_as_support_map!();
+}
+
 impl<P: Point, M: Isometry<P>> Shape<P, M> for ConvexHull<P> {
     impl_shape_common!();
     impl_as_support_map!();
 }
 
+impl<P: Point, M: Isometry<P>> Shape<P, M> for ConvexPolygon<P> {
+    impl_shape_common!();
+    impl_as_support_map!();
+}
+
 impl<P: Point, M: 'static + Send + Sync + Isometry<P>> Shape<P, M> for Compound<P, M> {
     impl_shape_common!();
     impl_as_composite_shape!();
@@ -106,6 +121,89 @@ impl<P: Point, M: Isometry<P>> Shape<P, M> for Polyline<P> {
     impl_as_composite_shape!();
 }
 
+// Only support-mapped shapes with a local pose can be used as dynamic shapes, since the other
+// shapes need specific collision detection algorithms.
+impl<P, M, S> Shape<P, M> for WithLocalPose<S, M>
+    where P: Point,
+          M: 'static + Send + Sync + Isometry<P>,
+          S: 'static + Send + Sync + SupportMap<P, M> + RayCast<P, M> + PointQuery<P, M> +
+             HasBoundingVolume<M, AABB<P>> + HasBoundingVolume<M, BoundingSphere<P>> {
+    impl_shape_common!();
+    impl_as_support_map!();
+}
+
+impl<P, M, S> Shape<P, M> for RoundShape<S, P::Real>
+    where P: Point,
+          M: 'static + Send + Sync + Isometry<P>,
+          S: 'static + Send + Sync + SupportMap<P, M> + PointQuery<P, M> +
+             HasBoundingVolume<M, AABB<P>> + HasBoundingVolume<M, BoundingSphere<P>> {
+    #[inline]
+    fn aabb(&self, m: &M) -> AABB<P> {
+        bounding_volume::aabb(self, m)
+    }
+
+    #[inline]
+    fn bounding_sphere(&self, m: &M) -> BoundingSphere<P> {
+        bounding_volume::bounding_sphere(self, m)
+    }
+
+    #[inline]
+    fn as_ray_cast(&self) -> Option<&RayCast<P, M>> {
+        Some(self)
+    }
+
+    #[inline]
+    fn as_point_query(&self) -> Option<&PointQuery<P, M>> {
+        Some(self)
+    }
+
+    impl_as_support_map!();
+}
+
+impl<P, M, S> Shape<P, M> for Scaled<S, P::Vector>
+    where P: Point,
+          M: 'static + Send + Sync + Isometry<P>,
+          S: 'static + Send + Sync + SupportMap<P, M> + HasBoundingVolume<M, BoundingSphere<P>> {
+    #[inline]
+    fn aabb(&self, m: &M) -> AABB<P> {
+        bounding_volume::aabb(self, m)
+    }
+
+    #[inline]
+    fn bounding_sphere(&self, m: &M) -> BoundingSphere<P> {
+        bounding_volume::bounding_sphere(self, m)
+    }
+
+    #[inline]
+    fn as_ray_cast(&self) -> Option<&RayCast<P, M>> {
+        Some(self)
+    }
+
+    impl_as_support_map!();
+}
+
 impl<P: Point, M: Isometry<P>> Shape<P, M> for Plane<P::Vector> {
     impl_shape_common!();
 }
+
+impl<P: Point, M: Isometry<P>> Shape<P, M> for HeightField<P::Real> {
+    #[inline]
+    fn aabb(&self, m: &M) -> AABB<P> {
+        bounding_volume::aabb(self, m)
+    }
+
+    #[inline]
+    fn bounding_sphere(&self, m: &M) -> BoundingSphere<P> {
+        bounding_volume::bounding_sphere(self, m)
+    }
+
+    #[inline]
+    fn as_ray_cast(&self) -> Option<&RayCast<P, M>> {
+        Some(self)
+    }
+
+    #[inline]
+    fn as_point_query(&self) -> Option<&PointQuery<P, M>> {
+        Some(self)
+    }
+}
@@ -1,6 +1,10 @@
 //! Traits for support mapping based shapes.
 
-use math::Point;
+use approx::ApproxEq;
+use alga::general::Real;
+use na;
+use math::{Point, Vector, Isometry};
+use shape::FeatureId;
 
 /// Traits of convex shapes representable by a support mapping function.
 ///
@@ -20,5 +24,52 @@ pub trait SupportMap<P: Point, M> {
      *            be normalized.
      */
     fn support_point(&self, transform: &M, dir: &P::Vector) -> P;
+
+    /// Identifies the smallest feature of this shape containing all the given points.
+    ///
+    /// The points are expressed in world-space, i.e., they are support points of `self`
+    /// transformed by `transform`. Curved shapes return the synthetic "surface" feature
+    /// `FeatureId::Face(0)`. Returns `FeatureId::Unknown` if the feature cannot be identified.
+    #[inline]
+    fn support_feature_id(&self, _: &M, _: &[P]) -> FeatureId {
+        FeatureId::Unknown
+    }
 }
 
+
+/// Checks that the support points of a shape in a few sampled directions are those of a convex
+/// shape.
+///
+/// For each pair of directions `d1` and `d2` given by `Vector::sample_sphere`, the support point
+/// in the direction `d1` must be at least as far along `d1` as the support point in the direction
+/// `d2`, up to a tolerance relative to the size of the shape. Otherwise, this returns `d1`. A
+/// support map violating this does not describe a convex shape, and the algorithms relying on it,
+/// e.g., GJK, give meaningless results.
+pub fn check_support_map_convexity<P, M, G: ?Sized>(m: &M, g: &G) -> Result<(), P::Vector>
+    where P: Point,
+          M: Isometry<P>,
+          G: SupportMap<P, M> {
+    let mut samples = Vec::new();
+
+    P::Vector::sample_sphere(|dir: P::Vector| samples.push((dir, g.support_point(m, &dir))));
+
+    let mut size = na::zero::<P::Real>();
+
+    for &(_, pt) in samples.iter() {
+        size = size.max(na::distance(&pt, &samples[0].1))
+    }
+
+    let tol = P::Real::default_epsilon().sqrt() * size;
+
+    for &(dir, pt) in samples.iter() {
+        let max = na::dot(&pt.coordinates(), &dir);
+
+        for &(_, other) in samples.iter() {
+            if na::dot(&other.coordinates(), &dir) > max + tol {
+                return Err(dir);
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+//! A shape with a fixed world-space pose and precomputed world-space data.
+
+use num::Zero;
+use alga::general::Id;
+use na;
+
+use shape::{SupportMap, Ball, Cuboid};
+use query::Ray;
+use math::{Point, Isometry};
+
+/// A shape at a fixed pose, with the image of its local frame by this pose precomputed.
+///
+/// This is a shape already expressed in world-space: its queries must be performed with the
+/// identity transformation. Compared to querying the shape with its pose every time, this avoids
+/// the rotation of the query directions and rays to the local frame of the shape, e.g., the
+/// support point of a cuboid is computed from its world-space axes directly. The axes are stored
+/// inline, so only the dimensions up to 3 are supported.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Transformed<P: Point, M, S> {
+    shape:  S,
+    m:      M,
+    center: P,
+    axes:   [P::Vector; 3]
+}
+
+impl<P: Point, M: Isometry<P>, S> Transformed<P, M, S> {
+    /// Creates a shape transformed by `m`.
+    ///
+    /// Fails if the dimension is greater than 3.
+    pub fn new(shape: S, m: M) -> Transformed<P, M, S> {
+        let dim = na::dimension::<P::Vector>();
+
+        assert!(dim <= 3, "Transformed shapes are only supported up to the dimension 3.");
+
+        let center   = m.transform_point(&P::origin());
+        let mut axes = [ P::Vector::zero(); 3 ];
+
+        for i in 0 .. dim {
+            let mut axis = P::Vector::zero();
+            axis[i] = na::one();
+
+            axes[i] = m.rotate_vector(&axis);
+        }
+
+        Transformed {
+            shape:  shape,
+            m:      m,
+            center: center,
+            axes:   axes
+        }
+    }
+
+    /// The transformed shape.
+    #[inline]
+    pub fn shape(&self) -> &S {
+        &self.shape
+    }
+
+    /// The transformation of the shape.
+    #[inline]
+    pub fn transform(&self) -> &M {
+        &self.m
+    }
+
+    /// The world-space image of the origin of the local frame of the shape.
+    #[inline]
+    pub fn center(&self) -> &P {
+        &self.center
+    }
+
+    /// The world-space images of the axes of the local frame of the shape.
+    #[inline]
+    pub fn axes(&self) -> &[P::Vector] {
+        &self.axes[.. na::dimension::<P::Vector>()]
+    }
+
+    /// Expresses a world-space ray in the local frame of the shape.
+    #[inline]
+    pub fn local_ray(&self, ray: &Ray<P>) -> Ray<P> {
+        let dpt        = ray.origin - self.center;
+        let mut origin = P::origin();
+        let mut dir    = P::Vector::zero();
+
+        for (i, axis) in self.axes().iter().enumerate() {
+            origin[i] = na::dot(&dpt, axis);
+            dir[i]    = na::dot(&ray.dir, axis);
+        }
+
+        Ray::new(origin, dir)
+    }
+
+    /// Expresses a vector given in the local frame of the shape in world-space.
+    #[inline]
+    pub fn world_vector(&self, v: &P::Vector) -> P::Vector {
+        let mut res = P::Vector::zero();
+
+        for (i, axis) in self.axes().iter().enumerate() {
+            res = res + *axis * v[i];
+        }
+
+        res
+    }
+}
+
+impl<P: Point, M: Isometry<P>> SupportMap<P, Id> for Transformed<P, M, Cuboid<P::Vector>> {
+    #[inline]
+    fn support_point(&self, _: &Id, dir: &P::Vector) -> P {
+        let half_extents = self.shape.half_extents();
+        let mut res      = self.center;
+
+        for (i, axis) in self.axes().iter().enumerate() {
+            if na::dot(dir, axis) < P::Real::zero() {
+                res = res + *axis * -half_extents[i];
+            }
+            else {
+                res = res + *axis * half_extents[i];
+            }
+        }
+
+        res
+    }
+}
+
+impl<P: Point, M: Isometry<P>> SupportMap<P, Id> for Transformed<P, M, Ball<P::Real>> {
+    #[inline]
+    fn support_point(&self, _: &Id, dir: &P::Vector) -> P {
+        self.center + na::normalize(dir) * self.shape.radius()
+    }
+}
@@ -1,7 +1,8 @@
 //! Definition of the triangle shape.
 
-use na::{self, Point3};
-use shape::{BaseMeshElement, SupportMap};
+use alga::general::Real;
+use na::{self, Point3, Vector3, Unit};
+use shape::{BaseMeshElement, SupportMap, FeatureId};
 use math::{Point, Isometry};
 
 
@@ -47,6 +48,28 @@ impl<P> Triangle<P> {
     }
 }
 
+impl<N: Real> Triangle<Point3<N>> {
+    /// The normal of this triangle, i.e., the cross product of `b - a` by `c - a`.
+    ///
+    /// Its norm is twice the area of this triangle and it points toward the side this triangle is
+    /// seen counterclockwise from.
+    #[inline]
+    pub fn scaled_normal(&self) -> Vector3<N> {
+        let ab = self.b - self.a;
+        let ac = self.c - self.a;
+
+        ab.cross(&ac)
+    }
+
+    /// The unit normal of this triangle, following the counterclockwise winding of its vertices.
+    ///
+    /// Returns `None` if this triangle is degenerate, i.e., if its vertices are collinear.
+    #[inline]
+    pub fn normal(&self) -> Option<Unit<Vector3<N>>> {
+        Unit::try_new(self.scaled_normal(), N::default_epsilon())
+    }
+}
+
 impl<P: Point> BaseMeshElement<Point3<usize>, P> for Triangle<P> {
     #[inline]
     fn new_with_vertices_and_indices(vs: &[P], is: &Point3<usize>) -> Triangle<P> {
@@ -83,4 +106,40 @@ impl<P: Point, M: Isometry<P>> SupportMap<P, M> for Triangle<P> {
 
         m.transform_point(res)
     }
+
+    /// Identifies the feature of this triangle containing all the given points.
+    ///
+    /// The vertices `a`, `b`, and `c` have the identifiers 0, 1, and 2. The edges `ab`, `bc`, and
+    /// `ca` have the identifiers 0, 1, and 2. The triangle itself is the face 0.
+    fn support_feature_id(&self, m: &M, pts: &[P]) -> FeatureId {
+        let mut mask = 0usize;
+
+        for pt in pts.iter() {
+            let local_pt = m.inverse_transform_point(pt);
+            let da = na::distance_squared(&local_pt, self.a());
+            let db = na::distance_squared(&local_pt, self.b());
+            let dc = na::distance_squared(&local_pt, self.c());
+
+            if da <= db && da <= dc {
+                mask |= 1;
+            }
+            else if db <= dc {
+                mask |= 2;
+            }
+            else {
+                mask |= 4;
+            }
+        }
+
+        match mask {
+            1 => FeatureId::Vertex(0),
+            2 => FeatureId::Vertex(1),
+            4 => FeatureId::Vertex(2),
+            3 => FeatureId::Edge(0),
+            6 => FeatureId::Edge(1),
+            5 => FeatureId::Edge(2),
+            7 => FeatureId::Face(0),
+            _ => FeatureId::Unknown
+        }
+    }
 }
@@ -1,26 +1,105 @@
 //! 2d line strip, 3d triangle mesh, and nd subsimplex mesh.
 
 use std::sync::Arc;
+use std::collections::HashMap;
 
+use rustc_serialize::{Encodable, Encoder, Decodable, Decoder};
+#[cfg(feature = "serde-serialize")]
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+#[cfg(feature = "serde-serialize")]
+use serde::de::Error;
+use alga::general::Real;
 use na::{self, Point2, Point3};
 use partitioning::BVT;
 use bounding_volume::AABB;
 use shape::{Triangle, BaseMesh, CompositeShape, Shape};
+use utils;
 use math::{Point, Isometry};
 
 /// Shape commonly known as a 2d line strip or a 3d triangle mesh.
+///
+/// The mesh is encoded with its BVT, see `BaseMesh`. The adjacency of its triangles is computed
+/// again when it is decoded.
 pub struct TriMesh<P: Point> {
-    mesh: BaseMesh<P, Point3<usize>, Triangle<P>>
+    mesh:      BaseMesh<P, Point3<usize>, Triangle<P>>,
+    adjacency: Arc<Vec<[Option<usize>; 3]>>
 }
 
 impl<P: Point> Clone for TriMesh<P> {
     fn clone(&self) -> TriMesh<P> {
         TriMesh {
-            mesh: self.mesh.clone()
+            mesh:      self.mesh.clone(),
+            adjacency: self.adjacency.clone()
         }
     }
 }
 
+impl<P: Point> Encodable for TriMesh<P>
+    where BaseMesh<P, Point3<usize>, Triangle<P>>: Encodable {
+    fn encode<S: Encoder>(&self, s: &mut S) -> Result<(), S::Error> {
+        s.emit_struct("TriMesh", 1, |s| {
+            s.emit_struct_field("mesh", 0, |s| self.mesh.encode(s))
+        })
+    }
+}
+
+impl<P: Point> Decodable for TriMesh<P>
+    where BaseMesh<P, Point3<usize>, Triangle<P>>: Decodable {
+    fn decode<D: Decoder>(d: &mut D) -> Result<TriMesh<P>, D::Error> {
+        let mesh: BaseMesh<P, Point3<usize>, Triangle<P>> = try!(d.read_struct("TriMesh", 1, |d| {
+            d.read_struct_field("mesh", 0, Decodable::decode)
+        }));
+
+        match TriMesh::from_decoded_mesh(mesh) {
+            Ok(res)  => Ok(res),
+            Err(msg) => Err(d.error(msg))
+        }
+    }
+}
+
+#[cfg(feature = "serde-serialize")]
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "TriMesh")]
+struct SerializedTriMesh<M> {
+    mesh: M
+}
+
+#[cfg(feature = "serde-serialize")]
+impl<P: Point> Serialize for TriMesh<P>
+    where BaseMesh<P, Point3<usize>, Triangle<P>>: Serialize {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        SerializedTriMesh { mesh: &self.mesh }.serialize(s)
+    }
+}
+
+#[cfg(feature = "serde-serialize")]
+impl<'de, P: Point> Deserialize<'de> for TriMesh<P>
+    where BaseMesh<P, Point3<usize>, Triangle<P>>: Deserialize<'de> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<TriMesh<P>, D::Error> {
+        let serialized: SerializedTriMesh<BaseMesh<P, Point3<usize>, Triangle<P>>> = try!(Deserialize::deserialize(d));
+
+        TriMesh::from_decoded_mesh(serialized.mesh).map_err(D::Error::custom)
+    }
+}
+
+impl<P: Point> TriMesh<P> {
+    // Checks the vertex indices of a decoded mesh.
+    fn from_decoded_mesh(mesh: BaseMesh<P, Point3<usize>, Triangle<P>>) -> Result<TriMesh<P>, &'static str> {
+        let nvertices = mesh.vertices().len();
+
+        if mesh.indices().iter().any(|idx| (0 .. 3).any(|k| idx[k] >= nvertices)) {
+            return Err("Invalid mesh: a vertex index is out of bounds.");
+        }
+
+        let adjacency = edge_adjacency(&mesh.indices()[..]);
+
+        Ok(TriMesh {
+            mesh:      mesh,
+            adjacency: Arc::new(adjacency)
+        })
+    }
+}
+
 impl<P: Point> TriMesh<P> {
     /// Builds a new mesh.
     pub fn new(vertices: Arc<Vec<P>>,
@@ -28,8 +107,11 @@ impl<P: Point> TriMesh<P> {
                uvs:      Option<Arc<Vec<Point2<P::Real>>>>,
                normals:  Option<Arc<Vec<P::Vector>>>) // a loosening margin for the BVT.
                -> TriMesh<P> {
+        let adjacency = edge_adjacency(&indices[..]);
+
         TriMesh {
-            mesh: BaseMesh::new(vertices, indices, uvs, normals)
+            mesh:      BaseMesh::new(vertices, indices, uvs, normals),
+            adjacency: Arc::new(adjacency)
         }
     }
 
@@ -76,15 +158,105 @@ impl<P: Point> TriMesh<P> {
     }
 }
 
+impl<P: Point> TriMesh<P> {
+    /// Mutable access to the vertices of this mesh, e.g., to deform it.
+    ///
+    /// See `BaseMesh::vertices_mut`. The normals of the mesh, if any, are left unchanged.
+    /// `.refit(...)` must be called after the vertices are modified.
+    #[inline]
+    pub fn vertices_mut(&mut self) -> &mut [P] {
+        self.mesh.vertices_mut()
+    }
+
+    /// Updates the bounding volumes of the triangles and of the BVT after the vertices moved.
+    ///
+    /// See `BaseMesh::refit`.
+    #[inline]
+    pub fn refit(&mut self, margin: P::Real) -> usize {
+        self.mesh.refit(margin)
+    }
+}
+
 impl<P: Point> TriMesh<P> {
     /// Gets the i-th mesh element.
     #[inline]
     pub fn triangle_at(&self, i: usize) -> Triangle<P> {
         self.mesh.element_at(i)
     }
+
+    /// The triangles sharing an edge with the i-th triangle.
+    ///
+    /// The j-th entry is the triangle adjacent to the i-th triangle along its edge `j`, with the
+    /// edges `ab`, `bc`, and `ca` numbered 0, 1, and 2. It is `None` if this edge lies on the
+    /// boundary of the mesh, or if it is shared by more than two triangles.
+    #[inline]
+    pub fn adjacent_triangles(&self, i: usize) -> &[Option<usize>; 3] {
+        &self.adjacency[i]
+    }
+
+    /// The generalized winding number of this 3D mesh around a point given in its local space.
+    ///
+    /// This is the sum of the solid angles of the triangles seen from `pt`, divided by `4 * pi`.
+    /// It is 1 inside of a closed mesh with counterclockwise triangles seen from outside, and 0
+    /// outside of it. Holes in the mesh make it vary continuously between those values.
+    pub fn winding_number(&self, pt: &P) -> P::Real {
+        assert!(na::dimension::<P::Vector>() == 3, "The winding number of a triangle mesh is only defined in 3D.");
+
+        let vertices = &self.vertices()[..];
+        let mut res  = na::zero::<P::Real>();
+
+        // Van Oosterom and Strackee's formula gives half of the solid angle of a triangle.
+        for idx in self.indices().iter() {
+            let a  = vertices[idx.x] - *pt;
+            let b  = vertices[idx.y] - *pt;
+            let c  = vertices[idx.z] - *pt;
+            let la = na::norm(&a);
+            let lb = na::norm(&b);
+            let lc = na::norm(&c);
+
+            let det   = na::dot(&a, &utils::cross3(&b, &c));
+            let denom = la * lb * lc + na::dot(&a, &b) * lc + na::dot(&b, &c) * la + na::dot(&c, &a) * lb;
+
+            res = res + det.atan2(denom);
+        }
+
+        res / P::Real::two_pi()
+    }
+}
+
+// Computes the triangles adjacent to each edge of each triangle, from the vertex indices.
+fn edge_adjacency(indices: &[Point3<usize>]) -> Vec<[Option<usize>; 3]> {
+    let mut adjacency = vec![[None; 3]; indices.len()];
+    let mut edges     = HashMap::<(usize, usize), Vec<(usize, usize)>>::new();
+
+    for (i, idx) in indices.iter().enumerate() {
+        for j in 0 .. 3 {
+            let (a, b) = (idx[j], idx[(j + 1) % 3]);
+            let key    = if a < b { (a, b) } else { (b, a) };
+
+            edges.entry(key).or_insert_with(Vec::new).push((i, j));
+        }
+    }
+
+    for sides in edges.values() {
+        if sides.len() == 2 {
+            let (i1, j1) = sides[0];
+            let (i2, j2) = sides[1];
+
+            adjacency[i1][j1] = Some(i2);
+            adjacency[i2][j2] = Some(i1);
+        }
+    }
+
+    adjacency
 }
 
 impl<P: Point, M: Isometry<P>> CompositeShape<P, M> for TriMesh<P> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.base_mesh().len()
+    }
+
     #[inline(always)]
     fn map_part_at(&self, i: usize, f: &mut FnMut(&M, &Shape<P, M>)) {
         let one: M = na::one();
@@ -99,6 +271,13 @@ impl<P: Point, M: Isometry<P>> CompositeShape<P, M> for TriMesh<P> {
         f(m, &element)
     }
 
+    #[inline]
+    fn parts_share_vertex(&self, i: usize, j: usize) -> bool {
+        let (a, b) = (self.indices()[i], self.indices()[j]);
+
+        a.iter().any(|v| b.iter().any(|w| v == w))
+    }
+
     #[inline]
     fn aabb_at(&self, i: usize) -> AABB<P> {
         self.bounding_volumes()[i].clone()
@@ -0,0 +1,58 @@
+//! A shape with a fixed transformation wrt. its local frame.
+
+use std::ops::Mul;
+
+use shape::SupportMap;
+use math::{Point, Isometry};
+
+/// A shape transformed by a fixed local pose.
+///
+/// The local pose is appended to the transformation given to every query on this shape. This
+/// allows, e.g., a cone with an axis other than `y`, or a shape not centered at the origin,
+/// without changing the transformation of the object it belongs to.
+#[derive(PartialEq, Debug, Clone, RustcEncodable, RustcDecodable)]
+pub struct WithLocalPose<S, M> {
+    shape:      S,
+    local_pose: M
+}
+
+impl<S, M> WithLocalPose<S, M> {
+    /// Creates a new shape transformed by `local_pose` wrt. the local frame of `shape`.
+    #[inline]
+    pub fn new(shape: S, local_pose: M) -> WithLocalPose<S, M> {
+        WithLocalPose {
+            shape:      shape,
+            local_pose: local_pose
+        }
+    }
+
+    /// The transformed shape.
+    #[inline]
+    pub fn shape(&self) -> &S {
+        &self.shape
+    }
+
+    /// The local pose of the transformed shape.
+    #[inline]
+    pub fn local_pose(&self) -> &M {
+        &self.local_pose
+    }
+}
+
+impl<S, M: Clone + Mul<M, Output = M>> WithLocalPose<S, M> {
+    /// The transformation of the inner shape when this shape is transformed by `m`.
+    #[inline]
+    pub fn shape_transform(&self, m: &M) -> M {
+        m.clone() * self.local_pose.clone()
+    }
+}
+
+impl<P, M, S> SupportMap<P, M> for WithLocalPose<S, M>
+    where P: Point,
+          M: Isometry<P>,
+          S: SupportMap<P, M> {
+    #[inline]
+    fn support_point(&self, m: &M, dir: &P::Vector) -> P {
+        self.shape.support_point(&self.shape_transform(m), dir)
+    }
+}
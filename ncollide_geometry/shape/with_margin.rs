@@ -0,0 +1,32 @@
+//! A convex shape inflated by a collision margin.
+
+use alga::linear::EuclideanSpace;
+
+use shape::{SupportMap, RoundShape, ShapeHandle, FeatureId};
+use math::{Point, Isometry};
+
+/// A support-mapped shape inflated by a collision margin.
+///
+/// This is a `RoundShape` with a dynamic inner shape: its support point in a direction is the
+/// support point of the wrapped shape, moved by the margin along this direction. Its edges and
+/// vertices are slightly rounded, which improves the numerical stability of GJK-based algorithms,
+/// and contacts are generated before the wrapped shapes actually touch. Every query on this shape,
+/// including the penetration depth of contacts, is relative to the inflated surface. The wrapped
+/// shape must be support-mapped.
+pub type WithMargin<P, M> = RoundShape<ShapeHandle<P, M>, <P as EuclideanSpace>::Real>;
+
+impl<P: Point, M: Isometry<P>> SupportMap<P, M> for ShapeHandle<P, M> {
+    #[inline]
+    fn support_point(&self, m: &M, dir: &P::Vector) -> P {
+        self.as_support_map()
+            .expect("No SupportMap implementation for the underlying shape.")
+            .support_point(m, dir)
+    }
+
+    #[inline]
+    fn support_feature_id(&self, m: &M, pts: &[P]) -> FeatureId {
+        self.as_support_map()
+            .expect("No SupportMap implementation for the underlying shape.")
+            .support_feature_id(m, pts)
+    }
+}
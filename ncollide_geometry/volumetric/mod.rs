@@ -0,0 +1,25 @@
+//! Volume, center of mass, and angular inertia of shapes.
+
+#[doc(inline)]
+pub use volumetric::volumetric::Volumetric;
+
+pub use volumetric::volumetric_cuboid::{cuboid_volume, cuboid_area, cuboid_unit_angular_inertia2,
+                                        cuboid_unit_angular_inertia3};
+pub use volumetric::volumetric_ball::{ball_volume, ball_area, ball_unit_angular_inertia};
+pub use volumetric::volumetric_cone::cone_volume;
+pub use volumetric::volumetric_compound::{compound_mass_properties2, compound_mass_properties3};
+pub use volumetric::volumetric_support_map::support_map_mass_properties;
+
+#[doc(hidden)]
+pub mod volumetric;
+mod volumetric_cuboid;
+mod volumetric_ball;
+mod volumetric_cone;
+mod volumetric_cylinder;
+mod volumetric_capsule;
+mod volumetric_support_map;
+mod volumetric_compound;
+mod volumetric_trimesh;
+mod volumetric_polyline;
+mod volumetric_convex_polygon;
+mod volumetric_convex_hull;
@@ -0,0 +1,31 @@
+use std::ops::Mul;
+use alga::general::Real;
+
+/// Trait implemented by objects which have a mass, a center of mass, and an inertia tensor.
+///
+/// In 2D, the "volume" is the surface of the shape and its "area" is its perimeter. The angular
+/// inertia is a scalar in 2D and a 3x3 matrix in 3D.
+pub trait Volumetric<N, P, I> {
+    /// Computes the area of this object.
+    fn area(&self) -> N;
+
+    /// Computes the volume of this object.
+    fn volume(&self) -> N;
+
+    /// Computes the center of mass of this object, expressed in its local space.
+    fn center_of_mass(&self) -> P;
+
+    /// Computes the angular inertia of this object with a unit density, wrt. its center of mass.
+    fn unit_angular_inertia(&self) -> I;
+
+    /// Given its density, this computes the mass, center of mass, and inertia tensor of this object.
+    fn mass_properties(&self, density: N) -> (N, P, I)
+        where N: Real,
+              I: Mul<N, Output = I> {
+        let mass = self.volume() * density;
+        let com  = self.center_of_mass();
+        let ai   = self.unit_angular_inertia() * density;
+
+        (mass, com, ai)
+    }
+}
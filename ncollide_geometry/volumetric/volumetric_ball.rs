@@ -0,0 +1,74 @@
+use alga::general::Real;
+use na::{self, Point2, Point3, Matrix3};
+
+use shape::Ball;
+use volumetric::Volumetric;
+
+/// Computes the volume of a ball of the given dimension.
+///
+/// This uses the recurrence `V(n) = V(n - 2) * 2 * pi * r² / n` with `V(0) = 1` and `V(1) = 2r`.
+#[inline]
+pub fn ball_volume<N: Real>(radius: N, dimension: usize) -> N {
+    let mut res: N = if dimension % 2 == 0 { na::one() } else { radius * na::convert(2.0f64) };
+    let mut dim    = if dimension % 2 == 0 { 2 } else { 3 };
+
+    while dim <= dimension {
+        res = res * N::two_pi() * radius * radius / na::convert(dim as f64);
+        dim = dim + 2;
+    }
+
+    res
+}
+
+/// Computes the area of a ball of the given dimension, i.e., the volume of its boundary.
+///
+/// In 2D, this is the perimeter of the disk.
+#[inline]
+pub fn ball_area<N: Real>(radius: N, dimension: usize) -> N {
+    ball_volume(radius, dimension) * na::convert(dimension as f64) / radius
+}
+
+/// Computes the unit angular inertia of a ball of the given dimension wrt. any axis through its
+/// center.
+#[inline]
+pub fn ball_unit_angular_inertia<N: Real>(radius: N, dimension: usize) -> N {
+    let _2: N = na::convert(2.0f64);
+
+    ball_volume(radius, dimension) * _2 * radius * radius / na::convert((dimension + 2) as f64)
+}
+
+impl<N: Real> Volumetric<N, Point2<N>, N> for Ball<N> {
+    fn area(&self) -> N {
+        ball_area(self.radius(), 2)
+    }
+
+    fn volume(&self) -> N {
+        ball_volume(self.radius(), 2)
+    }
+
+    fn center_of_mass(&self) -> Point2<N> {
+        Point2::origin()
+    }
+
+    fn unit_angular_inertia(&self) -> N {
+        ball_unit_angular_inertia(self.radius(), 2)
+    }
+}
+
+impl<N: Real> Volumetric<N, Point3<N>, Matrix3<N>> for Ball<N> {
+    fn area(&self) -> N {
+        ball_area(self.radius(), 3)
+    }
+
+    fn volume(&self) -> N {
+        ball_volume(self.radius(), 3)
+    }
+
+    fn center_of_mass(&self) -> Point3<N> {
+        Point3::origin()
+    }
+
+    fn unit_angular_inertia(&self) -> Matrix3<N> {
+        Matrix3::from_diagonal_element(ball_unit_angular_inertia(self.radius(), 3))
+    }
+}
@@ -0,0 +1,69 @@
+use alga::general::Real;
+use na::{self, Point2, Point3, Vector2, Vector3, Matrix3};
+
+use shape::Capsule;
+use volumetric::{self, Volumetric};
+
+impl<N: Real> Volumetric<N, Point2<N>, N> for Capsule<N> {
+    fn area(&self) -> N {
+        let height = self.half_height() * na::convert(2.0f64);
+
+        height * na::convert(2.0f64) + volumetric::ball_area(self.radius(), 2)
+    }
+
+    fn volume(&self) -> N {
+        let half_extents = Vector2::new(self.radius(), self.half_height());
+
+        volumetric::cuboid_volume(&half_extents) + volumetric::ball_volume(self.radius(), 2)
+    }
+
+    fn center_of_mass(&self) -> Point2<N> {
+        Point2::origin()
+    }
+
+    fn unit_angular_inertia(&self) -> N {
+        // The rectangle, and the two half-disks moved from the center of their disk to the center
+        // of the capsule: each half-disk has its centroid at `4r / (3pi)` from its flat side.
+        let (h, r)   = (self.half_height(), self.radius());
+        let rect     = volumetric::cuboid_unit_angular_inertia2(&Vector2::new(r, h));
+        let disk     = volumetric::ball_volume(r, 2);
+        let centroid = r * na::convert(4.0f64) / (N::pi() * na::convert(3.0f64));
+
+        rect + disk * (r * r / na::convert(2.0f64) + h * h + h * centroid * na::convert(2.0f64))
+    }
+}
+
+impl<N: Real> Volumetric<N, Point3<N>, Matrix3<N>> for Capsule<N> {
+    fn area(&self) -> N {
+        let height = self.half_height() * na::convert(2.0f64);
+
+        N::two_pi() * self.radius() * height + volumetric::ball_area(self.radius(), 3)
+    }
+
+    fn volume(&self) -> N {
+        let height = self.half_height() * na::convert(2.0f64);
+
+        N::pi() * self.radius() * self.radius() * height + volumetric::ball_volume(self.radius(), 3)
+    }
+
+    fn center_of_mass(&self) -> Point3<N> {
+        Point3::origin()
+    }
+
+    fn unit_angular_inertia(&self) -> Matrix3<N> {
+        // The cylinder, and the two hemispheres moved from the center of their ball to the center
+        // of the capsule: each hemisphere has its centroid at `3r / 8` from its flat side.
+        let (h, r)   = (self.half_height(), self.radius());
+        let r2       = r * r;
+        let height   = h * na::convert(2.0f64);
+        let cylinder = N::pi() * r2 * height;
+        let ball     = volumetric::ball_volume(r, 3);
+        let centroid = r * na::convert(3.0f64 / 8.0);
+
+        let iy  = cylinder * r2 / na::convert(2.0f64) + ball * r2 * na::convert(2.0f64 / 5.0);
+        let ixz = cylinder * (r2 * na::convert(3.0f64) + height * height) / na::convert(12.0f64) +
+                  ball * (r2 * na::convert(2.0f64 / 5.0) + h * h + h * centroid * na::convert(2.0f64));
+
+        Matrix3::from_diagonal(&Vector3::new(ixz, iy, ixz))
+    }
+}
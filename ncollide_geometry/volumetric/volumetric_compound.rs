@@ -0,0 +1,198 @@
+use std::ops::{Add, Mul};
+use num::Zero;
+
+use alga::general::Real;
+use na::{self, Point2, Point3, Vector2, Vector3, Matrix3, Isometry2, Isometry3};
+
+use shape::{Shape, Ball, Cuboid, Cone, Capsule, Cylinder, Compound, ConvexPolygon, ConvexHull, Polyline,
+            TriMesh};
+use volumetric::Volumetric;
+use math::{Point, Isometry, AngularInertia};
+
+/// Computes the mass, center of mass, and angular inertia wrt. the center of mass of a 2D
+/// compound shape, given the density of each of its parts.
+///
+/// The i-th element of `densities` is the density of the i-th part of `compound`. The parts are
+/// assumed not to overlap. Returns `None` if a part has no known mass properties, i.e., if it is
+/// not a ball, a cuboid, a cone, a capsule, a cylinder, a convex polygon, a convex hull, a closed
+/// polyline, or a compound shape of such parts. Fails if there are not as many densities as parts.
+pub fn compound_mass_properties2<N: Real>(compound: &Compound<Point2<N>, Isometry2<N>>, densities: &[N])
+                                          -> Option<(N, Point2<N>, N)> {
+    compound_mass_properties(compound, densities, |m| m.rotation, part_mass_properties2)
+        .map(|(_, mass, com, inertia)| (mass, com, inertia))
+}
+
+/// Computes the mass, center of mass, and angular inertia wrt. the center of mass of a 3D
+/// compound shape, given the density of each of its parts.
+///
+/// The i-th element of `densities` is the density of the i-th part of `compound`. The parts are
+/// assumed not to overlap. Returns `None` if a part has no known mass properties, i.e., if it is
+/// not a ball, a cuboid, a cone, a capsule, a cylinder, a convex hull, a closed triangle mesh, or
+/// a compound shape of such parts. Fails if there are not as many densities as parts.
+pub fn compound_mass_properties3<N: Real>(compound: &Compound<Point3<N>, Isometry3<N>>, densities: &[N])
+                                          -> Option<(N, Point3<N>, Matrix3<N>)> {
+    compound_mass_properties(compound, densities, |m| m.rotation, part_mass_properties3)
+        .map(|(_, mass, com, inertia)| (mass, com, inertia))
+}
+
+// The area, volume, center of mass, and unit angular inertia of a volumetric object.
+#[inline]
+fn mass_properties<N, P, I>(part: &Volumetric<N, P, I>) -> (N, N, P, I) {
+    (part.area(), part.volume(), part.center_of_mass(), part.unit_angular_inertia())
+}
+
+// The area, volume, center of mass, and unit angular inertia of the part of a 2D compound shape.
+fn part_mass_properties2<N: Real>(part: &Shape<Point2<N>, Isometry2<N>>) -> Option<(N, N, Point2<N>, N)> {
+    if let Some(b) = part.as_shape::<Ball<N>>() {
+        Some(mass_properties(b))
+    }
+    else if let Some(c) = part.as_shape::<Cuboid<Vector2<N>>>() {
+        Some(mass_properties(c))
+    }
+    else if let Some(c) = part.as_shape::<Cone<N>>() {
+        Some(mass_properties(c))
+    }
+    else if let Some(c) = part.as_shape::<Capsule<N>>() {
+        Some(mass_properties(c))
+    }
+    else if let Some(c) = part.as_shape::<Cylinder<N>>() {
+        Some(mass_properties(c))
+    }
+    else if let Some(p) = part.as_shape::<ConvexPolygon<Point2<N>>>() {
+        Some(mass_properties(p))
+    }
+    else if let Some(h) = part.as_shape::<ConvexHull<Point2<N>>>() {
+        Some(mass_properties(h))
+    }
+    else if let Some(p) = part.as_shape::<Polyline<Point2<N>>>() {
+        Some(mass_properties(p))
+    }
+    else if let Some(c) = part.as_shape::<Compound<Point2<N>, Isometry2<N>>>() {
+        compound_mass_properties(c, &vec![na::one(); c.shapes().len()], |m| m.rotation, part_mass_properties2)
+    }
+    else {
+        None
+    }
+}
+
+// The area, volume, center of mass, and unit angular inertia of the part of a 3D compound shape.
+fn part_mass_properties3<N: Real>(part: &Shape<Point3<N>, Isometry3<N>>) -> Option<(N, N, Point3<N>, Matrix3<N>)> {
+    if let Some(b) = part.as_shape::<Ball<N>>() {
+        Some(mass_properties(b))
+    }
+    else if let Some(c) = part.as_shape::<Cuboid<Vector3<N>>>() {
+        Some(mass_properties(c))
+    }
+    else if let Some(c) = part.as_shape::<Cone<N>>() {
+        Some(mass_properties(c))
+    }
+    else if let Some(c) = part.as_shape::<Capsule<N>>() {
+        Some(mass_properties(c))
+    }
+    else if let Some(c) = part.as_shape::<Cylinder<N>>() {
+        Some(mass_properties(c))
+    }
+    else if let Some(h) = part.as_shape::<ConvexHull<Point3<N>>>() {
+        Some(mass_properties(h))
+    }
+    else if let Some(m) = part.as_shape::<TriMesh<Point3<N>>>() {
+        Some(mass_properties(m))
+    }
+    else if let Some(c) = part.as_shape::<Compound<Point3<N>, Isometry3<N>>>() {
+        compound_mass_properties(c, &vec![na::one(); c.shapes().len()], |m| m.rotation, part_mass_properties3)
+    }
+    else {
+        None
+    }
+}
+
+// Aggregates the mass properties of the parts, moved to the compound center of mass with the
+// parallel-axis theorem. The area is the sum of the areas of the parts.
+fn compound_mass_properties<P, M, R, I, FR, FP>(compound: &Compound<P, M>,
+                                                densities: &[P::Real],
+                                                rotation:  FR,
+                                                part:      FP)
+                                                -> Option<(P::Real, P::Real, P, I)>
+    where P:  Point,
+          M:  Isometry<P>,
+          I:  AngularInertia<P::Real, P::Vector, R> + Zero + Add<I, Output = I> + Mul<P::Real, Output = I>,
+          FR: Fn(&M) -> R,
+          FP: Fn(&Shape<P, M>) -> Option<(P::Real, P::Real, P, I)> {
+    let shapes = compound.shapes();
+
+    assert!(densities.len() == shapes.len(), "There must be as many densities as compound parts.");
+
+    let mut parts   = Vec::with_capacity(shapes.len());
+    let mut area    = na::zero::<P::Real>();
+    let mut mass    = na::zero::<P::Real>();
+    let mut com_sum = na::zero::<P::Vector>();
+
+    for (&(ref m, ref shape), density) in shapes.iter().zip(densities.iter()) {
+        let (part_area, part_volume, part_com, part_inertia) = match part(shape.as_ref()) {
+            Some(props) => props,
+            None        => return None
+        };
+        let part_mass = part_volume * *density;
+        let part_com  = m.transform_point(&part_com);
+
+        area    = area + part_area;
+        mass    = mass + part_mass;
+        com_sum = com_sum + part_com.coordinates() * part_mass;
+
+        parts.push((part_mass, part_com, part_inertia.rotate(&rotation(m)) * *density));
+    }
+
+    let com = if mass.is_zero() { P::origin() } else { P::from_coordinates(com_sum / mass) };
+
+    let mut inertia = I::zero();
+
+    for (part_mass, part_com, part_inertia) in parts.into_iter() {
+        inertia = inertia + part_inertia.translate(part_mass, &(part_com - com));
+    }
+
+    Some((area, mass, com, inertia))
+}
+
+const UNSUPPORTED: &'static str = "The mass properties of a compound part are not supported.";
+
+impl<N: Real> Volumetric<N, Point2<N>, N> for Compound<Point2<N>, Isometry2<N>> {
+    /// The sum of the areas of the parts, including their overlapping boundaries.
+    ///
+    /// Fails if a part has no known mass properties, see `compound_mass_properties2`.
+    fn area(&self) -> N {
+        part_mass_properties2(self).expect(UNSUPPORTED).0
+    }
+
+    fn volume(&self) -> N {
+        part_mass_properties2(self).expect(UNSUPPORTED).1
+    }
+
+    fn center_of_mass(&self) -> Point2<N> {
+        part_mass_properties2(self).expect(UNSUPPORTED).2
+    }
+
+    fn unit_angular_inertia(&self) -> N {
+        part_mass_properties2(self).expect(UNSUPPORTED).3
+    }
+}
+
+impl<N: Real> Volumetric<N, Point3<N>, Matrix3<N>> for Compound<Point3<N>, Isometry3<N>> {
+    /// The sum of the areas of the parts, including their overlapping boundaries.
+    ///
+    /// Fails if a part has no known mass properties, see `compound_mass_properties3`.
+    fn area(&self) -> N {
+        part_mass_properties3(self).expect(UNSUPPORTED).0
+    }
+
+    fn volume(&self) -> N {
+        part_mass_properties3(self).expect(UNSUPPORTED).1
+    }
+
+    fn center_of_mass(&self) -> Point3<N> {
+        part_mass_properties3(self).expect(UNSUPPORTED).2
+    }
+
+    fn unit_angular_inertia(&self) -> Matrix3<N> {
+        part_mass_properties3(self).expect(UNSUPPORTED).3
+    }
+}
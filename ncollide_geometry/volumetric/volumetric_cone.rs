@@ -0,0 +1,78 @@
+use alga::general::Real;
+use na::{self, Point2, Point3, Vector3, Matrix3};
+
+use shape::Cone;
+use volumetric::{Volumetric, ball_volume};
+
+/// Computes the volume of a cone of the given dimension.
+///
+/// The cone has a height of `2 * half_height` and its base is a ball of radius `radius` of the
+/// dimension below, so its volume is `ball_volume(radius, dimension - 1) * height / dimension`. In
+/// 2D, the cone is an isosceles triangle with a base of width `2 * radius` and this is its area.
+/// Fails if `dimension` is zero.
+#[inline]
+pub fn cone_volume<N: Real>(half_height: N, radius: N, dimension: usize) -> N {
+    assert!(dimension != 0, "A cone cannot be zero-dimensional.");
+
+    let _2: N = na::convert(2.0f64);
+
+    ball_volume(radius, dimension - 1) * _2 * half_height / na::convert(dimension as f64)
+}
+
+impl<N: Real> Volumetric<N, Point2<N>, N> for Cone<N> {
+    /// The perimeter of this isosceles triangle.
+    fn area(&self) -> N {
+        let _2: N = na::convert(2.0f64);
+        let height = self.half_height() * _2;
+        let side   = (height * height + self.radius() * self.radius()).sqrt();
+
+        (self.radius() + side) * _2
+    }
+
+    fn volume(&self) -> N {
+        cone_volume(self.half_height(), self.radius(), 2)
+    }
+
+    /// The centroid of the triangle, at a third of its height from its base.
+    fn center_of_mass(&self) -> Point2<N> {
+        Point2::new(na::zero(), -self.half_height() / na::convert(3.0f64))
+    }
+
+    fn unit_angular_inertia(&self) -> N {
+        // The polar moment of a triangle of sides `a`, `b`, `c` is `m * (a² + b² + c²) / 36`.
+        let height = self.half_height() * na::convert(2.0f64);
+        let r2     = self.radius() * self.radius();
+
+        let m      = cone_volume(self.half_height(), self.radius(), 2);
+
+        m * (r2 * na::convert(3.0f64) + height * height) / na::convert(18.0f64)
+    }
+}
+
+impl<N: Real> Volumetric<N, Point3<N>, Matrix3<N>> for Cone<N> {
+    fn area(&self) -> N {
+        let height = self.half_height() * na::convert(2.0f64);
+        let side   = (height * height + self.radius() * self.radius()).sqrt();
+
+        N::pi() * self.radius() * (self.radius() + side)
+    }
+
+    fn volume(&self) -> N {
+        cone_volume(self.half_height(), self.radius(), 3)
+    }
+
+    /// The centroid of the cone, at a quarter of its height from its base.
+    fn center_of_mass(&self) -> Point3<N> {
+        Point3::new(na::zero(), -self.half_height() / na::convert(2.0f64), na::zero())
+    }
+
+    fn unit_angular_inertia(&self) -> Matrix3<N> {
+        let height = self.half_height() * na::convert(2.0f64);
+        let r2     = self.radius() * self.radius();
+        let m      = cone_volume(self.half_height(), self.radius(), 3);
+        let iy     = m * r2 * na::convert(3.0f64 / 10.0);
+        let ixz    = m * (r2 * na::convert(3.0f64 / 20.0) + height * height * na::convert(3.0f64 / 80.0));
+
+        Matrix3::from_diagonal(&Vector3::new(ixz, iy, ixz))
+    }
+}
@@ -0,0 +1,70 @@
+use alga::general::Real;
+use na::{self, Point2, Point3, Matrix3};
+
+use utils;
+use shape::{ConvexHull, ConvexPolygon};
+use volumetric::Volumetric;
+use volumetric::volumetric_trimesh::polyhedron_mass_properties;
+
+// The convex polygon bounding a 2D convex hull, `None` if its points are aligned.
+fn convex_hull_polygon<N: Real>(hull: &ConvexHull<Point2<N>>) -> Option<ConvexPolygon<Point2<N>>> {
+    ConvexPolygon::try_from_points(hull.points())
+}
+
+impl<N: Real> Volumetric<N, Point2<N>, N> for ConvexHull<Point2<N>> {
+    /// The perimeter of this convex hull, zero if its points are aligned.
+    fn area(&self) -> N {
+        convex_hull_polygon(self).map(|p| p.area()).unwrap_or(na::zero())
+    }
+
+    /// The surface of this convex hull, zero if its points are aligned.
+    fn volume(&self) -> N {
+        convex_hull_polygon(self).map(|p| p.volume()).unwrap_or(na::zero())
+    }
+
+    fn center_of_mass(&self) -> Point2<N> {
+        match convex_hull_polygon(self) {
+            Some(p) => p.center_of_mass(),
+            None    => utils::center(self.points())
+        }
+    }
+
+    fn unit_angular_inertia(&self) -> N {
+        convex_hull_polygon(self).map(|p| p.unit_angular_inertia()).unwrap_or(na::zero())
+    }
+}
+
+// The faces of a 3D convex hull are computed by `utils::point_cloud_hull_faces`.
+fn convex_hull_mass_properties<N: Real>(hull: &ConvexHull<Point3<N>>) -> (N, Point3<N>, Matrix3<N>) {
+    let faces = utils::point_cloud_hull_faces(hull.points());
+
+    if faces.is_empty() {
+        return (na::zero(), utils::center(hull.points()), na::zero());
+    }
+
+    polyhedron_mass_properties(hull.points(), faces.into_iter())
+}
+
+impl<N: Real> Volumetric<N, Point3<N>, Matrix3<N>> for ConvexHull<Point3<N>> {
+    /// The area of the boundary of this convex hull, zero if its points are coplanar.
+    fn area(&self) -> N {
+        let points = self.points();
+
+        utils::point_cloud_hull_faces(points).iter().fold(na::zero(), |acc, f| {
+            acc + utils::triangle_area(&points[f.x], &points[f.y], &points[f.z])
+        })
+    }
+
+    /// The volume of this convex hull, zero if its points are coplanar.
+    fn volume(&self) -> N {
+        convex_hull_mass_properties(self).0
+    }
+
+    fn center_of_mass(&self) -> Point3<N> {
+        convex_hull_mass_properties(self).1
+    }
+
+    fn unit_angular_inertia(&self) -> Matrix3<N> {
+        convex_hull_mass_properties(self).2
+    }
+}
@@ -0,0 +1,35 @@
+use alga::general::Real;
+use na::{self, Point2};
+
+use shape::ConvexPolygon;
+use volumetric::Volumetric;
+use volumetric::volumetric_polyline::polygon_mass_properties;
+
+fn convex_polygon_mass_properties<N: Real>(polygon: &ConvexPolygon<Point2<N>>) -> (N, Point2<N>, N) {
+    let n = polygon.points().len();
+
+    polygon_mass_properties(polygon.points(), (0 .. n).map(|i| (i, (i + 1) % n)))
+}
+
+impl<N: Real> Volumetric<N, Point2<N>, N> for ConvexPolygon<Point2<N>> {
+    /// The perimeter of this polygon.
+    fn area(&self) -> N {
+        (0 .. self.points().len()).fold(na::zero(), |acc, i| {
+            let (a, b) = self.edge(i);
+            acc + na::distance(a, b)
+        })
+    }
+
+    /// The surface of this polygon.
+    fn volume(&self) -> N {
+        convex_polygon_mass_properties(self).0
+    }
+
+    fn center_of_mass(&self) -> Point2<N> {
+        convex_polygon_mass_properties(self).1
+    }
+
+    fn unit_angular_inertia(&self) -> N {
+        convex_polygon_mass_properties(self).2
+    }
+}
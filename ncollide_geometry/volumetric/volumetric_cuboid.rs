@@ -0,0 +1,101 @@
+use alga::general::Real;
+use na::{self, Point2, Point3, Vector2, Vector3, Matrix3};
+
+use shape::Cuboid;
+use volumetric::Volumetric;
+use math::Vector;
+
+/// Computes the volume of a cuboid, i.e., the product of its full extents.
+#[inline]
+pub fn cuboid_volume<V: Vector>(half_extents: &V) -> V::Real {
+    let mut res: V::Real = na::one();
+
+    for i in 0 .. na::dimension::<V>() {
+        res = res * half_extents[i] * na::convert(2.0f64)
+    }
+
+    res
+}
+
+/// Computes the area of a cuboid, i.e., the sum of the volumes of its facets.
+///
+/// In 2D, this is the perimeter of the rectangle.
+#[inline]
+pub fn cuboid_area<V: Vector>(half_extents: &V) -> V::Real {
+    let dim     = na::dimension::<V>();
+    let mut res = na::zero::<V::Real>();
+
+    for i in 0 .. dim {
+        let mut facet: V::Real = na::one();
+
+        for j in 0 .. dim {
+            if j != i {
+                facet = facet * half_extents[j] * na::convert(2.0f64)
+            }
+        }
+
+        res = res + facet;
+    }
+
+    res * na::convert(2.0f64)
+}
+
+/// Computes the unit angular inertia of a rectangle wrt. its center.
+#[inline]
+pub fn cuboid_unit_angular_inertia2<N: Real>(half_extents: &Vector2<N>) -> N {
+    let _2: N = na::convert(2.0f64);
+    let _12: N = na::convert(12.0f64);
+    let w = _2 * half_extents.x;
+    let h = _2 * half_extents.y;
+
+    cuboid_volume(half_extents) * (w * w + h * h) / _12
+}
+
+/// Computes the unit angular inertia of a box wrt. its center.
+#[inline]
+pub fn cuboid_unit_angular_inertia3<N: Real>(half_extents: &Vector3<N>) -> Matrix3<N> {
+    let _2: N = na::convert(2.0f64);
+    let _12: N = na::convert(12.0f64);
+    let w  = _2 * half_extents.x;
+    let h  = _2 * half_extents.y;
+    let d  = _2 * half_extents.z;
+    let m  = cuboid_volume(half_extents) / _12;
+
+    Matrix3::from_diagonal(&Vector3::new(m * (h * h + d * d), m * (w * w + d * d), m * (w * w + h * h)))
+}
+
+impl<N: Real> Volumetric<N, Point2<N>, N> for Cuboid<Vector2<N>> {
+    fn area(&self) -> N {
+        cuboid_area(self.half_extents())
+    }
+
+    fn volume(&self) -> N {
+        cuboid_volume(self.half_extents())
+    }
+
+    fn center_of_mass(&self) -> Point2<N> {
+        Point2::origin()
+    }
+
+    fn unit_angular_inertia(&self) -> N {
+        cuboid_unit_angular_inertia2(self.half_extents())
+    }
+}
+
+impl<N: Real> Volumetric<N, Point3<N>, Matrix3<N>> for Cuboid<Vector3<N>> {
+    fn area(&self) -> N {
+        cuboid_area(self.half_extents())
+    }
+
+    fn volume(&self) -> N {
+        cuboid_volume(self.half_extents())
+    }
+
+    fn center_of_mass(&self) -> Point3<N> {
+        Point3::origin()
+    }
+
+    fn unit_angular_inertia(&self) -> Matrix3<N> {
+        cuboid_unit_angular_inertia3(self.half_extents())
+    }
+}
@@ -0,0 +1,57 @@
+use alga::general::Real;
+use na::{self, Point2, Point3, Vector2, Vector3, Matrix3};
+
+use shape::Cylinder;
+use volumetric::{self, Volumetric};
+
+// In 2D, a cylinder is a rectangle.
+#[inline]
+fn cylinder_half_extents2<N: Real>(cylinder: &Cylinder<N>) -> Vector2<N> {
+    Vector2::new(cylinder.radius(), cylinder.half_height())
+}
+
+impl<N: Real> Volumetric<N, Point2<N>, N> for Cylinder<N> {
+    fn area(&self) -> N {
+        volumetric::cuboid_area(&cylinder_half_extents2(self))
+    }
+
+    fn volume(&self) -> N {
+        volumetric::cuboid_volume(&cylinder_half_extents2(self))
+    }
+
+    fn center_of_mass(&self) -> Point2<N> {
+        Point2::origin()
+    }
+
+    fn unit_angular_inertia(&self) -> N {
+        volumetric::cuboid_unit_angular_inertia2(&cylinder_half_extents2(self))
+    }
+}
+
+impl<N: Real> Volumetric<N, Point3<N>, Matrix3<N>> for Cylinder<N> {
+    fn area(&self) -> N {
+        let height = self.half_height() * na::convert(2.0f64);
+
+        N::two_pi() * self.radius() * (self.radius() + height)
+    }
+
+    fn volume(&self) -> N {
+        let height = self.half_height() * na::convert(2.0f64);
+
+        N::pi() * self.radius() * self.radius() * height
+    }
+
+    fn center_of_mass(&self) -> Point3<N> {
+        Point3::origin()
+    }
+
+    fn unit_angular_inertia(&self) -> Matrix3<N> {
+        let height = self.half_height() * na::convert(2.0f64);
+        let r2     = self.radius() * self.radius();
+        let m      = N::pi() * r2 * height;
+        let iy     = m * r2 / na::convert(2.0f64);
+        let ixz    = m * (r2 * na::convert(3.0f64) + height * height) / na::convert(12.0f64);
+
+        Matrix3::from_diagonal(&Vector3::new(ixz, iy, ixz))
+    }
+}
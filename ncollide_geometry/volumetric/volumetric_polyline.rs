@@ -0,0 +1,79 @@
+use alga::general::Real;
+use na::{self, Point2, Vector2};
+
+use shape::Polyline;
+use volumetric::Volumetric;
+
+// Computes the area, center of mass, and unit angular inertia of the polygon bounded by the
+// closed loops of the given edges, as pairs of indices of `vertices`.
+//
+// By the divergence theorem, the polygon is decomposed into triangles sharing the first vertex and
+// based on its edges. Their signed areas cancel out outside of the polygon.
+pub fn polygon_mass_properties<N, E>(vertices: &[Point2<N>], edges: E) -> (N, Point2<N>, N)
+    where N: Real,
+          E: Iterator<Item = (usize, usize)> {
+    if vertices.is_empty() {
+        return (na::zero(), Point2::origin(), na::zero());
+    }
+
+    let origin      = vertices[0];
+    let mut area    = na::zero::<N>();
+    let mut com     = na::zero::<Vector2<N>>();
+    let mut inertia = na::zero::<N>();
+
+    for (ia, ib) in edges {
+        let a = vertices[ia] - origin;
+        let b = vertices[ib] - origin;
+
+        let cross = a.x * b.y - a.y * b.x;
+
+        area    = area + cross;
+        com     = com + (a + b) * cross;
+        inertia = inertia + (na::dot(&a, &a) + na::dot(&a, &b) + na::dot(&b, &b)) * cross;
+    }
+
+    if area.is_zero() {
+        return (na::zero(), origin, na::zero());
+    }
+
+    // Each triangle has an area of `cross / 2` and a centroid at `(a + b) / 3`.
+    com     = com / (area * na::convert(3.0f64));
+    area    = area / na::convert(2.0f64);
+    inertia = inertia / na::convert(12.0f64);
+
+    // The edges are clockwise.
+    if area < na::zero() {
+        area    = -area;
+        inertia = -inertia;
+    }
+
+    (area, origin + com, inertia - na::norm_squared(&com) * area)
+}
+
+fn polyline_mass_properties<N: Real>(polyline: &Polyline<Point2<N>>) -> (N, Point2<N>, N) {
+    polygon_mass_properties(&polyline.vertices()[..], polyline.indices().iter().map(|idx| (idx.x, idx.y)))
+}
+
+impl<N: Real> Volumetric<N, Point2<N>, N> for Polyline<Point2<N>> {
+    /// The perimeter of this polyline.
+    fn area(&self) -> N {
+        let vertices = &self.vertices()[..];
+
+        self.indices().iter().fold(na::zero(), |acc, idx| acc + na::distance(&vertices[idx.x], &vertices[idx.y]))
+    }
+
+    /// The area of the polygon bounded by this polyline.
+    ///
+    /// The segments of this polyline are assumed to form closed loops with consistent windings.
+    fn volume(&self) -> N {
+        polyline_mass_properties(self).0
+    }
+
+    fn center_of_mass(&self) -> Point2<N> {
+        polyline_mass_properties(self).1
+    }
+
+    fn unit_angular_inertia(&self) -> N {
+        polyline_mass_properties(self).2
+    }
+}
@@ -0,0 +1,124 @@
+use std::ops::{Add, Mul};
+use num::Zero;
+
+use alga::general::Id;
+use na;
+
+use shape::SupportMap;
+use bounding_volume;
+use query::QuerySettings;
+use query::algorithms::gjk;
+use query::algorithms::simplex::Simplex;
+use query::algorithms::johnson_simplex::JohnsonSimplex;
+use math::{Point, AngularInertia};
+
+/// Numerically computes the volume, the center of mass, and the unit angular inertia wrt. the
+/// center of mass of a convex support-mapped shape.
+///
+/// This does not depend on the dimension and is meant as a fallback for shapes without analytic
+/// mass properties. The AABB of `g` is sampled at `nsamples` points of a Halton sequence, and the
+/// mass properties are estimated from the samples falling inside of `g`, each being a point mass
+/// for the angular inertia. The error decreases roughly like `1 / nsamples`. If no sample lies
+/// inside of `g`, e.g., if `g` is flat, the volume and the angular inertia are zero and the center
+/// of the AABB is returned.
+pub fn support_map_mass_properties<P, R, I, G>(g: &G, nsamples: usize) -> (P::Real, P, I)
+    where P: Point,
+          I: AngularInertia<P::Real, P::Vector, R> + Zero + Add<I, Output = I> + Mul<P::Real, Output = I>,
+          G: SupportMap<P, Id> {
+    let dim     = na::dimension::<P::Vector>();
+    let aabb    = bounding_volume::support_map_aabb(&Id::new(), g);
+    let center  = aabb.center();
+    let extents = *aabb.maxs() - *aabb.mins();
+    let primes  = first_primes(dim);
+
+    let settings    = QuerySettings::default();
+    let mut simplex = JohnsonSimplex::<P>::new_w_tls();
+    let mut sum     = na::zero::<P::Vector>();
+    let mut inertia = I::zero();
+    let mut ninside = 0usize;
+
+    for i in 1 .. nsamples + 1 {
+        let mut pt = *aabb.mins();
+
+        for d in 0 .. dim {
+            pt[d] = pt[d] + extents[d] * na::convert(radical_inverse(i, primes[d]));
+        }
+
+        // The sample is inside of `g` iff the origin is inside of `g` translated by `-pt`.
+        let shifted = Shifted { shape: g, shift: pt.coordinates() };
+        simplex.reset(shifted.support_point(&Id::new(), &-pt.coordinates()));
+
+        if gjk::project_origin(&Id::new(), &shifted, &mut simplex, &settings).0.is_none() {
+            // The offsets are taken from the center of the AABB to limit the cancellations.
+            let offset = pt - center;
+
+            sum     = sum + offset;
+            inertia = inertia.translate(na::one(), &offset);
+            ninside = ninside + 1;
+        }
+    }
+
+    if ninside == 0 {
+        return (na::zero(), center, I::zero());
+    }
+
+    let mut box_volume = na::one::<P::Real>();
+
+    for d in 0 .. dim {
+        box_volume = box_volume * extents[d];
+    }
+
+    let ninside: P::Real = na::convert(ninside as f64);
+    let volume = box_volume * ninside / na::convert(nsamples as f64);
+    let com    = sum / ninside;
+
+    // Move the angular inertia of the unit point masses to the center of mass, and give each
+    // sample its share of the volume.
+    let inertia = inertia.translate(-ninside, &com) * (volume / ninside);
+
+    (volume, center + com, inertia)
+}
+
+// A support-mapped shape translated by `-shift`.
+struct Shifted<'a, P: Point, G: 'a> {
+    shape: &'a G,
+    shift: P::Vector
+}
+
+impl<'a, P: Point, G: SupportMap<P, Id>> SupportMap<P, Id> for Shifted<'a, P, G> {
+    #[inline]
+    fn support_point(&self, m: &Id, dir: &P::Vector) -> P {
+        self.shape.support_point(m, dir) + (-self.shift)
+    }
+}
+
+// The `n` first prime numbers, used as the bases of the Halton sequence.
+fn first_primes(n: usize) -> Vec<usize> {
+    let mut primes = Vec::with_capacity(n);
+    let mut candidate = 2;
+
+    while primes.len() < n {
+        if primes.iter().all(|p| candidate % p != 0) {
+            primes.push(candidate);
+        }
+
+        candidate = candidate + 1;
+    }
+
+    primes
+}
+
+// The `i`-th element of the van der Corput sequence in the given base, in `[0, 1[`.
+fn radical_inverse(mut i: usize, base: usize) -> f64 {
+    let inv_base   = 1.0 / base as f64;
+    let mut factor = inv_base;
+    let mut res    = 0.0;
+
+    while i > 0 {
+        res    = res + (i % base) as f64 * factor;
+        i      = i / base;
+        factor = factor * inv_base;
+    }
+
+    res
+}
@@ -0,0 +1,93 @@
+use alga::general::Real;
+use na::{self, Point3, Vector3, Matrix3};
+
+use utils;
+use shape::TriMesh;
+use volumetric::Volumetric;
+
+// Computes the volume, center of mass, and unit angular inertia of the polyhedron bounded by the
+// given triangles, as triplets of indices of `vertices`.
+//
+// By the divergence theorem, the polyhedron is decomposed into tetrahedra sharing its first vertex
+// and based on its triangles. Their signed volumes cancel out outside of the polyhedron.
+pub fn polyhedron_mass_properties<N, F>(vertices: &[Point3<N>], faces: F) -> (N, Point3<N>, Matrix3<N>)
+    where N: Real,
+          F: Iterator<Item = Point3<usize>> {
+    if vertices.is_empty() {
+        return (na::zero(), Point3::origin(), na::zero());
+    }
+
+    let _1: N = na::one();
+    let _2: N = na::convert(2.0f64);
+
+    // The covariance matrix of the canonical tetrahedron, times its determinant.
+    let canonical = Matrix3::new(_2, _1, _1,
+                                 _1, _2, _1,
+                                 _1, _1, _2) / na::convert::<f64, N>(120.0);
+
+    let origin         = vertices[0];
+    let mut volume     = na::zero::<N>();
+    let mut com        = na::zero::<Vector3<N>>();
+    let mut covariance = na::zero::<Matrix3<N>>();
+
+    for idx in faces {
+        let a = vertices[idx.x] - origin;
+        let b = vertices[idx.y] - origin;
+        let c = vertices[idx.z] - origin;
+
+        let basis = Matrix3::from_columns(&[ a, b, c ]);
+        let det   = basis.determinant();
+
+        volume     = volume + det;
+        com        = com + (a + b + c) * det;
+        covariance = covariance + basis * canonical * basis.transpose() * det;
+    }
+
+    if volume.is_zero() {
+        return (na::zero(), origin, na::zero());
+    }
+
+    // Each tetrahedron has a volume of `det / 6` and a centroid at `(a + b + c) / 4`.
+    com = com / (volume * na::convert(4.0f64));
+    volume = volume / na::convert(6.0f64);
+
+    // The polyhedron is inside-out.
+    if volume < na::zero() {
+        volume     = -volume;
+        covariance = -covariance;
+    }
+
+    let covariance = covariance - com * com.transpose() * volume;
+    let inertia    = Matrix3::from_diagonal_element(covariance.trace()) - covariance;
+
+    (volume, origin + com, inertia)
+}
+
+fn trimesh_mass_properties<N: Real>(mesh: &TriMesh<Point3<N>>) -> (N, Point3<N>, Matrix3<N>) {
+    polyhedron_mass_properties(&mesh.vertices()[..], mesh.indices().iter().cloned())
+}
+
+impl<N: Real> Volumetric<N, Point3<N>, Matrix3<N>> for TriMesh<Point3<N>> {
+    fn area(&self) -> N {
+        let vertices = &self.vertices()[..];
+
+        self.indices().iter().fold(na::zero(), |acc, idx| {
+            acc + utils::triangle_area(&vertices[idx.x], &vertices[idx.y], &vertices[idx.z])
+        })
+    }
+
+    /// The volume enclosed by this mesh.
+    ///
+    /// The mesh is assumed to be closed, with all its triangles oriented consistently.
+    fn volume(&self) -> N {
+        trimesh_mass_properties(self).0
+    }
+
+    fn center_of_mass(&self) -> Point3<N> {
+        trimesh_mass_properties(self).1
+    }
+
+    fn unit_angular_inertia(&self) -> Matrix3<N> {
+        trimesh_mass_properties(self).2
+    }
+}
@@ -0,0 +1,169 @@
+use alga::general::Real;
+use na::{self, Vector2, Vector3, Matrix3, Rotation3, UnitComplex, UnitQuaternion};
+
+use approx_eq;
+
+/// Trait implemented by angular inertia tensors.
+///
+/// In 2D, the angular inertia is a scalar rotated by a `UnitComplex`. In 3D, it is a 3x3 matrix
+/// rotated by a `UnitQuaternion`.
+pub trait AngularInertia<N, V, R>: Sized {
+    /// Expresses this angular inertia in a frame rotated by `rot`.
+    fn rotate(&self, rot: &R) -> Self;
+
+    /// Applies the parallel-axis theorem to move this angular inertia from the center of mass of
+    /// an object with the given `mass` to the point at `offset` from it.
+    ///
+    /// Because this is linear wrt. the mass, translating by the same `offset` with `-mass`
+    /// reverts this operation.
+    fn translate(&self, mass: N, offset: &V) -> Self;
+
+    /// The inverse of this angular inertia.
+    ///
+    /// The inverse of a singular angular inertia, e.g., the one of an object with a zero mass, or
+    /// of an infinite angular inertia, is zero.
+    fn invert(&self) -> Self;
+
+    /// Tests if this angular inertia is equal to `other` up to an absolute or a relative
+    /// tolerance. See `math::approx_eq`.
+    fn approx_eq(&self, other: &Self, abs_tol: N, rel_tol: N) -> bool;
+}
+
+impl<N: Real> AngularInertia<N, Vector2<N>, UnitComplex<N>> for N {
+    #[inline]
+    fn rotate(&self, _: &UnitComplex<N>) -> N {
+        *self
+    }
+
+    #[inline]
+    fn translate(&self, mass: N, offset: &Vector2<N>) -> N {
+        *self + mass * na::norm_squared(offset)
+    }
+
+    #[inline]
+    fn invert(&self) -> N {
+        if self.is_zero() || !is_finite(*self) {
+            N::zero()
+        }
+        else {
+            N::one() / *self
+        }
+    }
+
+    #[inline]
+    fn approx_eq(&self, other: &N, abs_tol: N, rel_tol: N) -> bool {
+        approx_eq::approx_eq(*self, *other, abs_tol, rel_tol)
+    }
+}
+
+impl<N: Real> AngularInertia<N, Vector3<N>, UnitQuaternion<N>> for Matrix3<N> {
+    #[inline]
+    fn rotate(&self, rot: &UnitQuaternion<N>) -> Matrix3<N> {
+        let rot = rot.to_rotation_matrix().unwrap();
+
+        rot * *self * rot.transpose()
+    }
+
+    #[inline]
+    fn translate(&self, mass: N, offset: &Vector3<N>) -> Matrix3<N> {
+        let diag = Matrix3::from_diagonal_element(na::norm_squared(offset));
+
+        *self + (diag - *offset * offset.transpose()) * mass
+    }
+
+    #[inline]
+    fn invert(&self) -> Matrix3<N> {
+        if self.iter().any(|e| !is_finite(*e)) {
+            return na::zero();
+        }
+
+        self.try_inverse().unwrap_or_else(na::zero)
+    }
+
+    #[inline]
+    fn approx_eq(&self, other: &Matrix3<N>, abs_tol: N, rel_tol: N) -> bool {
+        // Compare the Frobenius norms, like vectors.
+        let diff = (*self - *other).norm();
+
+        diff <= abs_tol || diff <= rel_tol * self.norm().max(other.norm())
+    }
+}
+
+// Both `inf - inf` and `NaN - NaN` are NaN.
+#[inline]
+fn is_finite<N: Real>(x: N) -> bool {
+    x - x == N::zero()
+}
+
+/// Computes the angular inertia `inertia` expressed in a frame rotated by `rot`, i.e.,
+/// `rot * inertia * rot^T` in 3D.
+#[inline]
+pub fn rotate_inertia<N, V, R, I: AngularInertia<N, V, R>>(inertia: &I, rot: &R) -> I {
+    inertia.rotate(rot)
+}
+
+/// Moves the angular inertia of an object of mass `mass` from its center of mass to the point at
+/// `offset` from it, using the parallel-axis theorem.
+#[inline]
+pub fn translate_inertia<N, V, R, I: AngularInertia<N, V, R>>(inertia: &I, mass: N, offset: &V) -> I {
+    inertia.translate(mass, offset)
+}
+
+/// The inverse of the angular inertia `inertia`, or zero if it is singular or infinite.
+#[inline]
+pub fn inverse_inertia<N, V, R, I: AngularInertia<N, V, R>>(inertia: &I) -> I {
+    inertia.invert()
+}
+
+/// The principal moments and axes of a 3D angular inertia.
+///
+/// Returns the moments `d` and the rotation `rot` such that `inertia = rot * diag(d) * rot^T`,
+/// i.e., the columns of the rotation matrix of `rot` are the principal axes. The symmetric
+/// matrix `inertia` is diagonalized with the cyclic Jacobi eigenvalue algorithm.
+pub fn principal_inertia<N: Real>(inertia: &Matrix3<N>) -> (Vector3<N>, UnitQuaternion<N>) {
+    let mut a = *inertia;
+    let mut v = Matrix3::<N>::identity();
+
+    let eps = N::default_epsilon();
+
+    for _ in 0 .. 50 {
+        let off = a[(0, 1)] * a[(0, 1)] + a[(0, 2)] * a[(0, 2)] + a[(1, 2)] * a[(1, 2)];
+
+        if off <= eps * eps * a.norm_squared() {
+            break;
+        }
+
+        for &(p, q) in [ (0, 1), (0, 2), (1, 2) ].iter() {
+            if a[(p, q)].is_zero() {
+                continue;
+            }
+
+            // The Jacobi rotation zeroing `a[(p, q)]`.
+            let theta = (a[(q, q)] - a[(p, p)]) / (a[(p, q)] * na::convert(2.0f64));
+            let t     = theta.signum() / (theta.abs() + (theta * theta + N::one()).sqrt());
+            let c     = N::one() / (t * t + N::one()).sqrt();
+            let s     = t * c;
+
+            let mut rot = Matrix3::identity();
+            rot[(p, p)] = c;
+            rot[(q, q)] = c;
+            rot[(p, q)] = s;
+            rot[(q, p)] = -s;
+
+            a = rot.transpose() * a * rot;
+            v = v * rot;
+        }
+    }
+
+    // Make the principal axes a right-handed frame.
+    if v.determinant() < N::zero() {
+        for i in 0 .. 3 {
+            v[(i, 2)] = -v[(i, 2)];
+        }
+    }
+
+    let moments = Vector3::new(a[(0, 0)], a[(1, 1)], a[(2, 2)]);
+    let rot     = UnitQuaternion::from_rotation_matrix(&Rotation3::from_matrix_unchecked(v));
+
+    (moments, rot)
+}
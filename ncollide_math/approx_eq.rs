@@ -0,0 +1,32 @@
+use alga::general::Real;
+use na;
+
+use vector::Vector;
+use angular_inertia::AngularInertia;
+
+/// Tests if two scalars are equal up to an absolute or a relative tolerance.
+///
+/// This returns `true` if `|a - b| <= abs_tol` or `|a - b| <= rel_tol * max(|a|, |b|)`. The
+/// absolute tolerance handles values close to zero while the relative one handles large values.
+#[inline]
+pub fn approx_eq<N: Real>(a: N, b: N, abs_tol: N, rel_tol: N) -> bool {
+    let diff = (a - b).abs();
+
+    a == b || diff <= abs_tol || diff <= rel_tol * a.abs().max(b.abs())
+}
+
+/// Tests if two vectors are equal up to an absolute or a relative tolerance on the norm of their
+/// difference.
+#[inline]
+pub fn approx_eq_vector<V: Vector>(a: &V, b: &V, abs_tol: V::Real, rel_tol: V::Real) -> bool {
+    let diff = na::norm(&(*a - *b));
+
+    diff <= abs_tol || diff <= rel_tol * na::norm(a).max(na::norm(b))
+}
+
+/// Tests if two angular inertias are equal up to an absolute or a relative tolerance.
+#[inline]
+pub fn approx_eq_inertia<N, V, R, I>(a: &I, b: &I, abs_tol: N, rel_tol: N) -> bool
+    where I: AngularInertia<N, V, R> {
+    a.approx_eq(b, abs_tol, rel_tol)
+}
@@ -10,7 +10,13 @@ extern crate nalgebra as na;
 pub use point::Point;
 pub use vector::Vector;
 pub use isometry::Isometry;
+pub use pose::{Pose, Pose2, Pose3};
+pub use angular_inertia::{AngularInertia, rotate_inertia, translate_inertia, inverse_inertia, principal_inertia};
+pub use approx_eq::{approx_eq, approx_eq_vector, approx_eq_inertia};
 
 mod point;
 mod vector;
 mod isometry;
+mod pose;
+mod angular_inertia;
+mod approx_eq;
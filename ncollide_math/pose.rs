@@ -0,0 +1,58 @@
+use alga::general::Real;
+use na::{Vector2, Vector3, Translation2, Translation3, UnitComplex, UnitQuaternion, Isometry2, Isometry3};
+
+/// A lightweight position and orientation, convertible from and to nalgebra isometries.
+///
+/// This allows downstream crates to describe positions without naming nalgebra isometries
+/// directly. Isometries are still used internally by ncollide.
+#[derive(PartialEq, Debug, Clone, Copy, RustcEncodable, RustcDecodable)]
+pub struct Pose<V, R> {
+    /// The translational part of this pose.
+    pub translation: V,
+    /// The rotational part of this pose.
+    pub rotation: R
+}
+
+impl<V, R> Pose<V, R> {
+    /// Creates a new pose from its translational and rotational parts.
+    #[inline]
+    pub fn new(translation: V, rotation: R) -> Pose<V, R> {
+        Pose {
+            translation: translation,
+            rotation:    rotation
+        }
+    }
+}
+
+/// A 2D pose.
+pub type Pose2<N> = Pose<Vector2<N>, UnitComplex<N>>;
+/// A 3D pose.
+pub type Pose3<N> = Pose<Vector3<N>, UnitQuaternion<N>>;
+
+impl<N: Real> From<Isometry2<N>> for Pose2<N> {
+    #[inline]
+    fn from(isometry: Isometry2<N>) -> Pose2<N> {
+        Pose::new(isometry.translation.vector, isometry.rotation)
+    }
+}
+
+impl<N: Real> Into<Isometry2<N>> for Pose2<N> {
+    #[inline]
+    fn into(self) -> Isometry2<N> {
+        Isometry2::from_parts(Translation2::from_vector(self.translation), self.rotation)
+    }
+}
+
+impl<N: Real> From<Isometry3<N>> for Pose3<N> {
+    #[inline]
+    fn from(isometry: Isometry3<N>) -> Pose3<N> {
+        Pose::new(isometry.translation.vector, isometry.rotation)
+    }
+}
+
+impl<N: Real> Into<Isometry3<N>> for Pose3<N> {
+    #[inline]
+    fn into(self) -> Isometry3<N> {
+        Isometry3::from_parts(Translation3::from_vector(self.translation), self.rotation)
+    }
+}
@@ -297,6 +297,17 @@ impl<P, BV, T> BroadPhase<P, BV, T> for DBVTBroadPhase<P, BV, T>
     }
 
     fn deferred_set_bounding_volume(&mut self, uid: usize, bounding_volume: BV) {
+        if !self.proxies.contains_key(uid) {
+            // The object might not have been added yet.
+            for to_add in self.to_add.iter_mut() {
+                if to_add.0 == uid {
+                    to_add.1 = bounding_volume.clone();
+                }
+            }
+
+            return;
+        }
+
         if let Some(proxy_key) = self.proxies.get_fast_key(uid) {
             let proxy = self.proxies.get_fast_mut(&proxy_key).unwrap();
 
@@ -4,11 +4,13 @@
 pub use self::broad_phase::BroadPhase;
 pub use self::brute_force_broad_phase::BruteForceBroadPhase;
 pub use self::dbvt_broad_phase::DBVTBroadPhase;
+pub use self::sweep_and_prune::SweepAndPrune;
 pub use self::broad_phase_pair_filter::{BroadPhasePairFilter, BroadPhasePairFilters};
 
 #[doc(hidden)]
 pub mod broad_phase;
 mod brute_force_broad_phase;
 mod dbvt_broad_phase;
+mod sweep_and_prune;
 #[doc(hidden)]
 pub mod broad_phase_pair_filter;
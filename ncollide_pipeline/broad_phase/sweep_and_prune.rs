@@ -0,0 +1,285 @@
+use alga::general::Id;
+use na;
+use utils::data::uid_remap::{UidRemap, FastKey};
+use utils::data::pair::{Pair, PairTWHash};
+use utils::data::hash_map::HashMap;
+use math::Point;
+use geometry::bounding_volume::{BoundingVolume, AABB};
+use geometry::query::{Ray, RayCast, PointQuery};
+use broad_phase::BroadPhase;
+
+#[derive(Clone, Copy)]
+struct SAPEndpoint<N> {
+    value:  N,
+    key:    FastKey,
+    is_min: bool
+}
+
+impl<N: PartialOrd> SAPEndpoint<N> {
+    // Minimum endpoints are sorted before maximum endpoints with the same value so that touching
+    // AABBs are considered as overlapping.
+    #[inline]
+    fn is_after(&self, other: &SAPEndpoint<N>) -> bool {
+        self.value > other.value || (self.value == other.value && !self.is_min && other.is_min)
+    }
+}
+
+/// Broad phase based on the Sweep-And-Prune algorithm.
+///
+/// It maintains, for each axis, the sorted list of the endpoints of the AABBs of all the objects.
+/// After some objects moved, the lists are sorted again by insertion sort and proximities start
+/// (resp. stop) whenever a minimum endpoint crosses a maximum endpoint toward (resp. away from)
+/// the lower values. Thus, an update costs `O(n + s)` per axis, with `n` the number of objects and
+/// `s` the number of endpoint swaps. This is close to linear when the objects move coherently,
+/// but `s` degrades to `O(n²)` in the worst case, e.g., when all the objects are added at once or
+/// teleported. Queries with a bounding volume, a ray or a point test every object.
+pub struct SweepAndPrune<P: Point, T> {
+    proxies:           UidRemap<(AABB<P>, T)>,
+    endpoints:         Vec<Vec<SAPEndpoint<P::Real>>>, // One sorted list per axis.
+    pairs:             HashMap<Pair, (), PairTWHash>,
+    margin:            P::Real, // The margin added to each bounding volume.
+    recompute_all:     bool,
+    to_remove:         Vec<usize>,
+    to_add:            Vec<(usize, AABB<P>, T)>,
+    to_update:         Vec<(FastKey, AABB<P>)>,
+
+    // Just to avoid dynamic allocations.
+    pairs_to_remove:   Vec<Pair>,
+    active:            Vec<FastKey>
+}
+
+impl<P: Point, T> SweepAndPrune<P, T> {
+    /// Creates a new broad phase based on the Sweep-And-Prune algorithm.
+    pub fn new(margin: P::Real, small_keys: bool) -> SweepAndPrune<P, T> {
+        SweepAndPrune {
+            proxies:         UidRemap::new(small_keys),
+            endpoints:       (0 .. na::dimension::<P::Vector>()).map(|_| Vec::new()).collect(),
+            pairs:           HashMap::new(PairTWHash::new()),
+            margin:          margin,
+            recompute_all:   false,
+            to_remove:       Vec::new(),
+            to_add:          Vec::new(),
+            to_update:       Vec::new(),
+            pairs_to_remove: Vec::new(),
+            active:          Vec::new()
+        }
+    }
+
+    /// Number of interferences detected by this broad phase.
+    #[inline]
+    pub fn num_interferences(&self) -> usize {
+        self.pairs.len()
+    }
+
+    // Sorts the endpoints of the `axis`-th axis and reports the proximities started or stopped by
+    // each swap.
+    fn sort_axis(&mut self,
+                 axis:            usize,
+                 allow_proximity: &mut FnMut(&T, &T) -> bool,
+                 handler:         &mut FnMut(&T, &T, bool)) {
+        let endpoints = &mut self.endpoints[axis];
+
+        for i in 1 .. endpoints.len() {
+            let mut j = i;
+
+            while j > 0 && endpoints[j - 1].is_after(&endpoints[j]) {
+                let left  = endpoints[j - 1];
+                let right = endpoints[j];
+
+                if right.is_min && !left.is_min {
+                    // The objects may start overlapping.
+                    let proxy1 = &self.proxies[right.key];
+                    let proxy2 = &self.proxies[left.key];
+
+                    if proxy1.0.intersects(&proxy2.0) && allow_proximity(&proxy1.1, &proxy2.1) {
+                        if self.pairs.insert(Pair::new(right.key, left.key), ()) {
+                            handler(&proxy1.1, &proxy2.1, true)
+                        }
+                    }
+                }
+                else if !right.is_min && left.is_min && right.key != left.key {
+                    // The objects stop overlapping.
+                    if self.pairs.remove(&Pair::new(right.key, left.key)) {
+                        handler(&self.proxies[right.key].1, &self.proxies[left.key].1, false)
+                    }
+                }
+
+                endpoints.swap(j - 1, j);
+                j = j - 1;
+            }
+        }
+    }
+
+    // Re-detects all the proximities by sweeping the first axis.
+    fn recompute_proximities(&mut self,
+                             allow_proximity: &mut FnMut(&T, &T) -> bool,
+                             handler:         &mut FnMut(&T, &T, bool)) {
+        for elt in self.pairs.elements().iter() {
+            let proxy1 = &self.proxies[elt.key.first];
+            let proxy2 = &self.proxies[elt.key.second];
+
+            if !allow_proximity(&proxy1.1, &proxy2.1) {
+                handler(&proxy1.1, &proxy2.1, false);
+                self.pairs_to_remove.push(elt.key);
+            }
+        }
+
+        for pair in self.pairs_to_remove.drain(..) {
+            let _ = self.pairs.remove(&pair);
+        }
+
+        for endpoint in self.endpoints[0].iter() {
+            if endpoint.is_min {
+                let proxy1 = &self.proxies[endpoint.key];
+
+                for key2 in self.active.iter() {
+                    let proxy2 = &self.proxies[*key2];
+
+                    if proxy1.0.intersects(&proxy2.0) && allow_proximity(&proxy1.1, &proxy2.1) {
+                        if self.pairs.insert(Pair::new(endpoint.key, *key2), ()) {
+                            handler(&proxy1.1, &proxy2.1, true)
+                        }
+                    }
+                }
+
+                self.active.push(endpoint.key);
+            }
+            else {
+                let key = endpoint.key;
+                self.active.retain(|k| *k != key);
+            }
+        }
+
+        self.active.clear();
+    }
+}
+
+impl<P: Point, T> BroadPhase<P, AABB<P>, T> for SweepAndPrune<P, T> {
+    #[inline]
+    fn deferred_add(&mut self, uid: usize, bv: AABB<P>, data: T) {
+        self.to_add.push((uid, bv, data));
+    }
+
+    fn deferred_remove(&mut self, uid: usize) {
+        if self.proxies.get_fast_key(uid).is_some() {
+            self.to_remove.push(uid);
+        }
+    }
+
+    fn deferred_set_bounding_volume(&mut self, uid: usize, bv: AABB<P>) {
+        if !self.proxies.contains_key(uid) {
+            // The object might not have been added yet.
+            for to_add in self.to_add.iter_mut() {
+                if to_add.0 == uid {
+                    to_add.1 = bv.clone();
+                }
+            }
+
+            return;
+        }
+
+        if let Some(key) = self.proxies.get_fast_key(uid) {
+            if !self.proxies[key].0.contains(&bv) {
+                self.to_update.push((key, bv.loosened(self.margin)));
+            }
+        }
+    }
+
+    fn deferred_recompute_all_proximities(&mut self) {
+        self.recompute_all = true;
+    }
+
+    fn update(&mut self, allow_proximity: &mut FnMut(&T, &T) -> bool, handler: &mut FnMut(&T, &T, bool)) {
+        /*
+         * Perform removals.
+         */
+        for uid in self.to_remove.drain(..) {
+            if let Some(key) = self.proxies.get_fast_key(uid) {
+                for elt in self.pairs.elements().iter() {
+                    if elt.key.first == key || elt.key.second == key {
+                        handler(&self.proxies[elt.key.first].1, &self.proxies[elt.key.second].1, false);
+                        self.pairs_to_remove.push(elt.key);
+                    }
+                }
+
+                for pair in self.pairs_to_remove.drain(..) {
+                    let _ = self.pairs.remove(&pair);
+                }
+
+                for endpoints in self.endpoints.iter_mut() {
+                    endpoints.retain(|e| e.key != key);
+                }
+
+                let _ = self.proxies.remove(uid);
+            }
+        }
+
+        /*
+         * Perform additions.
+         */
+        for (uid, bv, data) in self.to_add.drain(..) {
+            let lbv      = bv.loosened(self.margin);
+            let (key, _) = self.proxies.insert(uid, (lbv.clone(), data));
+
+            for (i, endpoints) in self.endpoints.iter_mut().enumerate() {
+                endpoints.push(SAPEndpoint { value: lbv.mins()[i], key: key, is_min: true });
+                endpoints.push(SAPEndpoint { value: lbv.maxs()[i], key: key, is_min: false });
+            }
+        }
+
+        /*
+         * Perform updates.
+         */
+        if !self.to_update.is_empty() {
+            for (key, bv) in self.to_update.drain(..) {
+                if let Some(proxy) = self.proxies.get_fast_mut(&key) {
+                    proxy.0 = bv;
+                }
+            }
+
+            for (i, endpoints) in self.endpoints.iter_mut().enumerate() {
+                for endpoint in endpoints.iter_mut() {
+                    let bv = &self.proxies[endpoint.key].0;
+
+                    endpoint.value = if endpoint.is_min { bv.mins()[i] } else { bv.maxs()[i] };
+                }
+            }
+        }
+
+        /*
+         * Sort the endpoints and report the proximity changes.
+         */
+        for axis in 0 .. self.endpoints.len() {
+            self.sort_axis(axis, allow_proximity, handler);
+        }
+
+        if self.recompute_all {
+            self.recompute_all = false;
+            self.recompute_proximities(allow_proximity, handler);
+        }
+    }
+
+    fn interferences_with_bounding_volume<'a>(&'a self, bv: &AABB<P>, out: &mut Vec<&'a T>) {
+        for proxy in self.proxies.values() {
+            if proxy.0.intersects(bv) {
+                out.push(&proxy.1)
+            }
+        }
+    }
+
+    fn interferences_with_ray<'a>(&'a self, ray: &Ray<P>, out: &mut Vec<&'a T>) {
+        for proxy in self.proxies.values() {
+            if proxy.0.intersects_ray(&Id::new(), ray) {
+                out.push(&proxy.1)
+            }
+        }
+    }
+
+    fn interferences_with_point<'a>(&'a self, point: &P, out: &mut Vec<&'a T>) {
+        for proxy in self.proxies.values() {
+            if proxy.0.contains_point(&Id::new(), point) {
+                out.push(&proxy.1)
+            }
+        }
+    }
+}
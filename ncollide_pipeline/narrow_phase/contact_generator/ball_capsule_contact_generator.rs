@@ -0,0 +1,121 @@
+use std::marker::PhantomData;
+
+use alga::linear::Translation;
+use math::{Point, Isometry};
+use geometry::shape::{Shape, Ball, Capsule};
+use geometry::query::Contact;
+use geometry::query::contacts_internal;
+use narrow_phase::{ContactGenerator, ContactDispatcher};
+
+
+/// Collision detector between a ball and a capsule.
+#[derive(Clone)]
+pub struct BallCapsuleContactGenerator<P: Point, M> {
+    contact:  Option<Contact<P>>,
+    mat_type: PhantomData<M>
+}
+
+impl<P: Point, M> BallCapsuleContactGenerator<P, M> {
+    /// Creates a new persistent collision detector between a ball and a capsule.
+    #[inline]
+    pub fn new() -> BallCapsuleContactGenerator<P, M> {
+        BallCapsuleContactGenerator {
+            contact:  None,
+            mat_type: PhantomData
+        }
+    }
+}
+
+/// Collision detector between a capsule and a ball.
+#[derive(Clone)]
+pub struct CapsuleBallContactGenerator<P: Point, M> {
+    contact:  Option<Contact<P>>,
+    mat_type: PhantomData<M>
+}
+
+impl<P: Point, M> CapsuleBallContactGenerator<P, M> {
+    /// Creates a new persistent collision detector between a capsule and a ball.
+    #[inline]
+    pub fn new() -> CapsuleBallContactGenerator<P, M> {
+        CapsuleBallContactGenerator {
+            contact:  None,
+            mat_type: PhantomData
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> ContactGenerator<P, M> for BallCapsuleContactGenerator<P, M> {
+    #[inline]
+    fn update(&mut self,
+              _:          &ContactDispatcher<P, M>,
+              ma:         &M,
+              a:          &Shape<P, M>,
+              mb:         &M,
+              b:          &Shape<P, M>,
+              prediction: P::Real)
+              -> bool {
+        if let (Some(ball), Some(capsule)) = (a.as_shape::<Ball<P::Real>>(), b.as_shape::<Capsule<P::Real>>()) {
+            let center   = P::from_coordinates(ma.translation().to_vector());
+            self.contact = contacts_internal::ball_against_capsule(&center, ball, mb, capsule, prediction);
+
+            true
+        }
+        else {
+            false
+        }
+    }
+
+    #[inline]
+    fn num_contacts(&self) -> usize {
+        match self.contact {
+            None    => 0,
+            Some(_) => 1
+        }
+    }
+
+    #[inline]
+    fn contacts(&self, out_contacts: &mut Vec<Contact<P>>) {
+        match self.contact {
+            Some(ref c) => out_contacts.push(c.clone()),
+            None        => ()
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> ContactGenerator<P, M> for CapsuleBallContactGenerator<P, M> {
+    #[inline]
+    fn update(&mut self,
+              _:          &ContactDispatcher<P, M>,
+              ma:         &M,
+              a:          &Shape<P, M>,
+              mb:         &M,
+              b:          &Shape<P, M>,
+              prediction: P::Real)
+              -> bool {
+        if let (Some(capsule), Some(ball)) = (a.as_shape::<Capsule<P::Real>>(), b.as_shape::<Ball<P::Real>>()) {
+            let center   = P::from_coordinates(mb.translation().to_vector());
+            self.contact = contacts_internal::capsule_against_ball(ma, capsule, &center, ball, prediction);
+
+            true
+        }
+        else {
+            false
+        }
+    }
+
+    #[inline]
+    fn num_contacts(&self) -> usize {
+        match self.contact {
+            None    => 0,
+            Some(_) => 1
+        }
+    }
+
+    #[inline]
+    fn contacts(&self, out_contacts: &mut Vec<Contact<P>>) {
+        match self.contact {
+            Some(ref c) => out_contacts.push(c.clone()),
+            None        => ()
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use std::marker::PhantomData;
+
+use alga::linear::Translation;
+use math::{Point, Isometry};
+use geometry::shape::{Shape, Ball, Cuboid};
+use geometry::query::Contact;
+use geometry::query::contacts_internal;
+use narrow_phase::{ContactGenerator, ContactDispatcher};
+
+
+/// Collision detector between a ball and a cuboid.
+#[derive(Clone)]
+pub struct BallCuboidContactGenerator<P: Point, M> {
+    contact:  Option<Contact<P>>,
+    mat_type: PhantomData<M>
+}
+
+impl<P: Point, M> BallCuboidContactGenerator<P, M> {
+    /// Creates a new persistent collision detector between a ball and a cuboid.
+    #[inline]
+    pub fn new() -> BallCuboidContactGenerator<P, M> {
+        BallCuboidContactGenerator {
+            contact:  None,
+            mat_type: PhantomData
+        }
+    }
+}
+
+/// Collision detector between a cuboid and a ball.
+#[derive(Clone)]
+pub struct CuboidBallContactGenerator<P: Point, M> {
+    contact:  Option<Contact<P>>,
+    mat_type: PhantomData<M>
+}
+
+impl<P: Point, M> CuboidBallContactGenerator<P, M> {
+    /// Creates a new persistent collision detector between a cuboid and a ball.
+    #[inline]
+    pub fn new() -> CuboidBallContactGenerator<P, M> {
+        CuboidBallContactGenerator {
+            contact:  None,
+            mat_type: PhantomData
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> ContactGenerator<P, M> for BallCuboidContactGenerator<P, M> {
+    #[inline]
+    fn update(&mut self,
+              _:          &ContactDispatcher<P, M>,
+              ma:         &M,
+              a:          &Shape<P, M>,
+              mb:         &M,
+              b:          &Shape<P, M>,
+              prediction: P::Real)
+              -> bool {
+        if let (Some(ball), Some(cuboid)) = (a.as_shape::<Ball<P::Real>>(), b.as_shape::<Cuboid<P::Vector>>()) {
+            let center   = P::from_coordinates(ma.translation().to_vector());
+            self.contact = contacts_internal::ball_against_cuboid(&center, ball, mb, cuboid, prediction);
+
+            true
+        }
+        else {
+            false
+        }
+    }
+
+    #[inline]
+    fn num_contacts(&self) -> usize {
+        match self.contact {
+            None    => 0,
+            Some(_) => 1
+        }
+    }
+
+    #[inline]
+    fn contacts(&self, out_contacts: &mut Vec<Contact<P>>) {
+        match self.contact {
+            Some(ref c) => out_contacts.push(c.clone()),
+            None        => ()
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> ContactGenerator<P, M> for CuboidBallContactGenerator<P, M> {
+    #[inline]
+    fn update(&mut self,
+              _:          &ContactDispatcher<P, M>,
+              ma:         &M,
+              a:          &Shape<P, M>,
+              mb:         &M,
+              b:          &Shape<P, M>,
+              prediction: P::Real)
+              -> bool {
+        if let (Some(cuboid), Some(ball)) = (a.as_shape::<Cuboid<P::Vector>>(), b.as_shape::<Ball<P::Real>>()) {
+            let center   = P::from_coordinates(mb.translation().to_vector());
+            self.contact = contacts_internal::cuboid_against_ball(ma, cuboid, &center, ball, prediction);
+
+            true
+        }
+        else {
+            false
+        }
+    }
+
+    #[inline]
+    fn num_contacts(&self) -> usize {
+        match self.contact {
+            None    => 0,
+            Some(_) => 1
+        }
+    }
+
+    #[inline]
+    fn contacts(&self, out_contacts: &mut Vec<Contact<P>>) {
+        match self.contact {
+            Some(ref c) => out_contacts.push(c.clone()),
+            None        => ()
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use std::marker::PhantomData;
+
+use math::{Point, Isometry};
+use geometry::shape::{Shape, Capsule};
+use geometry::query::Contact;
+use geometry::query::contacts_internal;
+use narrow_phase::{ContactGenerator, ContactDispatcher};
+
+
+/// Contact manifold generator between two capsules.
+///
+/// The manifold is recomputed at each update, see
+/// `contacts_internal::capsule_against_capsule_manifold`. It has two contacts if the capsules are
+/// parallel and side by side.
+#[derive(Clone)]
+pub struct CapsuleCapsuleContactGenerator<P: Point, M> {
+    contacts: Vec<Contact<P>>,
+    mat_type: PhantomData<M>
+}
+
+impl<P: Point, M> CapsuleCapsuleContactGenerator<P, M> {
+    /// Creates a new persistent contact manifold generator between two capsules.
+    #[inline]
+    pub fn new() -> CapsuleCapsuleContactGenerator<P, M> {
+        CapsuleCapsuleContactGenerator {
+            contacts: Vec::new(),
+            mat_type: PhantomData
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> ContactGenerator<P, M> for CapsuleCapsuleContactGenerator<P, M> {
+    fn update(&mut self,
+              _:          &ContactDispatcher<P, M>,
+              ma:         &M,
+              a:          &Shape<P, M>,
+              mb:         &M,
+              b:          &Shape<P, M>,
+              prediction: P::Real)
+              -> bool {
+        if let (Some(a), Some(b)) = (a.as_shape::<Capsule<P::Real>>(), b.as_shape::<Capsule<P::Real>>()) {
+            self.contacts.clear();
+            contacts_internal::capsule_against_capsule_manifold(ma, a, mb, b, prediction, &mut self.contacts);
+
+            true
+        }
+        else {
+            false
+        }
+    }
+
+    #[inline]
+    fn num_contacts(&self) -> usize {
+        self.contacts.len()
+    }
+
+    #[inline]
+    fn contacts(&self, out_contacts: &mut Vec<Contact<P>>) {
+        out_contacts.extend(self.contacts.iter().cloned())
+    }
+}
@@ -4,16 +4,24 @@ use utils::data::hash_map::HashMap;
 use utils::data::hash::UintTWHash;
 use geometry::bounding_volume::{self, BoundingVolume};
 use geometry::partitioning::BoundingVolumeInterferencesCollector;
-use geometry::shape::{Shape, CompositeShape};
+use geometry::shape::{Shape, CompositeShape, TriMesh, FeatureId};
 use geometry::query::Contact;
+use geometry::query::contacts_internal;
 use narrow_phase::{ContactGenerator, ContactDispatcher, ContactAlgorithm};
 
 
 /// Collision detector between a concave shape and another shape.
+///
+/// The contacts with a `TriMesh` located on its internal edges are corrected as by
+/// `contacts_internal::trimesh_against_shape_with_features`. The contacts are identified as by
+/// `contacts_internal::composite_shape_against_shape_with_features`: by the part index of the
+/// composite shape, and by the feature of the other shape if it is a support map.
 pub struct CompositeShapeShapeContactGenerator<P: Point, M> {
     sub_detectors: HashMap<usize, ContactAlgorithm<P, M>, UintTWHash>,
     to_delete:     Vec<usize>,
-    interferences: Vec<usize>
+    interferences: Vec<usize>,
+    contacts:      Vec<(Contact<P>, (FeatureId, FeatureId))>,
+    part_contacts: Vec<Contact<P>>
 }
 
 impl<P: Point, M> CompositeShapeShapeContactGenerator<P, M> {
@@ -22,7 +30,9 @@ impl<P: Point, M> CompositeShapeShapeContactGenerator<P, M> {
         CompositeShapeShapeContactGenerator {
             sub_detectors: HashMap::new_with_capacity(5, UintTWHash::new()),
             to_delete:     Vec::new(),
-            interferences: Vec::new()
+            interferences: Vec::new(),
+            contacts:      Vec::new(),
+            part_contacts: Vec::new()
         }
     }
 }
@@ -32,6 +42,7 @@ impl<P: Point, M: Isometry<P>> CompositeShapeShapeContactGenerator<P, M> {
                  dispatcher: &ContactDispatcher<P, M>,
                  m1:         &M,
                  g1:         &CompositeShape<P, M>,
+                 mesh:       Option<&TriMesh<P>>,
                  m2:         &M,
                  g2:         &Shape<P, M>,
                  prediction: P::Real,
@@ -93,6 +104,42 @@ impl<P: Point, M: Isometry<P>> CompositeShapeShapeContactGenerator<P, M> {
         }
 
         self.to_delete.clear();
+
+        // Collect the contacts with their features, with internal edges correction for meshes.
+        self.contacts.clear();
+
+        for detector in self.sub_detectors.elements().iter() {
+            detector.value.contacts(&mut self.part_contacts);
+
+            for mut c in self.part_contacts.drain(..) {
+                if swap {
+                    c.flip()
+                }
+
+                let feature2 = match g2.as_support_map() {
+                    Some(sm) => sm.support_feature_id(m2, &[c.world2]),
+                    None     => FeatureId::Unknown
+                };
+
+                let corrected = match mesh {
+                    Some(mesh) => contacts_internal::trimesh_internal_edges_correction(
+                        m1, mesh, detector.key, m2, g2, prediction, &c, feature2),
+                    None => Some((c, feature2))
+                };
+
+                if let Some((mut c, feature2)) = corrected {
+                    let feature1 = FeatureId::Face(detector.key);
+
+                    if swap {
+                        c.flip();
+                        self.contacts.push((c, (feature2, feature1)))
+                    }
+                    else {
+                        self.contacts.push((c, (feature1, feature2)))
+                    }
+                }
+            }
+        }
     }
 }
 
@@ -120,7 +167,7 @@ impl<P: Point, M: Isometry<P>> ContactGenerator<P, M> for CompositeShapeShapeCon
               prediction: P::Real)
               -> bool {
         if let Some(cs) = a.as_composite_shape() {
-            self.do_update(d, ma, cs, mb, b, prediction, false);
+            self.do_update(d, ma, cs, a.as_shape::<TriMesh<P>>(), mb, b, prediction, false);
 
             true
         }
@@ -130,19 +177,15 @@ impl<P: Point, M: Isometry<P>> ContactGenerator<P, M> for CompositeShapeShapeCon
     }
 
     fn num_contacts(&self) -> usize {
-        let mut res = 0;
-
-        for detector in self.sub_detectors.elements().iter() {
-            res = res + detector.value.num_contacts()
-        }
-
-        res
+        self.contacts.len()
     }
 
     fn contacts(&self, out: &mut Vec<Contact<P>>) {
-        for detector in self.sub_detectors.elements().iter() {
-            detector.value.contacts(out);
-        }
+        out.extend(self.contacts.iter().map(|c| c.0.clone()))
+    }
+
+    fn contacts_with_features(&self, out: &mut Vec<(Contact<P>, (FeatureId, FeatureId))>) {
+        out.extend(self.contacts.iter().cloned())
     }
 }
 
@@ -156,7 +199,7 @@ impl<P: Point, M: Isometry<P>> ContactGenerator<P, M> for ShapeCompositeShapeCon
               prediction: P::Real)
               -> bool {
         if let Some(cs) = b.as_composite_shape() {
-            self.sub_detector.do_update(d, mb, cs, ma, a, prediction, true);
+            self.sub_detector.do_update(d, mb, cs, b.as_shape::<TriMesh<P>>(), ma, a, prediction, true);
 
             true
         }
@@ -172,4 +215,8 @@ impl<P: Point, M: Isometry<P>> ContactGenerator<P, M> for ShapeCompositeShapeCon
     fn contacts(&self, out: &mut Vec<Contact<P>>) {
         self.sub_detector.contacts(out)
     }
+
+    fn contacts_with_features(&self, out: &mut Vec<(Contact<P>, (FeatureId, FeatureId))>) {
+        self.sub_detector.contacts_with_features(out)
+    }
 }
@@ -1,5 +1,5 @@
-use geometry::shape::Shape;
-use geometry::query::Contact;
+use geometry::shape::{Shape, FeatureId};
+use geometry::query::{Contact, PersistentManifold};
 use math::Point;
 
 /// Trait implemented algorithms that compute contact points, normals and penetration depths.
@@ -21,10 +21,66 @@ pub trait ContactGenerator<P: Point, M> {
 
     /// Collects the contacts generated during the last update.
     fn contacts(&self, &mut Vec<Contact<P>>);
+
+    /// Collects the contacts generated during the last update, with the features of both shapes
+    /// they lie on.
+    ///
+    /// The features are `FeatureId::Unknown` unless this generator identifies them, e.g., the
+    /// part of a composite shape for a `CompositeShapeShapeContactGenerator`.
+    fn contacts_with_features(&self, out: &mut Vec<(Contact<P>, (FeatureId, FeatureId))>) {
+        let mut contacts = Vec::new();
+        self.contacts(&mut contacts);
+
+        out.extend(contacts.into_iter().map(|c| (c, (FeatureId::Unknown, FeatureId::Unknown))))
+    }
+
+    /// The contacts generated during the last update, matched with those of the previous updates.
+    ///
+    /// The user data of each contact is an identifier kept as long as the contact is matched.
+    /// This is `None` if this generator does not track its contacts across updates, e.g., if it
+    /// is not a `PersistentContactManifoldGenerator`.
+    #[inline]
+    fn persistent_manifold(&self) -> Option<&PersistentManifold<P, usize>> {
+        None
+    }
 }
 
 pub type ContactAlgorithm<P, M> = Box<ContactGenerator<P, M> + 'static>;
 
+impl<P: Point, M> ContactGenerator<P, M> for ContactAlgorithm<P, M> {
+    #[inline]
+    fn update(&mut self,
+              dispatcher: &ContactDispatcher<P, M>,
+              ma:         &M,
+              a:          &Shape<P, M>,
+              mb:         &M,
+              b:          &Shape<P, M>,
+              prediction: P::Real)
+              -> bool {
+        (**self).update(dispatcher, ma, a, mb, b, prediction)
+    }
+
+    #[inline]
+    fn num_contacts(&self) -> usize {
+        (**self).num_contacts()
+    }
+
+    #[inline]
+    fn contacts(&self, out_contacts: &mut Vec<Contact<P>>) {
+        (**self).contacts(out_contacts)
+    }
+
+    #[inline]
+    fn contacts_with_features(&self, out: &mut Vec<(Contact<P>, (FeatureId, FeatureId))>) {
+        (**self).contacts_with_features(out)
+    }
+
+    #[inline]
+    fn persistent_manifold(&self) -> Option<&PersistentManifold<P, usize>> {
+        (**self).persistent_manifold()
+    }
+}
+
 pub trait ContactDispatcher<P, M> {
     /// Allocate a collision algorithm corresponding to the given pair of shapes.
     fn get_contact_algorithm(&self, a: &Shape<P, M>, b: &Shape<P, M>) -> Option<ContactAlgorithm<P, M>>;
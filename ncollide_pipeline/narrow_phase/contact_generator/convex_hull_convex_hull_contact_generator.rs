@@ -0,0 +1,79 @@
+use std::marker::PhantomData;
+
+use math::{Point, Isometry};
+use geometry::shape::{Shape, ConvexHull};
+use geometry::query::{Contact, QuerySettings};
+use geometry::query::contacts_internal;
+use narrow_phase::{ContactGenerator, ContactDispatcher};
+
+
+/// Contact manifold generator between two convex hulls.
+///
+/// The full manifold is recomputed at each update by clipping the features of the hulls, see
+/// `contacts_internal::convex_hull_against_convex_hull_with_settings`.
+pub struct ConvexHullConvexHullContactGenerator<P: Point, M> {
+    contacts: Vec<Contact<P>>,
+    settings: QuerySettings<P::Real>,
+    mat_type: PhantomData<M>
+}
+
+impl<P: Point, M> Clone for ConvexHullConvexHullContactGenerator<P, M> {
+    fn clone(&self) -> ConvexHullConvexHullContactGenerator<P, M> {
+        ConvexHullConvexHullContactGenerator {
+            contacts: self.contacts.clone(),
+            settings: self.settings,
+            mat_type: PhantomData
+        }
+    }
+}
+
+impl<P: Point, M> ConvexHullConvexHullContactGenerator<P, M> {
+    /// Creates a new persistent contact manifold generator between two convex hulls.
+    #[inline]
+    pub fn new() -> ConvexHullConvexHullContactGenerator<P, M> {
+        ConvexHullConvexHullContactGenerator::new_with_settings(QuerySettings::default())
+    }
+
+    /// Creates a new contact manifold generator between two convex hulls using the given
+    /// numerical tolerances and maximum number of contacts.
+    #[inline]
+    pub fn new_with_settings(settings: QuerySettings<P::Real>) -> ConvexHullConvexHullContactGenerator<P, M> {
+        ConvexHullConvexHullContactGenerator {
+            contacts: Vec::new(),
+            settings: settings,
+            mat_type: PhantomData
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> ContactGenerator<P, M> for ConvexHullConvexHullContactGenerator<P, M> {
+    fn update(&mut self,
+              _:          &ContactDispatcher<P, M>,
+              ma:         &M,
+              a:          &Shape<P, M>,
+              mb:         &M,
+              b:          &Shape<P, M>,
+              prediction: P::Real)
+              -> bool {
+        if let (Some(a), Some(b)) = (a.as_shape::<ConvexHull<P>>(), b.as_shape::<ConvexHull<P>>()) {
+            self.contacts.clear();
+            contacts_internal::convex_hull_against_convex_hull_with_settings(ma, a, mb, b, prediction, &self.settings,
+                                                                              &mut self.contacts);
+
+            true
+        }
+        else {
+            false
+        }
+    }
+
+    #[inline]
+    fn num_contacts(&self) -> usize {
+        self.contacts.len()
+    }
+
+    #[inline]
+    fn contacts(&self, out_contacts: &mut Vec<Contact<P>>) {
+        out_contacts.extend(self.contacts.iter().cloned())
+    }
+}
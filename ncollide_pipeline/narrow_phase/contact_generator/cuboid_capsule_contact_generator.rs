@@ -0,0 +1,115 @@
+use std::marker::PhantomData;
+
+use math::{Point, Isometry};
+use geometry::shape::{Shape, Cuboid, Capsule};
+use geometry::query::Contact;
+use geometry::query::contacts_internal;
+use narrow_phase::{ContactGenerator, ContactDispatcher};
+
+
+/// Contact manifold generator between a cuboid and a capsule.
+///
+/// The manifold is recomputed at each update, see
+/// `contacts_internal::cuboid_against_capsule_manifold`. It has two contacts if the
+/// capsule lies flat against a face of the cuboid.
+#[derive(Clone)]
+pub struct CuboidCapsuleContactGenerator<P: Point, M> {
+    contacts: Vec<Contact<P>>,
+    mat_type: PhantomData<M>
+}
+
+impl<P: Point, M> CuboidCapsuleContactGenerator<P, M> {
+    /// Creates a new persistent contact manifold generator between a cuboid and a capsule.
+    #[inline]
+    pub fn new() -> CuboidCapsuleContactGenerator<P, M> {
+        CuboidCapsuleContactGenerator {
+            contacts: Vec::new(),
+            mat_type: PhantomData
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> ContactGenerator<P, M> for CuboidCapsuleContactGenerator<P, M> {
+    fn update(&mut self,
+              _:          &ContactDispatcher<P, M>,
+              ma:         &M,
+              a:          &Shape<P, M>,
+              mb:         &M,
+              b:          &Shape<P, M>,
+              prediction: P::Real)
+              -> bool {
+        if let (Some(a), Some(b)) = (a.as_shape::<Cuboid<P::Vector>>(), b.as_shape::<Capsule<P::Real>>()) {
+            self.contacts.clear();
+            contacts_internal::cuboid_against_capsule_manifold(ma, a, mb, b, prediction, &mut self.contacts);
+
+            true
+        }
+        else {
+            false
+        }
+    }
+
+    #[inline]
+    fn num_contacts(&self) -> usize {
+        self.contacts.len()
+    }
+
+    #[inline]
+    fn contacts(&self, out_contacts: &mut Vec<Contact<P>>) {
+        out_contacts.extend(self.contacts.iter().cloned())
+    }
+}
+
+
+/// Contact manifold generator between a capsule and a cuboid.
+///
+/// The manifold is recomputed at each update, see
+/// `contacts_internal::capsule_against_cuboid_manifold`. It has two contacts if the
+/// capsule lies flat against a face of the cuboid.
+#[derive(Clone)]
+pub struct CapsuleCuboidContactGenerator<P: Point, M> {
+    contacts: Vec<Contact<P>>,
+    mat_type: PhantomData<M>
+}
+
+impl<P: Point, M> CapsuleCuboidContactGenerator<P, M> {
+    /// Creates a new persistent contact manifold generator between a capsule and a cuboid.
+    #[inline]
+    pub fn new() -> CapsuleCuboidContactGenerator<P, M> {
+        CapsuleCuboidContactGenerator {
+            contacts: Vec::new(),
+            mat_type: PhantomData
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> ContactGenerator<P, M> for CapsuleCuboidContactGenerator<P, M> {
+    fn update(&mut self,
+              _:          &ContactDispatcher<P, M>,
+              ma:         &M,
+              a:          &Shape<P, M>,
+              mb:         &M,
+              b:          &Shape<P, M>,
+              prediction: P::Real)
+              -> bool {
+        if let (Some(a), Some(b)) = (a.as_shape::<Capsule<P::Real>>(), b.as_shape::<Cuboid<P::Vector>>()) {
+            self.contacts.clear();
+            contacts_internal::capsule_against_cuboid_manifold(ma, a, mb, b, prediction, &mut self.contacts);
+
+            true
+        }
+        else {
+            false
+        }
+    }
+
+    #[inline]
+    fn num_contacts(&self) -> usize {
+        self.contacts.len()
+    }
+
+    #[inline]
+    fn contacts(&self, out_contacts: &mut Vec<Contact<P>>) {
+        out_contacts.extend(self.contacts.iter().cloned())
+    }
+}
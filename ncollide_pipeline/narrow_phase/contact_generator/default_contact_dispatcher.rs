@@ -1,6 +1,8 @@
 use std::marker::PhantomData;
 use math::{Point, Isometry};
-use geometry::shape::{Shape, Ball, Plane};
+use geometry::shape::{Shape, Ball, Capsule, Cuboid, Plane, HeightField, ConvexHull, ConvexPolygon, TriMesh, Triangle,
+                      Segment};
+use geometry::query::QuerySettings;
 use geometry::query::algorithms::johnson_simplex::JohnsonSimplex;
 use narrow_phase::{
     ContactDispatcher,
@@ -9,13 +11,30 @@ use narrow_phase::{
     PlaneSupportMapContactGenerator,
     SupportMapPlaneContactGenerator,
     SupportMapSupportMapContactGenerator,
+    ConvexHullConvexHullContactGenerator,
+    BallCapsuleContactGenerator,
+    CapsuleBallContactGenerator,
+    BallCuboidContactGenerator,
+    CuboidBallContactGenerator,
+    CapsuleCapsuleContactGenerator,
+    CuboidCapsuleContactGenerator,
+    CapsuleCuboidContactGenerator,
+    CuboidCuboidContactGenerator,
     CompositeShapeShapeContactGenerator,
     ShapeCompositeShapeContactGenerator,
+    TriMeshTriMeshContactGenerator,
+    HeightFieldShapeContactGenerator,
+    ShapeHeightFieldContactGenerator,
     OneShotContactManifoldGenerator
 };
 
 /// Collision dispatcher for shapes defined by `ncollide_entities`.
+///
+/// Contact algorithms for user-defined shapes can be registered with
+/// `.register_contact_algorithm(...)`: they take precedence over the built-in algorithms.
 pub struct DefaultContactDispatcher<P: Point, M> {
+    custom:       Vec<Box<Fn(&Shape<P, M>, &Shape<P, M>) -> Option<ContactAlgorithm<P, M>> + 'static>>,
+    settings:     QuerySettings<P::Real>,
     _point_type:  PhantomData<P>,
     _matrix_type: PhantomData<M>,
 }
@@ -23,15 +42,50 @@ pub struct DefaultContactDispatcher<P: Point, M> {
 impl<P: Point, M> DefaultContactDispatcher<P, M> {
     /// Creates a new basic collision dispatcher.
     pub fn new() -> DefaultContactDispatcher<P, M> {
+        DefaultContactDispatcher::new_with_settings(QuerySettings::default())
+    }
+
+    /// Creates a new basic collision dispatcher using the given numerical tolerances for the GJK-based
+    /// algorithms.
+    pub fn new_with_settings(settings: QuerySettings<P::Real>) -> DefaultContactDispatcher<P, M> {
         DefaultContactDispatcher {
+            custom:       Vec::new(),
+            settings:     settings,
             _point_type:  PhantomData,
             _matrix_type: PhantomData,
         }
     }
 }
 
+impl<P: Point, M: Isometry<P>> DefaultContactDispatcher<P, M> {
+    /// Registers the contact algorithm built by `builder` for the pairs of shapes of types `G1`
+    /// and `G2`, in this order.
+    ///
+    /// The pair `(G2, G1)` has to be registered separately if needed. The algorithms registered
+    /// last take precedence.
+    pub fn register_contact_algorithm<G1, G2, F>(&mut self, builder: F)
+        where G1: Shape<P, M>,
+              G2: Shape<P, M>,
+              F:  Fn() -> ContactAlgorithm<P, M> + 'static {
+        self.custom.push(Box::new(move |a: &Shape<P, M>, b: &Shape<P, M>| {
+            if a.is_shape::<G1>() && b.is_shape::<G2>() {
+                Some(builder())
+            }
+            else {
+                None
+            }
+        }))
+    }
+}
+
 impl<P: Point, M: Isometry<P>> ContactDispatcher<P, M> for DefaultContactDispatcher<P, M> {
     fn get_contact_algorithm(&self, a: &Shape<P, M>, b: &Shape<P, M>) -> Option<ContactAlgorithm<P, M>> {
+        for custom in self.custom.iter().rev() {
+            if let Some(algorithm) = custom(a, b) {
+                return Some(algorithm);
+            }
+        }
+
         let a_is_ball = a.is_shape::<Ball<P::Real>>();
         let b_is_ball = b.is_shape::<Ball<P::Real>>();
 
@@ -41,7 +95,7 @@ impl<P: Point, M: Isometry<P>> ContactDispatcher<P, M> for DefaultContactDispatc
         else if a.is_shape::<Plane<P::Vector>>() && b.is_support_map() {
             let wo_manifold = PlaneSupportMapContactGenerator::<P, M>::new();
 
-            if !b_is_ball {
+            if !b_is_ball && !is_polytope(b) {
                 let manifold = OneShotContactManifoldGenerator::new(wo_manifold);
                 Some(Box::new(manifold))
             }
@@ -52,7 +106,7 @@ impl<P: Point, M: Isometry<P>> ContactDispatcher<P, M> for DefaultContactDispatc
         else if b.is_shape::<Plane<P::Vector>>() && a.is_support_map() {
             let wo_manifold = SupportMapPlaneContactGenerator::<P, M>::new();
 
-            if !a_is_ball {
+            if !a_is_ball && !is_polytope(a) {
                 let manifold = OneShotContactManifoldGenerator::new(wo_manifold);
                 Some(Box::new(manifold))
             }
@@ -60,9 +114,36 @@ impl<P: Point, M: Isometry<P>> ContactDispatcher<P, M> for DefaultContactDispatc
                 Some(Box::new(wo_manifold))
             }
         }
+        else if a_is_ball && b.is_shape::<Capsule<P::Real>>() {
+            Some(Box::new(BallCapsuleContactGenerator::<P, M>::new()))
+        }
+        else if a.is_shape::<Capsule<P::Real>>() && b_is_ball {
+            Some(Box::new(CapsuleBallContactGenerator::<P, M>::new()))
+        }
+        else if a_is_ball && b.is_shape::<Cuboid<P::Vector>>() {
+            Some(Box::new(BallCuboidContactGenerator::<P, M>::new()))
+        }
+        else if a.is_shape::<Cuboid<P::Vector>>() && b_is_ball {
+            Some(Box::new(CuboidBallContactGenerator::<P, M>::new()))
+        }
+        else if a.is_shape::<Capsule<P::Real>>() && b.is_shape::<Capsule<P::Real>>() {
+            Some(Box::new(CapsuleCapsuleContactGenerator::<P, M>::new()))
+        }
+        else if a.is_shape::<Cuboid<P::Vector>>() && b.is_shape::<Capsule<P::Real>>() {
+            Some(Box::new(CuboidCapsuleContactGenerator::<P, M>::new()))
+        }
+        else if a.is_shape::<Capsule<P::Real>>() && b.is_shape::<Cuboid<P::Vector>>() {
+            Some(Box::new(CapsuleCuboidContactGenerator::<P, M>::new()))
+        }
+        else if a.is_shape::<Cuboid<P::Vector>>() && b.is_shape::<Cuboid<P::Vector>>() {
+            Some(Box::new(CuboidCuboidContactGenerator::<P, M>::new_with_settings(self.settings)))
+        }
+        else if a.is_shape::<ConvexHull<P>>() && b.is_shape::<ConvexHull<P>>() {
+            Some(Box::new(ConvexHullConvexHullContactGenerator::<P, M>::new_with_settings(self.settings)))
+        }
         else if a.is_support_map() && b.is_support_map() {
             let simplex     = JohnsonSimplex::new_w_tls();
-            let wo_manifold = SupportMapSupportMapContactGenerator::new(simplex);
+            let wo_manifold = SupportMapSupportMapContactGenerator::new_with_settings(simplex, self.settings);
 
             if !a_is_ball && !b_is_ball {
                 let manifold = OneShotContactManifoldGenerator::new(wo_manifold);
@@ -72,6 +153,15 @@ impl<P: Point, M: Isometry<P>> ContactDispatcher<P, M> for DefaultContactDispatc
                 Some(Box::new(wo_manifold))
             }
         }
+        else if a.is_shape::<HeightField<P::Real>>() {
+            Some(Box::new(HeightFieldShapeContactGenerator::<P, M>::new()))
+        }
+        else if b.is_shape::<HeightField<P::Real>>() {
+            Some(Box::new(ShapeHeightFieldContactGenerator::<P, M>::new()))
+        }
+        else if a.is_shape::<TriMesh<P>>() && b.is_shape::<TriMesh<P>>() {
+            Some(Box::new(TriMeshTriMeshContactGenerator::<P, M>::new()))
+        }
         else if a.is_composite_shape() {
             Some(Box::new(CompositeShapeShapeContactGenerator::<P, M>::new()))
         }
@@ -83,3 +173,13 @@ impl<P: Point, M: Isometry<P>> ContactDispatcher<P, M> for DefaultContactDispatc
         }
     }
 }
+
+// Whether the plane contact generators give a full manifold for this shape, see
+// `contacts_internal::plane_against_shape_manifold`.
+fn is_polytope<P: Point, M: Isometry<P>>(shape: &Shape<P, M>) -> bool {
+    shape.is_shape::<Cuboid<P::Vector>>()  ||
+    shape.is_shape::<ConvexHull<P>>()      ||
+    shape.is_shape::<ConvexPolygon<P>>()   ||
+    shape.is_shape::<Triangle<P>>()        ||
+    shape.is_shape::<Segment<P>>()
+}
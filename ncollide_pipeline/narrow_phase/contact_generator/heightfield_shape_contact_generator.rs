@@ -0,0 +1,200 @@
+use na;
+use math::{Point, Isometry};
+use utils::data::hash_map::HashMap;
+use utils::data::hash::UintTWHash;
+use geometry::bounding_volume::{self, BoundingVolume};
+use geometry::shape::{Shape, HeightField};
+use geometry::query::{Contact, contacts_internal};
+use narrow_phase::{ContactGenerator, ContactDispatcher, ContactAlgorithm};
+
+
+/// Collision detector between a heightfield and another shape.
+///
+/// One sub-detector is kept for each triangle of the heightfield cells intersecting the AABB of
+/// the other shape. If the heightfield has smooth normals, the normals of the contacts are
+/// interpolated at their points on the heightfield.
+pub struct HeightFieldShapeContactGenerator<P: Point, M> {
+    sub_detectors: HashMap<usize, (ContactAlgorithm<P, M>, usize), UintTWHash>,
+    to_delete:     Vec<usize>,
+    timestamp:     usize,
+    // The contacts with smoothed normals, if the heightfield has smooth normals.
+    contacts:      Option<Vec<Contact<P>>>
+}
+
+impl<P: Point, M> HeightFieldShapeContactGenerator<P, M> {
+    /// Creates a new collision detector between a heightfield and another shape.
+    pub fn new() -> HeightFieldShapeContactGenerator<P, M> {
+        HeightFieldShapeContactGenerator {
+            sub_detectors: HashMap::new_with_capacity(5, UintTWHash::new()),
+            to_delete:     Vec::new(),
+            timestamp:     0,
+            contacts:      None
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> HeightFieldShapeContactGenerator<P, M> {
+    fn do_update(&mut self,
+                 dispatcher: &ContactDispatcher<P, M>,
+                 m1:         &M,
+                 g1:         &HeightField<P::Real>,
+                 m2:         &M,
+                 g2:         &Shape<P, M>,
+                 prediction: P::Real,
+                 swap:       bool) {
+        self.timestamp = self.timestamp + 1;
+
+        let timestamp = self.timestamp;
+        let ls_m2     = na::inverse(m1) * m2.clone();
+        let ls_aabb2  = bounding_volume::aabb(g2, &ls_m2).loosened(prediction);
+
+        {
+            let sub_detectors = &mut self.sub_detectors;
+
+            g1.map_triangles_in_local_aabb(&ls_aabb2, &mut |i, triangle| {
+                let detector = sub_detectors.find_or_insert_lazy(i, || {
+                    let new_detector = if swap {
+                        dispatcher.get_contact_algorithm(g2, triangle)
+                    }
+                    else {
+                        dispatcher.get_contact_algorithm(triangle, g2)
+                    };
+
+                    new_detector.map(|d| (d, timestamp))
+                });
+
+                if let Some(detector) = detector {
+                    detector.1 = timestamp;
+
+                    if swap {
+                        assert!(detector.0.update(dispatcher, m2, g2, m1, triangle, prediction),
+                                "Internal error: the shape was no longer valid.");
+                    }
+                    else {
+                        assert!(detector.0.update(dispatcher, m1, triangle, m2, g2, prediction),
+                                "Internal error: the shape was no longer valid.");
+                    }
+                }
+            });
+        }
+
+        // Remove the sub detectors of the triangles no longer intersecting the other shape.
+        for detector in self.sub_detectors.elements().iter() {
+            if detector.value.1 != timestamp {
+                self.to_delete.push(detector.key);
+            }
+        }
+
+        for i in self.to_delete.iter() {
+            let _ = self.sub_detectors.remove(i);
+        }
+
+        self.to_delete.clear();
+
+        if g1.has_smooth_normals() {
+            let mut contacts = self.contacts.take().unwrap_or(Vec::new());
+            contacts.clear();
+
+            for detector in self.sub_detectors.elements().iter() {
+                let start = contacts.len();
+                detector.value.0.contacts(&mut contacts);
+
+                for c in contacts[start ..].iter_mut() {
+                    contacts_internal::smooth_heightfield_contact(m1, g1, detector.key, c, !swap);
+                }
+            }
+
+            self.contacts = Some(contacts);
+        }
+        else {
+            self.contacts = None;
+        }
+    }
+}
+
+/// Collision detector between a shape and a heightfield.
+pub struct ShapeHeightFieldContactGenerator<P: Point, M> {
+    sub_detector: HeightFieldShapeContactGenerator<P, M>
+}
+
+impl<P: Point, M> ShapeHeightFieldContactGenerator<P, M> {
+    /// Creates a new collision detector between a shape and a heightfield.
+    pub fn new() -> ShapeHeightFieldContactGenerator<P, M> {
+        ShapeHeightFieldContactGenerator {
+            sub_detector: HeightFieldShapeContactGenerator::new()
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> ContactGenerator<P, M> for HeightFieldShapeContactGenerator<P, M> {
+    fn update(&mut self,
+              d:  &ContactDispatcher<P, M>,
+              ma: &M,
+              a:  &Shape<P, M>,
+              mb: &M,
+              b:  &Shape<P, M>,
+              prediction: P::Real)
+              -> bool {
+        if let Some(hf) = a.as_shape::<HeightField<P::Real>>() {
+            self.do_update(d, ma, hf, mb, b, prediction, false);
+
+            true
+        }
+        else {
+            false
+        }
+    }
+
+    fn num_contacts(&self) -> usize {
+        if let Some(ref contacts) = self.contacts {
+            return contacts.len();
+        }
+
+        let mut res = 0;
+
+        for detector in self.sub_detectors.elements().iter() {
+            res = res + detector.value.0.num_contacts()
+        }
+
+        res
+    }
+
+    fn contacts(&self, out: &mut Vec<Contact<P>>) {
+        if let Some(ref contacts) = self.contacts {
+            out.extend(contacts.iter().cloned());
+            return;
+        }
+
+        for detector in self.sub_detectors.elements().iter() {
+            detector.value.0.contacts(out);
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> ContactGenerator<P, M> for ShapeHeightFieldContactGenerator<P, M> {
+    fn update(&mut self,
+              d:  &ContactDispatcher<P, M>,
+              ma: &M,
+              a:  &Shape<P, M>,
+              mb: &M,
+              b:  &Shape<P, M>,
+              prediction: P::Real)
+              -> bool {
+        if let Some(hf) = b.as_shape::<HeightField<P::Real>>() {
+            self.sub_detector.do_update(d, mb, hf, ma, a, prediction, true);
+
+            true
+        }
+        else {
+            false
+        }
+    }
+
+    fn num_contacts(&self) -> usize {
+        self.sub_detector.num_contacts()
+    }
+
+    fn contacts(&self, out: &mut Vec<Contact<P>>) {
+        self.sub_detector.contacts(out)
+    }
+}
@@ -30,7 +30,7 @@ impl<P: Point> ContactWLocals<P> {
 /// One contact is added per update until the maximum number of contact is reached. When the
 /// maximum number of contact is reached, each time a new contact is created, the new manifold is
 /// computed by maximizing the variance along each canonical axis (of the space in which leaves the
-/// contacts).
+/// contacts). The new contact is discarded if it does not increase this variance.
 #[derive(Clone)]
 pub struct IncrementalContactManifoldGenerator<P: Point, M, CD> {
     contacts:     Vec<ContactWLocals<P>>, // FIXME: replace by a vec slice to avoid allocations ?
@@ -137,7 +137,7 @@ impl<P, M, CD> IncrementalContactManifoldGenerator<P, M, CD>
                 let depth = na::dot(&dw, &c.contact.normal);
 
                 if depth >= -prediction &&
-                   na::norm_squared(&(dw - c.contact.normal * depth)) <= na::convert(0.01f64) {
+                   na::norm_squared(&(dw - *c.contact.normal * depth)) <= na::convert(0.01f64) {
                    c.contact.depth  = depth;
                    c.contact.world1 = world1;
                    c.contact.world2 = world2;
@@ -204,7 +204,25 @@ fn add_reduce_by_variance<P, M>(pts: &mut [ContactWLocals<P>], to_add: Contact<P
         }
     }
 
-    pts[argmax] = ContactWLocals::new_with_contact(to_add, m1, m2);
+    // Keep the current points if they are more spread out, so that the contacts of a resting
+    // manifold are not replaced at each update.
+    if varmax > points_variance(pts) {
+        pts[argmax] = ContactWLocals::new_with_contact(to_add, m1, m2);
+    }
+}
+
+fn points_variance<P>(pts: &[ContactWLocals<P>]) -> P::Real
+    where P: Point {
+    let mut mean = P::origin();
+
+    for pt in pts.iter() {
+        mean = mean + pt.center.coordinates()
+    }
+
+    let divisor: f64 = 1.0f64 / (pts.len() as f64);
+    mean = mean * na::convert(divisor);
+
+    pts.iter().fold(na::zero(), |sum, pt| sum + na::norm_squared(&(pt.center - mean)))
 }
 
 fn approx_variance<P>(pts: &[ContactWLocals<P>], to_add: &Contact<P>, to_ignore: usize) -> P::Real
@@ -5,9 +5,18 @@ pub use self::default_contact_dispatcher::DefaultContactDispatcher;
 pub use self::ball_ball_contact_generator::BallBallContactGenerator;
 pub use self::plane_support_map_contact_generator::{PlaneSupportMapContactGenerator, SupportMapPlaneContactGenerator};
 pub use self::support_map_support_map_contact_generator::SupportMapSupportMapContactGenerator;
+pub use self::convex_hull_convex_hull_contact_generator::ConvexHullConvexHullContactGenerator;
+pub use self::ball_capsule_contact_generator::{BallCapsuleContactGenerator, CapsuleBallContactGenerator};
+pub use self::ball_cuboid_contact_generator::{BallCuboidContactGenerator, CuboidBallContactGenerator};
+pub use self::capsule_capsule_contact_generator::CapsuleCapsuleContactGenerator;
+pub use self::cuboid_capsule_contact_generator::{CuboidCapsuleContactGenerator, CapsuleCuboidContactGenerator};
+pub use self::cuboid_cuboid_contact_generator::CuboidCuboidContactGenerator;
 pub use self::incremental_contact_manifold_generator::IncrementalContactManifoldGenerator;
 pub use self::one_shot_contact_manifold_generator::OneShotContactManifoldGenerator;
+pub use self::persistent_contact_manifold_generator::{PersistentContactManifoldGenerator, PersistentContactDispatcher};
 pub use self::composite_shape_shape_contact_generator::{CompositeShapeShapeContactGenerator, ShapeCompositeShapeContactGenerator};
+pub use self::trimesh_trimesh_contact_generator::TriMeshTriMeshContactGenerator;
+pub use self::heightfield_shape_contact_generator::{HeightFieldShapeContactGenerator, ShapeHeightFieldContactGenerator};
 
 // FIXME: un-hide this and move everything to a folder.
 #[doc(hidden)]
@@ -16,6 +25,15 @@ mod default_contact_dispatcher;
 mod ball_ball_contact_generator;
 mod plane_support_map_contact_generator;
 mod support_map_support_map_contact_generator;
+mod convex_hull_convex_hull_contact_generator;
+mod ball_capsule_contact_generator;
+mod ball_cuboid_contact_generator;
+mod capsule_capsule_contact_generator;
+mod cuboid_capsule_contact_generator;
+mod cuboid_cuboid_contact_generator;
 mod incremental_contact_manifold_generator;
 mod one_shot_contact_manifold_generator;
+mod persistent_contact_manifold_generator;
 mod composite_shape_shape_contact_generator;
+mod trimesh_trimesh_contact_generator;
+mod heightfield_shape_contact_generator;
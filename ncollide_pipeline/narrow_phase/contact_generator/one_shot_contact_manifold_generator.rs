@@ -52,7 +52,7 @@ impl<P, M, CD> ContactGenerator<P, M> for OneShotContactManifoldGenerator<P, M,
             // do the one-shot manifold generation
             match self.sub_detector.get_sub_collision(d, m1, g1, m2, g2, prediction) {
                 Some(Some(coll)) => {
-                    P::Vector::orthonormal_subspace_basis(&[coll.normal], |b| {
+                    P::Vector::orthonormal_subspace_basis(&[coll.normal.unwrap()], |b| {
                         let perturbation = M::Rotation::scaled_rotation_between(&coll.normal, &b, na::convert(0.01))
                                            .expect(ROT_ERROR);
                         let shifted_m1   = m1.append_rotation_wrt_point(&perturbation, &coll.world1)
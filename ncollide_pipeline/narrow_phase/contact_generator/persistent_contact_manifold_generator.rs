@@ -0,0 +1,161 @@
+use std::marker::PhantomData;
+use math::{Point, Isometry};
+use geometry::shape::{Shape, FeatureId};
+use geometry::query::{Contact, PersistentManifold, FeatureMatching};
+use narrow_phase::{ContactGenerator, ContactDispatcher, ContactAlgorithm};
+
+
+/// Contact generator matching the contacts of a sub-detector across updates.
+///
+/// The contacts of each update are stored in a `PersistentManifold`, identified by the features
+/// given by `ContactGenerator::contacts_with_features`, e.g., the triangle of a mesh, or by the
+/// features of the support-mapped shapes they lie on, as given by `SupportMap::support_feature_id`
+/// at the contact points. Each contact carries a unique identifier as user data: a contact matched
+/// with a contact of the previous update keeps its identifier, so that constraint solvers can
+/// warm-start using, e.g., the impulses they computed for this identifier. For the full
+/// manifold of up to 4 points in 3D, the sub-detector should be an
+/// `IncrementalContactManifoldGenerator` or a `OneShotContactManifoldGenerator`.
+#[derive(Clone)]
+pub struct PersistentContactManifoldGenerator<P: Point, M, CD> {
+    sub_detector: CD,
+    threshold:    P::Real,
+    manifold:     Option<PersistentManifold<P, usize>>,
+    features:     Vec<(Contact<P>, (FeatureId, FeatureId))>,
+    next_id:      usize,
+    _matrix:      PhantomData<M>
+}
+
+impl<P, M, CD> PersistentContactManifoldGenerator<P, M, CD>
+    where P:  Point,
+          CD: ContactGenerator<P, M> {
+    /// Creates a new persistent contact manifold generator.
+    ///
+    /// # Arguments:
+    /// * `cd` - collision detection sub-algorithm used to generate the contact points.
+    /// * `threshold` - the largest distance between the contact points on the first shape of two
+    /// matching contacts of successive updates.
+    pub fn new(cd: CD, threshold: P::Real) -> PersistentContactManifoldGenerator<P, M, CD> {
+        PersistentContactManifoldGenerator {
+            sub_detector: cd,
+            threshold:    threshold,
+            manifold:     None,
+            features:     Vec::new(),
+            next_id:      0,
+            _matrix:      PhantomData
+        }
+    }
+}
+
+impl<P, M, CD> ContactGenerator<P, M> for PersistentContactManifoldGenerator<P, M, CD>
+    where P:  Point,
+          M:  Isometry<P>,
+          CD: ContactGenerator<P, M> {
+    fn update(&mut self,
+              d:          &ContactDispatcher<P, M>,
+              m1:         &M,
+              g1:         &Shape<P, M>,
+              m2:         &M,
+              g2:         &Shape<P, M>,
+              prediction: P::Real)
+              -> bool {
+        if !self.sub_detector.update(d, m1, g1, m2, g2, prediction) {
+            return false;
+        }
+
+        if self.manifold.is_none() {
+            // The features of a shape that is not a support map are unknown. Those of a composite
+            // shape are its parts: match on the features of the other shape only, so that the
+            // contacts moving from one part to another are kept.
+            let matching = match (g1.is_support_map(), g2.is_support_map()) {
+                (false, true) => FeatureMatching::Second,
+                (true, false) => FeatureMatching::First,
+                _             => FeatureMatching::Both
+            };
+
+            self.manifold = Some(PersistentManifold::new_with_matching(self.threshold, matching));
+        }
+
+        self.sub_detector.contacts_with_features(&mut self.features);
+
+        for &mut (ref c, ref mut features) in self.features.iter_mut() {
+            if features.0 == FeatureId::Unknown {
+                features.0 = feature_id(m1, g1, &c.world1);
+            }
+
+            if features.1 == FeatureId::Unknown {
+                features.1 = feature_id(m2, g2, &c.world2);
+            }
+        }
+
+        let manifold = self.manifold.as_mut().unwrap();
+        let _ = manifold.update(&self.features[..]);
+        self.features.clear();
+
+        for c in manifold.contacts_mut().iter_mut() {
+            if c.age == 0 {
+                c.data       = self.next_id;
+                self.next_id = self.next_id + 1;
+            }
+        }
+
+        true
+    }
+
+    #[inline]
+    fn num_contacts(&self) -> usize {
+        self.manifold.as_ref().map_or(0, |m| m.len())
+    }
+
+    #[inline]
+    fn contacts(&self, out_contacts: &mut Vec<Contact<P>>) {
+        if let Some(ref manifold) = self.manifold {
+            for c in manifold.contacts().iter() {
+                out_contacts.push(c.contact.clone())
+            }
+        }
+    }
+
+    #[inline]
+    fn persistent_manifold(&self) -> Option<&PersistentManifold<P, usize>> {
+        self.manifold.as_ref()
+    }
+}
+
+fn feature_id<P: Point, M: Isometry<P>>(m: &M, g: &Shape<P, M>, pt: &P) -> FeatureId {
+    match g.as_support_map() {
+        Some(sm) => sm.support_feature_id(m, &[*pt]),
+        None     => FeatureId::Unknown
+    }
+}
+
+/// Collision dispatcher wrapping the algorithms of another dispatcher into
+/// `PersistentContactManifoldGenerator`s.
+///
+/// Use it with the `DefaultNarrowPhase` to have the persistent manifold of each contact pair of
+/// a collision world.
+pub struct PersistentContactDispatcher<P: Point, M> {
+    dispatcher: Box<ContactDispatcher<P, M> + 'static>,
+    threshold:  P::Real
+}
+
+impl<P: Point, M> PersistentContactDispatcher<P, M> {
+    /// Creates a new dispatcher wrapping the algorithms of `dispatcher`.
+    ///
+    /// The matching threshold of the persistent manifolds is `threshold`.
+    pub fn new(dispatcher: Box<ContactDispatcher<P, M> + 'static>, threshold: P::Real)
+               -> PersistentContactDispatcher<P, M> {
+        PersistentContactDispatcher {
+            dispatcher: dispatcher,
+            threshold:  threshold
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> ContactDispatcher<P, M> for PersistentContactDispatcher<P, M> {
+    fn get_contact_algorithm(&self, a: &Shape<P, M>, b: &Shape<P, M>) -> Option<ContactAlgorithm<P, M>> {
+        self.dispatcher.get_contact_algorithm(a, b).map(|algorithm| {
+            let res: ContactAlgorithm<P, M> = Box::new(PersistentContactManifoldGenerator::new(algorithm, self.threshold));
+            res
+        })
+    }
+}
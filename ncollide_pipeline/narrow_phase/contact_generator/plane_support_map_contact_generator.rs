@@ -8,11 +8,12 @@ use narrow_phase::{ContactGenerator, ContactDispatcher};
 
 /// Collision detector between a plane and a shape implementing the `SupportMap` trait.
 ///
-/// This detector generates only one contact point. For a full manifold generation, see
-/// `IncrementalContactManifoldGenerator`.
+/// The manifold is recomputed at each update, see `contacts_internal::plane_against_shape_manifold`. It
+/// has one contact per vertex of a polytope close to the plane, but only one contact for other
+/// shapes. For a full manifold generation with those, see `OneShotContactManifoldGenerator`.
 #[derive(Clone)]
 pub struct PlaneSupportMapContactGenerator<P: Point, M> {
-    contact:  Option<Contact<P>>,
+    contacts: Vec<Contact<P>>,
     mat_type: PhantomData<M> // FIXME: can we avoid this?
 }
 
@@ -22,7 +23,7 @@ impl<P: Point, M> PlaneSupportMapContactGenerator<P, M> {
     #[inline]
     pub fn new() -> PlaneSupportMapContactGenerator<P, M> {
         PlaneSupportMapContactGenerator {
-            contact:  None,
+            contacts: Vec::new(),
             mat_type: PhantomData
         }
     }
@@ -30,11 +31,12 @@ impl<P: Point, M> PlaneSupportMapContactGenerator<P, M> {
 
 /// Collision detector between a plane and a shape implementing the `SupportMap` trait.
 ///
-/// This detector generates only one contact point. For a full manifold generation, see
-/// `IncrementalContactManifoldGenerator`.
+/// The manifold is recomputed at each update, see `contacts_internal::shape_against_plane_manifold`. It
+/// has one contact per vertex of a polytope close to the plane, but only one contact for other
+/// shapes. For a full manifold generation with those, see `OneShotContactManifoldGenerator`.
 #[derive(Clone)]
 pub struct SupportMapPlaneContactGenerator<P: Point, M> {
-    contact:  Option<Contact<P>>,
+    contacts: Vec<Contact<P>>,
     mat_type: PhantomData<M> // FIXME: can we avoid this.
 }
 
@@ -44,7 +46,7 @@ impl<P: Point, M> SupportMapPlaneContactGenerator<P, M> {
     #[inline]
     pub fn new() -> SupportMapPlaneContactGenerator<P, M> {
         SupportMapPlaneContactGenerator {
-            contact:  None,
+            contacts: Vec::new(),
             mat_type: PhantomData
         }
     }
@@ -60,10 +62,9 @@ impl<P: Point, M: Isometry<P>> ContactGenerator<P, M> for PlaneSupportMapContact
               b:          &Shape<P, M>,
               prediction: P::Real)
               -> bool {
-        if let (Some(p), Some(sm)) = (plane.as_shape::<Plane<P::Vector>>(), b.as_support_map()) {
-                self.contact = contacts_internal::plane_against_support_map(ma, p, mb, sm, prediction);
-
-                true
+        if let Some(p) = plane.as_shape::<Plane<P::Vector>>() {
+            self.contacts.clear();
+            contacts_internal::plane_against_shape_manifold(ma, p, mb, b, prediction, &mut self.contacts)
         }
         else {
             false
@@ -72,18 +73,12 @@ impl<P: Point, M: Isometry<P>> ContactGenerator<P, M> for PlaneSupportMapContact
 
     #[inline]
     fn num_contacts(&self) -> usize {
-        match self.contact {
-            None    => 0,
-            Some(_) => 1
-        }
+        self.contacts.len()
     }
 
     #[inline]
     fn contacts(&self, out_contacts: &mut Vec<Contact<P>>) {
-        match self.contact {
-            Some(ref c) => out_contacts.push(c.clone()),
-            None        => ()
-        }
+        out_contacts.extend(self.contacts.iter().cloned())
     }
 }
 
@@ -97,10 +92,9 @@ impl<P: Point, M: Isometry<P>> ContactGenerator<P, M> for SupportMapPlaneContact
               plane:      &Shape<P, M>,
               prediction: P::Real)
               -> bool {
-        if let (Some(sm), Some(p)) = (a.as_support_map(), plane.as_shape::<Plane<P::Vector>>()) {
-                self.contact = contacts_internal::support_map_against_plane(ma, sm, mb, p, prediction);
-
-                true
+        if let Some(p) = plane.as_shape::<Plane<P::Vector>>() {
+            self.contacts.clear();
+            contacts_internal::shape_against_plane_manifold(ma, a, mb, p, prediction, &mut self.contacts)
         }
         else {
             false
@@ -109,17 +103,11 @@ impl<P: Point, M: Isometry<P>> ContactGenerator<P, M> for SupportMapPlaneContact
 
     #[inline]
     fn num_contacts(&self) -> usize {
-        match self.contact {
-            None    => 0,
-            Some(_) => 1
-        }
+        self.contacts.len()
     }
 
     #[inline]
     fn contacts(&self, out_contacts: &mut Vec<Contact<P>>) {
-        match self.contact {
-            Some(ref c) => out_contacts.push(c.clone()),
-            None        => ()
-        }
+        out_contacts.extend(self.contacts.iter().cloned())
     }
 }
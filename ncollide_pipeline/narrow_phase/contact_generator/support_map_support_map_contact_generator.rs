@@ -4,7 +4,7 @@ use geometry::shape::{Shape, AnnotatedPoint};
 use geometry::query::algorithms::simplex::Simplex;
 use geometry::query::algorithms::gjk::GJKResult;
 use geometry::query::contacts_internal;
-use geometry::query::Contact;
+use geometry::query::{Contact, QuerySettings};
 use narrow_phase::{ContactGenerator, ContactDispatcher};
 
 
@@ -16,6 +16,7 @@ use narrow_phase::{ContactGenerator, ContactDispatcher};
 pub struct SupportMapSupportMapContactGenerator<P: Point, M, S> {
     simplex:  S,
     contact:  GJKResult<Contact<P>, P::Vector>,
+    settings: QuerySettings<P::Real>,
     mat_type: PhantomData<M> // FIXME: can we avoid this?
 }
 
@@ -27,9 +28,15 @@ impl<P, M, S> SupportMapSupportMapContactGenerator<P, M, S>
     ///
     /// It is initialized with a pre-created simplex.
     pub fn new(simplex: S) -> SupportMapSupportMapContactGenerator<P, M, S> {
+        SupportMapSupportMapContactGenerator::new_with_settings(simplex, QuerySettings::default())
+    }
+
+    /// Creates a new persistant collision detector using the given numerical tolerances.
+    pub fn new_with_settings(simplex: S, settings: QuerySettings<P::Real>) -> SupportMapSupportMapContactGenerator<P, M, S> {
         SupportMapSupportMapContactGenerator {
             simplex:  simplex,
             contact:  GJKResult::Intersection,
+            settings: settings,
             mat_type: PhantomData
         }
     }
@@ -51,7 +58,7 @@ impl<P, M, S> ContactGenerator<P, M> for SupportMapSupportMapContactGenerator<P,
         if let (Some(sma), Some(smb)) = (a.as_support_map(), b.as_support_map()) {
             let initial_direction = match self.contact {
                 GJKResult::NoIntersection(ref separator) => Some(separator.clone()),
-                GJKResult::Projection(ref contact)       => Some(contact.normal.clone()),
+                GJKResult::Projection(ref contact)       => Some(contact.normal.unwrap()),
                 GJKResult::Intersection                  => None,
                 GJKResult::Proximity(_)                  => unreachable!()
             };
@@ -63,7 +70,8 @@ impl<P, M, S> ContactGenerator<P, M> for SupportMapSupportMapContactGenerator<P,
                 smb,
                 prediction,
                 &mut self.simplex,
-                initial_direction);
+                initial_direction,
+                &self.settings);
 
             true
         }
@@ -0,0 +1,73 @@
+use std::marker::PhantomData;
+
+use math::{Point, Isometry};
+use geometry::shape::{Shape, TriMesh, FeatureId};
+use geometry::query::Contact;
+use geometry::query::contacts_internal;
+use narrow_phase::{ContactGenerator, ContactDispatcher};
+
+
+/// Contact manifold generator between two triangle meshes.
+///
+/// The contacts of each pair of overlapping triangles are recomputed at each update by a
+/// simultaneous traversal of the BVTs of both meshes, see
+/// `contacts_internal::trimesh_against_trimesh_with_features`.
+pub struct TriMeshTriMeshContactGenerator<P: Point, M> {
+    contacts: Vec<Contact<P>>,
+    features: Vec<(Contact<P>, (FeatureId, FeatureId))>,
+    mat_type: PhantomData<M>
+}
+
+impl<P: Point, M> Clone for TriMeshTriMeshContactGenerator<P, M> {
+    fn clone(&self) -> TriMeshTriMeshContactGenerator<P, M> {
+        TriMeshTriMeshContactGenerator {
+            contacts: self.contacts.clone(),
+            features: Vec::new(),
+            mat_type: PhantomData
+        }
+    }
+}
+
+impl<P: Point, M> TriMeshTriMeshContactGenerator<P, M> {
+    /// Creates a new contact manifold generator between two triangle meshes.
+    #[inline]
+    pub fn new() -> TriMeshTriMeshContactGenerator<P, M> {
+        TriMeshTriMeshContactGenerator {
+            contacts: Vec::new(),
+            features: Vec::new(),
+            mat_type: PhantomData
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> ContactGenerator<P, M> for TriMeshTriMeshContactGenerator<P, M> {
+    fn update(&mut self,
+              _:          &ContactDispatcher<P, M>,
+              ma:         &M,
+              a:          &Shape<P, M>,
+              mb:         &M,
+              b:          &Shape<P, M>,
+              prediction: P::Real)
+              -> bool {
+        if let (Some(a), Some(b)) = (a.as_shape::<TriMesh<P>>(), b.as_shape::<TriMesh<P>>()) {
+            self.contacts.clear();
+            contacts_internal::trimesh_against_trimesh_with_features(ma, a, mb, b, prediction, &mut self.features);
+            self.contacts.extend(self.features.drain(..).map(|(c, _)| c));
+
+            true
+        }
+        else {
+            false
+        }
+    }
+
+    #[inline]
+    fn num_contacts(&self) -> usize {
+        self.contacts.len()
+    }
+
+    #[inline]
+    fn contacts(&self, out_contacts: &mut Vec<Contact<P>>) {
+        out_contacts.extend(self.contacts.iter().cloned())
+    }
+}
@@ -14,9 +14,24 @@ pub trait ContactHandler<P: Point, M, T> {
     fn handle_contact_stopped(&mut self, co1: &CollisionObject<P, M, T>, co2: &CollisionObject<P, M, T>);
 }
 
+/// An event emitted when two collision objects start or stop being in contact.
+///
+/// The collision objects are identified by their uids.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ContactEvent {
+    /// The two collision objects started being in contact.
+    Started(usize, usize),
+    /// The two collision objects stopped being in contact.
+    Stopped(usize, usize)
+}
+
 /// Signal for contact start/stop.
+///
+/// Besides executing the registered event handlers, the signal records the events it is
+/// triggered with until `clear_events` is called.
 pub struct ContactSignal<P: Point, M, T> {
     contact_handlers: Vec<(String, Box<ContactHandler<P, M, T> + 'static>)>,
+    events:           Vec<ContactEvent>
 }
 
 impl<P: Point, M, T> ContactSignal<P, M, T> {
@@ -24,6 +39,7 @@ impl<P: Point, M, T> ContactSignal<P, M, T> {
     pub fn new() -> ContactSignal<P, M, T> {
         ContactSignal {
             contact_handlers: Vec::new(),
+            events:           Vec::new()
         }
     }
 
@@ -62,6 +78,8 @@ impl<P: Point, M, T> ContactSignal<P, M, T> {
                                           co1:      &CollisionObject<P, M, T>,
                                           co2:      &CollisionObject<P, M, T>,
                                           contacts: &ContactAlgorithm<P, M>) {
+        self.events.push(ContactEvent::Started(co1.uid, co2.uid));
+
         for &mut (_, ref mut f) in self.contact_handlers.iter_mut() {
             f.handle_contact_started(co1, co2, contacts)
         }
@@ -71,8 +89,22 @@ impl<P: Point, M, T> ContactSignal<P, M, T> {
     pub fn trigger_contact_stopped_signal(&mut self,
                                           co1: &CollisionObject<P, M, T>,
                                           co2: &CollisionObject<P, M, T>) {
+        self.events.push(ContactEvent::Stopped(co1.uid, co2.uid));
+
         for &mut (_, ref mut f) in self.contact_handlers.iter_mut() {
             f.handle_contact_stopped(co1, co2)
         }
     }
+
+    /// The events this signal has been triggered with since the last call to `clear_events`.
+    #[inline]
+    pub fn events(&self) -> &[ContactEvent] {
+        &self.events[..]
+    }
+
+    /// Forgets the events recorded by this signal.
+    #[inline]
+    pub fn clear_events(&mut self) {
+        self.events.clear()
+    }
 }
@@ -149,6 +149,19 @@ impl<P: Point, M: 'static, T> NarrowPhase<P, M, T> for DefaultNarrowPhase<P, M>
         ContactPairs::new(objects, self.contact_generators.elements().iter())
     }
 
+    fn contact_pair<'a>(&'a self,
+                        objects: &'a UidRemap<CollisionObject<P, M, T>>,
+                        fk1:     &FastKey,
+                        fk2:     &FastKey)
+                        -> Option<(&'a CollisionObject<P, M, T>,
+                                   &'a CollisionObject<P, M, T>,
+                                   &'a ContactAlgorithm<P, M>)> {
+        // The objects are returned in the order of the key the algorithm was created with.
+        self.contact_generators.find_entry(&Pair::new(*fk1, *fk2)).map(|e| {
+            (&objects[e.key.first], &objects[e.key.second], &e.value)
+        })
+    }
+
     fn proximity_pairs<'a>(&'a self, objects: &'a UidRemap<CollisionObject<P, M, T>>)
                            -> ProximityPairs<'a, P, M, T> {
         ProximityPairs::new(objects, self.proximity_detectors.elements().iter())
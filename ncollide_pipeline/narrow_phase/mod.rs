@@ -13,9 +13,18 @@ pub use self::contact_generator::{
     BallBallContactGenerator,
     PlaneSupportMapContactGenerator, SupportMapPlaneContactGenerator,
     SupportMapSupportMapContactGenerator,
+    ConvexHullConvexHullContactGenerator,
+    BallCapsuleContactGenerator, CapsuleBallContactGenerator,
+    BallCuboidContactGenerator, CuboidBallContactGenerator,
+    CapsuleCapsuleContactGenerator,
+    CuboidCapsuleContactGenerator, CapsuleCuboidContactGenerator,
+    CuboidCuboidContactGenerator,
     CompositeShapeShapeContactGenerator, ShapeCompositeShapeContactGenerator,
+    TriMeshTriMeshContactGenerator,
+    HeightFieldShapeContactGenerator, ShapeHeightFieldContactGenerator,
     IncrementalContactManifoldGenerator,
-    OneShotContactManifoldGenerator
+    OneShotContactManifoldGenerator,
+    PersistentContactManifoldGenerator, PersistentContactDispatcher
 };
 
 #[doc(inline)]
@@ -24,7 +33,9 @@ pub use self::proximity_detector::{
     ProximityDispatcher,
     ProximityAlgorithm,
     DefaultProximityDispatcher,
+    PersistentProximityDetector,
     BallBallProximityDetector,
+    PlaneBallProximityDetector, BallPlaneProximityDetector,
     PlaneSupportMapProximityDetector, SupportMapPlaneProximityDetector,
     SupportMapSupportMapProximityDetector,
     CompositeShapeShapeProximityDetector,
@@ -32,9 +43,9 @@ pub use self::proximity_detector::{
 };
 
 #[doc(inline)]
-pub use self::contact_signal::{ContactSignal, ContactHandler};
+pub use self::contact_signal::{ContactSignal, ContactHandler, ContactEvent};
 #[doc(inline)]
-pub use self::proximity_signal::{ProximitySignal, ProximityHandler};
+pub use self::proximity_signal::{ProximitySignal, ProximityHandler, ProximityEvent};
 
 #[doc(hidden)]
 pub mod contact_generator;
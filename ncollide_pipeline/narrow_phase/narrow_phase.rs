@@ -35,6 +35,18 @@ pub trait NarrowPhase<P: Point, M, T> {
     fn contact_pairs<'a>(&'a self, objects: &'a UidRemap<CollisionObject<P, M, T>>)
                          -> ContactPairs<'a, P, M, T>;
 
+    /// Returns the contact pair between the objects identified by `fk1` and `fk2`, if any.
+    ///
+    /// The collision objects are returned in the order expected by the contact algorithm, i.e.,
+    /// not necessarily in the order of `fk1` and `fk2`.
+    fn contact_pair<'a>(&'a self,
+                        objects: &'a UidRemap<CollisionObject<P, M, T>>,
+                        fk1:     &FastKey,
+                        fk2:     &FastKey)
+                        -> Option<(&'a CollisionObject<P, M, T>,
+                                   &'a CollisionObject<P, M, T>,
+                                   &'a ContactAlgorithm<P, M>)>;
+
     /// Returns all the potential proximity pairs found during the broad phase, and validated by
     /// the narrow phase.
     fn proximity_pairs<'a>(&'a self, objects: &'a UidRemap<CollisionObject<P, M, T>>)
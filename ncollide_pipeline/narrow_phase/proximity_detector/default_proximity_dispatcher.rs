@@ -1,11 +1,14 @@
 use std::marker::PhantomData;
 use math::{Point, Isometry};
 use geometry::shape::{Shape, Ball, Plane};
+use geometry::query::QuerySettings;
 use geometry::query::algorithms::johnson_simplex::JohnsonSimplex;
 use narrow_phase::proximity_detector::{
     ProximityDispatcher,
     ProximityAlgorithm,
     BallBallProximityDetector,
+    PlaneBallProximityDetector,
+    BallPlaneProximityDetector,
     PlaneSupportMapProximityDetector,
     SupportMapPlaneProximityDetector,
     SupportMapSupportMapProximityDetector,
@@ -14,7 +17,12 @@ use narrow_phase::proximity_detector::{
 };
 
 /// Proximity dispatcher for shapes defined by `ncollide_entities`.
+///
+/// Proximity algorithms for user-defined shapes can be registered with
+/// `.register_proximity_algorithm(...)`: they take precedence over the built-in algorithms.
 pub struct DefaultProximityDispatcher<P: Point, M> {
+    custom:       Vec<Box<Fn(&Shape<P, M>, &Shape<P, M>) -> Option<ProximityAlgorithm<P, M>> + 'static>>,
+    settings:     QuerySettings<P::Real>,
     _point_type:  PhantomData<P>,
     _matrix_type: PhantomData<M>,
 }
@@ -22,21 +30,62 @@ pub struct DefaultProximityDispatcher<P: Point, M> {
 impl<P: Point, M> DefaultProximityDispatcher<P, M> {
     /// Creates a new basic proximity dispatcher.
     pub fn new() -> DefaultProximityDispatcher<P, M> {
+        DefaultProximityDispatcher::new_with_settings(QuerySettings::default())
+    }
+
+    /// Creates a new basic proximity dispatcher using the given numerical tolerances for the GJK-based
+    /// algorithms.
+    pub fn new_with_settings(settings: QuerySettings<P::Real>) -> DefaultProximityDispatcher<P, M> {
         DefaultProximityDispatcher {
+            custom:       Vec::new(),
+            settings:     settings,
             _point_type:  PhantomData,
             _matrix_type: PhantomData,
         }
     }
 }
 
+impl<P: Point, M: Isometry<P>> DefaultProximityDispatcher<P, M> {
+    /// Registers the proximity algorithm built by `builder` for the pairs of shapes of types `G1`
+    /// and `G2`, in this order.
+    ///
+    /// The pair `(G2, G1)` has to be registered separately if needed. The algorithms registered
+    /// last take precedence.
+    pub fn register_proximity_algorithm<G1, G2, F>(&mut self, builder: F)
+        where G1: Shape<P, M>,
+              G2: Shape<P, M>,
+              F:  Fn() -> ProximityAlgorithm<P, M> + 'static {
+        self.custom.push(Box::new(move |a: &Shape<P, M>, b: &Shape<P, M>| {
+            if a.is_shape::<G1>() && b.is_shape::<G2>() {
+                Some(builder())
+            }
+            else {
+                None
+            }
+        }))
+    }
+}
+
 impl<P: Point, M: Isometry<P>> ProximityDispatcher<P, M> for DefaultProximityDispatcher<P, M> {
     fn get_proximity_algorithm(&self, a: &Shape<P, M>, b: &Shape<P, M>) -> Option<ProximityAlgorithm<P, M>> {
+        for custom in self.custom.iter().rev() {
+            if let Some(algorithm) = custom(a, b) {
+                return Some(algorithm);
+            }
+        }
+
         let a_is_ball = a.is_shape::<Ball<P::Real>>();
         let b_is_ball = b.is_shape::<Ball<P::Real>>();
 
         if a_is_ball && b_is_ball {
             Some(Box::new(BallBallProximityDetector::<P, M>::new()))
         }
+        else if a.is_shape::<Plane<P::Vector>>() && b_is_ball {
+            Some(Box::new(PlaneBallProximityDetector::<P, M>::new()))
+        }
+        else if b.is_shape::<Plane<P::Vector>>() && a_is_ball {
+            Some(Box::new(BallPlaneProximityDetector::<P, M>::new()))
+        }
         else if a.is_shape::<Plane<P::Vector>>() && b.is_support_map() {
             Some(Box::new(PlaneSupportMapProximityDetector::<P, M>::new()))
         }
@@ -45,7 +94,7 @@ impl<P: Point, M: Isometry<P>> ProximityDispatcher<P, M> for DefaultProximityDis
         }
         else if a.is_support_map() && b.is_support_map() {
             let simplex = JohnsonSimplex::new_w_tls();
-            Some(Box::new(SupportMapSupportMapProximityDetector::new(simplex)))
+            Some(Box::new(SupportMapSupportMapProximityDetector::new_with_settings(simplex, self.settings)))
         }
         else if a.is_composite_shape() {
             Some(Box::new(CompositeShapeShapeProximityDetector::<P, M>::new()))
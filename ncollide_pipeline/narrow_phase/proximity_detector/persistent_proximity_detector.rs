@@ -0,0 +1,79 @@
+use math::{Point, Isometry};
+use geometry::shape::Shape;
+use geometry::query::Proximity;
+use narrow_phase::{ProximityDispatcher, ProximityAlgorithm, DefaultProximityDispatcher};
+
+
+/// Proximity detector keeping track of the proximity of a single pair of shapes across updates.
+///
+/// The actual detection algorithm is selected by a dispatcher the first time this is updated and
+/// reused, together with its internal cache, on the next updates. The proximity before the last
+/// update is kept so that changes of proximity can be reported.
+pub struct PersistentProximityDetector<P: Point, M> {
+    dispatcher:     Box<ProximityDispatcher<P, M> + 'static>,
+    algorithm:      Option<ProximityAlgorithm<P, M>>,
+    proximity:      Proximity,
+    prev_proximity: Proximity
+}
+
+impl<P: Point, M: Isometry<P>> PersistentProximityDetector<P, M> {
+    /// Creates a new proximity detector using the `DefaultProximityDispatcher`.
+    pub fn new() -> PersistentProximityDetector<P, M> {
+        PersistentProximityDetector::new_with_dispatcher(Box::new(DefaultProximityDispatcher::new()))
+    }
+
+    /// Creates a new proximity detector using the given dispatcher to select its algorithm.
+    pub fn new_with_dispatcher(dispatcher: Box<ProximityDispatcher<P, M> + 'static>)
+                               -> PersistentProximityDetector<P, M> {
+        PersistentProximityDetector {
+            dispatcher:     dispatcher,
+            algorithm:      None,
+            proximity:      Proximity::Disjoint,
+            prev_proximity: Proximity::Disjoint
+        }
+    }
+
+    /// Updates the proximity of the two given shapes and returns it.
+    ///
+    /// The same pair of shapes should be given at each update. If the dispatcher has no algorithm
+    /// for this pair of shapes, they are considered disjoint.
+    pub fn update(&mut self, ma: &M, a: &Shape<P, M>, mb: &M, b: &Shape<P, M>, margin: P::Real) -> Proximity {
+        self.prev_proximity = self.proximity;
+
+        if self.algorithm.is_none() {
+            self.algorithm = self.dispatcher.get_proximity_algorithm(a, b);
+        }
+
+        self.proximity = match self.algorithm {
+            Some(ref mut algorithm) => {
+                if algorithm.update(&*self.dispatcher, ma, a, mb, b, margin) {
+                    algorithm.proximity()
+                }
+                else {
+                    Proximity::Disjoint
+                }
+            },
+            None => Proximity::Disjoint
+        };
+
+        self.proximity
+    }
+
+    /// The proximity computed by the last update.
+    #[inline]
+    pub fn proximity(&self) -> Proximity {
+        self.proximity
+    }
+
+    /// The proximity computed by the update before the last one.
+    #[inline]
+    pub fn prev_proximity(&self) -> Proximity {
+        self.prev_proximity
+    }
+
+    /// Whether the last update changed the proximity.
+    #[inline]
+    pub fn proximity_changed(&self) -> bool {
+        self.proximity != self.prev_proximity
+    }
+}
@@ -0,0 +1,85 @@
+use std::marker::PhantomData;
+
+use alga::linear::Translation;
+use math::{Point, Isometry};
+use geometry::shape::{Shape, Ball, Plane};
+use geometry::query::Proximity;
+use geometry::query::proximity_internal;
+use narrow_phase::{ProximityDetector, ProximityDispatcher};
+
+
+/// Proximity detector between a plane and a ball.
+#[derive(Clone)]
+pub struct PlaneBallProximityDetector<P: Point, M> {
+    proximity:  Proximity,
+    pt_type:    PhantomData<P>,
+    mat_type:   PhantomData<M>
+}
+
+impl<P: Point, M> PlaneBallProximityDetector<P, M> {
+    /// Creates a new persistent proximity detector between a plane and a ball.
+    #[inline]
+    pub fn new() -> PlaneBallProximityDetector<P, M> {
+        PlaneBallProximityDetector {
+            proximity: Proximity::Disjoint,
+            pt_type:   PhantomData,
+            mat_type:  PhantomData
+        }
+    }
+}
+
+/// Proximity detector between a ball and a plane.
+#[derive(Clone)]
+pub struct BallPlaneProximityDetector<P: Point, M> {
+    subdetector: PlaneBallProximityDetector<P, M>
+}
+
+impl<P: Point, M> BallPlaneProximityDetector<P, M> {
+    /// Creates a new persistent proximity detector between a ball and a plane.
+    #[inline]
+    pub fn new() -> BallPlaneProximityDetector<P, M> {
+        BallPlaneProximityDetector {
+            subdetector: PlaneBallProximityDetector::new()
+        }
+    }
+}
+
+impl<P: Point, M: Isometry<P>> ProximityDetector<P, M> for PlaneBallProximityDetector<P, M> {
+    #[inline]
+    fn update(&mut self, _: &ProximityDispatcher<P, M>,
+              ma: &M, plane: &Shape<P, M>,
+              mb: &M, b: &Shape<P, M>,
+              margin: P::Real)
+              -> bool {
+        if let (Some(p), Some(ball)) = (plane.as_shape::<Plane<P::Vector>>(), b.as_shape::<Ball<P::Real>>()) {
+            let center = P::from_coordinates(mb.translation().to_vector());
+            self.proximity = proximity_internal::plane_against_ball(ma, p, &center, ball, margin);
+
+            true
+        }
+        else {
+            false
+        }
+    }
+
+    #[inline]
+    fn proximity(&self) -> Proximity {
+        self.proximity
+    }
+}
+
+impl<P: Point, M: Isometry<P>> ProximityDetector<P, M> for BallPlaneProximityDetector<P, M> {
+    #[inline]
+    fn update(&mut self, disp: &ProximityDispatcher<P, M>,
+              ma: &M, a: &Shape<P, M>,
+              mb: &M, b: &Shape<P, M>,
+              margin: P::Real)
+              -> bool {
+        self.subdetector.update(disp, mb, b, ma, a, margin)
+    }
+
+    #[inline]
+    fn proximity(&self) -> Proximity {
+        self.subdetector.proximity()
+    }
+}
@@ -4,7 +4,7 @@ use math::{Point, Isometry};
 use geometry::shape::{Shape, AnnotatedPoint};
 use geometry::query::algorithms::simplex::Simplex;
 use geometry::query::proximity_internal;
-use geometry::query::Proximity;
+use geometry::query::{Proximity, QuerySettings};
 use narrow_phase::{ProximityDetector, ProximityDispatcher};
 
 
@@ -16,6 +16,7 @@ pub struct SupportMapSupportMapProximityDetector<P: Point, M, S> {
     simplex:   S,
     proximity: Proximity,
     sep_axis:  P::Vector,
+    settings:  QuerySettings<P::Real>,
     pt_type:   PhantomData<P>, // FIXME: can we avoid this?
     mat_type:  PhantomData<M>  // FIXME: can we avoid this?
 }
@@ -28,10 +29,16 @@ impl<P, M, S> SupportMapSupportMapProximityDetector<P, M, S>
     ///
     /// It is initialized with a pre-created simplex.
     pub fn new(simplex: S) -> SupportMapSupportMapProximityDetector<P, M, S> {
+        SupportMapSupportMapProximityDetector::new_with_settings(simplex, QuerySettings::default())
+    }
+
+    /// Creates a new persistant proximity detector using the given numerical tolerances.
+    pub fn new_with_settings(simplex: S, settings: QuerySettings<P::Real>) -> SupportMapSupportMapProximityDetector<P, M, S> {
         SupportMapSupportMapProximityDetector {
             simplex:   simplex,
             proximity: Proximity::Disjoint,
             sep_axis:  na::zero(),
+            settings:  settings,
             pt_type:   PhantomData,
             mat_type:  PhantomData
         }
@@ -64,7 +71,8 @@ impl<P, M, S> ProximityDetector<P, M> for SupportMapSupportMapProximityDetector<
                 smb,
                 margin,
                 &mut self.simplex,
-                initial_direction);
+                initial_direction,
+                &self.settings);
 
             self.proximity = res.0;
             self.sep_axis  = res.1;
@@ -12,9 +12,26 @@ pub trait ProximityHandler<P: Point, M, T> {
                         new_status:  Proximity);
 }
 
+/// An event emitted when the proximity status of two collision objects changes.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ProximityEvent {
+    /// The uid of the first collision object.
+    pub uid1:        usize,
+    /// The uid of the second collision object.
+    pub uid2:        usize,
+    /// The previous proximity status of the collision objects.
+    pub prev_status: Proximity,
+    /// The new proximity status of the collision objects.
+    pub new_status:  Proximity
+}
+
 /// Signal for proximity start/stop.
+///
+/// Besides executing the registered event handlers, the signal records the events it is
+/// triggered with until `clear_events` is called.
 pub struct ProximitySignal<P: Point, M, T> {
     proximity_handlers: Vec<(String, Box<ProximityHandler<P, M, T> + 'static>)>,
+    events:             Vec<ProximityEvent>
 }
 
 impl<P: Point, M, T> ProximitySignal<P, M, T> {
@@ -22,6 +39,7 @@ impl<P: Point, M, T> ProximitySignal<P, M, T> {
     pub fn new() -> ProximitySignal<P, M, T> {
         ProximitySignal {
             proximity_handlers: Vec::new(),
+            events:             Vec::new()
         }
     }
 
@@ -61,8 +79,27 @@ impl<P: Point, M, T> ProximitySignal<P, M, T> {
                                     co2: &CollisionObject<P, M, T>,
                                     prev_status: Proximity,
                                     new_status: Proximity) {
+        self.events.push(ProximityEvent {
+            uid1:        co1.uid,
+            uid2:        co2.uid,
+            prev_status: prev_status,
+            new_status:  new_status
+        });
+
         for &mut (_, ref mut f) in self.proximity_handlers.iter_mut() {
             f.handle_proximity(co1, co2, prev_status, new_status)
         }
     }
+
+    /// The events this signal has been triggered with since the last call to `clear_events`.
+    #[inline]
+    pub fn events(&self) -> &[ProximityEvent] {
+        &self.events[..]
+    }
+
+    /// Forgets the events recorded by this signal.
+    #[inline]
+    pub fn clear_events(&mut self) {
+        self.events.clear()
+    }
 }
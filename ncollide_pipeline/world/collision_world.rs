@@ -1,14 +1,16 @@
 use std::mem;
+use std::collections::HashSet;
+use std::cmp::Ordering;
 use std::vec::IntoIter;
 use math::{Point, Isometry};
 use utils::data::uid_remap::{UidRemap, FastKey};
 use utils::data::vec_map::Values;
 use geometry::bounding_volume::{self, BoundingVolume, AABB};
 use geometry::shape::ShapeHandle;
-use geometry::query::{RayCast, Ray, RayIntersection, PointQuery};
+use geometry::query::{RayCast, Ray, RayIntersection, PointQuery, QuerySettings};
 use narrow_phase::{NarrowPhase, DefaultNarrowPhase, DefaultContactDispatcher, DefaultProximityDispatcher,
-                   ContactHandler, ContactPairs, Contacts, ContactSignal, ProximityHandler,
-                   ProximitySignal, ProximityPairs};
+                   ContactAlgorithm, ContactHandler, ContactPairs, Contacts, ContactSignal, ContactEvent,
+                   ProximityHandler, ProximitySignal, ProximityPairs, ProximityEvent};
 use broad_phase::{BroadPhase, DBVTBroadPhase, BroadPhasePairFilter, BroadPhasePairFilters};
 use world::{CollisionObject, GeometricQueryType, CollisionGroups, CollisionGroupsPairFilter};
 
@@ -26,6 +28,7 @@ pub struct CollisionWorld<P: Point, M, T> {
     proximity_signal:  ProximitySignal<P, M, T>,
     pair_filters:      BroadPhasePairFilters<P, M, T>,
     pos_to_update:     Vec<(FastKey, M)>,
+    groups_to_update:  Vec<(FastKey, CollisionGroups)>,
     objects_to_remove: Vec<usize>,
     objects_to_add:    Vec<CollisionObject<P, M, T>>,
     timestamp:         usize
@@ -36,9 +39,20 @@ impl<P: Point, M: Isometry<P>, T> CollisionWorld<P, M, T> {
     /// Creates a new collision world.
     // FIXME: use default values for `margin` and allow its modification by the user ?
     pub fn new(margin: P::Real, small_uids: bool) -> CollisionWorld<P, M, T> {
+        CollisionWorld::new_with_settings(margin, small_uids, QuerySettings::default())
+    }
+
+    /// Creates a new collision world with the given numerical tolerances.
+    ///
+    /// The `settings` are used by the GJK-based contact and proximity algorithms of the default
+    /// narrow phase, e.g., to adapt them to large worlds with `f32` coordinates.
+    /// Point queries, e.g., `interferences_with_point`, ignore them and always use the default
+    /// settings because `PointQuery` takes none.
+    pub fn new_with_settings(margin: P::Real, small_uids: bool, settings: QuerySettings<P::Real>)
+                             -> CollisionWorld<P, M, T> {
         let objects          = UidRemap::new(small_uids);
-        let coll_dispatcher  = Box::new(DefaultContactDispatcher::new());
-        let prox_dispatcher  = Box::new(DefaultProximityDispatcher::new());
+        let coll_dispatcher  = Box::new(DefaultContactDispatcher::new_with_settings(settings));
+        let prox_dispatcher  = Box::new(DefaultProximityDispatcher::new_with_settings(settings));
         let broad_phase      = Box::new(DBVTBroadPhase::<P, AABB<P>, FastKey>::new(margin, true));
         let narrow_phase     = DefaultNarrowPhase::new(coll_dispatcher, prox_dispatcher);
         let contact_signal   = ContactSignal::new();
@@ -52,6 +66,7 @@ impl<P: Point, M: Isometry<P>, T> CollisionWorld<P, M, T> {
             narrow_phase:      Box::new(narrow_phase),
             pair_filters:      BroadPhasePairFilters::new(),
             pos_to_update:     Vec::new(),
+            groups_to_update:  Vec::new(),
             objects_to_remove: Vec::new(),
             objects_to_add:    Vec::new(),
             timestamp:         0
@@ -77,8 +92,9 @@ impl<P: Point, M: Isometry<P>, T> CollisionWorld<P, M, T> {
     /// Updates the collision world.
     ///
     /// This executes the whole collision detection pipeline: the broad phase first, then the
-    /// narrow phase.
+    /// narrow phase. The contact and proximity events of the previous update are cleared first.
     pub fn update(&mut self) {
+        self.clear_events();
         self.perform_position_update();
         self.perform_additions_removals_and_broad_phase(); // this will perform the Broad Phase as well.
         self.perform_narrow_phase();
@@ -108,6 +124,21 @@ impl<P: Point, M: Isometry<P>, T> CollisionWorld<P, M, T> {
         }
     }
 
+    /// Sets the collision groups the collision object attached to the specified object will have
+    /// during the next update.
+    ///
+    /// Keep in mind that this will have a non-trivial overhead during the next update as it will
+    /// force re-detection of all collision pairs.
+    pub fn deferred_set_collision_groups(&mut self, uid: usize, groups: CollisionGroups) {
+        if let Some(fk) = self.objects.get_fast_key(uid) {
+            self.groups_to_update.push((fk, groups))
+        }
+        else {
+            panic!("Attempting to set the collision groups of an unknown object. \
+                    Did you forgot to call `.update()` after `.deferred_add()`-ing your objects?");
+        }
+    }
+
     /// Adds a filter that tells if a potential collision pair should be ignored or not.
     ///
     /// The proximity filter returns `false` for a given pair of collision objects if they should
@@ -178,6 +209,17 @@ impl<P: Point, M: Isometry<P>, T> CollisionWorld<P, M, T> {
 
         self.objects_to_add.shrink_to_fit();
 
+        // Update the collision groups, the filtered pairs have to be re-detected.
+        if !self.groups_to_update.is_empty() {
+            for (fk, groups) in self.groups_to_update.drain(..) {
+                if let Some(co) = self.objects.get_fast_mut(&fk) {
+                    co.collision_groups = groups;
+                }
+            }
+
+            self.broad_phase.deferred_recompute_all_proximities();
+        }
+
 
         // Clean up objects that have been marked as removed
         for uid in self.objects_to_remove.iter() {
@@ -235,18 +277,126 @@ impl<P: Point, M: Isometry<P>, T> CollisionWorld<P, M, T> {
         old
     }
 
+    /// Sets a new broad phase and returns the previous one.
+    ///
+    /// This allows the use of another strategy than the default `DBVTBroadPhase`, e.g., a
+    /// `SweepAndPrune` for worlds with many coherently moving objects. All the objects already
+    /// added to the world are transferred to the new broad phase, which detects their proximities
+    /// immediately. The narrow phase keeps its contact and proximity algorithms for the pairs the
+    /// new broad phase detects, so no spurious event is triggered. The other pairs are removed
+    /// from the narrow phase, as if their proximity stopped.
+    pub fn set_broad_phase(&mut self, broad_phase: BroadPhaseObject<P>) -> BroadPhaseObject<P> {
+        let old = mem::replace(&mut self.broad_phase, broad_phase);
+
+        for (fk, co) in self.objects.iter() {
+            let mut aabb = bounding_volume::aabb(co.shape.as_ref(), &co.position);
+            aabb.loosen(co.query_type.query_limit());
+            self.broad_phase.deferred_add(fk.uid(), aabb, fk);
+        }
+
+        let mut detected = HashSet::new();
+
+        {
+            let bf    = &mut self.broad_phase;
+            let nf    = &mut self.narrow_phase;
+            let sig   = &mut self.contact_signal;
+            let prox  = &mut self.proximity_signal;
+            let filts = &self.pair_filters;
+            let objs  = &self.objects;
+
+            bf.update(
+                // Filter:
+                &mut |b1, b2| CollisionWorld::filter_collision(filts, objs, b1, b2),
+                // Handler:
+                &mut |b1, b2, started| {
+                    if started {
+                        let _ = detected.insert((b1.uid().min(b2.uid()), b1.uid().max(b2.uid())));
+                    }
+
+                    nf.handle_interaction(sig, prox, objs, b1, b2, started)
+                });
+        }
+
+        // The pairs the old broad phase detected but the new one does not.
+        let mut stale = Vec::new();
+
+        {
+            let objs     = &self.objects;
+            let mut check = |co1: &CollisionObject<P, M, T>, co2: &CollisionObject<P, M, T>| {
+                if !detected.contains(&(co1.uid.min(co2.uid), co1.uid.max(co2.uid))) {
+                    if let (Some(fk1), Some(fk2)) = (objs.get_fast_key(co1.uid), objs.get_fast_key(co2.uid)) {
+                        stale.push((fk1, fk2))
+                    }
+                }
+            };
+
+            for (co1, co2, _) in self.narrow_phase.contact_pairs(objs) {
+                check(co1, co2)
+            }
+
+            for (co1, co2, _) in self.narrow_phase.proximity_pairs(objs) {
+                check(co1, co2)
+            }
+        }
+
+        for (fk1, fk2) in stale.into_iter() {
+            self.narrow_phase.handle_interaction(&mut self.contact_signal, &mut self.proximity_signal,
+                                                 &self.objects, &fk1, &fk2, false)
+        }
+
+        old
+    }
+
     /// Iterates through all the contact pairs detected since the last update.
     #[inline]
     pub fn contact_pairs(&self) -> ContactPairs<P, M, T> {
         self.narrow_phase.contact_pairs(&self.objects)
     }
 
+    /// The contact pair between the collision objects identified by `uid1` and `uid2`, if any.
+    ///
+    /// A contact pair exists while the bounding volumes of both objects overlap, even if they
+    /// have no contact. The collision objects are returned in the order expected by the contact
+    /// algorithm.
+    #[inline]
+    pub fn contact_pair(&self, uid1: usize, uid2: usize)
+                        -> Option<(&CollisionObject<P, M, T>, &CollisionObject<P, M, T>, &ContactAlgorithm<P, M>)> {
+        match (self.objects.get_fast_key(uid1), self.objects.get_fast_key(uid2)) {
+            (Some(fk1), Some(fk2)) => self.narrow_phase.contact_pair(&self.objects, &fk1, &fk2),
+            _                      => None
+        }
+    }
+
     /// Iterates through all the proximity pairs detected since the last update.
     #[inline]
     pub fn proximity_pairs(&self) -> ProximityPairs<P, M, T> {
         self.narrow_phase.proximity_pairs(&self.objects)
     }
 
+    /// The contact events emitted since the last call to `.clear_events()`, usually during the
+    /// last update.
+    #[inline]
+    pub fn contact_events(&self) -> &[ContactEvent] {
+        self.contact_signal.events()
+    }
+
+    /// The proximity events emitted since the last call to `.clear_events()`, usually during the
+    /// last update.
+    #[inline]
+    pub fn proximity_events(&self) -> &[ProximityEvent] {
+        self.proximity_signal.events()
+    }
+
+    /// Forgets the contact and proximity events emitted so far.
+    ///
+    /// This is done automatically by `.update()`, but not by the individual steps of the
+    /// pipeline, e.g., `.perform_narrow_phase()`.
+    #[inline]
+    pub fn clear_events(&mut self) {
+        self.contact_signal.clear_events();
+        self.proximity_signal.clear_events();
+    }
+
     /// Iterates through every contact detected since the last update.
     #[inline]
     pub fn contacts(&self) -> Contacts<P, M, T> {
@@ -275,11 +425,82 @@ impl<P: Point, M: Isometry<P>, T> CollisionWorld<P, M, T> {
         self.broad_phase.interferences_with_ray(ray, &mut fks);
 
         InterferencesWithRay {
-            ray:     ray,
-            groups:  groups,
-            objects: &self.objects,
-            idx:     fks.into_iter()
+            ray:       ray,
+            groups:    groups,
+            member_of: None,
+            objects:   &self.objects,
+            idx:       fks.into_iter()
+        }
+    }
+
+    /// Computes the interferences between every rigid bodies on this world and a batch of rays.
+    ///
+    /// Each intersection is appended to `out` with the index of its ray in `rays`. The
+    /// intersections of a given ray are contiguous, and the rays are processed in order, on the
+    /// calling thread. Unlike repeated calls to `.interferences_with_ray(...)`, the broad phase
+    /// candidates of every ray are collected in a single buffer allocated once per batch, and
+    /// `out` can be reused from one batch to the next. The broad phase may still allocate on its
+    /// own.
+    pub fn interferences_with_rays<'a>(&'a self,
+                                       rays:   &[Ray<P>],
+                                       groups: &CollisionGroups,
+                                       out:    &mut Vec<(usize, &'a CollisionObject<P, M, T>, RayIntersection<P::Vector>)>) {
+        let mut fks = Vec::new();
+
+        for (i, ray) in rays.iter().enumerate() {
+            fks.clear();
+            self.broad_phase.interferences_with_ray(ray, &mut fks);
+
+            for fk in fks.iter() {
+                let co = &self.objects[**fk];
+
+                if co.collision_groups.can_interact_with_groups(groups) {
+                    if let Some(inter) = co.shape.toi_and_normal_with_ray(&co.position, ray, true) {
+                        out.push((i, co, inter))
+                    }
+                }
+            }
+        }
+    }
+
+    /// Computes the closest intersection of each ray of a batch with the objects on this world.
+    ///
+    /// The i-th element of the result is the intersection with the smallest time of impact of
+    /// `rays[i]`, or `None` if it does not hit anything. Ties are resolved as by
+    /// `InterferencesWithRay::closest`. As for `.interferences_with_rays(...)`, the broad phase
+    /// candidates of every ray are collected in a single buffer allocated once per batch.
+    pub fn closest_interferences_with_rays<'a>(&'a self, rays: &[Ray<P>], groups: &CollisionGroups)
+        -> Vec<Option<(&'a CollisionObject<P, M, T>, RayIntersection<P::Vector>)>> {
+        let mut res = Vec::with_capacity(rays.len());
+        let mut fks = Vec::new();
+
+        for ray in rays.iter() {
+            let mut closest: Option<(&'a CollisionObject<P, M, T>, RayIntersection<P::Vector>)> = None;
+
+            fks.clear();
+            self.broad_phase.interferences_with_ray(ray, &mut fks);
+
+            for fk in fks.iter() {
+                let co = &self.objects[**fk];
+
+                if co.collision_groups.can_interact_with_groups(groups) {
+                    if let Some(inter) = co.shape.toi_and_normal_with_ray(&co.position, ray, true) {
+                        let is_closer = match closest {
+                            Some((_, ref best)) => inter.toi < best.toi,
+                            None                => true
+                        };
+
+                        if is_closer {
+                            closest = Some((co, inter))
+                        }
+                    }
+                }
+            }
+
+            res.push(closest);
         }
+
+        res
     }
 
     /// Computes the interferences between every rigid bodies of a given broad phase, and a point.
@@ -332,10 +553,63 @@ impl<P: Point, M: Isometry<P>, T> CollisionWorld<P, M, T> {
 
 /// Iterator through all the objects on the world that intersect a specific ray.
 pub struct InterferencesWithRay<'a, P: 'a + Point, M: 'a, T: 'a> {
-    ray:     &'a Ray<P>,
-    objects: &'a UidRemap<CollisionObject<P, M, T>>,
-    groups:  &'a CollisionGroups,
-    idx:     IntoIter<&'a FastKey>,
+    ray:       &'a Ray<P>,
+    objects:   &'a UidRemap<CollisionObject<P, M, T>>,
+    groups:    &'a CollisionGroups,
+    member_of: Option<usize>,
+    idx:       IntoIter<&'a FastKey>,
+}
+
+impl<'a, P: Point, M: Isometry<P>, T> InterferencesWithRay<'a, P, M, T> {
+    /// The remaining intersection with the smallest time of impact.
+    ///
+    /// This consumes the iterator in a single pass, without allocation.
+    pub fn closest(self) -> Option<(&'a CollisionObject<P, M, T>, RayIntersection<P::Vector>)> {
+        let mut res: Option<(&'a CollisionObject<P, M, T>, RayIntersection<P::Vector>)> = None;
+
+        for (co, inter) in self {
+            let is_closer = match res {
+                Some((_, ref best)) => inter.toi < best.toi,
+                None                => true
+            };
+
+            if is_closer {
+                res = Some((co, inter))
+            }
+        }
+
+        res
+    }
+
+    /// Same as `closest`, but also returns the world-space hit point.
+    ///
+    /// The hit point is computed by `RayIntersection::point`.
+    pub fn closest_with_point(self) -> Option<(&'a CollisionObject<P, M, T>, RayIntersection<P::Vector>, P)> {
+        let ray = self.ray;
+
+        self.closest().map(|(co, inter)| {
+            let pt = inter.point(ray);
+            (co, inter, pt)
+        })
+    }
+
+    /// Restricts this iterator to the objects member of the collision group `group_id`.
+    pub fn filter_group(mut self, group_id: usize) -> InterferencesWithRay<'a, P, M, T> {
+        assert!(group_id <= CollisionGroups::max_group_id(),
+                "There are at most 30 groups indexed from 0 to 29 (included).");
+
+        self.member_of = Some(group_id);
+        self
+    }
+
+    /// Collects the remaining intersections, sorted by increasing time of impact.
+    pub fn sorted_by_toi(self) -> Vec<(&'a CollisionObject<P, M, T>, RayIntersection<P::Vector>)> {
+        let mut res: Vec<_> = self.collect();
+
+        res.sort_by(|a, b| a.1.toi.partial_cmp(&b.1.toi).unwrap_or(Ordering::Equal));
+
+        res
+    }
 }
 
 impl<'a, P: Point, M: Isometry<P>, T> Iterator for InterferencesWithRay<'a, P, M, T> {
@@ -346,7 +620,12 @@ impl<'a, P: Point, M: Isometry<P>, T> Iterator for InterferencesWithRay<'a, P, M
         while let Some(id) = self.idx.next() {
             let co = &self.objects[*id];
 
-            if co.collision_groups.can_interact_with_groups(self.groups) {
+            let is_member = match self.member_of {
+                Some(group_id) => co.collision_groups.is_member_of(group_id),
+                None           => true
+            };
+
+            if is_member && co.collision_groups.can_interact_with_groups(self.groups) {
                 let inter = co.shape.toi_and_normal_with_ray(&co.position, self.ray, true);
 
                 if let Some(inter) = inter {
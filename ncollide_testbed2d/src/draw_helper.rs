@@ -23,13 +23,13 @@ pub fn draw_colls<N: Real + ToPrimitive, T>(window: &mut graphics::RenderWindow,
         draw_line(
             window,
             &center,
-            &(center + c.2.normal * c.2.depth),
+            &(center + *c.2.normal * c.2.depth),
             &Color::new_rgb(255, 0, 0));
 
         draw_line(
             window,
             &center,
-            &(center + c.2.normal),
+            &(center + *c.2.normal),
             &Color::new_rgb(0, 0, 255));
     }
 }
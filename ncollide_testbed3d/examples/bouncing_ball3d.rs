@@ -88,11 +88,11 @@ impl ContactHandler<Point3<f32>, Isometry3<f32>, CollisionObjectData> for Veloci
 
         // The ball is the one with a non-None velocity.
         if let Some(ref vel) = co1.data.velocity {
-            let normal = collector[0].normal;
+            let normal = collector[0].normal.unwrap();
             vel.set(vel.get() - 2.0 * na::dot(&vel.get(), &normal) * normal);
         }
         if let Some(ref vel) = co2.data.velocity {
-            let normal = -collector[0].normal;
+            let normal = -collector[0].normal.unwrap();
             vel.set(vel.get() - 2.0 * na::dot(&vel.get(), &normal) * normal);
         }
     }
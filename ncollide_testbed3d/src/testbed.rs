@@ -340,7 +340,7 @@ fn draw_collisions<T>(window: &mut Window, world: &mut CollisionWorld3<f32, T>)
         window.draw_line(&c.2.world1, &c.2.world2, &Point3::new(1.0, 0.0, 0.0));
 
         let center = na::center(&c.2.world1, &c.2.world2);
-        let end    = center + c.2.normal * 0.4f32;
+        let end    = center + *c.2.normal * 0.4f32;
         window.draw_line(&center, &end, &Point3::new(0.0, 1.0, 1.0))
     }
 }
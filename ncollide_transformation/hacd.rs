@@ -9,12 +9,13 @@ use na::{Translation3, Point3, Vector2, Vector3};
 use na;
 
 use utils;
-use geometry::shape::SupportMap;
+use geometry::shape::{self, SupportMap, ConvexHull};
 use geometry::bounding_volume::{self, BoundingVolume, AABB};
 use geometry::partitioning::{BVT, BoundingVolumeInterferencesCollector};
 use geometry::query::algorithms::johnson_simplex::JohnsonSimplex;
 use geometry::query::{ray_internal, Ray, RayCast, RayIntersection};
 use procedural::{TriMesh, IndexBuffer};
+use to_trimesh::ToTriMesh;
 
 /// Approximate convex decomposition of a triangle mesh.
 pub fn hacd<N: Real>(mesh:           TriMesh<Point3<N>>,
@@ -147,6 +148,26 @@ pub fn hacd<N: Real>(mesh:           TriMesh<Point3<N>>,
     (result, parts)
 }
 
+/// Approximate convex decomposition of a triangle mesh shape into convex hulls.
+///
+/// This is `hacd` applied to `mesh`, with its vertex normals recomputed if it has none. Each
+/// part is returned as the `ConvexHull` of the vertices of its convex hull, in the same frame as
+/// `mesh`: the parts can be put together into a `Compound` with identity local positions.
+pub fn hacd_convex_hulls<N: Real>(mesh:           &shape::TriMesh<Point3<N>>,
+                                  error:          N,
+                                  min_components: usize)
+                                  -> Vec<ConvexHull<Point3<N>>> {
+    let mut mesh = mesh.to_trimesh(());
+
+    if !mesh.has_normals() {
+        mesh.recompute_normals();
+    }
+
+    let (decomp, _) = hacd(mesh, error, min_components);
+
+    decomp.into_iter().map(|part| ConvexHull::new(part.coords)).collect()
+}
+
 fn normalize<N: Real>(mesh: &mut TriMesh<Point3<N>>) -> (Point3<N>, N) {
     let (mins, maxs) = bounding_volume::point_cloud_aabb(&Id::new(), &mesh.coords[..]);
     let diag   = na::distance(&mins, &maxs);
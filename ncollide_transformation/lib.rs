@@ -21,12 +21,16 @@ extern crate ncollide_geometry as geometry;
 extern crate ncollide_procedural as procedural;
 
 
-pub use to_trimesh::ToTriMesh;
-pub use to_polyline::ToPolyline;
-pub use hacd::hacd;
+pub use to_trimesh::{ToTriMesh, compound_to_trimesh};
+pub use to_polyline::{ToPolyline, compound_to_polylines};
+pub use hacd::{hacd, hacd_convex_hulls};
 pub use convex_hull3::convex_hull3;
 pub use convex_hull2::{convex_hull2, convex_hull2_idx};
 pub use triangulate::triangulate;
+pub use split_convex::split_convex;
+pub use subdivide::{subdivide, subdivide_onto};
+pub use merge_meshes::merge_meshes;
+pub use try_from_trimesh::TryFromTriMesh;
 
 mod to_trimesh;
 mod to_polyline;
@@ -36,3 +40,7 @@ pub mod convex_hull_utils; // Internal implementation details.
 mod convex_hull2;
 mod convex_hull3;
 mod triangulate;
+mod split_convex;
+mod subdivide;
+mod merge_meshes;
+mod try_from_trimesh;
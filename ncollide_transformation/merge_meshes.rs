@@ -0,0 +1,137 @@
+use std::sync::Arc;
+use std::collections::HashMap;
+
+use na::{self, Point3};
+use geometry::shape::TriMesh;
+use math::{Point, Isometry};
+
+/// Merges several positioned triangle meshes into a single mesh.
+///
+/// The vertices of each mesh are transformed by its isometry, and the indices of its triangles
+/// are shifted to address its vertices on the merged mesh. The resulting mesh has a single BVT
+/// containing the triangles of every mesh. Its normals are rotated accordingly. The normals, or
+/// the texture coordinates, are kept only if every mesh has some.
+///
+/// If `weld_distance` is set, it must be strictly positive. Then, the vertices closer than this
+/// distance are merged into the first of them, and the triangles made degenerate by this welding
+/// are removed. The normals and the texture coordinates of the merged vertices are the ones of
+/// the vertex they are merged into.
+pub fn merge_meshes<P, M>(meshes: &[(M, &TriMesh<P>)], weld_distance: Option<P::Real>) -> TriMesh<P>
+    where P: Point,
+          M: Isometry<P> {
+    let keep_normals = meshes.iter().all(|&(_, mesh)| mesh.normals().is_some());
+    let keep_uvs     = meshes.iter().all(|&(_, mesh)| mesh.uvs().is_some());
+
+    let mut vertices = Vec::new();
+    let mut indices  = Vec::new();
+    let mut normals  = Vec::new();
+    let mut uvs      = Vec::new();
+
+    for &(ref m, mesh) in meshes.iter() {
+        let shift = vertices.len();
+
+        vertices.extend(mesh.vertices().iter().map(|pt| m.transform_point(pt)));
+        indices.extend(mesh.indices().iter().map(|idx| Point3::new(idx.x + shift, idx.y + shift, idx.z + shift)));
+
+        if keep_normals {
+            let ns = mesh.normals().as_ref().unwrap();
+            normals.extend(ns.iter().map(|n| m.rotate_vector(n)));
+        }
+
+        if keep_uvs {
+            uvs.extend(mesh.uvs().as_ref().unwrap().iter().cloned());
+        }
+    }
+
+    if let Some(weld_distance) = weld_distance {
+        let remap = weld_vertices(&vertices[..], weld_distance);
+        let mut kept = vec![usize::max_value(); vertices.len()];
+        let mut welded_vertices = Vec::new();
+        let mut welded_normals  = Vec::new();
+        let mut welded_uvs      = Vec::new();
+
+        for (i, &j) in remap.iter().enumerate() {
+            if i == j {
+                kept[i] = welded_vertices.len();
+                welded_vertices.push(vertices[i]);
+
+                if keep_normals {
+                    welded_normals.push(normals[i]);
+                }
+
+                if keep_uvs {
+                    welded_uvs.push(uvs[i]);
+                }
+            }
+        }
+
+        indices = indices.into_iter().filter_map(|idx| {
+            let idx = Point3::new(kept[remap[idx.x]], kept[remap[idx.y]], kept[remap[idx.z]]);
+
+            if idx.x == idx.y || idx.y == idx.z || idx.z == idx.x {
+                None
+            }
+            else {
+                Some(idx)
+            }
+        }).collect();
+
+        vertices = welded_vertices;
+        normals  = welded_normals;
+        uvs      = welded_uvs;
+    }
+
+    TriMesh::new(Arc::new(vertices),
+                 Arc::new(indices),
+                 if keep_uvs { Some(Arc::new(uvs)) } else { None },
+                 if keep_normals { Some(Arc::new(normals)) } else { None })
+}
+
+// Maps each vertex to the first vertex closer than `distance` to it, possibly itself.
+//
+// The vertices are hashed on a grid with cells of width `distance` so that only the vertices on
+// adjacent cells are compared.
+fn weld_vertices<P: Point>(vertices: &[P], distance: P::Real) -> Vec<usize> {
+    assert!(distance > na::zero(), "The welding distance must be strictly positive.");
+
+    let dim        = na::dimension::<P::Vector>();
+    let sqdistance = distance * distance;
+    let mut grid   = HashMap::<Vec<i64>, Vec<usize>>::new();
+    let mut remap  = Vec::with_capacity(vertices.len());
+
+    let cell_of = |pt: &P| -> Vec<i64> {
+        (0 .. dim).map(|i| na::try_convert::<P::Real, f64>(pt[i] / distance).unwrap().floor() as i64).collect()
+    };
+
+    for (i, pt) in vertices.iter().enumerate() {
+        let cell     = cell_of(pt);
+        let mut best = i;
+
+        // Visit the 3^dim cells around the cell of `pt`.
+        for k in 0 .. 3usize.pow(dim as u32) {
+            let mut neighbor = cell.clone();
+            let mut code     = k;
+
+            for c in neighbor.iter_mut() {
+                *c   += (code % 3) as i64 - 1;
+                code /= 3;
+            }
+
+            if let Some(candidates) = grid.get(&neighbor) {
+                for &j in candidates.iter() {
+                    if j < best && na::distance_squared(pt, &vertices[j]) <= sqdistance {
+                        best = j;
+                    }
+                }
+            }
+        }
+
+        remap.push(best);
+
+        if best == i {
+            grid.entry(cell).or_insert_with(Vec::new).push(i);
+        }
+    }
+
+    remap
+}
@@ -0,0 +1,64 @@
+use approx::ApproxEq;
+use na;
+use geometry::shape::{ConvexHull, Plane};
+use math::{Point, Isometry};
+
+/// Cuts a convex hull by a plane and returns the two resulting convex pieces.
+///
+/// The plane is positioned by `m` in the local space of `convex`. The first piece lies on the
+/// side of the plane its normal points toward, the second one on the other side. A piece with no
+/// volume, i.e., with no vertex strictly on its side of the plane, is `None`.
+///
+/// The vertices of each piece are the vertices of `convex` on its side of the plane, together with
+/// the cross-section of `convex` by the plane. This cross-section is computed by intersecting the
+/// plane with every segment joining two vertices on opposite sides. Thus, a cuboid can be split by
+/// first building the convex hull of its corners.
+pub fn split_convex<P, M>(convex: &ConvexHull<P>, m: &M, plane: &Plane<P::Vector>)
+                          -> (Option<ConvexHull<P>>, Option<ConvexHull<P>>)
+    where P: Point,
+          M: Isometry<P> {
+    let center = m.transform_point(&P::origin());
+    let normal = m.rotate_vector(plane.normal());
+    let eps    = P::Real::default_epsilon();
+
+    let mut above   = Vec::new();
+    let mut below   = Vec::new();
+    let mut section = Vec::new();
+
+    for pt in convex.points().iter() {
+        let dist = na::dot(&(*pt - center), &normal);
+
+        if dist > eps {
+            above.push((*pt, dist))
+        }
+        else if dist < -eps {
+            below.push((*pt, dist))
+        }
+        else {
+            section.push(*pt)
+        }
+    }
+
+    // Cross-section.
+    for &(pa, da) in above.iter() {
+        for &(pb, db) in below.iter() {
+            let t = da / (da - db);
+
+            section.push(pa + (pb - pa) * t)
+        }
+    }
+
+    let piece = |side: Vec<(P, P::Real)>| {
+        if side.is_empty() {
+            None
+        }
+        else {
+            let mut pts: Vec<P> = side.into_iter().map(|(pt, _)| pt).collect();
+            pts.extend(section.iter().cloned());
+
+            Some(ConvexHull::new(pts))
+        }
+    };
+
+    (piece(above), piece(below))
+}
@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+
+use na::{self, Point3};
+use math::Point;
+use procedural::{TriMesh, IndexBuffer};
+
+/// Subdivides each triangle of a mesh into four triangles, `levels` times.
+///
+/// Each triangle is split by the midpoints of its edges. Those midpoints are shared by the two
+/// triangles adjacent to each edge so that the result is a proper indexed mesh without cracks. The
+/// normals and texture coordinates of the new vertices, if any, are interpolated from the edge
+/// endpoints.
+pub fn subdivide<P: Point>(mesh: &TriMesh<P>, levels: usize) -> TriMesh<P> {
+    subdivide_onto(mesh, levels, &mut |pt| *pt)
+}
+
+/// Subdivides each triangle of a mesh into four triangles, `levels` times, and moves the new
+/// vertices with `project`.
+///
+/// This is the same as `subdivide` except that each edge midpoint is replaced by its image by
+/// `project`, e.g., its projection on the surface the mesh approximates. The interpolated
+/// normals are not affected by `project`.
+pub fn subdivide_onto<P, F>(mesh: &TriMesh<P>, levels: usize, project: &mut F) -> TriMesh<P>
+    where P: Point,
+          F: FnMut(&P) -> P {
+    let mut res = mesh.clone();
+    res.unify_index_buffer();
+
+    let mut indices = res.indices.unwrap_unified();
+    let _0_5: P::Real = na::convert(0.5f64);
+
+    for _ in 0 .. levels {
+        let mut midpoints: HashMap<(u32, u32), u32> = HashMap::new();
+        let mut new_indices = Vec::with_capacity(indices.len() * 4);
+
+        {
+            let coords  = &mut res.coords;
+            let normals = &mut res.normals;
+            let uvs     = &mut res.uvs;
+
+            let mut midpoint = |a: u32, b: u32| -> u32 {
+                let key = if a < b { (a, b) } else { (b, a) };
+
+                match midpoints.entry(key) {
+                    Entry::Occupied(e) => *e.get(),
+                    Entry::Vacant(e) => {
+                        let (ia, ib) = (a as usize, b as usize);
+                        let mid = na::center(&coords[ia], &coords[ib]);
+
+                        coords.push(project(&mid));
+
+                        if let Some(ref mut ns) = *normals {
+                            let n = (ns[ia] + ns[ib]) * _0_5;
+                            let n = na::try_normalize(&n, na::zero()).unwrap_or(ns[ia]);
+                            ns.push(n);
+                        }
+
+                        if let Some(ref mut us) = *uvs {
+                            let uv = na::center(&us[ia], &us[ib]);
+                            us.push(uv);
+                        }
+
+                        *e.insert(coords.len() as u32 - 1)
+                    }
+                }
+            };
+
+            for t in indices.iter() {
+                let ab = midpoint(t.x, t.y);
+                let bc = midpoint(t.y, t.z);
+                let ca = midpoint(t.z, t.x);
+
+                new_indices.push(Point3::new(t.x, ab, ca));
+                new_indices.push(Point3::new(ab, t.y, bc));
+                new_indices.push(Point3::new(ca, bc, t.z));
+                new_indices.push(Point3::new(ab, bc, ca));
+            }
+        }
+
+        indices = new_indices;
+    }
+
+    res.indices = IndexBuffer::Unified(indices);
+
+    res
+}
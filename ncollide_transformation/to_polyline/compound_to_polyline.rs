@@ -0,0 +1,124 @@
+use alga::general::Real;
+use na::{Point2, Isometry2};
+use geometry::shape::{Shape2, Ball2, Capsule2, Compound2, Cone2, ConvexHull2, ConvexPolygon2, Cuboid2, Cylinder2,
+                      Ellipsoid2, Segment2, Triangle2, WithMargin};
+use procedural::{Polyline, Polyline2};
+use super::ToPolyline;
+
+impl<N: Real> ToPolyline<Point2<N>, u32> for Compound2<N> {
+    /// Concatenates the closed polylines of the parts of this compound, see `compound_to_polylines`.
+    ///
+    /// The polyline of each part is closed by repeating its first point before the polyline of
+    /// the next part starts. Thus, the polylines of consecutive parts are linked by a segment.
+    /// The normals are kept only if every part has some.
+    ///
+    /// # Panics
+    /// If a part of this compound has no discretization.
+    fn to_polyline(&self, nsubdiv: u32) -> Polyline2<N> {
+        let polylines = match compound_to_polylines(self, nsubdiv) {
+            Ok(polylines) => polylines,
+            Err(i)        => panic!("The part {} of this compound cannot be discretized.", i)
+        };
+
+        let keep_normals = polylines.iter().all(|polyline| polyline.normals().is_some());
+        let mut coords   = Vec::new();
+        let mut normals  = Vec::new();
+
+        for polyline in polylines.into_iter() {
+            let (pts, ns) = polyline.unwrap();
+
+            if let Some(first) = pts.first().cloned() {
+                coords.extend(pts.into_iter());
+                coords.push(first);
+
+                if keep_normals {
+                    let ns = ns.unwrap();
+                    let first_normal = ns[0];
+
+                    normals.extend(ns.into_iter());
+                    normals.push(first_normal);
+                }
+            }
+        }
+
+        Polyline::new(coords, if keep_normals { Some(normals) } else { None })
+    }
+}
+
+/// The closed polylines of the parts of a 2D compound, transformed by their poses.
+///
+/// The curved parts are discretized with `nsubdiv` subdivisions. The parts of nested compounds
+/// give one polyline each. Each part must be a shape with a `ToPolyline` implementation, i.e., a
+/// ball, capsule, cone, cuboid, cylinder, ellipse, convex hull, convex polygon, segment,
+/// triangle, shape with a margin, or another compound. Otherwise, the index of the first part
+/// without a discretization is returned as an error.
+pub fn compound_to_polylines<N: Real>(compound: &Compound2<N>, nsubdiv: u32) -> Result<Vec<Polyline2<N>>, usize> {
+    let mut res = Vec::with_capacity(compound.shapes().len());
+
+    for (i, &(ref m, ref shape)) in compound.shapes().iter().enumerate() {
+        let first = res.len();
+
+        if !push_shape_polylines(&**shape, nsubdiv, &mut res) {
+            return Err(i);
+        }
+
+        for polyline in res[first ..].iter_mut() {
+            polyline.transform_by(m);
+        }
+    }
+
+    Ok(res)
+}
+
+// Pushes the polylines of a shape to `out`. Returns `false` if it cannot be discretized.
+fn push_shape_polylines<N: Real>(shape: &Shape2<N>, nsubdiv: u32, out: &mut Vec<Polyline2<N>>) -> bool {
+    let polyline = if let Some(s) = shape.as_shape::<Ball2<N>>() {
+        s.to_polyline(nsubdiv)
+    }
+    else if let Some(s) = shape.as_shape::<Capsule2<N>>() {
+        s.to_polyline(nsubdiv)
+    }
+    else if let Some(s) = shape.as_shape::<Cone2<N>>() {
+        s.to_polyline(())
+    }
+    else if let Some(s) = shape.as_shape::<Cylinder2<N>>() {
+        s.to_polyline(())
+    }
+    else if let Some(s) = shape.as_shape::<Cuboid2<N>>() {
+        s.to_polyline(())
+    }
+    else if let Some(s) = shape.as_shape::<Ellipsoid2<N>>() {
+        s.to_polyline(nsubdiv)
+    }
+    else if let Some(s) = shape.as_shape::<WithMargin<Point2<N>, Isometry2<N>>>() {
+        s.to_polyline(nsubdiv)
+    }
+    else if let Some(s) = shape.as_shape::<ConvexHull2<N>>() {
+        s.to_polyline(())
+    }
+    else if let Some(s) = shape.as_shape::<ConvexPolygon2<N>>() {
+        s.to_polyline(())
+    }
+    else if let Some(s) = shape.as_shape::<Segment2<N>>() {
+        s.to_polyline(())
+    }
+    else if let Some(s) = shape.as_shape::<Triangle2<N>>() {
+        s.to_polyline(())
+    }
+    else if let Some(s) = shape.as_shape::<Compound2<N>>() {
+        return match compound_to_polylines(s, nsubdiv) {
+            Ok(polylines) => {
+                out.extend(polylines.into_iter());
+                true
+            },
+            Err(_) => false
+        };
+    }
+    else {
+        return false;
+    };
+
+    out.push(polyline);
+
+    true
+}
@@ -0,0 +1,18 @@
+use alga::general::Real;
+use na::Point2;
+use geometry::shape::{ConvexHull2, ConvexPolygon2};
+use procedural::{Polyline, Polyline2};
+use super::ToPolyline;
+
+impl<N: Real> ToPolyline<Point2<N>, ()> for ConvexHull2<N> {
+    /// Builds the convex hull of the points of this shape.
+    fn to_polyline(&self, _: ()) -> Polyline2<N> {
+        ::convex_hull2(self.points())
+    }
+}
+
+impl<N: Real> ToPolyline<Point2<N>, ()> for ConvexPolygon2<N> {
+    fn to_polyline(&self, _: ()) -> Polyline2<N> {
+        Polyline::new(self.points().to_vec(), None)
+    }
+}
@@ -1,42 +1,29 @@
-use na;
-use math::Point;
+use alga::general::Real;
+use na::{Point2, Isometry2};
 use geometry::shape::MinkowskiSum;
-use procedural::Polyline;
+use procedural::Polyline2;
 use super::ToPolyline;
 
 
-// XXX: Implemented this for other dimensions (harder because of the concavities.
-impl<'a, P, M, G1, G2, A, B> ToPolyline<P, (A, B)> for MinkowskiSum<'a, M, G1, G2>
-    where P:  Point,
-          G1: ToPolyline<P, A>,
-          G2: ToPolyline<P, B> {
-    fn to_polyline(&self, (a, b): (A, B)) -> Polyline<P> {
-        assert!(na::dimension::<P>() == 2);
+// XXX: Implement this for other dimensions (harder because of the concavities.
+impl<'a, N, G1: ?Sized, G2: ?Sized, A, B> ToPolyline<Point2<N>, (A, B)> for MinkowskiSum<'a, Isometry2<N>, G1, G2>
+    where N:  Real,
+          G1: ToPolyline<Point2<N>, A>,
+          G2: ToPolyline<Point2<N>, B> {
+    /// Builds the convex hull of the sums of the vertices of the discretizations of both shapes.
+    ///
+    /// The result is exact only if both shapes are convex.
+    fn to_polyline(&self, (a, b): (A, B)) -> Polyline2<N> {
+        let mut poly1 = self.g1().to_polyline(a);
+        let mut poly2 = self.g2().to_polyline(b);
 
-        let poly1 = self.g1().to_polyline(a);
-        let poly2 = self.g2().to_polyline(b);
+        poly1.transform_by(self.m1());
+        poly2.transform_by(self.m2());
 
-        // FIXME: this will work only for convex polyhedras.
         let mut all_points = Vec::with_capacity(poly1.coords().len() * poly2.coords().len());
 
-        let p1;
-        let p2;
-
-        if poly1.coords().len() > poly2.coords().len() {
-            p1 = poly1;
-            p2 = poly2;
-        }
-        else {
-            p1 = poly2;
-            p2 = poly1;
-        }
-
-        for pt in p2.coords().iter() {
-            let mut cpy = p1.clone();
-
-            cpy += pt.coordinates();
-
-            all_points.extend(cpy.coords().into_iter());
+        for pt in poly2.coords().iter() {
+            all_points.extend(poly1.coords().iter().map(|p| *p + pt.coords));
         }
 
         ::convex_hull2(&all_points[..])
@@ -1,14 +1,19 @@
 pub use self::to_polyline::ToPolyline;
+pub use self::compound_to_polyline::compound_to_polylines;
 
 #[doc(hidden)]
 pub mod to_polyline;
 
 mod ball_to_polyline;
 mod capsule_to_polyline;
+mod compound_to_polyline;
 mod cone_to_polyline;
+mod convex_to_polyline;
 mod cuboid_to_polyline;
 mod cylinder_to_polyline;
-// mod minkowski_sum_to_polyline;
+mod ellipsoid_to_polyline;
+mod minkowski_sum_to_polyline;
 mod reflection_to_polyline;
+mod round_shape_to_polyline;
 mod segment_to_polyline;
 mod triangle_to_polyline;
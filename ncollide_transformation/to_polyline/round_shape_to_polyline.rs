@@ -0,0 +1,23 @@
+use alga::general::Real;
+use na::{self, Point2, Isometry2};
+use geometry::shape::{RoundShape, SupportMap};
+use procedural::Polyline2;
+use procedural;
+use super::ToPolyline;
+
+impl<N: Real, S: SupportMap<Point2<N>, Isometry2<N>>> ToPolyline<Point2<N>, u32> for RoundShape<S, N> {
+    /// Maps each vertex of a discretized unit circle to the support point of this shape in its
+    /// direction.
+    ///
+    /// This also applies to a `WithMargin` shape.
+    fn to_polyline(&self, nsubdiv: u32) -> Polyline2<N> {
+        let mut polyline: Polyline2<N> = procedural::circle(&na::convert(2.0f64), nsubdiv);
+        let id = Isometry2::identity();
+
+        for pt in polyline.coords_mut().iter_mut() {
+            *pt = self.support_point(&id, &pt.coords);
+        }
+
+        polyline
+    }
+}
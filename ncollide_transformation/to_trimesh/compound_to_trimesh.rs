@@ -0,0 +1,116 @@
+use alga::general::Real;
+use na::{Point3, Isometry3};
+use geometry::shape::{Shape3, Ball3, Capsule3, Compound3, Cone3, ConvexHull3, Cuboid3, Cylinder3, Ellipsoid3,
+                      HeightField, Segment3, Triangle3, TriMesh3, WithMargin};
+use procedural::{self, IndexBuffer};
+use super::ToTriMesh;
+
+impl<N: Real> ToTriMesh<Point3<N>, u32> for Compound3<N> {
+    /// Merges the discretizations of the parts of this compound, see `compound_to_trimesh`.
+    ///
+    /// # Panics
+    /// If a part of this compound has no discretization.
+    fn to_trimesh(&self, nsubdiv: u32) -> procedural::TriMesh3<N> {
+        match compound_to_trimesh(self, nsubdiv) {
+            Ok(mesh) => mesh,
+            Err(i)   => panic!("The part {} of this compound cannot be discretized.", i)
+        }
+    }
+}
+
+/// Merges the discretizations of the parts of a compound, transformed by their poses.
+///
+/// The curved parts are discretized with `nsubdiv` subdivisions along each direction. Each part
+/// must be a shape with a `ToTriMesh` implementation, i.e., a ball, capsule, cone, cuboid,
+/// cylinder, ellipsoid, convex hull, segment, triangle, triangle mesh, heightfield, shape with a
+/// margin, or another compound. Otherwise, the index of the first part without a discretization
+/// is returned as an error. The normals, or the texture coordinates, are kept only if every part
+/// has some.
+pub fn compound_to_trimesh<N: Real>(compound: &Compound3<N>, nsubdiv: u32) -> Result<procedural::TriMesh3<N>, usize> {
+    let mut meshes = Vec::with_capacity(compound.shapes().len());
+
+    for (i, &(ref m, ref shape)) in compound.shapes().iter().enumerate() {
+        match shape_to_trimesh(&**shape, nsubdiv) {
+            Some(mut mesh) => {
+                mesh.unify_index_buffer();
+                mesh.transform_by(m);
+                meshes.push(mesh);
+            },
+            None => return Err(i)
+        }
+    }
+
+    let keep_normals = meshes.iter().all(|mesh| mesh.has_normals());
+    let keep_uvs     = meshes.iter().all(|mesh| mesh.has_uvs());
+
+    let mut coords  = Vec::new();
+    let mut normals = Vec::new();
+    let mut uvs     = Vec::new();
+    let mut indices = Vec::new();
+
+    for mesh in meshes.into_iter() {
+        let offset = coords.len() as u32;
+
+        coords.extend(mesh.coords.into_iter());
+
+        if keep_normals {
+            normals.extend(mesh.normals.unwrap().into_iter());
+        }
+
+        if keep_uvs {
+            uvs.extend(mesh.uvs.unwrap().into_iter());
+        }
+
+        indices.extend(mesh.indices.unwrap_unified().into_iter().map(|t| Point3::new(t.x + offset, t.y + offset, t.z + offset)));
+    }
+
+    Ok(procedural::TriMesh::new(coords,
+                                if keep_normals { Some(normals) } else { None },
+                                if keep_uvs { Some(uvs) } else { None },
+                                Some(IndexBuffer::Unified(indices))))
+}
+
+fn shape_to_trimesh<N: Real>(shape: &Shape3<N>, nsubdiv: u32) -> Option<procedural::TriMesh3<N>> {
+    if let Some(s) = shape.as_shape::<Ball3<N>>() {
+        Some(s.to_trimesh((nsubdiv, nsubdiv)))
+    }
+    else if let Some(s) = shape.as_shape::<Capsule3<N>>() {
+        Some(s.to_trimesh((nsubdiv, nsubdiv)))
+    }
+    else if let Some(s) = shape.as_shape::<Cone3<N>>() {
+        Some(s.to_trimesh(nsubdiv))
+    }
+    else if let Some(s) = shape.as_shape::<Cylinder3<N>>() {
+        Some(s.to_trimesh(nsubdiv))
+    }
+    else if let Some(s) = shape.as_shape::<Cuboid3<N>>() {
+        Some(s.to_trimesh(()))
+    }
+    else if let Some(s) = shape.as_shape::<Ellipsoid3<N>>() {
+        Some(s.to_trimesh((nsubdiv, nsubdiv)))
+    }
+    else if let Some(s) = shape.as_shape::<WithMargin<Point3<N>, Isometry3<N>>>() {
+        Some(s.to_trimesh((nsubdiv, nsubdiv)))
+    }
+    else if let Some(s) = shape.as_shape::<ConvexHull3<N>>() {
+        Some(s.to_trimesh(()))
+    }
+    else if let Some(s) = shape.as_shape::<Segment3<N>>() {
+        Some(s.to_trimesh(()))
+    }
+    else if let Some(s) = shape.as_shape::<Triangle3<N>>() {
+        Some(s.to_trimesh(()))
+    }
+    else if let Some(s) = shape.as_shape::<TriMesh3<N>>() {
+        Some(s.to_trimesh(()))
+    }
+    else if let Some(s) = shape.as_shape::<HeightField<N>>() {
+        Some(s.to_trimesh(()))
+    }
+    else if let Some(s) = shape.as_shape::<Compound3<N>>() {
+        compound_to_trimesh(s, nsubdiv).ok()
+    }
+    else {
+        None
+    }
+}
@@ -0,0 +1,12 @@
+use alga::general::Real;
+use na::Point3;
+use geometry::shape::ConvexHull3;
+use procedural::TriMesh3;
+use super::ToTriMesh;
+
+impl<N: Real> ToTriMesh<Point3<N>, ()> for ConvexHull3<N> {
+    /// Builds the convex hull of the points of this shape.
+    fn to_trimesh(&self, _: ()) -> TriMesh3<N> {
+        ::convex_hull3(self.points())
+    }
+}
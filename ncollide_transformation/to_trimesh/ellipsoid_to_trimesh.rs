@@ -0,0 +1,25 @@
+use alga::general::Real;
+use na::{self, Point3};
+use geometry::shape::Ellipsoid3;
+use procedural::TriMesh3;
+use procedural;
+use super::ToTriMesh;
+
+impl<N: Real> ToTriMesh<Point3<N>, (u32, u32)> for Ellipsoid3<N> {
+    /// Scales a discretized unit sphere by the radii of this ellipsoid.
+    fn to_trimesh(&self, (ntheta_subdiv, nphi_subdiv): (u32, u32)) -> TriMesh3<N> {
+        let radii    = *self.radii();
+        let mut mesh = procedural::sphere(na::convert(2.0f64), ntheta_subdiv, nphi_subdiv, true);
+
+        mesh.scale_by(&radii);
+
+        // The normals are transformed by the inverse of the scaling.
+        if let Some(ref mut normals) = mesh.normals {
+            for n in normals.iter_mut() {
+                *n = na::normalize(&n.component_div(&radii));
+            }
+        }
+
+        mesh
+    }
+}
@@ -0,0 +1,44 @@
+use alga::general::Real;
+use na::{self, Point2, Point3};
+use geometry::shape::HeightField;
+use procedural::{TriMesh, TriMesh3, IndexBuffer};
+use super::ToTriMesh;
+
+impl<N: Real> ToTriMesh<Point3<N>, ()> for HeightField<N> {
+    /// Builds the two triangles of each cell of this heightfield.
+    ///
+    /// Each grid node is a single vertex shared by the cells around it. The vertex of the node at
+    /// the `i`-th row and `j`-th column has the index `i * (self.num_cells_x() + 1) + j`, and the
+    /// texture coordinates `(j / self.num_cells_x(), i / self.num_cells_z())`.
+    fn to_trimesh(&self, _: ()) -> TriMesh3<N> {
+        let nrows = self.num_cells_z() + 1;
+        let ncols = self.num_cells_x() + 1;
+
+        let mut coords  = Vec::with_capacity(nrows * ncols);
+        let mut uvs     = Vec::with_capacity(nrows * ncols);
+        let mut indices = Vec::with_capacity(2 * self.num_cells_x() * self.num_cells_z());
+
+        for i in 0 .. nrows {
+            for j in 0 .. ncols {
+                coords.push(self.node_at(i, j));
+                uvs.push(Point2::new(na::convert::<f64, N>(j as f64) / na::convert(self.num_cells_x() as f64),
+                                     na::convert::<f64, N>(i as f64) / na::convert(self.num_cells_z() as f64)));
+            }
+        }
+
+        for i in 0 .. nrows - 1 {
+            for j in 0 .. ncols - 1 {
+                let p00 = (i * ncols + j) as u32;
+                let p01 = p00 + 1;
+                let p10 = p00 + ncols as u32;
+                let p11 = p10 + 1;
+
+                // Same triangles as `HeightField::triangles_at`.
+                indices.push(Point3::new(p00, p10, p11));
+                indices.push(Point3::new(p00, p11, p01));
+            }
+        }
+
+        TriMesh::new(coords, None, Some(uvs), Some(IndexBuffer::Unified(indices)))
+    }
+}
@@ -1,51 +1,30 @@
-use std::ops::{Mul, Add};
-use num::Zero;
-use na::{Outer, EigenQR, Translate };
-use na;
+use alga::general::Real;
+use na::{Point3, Isometry3};
 use geometry::shape::MinkowskiSum;
-use procedural::TriMesh;
+use procedural::TriMesh3;
 use super::ToTriMesh;
-use math::{Point, Vector};
 
 
-// XXX: Implemente this for other dimensions (harder because of the concavities.
-impl<'a, P, M1, G1, G2, A, B> ToTriMesh<P, (A, B)> for MinkowskiSum<'a, M1, G1, G2>
-    where P:  Point,
-          P::Vector: Translate<P> + Outer + Mul<<<P as Point>::Vect as Outer>::OuterProductType, Output = <P as Point>::Vect>,
-          G1: ToTriMesh<P, A>,
-          G2: ToTriMesh<P, B>,
-          <P::Vector as Outer>::OuterProductType: EigenQR<P::Real, P::Vector> +
-                                                Mul<P, Output = P> +
-                                                Add<<P::Vector as Outer>::OuterProductType, Output = <P::Vector as Outer>::OuterProductType>
-                                                + Zero + Copy {
-    fn to_trimesh(&self, (a, b): (A, B)) -> TriMesh<P> {
-        assert!(na::dimension::<P>() == 3);
+// XXX: Implement this for other dimensions (harder because of the concavities.
+impl<'a, N, G1: ?Sized, G2: ?Sized, A, B> ToTriMesh<Point3<N>, (A, B)> for MinkowskiSum<'a, Isometry3<N>, G1, G2>
+    where N:  Real,
+          G1: ToTriMesh<Point3<N>, A>,
+          G2: ToTriMesh<Point3<N>, B> {
+    /// Builds the convex hull of the sums of the vertices of the discretizations of both shapes.
+    ///
+    /// The result is exact only if both shapes are convex.
+    fn to_trimesh(&self, (a, b): (A, B)) -> TriMesh3<N> {
+        let mut mesh1 = self.g1().to_trimesh(a);
+        let mut mesh2 = self.g2().to_trimesh(b);
 
-        let poly1 = self.g1().to_trimesh(a);
-        let poly2 = self.g2().to_trimesh(b);
+        mesh1.transform_by(self.m1());
+        mesh2.transform_by(self.m2());
 
-        // FIXME: this will work only for convex polyhedras.
-        let mut all_points = Vec::with_capacity(poly1.coords.len() * poly2.coords.len());
+        // XXX: We are assuming all the vertices of both meshes are actually used.
+        let mut all_points = Vec::with_capacity(mesh1.coords.len() * mesh2.coords.len());
 
-        let p1;
-        let p2;
-
-        if poly1.coords.len() > poly2.coords.len() {
-            p1 = poly1;
-            p2 = poly2;
-        }
-        else {
-            p1 = poly2;
-            p2 = poly1;
-        }
-
-        // XXX: We are that assuming all the vertices of both meshes are actually used.
-        for pt in p2.coords.iter() {
-            let mut cpy = p1.clone();
-
-            cpy.translate_by(pt.as_vector());
-
-            all_points.extend(cpy.coords.into_iter());
+        for pt in mesh2.coords.iter() {
+            all_points.extend(mesh1.coords.iter().map(|p| *p + pt.coords));
         }
 
         ::convex_hull3(&all_points[..])
@@ -1,14 +1,22 @@
 pub use self::to_trimesh::ToTriMesh;
+pub use self::compound_to_trimesh::compound_to_trimesh;
 
 
 #[doc(hidden)]
 pub mod to_trimesh;
 mod ball_to_trimesh;
 mod capsule_to_trimesh;
+mod compound_to_trimesh;
 mod cone_to_trimesh;
+mod convex_to_trimesh;
 mod cuboid_to_trimesh;
 mod cylinder_to_trimesh;
+mod ellipsoid_to_trimesh;
 mod mesh_to_trimesh;
-// mod minkowski_sum_to_trimesh;
+mod heightfield_to_trimesh;
+mod minkowski_sum_to_trimesh;
 mod reflection_to_trimesh;
+mod round_shape_to_trimesh;
+mod segment_to_trimesh;
+mod torus_to_trimesh;
 mod triangle_to_trimesh;
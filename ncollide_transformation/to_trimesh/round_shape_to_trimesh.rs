@@ -0,0 +1,25 @@
+use alga::general::Real;
+use na::{self, Point3, Isometry3};
+use geometry::shape::{RoundShape, SupportMap};
+use procedural::TriMesh3;
+use procedural;
+use super::ToTriMesh;
+
+impl<N: Real, S: SupportMap<Point3<N>, Isometry3<N>>> ToTriMesh<Point3<N>, (u32, u32)> for RoundShape<S, N> {
+    /// Maps each vertex of a discretized unit sphere to the support point of this shape in its
+    /// direction.
+    ///
+    /// Since this shape is convex, the result approximates it with the given number of
+    /// subdivisions of the sphere. The normal of each vertex is its direction on the sphere. This
+    /// also applies to a `WithMargin` shape.
+    fn to_trimesh(&self, (ntheta_subdiv, nphi_subdiv): (u32, u32)) -> TriMesh3<N> {
+        let mut mesh = procedural::sphere(na::convert(2.0f64), ntheta_subdiv, nphi_subdiv, true);
+        let id       = Isometry3::identity();
+
+        for pt in mesh.coords.iter_mut() {
+            *pt = self.support_point(&id, &pt.coords);
+        }
+
+        mesh
+    }
+}
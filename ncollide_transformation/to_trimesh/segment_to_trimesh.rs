@@ -0,0 +1,14 @@
+use alga::general::Real;
+use na::Point3;
+use geometry::shape::Segment3;
+use procedural::{TriMesh, TriMesh3, IndexBuffer};
+use super::ToTriMesh;
+
+impl<N: Real> ToTriMesh<Point3<N>, ()> for Segment3<N> {
+    /// A single degenerate triangle with two vertices, so that a segment can be merged with other
+    /// triangle meshes.
+    fn to_trimesh(&self, _: ()) -> TriMesh3<N> {
+        TriMesh::new(vec![ *self.a(), *self.b() ], None, None,
+                     Some(IndexBuffer::Unified(vec![ Point3::new(0, 1, 1) ])))
+    }
+}
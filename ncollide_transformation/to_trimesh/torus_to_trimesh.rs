@@ -0,0 +1,50 @@
+use alga::general::Real;
+use na::{self, Point3, Vector3};
+use geometry::shape::Torus;
+use procedural::{TriMesh, TriMesh3, IndexBuffer};
+use super::ToTriMesh;
+
+impl<N: Real> ToTriMesh<Point3<N>, (u32, u32)> for Torus<N> {
+    /// Discretizes this torus around the `y` axis.
+    ///
+    /// The major circle is subdivided `ntheta_subdiv` times, and the tube `nphi_subdiv` times.
+    fn to_trimesh(&self, (ntheta_subdiv, nphi_subdiv): (u32, u32)) -> TriMesh3<N> {
+        let dtheta = N::two_pi() / na::convert(ntheta_subdiv as f64);
+        let dphi   = N::two_pi() / na::convert(nphi_subdiv as f64);
+
+        let mut coords  = Vec::with_capacity((ntheta_subdiv * nphi_subdiv) as usize);
+        let mut normals = Vec::with_capacity((ntheta_subdiv * nphi_subdiv) as usize);
+
+        for i in 0 .. ntheta_subdiv {
+            let theta  = dtheta * na::convert(i as f64);
+            let center = Vector3::new(theta.cos(), na::zero(), theta.sin()) * self.major_radius();
+
+            for j in 0 .. nphi_subdiv {
+                let phi    = dphi * na::convert(j as f64);
+                let normal = Vector3::new(phi.cos() * theta.cos(), phi.sin(), phi.cos() * theta.sin());
+
+                coords.push(Point3::from_coordinates(center + normal * self.minor_radius()));
+                normals.push(normal);
+            }
+        }
+
+        let mut idx = Vec::with_capacity((2 * ntheta_subdiv * nphi_subdiv) as usize);
+
+        for i in 0 .. ntheta_subdiv {
+            let next_i = (i + 1) % ntheta_subdiv;
+
+            for j in 0 .. nphi_subdiv {
+                let next_j = (j + 1) % nphi_subdiv;
+                let a = i * nphi_subdiv + j;
+                let b = next_i * nphi_subdiv + j;
+                let c = next_i * nphi_subdiv + next_j;
+                let d = i * nphi_subdiv + next_j;
+
+                idx.push(Point3::new(a, d, c));
+                idx.push(Point3::new(a, c, b));
+            }
+        }
+
+        TriMesh::new(coords, Some(normals), None, Some(IndexBuffer::Unified(idx)))
+    }
+}
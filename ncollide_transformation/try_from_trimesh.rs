@@ -0,0 +1,43 @@
+use alga::general::Real;
+use na::Point3;
+use utils;
+use geometry::shape::{TriMesh, ConvexHull};
+use math::Point;
+
+/// Trait implemented by shapes that can be built from a triangle mesh.
+pub trait TryFromTriMesh<P: Point>: Sized {
+    /// Builds this shape from the vertices of a triangle mesh.
+    ///
+    /// Returns `None` if the shape cannot be built from this mesh.
+    fn try_from_trimesh(mesh: &TriMesh<P>) -> Option<Self>;
+}
+
+impl<N: Real> TryFromTriMesh<Point3<N>> for ConvexHull<Point3<N>> {
+    /// Builds the convex hull of the vertices of a triangle mesh.
+    ///
+    /// The triangles of the mesh are ignored and the vertices lying inside of the hull are
+    /// discarded. The vertices of the hull are exactly those of the mesh, in the same order.
+    /// Returns `None` if the vertices are flat, i.e., if they do not span a volume. The flatness
+    /// test is relative to the size of the mesh.
+    fn try_from_trimesh(mesh: &TriMesh<Point3<N>>) -> Option<ConvexHull<Point3<N>>> {
+        let vertices = &mesh.vertices()[..];
+        let faces    = utils::point_cloud_hull_faces(vertices);
+
+        if faces.is_empty() {
+            return None;
+        }
+
+        // The faces index the mesh vertices directly: keep those they reference.
+        let mut on_hull = vec![ false; vertices.len() ];
+
+        for f in faces.iter() {
+            on_hull[f.x] = true;
+            on_hull[f.y] = true;
+            on_hull[f.z] = true;
+        }
+
+        let hull = vertices.iter().zip(on_hull.iter()).filter(|&(_, on)| *on).map(|(pt, _)| *pt).collect();
+
+        Some(ConvexHull::new(hull))
+    }
+}
@@ -300,6 +300,14 @@ impl<K: PartialEq, V, H: HashFun<K>> HashMap<K, V, H> {
 
     /// Finds a reference to the element with a given key.
     pub fn find<'a>(&'a self, key: &K) -> Option<&'a V> {
+        self.find_entry(key).map(|e| &e.value)
+    }
+
+    /// Finds a reference to the entry with a given key.
+    ///
+    /// The key of the entry is the one it was inserted with, which may differ from `key` if the
+    /// equality of the keys is not structural.
+    pub fn find_entry<'a>(&'a self, key: &K) -> Option<&'a Entry<K, V>> {
         let h = self.hash.hash(key) & self.mask;
 
         let mut pos = self.htable[h];
@@ -317,7 +325,7 @@ impl<K: PartialEq, V, H: HashFun<K>> HashMap<K, V, H> {
             None
         }
         else {
-            Some(&self.table[pos as usize].value)
+            Some(&self.table[pos as usize])
         }
     }
 
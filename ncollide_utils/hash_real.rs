@@ -0,0 +1,34 @@
+use std::f64;
+use std::hash::Hasher;
+
+use alga::general::Real;
+use na;
+
+/// The bits of a scalar converted to `f64`, identifying it exactly.
+///
+/// Both zeros have the same bits since they compare equal. All NaNs have the same bits too so
+/// that comparing scalars by their bits is reflexive, though a NaN is not equal to itself. A
+/// scalar that cannot be represented as a `f64` is identified as a NaN.
+#[inline]
+pub fn real_bits<N: Real>(x: N) -> u64 {
+    let x: f64 = na::try_convert(x).unwrap_or(f64::NAN);
+
+    if x == 0.0 {
+        0
+    }
+    else if x.is_nan() {
+        f64::NAN.to_bits()
+    }
+    else {
+        x.to_bits()
+    }
+}
+
+/// Feeds the exact representation of a scalar to a hasher.
+///
+/// This hashes the `real_bits` of the scalar: two scalars with the same bits, including both
+/// zeros and all NaNs, hash identically.
+#[inline]
+pub fn hash_real<N: Real, H: Hasher>(x: N, state: &mut H) {
+    state.write_u64(real_bits(x))
+}
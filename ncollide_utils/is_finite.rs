@@ -0,0 +1,22 @@
+use alga::general::Real;
+use na;
+use math::{Point, Vector};
+
+/// Tests whether a scalar is neither infinite nor NaN.
+#[inline]
+pub fn is_finite<N: Real>(x: N) -> bool {
+    // Both `inf - inf` and `NaN - NaN` are NaN.
+    x - x == na::zero()
+}
+
+/// Tests whether all the components of a vector are neither infinite nor NaN.
+#[inline]
+pub fn is_finite_vector<V: Vector>(v: &V) -> bool {
+    (0 .. na::dimension::<V>()).all(|i| is_finite(v[i]))
+}
+
+/// Tests whether all the coordinates of a point are neither infinite nor NaN.
+#[inline]
+pub fn is_finite_point<P: Point>(pt: &P) -> bool {
+    is_finite_vector(&pt.coordinates())
+}
@@ -37,7 +37,11 @@ pub use median::median;
 pub use sort::sort3;
 pub use cross3::cross3;
 pub use point_cloud_support_point::point_cloud_support_point;
+pub use point_cloud_hull_faces::point_cloud_hull_faces;
 pub use repeat::repeat;
+pub use is_finite::{is_finite, is_finite_vector, is_finite_point};
+pub use hash_real::{hash_real, real_bits};
+pub use orient2d::orient2d;
 
 
 pub mod data;
@@ -56,4 +60,8 @@ mod median;
 mod sort;
 mod cross3;
 mod point_cloud_support_point;
+mod point_cloud_hull_faces;
 mod repeat;
+mod is_finite;
+mod hash_real;
+mod orient2d;
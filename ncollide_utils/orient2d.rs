@@ -0,0 +1,134 @@
+use std::cmp::Ordering;
+
+use alga::general::Real;
+use na;
+
+use math::Point;
+
+/// Exactly computes the orientation of the 2D triangle `(pa, pb, pc)`.
+///
+/// Returns `Ordering::Greater` if the triangle is counterclockwise, i.e., if `pc` lies on the left
+/// of the line oriented from `pa` to `pb`, `Ordering::Less` if it is clockwise, and
+/// `Ordering::Equal` if the three points are collinear. The result is exact, unless some
+/// intermediate product overflows or underflows: the determinant is first evaluated with floating
+/// point arithmetic and, only if its sign cannot be trusted, it is then computed exactly with
+/// floating point expansions as described by J. R. Shewchuk in "Adaptive Precision
+/// Floating-Point Arithmetic and Fast Robust Geometric Predicates".
+pub fn orient2d<P: Point>(pa: &P, pb: &P, pc: &P) -> Ordering {
+    assert!(na::dimension::<P::Vector>() == 2, "The orientation predicate is only defined in 2D.");
+
+    let (ax, ay) = (pa[0], pa[1]);
+    let (bx, by) = (pb[0], pb[1]);
+    let (cx, cy) = (pc[0], pc[1]);
+
+    let det_left  = (ax - cx) * (by - cy);
+    let det_right = (ay - cy) * (bx - cx);
+    let det       = det_left - det_right;
+
+    let (epsilon, splitter) = epsilon_and_splitter::<P::Real>();
+    let _3: P::Real  = na::convert(3.0f64);
+    let _16: P::Real = na::convert(16.0f64);
+    let err_bound    = (_3 + _16 * epsilon) * epsilon * (det_left.abs() + det_right.abs());
+
+    if det > err_bound {
+        return Ordering::Greater;
+    }
+    else if -det > err_bound {
+        return Ordering::Less;
+    }
+
+    // The determinant is expanded so that it is a sum of exact products of the coordinates. Each
+    // product adds at most two components to the expansion.
+    let products      = [ (ax, by), (-ax, cy), (-cx, by), (-ay, bx), (ay, cx), (cy, bx) ];
+    let mut expansion = [ na::zero::<P::Real>(); 12 ];
+    let mut len       = 0;
+
+    for &(a, b) in products.iter() {
+        let (x, y) = two_product(a, b, splitter);
+        len = grow_expansion(&mut expansion, len, y);
+        len = grow_expansion(&mut expansion, len, x);
+    }
+
+    // The components are non-overlapping and sorted by increasing magnitude: the sign is given by
+    // the last one.
+    if len == 0 {
+        Ordering::Equal
+    }
+    else if expansion[len - 1] > na::zero() {
+        Ordering::Greater
+    }
+    else {
+        Ordering::Less
+    }
+}
+
+// The largest power of two such that `1 + epsilon` is rounded to `1`, and the constant used to
+// split a number into two halves with non-overlapping bits.
+//
+// With `p` bits of mantissa, the machine epsilon is `2^(1 - p)`, so that `epsilon` is half of it
+// and the splitter is `2^ceil(p / 2) + 1`. Every operation here is exact on powers of two.
+#[inline]
+fn epsilon_and_splitter<N: Real>() -> (N, N) {
+    let _0_5: N = na::convert(0.5f64);
+    let _2: N   = na::convert(2.0f64);
+    let eps     = N::default_epsilon();
+    let half    = (N::one() / eps).sqrt().log2().floor().exp2();
+
+    (eps * _0_5, half * _2 + N::one())
+}
+
+// Computes `(x, y)` such that `x = fl(a + b)` and `x + y = a + b` exactly.
+#[inline]
+fn two_sum<N: Real>(a: N, b: N) -> (N, N) {
+    let x  = a + b;
+    let bv = x - a;
+    let av = x - bv;
+
+    (x, (a - av) + (b - bv))
+}
+
+// Computes `(x, y)` such that `x = fl(a * b)` and `x + y = a * b` exactly.
+#[inline]
+fn two_product<N: Real>(a: N, b: N, splitter: N) -> (N, N) {
+    let x          = a * b;
+    let (ahi, alo) = split(a, splitter);
+    let (bhi, blo) = split(b, splitter);
+    let err1       = x - ahi * bhi;
+    let err2       = err1 - alo * bhi;
+    let err3       = err2 - ahi * blo;
+
+    (x, alo * blo - err3)
+}
+
+#[inline]
+fn split<N: Real>(a: N, splitter: N) -> (N, N) {
+    let c    = splitter * a;
+    let abig = c - a;
+    let hi   = c - abig;
+
+    (hi, a - hi)
+}
+
+// Adds `b` to the non-overlapping expansion made of the `len` first components of `e`, removing
+// the zero components. Returns the new number of components.
+fn grow_expansion<N: Real>(e: &mut [N], len: usize, b: N) -> usize {
+    let mut q = b;
+    let mut k = 0;
+
+    for i in 0 .. len {
+        let (sum, h) = two_sum(q, e[i]);
+        q = sum;
+
+        if !h.is_zero() {
+            e[k] = h;
+            k    = k + 1;
+        }
+    }
+
+    if !q.is_zero() {
+        e[k] = q;
+        k    = k + 1;
+    }
+
+    k
+}
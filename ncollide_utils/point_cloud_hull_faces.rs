@@ -0,0 +1,120 @@
+use approx::ApproxEq;
+use alga::general::Real;
+use na::{self, Point3};
+
+use cross3::cross3;
+use math::Point;
+
+/// Computes the triangular faces of the convex hull of a 3D cloud of points.
+///
+/// Each face is given by the indices of its vertices in `points`, ordered counterclockwise when
+/// seen from outside of the hull. The hull is built incrementally, in `O(n * h)` time, `n` being
+/// the number of points and `h` the number of faces. Points closer to the hull than a tolerance
+/// relative to the size of the cloud are ignored. Returns no face if the points are coplanar.
+pub fn point_cloud_hull_faces<P: Point>(points: &[P]) -> Vec<Point3<usize>> {
+    assert!(na::dimension::<P::Vector>() == 3, "The hull faces are only computed in 3D.");
+
+    if points.len() < 4 {
+        return Vec::new();
+    }
+
+    let scale = points.iter().fold(na::zero::<P::Real>(), |s, pt| s.max(na::distance(pt, &points[0])));
+    let eps   = <P::Real as ApproxEq>::default_epsilon().sqrt() * (na::one::<P::Real>() + scale);
+
+    // The initial tetrahedron.
+    let p0 = points[0];
+    let i1 = farthest(points, |pt| na::distance(pt, &p0));
+    let d1 = points[i1] - p0;
+
+    if na::norm(&d1) <= eps {
+        return Vec::new();
+    }
+
+    let i2 = farthest(points, |pt| na::norm(&cross3(&(*pt - p0), &d1)));
+    let n  = cross3(&d1, &(points[i2] - p0));
+
+    if na::norm(&n) <= eps * na::norm(&d1) {
+        return Vec::new();
+    }
+
+    let i3 = farthest(points, |pt| na::dot(&(*pt - p0), &n).abs());
+
+    if na::dot(&(points[i3] - p0), &n).abs() <= eps * na::norm(&n) {
+        return Vec::new();
+    }
+
+    let tetrahedron = [ 0, i1, i2, i3 ];
+    let mut faces   = Vec::new();
+
+    for &(a, b, c, d) in [ (0, i1, i2, i3), (0, i1, i3, i2), (0, i2, i3, i1), (i1, i2, i3, 0) ].iter() {
+        if na::dot(&normal(points, &Point3::new(a, b, c)), &(points[d] - points[a])) > na::zero() {
+            faces.push(Point3::new(a, c, b))
+        }
+        else {
+            faces.push(Point3::new(a, b, c))
+        }
+    }
+
+    let mut visible = Vec::new();
+    let mut horizon = Vec::new();
+
+    for (i, pt) in points.iter().enumerate() {
+        if tetrahedron.contains(&i) {
+            continue;
+        }
+
+        visible.clear();
+        visible.extend(faces.iter().map(|f| {
+            let n = normal(points, f);
+            na::dot(&n, &(*pt - points[f.x])) > eps * na::norm(&n)
+        }));
+
+        if !visible.iter().any(|v| *v) {
+            continue;
+        }
+
+        // The edges of the visible faces that are not shared with another visible face.
+        horizon.clear();
+
+        for (f, _) in faces.iter().zip(visible.iter()).filter(|&(_, v)| *v) {
+            for &(u, v) in [ (f.x, f.y), (f.y, f.z), (f.z, f.x) ].iter() {
+                let shared = faces.iter().zip(visible.iter()).any(|(g, w)| {
+                    *w && ((g.x == v && g.y == u) || (g.y == v && g.z == u) || (g.z == v && g.x == u))
+                });
+
+                if !shared {
+                    horizon.push((u, v))
+                }
+            }
+        }
+
+        let mut id = 0;
+        faces.retain(|_| { id += 1; !visible[id - 1] });
+        faces.extend(horizon.iter().map(|&(u, v)| Point3::new(u, v, i)));
+    }
+
+    faces
+}
+
+// The non-normalized outward normal of a face.
+#[inline]
+fn normal<P: Point>(points: &[P], f: &Point3<usize>) -> P::Vector {
+    cross3(&(points[f.y] - points[f.x]), &(points[f.z] - points[f.x]))
+}
+
+// The index of the point maximizing `f`.
+fn farthest<P: Point, F: Fn(&P) -> P::Real>(points: &[P], f: F) -> usize {
+    let mut best      = 0;
+    let mut best_dist = f(&points[0]);
+
+    for (i, pt) in points.iter().enumerate().skip(1) {
+        let dist = f(pt);
+
+        if dist > best_dist {
+            best      = i;
+            best_dist = dist;
+        }
+    }
+
+    best
+}
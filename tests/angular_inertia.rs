@@ -0,0 +1,79 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Matrix3, UnitComplex, UnitQuaternion, Vector2, Vector3};
+use ncollide::math;
+
+#[test]
+fn translate_then_untranslate_inertia3() {
+    let inertia = Matrix3::new(2.0f64, 0.1, 0.2,
+                               0.1, 3.0, 0.3,
+                               0.2, 0.3, 4.0);
+    let offset  = Vector3::new(1.0, -2.0, 0.5);
+    let moved   = math::translate_inertia(&inertia, 1.5, &offset);
+    let back    = math::translate_inertia(&moved, -1.5, &offset);
+
+    assert!((moved - inertia).norm() > 1.0);
+    assert!((back - inertia).norm() < 1.0e-12);
+
+    // The parallel-axis term of a point mass on the x axis does not change the x component.
+    let on_x = math::translate_inertia(&Matrix3::<f64>::from_diagonal_element(0.0), 2.0, &Vector3::x());
+    assert!((on_x - Matrix3::from_diagonal(&Vector3::new(0.0, 2.0, 2.0))).norm() < 1.0e-12);
+}
+
+#[test]
+fn rotate_inertia3() {
+    let inertia = Matrix3::from_diagonal(&Vector3::new(1.0f64, 2.0, 3.0));
+    let rot     = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), std::f64::consts::FRAC_PI_2);
+    let rotated = math::rotate_inertia(&inertia, &rot);
+
+    assert!((rotated - Matrix3::from_diagonal(&Vector3::new(2.0, 1.0, 3.0))).norm() < 1.0e-12);
+    assert!((math::rotate_inertia(&rotated, &rot.inverse()) - inertia).norm() < 1.0e-12);
+}
+
+#[test]
+fn translate_then_untranslate_inertia2() {
+    let offset = Vector2::new(1.0f64, 2.0);
+    let moved  = math::translate_inertia(&0.5f64, 2.0, &offset);
+    let back   = math::translate_inertia(&moved, -2.0, &offset);
+
+    assert!((moved - 10.5).abs() < 1.0e-12);
+    assert!((back - 0.5).abs() < 1.0e-12);
+    assert_eq!(math::rotate_inertia(&0.5f64, &UnitComplex::new(1.0)), 0.5);
+}
+
+#[test]
+fn inverse_inertia() {
+    let inertia = Matrix3::from_diagonal(&Vector3::new(2.0f64, 4.0, 0.5));
+    let inverse = math::inverse_inertia(&inertia);
+
+    assert!((inverse - Matrix3::from_diagonal(&Vector3::new(0.5, 0.25, 2.0))).norm() < 1.0e-12);
+    assert_eq!(math::inverse_inertia(&Matrix3::from_diagonal_element(0.0f64)), Matrix3::from_diagonal_element(0.0));
+    assert_eq!(math::inverse_inertia(&Matrix3::from_diagonal_element(std::f64::INFINITY)), Matrix3::from_diagonal_element(0.0));
+
+    assert_eq!(math::inverse_inertia(&4.0f64), 0.25);
+    assert_eq!(math::inverse_inertia(&0.0f64), 0.0);
+    assert_eq!(math::inverse_inertia(&std::f64::INFINITY), 0.0);
+}
+
+#[test]
+fn principal_inertia3() {
+    let diagonal = Matrix3::from_diagonal(&Vector3::new(1.0f64, 2.0, 3.0));
+    let (moments, rot) = math::principal_inertia(&diagonal);
+
+    assert!((moments - Vector3::new(1.0, 2.0, 3.0)).norm() < 1.0e-12);
+    assert!(rot.angle() < 1.0e-12);
+
+    // The decomposition of a rotated tensor gives back the moments and the rotation.
+    let rot     = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), 0.3) *
+                  UnitQuaternion::from_axis_angle(&Vector3::x_axis(), 0.7);
+    let rotated = math::rotate_inertia(&diagonal, &rot);
+    let (moments, principal) = math::principal_inertia(&rotated);
+    let diag    = Matrix3::from_diagonal(&moments);
+
+    assert!((math::rotate_inertia(&diag, &principal) - rotated).norm() < 1.0e-9);
+
+    let mut sorted = [ moments.x, moments.y, moments.z ];
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert!((Vector3::new(sorted[0], sorted[1], sorted[2]) - Vector3::new(1.0, 2.0, 3.0)).norm() < 1.0e-9);
+}
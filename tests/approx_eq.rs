@@ -0,0 +1,30 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Matrix3, Vector3};
+use ncollide::math;
+
+#[test]
+fn approx_eq_scalars() {
+    assert!(math::approx_eq(1.0f64, 1.0 + 1.0e-12, 1.0e-10, 1.0e-10));
+    assert!(!math::approx_eq(1.0f64, 1.001, 1.0e-10, 1.0e-10));
+
+    // The relative tolerance handles large values, the absolute one values close to zero.
+    assert!(math::approx_eq(1.0e12f64, 1.0e12 + 1.0, 1.0e-10, 1.0e-10));
+    assert!(math::approx_eq(0.0f64, 1.0e-12, 1.0e-10, 1.0e-10));
+    assert!(!math::approx_eq(0.0f64, 1.0e-12, 0.0, 1.0e-10));
+}
+
+#[test]
+fn approx_eq_vectors_and_inertias() {
+    let v = Vector3::new(1.0f64, 2.0, 3.0);
+
+    assert!(math::approx_eq_vector(&v, &(v + Vector3::x() * 1.0e-12), 1.0e-10, 1.0e-10));
+    assert!(!math::approx_eq_vector(&v, &(v + Vector3::x() * 1.0e-3), 1.0e-10, 1.0e-10));
+
+    let inertia = Matrix3::from_diagonal(&v);
+
+    assert!(math::approx_eq_inertia(&inertia, &(inertia * (1.0 + 1.0e-12)), 1.0e-10, 1.0e-10));
+    assert!(!math::approx_eq_inertia(&inertia, &(inertia * 1.001), 1.0e-10, 1.0e-10));
+    assert!(math::approx_eq_inertia(&1.0f64, &(1.0 + 1.0e-12), 1.0e-10, 1.0e-10));
+}
@@ -0,0 +1,34 @@
+#![cfg(feature = "arbitrary")]
+
+extern crate nalgebra as na;
+extern crate quickcheck;
+extern crate ncollide;
+
+use quickcheck::quickcheck;
+use na::{Isometry3, Vector3};
+use ncollide::shape::{Ball, Cuboid, Cone, Capsule};
+use ncollide::query::{self, contacts_internal};
+
+#[test]
+fn distance_is_symmetric() {
+    fn prop(m1: Isometry3<f64>, b: Ball<f64>, m2: Isometry3<f64>, c: Cuboid<Vector3<f64>>) -> bool {
+        let d12 = query::distance(&m1, &b, &m2, &c);
+        let d21 = query::distance(&m2, &c, &m1, &b);
+
+        (d12 - d21).abs() <= 1.0e-6 * (1.0 + d12)
+    }
+
+    quickcheck(prop as fn(Isometry3<f64>, Ball<f64>, Isometry3<f64>, Cuboid<Vector3<f64>>) -> bool);
+}
+
+#[test]
+fn contact_normal_is_unit() {
+    fn prop(m1: Isometry3<f64>, c1: Cone<f64>, m2: Isometry3<f64>, c2: Capsule<f64>) -> bool {
+        match contacts_internal::support_map_against_support_map(&m1, &c1, &m2, &c2, 1.0) {
+            Some(c) => (na::norm(&*c.normal) - 1.0).abs() <= 1.0e-6,
+            None    => true
+        }
+    }
+
+    quickcheck(prop as fn(Isometry3<f64>, Cone<f64>, Isometry3<f64>, Capsule<f64>) -> bool);
+}
@@ -0,0 +1,61 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Ball, BallArray};
+use ncollide::query::{Ray, RayCast, PointQuery};
+
+fn balls() -> Vec<(Point3<f64>, f64)> {
+    (0 .. 50).map(|i| {
+        let t = i as f64;
+        (Point3::new(3.0 * t.sin(), 2.0 * t.cos(), 0.1 * t), 0.2 + 0.01 * t)
+    }).collect()
+}
+
+#[test]
+fn ball_array_matches_naive_loop() {
+    let balls = balls();
+    let array = BallArray::new(balls.iter().map(|b| b.0).collect(), balls.iter().map(|b| b.1).collect());
+    let rays  = [ Ray::new(Point3::new(-5.0, 0.1, 2.0), Vector3::new(1.0, 0.05, 0.0)),
+                  Ray::new(Point3::new(0.0, 0.0, -1.0), Vector3::new(0.01, 0.02, 1.0)),
+                  Ray::new(balls[3].0, Vector3::new(0.0, 1.0, 0.0)) ];
+
+    for ray in rays.iter() {
+        for solid in [ true, false ].iter() {
+            let hits = array.ray_cast_all(ray, *solid);
+            assert_eq!(hits.len(), balls.len());
+
+            for (hit, &(center, radius)) in hits.iter().zip(balls.iter()) {
+                let m        = Isometry3::new(center.coords, na::zero());
+                let expected = Ball::new(radius).toi_and_normal_with_ray(&m, ray, *solid);
+
+                match (hit, &expected) {
+                    (&Some(ref hit), &Some(ref expected)) => {
+                        assert!((hit.toi - expected.toi).abs() < 1.0e-7);
+                        assert!(na::norm(&(*hit.normal - *expected.normal)) < 1.0e-7);
+                    },
+                    (&None, &None) => { },
+                    _ => panic!("Ray intersections mismatch: {:?} {:?}", hit.is_some(), expected.is_some())
+                }
+            }
+        }
+    }
+
+    for pt in [ Point3::new(1.0, 1.0, 1.0), Point3::new(-2.0, 0.5, 4.0), balls[7].0 ].iter() {
+        let mut expected  = 0;
+        let mut best_dist = ::std::f64::MAX;
+
+        for (i, &(center, radius)) in balls.iter().enumerate() {
+            let m    = Isometry3::new(center.coords, na::zero());
+            let dist = Ball::new(radius).distance_to_point(&m, pt, false);
+            let dist = if Ball::new(radius).contains_point(&m, pt) { -dist } else { dist };
+
+            if dist < best_dist {
+                expected  = i;
+                best_dist = dist;
+            }
+        }
+
+        assert_eq!(array.closest_to_point(pt), expected);
+    }
+}
@@ -15,5 +15,5 @@ fn test_ball_ball_toi() {
 
     let cast = query::time_of_impact(&m1, &Vector3::new(0.0, 10.0, 0.0), &b, &m2, &na::zero(), &b);
 
-    assert_eq!(cast.unwrap(), 0.9);
+    assert_eq!(cast.unwrap().toi, 0.9);
 }
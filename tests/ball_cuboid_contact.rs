@@ -0,0 +1,93 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Ball, Cuboid, ShapeHandle};
+use ncollide::query::{self, Contact};
+use ncollide::query::contacts_internal;
+use ncollide::narrow_phase::{ContactDispatcher, DefaultContactDispatcher};
+
+fn contact_at(x: f64, y: f64, z: f64) -> Contact<Point3<f64>> {
+    let cuboid  = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let ball    = Ball::new(0.5f64);
+    let mcuboid = Isometry3::new(na::zero(), na::zero());
+    let mball   = Isometry3::new(Vector3::new(x, y, z), na::zero());
+
+    query::contact(&mcuboid, &cuboid, &mball, &ball, 0.0).expect("Missing ball-cuboid contact.")
+}
+
+fn assert_contact(c: &Contact<Point3<f64>>, normal: Vector3<f64>, depth: f64, world1: Point3<f64>) {
+    assert!(na::norm(&(*c.normal - na::normalize(&normal))) < 1.0e-12, "{:?}", *c.normal);
+    assert!((c.depth - depth).abs() < 1.0e-12, "{}", c.depth);
+    assert!(na::distance(&c.world1, &world1) < 1.0e-12, "{:?}", c.world1);
+    assert!(na::distance(&c.world2, &(world1 + *c.normal * -c.depth)) < 1.0e-12, "{:?}", c.world2);
+}
+
+#[test]
+fn ball_touching_face_edge_and_corner() {
+    let face = contact_at(1.4, 0.2, -0.3);
+    assert_contact(&face, Vector3::x(), 0.1, Point3::new(1.0, 0.2, -0.3));
+
+    let edge = contact_at(1.3, 1.3, 0.0);
+    assert_contact(&edge, Vector3::new(1.0, 1.0, 0.0), 0.5 - 0.3 * 2.0f64.sqrt(), Point3::new(1.0, 1.0, 0.0));
+
+    let corner = contact_at(-1.2, 1.2, 1.2);
+    assert_contact(&corner, Vector3::new(-1.0, 1.0, 1.0), 0.5 - 0.2 * 3.0f64.sqrt(), Point3::new(-1.0, 1.0, 1.0));
+}
+
+#[test]
+fn ball_center_inside_or_on_cuboid() {
+    let inside = contact_at(0.1, -0.8, 0.0);
+    assert_contact(&inside, -Vector3::y(), 0.7, Point3::new(0.1, -1.0, 0.0));
+
+    let on_face = contact_at(0.2, 0.0, 1.0);
+    assert_contact(&on_face, Vector3::z(), 0.5, Point3::new(0.2, 0.0, 1.0));
+}
+
+#[test]
+fn ball_cuboid_matches_support_map_contact() {
+    let cuboid  = Cuboid::new(Vector3::new(1.0f64, 2.0, 0.5));
+    let ball    = Ball::new(0.5f64);
+    let mcuboid = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.3, -0.2, 0.1));
+    let mball   = Isometry3::new(Vector3::new(2.5, 3.4, 3.1), na::zero());
+
+    let ball_center = Point3::new(2.5, 3.4, 3.1);
+    let analytic    = contacts_internal::cuboid_against_ball(&mcuboid, &cuboid, &ball_center, &ball, 1.0).unwrap();
+    let generic     = contacts_internal::support_map_against_support_map(&mcuboid, &cuboid, &mball, &ball, 1.0).unwrap();
+
+    // GJK only approximates the normal.
+    assert!(na::norm(&(*analytic.normal - *generic.normal)) < 1.0e-3);
+    assert!((analytic.depth - generic.depth).abs() < 1.0e-6);
+
+    let flipped = query::contact(&mball, &ball, &mcuboid, &cuboid, 1.0).unwrap();
+    assert!(na::norm(&(*flipped.normal + *analytic.normal)) < 1.0e-12);
+    assert!((flipped.depth - analytic.depth).abs() < 1.0e-12);
+    assert!(query::contact(&mball, &ball, &Isometry3::new(Vector3::new(10.0, 0.0, 0.0), na::zero()), &cuboid, 1.0).is_none());
+}
+
+#[test]
+fn ball_cuboid_dispatched_to_analytic_contact() {
+    let cuboid: ShapeHandle<Point3<f64>, Isometry3<f64>> = ShapeHandle::new(Cuboid::new(Vector3::new(1.0, 1.0, 1.0)));
+    let ball:   ShapeHandle<Point3<f64>, Isometry3<f64>> = ShapeHandle::new(Ball::new(0.5));
+    let mcuboid    = Isometry3::new(na::zero(), na::zero());
+    let mball      = Isometry3::new(Vector3::new(1.3, 1.3, 0.0), na::zero());
+    let dispatcher = DefaultContactDispatcher::new();
+    let expected   = contact_at(1.3, 1.3, 0.0);
+
+    let mut algo     = dispatcher.get_contact_algorithm(&*cuboid, &*ball).unwrap();
+    let mut contacts = Vec::new();
+    assert!(algo.update(&dispatcher, &mcuboid, &*cuboid, &mball, &*ball, 0.0));
+    algo.contacts(&mut contacts);
+
+    assert_eq!(contacts.len(), 1);
+    assert_contact(&contacts[0], *expected.normal, expected.depth, expected.world1);
+
+    // The exact normal on the edge shows the analytic contact is used in the other order too.
+    let mut algo     = dispatcher.get_contact_algorithm(&*ball, &*cuboid).unwrap();
+    let mut contacts = Vec::new();
+    assert!(algo.update(&dispatcher, &mball, &*ball, &mcuboid, &*cuboid, 0.0));
+    algo.contacts(&mut contacts);
+
+    assert_eq!(contacts.len(), 1);
+    assert_contact(&contacts[0], -*expected.normal, expected.depth, expected.world2);
+}
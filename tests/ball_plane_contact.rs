@@ -0,0 +1,38 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Ball, Plane};
+use ncollide::query;
+
+#[test]
+fn ball_resting_on_penetrating_and_hovering_above_plane() {
+    let plane  = Plane::new(Vector3::y());
+    let ball   = Ball::new(0.5f64);
+    let mplane = Isometry3::new(Vector3::new(1.0, 1.0, 0.0), na::zero());
+
+    // Resting exactly on the plane.
+    let mball = Isometry3::new(Vector3::new(3.0, 1.5, 2.0), na::zero());
+    let c     = query::contact(&mplane, &plane, &mball, &ball, 0.0).expect("Missing resting contact.");
+
+    assert!(c.depth.abs() < 1.0e-12);
+    assert_eq!(*c.normal, Vector3::y());
+    assert!(na::distance(&c.world1, &Point3::new(3.0, 1.0, 2.0)) < 1.0e-12);
+    assert!(na::distance(&c.world2, &Point3::new(3.0, 1.0, 2.0)) < 1.0e-12);
+
+    // Penetrating.
+    let mball = Isometry3::new(Vector3::new(3.0, 1.2, 2.0), na::zero());
+    let c     = query::contact(&mball, &ball, &mplane, &plane, 0.0).expect("Missing penetrating contact.");
+
+    assert!((c.depth - 0.3).abs() < 1.0e-12);
+    assert_eq!(*c.normal, -Vector3::y());
+    assert!(na::distance(&c.world1, &Point3::new(3.0, 0.7, 2.0)) < 1.0e-12);
+    assert!(na::distance(&c.world2, &Point3::new(3.0, 1.0, 2.0)) < 1.0e-12);
+
+    // Hovering above the plane, within then beyond the prediction.
+    let mball = Isometry3::new(Vector3::new(3.0, 1.6, 2.0), na::zero());
+    let c     = query::contact(&mplane, &plane, &mball, &ball, 0.2).expect("Missing predicted contact.");
+
+    assert!((c.depth + 0.1).abs() < 1.0e-12);
+    assert!(query::contact(&mplane, &plane, &mball, &ball, 0.05).is_none());
+}
@@ -0,0 +1,57 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry2, Point2, Vector2};
+use ncollide::shape::{Ball, Plane, ShapeHandle};
+use ncollide::query::{self, Proximity};
+use ncollide::narrow_phase::{ProximityDispatcher, DefaultProximityDispatcher};
+
+fn at(x: f64, y: f64) -> Isometry2<f64> {
+    Isometry2::new(Vector2::new(x, y), 0.0)
+}
+
+#[test]
+fn ball_ball_proximity() {
+    let b1 = Ball::new(1.0f64);
+    let b2 = Ball::new(0.5f64);
+
+    assert_eq!(query::proximity(&at(0.0, 0.0), &b1, &at(1.0, 0.0), &b2, 0.1), Proximity::Intersecting);
+    assert_eq!(query::proximity(&at(0.0, 0.0), &b1, &at(1.5, 0.0), &b2, 0.1), Proximity::Intersecting);
+    assert_eq!(query::proximity(&at(0.0, 0.0), &b1, &at(0.0, 1.55), &b2, 0.1), Proximity::WithinMargin);
+    assert_eq!(query::proximity(&at(0.0, 0.0), &b1, &at(1.7, 0.0), &b2, 0.1), Proximity::Disjoint);
+}
+
+#[test]
+fn ball_plane_proximity() {
+    let ball  = Ball::new(0.5f64);
+    let plane = Plane::new(Vector2::y());
+
+    for &(y, expected) in [ (0.25, Proximity::Intersecting),
+                            (-3.0, Proximity::Intersecting),
+                            (0.5,  Proximity::Intersecting),
+                            (0.55, Proximity::WithinMargin),
+                            (0.7,  Proximity::Disjoint) ].iter() {
+        assert_eq!(query::proximity(&at(0.0, -1.0), &plane, &at(3.0, y - 1.0), &ball, 0.1), expected);
+        assert_eq!(query::proximity(&at(3.0, y - 1.0), &ball, &at(0.0, -1.0), &plane, 0.1), expected);
+    }
+}
+
+#[test]
+fn ball_plane_proximity_dispatcher() {
+    let ball:  ShapeHandle<Point2<f64>, Isometry2<f64>> = ShapeHandle::new(Ball::new(0.5f64));
+    let plane: ShapeHandle<Point2<f64>, Isometry2<f64>> = ShapeHandle::new(Plane::new(Vector2::y()));
+    let dispatcher = DefaultProximityDispatcher::new();
+
+    let mut plane_ball = dispatcher.get_proximity_algorithm(&*plane, &*ball).unwrap();
+    let mut ball_plane = dispatcher.get_proximity_algorithm(&*ball, &*plane).unwrap();
+
+    for &(y, expected) in [ (0.25, Proximity::Intersecting),
+                            (0.5,  Proximity::Intersecting),
+                            (0.55, Proximity::WithinMargin),
+                            (0.7,  Proximity::Disjoint) ].iter() {
+        assert!(plane_ball.update(&dispatcher, &at(0.0, -1.0), &*plane, &at(3.0, y - 1.0), &*ball, 0.1));
+        assert!(ball_plane.update(&dispatcher, &at(3.0, y - 1.0), &*ball, &at(0.0, -1.0), &*plane, 0.1));
+        assert_eq!(plane_ball.proximity(), expected);
+        assert_eq!(ball_plane.proximity(), expected);
+    }
+}
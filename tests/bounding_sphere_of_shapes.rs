@@ -0,0 +1,30 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Ball, Shape};
+use ncollide::bounding_volume::{self, BoundingVolume};
+
+#[test]
+fn bounding_sphere_of_two_offset_balls() {
+    let ball1 = Ball::new(0.5f64);
+    let ball2 = Ball::new(1.0f64);
+    let m1    = Isometry3::new(Vector3::new(-2.0, 0.0, 0.0), na::zero());
+    let m2    = Isometry3::new(Vector3::new(3.0, 1.0, 0.0), na::zero());
+
+    let shapes = [ (&ball1 as &Shape<Point3<f64>, Isometry3<f64>>, m1),
+                   (&ball2 as &Shape<Point3<f64>, Isometry3<f64>>, m2) ];
+    let bs = bounding_volume::bounding_sphere_of_shapes(&shapes);
+
+    assert!(bs.contains_relative(&bounding_volume::bounding_sphere(&ball1, &m1), 1.0e-7));
+    assert!(bs.contains_relative(&bounding_volume::bounding_sphere(&ball2, &m2), 1.0e-7));
+}
+
+#[test]
+fn bounding_sphere_of_no_shape() {
+    let shapes: [(&Shape<Point3<f64>, Isometry3<f64>>, Isometry3<f64>); 0] = [];
+    let bs = bounding_volume::bounding_sphere_of_shapes(&shapes);
+
+    assert_eq!(*bs.center(), Point3::origin());
+    assert_eq!(bs.radius(), 0.0);
+}
@@ -0,0 +1,26 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::Point3;
+use ncollide::bounding_volume::{BoundingSphere, BoundingVolume};
+
+#[test]
+fn merged_spheres_contain_every_sphere() {
+    let spheres = [
+        BoundingSphere::new(Point3::new(0.0, 0.0, 0.0), 1.0),
+        BoundingSphere::new(Point3::new(4.0, 1.0, 0.0), 0.5),
+        BoundingSphere::new(Point3::new(-1.0, 3.0, 2.0), 2.0)
+    ];
+    let merged = BoundingSphere::of_spheres(&spheres);
+
+    for sphere in spheres.iter() {
+        assert!(merged.contains_relative(sphere, 1.0e-7));
+    }
+}
+
+#[test]
+fn single_sphere_is_unchanged() {
+    let sphere = BoundingSphere::new(Point3::new(1.0, 2.0, 3.0), 0.5);
+
+    assert_eq!(BoundingSphere::of_spheres(&[ sphere.clone() ]), sphere);
+}
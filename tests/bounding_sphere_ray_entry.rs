@@ -0,0 +1,29 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Point3, Vector3};
+use ncollide::bounding_volume::BoundingSphere;
+use ncollide::query::Ray;
+
+#[test]
+fn ray_entry_toi_is_the_near_root() {
+    let sphere = BoundingSphere::new(Point3::new(1.0, 2.0, 3.0), 2.0f64);
+    let ray    = Ray::new(Point3::new(-5.0, 1.0, 2.5), Vector3::new(2.0, 0.1, 0.0));
+
+    // Roots of `|origin + t * dir - center|² = radius²`.
+    let dcenter = ray.origin - *sphere.center();
+    let a       = na::norm_squared(&ray.dir);
+    let b       = na::dot(&dcenter, &ray.dir);
+    let c       = na::norm_squared(&dcenter) - sphere.radius() * sphere.radius();
+    let near    = (-b - (b * b - a * c).sqrt()) / a;
+
+    assert!((sphere.ray_entry_toi(&ray).unwrap() - near).abs() < 1.0e-12);
+
+    // From inside, the ray enters the sphere immediately.
+    let inside = Ray::new(Point3::new(1.5, 2.0, 3.0), Vector3::x());
+    assert_eq!(sphere.ray_entry_toi(&inside), Some(0.0));
+
+    // Rays pointing away from the sphere, or missing it, never enter it.
+    assert!(sphere.ray_entry_toi(&Ray::new(Point3::new(-5.0, 1.0, 2.5), -ray.dir)).is_none());
+    assert!(sphere.ray_entry_toi(&Ray::new(Point3::new(-5.0, 10.0, 2.5), Vector3::x())).is_none());
+}
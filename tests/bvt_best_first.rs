@@ -0,0 +1,56 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Point2, Vector2};
+use ncollide::bounding_volume::AABB;
+use ncollide::partitioning::{BVT, BVTCostFn};
+
+// Searches the leaf with the center closest to a point. The distance to an AABB is a lower bound
+// of the distances to the centers of the leaves it contains.
+struct ClosestCenterCostFn<'a> {
+    point:   Point2<f64>,
+    centers: &'a [Point2<f64>]
+}
+
+impl<'a> BVTCostFn<f64, usize, AABB<Point2<f64>>> for ClosestCenterCostFn<'a> {
+    type UserData = f64;
+
+    fn compute_bv_cost(&mut self, aabb: &AABB<Point2<f64>>) -> Option<f64> {
+        let mut sqdist = 0.0;
+
+        for i in 0 .. 2 {
+            let d = (aabb.mins()[i] - self.point[i]).max(self.point[i] - aabb.maxs()[i]).max(0.0);
+            sqdist = sqdist + d * d;
+        }
+
+        Some(sqdist.sqrt())
+    }
+
+    fn compute_b_cost(&mut self, b: &usize) -> Option<(f64, f64)> {
+        let dist = na::distance(&self.point, &self.centers[*b]);
+
+        Some((dist, dist))
+    }
+}
+
+#[test]
+fn best_first_search_finds_the_closest_center() {
+    let centers: Vec<Point2<f64>> = (0 .. 50usize).map(|i| {
+        Point2::new(((i * 37) % 11) as f64 * 0.9, ((i * 13) % 7) as f64 * 1.3 + (i % 3) as f64 * 0.1)
+    }).collect();
+    let leaves = centers.iter().enumerate().map(|(i, c)| {
+        let half = Vector2::new(0.3 + (i % 4) as f64 * 0.1, 0.2);
+        (i, AABB::new(*c + (-half), *c + half))
+    }).collect();
+    let bvt = BVT::new_balanced(leaves);
+
+    for point in [Point2::new(-3.0, 2.0), Point2::new(4.1, 4.4), Point2::new(20.0, -7.0)].iter() {
+        let mut cost_fn = ClosestCenterCostFn { point: *point, centers: &centers[..] };
+        let (best, dist) = bvt.best_first_search(&mut cost_fn).expect("The tree must not be empty.");
+
+        let brute_force = centers.iter().map(|c| na::distance(point, c)).fold(::std::f64::MAX, f64::min);
+
+        assert_eq!(dist, brute_force);
+        assert_eq!(na::distance(point, &centers[*best]), brute_force);
+    }
+}
@@ -0,0 +1,60 @@
+extern crate alga;
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use alga::general::Id;
+use na::{Isometry2, Point2, Vector2};
+use ncollide::shape::Ball;
+use ncollide::bounding_volume::{self, BoundingVolume, AABB, BoundingSphere};
+use ncollide::partitioning::{BVT, BoundingVolumeInterferencesCollector};
+use ncollide::query::{Ray, RayCast, RayInterferencesCollector};
+
+// Checks the BVT queries against a brute-force test of every leaf, for any bounding volume.
+fn check_queries<BV, F>(bounding_volume: F)
+    where BV: BoundingVolume<Point2<f64>> + RayCast<Point2<f64>, Id> + Clone,
+          F:  Fn(&Ball<f64>, &Isometry2<f64>) -> BV {
+    let ball   = Ball::new(0.5);
+    let leaves: Vec<(usize, BV)> = (0 .. 20usize).map(|i| {
+        let pos = Isometry2::new(Vector2::new((i % 5) as f64 * 1.5, (i / 5) as f64 * 1.5), 0.0);
+        (i, bounding_volume(&ball, &pos))
+    }).collect();
+    let bvt = BVT::new_balanced(leaves.clone());
+
+    let region = bounding_volume(&Ball::new(1.2), &Isometry2::new(Vector2::new(2.0, 2.5), 0.0));
+    let mut expected: Vec<usize> = leaves.iter().filter(|l| l.1.intersects(&region)).map(|l| l.0).collect();
+    let mut found = Vec::new();
+
+    {
+        let mut visitor = BoundingVolumeInterferencesCollector::new(&region, &mut found);
+        bvt.visit(&mut visitor);
+    }
+
+    found.sort();
+    expected.sort();
+    assert!(!expected.is_empty());
+    assert_eq!(found, expected);
+
+    let ray = Ray::new(Point2::new(-1.0, -0.5), Vector2::new(1.0, 0.5));
+    let mut expected: Vec<usize> = leaves.iter().filter(|l| l.1.intersects_ray(&Id::new(), &ray)).map(|l| l.0).collect();
+    let mut found = Vec::new();
+
+    {
+        let mut visitor = RayInterferencesCollector::new(&ray, &mut found);
+        bvt.visit(&mut visitor);
+    }
+
+    found.sort();
+    expected.sort();
+    assert!(!expected.is_empty());
+    assert_eq!(found, expected);
+}
+
+#[test]
+fn bvt_queries_with_aabbs() {
+    check_queries::<AABB<Point2<f64>>, _>(|b, m| bounding_volume::aabb(b, m))
+}
+
+#[test]
+fn bvt_queries_with_bounding_spheres() {
+    check_queries::<BoundingSphere<Point2<f64>>, _>(|b, m| bounding_volume::bounding_sphere(b, m))
+}
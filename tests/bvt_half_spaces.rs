@@ -0,0 +1,85 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Point3, Vector3};
+use ncollide::bounding_volume::{AABB, BoundingSphere};
+use ncollide::partitioning::{BVT, HalfSpacesInterferencesCollector};
+
+// A frustum looking along `+z` from the origin, with a 90° field of view.
+fn frustum() -> Vec<(Point3<f64>, Vector3<f64>)> {
+    let origin = Point3::origin();
+
+    vec![
+        (origin, Vector3::new(1.0, 0.0, -1.0)),
+        (origin, Vector3::new(-1.0, 0.0, -1.0)),
+        (origin, Vector3::new(0.0, 1.0, -1.0)),
+        (origin, Vector3::new(0.0, -1.0, -1.0)),
+        (Point3::new(0.0, 0.0, 1.0), Vector3::new(0.0, 0.0, -1.0)),
+        (Point3::new(0.0, 0.0, 10.0), Vector3::new(0.0, 0.0, 1.0))
+    ]
+}
+
+fn grid() -> Vec<Point3<f64>> {
+    let mut res = Vec::new();
+
+    for i in -12 .. 13 {
+        for j in -12 .. 13 {
+            for k in -2 .. 13 {
+                res.push(Point3::new(i as f64, j as f64, k as f64 + 0.5));
+            }
+        }
+    }
+
+    res
+}
+
+fn is_in_frustum(pt: &Point3<f64>) -> bool {
+    pt.x.abs() <= pt.z && pt.y.abs() <= pt.z && pt.z >= 1.0 && pt.z <= 10.0
+}
+
+#[test]
+fn bvt_aabb_half_spaces_interferences() {
+    let points = grid();
+    let eps    = Vector3::new(1.0e-6, 1.0e-6, 1.0e-6);
+    let leaves = points.iter().map(|pt| AABB::new(*pt - eps, *pt + eps)).enumerate().collect();
+    let bvt    = BVT::new_balanced(leaves);
+
+    let half_spaces = frustum();
+    let mut found   = Vec::new();
+
+    {
+        let mut visitor = HalfSpacesInterferencesCollector::new(&half_spaces, &mut found);
+        bvt.visit(&mut visitor);
+    }
+
+    found.sort();
+
+    let expected: Vec<usize> = (0 .. points.len()).filter(|i| is_in_frustum(&points[*i])).collect();
+    assert!(!expected.is_empty());
+    assert_eq!(found, expected);
+}
+
+#[test]
+fn bvt_bounding_sphere_half_spaces_interferences() {
+    let points = grid();
+    let leaves = points.iter().map(|pt| BoundingSphere::new(*pt, 0.25)).enumerate().collect();
+    let bvt    = BVT::new_balanced(leaves);
+
+    let half_spaces = frustum();
+    let mut found   = Vec::new();
+
+    {
+        let mut visitor = HalfSpacesInterferencesCollector::new(&half_spaces, &mut found);
+        bvt.visit(&mut visitor);
+    }
+
+    // The spheres centered inside of the frustum are found, and the spheres far from it are not.
+    for (i, pt) in points.iter().enumerate() {
+        if is_in_frustum(pt) {
+            assert!(found.contains(&i));
+        }
+        else if pt.z < 0.5 || pt.z > 10.5 || pt.x.abs() > pt.z + 1.0 || pt.y.abs() > pt.z + 1.0 {
+            assert!(!found.contains(&i));
+        }
+    }
+}
@@ -0,0 +1,23 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Point2, Vector2};
+use ncollide::bounding_volume::AABB;
+use ncollide::partitioning::BVT;
+
+#[test]
+fn into_leaves_returns_the_inserted_leaves() {
+    let leaves: Vec<_> = (0 .. 13usize).map(|i| {
+        let mins = Point2::new((i % 5) as f64 * 2.0, (i / 5) as f64 * 3.0);
+        (i, AABB::new(mins, mins + Vector2::new(1.0, 1.0)))
+    }).collect();
+
+    let bvt = BVT::new_balanced(leaves.clone());
+    let mut res = bvt.into_leaves();
+
+    res.sort_by_key(|&(i, _)| i);
+    assert_eq!(res, leaves);
+
+    let empty = BVT::<usize, AABB<Point2<f64>>>::new_balanced(Vec::new());
+    assert!(empty.into_leaves().is_empty());
+}
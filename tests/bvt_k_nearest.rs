@@ -0,0 +1,56 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Ball, Capsule, Cone, Cuboid};
+use ncollide::bounding_volume::{self, BoundingSphere};
+use ncollide::partitioning::BVT;
+use ncollide::query::PointQuery;
+
+#[test]
+fn bvt_k_nearest_matches_brute_force() {
+    let ball = Ball::new(0.5f64);
+    let caps = Capsule::new(0.5, 0.75);
+    let cone = Cone::new(0.5, 0.75);
+    let cube = Cuboid::new(Vector3::new(1.0, 0.5, 1.0));
+
+    let poss = [
+        Isometry3::new(Vector3::new(0.0, 0.0, 1.0), na::zero()),
+        Isometry3::new(Vector3::new(0.0, 0.0, 2.0), na::zero()),
+        Isometry3::new(Vector3::new(0.0, 0.0, 3.0), na::zero()),
+        Isometry3::new(Vector3::new(0.0, 2.0, 4.0), na::zero())
+    ];
+
+    let spheres: Vec<BoundingSphere<Point3<f64>>> = vec![
+        bounding_volume::bounding_sphere(&ball, &poss[0]),
+        bounding_volume::bounding_sphere(&caps, &poss[1]),
+        bounding_volume::bounding_sphere(&cone, &poss[2]),
+        bounding_volume::bounding_sphere(&cube, &poss[3])
+    ];
+
+    let bvt = BVT::new_balanced(spheres.iter().cloned().enumerate().collect());
+    let id  = Isometry3::identity();
+
+    let points = [
+        Point3::new(0.0, 0.0, -3.0),
+        Point3::new(0.5, 1.0, 2.5),
+        Point3::new(10.0, -4.0, 7.0),
+        Point3::new(0.0, 2.0, 4.0)
+    ];
+
+    for point in points.iter() {
+        let mut brute_force: Vec<f64> = spheres.iter().map(|s| s.distance_to_point(&id, point, true)).collect();
+        brute_force.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        for k in 0 .. 6 {
+            let nearest = bvt.k_nearest(point, k);
+
+            assert_eq!(nearest.len(), k.min(spheres.len()));
+
+            for (i, &(leaf, dist)) in nearest.iter().enumerate() {
+                assert!((dist - brute_force[i]).abs() < 1.0e-7);
+                assert!((spheres[*leaf].distance_to_point(&id, point, true) - dist).abs() < 1.0e-7);
+            }
+        }
+    }
+}
@@ -0,0 +1,101 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Point3, Vector3};
+use ncollide::bounding_volume::AABB;
+use ncollide::partitioning::{BVT, DBVT, KNearestNeighborsVisitor, PointsWithinRadiusCollector};
+
+fn point_cloud() -> Vec<Point3<f64>> {
+    // A deterministic pseudo-random sequence.
+    let mut seed = 12345u64;
+    let mut rand = || {
+        seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (seed >> 11) as f64 / (1u64 << 53) as f64 * 10.0
+    };
+
+    (0 .. 500).map(|_| Point3::new(rand(), rand(), rand())).collect()
+}
+
+fn point_aabb(pt: &Point3<f64>) -> AABB<Point3<f64>> {
+    let eps = Vector3::new(1.0e-6, 1.0e-6, 1.0e-6);
+    AABB::new(*pt - eps, *pt + eps)
+}
+
+#[test]
+fn bvt_k_nearest_neighbors_visitor() {
+    let points = point_cloud();
+    let bvt    = BVT::new_balanced(points.iter().map(point_aabb).enumerate().collect());
+    let query  = Point3::new(5.0, 5.0, 5.0);
+
+    let mut brute_force: Vec<(usize, f64)> = points.iter().map(|pt| na::distance(pt, &query)).enumerate().collect();
+    brute_force.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+
+    for &k in [ 0, 1, 10, 600 ].iter() {
+        let mut nearest = Vec::new();
+
+        {
+            let mut visitor = KNearestNeighborsVisitor::new(&query, k, &mut nearest);
+            bvt.visit(&mut visitor);
+        }
+
+        assert_eq!(nearest.len(), k.min(points.len()));
+
+        for (i, &(leaf, dist)) in nearest.iter().enumerate() {
+            assert_eq!(leaf, brute_force[i].0);
+            assert!((dist - brute_force[i].1).abs() < 1.0e-5);
+        }
+    }
+}
+
+#[test]
+fn dbvt_points_within_radius_collector() {
+    let points   = point_cloud();
+    let mut dbvt = DBVT::new();
+
+    for (i, pt) in points.iter().enumerate() {
+        let _ = dbvt.insert_new(i, point_aabb(pt));
+    }
+
+    let query = Point3::new(2.0, 7.0, 4.0);
+
+    for &radius in [ 0.0, 1.0, 2.5 ].iter() {
+        // The collector clears the results of the previous query.
+        let mut found = vec![ points.len() ];
+
+        {
+            let mut visitor = PointsWithinRadiusCollector::new(&query, radius, &mut found);
+            dbvt.visit(&mut visitor);
+        }
+
+        found.sort();
+
+        let expected: Vec<usize> = (0 .. points.len()).filter(|i| na::distance(&points[*i], &query) <= radius).collect();
+        assert_eq!(found, expected);
+    }
+}
+
+#[test]
+fn dbvt_k_nearest() {
+    let points   = point_cloud();
+    let mut dbvt = DBVT::new();
+
+    for (i, pt) in points.iter().enumerate() {
+        let _ = dbvt.insert_new(i, point_aabb(pt));
+    }
+
+    let query = Point3::new(2.0, 7.0, 4.0);
+
+    let mut brute_force: Vec<(usize, f64)> = points.iter().map(|pt| na::distance(pt, &query)).enumerate().collect();
+    brute_force.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+
+    for &k in [ 0, 1, 10, 600 ].iter() {
+        let nearest = dbvt.k_nearest(&query, k);
+
+        assert_eq!(nearest.len(), k.min(points.len()));
+
+        for (i, &(leaf, dist)) in nearest.iter().enumerate() {
+            assert_eq!(leaf, brute_force[i].0);
+            assert!((dist - brute_force[i].1).abs() < 1.0e-5);
+        }
+    }
+}
@@ -0,0 +1,72 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Point2, Vector2};
+use ncollide::bounding_volume::AABB;
+use ncollide::partitioning::{BVT, BoundingVolumeInterferencesCollector};
+
+fn leaf_aabb(i: usize, shift: &Vector2<f64>) -> AABB<Point2<f64>> {
+    let mins = Point2::new(i as f64 * 2.0, 0.0) + *shift;
+
+    AABB::new(mins, mins + Vector2::new(1.0, 1.0))
+}
+
+fn interferences(bvt: &BVT<usize, AABB<Point2<f64>>>, aabb: &AABB<Point2<f64>>) -> Vec<usize> {
+    let mut res = Vec::new();
+
+    {
+        let mut visitor = BoundingVolumeInterferencesCollector::new(aabb, &mut res);
+        bvt.visit(&mut visitor);
+    }
+
+    res.sort();
+    res
+}
+
+#[test]
+fn refit_after_shifting_leaves() {
+    let zero   = na::zero();
+    let shift  = Vector2::new(0.6, 0.3);
+    let leaves = (0 .. 8usize).map(|i| (i, leaf_aabb(i, &zero))).collect();
+    let mut bvt = BVT::new_balanced(leaves);
+
+    bvt.refit(&mut |i: &usize| leaf_aabb(*i, &shift));
+
+    let moved   = (0 .. 8usize).map(|i| (i, leaf_aabb(i, &shift))).collect();
+    let rebuilt = BVT::new_balanced(moved);
+
+    assert!(bvt.is_valid());
+    assert_eq!(bvt.root_bounding_volume(), rebuilt.root_bounding_volume());
+
+    for i in 0 .. 34 {
+        let x     = i as f64 * 0.5;
+        let query = AABB::new(Point2::new(x, 1.1), Point2::new(x + 0.2, 1.2));
+
+        assert_eq!(interferences(&bvt, &query), interferences(&rebuilt, &query));
+    }
+
+    // The leaf 2 now covers [4.6, 5.6] along `x` and can be hit only after the refit.
+    let query = AABB::new(Point2::new(5.2, 0.5), Point2::new(5.3, 0.6));
+    assert_eq!(interferences(&bvt, &query), vec![ 2 ]);
+}
+
+#[test]
+fn refit_some_leaves() {
+    let zero   = na::zero();
+    let shift  = Vector2::new(0.0, 3.0);
+    let leaves = (0 .. 8usize).map(|i| (i, leaf_aabb(i, &zero))).collect();
+    let mut bvt = BVT::new_balanced(leaves);
+
+    let nchanged = bvt.refit_partially(&mut |i: &usize| {
+        if *i == 5 { Some(leaf_aabb(*i, &shift)) } else { None }
+    });
+
+    assert_eq!(nchanged, 1);
+    assert!(bvt.is_valid());
+    assert_eq!(*bvt.root_bounding_volume().unwrap().maxs(), Point2::new(15.0, 4.0));
+
+    let query = AABB::new(Point2::new(10.2, 3.5), Point2::new(10.3, 3.6));
+    assert_eq!(interferences(&bvt, &query), vec![ 5 ]);
+    let query = AABB::new(Point2::new(10.2, 0.5), Point2::new(10.3, 0.6));
+    assert!(interferences(&bvt, &query).is_empty());
+}
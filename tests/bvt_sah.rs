@@ -0,0 +1,62 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Point2, Vector2};
+use ncollide::bounding_volume::{AABB, BoundingVolume};
+use ncollide::partitioning::{BVT, BVTVisitor, BoundingVolumeInterferencesCollector};
+
+// Sums the perimeters of the internal nodes, i.e., the surface area heuristic cost of the tree.
+struct PerimeterSum(f64);
+
+impl BVTVisitor<usize, AABB<Point2<f64>>> for PerimeterSum {
+    fn visit_internal(&mut self, bv: &AABB<Point2<f64>>) -> bool {
+        let extents = *bv.maxs() - *bv.mins();
+        self.0 += extents.x + extents.y;
+        true
+    }
+
+    fn visit_leaf(&mut self, _: &usize, _: &AABB<Point2<f64>>) { }
+}
+
+fn leaves() -> Vec<(usize, AABB<Point2<f64>>)> {
+    // Many small boxes next to a few huge ones.
+    (0 .. 200usize).map(|i| {
+        let center = Point2::new((i % 20) as f64 * 0.05, (i / 20) as f64 * 0.05);
+        let half   = if i % 50 == 0 { Vector2::new(20.0, 20.0) } else { Vector2::new(0.01, 0.01) };
+        let shift  = if i % 50 == 0 { Vector2::new(30.0, 0.0) } else { na::zero() };
+
+        (i, AABB::new(center + shift - half, center + shift + half))
+    }).collect()
+}
+
+#[test]
+fn bvt_sah_is_valid_and_cheaper() {
+    let sah      = BVT::new_with_sah(leaves());
+    let balanced = BVT::new_balanced(leaves());
+
+    assert!(sah.is_valid());
+
+    let query = AABB::new(Point2::new(0.2, 0.2), Point2::new(0.4, 0.3));
+    let mut brute_force: Vec<usize> = leaves().into_iter().filter(|l| l.1.intersects(&query)).map(|l| l.0).collect();
+    let mut found = Vec::new();
+
+    {
+        let mut visitor = BoundingVolumeInterferencesCollector::new(&query, &mut found);
+        sah.visit(&mut visitor);
+    }
+
+    brute_force.sort();
+    found.sort();
+    assert_eq!(found, brute_force);
+
+    let mut sah_cost      = PerimeterSum(0.0);
+    let mut balanced_cost = PerimeterSum(0.0);
+    sah.visit(&mut sah_cost);
+    balanced.visit(&mut balanced_cost);
+
+    assert!(sah_cost.0 < balanced_cost.0);
+
+    let mut all = sah.into_leaves().into_iter().map(|l| l.0).collect::<Vec<_>>();
+    all.sort();
+    assert_eq!(all, (0 .. 200).collect::<Vec<_>>());
+}
@@ -0,0 +1,161 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+#[cfg(feature = "serde-serialize")]
+extern crate serde_json;
+
+use na::{Point2, Point3, Vector2};
+use ncollide::bounding_volume::{AABB, BoundingSphere, BoundingVolume};
+use ncollide::partitioning::{BVT, BVTNode};
+use ncollide::query::{Ray, RayInterferencesCollector};
+
+fn unit_aabb(x: f64) -> AABB<Point2<f64>> {
+    AABB::new(Point2::new(x, 0.0), Point2::new(x + 1.0, 1.0))
+}
+
+fn ray_hits(bvt: &BVT<usize, AABB<Point2<f64>>>, ray: &Ray<Point2<f64>>) -> Vec<usize> {
+    let mut hits = Vec::new();
+
+    {
+        let mut visitor = RayInterferencesCollector::new(ray, &mut hits);
+        bvt.visit(&mut visitor);
+    }
+
+    hits.sort();
+    hits
+}
+
+#[test]
+fn bvt_from_valid_root() {
+    let leaves: Vec<_> = (0 .. 4usize).map(|i| (i, unit_aabb(i as f64 * 2.0))).collect();
+    let balanced       = BVT::new_balanced(leaves);
+
+    assert!(balanced.is_valid());
+
+    let left  = BVTNode::Internal(AABB::new(Point2::new(0.0, 0.0), Point2::new(3.0, 1.0)),
+                                  Box::new(BVTNode::Leaf(unit_aabb(0.0), 0)),
+                                  Box::new(BVTNode::Leaf(unit_aabb(2.0), 1)));
+    let right = BVTNode::Internal(AABB::new(Point2::new(4.0, 0.0), Point2::new(7.0, 1.0)),
+                                  Box::new(BVTNode::Leaf(unit_aabb(4.0), 2)),
+                                  Box::new(BVTNode::Leaf(unit_aabb(6.0), 3)));
+    let root  = BVTNode::Internal(AABB::new(Point2::new(0.0, 0.0), Point2::new(7.0, 1.0)),
+                                  Box::new(left), Box::new(right));
+
+    let rebuilt = BVT::from_root(root).expect("The tree should be valid.");
+
+    for x in 0 .. 16 {
+        let ray = Ray::new(Point2::new(x as f64 * 0.5, -1.0), Vector2::y());
+
+        assert_eq!(ray_hits(&balanced, &ray), ray_hits(&rebuilt, &ray));
+    }
+}
+
+#[test]
+fn bvt_from_corrupted_root() {
+    // The root bounding volume misses the second leaf.
+    let root = BVTNode::Internal(unit_aabb(0.0),
+                                 Box::new(BVTNode::Leaf(unit_aabb(0.0), 0usize)),
+                                 Box::new(BVTNode::Leaf(unit_aabb(2.0), 1)));
+
+    assert!(BVT::from_root(root).is_none());
+}
+
+#[test]
+fn bvt_refit_and_into_leaves() {
+    let leaves: Vec<_> = (0 .. 7usize).map(|i| (i, unit_aabb(i as f64 * 2.0))).collect();
+    let mut bvt        = BVT::new_balanced(leaves);
+    let depth          = bvt.depth();
+
+    bvt.refit(&mut |i| unit_aabb(*i as f64 * 3.0));
+
+    assert!(bvt.is_valid());
+    assert_eq!(bvt.depth(), depth);
+
+    let root = bvt.root_bounding_volume().unwrap().clone();
+    assert_eq!(root, AABB::new(Point2::new(0.0, 0.0), Point2::new(19.0, 1.0)));
+
+    let ray = Ray::new(Point2::new(9.5, -1.0), Vector2::y());
+    assert_eq!(ray_hits(&bvt, &ray), vec![ 3 ]);
+
+    let mut ids: Vec<_> = bvt.into_leaves().into_iter().map(|(i, _)| i).collect();
+    ids.sort();
+    assert_eq!(ids, (0 .. 7).collect::<Vec<_>>());
+}
+
+#[test]
+fn bvt_of_3d_bounding_spheres_is_valid() {
+    // Merging spheres with arbitrary centers introduces rounding errors.
+    let leaves: Vec<_> = (0 .. 50usize).map(|i| {
+        let t = i as f64;
+        let center = Point3::new((t * 1.3).sin() * 10.0, (t * 0.7).cos() * 7.0, t * 0.37 - 3.1);
+
+        (i, BoundingSphere::new(center, 0.1 + (t * 0.91).sin().abs()))
+    }).collect();
+    let bvt = BVT::new_balanced(leaves.clone());
+
+    assert!(bvt.is_valid());
+
+    // Rebuild the same tree by merging the leaves pairwise.
+    let mut nodes: Vec<_> = leaves.into_iter().map(|(i, bs)| BVTNode::Leaf(bs, i)).collect();
+
+    while nodes.len() > 1 {
+        let mut parents = Vec::new();
+        let mut it      = nodes.into_iter();
+
+        while let Some(left) = it.next() {
+            match it.next() {
+                Some(right) => {
+                    let bv = left.bounding_volume().merged(right.bounding_volume());
+                    parents.push(BVTNode::Internal(bv, Box::new(left), Box::new(right)))
+                },
+                None => parents.push(left)
+            }
+        }
+
+        nodes = parents;
+    }
+
+    assert!(BVT::from_root(nodes.pop().unwrap()).is_some());
+}
+
+#[cfg(feature = "serde-serialize")]
+#[test]
+fn bvt_encode_decode() {
+    let leaves: Vec<_> = (0 .. 5usize).map(|i| (i, unit_aabb(i as f64 * 2.0))).collect();
+    let bvt            = BVT::new_balanced(leaves);
+    let decoded: BVT<usize, AABB<Point2<f64>>> = serde_json::from_str(&serde_json::to_string(&bvt).unwrap()).unwrap();
+
+    assert!(decoded.is_valid());
+    assert_eq!(decoded.root_bounding_volume(), bvt.root_bounding_volume());
+
+    for x in 0 .. 20 {
+        let ray = Ray::new(Point2::new(x as f64 * 0.5, -1.0), Vector2::y());
+
+        assert_eq!(ray_hits(&bvt, &ray), ray_hits(&decoded, &ray));
+    }
+}
+
+#[cfg(feature = "serde-serialize")]
+#[test]
+fn bvt_encode_decode_bounding_spheres() {
+    let leaves: Vec<_> = (0 .. 5usize).map(|i| {
+        (i, BoundingSphere::new(Point2::new(i as f64 * 2.0, 0.0), 0.5))
+    }).collect();
+    let bvt     = BVT::new_balanced(leaves);
+    let decoded: BVT<usize, BoundingSphere<Point2<f64>>> = serde_json::from_str(&serde_json::to_string(&bvt).unwrap()).unwrap();
+
+    assert!(decoded.is_valid());
+    assert_eq!(decoded.root_bounding_volume(), bvt.root_bounding_volume());
+
+    let mut ids: Vec<_> = decoded.into_leaves().into_iter().map(|(i, _)| i).collect();
+    ids.sort();
+    assert_eq!(ids, (0 .. 5).collect::<Vec<_>>());
+
+    // The root bounding sphere misses the second leaf.
+    let root = BVTNode::Internal(BoundingSphere::new(Point2::new(0.0, 0.0), 0.5),
+                                 Box::new(BVTNode::Leaf(BoundingSphere::new(Point2::new(0.0, 0.0), 0.5), 0usize)),
+                                 Box::new(BVTNode::Leaf(BoundingSphere::new(Point2::new(2.0, 0.0), 0.5), 1)));
+    let encoded = format!("{{\"tree\":{}}}", serde_json::to_string(&root).unwrap());
+    let decoded: Result<BVT<usize, BoundingSphere<Point2<f64>>>, _> = serde_json::from_str(&encoded);
+
+    assert!(decoded.is_err());
+}
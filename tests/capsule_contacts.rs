@@ -0,0 +1,82 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::f64::consts::FRAC_PI_2;
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Ball, Capsule, ShapeHandle};
+use ncollide::query::{self, Contact};
+use ncollide::narrow_phase::{ContactDispatcher, DefaultContactDispatcher};
+
+fn manifold(m1: &Isometry3<f64>, g1: ShapeHandle<Point3<f64>, Isometry3<f64>>,
+            m2: &Isometry3<f64>, g2: ShapeHandle<Point3<f64>, Isometry3<f64>>) -> Vec<Contact<Point3<f64>>> {
+    let dispatcher = DefaultContactDispatcher::new();
+    let mut algo   = dispatcher.get_contact_algorithm(&*g1, &*g2).unwrap();
+    let mut res    = Vec::new();
+
+    assert!(algo.update(&dispatcher, m1, &*g1, m2, &*g2, 0.0));
+    algo.contacts(&mut res);
+
+    res
+}
+
+fn assert_contact(c: &Contact<Point3<f64>>, normal: Vector3<f64>, depth: f64, world1: Point3<f64>) {
+    assert!(na::norm(&(*c.normal - normal)) < 1.0e-7, "Wrong normal: {}", *c.normal);
+    assert!((c.depth - depth).abs() < 1.0e-7, "Wrong depth: {}", c.depth);
+    assert!(na::distance(&c.world1, &world1) < 1.0e-7, "Wrong contact point: {}", c.world1);
+}
+
+#[test]
+fn ball_on_capsule_end_cap() {
+    let ball    = Ball::new(0.5);
+    let capsule = Capsule::new(1.0, 0.5);
+    let mb      = Isometry3::new(Vector3::new(0.0, 1.8, 0.0), na::zero());
+    let mc      = Isometry3::new(na::zero(), na::zero());
+
+    let c = query::contact(&mb, &ball, &mc, &capsule, 0.0).unwrap();
+    assert_contact(&c, -Vector3::y(), 0.2, Point3::new(0.0, 1.3, 0.0));
+
+    // The dispatcher gives the same contact, in both orders.
+    let contacts = manifold(&mb, ShapeHandle::new(ball.clone()), &mc, ShapeHandle::new(capsule.clone()));
+    assert_eq!(contacts.len(), 1);
+    assert_contact(&contacts[0], -Vector3::y(), 0.2, Point3::new(0.0, 1.3, 0.0));
+
+    let contacts = manifold(&mc, ShapeHandle::new(capsule), &mb, ShapeHandle::new(ball));
+    assert_eq!(contacts.len(), 1);
+    assert_contact(&contacts[0], Vector3::y(), 0.2, Point3::new(0.0, 1.5, 0.0));
+}
+
+#[test]
+fn capsules_side_by_side_and_crossing() {
+    let capsule = Capsule::new(1.0, 0.5);
+    let m1      = Isometry3::new(na::zero(), na::zero());
+
+    // A horizontal capsule, orthogonal to the `x` axis, touching the side of the vertical one.
+    let m2 = Isometry3::new(Vector3::new(0.9, 0.3, 0.0), Vector3::x() * FRAC_PI_2);
+    let c  = query::contact(&m1, &capsule, &m2, &capsule, 0.0).unwrap();
+    assert_contact(&c, Vector3::x(), 0.1, Point3::new(0.5, 0.3, 0.0));
+
+    let contacts = manifold(&m1, ShapeHandle::new(capsule.clone()), &m2, ShapeHandle::new(capsule.clone()));
+    assert_eq!(contacts.len(), 1);
+    assert_contact(&contacts[0], Vector3::x(), 0.1, Point3::new(0.5, 0.3, 0.0));
+
+    // A horizontal capsule along the `x` axis, with a core segment crossing the vertical one.
+    let m2 = Isometry3::new(Vector3::new(0.9, 0.3, 0.0), Vector3::z() * FRAC_PI_2);
+    let c  = query::contact(&m1, &capsule, &m2, &capsule, 0.0).unwrap();
+    assert_contact(&c, Vector3::x(), 1.0, Point3::new(0.5, 0.3, 0.0));
+}
+
+#[test]
+fn parallel_capsules_two_contacts() {
+    let capsule = Capsule::new(1.0, 0.5);
+    let m1      = Isometry3::new(na::zero(), na::zero());
+    let m2      = Isometry3::new(Vector3::new(0.0, 0.5, 0.9), na::zero());
+
+    let mut contacts = manifold(&m1, ShapeHandle::new(capsule.clone()), &m2, ShapeHandle::new(capsule));
+    contacts.sort_by(|a, b| a.world1.y.partial_cmp(&b.world1.y).unwrap());
+
+    // The core segments overlap for y in [-0.5, 1.0].
+    assert_eq!(contacts.len(), 2);
+    assert_contact(&contacts[0], Vector3::z(), 0.1, Point3::new(0.0, -0.5, 0.5));
+    assert_contact(&contacts[1], Vector3::z(), 0.1, Point3::new(0.0, 1.0, 0.5));
+    assert!(na::distance(&contacts[1].world2, &Point3::new(0.0, 1.0, 0.4)) < 1.0e-7);
+}
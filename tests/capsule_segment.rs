@@ -0,0 +1,18 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Point2, Point3};
+use ncollide::shape::{Ball, Capsule};
+
+#[test]
+fn capsule_segment_and_ball_center() {
+    let capsule = Capsule::new(1.5f64, 0.5);
+    let (a, b): (Point3<f64>, Point3<f64>) = capsule.segment();
+
+    assert!((na::distance(&a, &b) - 2.0 * capsule.half_height()).abs() < 1.0e-12);
+    assert_eq!(a, Point3::new(0.0, -1.5, 0.0));
+    assert_eq!(b, Point3::new(0.0, 1.5, 0.0));
+
+    let ball = Ball::new(2.0f64);
+    assert_eq!(ball.center::<Point2<f64>>(), Point2::origin());
+}
@@ -0,0 +1,54 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::f64::consts::FRAC_PI_4;
+
+use na::{Isometry3, Translation3, UnitQuaternion, Vector3};
+use ncollide::shape::{Cuboid, FeatureId};
+use ncollide::query::{self, ClosestPoints};
+
+#[test]
+fn cuboid_cuboid_face_vertex_features() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+
+    // The second cuboid stands on one of its vertices above the top face of the first one.
+    let rot = UnitQuaternion::rotation_between(&Vector3::new(-1.0, -1.0, -1.0), &Vector3::new(0.0, -1.0, 0.0)).unwrap();
+    let m1  = Isometry3::identity();
+    let m2  = Isometry3::from_parts(Translation3::new(0.2, 1.0 + 3.0f64.sqrt() + 0.1, 0.3), rot);
+
+    match query::closest_points(&m1, &cuboid, &m2, &cuboid, 1.0) {
+        ClosestPoints::WithinMargin(p1, p2, Some((f1, f2))) => {
+            assert!((na::distance(&p1, &p2) - 0.1).abs() < 1.0e-6);
+            // The top face spans the axes `x` and `z`, and has only positive coordinates.
+            assert_eq!(f1, FeatureId::Face((0b101 << 3) | 0b000));
+
+            match f2 {
+                FeatureId::Vertex(_) => { },
+                _                    => panic!("Expected a vertex, found: {:?}", f2)
+            }
+        },
+        res => panic!("Unexpected closest points: {:?}", res)
+    }
+}
+
+#[test]
+fn cuboid_cuboid_edge_edge_features() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+
+    // The top edge of the first cuboid is parallel to `z` and the bottom edge of the second
+    // cuboid is parallel to `x`.
+    let m1 = Isometry3::new(na::zero(), Vector3::z() * FRAC_PI_4);
+    let m2 = Isometry3::new(Vector3::new(0.1, 2.0 * 2.0f64.sqrt() + 0.1, 0.2), Vector3::x() * FRAC_PI_4);
+
+    match query::closest_points(&m1, &cuboid, &m2, &cuboid, 1.0) {
+        ClosestPoints::WithinMargin(p1, p2, Some((f1, f2))) => {
+            assert!((na::distance(&p1, &p2) - 0.1).abs() < 1.0e-6);
+
+            match (f1, f2) {
+                (FeatureId::Edge(_), FeatureId::Edge(_)) => { },
+                _ => panic!("Expected two edges, found: {:?}", (f1, f2))
+            }
+        },
+        res => panic!("Unexpected closest points: {:?}", res)
+    }
+}
@@ -0,0 +1,118 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::cell::Cell;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::bounding_volume;
+use ncollide::shape::{ConvexHull, Cuboid, SupportMap};
+use ncollide::query::{self, ClosestPoints, QuerySettings};
+use ncollide::query::algorithms::johnson_simplex::JohnsonSimplex;
+use ncollide::query::closest_points_internal;
+
+#[test]
+fn far_apart_cuboids_closest_points_unchanged() {
+    let cube = Cuboid::new(Vector3::new(1.0, 0.5, 0.25));
+
+    for i in 0 .. 50 {
+        let t  = i as f64;
+        let m1 = Isometry3::new(Vector3::new(t.sin(), t.cos(), 0.0), Vector3::new(0.1 * t, 0.2, 0.3));
+        let m2 = Isometry3::new(Vector3::new(10.0 + t, 2.0 * t.cos(), -t.sin()), Vector3::new(0.3, 0.1 * t, 0.2));
+
+        let fast = query::closest_points(&m1, &cube, &m2, &cube, 100.0);
+        let slow = closest_points_internal::support_map_against_support_map_with_params(
+            &m1, &cube, &m2, &cube, 100.0, &mut JohnsonSimplex::new_w_tls(), None, &QuerySettings::default());
+
+        match (fast, slow) {
+            (ClosestPoints::WithinMargin(a1, a2, _), ClosestPoints::WithinMargin(b1, b2, _)) => {
+                let d1: f64 = na::distance(&a1, &a2);
+                let d2: f64 = na::distance(&b1, &b2);
+                assert!((d1 - d2).abs() < 1.0e-6, "Distances differ: {} and {}", d1, d2);
+            },
+            _ => panic!("The cuboids are within the margin.")
+        }
+
+        // Beyond the margin.
+        match query::closest_points(&m1, &cube, &m2, &cube, 1.0) {
+            ClosestPoints::Disjoint => { },
+            _                       => panic!("The cuboids are farther than the margin.")
+        }
+    }
+}
+
+#[test]
+fn far_apart_cuboids_closest_points_on_facing_faces() {
+    let cube = Cuboid::new(Vector3::new(0.5, 0.5, 0.5));
+    let m1   = Isometry3::new(na::zero(), na::zero());
+    let m2   = Isometry3::new(Vector3::new(0.0, 5.0, 0.0), na::zero());
+
+    match query::closest_points(&m1, &cube, &m2, &cube, 10.0) {
+        ClosestPoints::WithinMargin(p1, p2, _) => {
+            assert!(na::distance(&p1, &Point3::new(p1.x, 0.5, p1.z)) < 1.0e-7);
+            assert!(na::distance(&p2, &Point3::new(p2.x, 4.5, p2.z)) < 1.0e-7);
+        },
+        _ => panic!("The cuboids are within the margin.")
+    }
+}
+
+// A support map counting its evaluations, i.e., the iterations of the GJK algorithm.
+struct CountingSupportMap<'a, G: 'a> {
+    shape: &'a G,
+    count: Cell<usize>
+}
+
+impl<'a, G: SupportMap<Point3<f64>, Isometry3<f64>>> SupportMap<Point3<f64>, Isometry3<f64>> for CountingSupportMap<'a, G> {
+    fn support_point(&self, m: &Isometry3<f64>, dir: &Vector3<f64>) -> Point3<f64> {
+        self.count.set(self.count.get() + 1);
+        self.shape.support_point(m, dir)
+    }
+}
+
+// The number of support point evaluations of the first shape by the GJK algorithm on far apart
+// pairs of `hull`, with or without the bounding spheres warm-start direction.
+fn gjk_iterations(hull: &ConvexHull<Point3<f64>>, warm_start: bool) -> usize {
+    let settings = QuerySettings::default();
+    let counting = CountingSupportMap { shape: hull, count: Cell::new(0) };
+
+    for i in 0 .. 128 {
+        let t  = i as f64;
+        let m1 = Isometry3::new(Vector3::new(t.sin(), t.cos(), 0.0), Vector3::new(0.1 * t, 0.2, 0.3));
+        let m2 = Isometry3::new(Vector3::new(10.0 + t.cos(), 10.0 * t.sin(), 5.0), Vector3::new(0.3, 0.1 * t, 0.2));
+
+        let init_dir = if warm_start {
+            let bs1 = bounding_volume::bounding_sphere(hull, &m1);
+            let bs2 = bounding_volume::bounding_sphere(hull, &m2);
+
+            Some(*bs1.center() - *bs2.center())
+        }
+        else {
+            None
+        };
+
+        let _ = closest_points_internal::support_map_against_support_map_with_params(
+            &m1, &counting, &m2, hull, 100.0, &mut JohnsonSimplex::new_w_tls(), init_dir, &settings);
+    }
+
+    counting.count.get()
+}
+
+#[test]
+fn far_apart_hulls_warm_start_does_not_increase_gjk_iterations() {
+    // A box-shaped hull offset from the origin of its local frame, so that the warm-start
+    // direction given by the bounding spheres differs from the default one.
+    let mut pts = Vec::new();
+
+    for i in 0 .. 8 {
+        let x = if i & 1 == 0 { -1.0 } else { 1.0 };
+        let y = if i & 2 == 0 { 2.5 } else { 3.5 };
+        let z = if i & 4 == 0 { -0.25 } else { 0.25 };
+
+        pts.push(Point3::new(x, y, z));
+    }
+
+    let hull = ConvexHull::new(pts);
+    let cold = gjk_iterations(&hull, false);
+    let warm = gjk_iterations(&hull, true);
+
+    assert!(warm <= cold, "The warm-start direction increased the GJK iterations: {} > {}.", warm, cold);
+}
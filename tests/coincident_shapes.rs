@@ -0,0 +1,35 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Vector3};
+use ncollide::shape::{Cuboid, Cylinder, Capsule};
+use ncollide::query::{self, ClosestPoints, Contact};
+use ncollide::query::contacts_internal;
+
+fn assert_sane_penetration(c: Option<Contact<na::Point3<f64>>>, max_depth: f64) {
+    let c = c.expect("Missing contact between coincident shapes.");
+
+    assert!(c.depth > 0.0 && c.depth <= max_depth + 1.0e-3, "Invalid depth: {}", c.depth);
+    assert!((na::norm(&*c.normal) - 1.0).abs() < 1.0e-5, "Non-unit normal: {:?}", *c.normal);
+}
+
+#[test]
+fn coincident_cuboids() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 2.0, 3.0));
+    let m      = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+
+    // The smallest penetration is along the smallest half-extent.
+    assert_sane_penetration(query::contact(&m, &cuboid, &m, &cuboid, 0.0), 2.0);
+    assert!(query::closest_points(&m, &cuboid, &m, &cuboid, 1.0) == ClosestPoints::Intersecting);
+    assert!(query::distance(&m, &cuboid, &m, &cuboid) == 0.0);
+}
+
+#[test]
+fn coincident_cylinders_and_capsules() {
+    let m        = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    let cylinder = Cylinder::new(1.0f64, 1.0);
+    let capsule  = Capsule::new(1.0f64, 1.0);
+
+    assert_sane_penetration(contacts_internal::support_map_against_support_map(&m, &cylinder, &m, &cylinder, 0.0), 2.0);
+    assert_sane_penetration(contacts_internal::support_map_against_support_map(&m, &capsule, &m, &capsule, 0.0), 2.0);
+}
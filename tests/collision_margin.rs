@@ -0,0 +1,44 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Vector3};
+use ncollide::shape::{Cuboid, Compound, ShapeHandle, WithMargin, Shape};
+use ncollide::query;
+
+// The contact depth between `g1` at the origin and a unit cube moved by `x` along the `x` axis.
+fn depth(g1: &Shape<na::Point3<f64>, Isometry3<f64>>, x: f64) -> Option<f64> {
+    let cube = Cuboid::new(Vector3::new(0.5, 0.5, 0.5));
+    let pos  = Isometry3::new(Vector3::new(x, 0.0, 0.0), na::zero());
+
+    query::contact(&Isometry3::identity(), g1, &pos, &cube, 0.0).map(|c| c.depth)
+}
+
+#[test]
+fn margin_generates_contacts_earlier() {
+    let cube     = ShapeHandle::new(Cuboid::new(Vector3::new(0.5, 0.5, 0.5)));
+    let inflated = WithMargin::new(cube.clone(), 0.01);
+
+    // Separated by 0.005: only the inflated cube is in contact.
+    assert!(depth(&*cube, 1.005).is_none());
+    assert!((depth(&inflated, 1.005).unwrap() - 0.005).abs() < 1.0e-6);
+
+    // Penetrating: the depth is relative to the inflated surface.
+    let d  = depth(&*cube, 0.98).unwrap();
+    let dm = depth(&inflated, 0.98).unwrap();
+    assert!((d - 0.02).abs() < 1.0e-6);
+    assert!((dm - d - 0.01).abs() < 1.0e-6);
+}
+
+#[test]
+fn compound_with_margin_inflates_its_parts() {
+    let cube     = ShapeHandle::new(Cuboid::new(Vector3::new(0.5, 0.5, 0.5)));
+    let parts    = vec![ (Isometry3::identity(), cube) ];
+    let compound = Compound::new(parts.clone());
+    let inflated = Compound::new_with_margin(parts, 0.01);
+
+    assert_eq!(compound.margin(), 0.0);
+    assert_eq!(inflated.margin(), 0.01);
+    assert!(depth(&compound, 1.005).is_none());
+    assert!((depth(&inflated, 1.005).unwrap() - 0.005).abs() < 1.0e-6);
+    assert!((depth(&inflated, 0.98).unwrap() - 0.03).abs() < 1.0e-6);
+}
@@ -0,0 +1,97 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Ball, Compound, CompositeShape, Cuboid, ShapeHandle, TriMesh};
+use ncollide::query::{point_internal, ray_internal, PointQuery, Ray, RayCast};
+
+#[test]
+fn composite_shape_ray_cast_on_trimesh_and_compound() {
+    // A unit quad on the plane z = 0.
+    let vertices = vec![ Point3::new(0.0f64, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0),
+                         Point3::new(1.0, 1.0, 0.0), Point3::new(0.0, 1.0, 0.0) ];
+    let indices  = vec![ Point3::new(0usize, 1, 2), Point3::new(0, 2, 3) ];
+    let mesh     = TriMesh::new(Arc::new(vertices), Arc::new(indices), None, None);
+
+    let compound = Compound::new(vec![
+        (Isometry3::new(Vector3::new(0.0, 0.0, 0.0), na::zero()), ShapeHandle::new(Ball::new(0.5f64))),
+        (Isometry3::new(Vector3::new(0.0, 0.0, 5.0), na::zero()), ShapeHandle::new(Cuboid::new(Vector3::new(1.0, 1.0, 1.0))))
+    ]);
+
+    let m   = Isometry3::new(Vector3::new(0.0, 0.0, 1.0), na::zero());
+    let ray = Ray::new(Point3::new(0.25, 0.75, 10.0), -Vector3::z());
+
+    assert_eq!(CompositeShape::<Point3<f64>, Isometry3<f64>>::len(&mesh), 2);
+    assert_eq!(CompositeShape::<Point3<f64>, Isometry3<f64>>::len(&compound), 2);
+
+    let (part, inter) = ray_internal::composite_shape_toi_and_normal_with_ray(&m, &mesh, &ray, true).unwrap();
+    assert_eq!(part, 1);
+    assert!((inter.toi - 9.0).abs() < 1.0e-7);
+    assert!((inter.toi - mesh.toi_with_ray(&m, &ray, true).unwrap()).abs() < 1.0e-7);
+
+    let (part, inter) = ray_internal::composite_shape_toi_and_normal_with_ray(&m, &compound, &ray, true).unwrap();
+    assert_eq!(part, 1);
+    assert!((inter.toi - 3.0).abs() < 1.0e-7);
+    assert!((ray_internal::composite_shape_toi_with_ray(&m, &compound, &ray, true).unwrap() - 3.0).abs() < 1.0e-7);
+
+    let pt   = Point3::new(0.0, 0.0, 10.0);
+    let proj = point_internal::composite_shape_project_point(&m, &compound, &pt, true);
+    assert!(na::distance(&proj.point, &Point3::new(0.0, 0.0, 7.0)) < 1.0e-7);
+    assert!(point_internal::composite_shape_contains_point(&m, &compound, &Point3::new(0.5, 0.5, 6.5)));
+    assert!(!compound.contains_point(&m, &Point3::new(0.0, 0.0, 3.0)));
+}
+
+#[test]
+fn composite_shape_ray_cast_all_hits_on_closed_box() {
+    // A closed box [-1, 1]^3. The ray crosses the diagonal shared by the two triangles of each
+    // face it goes through.
+    let mut vertices = Vec::new();
+
+    for i in 0 .. 8 {
+        vertices.push(Point3::new(if i & 1 == 0 { -1.0f64 } else { 1.0 },
+                                  if i & 2 == 0 { -1.0 } else { 1.0 },
+                                  if i & 4 == 0 { -1.0 } else { 1.0 }))
+    }
+
+    let indices = vec![
+        Point3::new(0usize, 2, 6), Point3::new(0, 6, 4), // x = -1
+        Point3::new(1, 5, 7), Point3::new(1, 7, 3),      // x = 1
+        Point3::new(0, 4, 5), Point3::new(0, 5, 1),      // y = -1
+        Point3::new(2, 3, 7), Point3::new(2, 7, 6),      // y = 1
+        Point3::new(0, 1, 3), Point3::new(0, 3, 2),      // z = -1
+        Point3::new(4, 6, 7), Point3::new(4, 7, 5)       // z = 1
+    ];
+    let mesh = TriMesh::new(Arc::new(vertices), Arc::new(indices), None, None);
+
+    let m    = Isometry3::new(Vector3::new(0.0, 0.0, 1.0), na::zero());
+    let ray  = Ray::new(Point3::new(-5.0, 0.0, 1.0), Vector3::x());
+    let hits = ray_internal::composite_shape_toi_all_with_ray(&m, &mesh, &ray, true);
+
+    assert_eq!(hits.len(), 2);
+    assert!((hits[0].1.toi - 4.0).abs() < 1.0e-7);
+    assert!((hits[1].1.toi - 6.0).abs() < 1.0e-7);
+    assert!(hits[0].0 < 2 && hits[1].0 >= 2 && hits[1].0 < 4);
+
+    let miss = Ray::new(Point3::new(-5.0, 3.0, 1.0), Vector3::x());
+    assert!(ray_internal::composite_shape_toi_all_with_ray(&m, &mesh, &miss, true).is_empty());
+}
+
+#[test]
+fn composite_shape_ray_cast_all_hits_on_touching_compound_parts() {
+    // Two unit cubes touching along the plane x = 0: the ray enters the second one where it
+    // leaves the first one. Both hits are kept since the parts do not share any vertex.
+    let cube     = ShapeHandle::new(Cuboid::new(Vector3::new(0.5f64, 0.5, 0.5)));
+    let compound = Compound::new(vec![
+        (Isometry3::new(Vector3::new(-0.5, 0.0, 0.0), na::zero()), cube.clone()),
+        (Isometry3::new(Vector3::new(0.5, 0.0, 0.0), na::zero()), cube)
+    ]);
+
+    let m    = Isometry3::new(na::zero(), na::zero());
+    let ray  = Ray::new(Point3::new(-0.5, 0.0, 0.0), Vector3::x());
+    let hits = ray_internal::composite_shape_toi_all_with_ray(&m, &compound, &ray, false);
+
+    assert_eq!(hits.len(), 2);
+    assert!((hits[0].1.toi - 0.5).abs() < 1.0e-7 && (hits[1].1.toi - 0.5).abs() < 1.0e-7);
+    assert!(hits[0].0 == 0 && hits[1].0 == 1);
+}
@@ -0,0 +1,35 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry2, Point2, Vector2};
+use ncollide::shape::{Ball, Compound, Cuboid, ShapeHandle};
+use ncollide::query::{Ray, RayCast};
+
+#[test]
+fn compound_ray_cast_reports_hit_part() {
+    let shapes = vec![
+        (Isometry2::new(Vector2::new(0.0, 0.0), na::zero()), ShapeHandle::new(Ball::new(0.5f64))),
+        (Isometry2::new(Vector2::new(3.0, 0.0), na::zero()), ShapeHandle::new(Cuboid::new(Vector2::new(0.5, 0.5)))),
+        (Isometry2::new(Vector2::new(0.0, 3.0), na::zero()), ShapeHandle::new(Ball::new(1.0)))
+    ];
+    let compound = Compound::new(shapes);
+    let m        = Isometry2::new(Vector2::new(1.0, 1.0), na::zero());
+
+    let expected = [
+        (Ray::new(Point2::new(-5.0, 1.0), Vector2::x()), 0, 5.5),
+        (Ray::new(Point2::new(4.0, -5.0), Vector2::y()), 1, 5.5),
+        (Ray::new(Point2::new(1.0, 10.0), -Vector2::y()), 2, 5.0),
+        // Passes through parts 0 and 2, the closest one is reported.
+        (Ray::new(Point2::new(1.0, -5.0), Vector2::y()), 0, 5.5)
+    ];
+
+    for &(ref ray, part, toi) in expected.iter() {
+        let (hit_part, inter) = compound.toi_and_part_with_ray(&m, ray, true).unwrap();
+
+        assert_eq!(hit_part, part);
+        assert!((inter.toi - toi).abs() < 1.0e-7);
+        assert!((compound.toi_with_ray(&m, ray, true).unwrap() - toi).abs() < 1.0e-7);
+    }
+
+    assert!(compound.toi_and_part_with_ray(&m, &Ray::new(Point2::new(10.0, 10.0), Vector2::x()), true).is_none());
+}
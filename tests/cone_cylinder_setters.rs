@@ -0,0 +1,39 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Cone, Cylinder, ShapeHandle};
+use ncollide::bounding_volume::{self, BoundingSphere};
+
+#[test]
+fn cone_bounding_sphere_after_set_radius() {
+    let mut cone = Cone::new(1.0f64, 0.5);
+    let m        = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), na::zero());
+
+    cone.set_radius(2.0);
+    assert_eq!(cone.radius(), 2.0);
+
+    let bs: BoundingSphere<Point3<f64>> = bounding_volume::bounding_sphere(&cone, &m);
+    assert!((bs.radius() - (1.0f64 + 4.0).sqrt()).abs() < 1.0e-7);
+
+    // Shapes behind a handle are built from the current dimensions.
+    let handle: ShapeHandle<Point3<f64>, Isometry3<f64>> = ShapeHandle::new(cone);
+    assert!((handle.bounding_sphere(&m).radius() - bs.radius()).abs() < 1.0e-7);
+}
+
+#[test]
+fn cylinder_bounding_sphere_after_set_half_height() {
+    let mut cylinder = Cylinder::new(1.0f64, 1.0);
+    let m            = Isometry3::new(na::zero(), na::zero());
+
+    cylinder.set_half_height(3.0);
+
+    let bs: BoundingSphere<Point3<f64>> = bounding_volume::bounding_sphere(&cylinder, &m);
+    assert!((bs.radius() - 10.0f64.sqrt()).abs() < 1.0e-7);
+}
+
+#[test]
+#[should_panic]
+fn cone_negative_radius() {
+    Cone::new(1.0f64, 1.0).set_radius(-1.0);
+}
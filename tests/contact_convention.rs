@@ -0,0 +1,60 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry2, Vector2};
+use ncollide::shape::{Ball, Cuboid, Plane};
+use ncollide::query::{self, Contact};
+use ncollide::query::contacts_internal;
+
+fn assert_same_contact(c1: &Contact<na::Point2<f64>>, c2: &Contact<na::Point2<f64>>) {
+    assert!(na::norm(&(*c1.normal - *c2.normal)) < 1.0e-6, "{:?} != {:?}", *c1.normal, *c2.normal);
+    assert!((c1.depth - c2.depth).abs() < 1.0e-6, "{} != {}", c1.depth, c2.depth);
+}
+
+#[test]
+fn overlapping_cuboids_normal_and_depth() {
+    let cuboid = Cuboid::new(Vector2::new(1.0f64, 1.0));
+    let m1     = Isometry2::new(na::zero(), na::zero());
+    let m2     = Isometry2::new(Vector2::new(1.5, 0.2), na::zero());
+
+    // Penetrating: solved by the fallback algorithm of GJK.
+    let c = query::contact(&m1, &cuboid, &m2, &cuboid, 0.0).unwrap();
+    assert!(na::norm(&(*c.normal - Vector2::x())) < 1.0e-6);
+    assert!((c.depth - 0.5).abs() < 1.0e-6);
+
+    // Against the plane supporting the left face of the second cuboid.
+    let plane  = Plane::new(-Vector2::x());
+    let mplane = Isometry2::new(Vector2::new(0.5, 0.0), na::zero());
+    let cp     = query::contact(&m1, &cuboid, &mplane, &plane, 0.0).unwrap();
+    assert_same_contact(&c, &cp);
+
+    // Swapping the shapes flips the normal but not the depth.
+    let flipped = query::contact(&m2, &cuboid, &m1, &cuboid, 0.0).unwrap();
+    assert!(na::norm(&(*flipped.normal + *c.normal)) < 1.0e-6);
+    assert!((flipped.depth - c.depth).abs() < 1.0e-6);
+
+    // Separated but within the prediction distance: solved by GJK.
+    let m2 = Isometry2::new(Vector2::new(2.5, 0.2), na::zero());
+    let c  = query::contact(&m1, &cuboid, &m2, &cuboid, 1.0).unwrap();
+    assert!(na::norm(&(*c.normal - Vector2::x())) < 1.0e-6);
+    assert!((c.depth + 0.5).abs() < 1.0e-6);
+}
+
+#[test]
+fn analytic_and_support_map_ball_contacts_agree() {
+    let ball = Ball::new(1.0f64);
+    let m1   = Isometry2::new(na::zero(), na::zero());
+
+    for &(dist, prediction) in [ (1.5, 0.0), (2.5, 1.0) ].iter() {
+        let m2       = Isometry2::new(Vector2::new(0.0, dist), na::zero());
+        let analytic = query::contact(&m1, &ball, &m2, &ball, prediction).unwrap();
+        let sm       = contacts_internal::support_map_against_support_map(&m1, &ball, &m2, &ball, prediction).unwrap();
+
+        assert!(na::norm(&(*analytic.normal - Vector2::y())) < 1.0e-6);
+        assert!((analytic.depth - (2.0 - dist)).abs() < 1.0e-6);
+
+        // The fallback algorithm only approximates the penetration of curved shapes.
+        assert!(na::dot(&*analytic.normal, &*sm.normal) > 0.99);
+        assert!((analytic.depth - sm.depth).abs() < 1.0e-2);
+    }
+}
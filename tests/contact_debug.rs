@@ -0,0 +1,50 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Vector3};
+use ncollide::shape::{Cuboid, FeatureId, SupportMap};
+use ncollide::query::contacts_internal;
+
+#[test]
+fn separated_cuboids_debug_direction() {
+    let cube = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m1   = Isometry3::new(na::zero(), na::zero());
+    let m2   = Isometry3::new(Vector3::new(5.0, 0.3, -0.2), na::zero());
+
+    // Beyond the prediction distance, GJK stops as soon as it finds a separating axis.
+    let (contact, debug) = contacts_internal::support_map_against_support_map_with_debug(&m1, &cube, &m2, &cube, 0.0);
+    assert!(contact.is_none());
+    assert!(!debug.penetration_fallback);
+    assert!(na::dot(&debug.dir, &Vector3::x()) > 0.9, "Wrong separating axis: {}", debug.dir);
+
+    let max1 = na::dot(&cube.support_point(&m1, &debug.dir).coords, &debug.dir);
+    let min2 = na::dot(&cube.support_point(&m2, &-debug.dir).coords, &debug.dir);
+    assert!(max1 < min2);
+
+    // Within the prediction distance: the direction is the contact normal.
+    let (contact, debug) = contacts_internal::support_map_against_support_map_with_debug(&m1, &cube, &m2, &cube, 5.0);
+    let contact = contact.unwrap();
+    assert!(na::norm(&(debug.dir - Vector3::x())) < 1.0e-7, "Wrong separating axis: {}", debug.dir);
+    assert_eq!(debug.dir, *contact.normal);
+    assert!((contact.depth + 3.0).abs() < 1.0e-7);
+    assert!(!debug.simplex.is_empty());
+
+    match debug.features {
+        (FeatureId::Face(_), FeatureId::Face(_)) => { },
+        features => panic!("The witness features should be faces: {:?}", features)
+    }
+}
+
+#[test]
+fn penetrating_cuboids_use_fallback() {
+    let cube = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m1   = Isometry3::new(na::zero(), na::zero());
+    let m2   = Isometry3::new(Vector3::new(1.5, 0.3, -0.2), na::zero());
+
+    let (contact, debug) = contacts_internal::support_map_against_support_map_with_debug(&m1, &cube, &m2, &cube, 0.0);
+    let contact = contact.unwrap();
+
+    assert!(debug.penetration_fallback);
+    assert_eq!(debug.dir, *contact.normal);
+    assert!(na::dot(&debug.dir, &Vector3::x()) > 0.9);
+}
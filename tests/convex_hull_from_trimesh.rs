@@ -0,0 +1,41 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+use na::Point3;
+use ncollide::shape::{ConvexHull, TriMesh};
+use ncollide::transformation::TryFromTriMesh;
+
+#[test]
+fn cube_mesh_with_interior_vertex_to_convex_hull() {
+    let mut vertices = Vec::new();
+
+    for i in 0 .. 8 {
+        vertices.push(Point3::new(if i & 1 == 0 { -1.0 } else { 1.0 },
+                                  if i & 2 == 0 { -1.0 } else { 1.0 },
+                                  if i & 4 == 0 { -1.0 } else { 1.0 }));
+    }
+
+    // The triangles are not used, one of them reaches the interior vertex.
+    vertices.push(Point3::new(0.1, 0.2, -0.3));
+    let indices = vec![ Point3::new(0usize, 1, 3), Point3::new(0, 3, 2), Point3::new(0, 1, 8) ];
+    let mesh    = TriMesh::new(Arc::new(vertices.clone()), Arc::new(indices), None, None);
+
+    let hull = ConvexHull::try_from_trimesh(&mesh).unwrap();
+    assert_eq!(hull.points().len(), 8);
+
+    for pt in hull.points() {
+        assert!(vertices[.. 8].contains(pt));
+    }
+}
+
+#[test]
+fn flat_mesh_has_no_convex_hull() {
+    let vertices = vec![ Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0),
+                         Point3::new(1.0, 0.0, 1.0), Point3::new(0.0, 0.0, 1.0),
+                         Point3::new(0.5, 0.0, 0.5) ];
+    let indices  = vec![ Point3::new(0usize, 1, 2), Point3::new(0, 2, 3) ];
+    let mesh     = TriMesh::new(Arc::new(vertices), Arc::new(indices), None, None);
+
+    assert!(ConvexHull::try_from_trimesh(&mesh).is_none());
+}
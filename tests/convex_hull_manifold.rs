@@ -0,0 +1,90 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{ConvexHull, Cuboid, ShapeHandle};
+use ncollide::query::{self, Contact};
+use ncollide::narrow_phase::{ContactDispatcher, DefaultContactDispatcher};
+
+fn box_hull(half: f64) -> ConvexHull<Point3<f64>> {
+    let mut pts = Vec::new();
+
+    for i in 0 .. 8 {
+        pts.push(Point3::new(if i & 1 == 0 { -half } else { half },
+                             if i & 2 == 0 { -half } else { half },
+                             if i & 4 == 0 { -half } else { half }));
+    }
+
+    ConvexHull::new(pts)
+}
+
+fn manifold(m1: &Isometry3<f64>, g1: ConvexHull<Point3<f64>>,
+            m2: &Isometry3<f64>, g2: ConvexHull<Point3<f64>>) -> Vec<Contact<Point3<f64>>> {
+    let dispatcher = DefaultContactDispatcher::new();
+    let g1         = ShapeHandle::new(g1);
+    let g2         = ShapeHandle::new(g2);
+    let mut algo   = dispatcher.get_contact_algorithm(&*g1, &*g2).unwrap();
+    let mut res    = Vec::new();
+
+    assert!(algo.update(&dispatcher, m1, &*g1, m2, &*g2, 0.0));
+    algo.contacts(&mut res);
+    assert_eq!(algo.num_contacts(), res.len());
+
+    res
+}
+
+#[test]
+fn offset_box_hulls_four_point_manifold() {
+    let m1       = Isometry3::new(na::zero(), na::zero());
+    let m2       = Isometry3::new(Vector3::new(0.5, 1.9, 0.0), na::zero());
+    let contacts = manifold(&m1, box_hull(1.0), &m2, box_hull(1.0));
+
+    // The same normal and depth as the dedicated cuboid-cuboid contact.
+    let cuboid    = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let reference = query::contact(&m1, &cuboid, &m2, &cuboid, 0.0).unwrap();
+
+    assert_eq!(contacts.len(), 4);
+
+    for c in contacts.iter() {
+        assert!(na::norm(&(*c.normal - *reference.normal)) < 1.0e-6);
+        assert!((c.depth - reference.depth).abs() < 1.0e-6);
+        assert!((c.world1.y - 1.0).abs() < 1.0e-6 && (c.world2.y - 0.9).abs() < 1.0e-6);
+    }
+
+    // The corners of the overlap of the top face of the first box and the bottom face of the second.
+    for &(x, z) in [ (-0.5, -1.0), (1.0, -1.0), (1.0, 1.0), (-0.5, 1.0) ].iter() {
+        assert!(contacts.iter().any(|c| (c.world1.x - x).abs() < 1.0e-6 && (c.world1.z - z).abs() < 1.0e-6),
+                "Missing contact at ({}, {}).", x, z);
+    }
+}
+
+#[test]
+fn rotated_box_hull_on_box_hull() {
+    let m1       = Isometry3::new(na::zero(), na::zero());
+    let m2       = Isometry3::new(Vector3::new(0.0, 1.4, 0.0), Vector3::new(0.0, 0.3, 0.0));
+    let contacts = manifold(&m1, box_hull(1.0), &m2, box_hull(0.5));
+
+    // The bottom face of the small box lies inside of the top face of the large one.
+    assert_eq!(contacts.len(), 4);
+
+    for c in contacts.iter() {
+        assert!((c.depth - 0.1).abs() < 1.0e-6);
+        assert!((na::norm(&Vector3::new(c.world1.x, 0.0, c.world1.z)) - 0.5 * 2.0f64.sqrt()).abs() < 1.0e-6);
+    }
+}
+
+#[test]
+fn pyramid_apex_on_box_hull() {
+    let pyramid = ConvexHull::new(vec![
+        Point3::new(0.0, -1.0, 0.0),
+        Point3::new(-1.0, 1.0, -1.0), Point3::new(1.0, 1.0, -1.0),
+        Point3::new(1.0, 1.0, 1.0), Point3::new(-1.0, 1.0, 1.0)
+    ]);
+    let m1       = Isometry3::new(na::zero(), na::zero());
+    let m2       = Isometry3::new(Vector3::new(0.2, 1.95, 0.1), na::zero());
+    let contacts = manifold(&m1, box_hull(1.0), &m2, pyramid);
+
+    assert_eq!(contacts.len(), 1);
+    assert!((contacts[0].depth - 0.05).abs() < 1.0e-6);
+    assert!(na::distance(&contacts[0].world2, &Point3::new(0.2, 0.95, 0.1)) < 1.0e-6);
+}
@@ -0,0 +1,51 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry2, Point2, Vector2};
+use ncollide::shape::ConvexPolygon;
+use ncollide::query::{PointQuery, Ray, RayCast};
+
+fn triangle() -> ConvexPolygon<Point2<f64>> {
+    ConvexPolygon::from_ccw_points(&[ Point2::new(0.0, 0.0), Point2::new(2.0, 0.0), Point2::new(0.0, 2.0) ])
+}
+
+#[test]
+fn convex_polygon_point_query() {
+    let poly = triangle();
+    let m    = Isometry2::new(Vector2::new(1.0, 1.0), na::zero());
+
+    assert!(poly.contains_point(&m, &Point2::new(1.5, 1.5)));
+    assert!(!poly.contains_point(&m, &Point2::new(3.0, 3.0)));
+
+    // Nearest to the hypotenuse.
+    let proj = poly.project_point(&m, &Point2::new(3.0, 3.0), true);
+    assert!(!proj.is_inside);
+    assert!(na::distance(&proj.point, &Point2::new(2.0, 2.0)) < 1.0e-9);
+
+    // Nearest to the bottom edge.
+    let proj = poly.project_point(&m, &Point2::new(1.5, -1.0), true);
+    assert!(na::distance(&proj.point, &Point2::new(1.5, 1.0)) < 1.0e-9);
+
+    // From inside, on the closest edge.
+    let proj = poly.project_point(&m, &Point2::new(1.2, 1.5), false);
+    assert!(proj.is_inside);
+    assert!(na::distance(&proj.point, &Point2::new(1.0, 1.5)) < 1.0e-9);
+}
+
+#[test]
+fn convex_polygon_ray_cast() {
+    let poly = triangle();
+    let m    = Isometry2::new(Vector2::new(1.0, 1.0), na::zero());
+
+    let ray   = Ray::new(Point2::new(1.5, -3.0), Vector2::y());
+    let inter = poly.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert!((inter.toi - 4.0).abs() < 1.0e-9);
+    assert!(na::norm(&(*inter.normal + Vector2::y())) < 1.0e-9);
+
+    let ray   = Ray::new(Point2::new(1.5, 1.5), Vector2::x());
+    let inter = poly.toi_and_normal_with_ray(&m, &ray, false).unwrap();
+    assert!((inter.toi - 1.0).abs() < 1.0e-9);
+
+    assert!(poly.toi_with_ray(&m, &ray, true).unwrap() == 0.0);
+    assert!(!poly.intersects_ray(&m, &Ray::new(Point2::new(5.0, 1.5), Vector2::x())));
+}
@@ -0,0 +1,65 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::cmp::Ordering;
+
+use na::{Isometry2, Point2, Vector2};
+use ncollide::shape::{Compound, ConvexPolygon, ShapeHandle2};
+use ncollide::volumetric::{self, Volumetric};
+use ncollide::utils;
+
+#[test]
+fn convex_polygon_from_point_cloud() {
+    let points = [
+        Point2::new(1.0, 0.5), Point2::new(0.0, 0.0), Point2::new(2.0, 1.0), Point2::new(0.5, 0.2),
+        Point2::new(1.0, 0.0), Point2::new(0.0, 1.0), Point2::new(2.0, 0.0), Point2::new(0.0, 0.0),
+        Point2::new(2.0, 0.5), Point2::new(1.5, 0.9)
+    ];
+
+    // The interior, duplicate and edge points are discarded.
+    let poly = ConvexPolygon::try_from_points(&points).unwrap();
+    assert_eq!(poly.points(), &[ Point2::new(0.0, 0.0), Point2::new(2.0, 0.0),
+                                 Point2::new(2.0, 1.0), Point2::new(0.0, 1.0) ]);
+
+    let collinear = [ Point2::new(0.0, 0.0), Point2::new(1.0, 1.0), Point2::new(3.0, 3.0), Point2::new(1.0, 1.0) ];
+    assert!(ConvexPolygon::try_from_points(&collinear).is_none());
+}
+
+#[test]
+fn exact_orientation_of_nearly_collinear_points() {
+    let ulp = 2.0f64.powi(-53);
+    let b   = Point2::new(12.0, 12.0);
+    let c   = Point2::new(24.0, 24.0);
+
+    // The point `a` is at most a few ulps away from the line through `b` and `c`, on the left of
+    // it iff `j > i`.
+    for i in 0 .. 32 {
+        for j in 0 .. 32 {
+            let a = Point2::new(0.5 + i as f64 * ulp, 0.5 + j as f64 * ulp);
+            assert_eq!(utils::orient2d(&a, &b, &c), j.cmp(&i));
+        }
+    }
+
+    assert_eq!(utils::orient2d(&Point2::new(0.0f32, 0.0), &Point2::new(1.0, 0.0), &Point2::new(0.0, 1.0)),
+               Ordering::Greater);
+}
+
+#[test]
+fn convex_polygon_mass_properties() {
+    let points = [ Point2::new(0.0f64, 0.0), Point2::new(2.0, 0.0), Point2::new(2.0, 1.0), Point2::new(0.0, 1.0) ];
+    let poly   = ConvexPolygon::try_from_points(&points).unwrap();
+
+    assert!((poly.area() - 6.0).abs() < 1.0e-9);
+    assert!((poly.volume() - 2.0).abs() < 1.0e-9);
+    assert!(na::distance(&poly.center_of_mass(), &Point2::new(1.0, 0.5)) < 1.0e-9);
+    assert!((poly.unit_angular_inertia() - 2.0 * 5.0 / 12.0).abs() < 1.0e-9);
+
+    // Convex polygons can be parts of compounds.
+    let m        = Isometry2::new(Vector2::new(1.0, 2.0), na::zero());
+    let compound = Compound::new(vec![ (m, ShapeHandle2::new(poly)) ]);
+    let (mass, com, inertia) = volumetric::compound_mass_properties2(&compound, &[ 3.0 ]).unwrap();
+
+    assert!((mass - 6.0).abs() < 1.0e-9);
+    assert!(na::distance(&com, &Point2::new(2.0, 2.5)) < 1.0e-9);
+    assert!((inertia - 3.0 * 2.0 * 5.0 / 12.0).abs() < 1.0e-9);
+}
@@ -0,0 +1,73 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::f64::consts::FRAC_PI_2;
+use na::{Isometry3, Point3, Vector3, Translation3, UnitQuaternion};
+use ncollide::shape::{Cuboid, Capsule, ShapeHandle};
+use ncollide::query::{self, Contact};
+use ncollide::narrow_phase::{ContactDispatcher, DefaultContactDispatcher};
+
+fn manifold(m1: &Isometry3<f64>, g1: ShapeHandle<Point3<f64>, Isometry3<f64>>,
+            m2: &Isometry3<f64>, g2: ShapeHandle<Point3<f64>, Isometry3<f64>>) -> Vec<Contact<Point3<f64>>> {
+    let dispatcher = DefaultContactDispatcher::new();
+    let mut algo   = dispatcher.get_contact_algorithm(&*g1, &*g2).unwrap();
+    let mut res    = Vec::new();
+
+    assert!(algo.update(&dispatcher, m1, &*g1, m2, &*g2, 0.0));
+    algo.contacts(&mut res);
+
+    res
+}
+
+fn assert_contact(c: &Contact<Point3<f64>>, normal: Vector3<f64>, depth: f64, world1: Point3<f64>) {
+    assert!(na::norm(&(*c.normal - normal)) < 1.0e-7, "Wrong normal: {}", *c.normal);
+    assert!((c.depth - depth).abs() < 1.0e-7, "Wrong depth: {}", c.depth);
+    assert!(na::distance(&c.world1, &world1) < 1.0e-7, "Wrong contact point: {}", c.world1);
+}
+
+#[test]
+fn capsule_resting_flat_on_cuboid_top_face() {
+    let cuboid  = Cuboid::new(Vector3::new(2.0, 1.0, 2.0));
+    let capsule = Capsule::new(1.0, 0.5);
+    let mb      = Isometry3::new(na::zero(), na::zero());
+    // The capsule axis is along `x`.
+    let mc      = Isometry3::new(Vector3::new(0.0, 1.4, 0.0), Vector3::z() * FRAC_PI_2);
+
+    let c = query::contact(&mb, &cuboid, &mc, &capsule, 0.0).unwrap();
+    assert_contact(&c, Vector3::y(), 0.1, Point3::new(c.world1.x, 1.0, 0.0));
+
+    let mut contacts = manifold(&mb, ShapeHandle::new(cuboid.clone()), &mc, ShapeHandle::new(capsule.clone()));
+    assert_eq!(contacts.len(), 2);
+    contacts.sort_by(|a, b| a.world1.x.partial_cmp(&b.world1.x).unwrap());
+    assert_contact(&contacts[0], Vector3::y(), 0.1, Point3::new(-1.0, 1.0, 0.0));
+    assert_contact(&contacts[1], Vector3::y(), 0.1, Point3::new(1.0, 1.0, 0.0));
+
+    // Same manifold, flipped, in the other order.
+    let contacts = manifold(&mc, ShapeHandle::new(capsule), &mb, ShapeHandle::new(cuboid));
+    assert_eq!(contacts.len(), 2);
+
+    for c in contacts.iter() {
+        assert!(na::norm(&(*c.normal + Vector3::y())) < 1.0e-7);
+        assert!((c.world2.y - 1.0).abs() < 1.0e-7);
+    }
+}
+
+#[test]
+fn capsule_poking_cuboid_corner() {
+    let cuboid   = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let capsule  = Capsule::new(1.0, 0.5);
+    let diagonal = na::normalize(&Vector3::new(1.0, 1.0, 1.0));
+    let corner   = Point3::new(1.0, 1.0, 1.0);
+    let mb       = Isometry3::new(na::zero(), na::zero());
+    // The capsule axis is along the diagonal, its lower end cap penetrates the corner by 0.1.
+    let rot      = UnitQuaternion::rotation_between(&Vector3::y(), &diagonal).unwrap();
+    let center   = corner + diagonal * 1.4;
+    let mc       = Isometry3::from_parts(Translation3::from_vector(center.coords), rot);
+
+    let c = query::contact(&mb, &cuboid, &mc, &capsule, 0.0).unwrap();
+    assert_contact(&c, diagonal, 0.1, corner);
+
+    let contacts = manifold(&mb, ShapeHandle::new(cuboid), &mc, ShapeHandle::new(capsule));
+    assert_eq!(contacts.len(), 1);
+    assert_contact(&contacts[0], diagonal, 0.1, corner);
+}
@@ -0,0 +1,92 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry2, Point2, Vector2};
+use ncollide::bounding_volume::{self, AABB2};
+use ncollide::shape::{Ball, Cuboid, Shape, Shape2, ShapeHandle};
+use ncollide::query::{self, Contact};
+use ncollide::narrow_phase::{ContactAlgorithm2, ContactDispatcher, ContactGenerator, DefaultContactDispatcher,
+                             DefaultNarrowPhase, DefaultProximityDispatcher};
+use ncollide::world::{CollisionGroups, CollisionWorld2, GeometricQueryType};
+
+// A user-defined shape without any support mapping.
+struct Tile {
+    half_width: f64
+}
+
+impl Shape<Point2<f64>, Isometry2<f64>> for Tile {
+    fn aabb(&self, m: &Isometry2<f64>) -> AABB2<f64> {
+        bounding_volume::aabb(&Cuboid::new(Vector2::new(self.half_width, self.half_width)), m)
+    }
+}
+
+#[derive(Clone)]
+struct TileBallContactGenerator {
+    contact: Option<Contact<Point2<f64>>>
+}
+
+impl ContactGenerator<Point2<f64>, Isometry2<f64>> for TileBallContactGenerator {
+    fn update(&mut self, _: &ContactDispatcher<Point2<f64>, Isometry2<f64>>,
+              ma: &Isometry2<f64>, a: &Shape2<f64>, mb: &Isometry2<f64>, b: &Shape2<f64>, prediction: f64) -> bool {
+        match (a.as_shape::<Tile>(), b.as_shape::<Ball<f64>>()) {
+            (Some(tile), Some(ball)) => {
+                let cuboid   = Cuboid::new(Vector2::new(tile.half_width, tile.half_width));
+                self.contact = query::contact(ma, &cuboid, mb, ball, prediction);
+                true
+            },
+            _ => false
+        }
+    }
+
+    fn num_contacts(&self) -> usize {
+        self.contact.iter().count()
+    }
+
+    fn contacts(&self, out_contacts: &mut Vec<Contact<Point2<f64>>>) {
+        out_contacts.extend(self.contact.iter().cloned())
+    }
+}
+
+fn dispatcher() -> DefaultContactDispatcher<Point2<f64>, Isometry2<f64>> {
+    let mut dispatcher = DefaultContactDispatcher::new();
+    dispatcher.register_contact_algorithm::<Tile, Ball<f64>, _>(|| {
+        let res: ContactAlgorithm2<f64> = Box::new(TileBallContactGenerator { contact: None });
+        res
+    });
+
+    dispatcher
+}
+
+#[test]
+fn registered_algorithm_is_dispatched() {
+    let tile = Tile { half_width: 1.0 };
+    let ball = Ball::new(0.5f64);
+
+    assert!(DefaultContactDispatcher::new().get_contact_algorithm(&tile, &ball).is_none());
+
+    let dispatcher = dispatcher();
+    assert!(dispatcher.get_contact_algorithm(&tile, &ball).is_some());
+    // Only the registered order is supported.
+    assert!(dispatcher.get_contact_algorithm(&ball, &tile).is_none());
+    // The built-in algorithms are still available.
+    assert!(dispatcher.get_contact_algorithm(&ball, &ball).is_some());
+}
+
+#[test]
+fn custom_shape_in_collision_world() {
+    let query  = GeometricQueryType::Contacts(0.0);
+    let groups = CollisionGroups::new();
+
+    let mut world = CollisionWorld2::new(0.02, true);
+    let narrow_phase = DefaultNarrowPhase::new(Box::new(dispatcher()), Box::new(DefaultProximityDispatcher::new()));
+    let _ = world.set_narrow_phase(Box::new(narrow_phase));
+
+    world.deferred_add(0, Isometry2::identity(), ShapeHandle::new(Tile { half_width: 1.0 }), groups, query, ());
+    world.deferred_add(1, Isometry2::new(Vector2::new(1.4, 0.0), 0.0), ShapeHandle::new(Ball::new(0.5f64)),
+                       groups, query, ());
+    world.update();
+
+    let contacts: Vec<_> = world.contacts().collect();
+    assert_eq!(contacts.len(), 1);
+    assert!((contacts[0].2.depth - 0.1).abs() < 1.0e-7);
+}
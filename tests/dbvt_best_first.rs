@@ -0,0 +1,56 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry2, Point2, Vector2};
+use ncollide::shape::Ball;
+use ncollide::bounding_volume::{self, BoundingSphere};
+use ncollide::partitioning::{BVT, DBVT};
+use ncollide::query::{PointProjectionCostFn, PointQuery, Ray, RayCast, RayIntersectionCostFn};
+
+#[test]
+fn dbvt_best_first_search_matches_bvt() {
+    let ball    = Ball::new(0.3f64);
+    let centers: Vec<Point2<f64>> = (0 .. 30usize).map(|i| {
+        Point2::new(((i * 7) % 11) as f64, ((i * 5) % 13) as f64 * 0.7)
+    }).collect();
+    let leaves: Vec<(usize, BoundingSphere<Point2<f64>>)> = centers.iter().enumerate().map(|(i, c)| {
+        (i, bounding_volume::bounding_sphere(&ball, &Isometry2::new(c.coords, na::zero())))
+    }).collect();
+
+    let bvt      = BVT::new_balanced(leaves.clone());
+    let mut dbvt = DBVT::new();
+
+    for (i, bs) in leaves.into_iter() {
+        let _ = dbvt.insert_new(i, bs);
+    }
+
+    let project = |i: &usize| ball.project_point(&Isometry2::new(centers[*i].coords, na::zero()),
+                                                 &Point2::new(4.2, 3.1), true);
+    let point   = Point2::new(4.2, 3.1);
+
+    let (best_bvt, _)  = bvt.best_first_search(&mut PointProjectionCostFn::new(&point, &project)).unwrap();
+    let (best_dbvt, _) = dbvt.best_first_search(&mut PointProjectionCostFn::new(&point, &project)).unwrap();
+    let brute_force    = (0 .. centers.len()).min_by(|a, b| {
+        na::distance(&point, &centers[*a]).partial_cmp(&na::distance(&point, &centers[*b])).unwrap()
+    }).unwrap();
+
+    assert_eq!(*best_bvt, brute_force);
+    assert_eq!(best_dbvt, brute_force);
+
+    // The leaves are the ball bounding spheres, so a ray search on them is exact.
+    let bvs: Vec<BoundingSphere<Point2<f64>>> = centers.iter().map(|c| BoundingSphere::new(*c, 0.3)).collect();
+    let mut dbvt = DBVT::new();
+
+    for bs in bvs.iter() {
+        let _ = dbvt.insert_new(bs.clone(), bs.clone());
+    }
+
+    let ray = Ray::new(Point2::new(-1.0, 0.0), Vector2::new(1.0, 0.0));
+    let (hit, inter) = dbvt.best_first_search(&mut RayIntersectionCostFn::new(&ray, true, false))
+                           .expect("The ray must hit a ball.");
+    let ti = Isometry2::identity();
+    let brute_force = bvs.iter().filter_map(|bs| bs.toi_with_ray(&ti, &ray, true)).fold(::std::f64::MAX, f64::min);
+
+    assert!((inter.toi - brute_force).abs() < 1.0e-9);
+    assert!((hit.toi_with_ray(&ti, &ray, true).unwrap() - brute_force).abs() < 1.0e-9);
+}
@@ -0,0 +1,52 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Point2, Vector2};
+use ncollide::bounding_volume::AABB;
+use ncollide::partitioning::DBVT;
+use ncollide::query::{Ray, RayInterferencesCollector};
+
+fn square(center: Point2<f64>) -> AABB<Point2<f64>> {
+    let half = Vector2::new(0.5, 0.5);
+
+    AABB::new(center + (-half), center + half)
+}
+
+fn hits(dbvt: &DBVT<Point2<f64>, usize, AABB<Point2<f64>>>, x: f64) -> Vec<usize> {
+    let ray = Ray::new(Point2::new(x, -100.0), Vector2::y());
+    let mut hits = Vec::new();
+
+    {
+        let mut visitor = RayInterferencesCollector::new(&ray, &mut hits);
+        dbvt.visit(&mut visitor);
+    }
+
+    hits.sort();
+    hits
+}
+
+#[test]
+fn dbvt_refit_moves_leaves() {
+    let mut dbvt   = DBVT::new();
+    let mut leaves = Vec::new();
+
+    for i in 0 .. 10usize {
+        leaves.push(dbvt.insert_new(i, square(Point2::new(i as f64 * 2.0, 0.0))));
+    }
+
+    assert_eq!(dbvt.len(), 10);
+    assert_eq!(hits(&dbvt, 6.0), vec![ 3 ]);
+
+    // A small motion stays within the enlarged bounding volume.
+    assert!(!dbvt.refit(&mut leaves[3], square(Point2::new(6.0, 0.0)), 0.2));
+    assert!(dbvt.refit(&mut leaves[3], square(Point2::new(6.0, 0.7)), 0.2));
+    assert!(!dbvt.refit(&mut leaves[3], square(Point2::new(6.1, 0.8)), 0.2));
+
+    // A large motion reinserts the leaf.
+    assert!(dbvt.refit(&mut leaves[3], square(Point2::new(9.0, 50.0)), 0.2));
+
+    assert_eq!(dbvt.len(), 10);
+    assert!(hits(&dbvt, 6.0).is_empty());
+    assert_eq!(hits(&dbvt, 8.4), vec![ 3, 4 ]);
+    assert_eq!(hits(&dbvt, 12.0), vec![ 6 ]);
+}
@@ -0,0 +1,64 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Shape, SupportMap, Ball, Cuboid};
+use ncollide::bounding_volume::AABB;
+use ncollide::query;
+
+// A ball with a radius growing each time its support map is requested.
+struct GrowingBall {
+    queries: AtomicUsize
+}
+
+impl GrowingBall {
+    fn radius(&self) -> f64 {
+        1.0 + self.queries.load(Ordering::SeqCst) as f64
+    }
+}
+
+impl SupportMap<Point3<f64>, Isometry3<f64>> for GrowingBall {
+    fn support_point(&self, m: &Isometry3<f64>, dir: &Vector3<f64>) -> Point3<f64> {
+        Point3::from_coordinates(m.translation.vector + na::normalize(dir) * self.radius())
+    }
+}
+
+impl Shape<Point3<f64>, Isometry3<f64>> for GrowingBall {
+    fn aabb(&self, m: &Isometry3<f64>) -> AABB<Point3<f64>> {
+        let center = Point3::from_coordinates(m.translation.vector);
+        let radius = Vector3::from_element(self.radius());
+
+        AABB::new(center + (-radius), center + radius)
+    }
+
+    fn as_support_map(&self) -> Option<&SupportMap<Point3<f64>, Isometry3<f64>>> {
+        let _ = self.queries.fetch_add(1, Ordering::SeqCst);
+        Some(self)
+    }
+}
+
+#[test]
+fn distance_checked_matches_distance() {
+    let m1     = Isometry3::new(Vector3::new(0.5, 0.0, -0.2), Vector3::new(0.1, 0.2, 0.3));
+    let m2     = Isometry3::new(Vector3::new(4.0, 1.0, 0.0), Vector3::new(-0.3, 0.0, 0.7));
+    let ball   = Ball::new(1.0);
+    let cuboid = Cuboid::new(Vector3::new(1.0, 0.5, 2.0));
+
+    let expected = query::distance(&m1, &ball, &m2, &cuboid);
+    let found    = query::distance_checked(&m1, &ball, &m2, &cuboid);
+
+    assert_eq!(expected, found);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "must not depend on their order")]
+fn distance_checked_detects_asymmetric_shapes() {
+    let m1    = Isometry3::new(na::zero(), na::zero());
+    let m2    = Isometry3::new(Vector3::new(10.0, 0.0, 0.0), na::zero());
+    let ball  = Ball::new(1.0);
+    let mock  = GrowingBall { queries: AtomicUsize::new(0) };
+
+    let _ = query::distance_checked(&m1, &mock, &m2, &ball);
+}
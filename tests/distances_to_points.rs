@@ -0,0 +1,35 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry2, Point2, Vector2};
+use ncollide::shape::{Ball, Cuboid, Shape2};
+use ncollide::query::PointQuery;
+
+#[test]
+fn ball_distances_to_points() {
+    let ball = Ball::new(1.0f64);
+    let m    = Isometry2::new(Vector2::new(1.0, 1.0), na::zero());
+    let pts  = [ Point2::new(1.0, 1.0), Point2::new(1.5, 1.0), Point2::new(4.0, 1.0), Point2::new(1.0, -2.0) ];
+
+    let dists = ball.distances_to_points(&m, &pts);
+
+    assert_eq!(dists.len(), pts.len());
+    assert!((dists[0] + 1.0).abs() < 1.0e-9);
+    assert!((dists[1] + 0.5).abs() < 1.0e-9);
+    assert!((dists[2] - 2.0).abs() < 1.0e-9);
+    assert!((dists[3] - 2.0).abs() < 1.0e-9);
+}
+
+#[test]
+fn shape_distances_to_points() {
+    let cuboid = Cuboid::new(Vector2::new(1.0f64, 2.0));
+    let shape  = &cuboid as &Shape2<f64>;
+    let m      = Isometry2::new(na::zero(), na::zero());
+    let pts    = [ Point2::new(0.5, 0.0), Point2::new(3.0, 0.0), Point2::new(0.0, -5.0) ];
+
+    let dists = shape.distances_to_points(&m, &pts);
+
+    assert!((dists[0] + 0.5).abs() < 1.0e-9);
+    assert!((dists[1] - 2.0).abs() < 1.0e-9);
+    assert!((dists[2] - 3.0).abs() < 1.0e-9);
+}
@@ -0,0 +1,50 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Ball, Ellipsoid, SupportMap};
+use ncollide::query::{Ray, RayCast};
+
+#[test]
+fn ellipsoid_support_point_matches_sampling() {
+    let e   = Ellipsoid::new(Vector3::new(1.0f64, 2.0, 3.0));
+    let m   = Isometry3::new(na::zero(), na::zero());
+    let dir = Vector3::new(0.3, -0.5, 0.8);
+
+    let supp = e.support_point(&m, &dir);
+
+    // Brute-force: sample the ellipsoid surface using spherical coordinates.
+    let mut best = -1.0e10f64;
+    let n = 400;
+
+    for i in 0 .. n + 1 {
+        let theta = (i as f64) * std::f64::consts::PI / (n as f64);
+
+        for j in 0 .. 2 * n {
+            let phi = (j as f64) * std::f64::consts::PI / (n as f64);
+            let pt  = Vector3::new(theta.sin() * phi.cos(), theta.sin() * phi.sin(), theta.cos());
+            let pt  = Vector3::new(pt.x * 1.0, pt.y * 2.0, pt.z * 3.0);
+
+            best = best.max(na::dot(&pt, &dir));
+        }
+    }
+
+    let supp_dot = na::dot(&supp.coords, &dir);
+
+    assert!(supp_dot >= best - 1.0e-9);
+    assert!((supp_dot - best).abs() < 1.0e-3);
+}
+
+#[test]
+fn ellipsoid_ray_cast_matches_ball() {
+    let e   = Ellipsoid::new(Vector3::new(1.5f64, 1.5, 1.5));
+    let b   = Ball::new(1.5f64);
+    let m   = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    let ray = Ray::new(Point3::new(-5.0, 2.5, 3.0), Vector3::new(1.0, 0.1, 0.0));
+
+    let ie = e.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    let ib = b.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+
+    assert!((ie.toi - ib.toi).abs() < 1.0e-9);
+    assert!(na::norm(&(*ie.normal - *ib.normal)) < 1.0e-9);
+}
@@ -0,0 +1,50 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Cuboid, FeatureId, SupportMap};
+use ncollide::query::{Ray, RayCast};
+
+#[test]
+fn ray_cast_flat_cuboid_from_both_sides() {
+    let quad = Cuboid::new(Vector3::new(1.0f64, 2.0, 0.0));
+    let m    = Isometry3::new(Vector3::new(0.0, 0.0, 1.0), na::zero());
+
+    let from_above = Ray::new(Point3::new(0.5, 0.5, 5.0), -Vector3::z());
+    let from_below = Ray::new(Point3::new(0.5, -1.5, -3.0), Vector3::z());
+    let oblique    = Ray::new(Point3::new(-2.0, 0.0, 4.0), Vector3::new(1.0, 0.0, -1.0).normalize());
+    let miss       = Ray::new(Point3::new(1.5, 0.0, 5.0), -Vector3::z());
+
+    let inter = quad.toi_and_normal_with_ray(&m, &from_above, true).unwrap();
+    assert!((inter.toi - 4.0).abs() < 1.0e-7);
+    assert!(na::norm(&(*inter.normal - Vector3::z())) < 1.0e-7);
+
+    let inter = quad.toi_and_normal_with_ray(&m, &from_below, true).unwrap();
+    assert!((inter.toi - 4.0).abs() < 1.0e-7);
+    assert!(na::norm(&(*inter.normal + Vector3::z())) < 1.0e-7);
+
+    let inter = quad.toi_and_normal_with_ray(&m, &oblique, false).unwrap();
+    assert!((inter.toi - 3.0 * 2.0f64.sqrt()).abs() < 1.0e-7);
+    assert!(na::norm(&(*inter.normal - Vector3::z())) < 1.0e-7);
+
+    assert!(quad.toi_with_ray(&m, &miss, true).is_none());
+}
+
+#[test]
+fn flat_cuboid_support_and_features() {
+    let quad = Cuboid::new(Vector3::new(1.0f64, 2.0, 0.0));
+    let m    = Isometry3::identity();
+
+    let up   = quad.support_point(&m, &Vector3::new(1.0, 1.0, 1.0));
+    let down = quad.support_point(&m, &Vector3::new(1.0, 1.0, -1.0));
+
+    assert_eq!(up, down);
+    assert_eq!(up, Point3::new(1.0, 2.0, 0.0));
+
+    // The whole rectangle is a single face.
+    let pts = [ Point3::new(1.0, 2.0, 0.0), Point3::new(-1.0, 2.0, 0.0), Point3::new(-1.0, -2.0, 0.0) ];
+    match quad.support_feature_id(&m, &pts) {
+        FeatureId::Face(_) => { },
+        other              => panic!("Unexpected feature: {:?}", other)
+    }
+}
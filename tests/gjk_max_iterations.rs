@@ -0,0 +1,46 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{self, AnnotatedPoint, Cuboid};
+use ncollide::query::QuerySettings;
+use ncollide::query::algorithms::gjk::{self, GJKResult, GJKStatus};
+use ncollide::query::algorithms::simplex::Simplex;
+use ncollide::query::algorithms::johnson_simplex::JohnsonSimplex;
+
+fn gjk_status(m1: &Isometry3<f64>, c1: &Cuboid<Vector3<f64>>,
+              m2: &Isometry3<f64>, c2: &Cuboid<Vector3<f64>>,
+              settings: &QuerySettings<f64>) -> GJKStatus {
+    let mut simplex = JohnsonSimplex::<AnnotatedPoint<Point3<f64>>>::new_w_tls();
+    simplex.reset(shape::cso_support_point(m1, c1, m2, c2, Vector3::x()));
+
+    let (res, status) = gjk::closest_points_with_max_dist(m1, c1, m2, c2, 10.0, &mut simplex, settings);
+
+    match res {
+        GJKResult::Projection(_) => { },
+        _                        => panic!("The cuboids are disjoint and closer than the maximum distance.")
+    }
+
+    status
+}
+
+#[test]
+fn ill_conditioned_gjk_reports_max_iterations() {
+    // Two crossing needles with a huge aspect ratio.
+    let needle = Cuboid::new(Vector3::new(1.0e6, 1.0e-3, 1.0e-3));
+    let m1     = Isometry3::new(na::zero(), na::zero());
+    let m2     = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+
+    let settings = QuerySettings { max_iterations: 2, .. QuerySettings::default() };
+    assert_eq!(gjk_status(&m1, &needle, &m2, &needle, &settings), GJKStatus::MaxIterationsReached);
+    assert_eq!(gjk_status(&m1, &needle, &m2, &needle, &QuerySettings::default()), GJKStatus::Converged);
+}
+
+#[test]
+fn well_conditioned_gjk_converges() {
+    let cube = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let m1   = Isometry3::new(na::zero(), na::zero());
+    let m2   = Isometry3::new(Vector3::new(2.5, 0.3, -0.2), Vector3::new(0.3, 0.2, 0.1));
+
+    assert_eq!(gjk_status(&m1, &cube, &m2, &cube, &QuerySettings::default()), GJKStatus::Converged);
+}
@@ -0,0 +1,47 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+use na::{Isometry3, Point3};
+use ncollide::procedural::path::{PolylinePath, PolylinePattern, StrokePattern, NoCap};
+use ncollide::procedural;
+use ncollide::query::PointQuery;
+use ncollide::shape::{Compound, ShapeHandle3, TriMesh};
+use ncollide::transformation;
+
+#[test]
+fn bent_tube_to_compound() {
+    let control_points = [
+        Point3::new(0.0f64, 1.0, 0.0),
+        Point3::new(2.0, 4.0, 2.0),
+        Point3::new(2.0, 1.0, 4.0),
+        Point3::new(4.0, 4.0, 6.0),
+        Point3::new(2.0, 1.0, 8.0),
+        Point3::new(2.0, 4.0, 10.0),
+        Point3::new(0.0, 1.0, 12.0)
+    ];
+    let bezier      = procedural::bezier_curve(&control_points, 30);
+    let mut path    = PolylinePath::new(&bezier);
+    let pattern     = procedural::unit_circle(10);
+    let mut pattern = PolylinePattern::new(&pattern, true, NoCap::new(), NoCap::new());
+    let tube        = pattern.stroke(&mut path);
+
+    // The stroke has no normals: they are recomputed by the decomposition.
+    assert!(!tube.has_normals());
+
+    let indices = tube.indices.unwrap_unified().into_iter().map(|t| na::convert(t)).collect();
+    let mesh    = TriMesh::new(Arc::new(tube.coords), Arc::new(indices), None, None);
+    let hulls   = transformation::hacd_convex_hulls(&mesh, 0.03, 0);
+
+    // The decomposition follows the bends of the tube.
+    assert!(hulls.len() > 1);
+
+    let id       = Isometry3::identity();
+    let parts    = hulls.into_iter().map(|h| (id, ShapeHandle3::new(h))).collect();
+    let compound = Compound::new(parts);
+
+    // Every vertex of the mesh lies on one of the convex parts.
+    for pt in mesh.vertices().iter() {
+        assert!(compound.distance_to_point(&id, pt, true) < 1.0e-6);
+    }
+}
@@ -0,0 +1,49 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{DMatrix, Isometry3, Point3, Vector3};
+use ncollide::shape::{Ball, FeatureId, HeightField};
+use ncollide::query::{self, ClosestPoints};
+
+fn slope() -> HeightField<f64> {
+    // The surface is the plane `y = x / 2`.
+    let heights = DMatrix::from_fn(5, 5, |_, j| j as f64 / 2.0 - 1.0);
+    HeightField::new(heights, Vector3::new(4.0, 1.0, 4.0))
+}
+
+#[test]
+fn heightfield_ball_closest_points() {
+    let hf   = slope();
+    let ball = Ball::new(0.5f64);
+    let m1   = Isometry3::identity();
+    let m2   = Isometry3::new(Vector3::new(0.0, 2.0, 0.5), na::zero());
+
+    let dist   = 4.0 / 5.0f64.sqrt() - 0.5;
+    let normal = Vector3::new(-1.0, 2.0, 0.0) / 5.0f64.sqrt();
+
+    // GJK on a ball is only accurate up to its tolerance. The closest point lies inside of the
+    // second triangle of the cell at the row 2 and column 2.
+    match query::closest_points(&m1, &hf, &m2, &ball, 2.0) {
+        ClosestPoints::WithinMargin(p1, p2, Some((f1, f2))) => {
+            assert!(na::distance(&p1, &Point3::new(0.8, 0.4, 0.5)) < 1.0e-4);
+            assert!(na::distance(&p2, &(Point3::new(0.0, 2.0, 0.5) + normal * -0.5)) < 1.0e-4);
+            assert_eq!(f1, FeatureId::Face(2 * (2 * 4 + 2) + 1));
+            assert_eq!(f2, FeatureId::Face(0));
+        },
+        res => panic!("Unexpected closest points: {:?}", res)
+    }
+
+    // The same points are returned when the heightfield is the second shape.
+    match query::closest_points(&m2, &ball, &m1, &hf, 2.0) {
+        ClosestPoints::WithinMargin(p1, p2, Some((_, f2))) => {
+            assert!((na::distance(&p1, &p2) - dist).abs() < 1.0e-4);
+            assert_eq!(f2, FeatureId::Face(2 * (2 * 4 + 2) + 1));
+        },
+        res => panic!("Unexpected closest points: {:?}", res)
+    }
+
+    assert_eq!(query::closest_points(&m1, &hf, &m2, &ball, 1.0), ClosestPoints::Disjoint);
+
+    let m2 = Isometry3::new(Vector3::new(0.0, 0.2, 0.5), na::zero());
+    assert_eq!(query::closest_points(&m1, &hf, &m2, &ball, 1.0), ClosestPoints::Intersecting);
+}
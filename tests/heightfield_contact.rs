@@ -0,0 +1,58 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{DMatrix, Isometry3, Vector3};
+use ncollide::shape::{Ball, Cuboid, HeightField};
+use ncollide::query;
+use ncollide::narrow_phase::{ContactDispatcher, DefaultContactDispatcher};
+
+fn flat_heightfield() -> HeightField<f64> {
+    HeightField::new(DMatrix::from_element(11, 11, 0.0), Vector3::new(10.0, 1.0, 10.0))
+}
+
+#[test]
+fn ball_resting_on_flat_heightfield() {
+    let hf   = flat_heightfield();
+    let ball = Ball::new(0.5f64);
+    let m1   = Isometry3::new(na::zero(), na::zero());
+    let m2   = Isometry3::new(Vector3::new(0.3, 0.4, -1.2), na::zero());
+
+    let c = query::contact(&m1, &hf, &m2, &ball, 0.0).expect("Missing heightfield contact.");
+    assert!(na::norm(&(*c.normal - Vector3::y())) < 1.0e-6);
+    assert!((c.depth - 0.1).abs() < 1.0e-6);
+
+    // Swapping the shapes flips the normal.
+    let c = query::contact(&m2, &ball, &m1, &hf, 0.0).expect("Missing heightfield contact.");
+    assert!(na::norm(&(*c.normal + Vector3::y())) < 1.0e-6);
+
+    let far = Isometry3::new(Vector3::new(0.3, 2.0, -1.2), na::zero());
+    assert!(query::contact(&m1, &hf, &far, &ball, 0.1).is_none());
+}
+
+#[test]
+fn cuboid_outside_of_heightfield_grid() {
+    let hf     = flat_heightfield();
+    let cuboid = Cuboid::new(Vector3::new(0.5f64, 0.5, 0.5));
+    let m1     = Isometry3::new(na::zero(), na::zero());
+    let m2     = Isometry3::new(Vector3::new(6.0, 0.0, 0.0), na::zero());
+
+    assert!(query::contact(&m1, &hf, &m2, &cuboid, 0.0).is_none());
+}
+
+#[test]
+fn ball_heightfield_contact_generator() {
+    let hf   = flat_heightfield();
+    let ball = Ball::new(0.5f64);
+    let m1   = Isometry3::new(na::zero(), na::zero());
+    let m2   = Isometry3::new(Vector3::new(0.3, 0.4, -1.2), na::zero());
+
+    let dispatcher = DefaultContactDispatcher::new();
+    let mut generator = dispatcher.get_contact_algorithm(&hf, &ball).expect("Missing contact algorithm.");
+    let mut contacts  = Vec::new();
+
+    assert!(generator.update(&dispatcher, &m1, &hf, &m2, &ball, 0.0));
+    generator.contacts(&mut contacts);
+
+    assert!(!contacts.is_empty());
+    assert!(contacts.iter().all(|c| na::norm(&(*c.normal - Vector3::y())) < 1.0e-6));
+}
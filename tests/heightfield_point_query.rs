@@ -0,0 +1,48 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{DMatrix, Isometry3, Point3, Vector3};
+use ncollide::shape::{HeightField, Shape3};
+use ncollide::query::{PointQuery, Ray};
+
+fn slope() -> HeightField<f64> {
+    // The height increases linearly from `-1` to `1` along `x`.
+    let heights = DMatrix::from_fn(5, 5, |_, j| j as f64 / 2.0 - 1.0);
+    HeightField::new(heights, Vector3::new(4.0, 1.0, 4.0))
+}
+
+#[test]
+fn project_point_on_heightfield() {
+    let hf = slope();
+    let m  = Isometry3::new(Vector3::new(0.0, 1.0, 0.0), na::zero());
+
+    // Above the slope.
+    let pt       = Point3::new(0.0, 3.0, 0.5);
+    let proj     = hf.project_point(&m, &pt, true);
+    let expected = Point3::new(0.8, 1.4, 0.5);
+
+    assert!(!proj.is_inside);
+    assert!(na::distance(&proj.point, &expected) < 1.0e-7);
+    assert!((hf.distance_to_point(&m, &pt, true) - 3.2f64.sqrt()).abs() < 1.0e-7);
+
+    // On the slope.
+    assert!(hf.project_point(&m, &Point3::new(1.0, 1.5, -1.0), false).is_inside);
+
+    // Beside the grid, the projection lies on its border.
+    let proj = hf.project_point(&m, &Point3::new(5.0, 2.0, 0.0), true);
+    assert!(na::distance(&proj.point, &Point3::new(2.0, 2.0, 0.0)) < 1.0e-7);
+}
+
+#[test]
+fn heightfield_queries_through_shape() {
+    let hf = slope();
+    let m  = Isometry3::identity();
+    let s  = &hf as &Shape3<f64>;
+
+    let proj = s.as_point_query().unwrap().project_point(&m, &Point3::new(0.5, 2.0, 0.0), true);
+    assert!(na::distance(&proj.point, &Point3::new(1.2, 0.6, 0.0)) < 1.0e-7);
+
+    let ray = Ray::new(Point3::new(1.0, 5.0, 1.0), -Vector3::y());
+    let toi = s.as_ray_cast().unwrap().toi_with_ray(&m, &ray, true).unwrap();
+    assert!((toi - 4.5).abs() < 1.0e-7);
+}
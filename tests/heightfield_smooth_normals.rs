@@ -0,0 +1,77 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::f64;
+use na::{DMatrix, Isometry3, Point3, Vector3};
+use ncollide::shape::{Ball, HeightField};
+use ncollide::query::{self, Ray, RayCast};
+
+// A ridge along the `z` axis: the left cells have the normal `(-1, 1, 0) / sqrt(2)` and the
+// right cells `(1, 1, 0) / sqrt(2)`. Away from the grid boundary, the smooth normals of the ridge
+// nodes are vertical.
+fn ridge() -> HeightField<f64> {
+    let heights = DMatrix::from_fn(5, 3, |_, j| if j == 1 { 1.0 } else { 0.0 });
+
+    HeightField::new(heights, Vector3::new(2.0, 1.0, 2.0))
+}
+
+#[test]
+fn ray_normal_near_a_ridge() {
+    let mut hf = ridge();
+    let m      = Isometry3::new(na::zero(), na::zero());
+    let ray    = Ray::new(Point3::new(-0.05, 5.0, 0.3), -Vector3::y());
+    let flat   = hf.toi_and_normal_with_ray(&m, &ray, true).expect("Missing heightfield ray hit.");
+    let left   = Vector3::new(-1.0, 1.0, 0.0) / f64::consts::SQRT_2;
+
+    assert!((flat.toi - 4.05).abs() < 1.0e-9);
+    assert!(na::norm(&(*flat.normal - left)) < 1.0e-9);
+
+    hf.set_smooth_normals(true);
+    let smooth = hf.toi_and_normal_with_ray(&m, &ray, true).expect("Missing heightfield ray hit.");
+
+    assert!((smooth.toi - 4.05).abs() < 1.0e-9);
+    assert!((na::norm(&*smooth.normal) - 1.0).abs() < 1.0e-9);
+    // Between the normals of both sides of the ridge, close to the vertical.
+    assert!(smooth.normal.x < 0.0 && smooth.normal.x > -0.1);
+    assert!(smooth.normal.y > left.y);
+
+    // Seen from below, the normal is flipped.
+    let ray   = Ray::new(Point3::new(-0.05, -5.0, 0.3), Vector3::y());
+    let below = hf.toi_and_normal_with_ray(&m, &ray, true).expect("Missing heightfield ray hit.");
+    assert!(na::norm(&(*below.normal + *smooth.normal)) < 1.0e-9);
+}
+
+#[test]
+fn contact_normal_near_a_ridge() {
+    let mut hf = ridge();
+    let ball   = Ball::new(0.5);
+    let m1     = Isometry3::new(na::zero(), na::zero());
+    let m2     = Isometry3::new(Vector3::new(-0.4, 1.2, 0.3), na::zero());
+    let left   = Vector3::new(-1.0, 1.0, 0.0) / f64::consts::SQRT_2;
+
+    let flat = query::contact(&m1, &hf, &m2, &ball, 0.0).expect("Missing heightfield contact.");
+    assert!(na::norm(&(*flat.normal - left)) < 1.0e-6);
+
+    hf.set_smooth_normals(true);
+    let smooth = query::contact(&m1, &hf, &m2, &ball, 0.0).expect("Missing heightfield contact.");
+
+    assert!((smooth.depth - flat.depth).abs() < 1.0e-9);
+    assert!(smooth.normal.x < 0.0 && smooth.normal.x > left.x);
+    assert!(smooth.normal.y > left.y);
+}
+
+#[test]
+fn smooth_contact_points_follow_the_normal() {
+    let mut hf = ridge();
+    let ball   = Ball::new(0.5);
+    let m1     = Isometry3::new(na::zero(), na::zero());
+    let m2     = Isometry3::new(Vector3::new(-0.4, 1.2, 0.3), na::zero());
+
+    hf.set_smooth_normals(true);
+
+    let c = query::contact(&m1, &hf, &m2, &ball, 0.0).expect("Missing heightfield contact.");
+    assert!(na::norm(&(c.world2 - (c.world1 - *c.normal * c.depth))) < 1.0e-9);
+
+    let c = query::contact(&m2, &ball, &m1, &hf, 0.0).expect("Missing heightfield contact.");
+    assert!(na::norm(&(c.world2 - (c.world1 - *c.normal * c.depth))) < 1.0e-9);
+}
@@ -0,0 +1,36 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{DMatrix, Point3, Vector3};
+use ncollide::shape::HeightField;
+use ncollide::procedural::IndexBuffer;
+use ncollide::transformation::ToTriMesh;
+
+#[test]
+fn heightfield_to_trimesh_shares_grid_nodes() {
+    // 2×2 cells.
+    let heights     = DMatrix::from_row_slice(3, 3, &[ 0.0, 1.0, 0.0,
+                                                       1.0, 2.0, 1.0,
+                                                       0.0, 1.0, 0.0 ]);
+    let heightfield = HeightField::new(heights, Vector3::new(4.0, 0.5, 2.0));
+    let mesh        = heightfield.to_trimesh(());
+
+    assert_eq!(mesh.coords.len(), 9);
+    assert_eq!(mesh.num_triangles(), 8);
+    assert_eq!(mesh.coords[4], Point3::new(0.0, 1.0, 0.0));
+    assert_eq!(mesh.coords[8], Point3::new(2.0, 0.0, 1.0));
+
+    match mesh.indices {
+        IndexBuffer::Unified(ref indices) => {
+            // Every triangle matches the one of the heightfield with the same identifier.
+            for (id, idx) in indices.iter().enumerate() {
+                let triangle = heightfield.triangle_at::<Point3<f64>>(id);
+
+                assert_eq!(mesh.coords[idx.x as usize], *triangle.a());
+                assert_eq!(mesh.coords[idx.y as usize], *triangle.b());
+                assert_eq!(mesh.coords[idx.z as usize], *triangle.c());
+            }
+        },
+        IndexBuffer::Split(_) => panic!("The heightfield mesh vertices must be shared.")
+    }
+}
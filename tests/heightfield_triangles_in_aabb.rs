@@ -0,0 +1,40 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{DMatrix, Isometry3, Point3, Vector3};
+use ncollide::shape::HeightField;
+use ncollide::bounding_volume::AABB;
+
+// A flat 4x4 grid of unit cells centered at `(10, 0, 0)`.
+fn heightfield() -> (Isometry3<f64>, HeightField<f64>) {
+    let heights = DMatrix::from_element(5, 5, 0.0);
+
+    (Isometry3::new(Vector3::new(10.0, 0.0, 0.0), na::zero()),
+     HeightField::new(heights, Vector3::new(4.0, 1.0, 4.0)))
+}
+
+#[test]
+fn aabb_covering_one_cell() {
+    let (m, hf) = heightfield();
+    let aabb    = AABB::new(Point3::new(9.2, -1.0, 0.2), Point3::new(9.8, 1.0, 0.8));
+    let tris: Vec<_> = hf.triangles_in_aabb(&m, &aabb).collect();
+
+    assert_eq!(tris.len(), 2);
+
+    // The cell spanning `[-1, 0] x [0, 1]` in local space.
+    for t in tris.iter() {
+        for pt in [ t.a(), t.b(), t.c() ].iter() {
+            assert!(pt.x >= -1.0 && pt.x <= 0.0 && pt.z >= 0.0 && pt.z <= 1.0);
+        }
+    }
+}
+
+#[test]
+fn aabb_clamped_to_the_grid() {
+    let (m, hf) = heightfield();
+    let large   = AABB::new(Point3::new(-100.0, -1.0, -100.0), Point3::new(100.0, 1.0, 100.0));
+    let outside = AABB::new(Point3::new(0.0, -1.0, 0.0), Point3::new(1.0, 1.0, 1.0));
+
+    assert_eq!(hf.triangles_in_aabb(&m, &large).count(), 32);
+    assert_eq!(hf.triangles_in_aabb(&m, &outside).count(), 0);
+}
@@ -0,0 +1,77 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+use na::{Isometry3, Point3, Unit, Vector3};
+use ncollide::shape::{Ball, Shape};
+use ncollide::query::Contact;
+use ncollide::narrow_phase::{ContactDispatcher, ContactGenerator, DefaultContactDispatcher,
+                             IncrementalContactManifoldGenerator};
+
+// A contact generator returning the contact set from outside before each update.
+struct ScriptedContact(Rc<RefCell<Option<Contact<Point3<f64>>>>>);
+
+impl ContactGenerator<Point3<f64>, Isometry3<f64>> for ScriptedContact {
+    fn update(&mut self, _: &ContactDispatcher<Point3<f64>, Isometry3<f64>>,
+              _: &Isometry3<f64>, _: &Shape<Point3<f64>, Isometry3<f64>>,
+              _: &Isometry3<f64>, _: &Shape<Point3<f64>, Isometry3<f64>>,
+              _: f64)
+              -> bool {
+        true
+    }
+
+    fn num_contacts(&self) -> usize {
+        if self.0.borrow().is_some() { 1 } else { 0 }
+    }
+
+    fn contacts(&self, out: &mut Vec<Contact<Point3<f64>>>) {
+        out.extend(self.0.borrow().iter().cloned())
+    }
+}
+
+fn contact_at(x: f64, z: f64) -> Contact<Point3<f64>> {
+    let pt = Point3::new(x, 0.0, z);
+
+    Contact::new(pt, pt, Unit::new_normalize(Vector3::y()), 0.0)
+}
+
+#[test]
+fn resting_manifold_keeps_its_corners() {
+    let dispatcher = DefaultContactDispatcher::new();
+    let ball       = Ball::new(1.0f64);
+    let m          = Isometry3::new(na::zero(), na::zero());
+    let next       = Rc::new(RefCell::new(None));
+    let mut gen    = IncrementalContactManifoldGenerator::new(ScriptedContact(next.clone()));
+
+    let corners = [ (-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0) ];
+
+    for &(x, z) in corners.iter() {
+        *next.borrow_mut() = Some(contact_at(x, z));
+        assert!(gen.update(&dispatcher, &m, &ball, &m, &ball, 0.1));
+    }
+
+    // The manifold is full. A new contact at its center does not spread it more: replacing any
+    // corner by it, as done previously, would shrink the support polygon of a resting body.
+    *next.borrow_mut() = Some(contact_at(0.0, 0.0));
+    assert!(gen.update(&dispatcher, &m, &ball, &m, &ball, 0.1));
+
+    let mut contacts = Vec::new();
+    gen.contacts(&mut contacts);
+
+    assert_eq!(contacts.len(), 4);
+
+    for &(x, z) in corners.iter() {
+        assert!(contacts.iter().any(|c| c.world1 == Point3::new(x, 0.0, z)));
+    }
+
+    // A contact spreading the manifold more still replaces one of its points.
+    *next.borrow_mut() = Some(contact_at(3.0, 3.0));
+    assert!(gen.update(&dispatcher, &m, &ball, &m, &ball, 0.1));
+
+    contacts.clear();
+    gen.contacts(&mut contacts);
+
+    assert_eq!(contacts.len(), 4);
+    assert!(contacts.iter().any(|c| c.world1 == Point3::new(3.0, 0.0, 3.0)));
+}
@@ -0,0 +1,151 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::f64::consts::PI;
+use std::sync::Arc;
+
+use na::{Isometry2, Isometry3, Point2, Point3, Vector2, Vector3};
+use ncollide::shape::{Ball, ConvexHull, ConvexPolygon, Cuboid, Polyline, Scaled, Triangle, TriMesh};
+use ncollide::query;
+
+#[test]
+fn ball_intersection_volume() {
+    let ball = Ball::new(1.0f64);
+    let m1   = Isometry3::identity();
+
+    // Two spherical caps of height 0.5.
+    let m2       = Isometry3::new(Vector3::new(1.0, 0.0, 0.0), na::zero());
+    let expected = 2.0 * PI * 0.5 * 0.5 * (3.0 - 0.5) / 3.0;
+    assert!((query::intersection_volume(&m1, &ball, &m2, &ball).unwrap() - expected).abs() < 1.0e-9);
+
+    let m2 = Isometry3::new(Vector3::new(3.0, 0.0, 0.0), na::zero());
+    assert_eq!(query::intersection_volume(&m1, &ball, &m2, &ball).unwrap(), 0.0);
+
+    let small = Ball::new(0.5f64);
+    let m2    = Isometry3::new(Vector3::new(0.2, 0.0, 0.0), na::zero());
+    assert!((query::intersection_volume(&m1, &ball, &m2, &small).unwrap() - PI / 6.0).abs() < 1.0e-9);
+
+    // Two circles at a distance of their radius.
+    let ball     = Ball::new(1.0f64);
+    let m2       = Isometry2::new(Vector2::new(1.0, 0.0), na::zero());
+    let expected = 2.0 * PI / 3.0 - 3.0f64.sqrt() / 2.0;
+    assert!((query::intersection_volume(&Isometry2::identity(), &ball, &m2, &ball).unwrap() - expected).abs() < 1.0e-9);
+}
+
+#[test]
+fn polytope_intersection_volume() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m1     = Isometry3::identity();
+    let m2     = Isometry3::new(Vector3::new(0.5, 1.0, 1.5), na::zero());
+
+    assert!((query::intersection_volume(&m1, &cuboid, &m2, &cuboid).unwrap() - 1.5 * 1.0 * 0.5).abs() < 1.0e-9);
+
+    // A cube rotated by 45 degrees around `z` on the corner of another one.
+    let m2     = Isometry3::new(Vector3::new(1.0, 1.0, 0.0), Vector3::z() * (PI / 4.0));
+    let volume = query::intersection_volume(&m1, &cuboid, &m2, &cuboid).unwrap();
+    let hdiag  = 2.0f64.sqrt();
+    assert!((volume - hdiag * hdiag / 2.0 * 2.0).abs() < 1.0e-9);
+
+    // A tetrahedron with a corner at the center of the cube: only the part of the unit cube
+    // below `x + y + z = 2` is inside of it.
+    let tetra = ConvexHull::new(vec![ Point3::new(0.0, 0.0, 0.0), Point3::new(2.0, 0.0, 0.0),
+                                      Point3::new(0.0, 2.0, 0.0), Point3::new(0.0, 0.0, 2.0) ]);
+    assert!((query::intersection_volume(&m1, &cuboid, &m1, &tetra).unwrap() - 5.0 / 6.0).abs() < 1.0e-9);
+
+    // The tetrahedron is inside of the cube.
+    let m2 = Isometry3::new(Vector3::new(-1.0, -1.0, -1.0), na::zero());
+    assert!((query::intersection_volume(&m1, &cuboid, &m2, &tetra).unwrap() - 8.0 / 6.0).abs() < 1.0e-9);
+}
+
+#[test]
+fn polygon_intersection_area() {
+    let square   = Cuboid::new(Vector2::new(1.0f64, 1.0));
+    let triangle = Triangle::new(Point2::new(0.0, 0.0), Point2::new(0.0, 4.0), Point2::new(4.0, 0.0));
+    let polygon  = ConvexPolygon::from_ccw_points(&[ Point2::new(0.0, 0.0), Point2::new(4.0, 0.0), Point2::new(0.0, 4.0) ]);
+    let m        = Isometry2::identity();
+
+    assert!((query::intersection_volume(&m, &square, &m, &triangle).unwrap() - 1.0).abs() < 1.0e-9);
+    assert!((query::intersection_volume(&m, &polygon, &m, &square).unwrap() - 1.0).abs() < 1.0e-9);
+
+    // The square is inside of the translated triangle.
+    let m2 = Isometry2::new(Vector2::new(-1.0, -1.0), na::zero());
+    assert!((query::intersection_volume(&m, &square, &m2, &triangle).unwrap() - 4.0).abs() < 1.0e-9);
+
+    // The triangle only touches the square.
+    let m2 = Isometry2::new(Vector2::new(1.0, 1.0), na::zero());
+    assert!(query::intersection_volume(&m, &square, &m2, &triangle).unwrap().abs() < 1.0e-9);
+}
+
+#[test]
+fn sampled_intersection_volume() {
+    // Half of the ball is inside of the cuboid.
+    let ball   = Ball::new(1.0f64);
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m1     = Isometry3::identity();
+    let m2     = Isometry3::new(Vector3::new(1.0, 0.0, 0.0), na::zero());
+
+    let expected = 2.0 / 3.0 * PI;
+    let volume   = query::intersection_volume(&m1, &ball, &m2, &cuboid).unwrap();
+
+    assert!((volume - expected).abs() < 0.01 * expected);
+}
+
+#[test]
+fn sampled_intersection_volume_of_closed_meshes() {
+    // The boundary of the cube [-1, 1]^3, with counterclockwise triangles seen from outside.
+    let mut vertices = Vec::new();
+
+    for i in 0 .. 8 {
+        vertices.push(Point3::new(if i & 1 != 0 { 1.0f64 } else { -1.0 },
+                                  if i & 2 != 0 { 1.0 } else { -1.0 },
+                                  if i & 4 != 0 { 1.0 } else { -1.0 }));
+    }
+
+    let quads = [ [ 0, 4, 6, 2 ], [ 1, 3, 7, 5 ], [ 0, 1, 5, 4 ], [ 2, 6, 7, 3 ], [ 0, 2, 3, 1 ], [ 4, 5, 7, 6 ] ];
+    let mut indices = Vec::new();
+
+    for q in quads.iter() {
+        indices.push(Point3::new(q[0], q[1], q[2]));
+        indices.push(Point3::new(q[0], q[2], q[3]));
+    }
+
+    let mesh   = TriMesh::new(Arc::new(vertices), Arc::new(indices), None, None);
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m1     = Isometry3::identity();
+    let m2     = Isometry3::new(Vector3::new(1.0, 0.0, 0.0), na::zero());
+
+    assert!((mesh.winding_number(&Point3::origin()) - 1.0).abs() < 1.0e-9);
+    assert!(mesh.winding_number(&Point3::new(3.0, 0.5, 0.0)).abs() < 1.0e-9);
+    assert!((query::intersection_volume(&m1, &mesh, &m2, &cuboid).unwrap() - 4.0).abs() < 0.05);
+
+    // A clockwise square loop.
+    let square = Polyline::new(Arc::new(vec![ Point2::new(-1.0f64, -1.0), Point2::new(-1.0, 1.0),
+                                              Point2::new(1.0, 1.0), Point2::new(1.0, -1.0) ]),
+                               Arc::new(vec![ Point2::new(0, 1), Point2::new(1, 2), Point2::new(2, 3), Point2::new(3, 0) ]),
+                               None, None);
+    let ball   = Ball::new(1.0f64);
+    let m2     = Isometry2::new(Vector2::new(1.0, 0.0), na::zero());
+
+    assert!((square.winding_number(&Point2::origin()) + 1.0).abs() < 1.0e-9);
+    assert!((query::intersection_volume(&Isometry2::identity(), &square, &m2, &ball).unwrap() - PI / 2.0).abs() < 0.01);
+}
+
+#[test]
+fn intersection_volume_without_point_queries() {
+    let scaled = Scaled::new_uniform(Ball::new(1.0f64), 2.0);
+    let ball   = Ball::new(1.0f64);
+    let m      = Isometry3::identity();
+
+    assert!(query::intersection_volume(&m, &scaled, &m, &ball).is_none());
+}
+
+#[test]
+fn nearly_nested_circles_intersection_area() {
+    // The cosine of the angle of the big circular segment is rounded to a value above 1.
+    let big   = Ball::new(7.3f64);
+    let small = Ball::new(0.7f64);
+    let m2    = Isometry2::new(Vector2::new(6.6000000000000005, 0.0), na::zero());
+    let area  = query::intersection_volume(&Isometry2::identity(), &big, &m2, &small).unwrap();
+
+    assert!((area - PI * 0.49).abs() < 1.0e-6);
+}
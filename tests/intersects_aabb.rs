@@ -0,0 +1,37 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::f64::consts::FRAC_PI_4;
+use na::{Isometry2, Point2, Vector2};
+use ncollide::bounding_volume::AABB;
+use ncollide::shape::{self, Ball, Cuboid};
+
+#[test]
+fn ball_against_query_aabb() {
+    let ball = Ball::new(1.0f64);
+    let aabb = AABB::new(Point2::new(0.0, 0.0), Point2::new(4.0, 4.0));
+
+    let straddling = Isometry2::new(Vector2::new(-0.5, 2.0), na::zero());
+    let inside     = Isometry2::new(Vector2::new(2.0, 2.0), na::zero());
+    let outside    = Isometry2::new(Vector2::new(6.0, 2.0), na::zero());
+    // The AABB of the ball overlaps the query AABB but the ball does not.
+    let near_corner = Isometry2::new(Vector2::new(-0.8, -0.8), na::zero());
+
+    assert!(shape::intersects_aabb(&straddling, &ball, &aabb));
+    assert!(shape::intersects_aabb(&inside, &ball, &aabb));
+    assert!(!shape::intersects_aabb(&outside, &ball, &aabb));
+    assert!(!shape::intersects_aabb(&near_corner, &ball, &aabb));
+}
+
+#[test]
+fn rotated_cuboid_against_query_aabb() {
+    let cuboid = Cuboid::new(Vector2::new(1.0f64, 1.0));
+    let aabb   = AABB::new(Point2::new(0.0, 0.0), Point2::new(4.0, 4.0));
+
+    // The AABB of the rotated cuboid overlaps the query AABB corner but the cuboid does not.
+    let near_corner = Isometry2::new(Vector2::new(-1.1, -1.1), FRAC_PI_4);
+    let touching    = Isometry2::new(Vector2::new(-0.6, -0.6), FRAC_PI_4);
+
+    assert!(!shape::intersects_aabb(&near_corner, &cuboid, &aabb));
+    assert!(shape::intersects_aabb(&touching, &cuboid, &aabb));
+}
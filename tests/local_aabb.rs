@@ -0,0 +1,37 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point2, Point3, Vector2, Vector3};
+use ncollide::shape::{Ball, Capsule, Cone, Cuboid, Cylinder, Ellipsoid};
+use ncollide::bounding_volume::{self, AABB, HasLocalAABB};
+
+fn half_extents<G: HasLocalAABB<Point3<f64>>>(shape: &G) -> Vector3<f64> {
+    let aabb = shape.local_aabb();
+
+    assert_eq!(aabb.center(), Point3::origin());
+    aabb.half_extents()
+}
+
+#[test]
+fn analytic_shapes_local_aabb() {
+    assert_eq!(half_extents(&Ball::new(2.0)), Vector3::new(2.0, 2.0, 2.0));
+    assert_eq!(half_extents(&Cuboid::new(Vector3::new(1.0, 2.0, 3.0))), Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(half_extents(&Cone::new(2.0, 0.5)), Vector3::new(0.5, 2.0, 0.5));
+    assert_eq!(half_extents(&Cylinder::new(2.0, 0.5)), Vector3::new(0.5, 2.0, 0.5));
+    assert_eq!(half_extents(&Capsule::new(2.0, 0.5)), Vector3::new(0.5, 2.5, 0.5));
+    assert_eq!(half_extents(&Ellipsoid::new(Vector3::new(1.0, 2.0, 3.0))), Vector3::new(1.0, 2.0, 3.0));
+
+    let cone: AABB<Point2<f64>> = Cone::new(2.0, 0.5).local_aabb();
+    assert_eq!(cone.half_extents(), Vector2::new(0.5, 2.0));
+}
+
+#[test]
+fn local_aabb_matches_aabb_with_identity() {
+    let cone = Cone::new(2.0f64, 0.5);
+    let id   = Isometry3::identity();
+    let aabb = bounding_volume::aabb(&cone, &id);
+    let local: AABB<Point3<f64>> = cone.local_aabb();
+
+    assert!(na::distance(aabb.mins(), local.mins()) < 1.0e-9);
+    assert!(na::distance(aabb.maxs(), local.maxs()) < 1.0e-9);
+}
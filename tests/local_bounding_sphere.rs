@@ -0,0 +1,20 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::Cuboid;
+use ncollide::bounding_volume::{self, BoundingSphere, HasLocalBoundingSphere};
+
+#[test]
+fn cuboid_local_bounding_sphere() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 2.0, 3.0));
+    let local: BoundingSphere<Point3<f64>> = cuboid.local_bounding_sphere();
+
+    assert_eq!(*local.center(), Point3::origin());
+    assert!((local.radius() - (1.0f64 + 4.0 + 9.0).sqrt()).abs() < 1.0e-12);
+
+    let m     = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    let world = bounding_volume::bounding_sphere(&cuboid, &m);
+
+    assert_eq!(world, local.transform_by(&m));
+}
@@ -0,0 +1,26 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Vector3};
+use ncollide::shape::{Cuboid, Cylinder};
+use ncollide::query;
+
+#[test]
+fn local_contact_points_transformed_back_to_world() {
+    let cuboid   = Cuboid::new(Vector3::new(1.0f64, 0.5, 2.0));
+    let cylinder = Cylinder::new(0.5f64, 0.75);
+    let m1       = Isometry3::new(Vector3::new(0.1, -0.2, 0.3), Vector3::new(0.3, 0.2, -0.1));
+    let m2       = Isometry3::new(Vector3::new(0.5, 1.0, 0.2), Vector3::new(-0.5, 0.1, 0.7));
+
+    let world = query::contact(&m1, &cuboid, &m2, &cylinder, 0.0).unwrap();
+    let local = query::contact_local(&m1, &cuboid, &m2, &cylinder, 0.0).unwrap();
+
+    assert!(na::distance(&(m1 * local.local1), &world.world1) < 1.0e-7);
+    assert!(na::distance(&(m2 * local.local2), &world.world2) < 1.0e-7);
+    assert_eq!(local.normal, world.normal);
+    assert_eq!(local.depth, world.depth);
+
+    let back = local.to_world(&m1, &m2);
+    assert!(na::distance(&back.world1, &world.world1) < 1.0e-7);
+    assert!(na::distance(&back.world2, &world.world2) < 1.0e-7);
+}
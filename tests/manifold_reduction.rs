@@ -0,0 +1,77 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::f64::consts::FRAC_PI_4;
+use na::{Isometry3, Point3, Vector3, Unit};
+use ncollide::shape::ConvexHull;
+use ncollide::query::{Contact, QuerySettings};
+use ncollide::query::contacts_internal;
+
+fn box_hull(half: f64) -> ConvexHull<Point3<f64>> {
+    let mut pts = Vec::new();
+
+    for i in 0 .. 8 {
+        pts.push(Point3::new(if i & 1 == 0 { -half } else { half },
+                             if i & 2 == 0 { -half } else { half },
+                             if i & 4 == 0 { -half } else { half }));
+    }
+
+    ConvexHull::new(pts)
+}
+
+#[test]
+fn octagonal_box_box_overlap_reduced_to_four_points() {
+    // The top face of the first box and the bottom face of the second one, rotated by 45 degrees,
+    // overlap on an octagon.
+    let m1       = Isometry3::new(na::zero(), na::zero());
+    let m2       = Isometry3::new(Vector3::new(0.0, 1.9, 0.0), Vector3::y() * FRAC_PI_4);
+    let settings = QuerySettings { max_manifold_points: 8, .. QuerySettings::default() };
+    let mut all  = Vec::new();
+
+    contacts_internal::convex_hull_against_convex_hull_with_settings(
+        &m1, &box_hull(1.0), &m2, &box_hull(1.0), 0.0, &settings, &mut all);
+    assert_eq!(all.len(), 8);
+
+    let mut reduced = Vec::new();
+    contacts_internal::convex_hull_against_convex_hull(&m1, &box_hull(1.0), &m2, &box_hull(1.0), 0.0, &mut reduced);
+    assert_eq!(reduced.len(), 4);
+
+    // Well-spread points: every pair of the kept points is farther apart than an octagon side.
+    let side = na::distance(&all[0].world1, &all[1].world1);
+
+    for (i, a) in reduced.iter().enumerate() {
+        assert!(all.contains(a));
+
+        for b in reduced[i + 1 ..].iter() {
+            assert!(na::distance(&a.world1, &b.world1) > side * 1.5);
+        }
+    }
+}
+
+#[test]
+fn reduced_manifold_keeps_the_deepest_point() {
+    let normal       = Unit::new_normalize(Vector3::y());
+    let mut contacts = Vec::new();
+
+    // Eight points on a circle, the deepest one is not the first.
+    for i in 0 .. 8 {
+        let angle = i as f64 * FRAC_PI_4;
+        let pt    = Point3::new(angle.cos(), 0.0, angle.sin());
+        let depth = if i == 5 { 0.3 } else { 0.1 };
+
+        contacts.push(Contact::new(pt, pt + Vector3::y() * -depth, normal, depth));
+    }
+
+    let deepest = contacts[5].clone();
+    contacts_internal::reduce_contact_manifold(&mut contacts, 4);
+
+    assert_eq!(contacts.len(), 4);
+    assert!(contacts.contains(&deepest));
+
+    // The kept points form the largest square inscribed in the circle.
+    for (i, a) in contacts.iter().enumerate() {
+        for b in contacts[i + 1 ..].iter() {
+            assert!(na::distance(&a.world1, &b.world1) > 1.4);
+        }
+    }
+}
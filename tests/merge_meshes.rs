@@ -0,0 +1,51 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::TriMesh;
+use ncollide::query::{Ray, RayCast};
+use ncollide::transformation;
+
+// A unit square on the plane `y = 0`, centered at the origin.
+fn quad() -> TriMesh<Point3<f64>> {
+    let vertices = vec![Point3::new(-0.5, 0.0, -0.5), Point3::new(0.5, 0.0, -0.5),
+                        Point3::new(0.5, 0.0, 0.5),   Point3::new(-0.5, 0.0, 0.5)];
+    let indices  = vec![Point3::new(0usize, 1, 2), Point3::new(0, 2, 3)];
+
+    TriMesh::new(Arc::new(vertices), Arc::new(indices), None, None)
+}
+
+#[test]
+fn merged_quads_are_both_hit_by_rays() {
+    let quad   = quad();
+    let left   = Isometry3::new(Vector3::new(-3.0, 0.0, 0.0), na::zero());
+    let right  = Isometry3::new(Vector3::new(3.0, 1.0, 0.0), Vector3::new(0.0, 0.0, 0.1));
+    let merged = transformation::merge_meshes(&[ (left, &quad), (right, &quad) ], None);
+
+    assert_eq!(merged.vertices().len(), 8);
+    assert_eq!(merged.indices().len(), 4);
+
+    let down = -Vector3::y();
+    let id   = Isometry3::identity();
+
+    let toi1 = merged.toi_with_ray(&id, &Ray::new(Point3::new(-3.0, 5.0, 0.0), down), true).unwrap();
+    let toi2 = merged.toi_with_ray(&id, &Ray::new(Point3::new(3.0, 5.0, 0.0), down), true).unwrap();
+
+    assert!((toi1 - 5.0).abs() < 1.0e-9);
+    assert!((toi2 - 4.0).abs() < 1.0e-9);
+    assert!(merged.toi_with_ray(&id, &Ray::new(Point3::new(0.0, 5.0, 0.0), down), true).is_none());
+}
+
+#[test]
+fn merged_meshes_weld_coincident_vertices() {
+    let quad   = quad();
+    let left   = Isometry3::new(Vector3::new(-0.5, 0.0, 0.0), na::zero());
+    let right  = Isometry3::new(Vector3::new(0.5 + 1.0e-7, 0.0, 0.0), na::zero());
+    let merged = transformation::merge_meshes(&[ (left, &quad), (right, &quad) ], Some(1.0e-5));
+
+    // The two quads share an edge.
+    assert_eq!(merged.vertices().len(), 6);
+    assert_eq!(merged.indices().len(), 4);
+    assert!(merged.indices().iter().all(|idx| idx.x < 6 && idx.y < 6 && idx.z < 6));
+}
@@ -0,0 +1,106 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Cuboid, TriMesh, FeatureId, SupportMap, ShapeHandle};
+use ncollide::query::{Contact, PersistentManifold, FeatureMatching};
+use ncollide::query::contacts_internal;
+use ncollide::narrow_phase::{ContactDispatcher, DefaultContactDispatcher, PersistentContactDispatcher};
+
+type Contacts = Vec<(Contact<Point3<f64>>, (FeatureId, FeatureId))>;
+
+// A square made of two triangles sharing the diagonal `x = z`.
+fn quad() -> TriMesh<Point3<f64>> {
+    let vertices = vec![Point3::new(-5.0, 0.0, -5.0), Point3::new(5.0, 0.0, -5.0),
+                        Point3::new(5.0, 0.0, 5.0),   Point3::new(-5.0, 0.0, 5.0)];
+    let indices  = vec![Point3::new(0usize, 1, 2), Point3::new(0, 2, 3)];
+
+    TriMesh::new(Arc::new(vertices), Arc::new(indices), None, None)
+}
+
+// Contacts with a small tilted box, centered at `(x, ., z)`, with its lowest vertex slightly
+// below the quad.
+fn box_contacts(mesh: &TriMesh<Point3<f64>>, x: f64, z: f64) -> Contacts {
+    let cube    = Cuboid::new(Vector3::new(0.05, 0.05, 0.05));
+    let rot     = Vector3::new(0.3, 0.0, 0.2);
+    let lowest  = cube.support_point(&Isometry3::new(na::zero(), rot), &-Vector3::y());
+    let pos     = Isometry3::new(Vector3::new(x, -lowest.y - 0.01, z), rot);
+    let mut res = Vec::new();
+
+    contacts_internal::composite_shape_against_shape_with_features(&Isometry3::identity(), mesh,
+                                                                   &pos, &cube, 0.0, &mut res);
+
+    res
+}
+
+#[test]
+fn mesh_contacts_identify_triangles_and_box_features() {
+    let mesh = quad();
+    let c1   = box_contacts(&mesh, 0.3, 0.0);
+    let c2   = box_contacts(&mesh, 0.0, 0.3);
+
+    assert_eq!(c1.len(), 1);
+    assert_eq!(c2.len(), 1);
+    assert_eq!((c1[0].1).0, FeatureId::Face(0));
+    assert_eq!((c2[0].1).0, FeatureId::Face(1));
+    assert!(match (c1[0].1).1 { FeatureId::Vertex(_) => true, _ => false });
+    assert_eq!((c1[0].1).1, (c2[0].1).1);
+    assert!((c1[0].0.depth - 0.01).abs() < 1.0e-6);
+}
+
+#[test]
+fn persistent_manifold_keeps_contact_across_shared_mesh_edge() {
+    let mesh = quad();
+    let mut both   = PersistentManifold::<_, ()>::new(0.5);
+    let mut second = PersistentManifold::new_with_matching(0.5, FeatureMatching::Second);
+
+    assert_eq!(both.update(&box_contacts(&mesh, 0.3, 0.0)), 0);
+    assert_eq!(second.update(&box_contacts(&mesh, 0.3, 0.0)), 0);
+    second.contacts_mut()[0].data = 42usize;
+
+    assert_eq!(second.update(&box_contacts(&mesh, 0.0, 0.3)), 1);
+    assert_eq!(second.len(), 1);
+    assert_eq!(second.contacts()[0].data, 42);
+    assert_eq!(second.contacts()[0].age, 1);
+
+    // Matching on both features forgets the contact once it lies on the other triangle.
+    assert_eq!(both.update(&box_contacts(&mesh, 0.0, 0.3)), 0);
+}
+
+#[test]
+fn persistent_contact_generator_keeps_mesh_contact_across_shared_edge() {
+    let mesh: ShapeHandle<Point3<f64>, Isometry3<f64>> = ShapeHandle::new(quad());
+    let cube: ShapeHandle<Point3<f64>, Isometry3<f64>> = ShapeHandle::new(Cuboid::new(Vector3::new(0.05, 0.05, 0.05)));
+    let dispatcher = PersistentContactDispatcher::new(Box::new(DefaultContactDispatcher::new()), 0.5);
+    let mut algo   = dispatcher.get_contact_algorithm(&*mesh, &*cube).unwrap();
+
+    let rot      = Vector3::new(0.3, 0.0, 0.2);
+    let lowest   = cube.as_support_map().unwrap().support_point(&Isometry3::new(na::zero(), rot), &-Vector3::y());
+    let mut on_first = Vec::new();
+    let mut carried  = false;
+
+    // Slide the tilted box from the first triangle to the second one, across their shared edge.
+    for i in 0 .. 31 {
+        let t   = i as f64 / 30.0;
+        let pos = Isometry3::new(Vector3::new(0.3 * (1.0 - t), -lowest.y - 0.01, 0.3 * t), rot);
+
+        assert!(algo.update(&dispatcher, &Isometry3::identity(), &*mesh, &pos, &*cube, 0.0));
+
+        let manifold = algo.persistent_manifold().unwrap();
+
+        for c in manifold.contacts() {
+            assert!(match c.features.1 { FeatureId::Vertex(_) => true, _ => false });
+
+            if c.features.0 == FeatureId::Face(1) && c.age != 0 && on_first.contains(&c.data) {
+                carried = true;
+            }
+        }
+
+        on_first = manifold.contacts().iter().filter(|c| c.features.0 == FeatureId::Face(0)).map(|c| c.data).collect();
+    }
+
+    // A contact moved to the other triangle but kept its identifier.
+    assert!(carried);
+    assert!(on_first.is_empty());
+}
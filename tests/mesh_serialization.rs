@@ -0,0 +1,109 @@
+#![cfg(feature = "serde-serialize")]
+
+extern crate nalgebra as na;
+extern crate ncollide;
+extern crate serde_json;
+
+use std::sync::Arc;
+
+use serde_json::Value;
+use na::{Isometry3, Point2, Point3, Vector3};
+use ncollide::shape::{Polyline, TriMesh};
+use ncollide::query::{Ray, RayCast};
+
+// A flat `n x n` grid of quads on the `xz` plane, each split into two triangles.
+fn grid(n: usize) -> TriMesh<Point3<f64>> {
+    let mut vertices = Vec::new();
+    let mut indices  = Vec::new();
+    let mut normals  = Vec::new();
+
+    for i in 0 .. n + 1 {
+        for j in 0 .. n + 1 {
+            vertices.push(Point3::new(i as f64, 0.0, j as f64));
+            normals.push(Vector3::y());
+        }
+    }
+
+    for i in 0 .. n {
+        for j in 0 .. n {
+            let a = i * (n + 1) + j;
+
+            indices.push(Point3::new(a, a + n + 1, a + n + 2));
+            indices.push(Point3::new(a, a + n + 2, a + 1));
+        }
+    }
+
+    TriMesh::new(Arc::new(vertices), Arc::new(indices), None, Some(Arc::new(normals)))
+}
+
+// Replaces the content of every leaf of a serialized BVT.
+fn set_leaves(node: &mut Value, leaf: usize) {
+    if let Some(children) = node.get_mut("Internal") {
+        set_leaves(&mut children[1], leaf);
+        set_leaves(&mut children[2], leaf);
+    }
+
+    if let Some(content) = node.get_mut("Leaf") {
+        content[1] = Value::from(leaf);
+    }
+}
+
+#[test]
+fn trimesh_serialize_deserialize() {
+    let mesh    = grid(4);
+    let decoded: TriMesh<Point3<f64>> = serde_json::from_str(&serde_json::to_string(&mesh).unwrap()).unwrap();
+    let m       = Isometry3::identity();
+
+    assert_eq!(decoded.vertices(), mesh.vertices());
+    assert_eq!(decoded.indices(), mesh.indices());
+    assert_eq!(decoded.normals(), mesh.normals());
+    assert_eq!(decoded.bounding_volumes(), mesh.bounding_volumes());
+
+    for i in 0 .. 9 {
+        let ray = Ray::new(Point3::new(i as f64 * 0.5, 10.0, 1.5), -Vector3::y());
+
+        assert_eq!(decoded.toi_with_ray(&m, &ray, true), mesh.toi_with_ray(&m, &ray, true));
+    }
+}
+
+#[test]
+fn polyline_serialize_deserialize() {
+    let vertices = vec![ Point2::new(0.0, 0.0), Point2::new(1.0, 1.0), Point2::new(2.0, 0.0) ];
+    let indices  = vec![ Point2::new(0, 1), Point2::new(1, 2) ];
+    let polyline = Polyline::new(Arc::new(vertices), Arc::new(indices), None, None);
+    let decoded: Polyline<Point2<f64>> = serde_json::from_str(&serde_json::to_string(&polyline).unwrap()).unwrap();
+
+    assert_eq!(decoded.vertices(), polyline.vertices());
+    assert_eq!(decoded.indices(), polyline.indices());
+}
+
+#[test]
+fn trimesh_deserialize_invalid_bvt_leaves() {
+    let mut value = serde_json::to_value(&grid(2)).unwrap();
+    set_leaves(&mut value["mesh"]["bvt"]["tree"], 100);
+
+    let decoded: Result<TriMesh<Point3<f64>>, _> = serde_json::from_value(value);
+
+    assert!(decoded.is_err());
+}
+
+#[test]
+fn trimesh_deserialize_invalid_normals() {
+    let mut value = serde_json::to_value(&grid(2)).unwrap();
+    value["mesh"]["normals"].as_array_mut().unwrap().pop();
+
+    let decoded: Result<TriMesh<Point3<f64>>, _> = serde_json::from_value(value);
+
+    assert!(decoded.is_err());
+}
+
+#[test]
+fn trimesh_deserialize_invalid_vertex_indices() {
+    let mut value = serde_json::to_value(&grid(2)).unwrap();
+    value["mesh"]["vertices"].as_array_mut().unwrap().truncate(3);
+    value["mesh"]["normals"].as_array_mut().unwrap().truncate(3);
+
+    let decoded: Result<TriMesh<Point3<f64>>, _> = serde_json::from_value(value);
+
+    assert!(decoded.is_err());
+}
@@ -0,0 +1,64 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::f64;
+use na::{Isometry3, Point2, Point3, Vector3};
+use ncollide::bounding_volume::AABB;
+use ncollide::partitioning::BVT;
+use ncollide::query::{Ray, RayCast};
+use ncollide::shape::{Ball, Cuboid, Cylinder, Plane, ShapeHandle3, TriMesh};
+use std::sync::Arc;
+
+#[test]
+fn ray_casts_with_non_finite_inputs() {
+    let shapes: Vec<ShapeHandle3<f64>> = vec![
+        ShapeHandle3::new(Ball::new(1.0)),
+        ShapeHandle3::new(Cuboid::new(Vector3::new(1.0, 1.0, 1.0))),
+        ShapeHandle3::new(Cylinder::new(1.0, 1.0)),
+        ShapeHandle3::new(Plane::new(Vector3::y())),
+        ShapeHandle3::new(TriMesh::new(Arc::new(vec![Point3::new(-1.0, 0.0, -1.0),
+                                                     Point3::new(1.0, 0.0, -1.0),
+                                                     Point3::new(0.0, 0.0, 1.0)]),
+                                       Arc::new(vec![Point3::new(0, 1, 2)]), None, None))
+    ];
+
+    let origin = Point3::new(0.0, 5.0, 0.0);
+    let rays   = [
+        Ray::new(origin, Vector3::new(0.0, f64::NAN, 0.0)),
+        Ray::new(origin, Vector3::new(0.0, -f64::INFINITY, 0.0)),
+        Ray::new(Point3::new(f64::NAN, 5.0, 0.0), -Vector3::y())
+    ];
+    let id     = Isometry3::identity();
+    let nan_m  = Isometry3::new(Vector3::new(f64::NAN, 0.0, 0.0), na::zero());
+
+    for shape in shapes.iter() {
+        let finite = Ray::new(origin, -Vector3::y());
+        assert!(shape.toi_and_normal_with_ray(&id, &finite, true).is_some());
+        assert!(shape.toi_and_normal_with_ray(&nan_m, &finite, true).is_none());
+
+        for ray in rays.iter() {
+            assert!(!ray.is_finite());
+            assert!(shape.toi_with_ray(&id, ray, true).is_none());
+            assert!(shape.toi_and_normal_with_ray(&id, ray, false).is_none());
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "the bounding volume of the leaf 2 is not finite")]
+fn bvt_with_an_infinite_leaf() {
+    let leaves = vec![
+        (0usize, AABB::new(Point2::new(0.0, 0.0), Point2::new(1.0, 1.0))),
+        (1, AABB::new(Point2::new(2.0, 0.0), Point2::new(3.0, 1.0))),
+        (2, AABB::new(Point2::new(4.0, 0.0), Point2::new(f64::INFINITY, 1.0)))
+    ];
+
+    let _ = BVT::new_balanced(leaves);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "A ball radius must be finite.")]
+fn ball_with_an_infinite_radius() {
+    let _ = Ball::new(f64::INFINITY);
+}
@@ -0,0 +1,59 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+use na::{Isometry2, Isometry3, Point3, Vector2, Vector3};
+use ncollide::shape::{Ball, Cuboid, TriMesh};
+use ncollide::query::{self, ConstantVelocityMotion, TOIStatus};
+
+#[test]
+fn spinning_rod_hits_ball_2d() {
+    let rod  = Cuboid::new(Vector2::new(1.0f64, 0.01));
+    let ball = Ball::new(0.1f64);
+
+    // The rod starts along the `y` axis and rotates clockwise.
+    let start       = Isometry2::new(na::zero(), std::f64::consts::FRAC_PI_2);
+    let rod_motion  = ConstantVelocityMotion::new(start, na::zero(), -1.0);
+    let ball_motion = ConstantVelocityMotion::new(Isometry2::new(Vector2::new(0.6, 0.6), 0.0), na::zero(), 0.0);
+
+    let res = query::nonlinear_time_of_impact(&rod_motion, &rod, &ball_motion, &ball, 10.0)
+        .expect("The rod must hit the ball.");
+
+    // The rod is at the angle `toi` from the `y` axis when its side touches the ball.
+    let center_dist = (0.72f64).sqrt();
+    let expected    = std::f64::consts::FRAC_PI_4 - (0.11 / center_dist).asin();
+
+    assert_eq!(res.status, TOIStatus::Converged);
+    assert!((res.toi - expected).abs() < 1.0e-3);
+    assert!(na::distance(&res.witness1, &res.witness2) < 1.0e-3);
+
+    // The rod does not move: a linear time of impact computation can only miss the contact.
+    assert!(query::time_of_impact(&start, &na::zero(), &rod, &ball_motion.start, &na::zero(), &ball).is_none());
+    assert!(query::nonlinear_time_of_impact(&rod_motion, &rod, &ball_motion, &ball, 0.5).is_none());
+
+    // Rotating the other way, the rod moves away from the ball and hits it later.
+    let rod_motion = ConstantVelocityMotion::new(start, na::zero(), 1.0);
+    let res        = query::nonlinear_time_of_impact(&rod_motion, &rod, &ball_motion, &ball, 10.0).unwrap();
+    assert!((res.toi - (std::f64::consts::PI - 2.0 * (0.11 / center_dist).asin() - expected)).abs() < 1.0e-3);
+}
+
+#[test]
+fn spinning_cuboid_hits_trimesh() {
+    let vertices = vec![ Point3::new(0.6f64, 0.6, -0.5), Point3::new(0.7, 0.5, 0.5), Point3::new(0.5, 0.7, 0.5) ];
+    let indices  = vec![ Point3::new(0usize, 1, 2) ];
+    let mesh     = TriMesh::new(Arc::new(vertices), Arc::new(indices), None, None);
+    let rod      = Cuboid::new(Vector3::new(0.01f64, 1.0, 0.01));
+
+    let rod_motion  = ConstantVelocityMotion::new(Isometry3::identity(), Vector3::new(0.0, 0.0, 0.1), Vector3::new(0.0, 0.0, -2.0));
+    let mesh_motion = ConstantVelocityMotion::new(Isometry3::identity(), na::zero(), na::zero());
+
+    let res = query::nonlinear_time_of_impact(&rod_motion, &rod, &mesh_motion, &mesh, 1.0).unwrap();
+
+    assert_eq!(res.status, TOIStatus::Converged);
+    assert!(res.toi > 0.1 && res.toi < std::f64::consts::FRAC_PI_8);
+
+    let before = Isometry3::new(Vector3::new(0.0, 0.0, 0.1 * res.toi * 0.9), Vector3::new(0.0, 0.0, -2.0 * res.toi * 0.9));
+    let at     = Isometry3::new(Vector3::new(0.0, 0.0, 0.1 * res.toi), Vector3::new(0.0, 0.0, -2.0 * res.toi));
+    assert!(query::distance(&before, &rod, &Isometry3::identity(), &mesh) > 1.0e-3);
+    assert!(query::distance(&at, &rod, &Isometry3::identity(), &mesh) < 1.0e-3);
+}
@@ -0,0 +1,106 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::f64;
+use na::{Isometry3, Point3, Rotation3, Vector3};
+use ncollide::shape::Cuboid;
+use ncollide::bounding_volume::{self, BoundingVolume, BoundingVolumeInterferencesCollector, AABB, OBB, OBB3};
+use ncollide::partitioning::BVT;
+use ncollide::query::{Ray, RayCast};
+
+fn volume(half_extents: &Vector3<f64>) -> f64 {
+    8.0 * half_extents.x * half_extents.y * half_extents.z
+}
+
+#[test]
+fn obb_of_rotated_cuboid_is_tight() {
+    let cuboid     = Cuboid::new(Vector3::new(1.0f64, 2.0, 0.5));
+    let m          = Isometry3::new(Vector3::new(1.0, -2.0, 3.0), Vector3::new(0.3, -0.7, 1.1));
+    let obb: OBB3<f64> = bounding_volume::obb(&cuboid, &m);
+    let aabb: AABB<_>  = bounding_volume::aabb(&cuboid, &m);
+
+    assert!(obb.contains(&obb));
+    assert!(!obb.tightened(1.0e-6).contains(&obb));
+    assert!((volume(obb.half_extents()) - volume(cuboid.half_extents())).abs() < 1.0e-12);
+    assert!(volume(&aabb.half_extents()) > 2.0 * volume(obb.half_extents()));
+
+    // A point outside of the cuboid but inside of its AABB.
+    let corner = m * Point3::new(1.0, 2.0, 0.5);
+    let pt     = corner + (corner - m * Point3::origin()) * 0.01;
+
+    assert!(!obb.contains_point(&pt));
+    assert!(na::partial_le(aabb.mins(), &pt) && na::partial_le(&pt, aabb.maxs()));
+}
+
+#[test]
+fn obb_separating_axes() {
+    // Two thin boxes rotated by 45 degrees around `z`, side by side: their AABBs overlap.
+    let rot    = Vector3::z() * f64::consts::FRAC_PI_4;
+    let cuboid = Cuboid::new(Vector3::new(2.0f64, 0.1, 1.0));
+    let m1     = Isometry3::new(na::zero(), rot);
+    let m2     = Isometry3::new(Vector3::new(-0.5, 0.5, 0.0), rot);
+    let m3     = Isometry3::new(Vector3::new(-0.1, 0.1, 0.0), rot);
+
+    let obb1: OBB3<f64> = bounding_volume::obb(&cuboid, &m1);
+    let obb2: OBB3<f64> = bounding_volume::obb(&cuboid, &m2);
+    let obb3: OBB3<f64> = bounding_volume::obb(&cuboid, &m3);
+    let aabb1: AABB<_>  = bounding_volume::aabb(&cuboid, &m1);
+    let aabb2: AABB<_>  = bounding_volume::aabb(&cuboid, &m2);
+
+    assert!(aabb1.intersects(&aabb2));
+    assert!(!obb1.intersects(&obb2));
+    assert!(obb1.intersects(&obb3));
+
+    let merged = obb1.merged(&obb2);
+    assert!(merged.contains(&obb1) && merged.contains(&obb2));
+}
+
+#[test]
+fn obb_ray_cast() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m      = Isometry3::new(Vector3::new(5.0, 0.0, 0.0), Vector3::z() * f64::consts::FRAC_PI_4);
+    let obb: OBB3<f64> = bounding_volume::obb(&cuboid, &m);
+    let id     = Isometry3::new(na::zero(), na::zero());
+
+    let hit  = Ray::new(Point3::origin(), Vector3::x());
+    let miss = Ray::new(Point3::new(0.0, 1.5, 0.0), Vector3::x());
+
+    let toi = obb.toi_with_ray(&id, &hit, true).expect("The ray should hit the OBB.");
+    assert!((toi - (5.0 - f64::consts::SQRT_2)).abs() < 1.0e-9);
+    assert!(!obb.intersects_ray(&id, &miss));
+}
+
+#[test]
+fn obb_bvt_leaves() {
+    let cuboid = Cuboid::new(Vector3::new(0.5f64, 0.1, 0.1));
+    let leaves: Vec<(usize, OBB3<f64>)> = (0 .. 16usize).map(|i| {
+        let m = Isometry3::new(Vector3::new(i as f64, 0.0, 0.0), Vector3::z() * (i as f64 * 0.3));
+        (i, bounding_volume::obb(&cuboid, &m))
+    }).collect();
+    let query = leaves[7].1.clone();
+    let bvt   = BVT::new_balanced(leaves);
+
+    assert!(bvt.is_valid());
+
+    let mut hits = Vec::new();
+
+    {
+        let mut visitor = BoundingVolumeInterferencesCollector::new(&query, &mut hits);
+        bvt.visit(&mut visitor);
+    }
+
+    hits.sort();
+    assert!(hits.contains(&7) && hits.iter().all(|&i| i >= 6 && i <= 8));
+}
+
+#[test]
+fn merge_obbs_placed_by_rotations() {
+    // OBBs placed by rotations are all centered at the origin, and so is their merge.
+    let obb1: OBB<Point3<f64>, Rotation3<f64>> = OBB::new(Rotation3::identity(), Vector3::new(1.0, 1.0, 1.0));
+    let obb2: OBB<Point3<f64>, Rotation3<f64>> = OBB::new(Rotation3::new(Vector3::new(0.3, -0.2, 0.7)),
+                                                         Vector3::new(0.5, 3.0, 0.5));
+    let merged = obb1.merged(&obb2);
+
+    assert!(merged.contains(&obb1) && merged.contains(&obb2));
+    assert!(merged.half_extents().y > 2.0);
+}
@@ -0,0 +1,73 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::collections::HashSet;
+use na::{Point3, Vector3};
+use ncollide::bounding_volume::{BoundingVolume, AABB};
+use ncollide::partitioning::{Octree, BoundingVolumeInterferencesCollector};
+use ncollide::query::{Ray, RayCast, RayInterferencesCollector};
+
+fn aabb_at(i: usize) -> AABB<Point3<f64>> {
+    let fi     = i as f64;
+    // Some objects lie partially or entirely outside of the octree bounds.
+    let center = Point3::new((fi * 0.7).sin() * 11.0, (fi * 1.3).cos() * 9.0, (fi * 2.1).sin() * 10.0);
+    let half   = Vector3::new(0.1, 0.2, 0.1) * (1.0 + (i % 7) as f64 * 1.5);
+
+    AABB::new(center + -half, center + half)
+}
+
+fn region_query(octree: &Octree<Point3<f64>, usize>, region: &AABB<Point3<f64>>) -> HashSet<usize> {
+    let mut hits = Vec::new();
+
+    {
+        let mut visitor = BoundingVolumeInterferencesCollector::new(region, &mut hits);
+        octree.visit(&mut visitor);
+    }
+
+    hits.into_iter().collect()
+}
+
+#[test]
+fn octree_matches_brute_force() {
+    let n          = 200;
+    let bounds     = AABB::new(Point3::new(-10.0, -10.0, -10.0), Point3::new(10.0, 10.0, 10.0));
+    let mut octree = Octree::new(bounds, 5);
+    let handles: Vec<usize> = (0 .. n).map(|i| octree.insert(aabb_at(i), i)).collect();
+    let mut alive: HashSet<usize> = (0 .. n).collect();
+
+    assert_eq!(octree.len(), n);
+
+    for step in 0 .. 3 {
+        for k in 0 .. 20 {
+            let fk     = k as f64;
+            let center = Point3::new((fk * 0.37).cos() * 9.0, (fk * 0.91).sin() * 9.0, fk - 10.0);
+            let region = AABB::new(center + Vector3::new(-2.0, -1.5, -3.0), center + Vector3::new(2.0, 1.5, 3.0));
+            let expected: HashSet<usize> = alive.iter().cloned().filter(|i| aabb_at(*i).intersects(&region)).collect();
+
+            assert_eq!(region_query(&octree, &region), expected);
+
+            let ray = Ray::new(center, na::normalize(&Vector3::new(1.0, fk.sin(), fk.cos())));
+            let mut hits = Vec::new();
+            {
+                let mut visitor = RayInterferencesCollector::new(&ray, &mut hits);
+                octree.visit(&mut visitor);
+            }
+            let hits: HashSet<usize> = hits.into_iter().collect();
+            let expected: HashSet<usize> = alive.iter().cloned()
+                                                .filter(|i| aabb_at(*i).intersects_ray(&na::Isometry3::identity(), &ray))
+                                                .collect();
+
+            assert_eq!(hits, expected);
+        }
+
+        // Remove some objects before querying again.
+        for i in (step .. n).filter(|i| i % 3 == 0) {
+            if alive.remove(&i) {
+                assert_eq!(octree.remove(handles[i]), Some(i));
+            }
+        }
+
+        assert_eq!(octree.remove(handles[0]), None);
+        assert_eq!(octree.len(), alive.len());
+    }
+}
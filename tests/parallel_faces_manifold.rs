@@ -0,0 +1,97 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{ConvexHull, Cuboid, ShapeHandle};
+use ncollide::query::Contact;
+use ncollide::query::contacts_internal;
+use ncollide::narrow_phase::{ContactDispatcher, DefaultContactDispatcher};
+
+fn box_hull(he: Vector3<f64>) -> ConvexHull<Point3<f64>> {
+    let mut pts = Vec::new();
+
+    for i in 0 .. 8 {
+        pts.push(Point3::new(if i & 1 == 0 { -he.x } else { he.x },
+                             if i & 2 == 0 { -he.y } else { he.y },
+                             if i & 4 == 0 { -he.z } else { he.z }));
+    }
+
+    ConvexHull::new(pts)
+}
+
+fn check_overlap_corners(contacts: &[Contact<Point3<f64>>], top: f64, corners: &[(f64, f64)]) {
+    assert_eq!(contacts.len(), 4);
+
+    for c in contacts.iter() {
+        assert!(na::norm(&(*c.normal - Vector3::y())) < 1.0e-12);
+        assert!((c.world1.y - top).abs() < 1.0e-9);
+    }
+
+    for &(x, z) in corners.iter() {
+        assert!(contacts.iter().any(|c| (c.world1.x - x).abs() < 1.0e-9 && (c.world1.z - z).abs() < 1.0e-9),
+                "Missing contact at ({}, {}).", x, z);
+    }
+}
+
+#[test]
+fn small_box_stacked_flat_on_slab() {
+    let slab = box_hull(Vector3::new(100.0, 1.0, 100.0));
+    let cube = box_hull(Vector3::new(0.1, 0.1, 0.1));
+    let m1   = Isometry3::new(na::zero(), na::zero());
+    let m2   = Isometry3::new(Vector3::new(17.0, 1.09, 3.0), na::zero());
+
+    let mut contacts = Vec::new();
+    contacts_internal::convex_hull_against_convex_hull(&m1, &slab, &m2, &cube, 0.0, &mut contacts);
+
+    check_overlap_corners(&contacts[..], 1.0, &[ (16.9, 2.9), (17.1, 2.9), (17.1, 3.1), (16.9, 3.1) ]);
+
+    for c in contacts.iter() {
+        assert!((c.depth - 0.01).abs() < 1.0e-9);
+    }
+}
+
+#[test]
+fn offset_boxes_stacked_flat() {
+    let b1 = box_hull(Vector3::new(1.0, 1.0, 1.0));
+    let b2 = box_hull(Vector3::new(1.0, 0.5, 1.0));
+    let m1 = Isometry3::new(na::zero(), na::zero());
+    let m2 = Isometry3::new(Vector3::new(0.3, 1.45, 0.7), na::zero());
+
+    let mut contacts = Vec::new();
+    contacts_internal::convex_hull_against_convex_hull(&m1, &b1, &m2, &b2, 0.0, &mut contacts);
+
+    check_overlap_corners(&contacts[..], 1.0, &[ (-0.7, -0.3), (1.0, -0.3), (1.0, 1.0), (-0.7, 1.0) ]);
+
+    // The contacts are the well-separated corners of the 1.7 x 1.3 overlap.
+    for (i, c1) in contacts.iter().enumerate() {
+        for c2 in contacts[i + 1 ..].iter() {
+            assert!(na::distance(&c1.world1, &c2.world1) >= 1.3 - 1.0e-9);
+        }
+    }
+}
+
+#[test]
+fn cuboids_stacked_flat() {
+    let slab = Cuboid::new(Vector3::new(100.0, 1.0, 100.0));
+    let cube = Cuboid::new(Vector3::new(0.1, 0.1, 0.1));
+    let m1   = Isometry3::new(na::zero(), na::zero());
+    let m2   = Isometry3::new(Vector3::new(17.0, 1.09, 3.0), na::zero());
+    let corners = [ (16.9, 2.9), (17.1, 2.9), (17.1, 3.1), (16.9, 3.1) ];
+
+    let mut contacts = Vec::new();
+    contacts_internal::cuboid_against_cuboid_manifold(&m1, &slab, &m2, &cube, 0.0, &mut contacts);
+
+    check_overlap_corners(&contacts[..], 1.0, &corners);
+
+    // The default contact dispatcher clips the cuboids too.
+    let dispatcher = DefaultContactDispatcher::new();
+    let slab       = ShapeHandle::new(slab);
+    let cube       = ShapeHandle::new(cube);
+    let mut algo   = dispatcher.get_contact_algorithm(&*slab, &*cube).unwrap();
+
+    contacts.clear();
+    assert!(algo.update(&dispatcher, &m1, &*slab, &m2, &*cube, 0.0));
+    algo.contacts(&mut contacts);
+
+    check_overlap_corners(&contacts[..], 1.0, &corners);
+}
@@ -0,0 +1,48 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Vector3};
+use ncollide::shape::Cuboid;
+use ncollide::narrow_phase::{ContactDispatcher, ContactGenerator, DefaultContactDispatcher, PersistentContactDispatcher};
+
+fn ids(algorithm: &ContactGenerator<na::Point3<f64>, Isometry3<f64>>) -> Vec<(usize, usize)> {
+    let mut res: Vec<_> = algorithm.persistent_manifold().unwrap().contacts().iter().map(|c| (c.data, c.age)).collect();
+    res.sort();
+    res
+}
+
+#[test]
+fn contacts_of_a_resting_box_keep_their_ids() {
+    let dispatcher = PersistentContactDispatcher::new(Box::new(DefaultContactDispatcher::new()), 0.1);
+    let ground     = Cuboid::new(Vector3::new(10.0f64, 1.0, 10.0));
+    let cube       = Cuboid::new(Vector3::new(0.5f64, 0.5, 0.5));
+    let m1         = Isometry3::new(Vector3::new(0.0, -1.0, 0.0), na::zero());
+    let at         = |x: f64| Isometry3::new(Vector3::new(x, 0.49, 0.0), na::zero());
+
+    let mut algorithm = dispatcher.get_contact_algorithm(&ground, &cube).unwrap();
+    assert!(algorithm.persistent_manifold().is_none());
+
+    assert!(algorithm.update(&dispatcher, &m1, &ground, &at(0.0), &cube, 0.0));
+    assert_eq!(algorithm.num_contacts(), 4);
+    assert!(ids(&*algorithm).iter().all(|&(_, age)| age == 0));
+
+    // Let the incremental manifold settle on the corners of the box.
+    for _ in 0 .. 3 {
+        assert!(algorithm.update(&dispatcher, &m1, &ground, &at(0.0), &cube, 0.0));
+    }
+
+    // The contacts are matched while the box rests or slides slowly.
+    let first = ids(&*algorithm);
+
+    for i in 1 .. 5 {
+        assert!(algorithm.update(&dispatcher, &m1, &ground, &at(i as f64 * 0.01), &cube, 0.0));
+        assert_eq!(ids(&*algorithm), first.iter().map(|&(id, age)| (id, age + i)).collect::<Vec<_>>());
+    }
+
+    // After a jump, the contacts are new.
+    assert!(algorithm.update(&dispatcher, &m1, &ground, &at(2.0), &cube, 0.0));
+    let last_id = first.iter().map(|&(id, _)| id).max().unwrap();
+
+    assert!(algorithm.num_contacts() != 0);
+    assert!(ids(&*algorithm).iter().all(|&(id, age)| id > last_id && age == 0));
+}
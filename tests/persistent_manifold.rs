@@ -0,0 +1,52 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Cuboid, FeatureId};
+use ncollide::query::{Contact, PersistentManifold};
+
+// The four contacts between the bottom face of a cube and the top face of a larger cube below it.
+fn box_box_contacts(shift: Vector3<f64>) -> Vec<(Contact<Point3<f64>>, (FeatureId, FeatureId))> {
+    let cube   = Cuboid::new(Vector3::new(0.5f64, 0.5, 0.5));
+    let pos    = Isometry3::new(Vector3::new(0.0, 0.49, 0.0) + shift, na::zero());
+    let normal = Vector3::y_axis();
+    let mut res = Vec::new();
+
+    for (i, &(x, z)) in [ (-0.5, -0.5), (0.5, -0.5), (0.5, 0.5), (-0.5, 0.5) ].iter().enumerate() {
+        let world1 = pos * Point3::new(x, -cube.half_extents().y, z);
+        let world2 = Point3::new(world1.x, 0.0, world1.z);
+        let contact = Contact::new(world1, world2, normal, world2.y - world1.y);
+
+        res.push((contact, (FeatureId::Vertex(i), FeatureId::Face(2))));
+    }
+
+    res
+}
+
+#[test]
+fn persistent_manifold_matches_moved_box_box_contacts() {
+    let mut manifold = PersistentManifold::new(0.05);
+
+    assert_eq!(manifold.update(&box_box_contacts(na::zero())), 0);
+
+    for (i, c) in manifold.contacts_mut().iter_mut().enumerate() {
+        assert_eq!(c.age, 0);
+        c.data = i as f64 + 1.0;
+    }
+
+    assert_eq!(manifold.update(&box_box_contacts(Vector3::new(0.01, -0.005, 0.01))), 4);
+    assert_eq!(manifold.len(), 4);
+
+    for (i, c) in manifold.contacts().iter().enumerate() {
+        assert_eq!(c.age, 1);
+        assert_eq!(c.data, i as f64 + 1.0);
+    }
+
+    // Contacts that moved too far are new.
+    assert_eq!(manifold.update(&box_box_contacts(Vector3::new(1.0, 0.0, 0.0))), 0);
+
+    for c in manifold.contacts().iter() {
+        assert_eq!(c.age, 0);
+        assert_eq!(c.data, 0.0);
+    }
+}
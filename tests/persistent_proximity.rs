@@ -0,0 +1,49 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry2, Vector2};
+use ncollide::shape::{Ball, Cuboid};
+use ncollide::query::Proximity;
+use ncollide::narrow_phase::PersistentProximityDetector;
+
+#[test]
+fn ball_crossing_the_margin_of_another() {
+    let ball1    = Ball::new(1.0f64);
+    let ball2    = Ball::new(0.5f64);
+    let m1       = Isometry2::new(na::zero(), na::zero());
+    let margin   = 0.5;
+    let mut prox = PersistentProximityDetector::new();
+
+    let mut transitions = Vec::new();
+
+    for i in 0 .. 30 {
+        let m2  = Isometry2::new(Vector2::new(4.0 - i as f64 * 0.1, 0.0), na::zero());
+        let res = prox.update(&m1, &ball1, &m2, &ball2, margin);
+
+        assert_eq!(res, prox.proximity());
+
+        if prox.proximity_changed() {
+            transitions.push((prox.prev_proximity(), prox.proximity()));
+        }
+    }
+
+    assert_eq!(transitions, vec![ (Proximity::Disjoint, Proximity::WithinMargin),
+                                  (Proximity::WithinMargin, Proximity::Intersecting) ]);
+}
+
+#[test]
+fn support_maps_crossing_the_margin_of_each_other() {
+    let ball     = Ball::new(1.0f64);
+    let cuboid   = Cuboid::new(Vector2::new(0.5f64, 0.5));
+    let mut prox = PersistentProximityDetector::new();
+
+    let poss = [ 3.0, 1.8, 1.2, 1.8, 3.0 ];
+    let expected = [ Proximity::Disjoint, Proximity::WithinMargin, Proximity::Intersecting,
+                     Proximity::WithinMargin, Proximity::Disjoint ];
+
+    for (x, prox_expected) in poss.iter().zip(expected.iter()) {
+        let m2 = Isometry2::new(Vector2::new(*x, 0.0), na::zero());
+
+        assert_eq!(prox.update(&Isometry2::identity(), &ball, &m2, &cuboid, 0.5), *prox_expected);
+    }
+}
@@ -0,0 +1,78 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Cuboid, Cylinder, Plane};
+use ncollide::query::{contacts_internal, Ray, RayCast};
+use ncollide::narrow_phase::{ContactDispatcher, DefaultContactDispatcher};
+
+#[test]
+fn plane_cuboid_manifold() {
+    let plane  = Plane::new(Vector3::y());
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let mp     = Isometry3::identity();
+    let mc     = Isometry3::new(Vector3::new(0.0, 0.9, 0.0), na::zero());
+
+    let mut contacts = Vec::new();
+    assert!(contacts_internal::plane_against_shape_manifold(&mp, &plane, &mc, &cuboid, 0.1, &mut contacts));
+    assert_eq!(contacts.len(), 4);
+
+    for c in contacts.iter() {
+        assert!((c.depth - 0.1).abs() < 1.0e-9);
+        assert!(c.world1.y.abs() < 1.0e-9);
+        assert!((c.world2.y + 0.1).abs() < 1.0e-9);
+        assert_eq!(*c.normal, Vector3::y());
+    }
+
+    let mut flipped = Vec::new();
+    assert!(contacts_internal::shape_against_plane_manifold(&mc, &cuboid, &mp, &plane, 0.1, &mut flipped));
+    assert_eq!(flipped.len(), 4);
+    assert!(flipped.iter().all(|c| *c.normal == -Vector3::y()));
+
+    // Far above the plane.
+    let mc       = Isometry3::new(Vector3::new(0.0, 2.0, 0.0), na::zero());
+    let mut none = Vec::new();
+    assert!(contacts_internal::plane_against_shape_manifold(&mp, &plane, &mc, &cuboid, 0.1, &mut none));
+    assert!(none.is_empty());
+}
+
+#[test]
+fn plane_cylinder_single_contact() {
+    let plane    = Plane::new(Vector3::y());
+    let cylinder = Cylinder::new(1.0f64, 0.5);
+    let mp       = Isometry3::identity();
+    let mc       = Isometry3::new(Vector3::new(0.0, 0.9, 0.0), na::zero());
+
+    let mut contacts = Vec::new();
+    assert!(contacts_internal::plane_against_shape_manifold(&mp, &plane, &mc, &cylinder, 0.1, &mut contacts));
+    assert_eq!(contacts.len(), 1);
+    assert!((contacts[0].depth - 0.1).abs() < 1.0e-9);
+}
+
+#[test]
+fn plane_cuboid_contact_generator() {
+    let dispatcher = DefaultContactDispatcher::new();
+    let plane      = Plane::new(Vector3::y());
+    let cuboid     = Cuboid::new(Vector3::new(1.0f64, 0.5, 1.0));
+    let mp         = Isometry3::identity();
+    let mc         = Isometry3::new(Vector3::new(0.0, 0.45, 0.0), na::zero());
+
+    let mut algorithm = dispatcher.get_contact_algorithm(&plane, &cuboid).unwrap();
+    assert!(algorithm.update(&dispatcher, &mp, &plane, &mc, &cuboid, 0.0));
+    assert_eq!(algorithm.num_contacts(), 4);
+
+    let mut algorithm = dispatcher.get_contact_algorithm(&cuboid, &plane).unwrap();
+    assert!(algorithm.update(&dispatcher, &mc, &cuboid, &mp, &plane, 0.0));
+    assert_eq!(algorithm.num_contacts(), 4);
+}
+
+#[test]
+fn ray_from_inside_of_plane() {
+    let plane = Plane::new(Vector3::y());
+    let m     = Isometry3::identity();
+    let ray   = Ray::new(Point3::new(0.0f64, -2.0, 0.0), Vector3::y());
+
+    assert_eq!(plane.toi_with_ray(&m, &ray, true), Some(0.0));
+    assert_eq!(plane.toi_with_ray(&m, &ray, false), Some(2.0));
+    assert!(plane.toi_and_normal_with_ray_and_max_toi(&m, &ray, 1.0, false).is_none());
+}
@@ -0,0 +1,55 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Point3, Vector3, Isometry3};
+use ncollide::partitioning::BVT;
+use ncollide::shape::{Cone, Ball, Cuboid, Capsule};
+use ncollide::query::{self, PointQuery};
+use ncollide::bounding_volume::{self, BoundingSphere, HasBoundingVolume};
+
+trait Shape3: HasBoundingVolume<Isometry3<f64>, BoundingSphere<Point3<f64>>> +
+              PointQuery<Point3<f64>, Isometry3<f64>> {
+}
+
+impl<T> Shape3 for T
+    where T: HasBoundingVolume<Isometry3<f64>, BoundingSphere<Point3<f64>>> +
+             PointQuery<Point3<f64>, Isometry3<f64>> {
+}
+
+#[test]
+fn closest_shape_of_bvt_scene() {
+    let ball = Ball::new(0.5);
+    let caps = Capsule::new(0.5, 0.75);
+    let cone = Cone::new(0.5, 0.75);
+    let cube = Cuboid::new(Vector3::new(1.0, 0.5, 1.0));
+
+    let shapes = [
+        &ball as &Shape3,
+        &caps as &Shape3,
+        &cone as &Shape3,
+        &cube as &Shape3
+    ];
+
+    let poss = [
+        Isometry3::new(Vector3::new(0.0, 0.0, 1.0), na::zero()),
+        Isometry3::new(Vector3::new(0.0, 0.0, 2.0), na::zero()),
+        Isometry3::new(Vector3::new(0.0, 0.0, 3.0), na::zero()),
+        Isometry3::new(Vector3::new(0.0, 2.0, 4.0), na::zero())
+    ];
+
+    let idx_and_bounding_spheres: Vec<(usize, BoundingSphere<Point3<f64>>)> =
+        (0 .. 4).map(|i| (i, bounding_volume::bounding_sphere(shapes[i], &poss[i]))).collect();
+    let bvt = BVT::new_balanced(idx_and_bounding_spheres);
+
+    // Closer to the ball than to the capsule next to it.
+    let pt = Point3::new(0.8, 0.0, 1.0);
+    let (i, proj) = query::bvt_project_point(&bvt, &pt, |i| shapes[*i].project_point(&poss[*i], &pt, true)).unwrap();
+    assert_eq!(*i, 0);
+    assert!(na::distance(&proj.point, &Point3::new(0.5, 0.0, 1.0)) < 1.0e-7);
+
+    // Above the cuboid.
+    let pt = Point3::new(0.2, 3.0, 4.5);
+    let (i, proj) = query::bvt_project_point(&bvt, &pt, |i| shapes[*i].project_point(&poss[*i], &pt, true)).unwrap();
+    assert_eq!(*i, 3);
+    assert!(na::distance(&proj.point, &Point3::new(0.2, 2.5, 4.5)) < 1.0e-7);
+}
@@ -0,0 +1,153 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+
+use na::{Isometry2, Isometry3, Point2, Point3, Vector3};
+use ncollide::shape::{Ball, Capsule, Compound, Cone, ConvexHull, Cuboid, Cylinder, FeatureId, Segment, ShapeHandle3,
+                      Triangle, TriMesh};
+use ncollide::query::PointQuery;
+
+#[test]
+fn cuboid_projection_features() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 2.0, 3.0));
+    let m      = Isometry3::identity();
+
+    // The top face spans the axes `x` and `z`.
+    let proj = cuboid.project_point(&m, &Point3::new(0.5, 5.0, 0.2), true);
+    assert_eq!(proj.feature, FeatureId::Face(0b101 << 3));
+
+    // The edge along `z` on the positive sides of `x` and `y`.
+    let proj = cuboid.project_point(&m, &Point3::new(5.0, 5.0, 0.2), true);
+    assert_eq!(proj.feature, FeatureId::Edge(0b100 << 3));
+
+    // The vertex on the negative sides of all the axes.
+    let proj = cuboid.project_point(&m, &Point3::new(-5.0, -5.0, -5.0), true);
+    assert_eq!(proj.feature, FeatureId::Vertex(0b111));
+
+    // An inside point is projected on the closest face, here on the negative side of `x`.
+    let proj = cuboid.project_point(&m, &Point3::new(-0.9, 0.0, 0.0), false);
+    assert!(proj.is_inside);
+    assert!(na::distance(&proj.point, &Point3::new(-1.0, 0.0, 0.0)) < 1.0e-9);
+    assert_eq!(proj.feature, FeatureId::Face((0b110 << 3) | 0b001));
+
+    // An inside point is its own projection on a solid cuboid.
+    let proj = cuboid.project_point(&m, &Point3::new(-0.9, 0.0, 0.0), true);
+    assert_eq!(proj.feature, FeatureId::Unknown);
+}
+
+#[test]
+fn ball_segment_triangle_projection_features() {
+    let m = Isometry3::identity();
+
+    let ball = Ball::new(1.0f64);
+    assert_eq!(ball.project_point(&m, &Point3::new(0.0, 3.0, 0.0), true).feature, FeatureId::Face(0));
+    assert_eq!(ball.project_point(&m, &Point3::new(0.0, 0.5, 0.0), true).feature, FeatureId::Unknown);
+
+    let segment = Segment::new(Point3::new(0.0f64, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0));
+    assert_eq!(segment.project_point(&m, &Point3::new(2.0, 1.0, 0.0), true).feature, FeatureId::Vertex(1));
+    assert_eq!(segment.project_point(&m, &Point3::new(0.5, 1.0, 0.0), true).feature, FeatureId::Edge(0));
+
+    let triangle = Triangle::new(Point3::new(0.0f64, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0));
+    assert_eq!(triangle.project_point(&m, &Point3::new(-1.0, -1.0, 1.0), true).feature, FeatureId::Vertex(0));
+    assert_eq!(triangle.project_point(&m, &Point3::new(1.0, 1.0, 1.0), true).feature, FeatureId::Edge(1));
+    assert_eq!(triangle.project_point(&m, &Point3::new(0.2, 0.2, 1.0), true).feature, FeatureId::Face(0));
+}
+
+#[test]
+fn triangle2_inside_projection_on_closest_edge() {
+    let triangle = Triangle::new(Point2::new(0.0f64, 0.0), Point2::new(10.0, 0.0), Point2::new(0.0, 1.0));
+    let pt       = Point2::new(5.0, 0.4);
+    let proj     = triangle.project_point(&Isometry2::identity(), &pt, false);
+
+    // The hypotenuse `bc` is closer than the edge `ab`.
+    assert!(proj.is_inside);
+    assert_eq!(proj.feature, FeatureId::Edge(1));
+    assert!((na::distance(&pt, &proj.point) - 1.0 / 101.0f64.sqrt()).abs() < 1.0e-9);
+}
+
+#[test]
+fn trimesh_and_compound_projection_features() {
+    // A unit quad on the `xz` plane made of the triangles 0 and 1 sharing the diagonal `(0, 2)`.
+    let vertices = vec![
+        Point3::new(0.0f64, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(0.0, 0.0, 1.0)
+    ];
+    let indices = vec![ Point3::new(0, 1, 2), Point3::new(0, 2, 3) ];
+    let mesh    = TriMesh::new(Arc::new(vertices), Arc::new(indices), None, None);
+    let m       = Isometry3::identity();
+
+    assert_eq!(mesh.project_point(&m, &Point3::new(0.2, 1.0, 0.8), true).feature, FeatureId::Face(1));
+    assert_eq!(mesh.project_point(&m, &Point3::new(2.0, 1.0, -1.0), true).feature, FeatureId::Vertex(1));
+    // The diagonal is the edge `ac` of the triangle 0.
+    assert_eq!(mesh.project_point(&m, &Point3::new(0.3, 1.0, 0.3), true).feature, FeatureId::Edge(2));
+    assert_eq!(mesh.project_point(&m, &Point3::new(0.5, 1.0, -1.0), true).feature, FeatureId::Edge(0));
+
+    // The projection on a compound reports the feature of its closest part, encoded with the
+    // index of this part.
+    let delta    = Isometry3::new(Vector3::new(5.0, 0.0, 0.0), na::zero());
+    let parts    = vec![ (m, ShapeHandle3::new(Ball::new(1.0))),
+                         (delta, ShapeHandle3::new(Cuboid::new(Vector3::new(1.0, 1.0, 1.0)))) ];
+    let compound = Compound::new(parts);
+
+    assert_eq!(compound.project_point(&m, &Point3::new(-3.0, 0.0, 0.0), true).feature, FeatureId::Face(0));
+    let feature = compound.project_point(&m, &Point3::new(5.5, 3.0, 0.5), true).feature;
+    assert_eq!(feature, FeatureId::Face((0b101 << 3) * 2 + 1));
+    assert_eq!(compound.subshape_feature_id(feature), Some((1, FeatureId::Face(0b101 << 3))));
+    assert_eq!(compound.subshape_feature_id(FeatureId::Unknown), None);
+}
+
+#[test]
+fn rounded_shapes_projection_features() {
+    let m = Isometry3::identity();
+
+    let capsule = Capsule::new(1.0f64, 0.5);
+    let proj    = capsule.project_point(&m, &Point3::new(2.0, 0.5, 0.0), true);
+    assert_eq!(proj.feature, FeatureId::Face(0));
+    assert!(na::distance(&proj.point, &Point3::new(0.5, 0.5, 0.0)) < 1.0e-9);
+    assert_eq!(capsule.project_point(&m, &Point3::new(0.0, -3.0, 0.0), true).feature, FeatureId::Face(1));
+    assert_eq!(capsule.project_point(&m, &Point3::new(1.0, 2.0, 0.0), true).feature, FeatureId::Face(2));
+
+    // Inside of the capsule, the point is projected on the closest part of the boundary.
+    let proj = capsule.project_point(&m, &Point3::new(0.0, 0.2, 0.4), false);
+    assert!(proj.is_inside);
+    assert!(na::distance(&proj.point, &Point3::new(0.0, 0.2, 0.5)) < 1.0e-9);
+
+    let cylinder = Cylinder::new(1.0f64, 0.5);
+    assert_eq!(cylinder.project_point(&m, &Point3::new(2.0, 0.0, 0.0), true).feature, FeatureId::Face(0));
+    assert_eq!(cylinder.project_point(&m, &Point3::new(0.1, 3.0, 0.0), true).feature, FeatureId::Face(2));
+    assert_eq!(cylinder.project_point(&m, &Point3::new(2.0, -3.0, 0.0), true).feature, FeatureId::Edge(0));
+
+    let cone = Cone::new(1.0f64, 0.5);
+    assert_eq!(cone.project_point(&m, &Point3::new(0.0, 3.0, 0.0), true).feature, FeatureId::Vertex(0));
+    assert_eq!(cone.project_point(&m, &Point3::new(0.1, -3.0, 0.0), true).feature, FeatureId::Face(1));
+}
+
+#[test]
+fn convex_hull_projection_features() {
+    // A unit cube with an extra point in the middle of the edge joining the points 0 and 1.
+    let mut points = Vec::new();
+
+    for i in 0 .. 8 {
+        points.push(Point3::new((i & 1) as f64, ((i >> 1) & 1) as f64, ((i >> 2) & 1) as f64));
+    }
+
+    points.push(Point3::new(0.5, 0.0, 0.0));
+
+    let hull = ConvexHull::new(points);
+    let m    = Isometry3::identity();
+    let n    = 9;
+
+    assert_eq!(hull.project_point(&m, &Point3::new(2.0, 2.0, 2.0), true).feature, FeatureId::Vertex(7));
+    // The edge joining the points 0 and 1, and the extra point.
+    assert_eq!(hull.project_point(&m, &Point3::new(0.3, -1.0, -1.0), true).feature, FeatureId::Edge(1));
+    // The face `z = 0` with the points 0, 1, 2, 3 and 8.
+    assert_eq!(hull.project_point(&m, &Point3::new(0.3, 0.6, -1.0), true).feature, FeatureId::Face((0 * n + 1) * n + 2));
+    // The face `x = 1` with the points 1, 3, 5 and 7, projected from inside.
+    let proj = hull.project_point(&m, &Point3::new(0.9, 0.5, 0.5), false);
+    assert!(proj.is_inside);
+    assert_eq!(proj.feature, FeatureId::Face((1 * n + 3) * n + 5));
+    assert_eq!(hull.project_point(&m, &Point3::new(0.9, 0.5, 0.5), true).feature, FeatureId::Unknown);
+}
@@ -0,0 +1,52 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+use na::Point2;
+use ncollide::shape::Polyline;
+
+fn closed_polyline(vertices: Vec<Point2<f64>>) -> Polyline<Point2<f64>> {
+    let n       = vertices.len();
+    let indices = (0 .. n).map(|i| Point2::new(i, (i + 1) % n)).collect();
+
+    Polyline::new(Arc::new(vertices), Arc::new(indices), None, None)
+}
+
+#[test]
+fn ccw_square_is_convex_with_positive_area() {
+    let square = closed_polyline(vec![ Point2::new(0.0, 0.0), Point2::new(2.0, 0.0),
+                                       Point2::new(2.0, 2.0), Point2::new(0.0, 2.0) ]);
+
+    assert!((square.signed_area() - 4.0).abs() < 1.0e-7);
+    assert!(square.is_convex());
+}
+
+#[test]
+fn cw_square_has_negative_area() {
+    let square = closed_polyline(vec![ Point2::new(0.0, 0.0), Point2::new(0.0, 2.0),
+                                       Point2::new(2.0, 2.0), Point2::new(2.0, 0.0) ]);
+
+    assert!((square.signed_area() + 4.0).abs() < 1.0e-7);
+    assert!(square.is_convex());
+}
+
+#[test]
+fn non_convex_pentagon() {
+    // A square with a notch on its top side.
+    let pentagon = closed_polyline(vec![ Point2::new(0.0, 0.0), Point2::new(2.0, 0.0),
+                                         Point2::new(2.0, 2.0), Point2::new(1.0, 1.0),
+                                         Point2::new(0.0, 2.0) ]);
+
+    assert!((pentagon.signed_area() - 3.0).abs() < 1.0e-7);
+    assert!(!pentagon.is_convex());
+}
+
+#[test]
+fn pentagram_is_not_convex() {
+    let pts: Vec<Point2<f64>> = (0 .. 5).map(|i| {
+        let angle = (i * 2) as f64 * 2.0 * ::std::f64::consts::PI / 5.0;
+        Point2::new(angle.cos(), angle.sin())
+    }).collect();
+
+    assert!(!closed_polyline(pts).is_convex());
+}
@@ -0,0 +1,37 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry2, Isometry3, Vector2, Vector3};
+use ncollide::math::{Pose2, Pose3};
+use ncollide::shape::{Ball, Compound, ShapeHandle};
+
+#[test]
+fn pose3_isometry3_round_trip() {
+    let isometry = Isometry3::new(Vector3::new(1.0f64, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    let pose: Pose3<f64> = isometry.into();
+
+    assert_eq!(pose.translation, isometry.translation.vector);
+    assert_eq!(pose.rotation, isometry.rotation);
+
+    let back: Isometry3<f64> = pose.into();
+    assert_eq!(back, isometry);
+}
+
+#[test]
+fn pose2_isometry2_round_trip() {
+    let isometry = Isometry2::new(Vector2::new(1.0f64, 2.0), 0.5);
+    let pose     = Pose2::from(isometry);
+    let back: Isometry2<f64> = pose.into();
+
+    assert_eq!(back, isometry);
+}
+
+#[test]
+fn compound_from_poses() {
+    let pose     = Pose3::from(Isometry3::new(Vector3::new(1.0f64, 0.0, 0.0), na::zero()));
+    let shapes   = vec![ (pose, ShapeHandle::new(Ball::new(1.0f64))) ];
+    let compound = Compound::<_, Isometry3<f64>>::from_poses(shapes);
+    let expected: Isometry3<f64> = pose.into();
+
+    assert_eq!(compound.shapes()[0].0, expected);
+}
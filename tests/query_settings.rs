@@ -0,0 +1,124 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::cell::Cell;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::bounding_volume::{self, AABB3};
+use ncollide::shape::{self, Ball, Cuboid, Shape, ShapeHandle, SupportMap};
+use ncollide::query::QuerySettings;
+use ncollide::query::algorithms::{gjk, minkowski_sampling};
+use ncollide::query::algorithms::simplex::Simplex;
+use ncollide::query::algorithms::johnson_simplex::JohnsonSimplex;
+use ncollide::world::{CollisionGroups, CollisionWorld3, GeometricQueryType};
+
+// A support map counting the number of support point evaluations, i.e., GJK iterations.
+struct CountingSupportMap<'a, G: 'a> {
+    shape: &'a G,
+    count: Cell<usize>
+}
+
+impl<'a, G: SupportMap<Point3<f64>, Isometry3<f64>>> SupportMap<Point3<f64>, Isometry3<f64>> for CountingSupportMap<'a, G> {
+    fn support_point(&self, m: &Isometry3<f64>, dir: &Vector3<f64>) -> Point3<f64> {
+        self.count.set(self.count.get() + 1);
+        self.shape.support_point(m, dir)
+    }
+}
+
+fn gjk_iterations(settings: &QuerySettings<f64>) -> usize {
+    let ball  = Ball::new(1.0f64);
+    let shape = CountingSupportMap { shape: &ball, count: Cell::new(0) };
+    let m     = Isometry3::new(Vector3::new(3.0, 1.0, 0.5), na::zero());
+
+    let mut simplex = JohnsonSimplex::new_w_tls();
+    simplex.reset(shape.support_point(&m, &Vector3::new(-1.0, 0.0, 0.0)));
+
+    let proj = gjk::project_origin(&m, &shape, &mut simplex, settings).0.unwrap();
+    let expected = (3.0f64 * 3.0 + 1.0 + 0.25).sqrt() - 1.0;
+
+    assert!(na::norm(&proj.coords) >= expected - 1.0e-9);
+
+    shape.count.get()
+}
+
+#[test]
+fn tighter_gjk_tolerance_needs_more_iterations() {
+    let default = QuerySettings::default();
+    let loose   = QuerySettings { gjk_tolerance: 1.0e-2, .. default };
+    let tight   = QuerySettings { gjk_tolerance: 1.0e-12, .. default };
+
+    let loose_iters   = gjk_iterations(&loose);
+    let default_iters = gjk_iterations(&default);
+    let tight_iters   = gjk_iterations(&tight);
+
+    assert!(loose_iters < default_iters);
+    assert!(default_iters <= tight_iters);
+}
+
+#[test]
+fn penetration_shift_setting() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f32, 1.0, 1.0));
+    let m1     = Isometry3::identity();
+    let m2     = Isometry3::new(Vector3::new(1.5, 0.0, 0.0), na::zero());
+
+    for shift in [ 0.01f32, 0.1, 0.5 ].iter() {
+        let settings = QuerySettings { penetration_shift: *shift, .. QuerySettings::default() };
+        let mut simplex = JohnsonSimplex::new_w_tls();
+        simplex.reset(shape::cso_support_point(&m1, &cuboid, &m2, &cuboid, Vector3::x()));
+
+        let (p1, p2, normal) = minkowski_sampling::closest_points_with_settings(
+            &m1, &cuboid, &m2, &cuboid, &mut simplex, &settings).0.unwrap();
+
+        assert!((normal - Vector3::x()).norm() < 1.0e-4);
+        assert!((na::dot(&(p1 - p2), &normal) - 0.5).abs() < 1.0e-4);
+    }
+}
+
+// A ball which is not seen as a `Ball` by the dispatchers, counting its support point evaluations.
+struct CountingBall {
+    ball:  Ball<f64>,
+    count: Arc<AtomicUsize>
+}
+
+impl SupportMap<Point3<f64>, Isometry3<f64>> for CountingBall {
+    fn support_point(&self, m: &Isometry3<f64>, dir: &Vector3<f64>) -> Point3<f64> {
+        let _ = self.count.fetch_add(1, Ordering::SeqCst);
+        self.ball.support_point(m, dir)
+    }
+}
+
+impl Shape<Point3<f64>, Isometry3<f64>> for CountingBall {
+    fn aabb(&self, m: &Isometry3<f64>) -> AABB3<f64> {
+        bounding_volume::aabb(&self.ball, m)
+    }
+
+    fn as_support_map(&self) -> Option<&SupportMap<Point3<f64>, Isometry3<f64>>> {
+        Some(self)
+    }
+}
+
+fn world_support_points(settings: QuerySettings<f64>) -> usize {
+    let count = Arc::new(AtomicUsize::new(0));
+    let shape = ShapeHandle::new(CountingBall { ball: Ball::new(1.0), count: count.clone() });
+    let query = GeometricQueryType::Contacts(1.0);
+    let at    = |x: f64| Isometry3::new(Vector3::new(x, 0.3, 0.0), na::zero());
+
+    let mut world = CollisionWorld3::new_with_settings(0.02, false, settings);
+    world.deferred_add(0, Isometry3::identity(), shape.clone(), CollisionGroups::new(), query, ());
+    world.deferred_add(1, at(2.5), shape, CollisionGroups::new(), query, ());
+    world.update();
+
+    assert!(world.contacts().count() > 0);
+
+    count.load(Ordering::SeqCst)
+}
+
+#[test]
+fn world_query_settings() {
+    let default = QuerySettings::default();
+    let loose   = QuerySettings { gjk_tolerance: 1.0e-1, .. default };
+
+    assert!(world_support_points(loose) < world_support_points(default));
+}
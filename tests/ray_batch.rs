@@ -0,0 +1,38 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry2, Point2, Vector2};
+use ncollide::shape::Cuboid;
+use ncollide::query::{Ray, RayCast};
+
+#[test]
+fn cast_ray_fan_at_cuboid() {
+    let cuboid = Cuboid::new(Vector2::new(1.0f64, 1.0));
+    let m      = Isometry2::new(Vector2::new(5.0, 0.0), na::zero());
+    let origin = Point2::origin();
+
+    // Rays with angles from -45° to 45°. Only those within atan(1 / 4) of the x axis hit the box.
+    let rays: Vec<_> = (0 .. 19).map(|i| {
+        let angle = (i as f64 - 9.0) * 5.0f64.to_radians();
+        Ray::new(origin, Vector2::new(angle.cos(), angle.sin()))
+    }).collect();
+
+    let hits = cuboid.cast_rays(&m, &rays, true);
+
+    assert_eq!(hits.len(), rays.len());
+
+    for (i, hit) in hits.iter().enumerate() {
+        let angle = (i as f64 - 9.0) * 5.0f64.to_radians();
+
+        match *hit {
+            Some(ref inter) => {
+                assert!(angle.abs() < 0.25f64.atan());
+                assert!(na::norm(&(*inter.normal + Vector2::x())) < 1.0e-7);
+                assert!((inter.toi - 4.0 / angle.cos()).abs() < 1.0e-7);
+            },
+            None => assert!(angle.abs() > 0.25f64.atan())
+        }
+    }
+
+    assert_eq!(hits.iter().filter(|h| h.is_some()).count(), 5);
+}
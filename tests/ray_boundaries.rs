@@ -0,0 +1,66 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Ball, Cuboid, Cone, TriMesh};
+use ncollide::query::{Ray, RayCast};
+
+// Casts rays from the top of `shape`, at the height `top`, and from above it.
+fn check_boundaries<S: RayCast<Point3<f64>, Isometry3<f64>>>(shape: &S, top: f64, exit_toi: Option<f64>) {
+    let m    = Isometry3::identity();
+    let up   = Vector3::y();
+    let down = -Vector3::y();
+    let on   = Point3::new(0.0, top, 0.0);
+
+    // Starting on the boundary, toward the outside.
+    assert_eq!(shape.toi_with_ray(&m, &Ray::new(on, up), true), Some(0.0));
+    assert_eq!(shape.toi_with_ray(&m, &Ray::new(on, up), false), Some(0.0));
+
+    // Starting on the boundary, toward the inside.
+    assert_eq!(shape.toi_with_ray(&m, &Ray::new(on, down), true), Some(0.0));
+
+    match exit_toi {
+        Some(toi) => {
+            let exit = shape.toi_with_ray(&m, &Ray::new(on, down), false).unwrap();
+            assert!((exit - toi).abs() < 1.0e-7, "Wrong exit time of impact: {}", exit);
+        },
+        None => assert_eq!(shape.toi_with_ray(&m, &Ray::new(on, down), false), Some(0.0))
+    }
+
+    // The maximum time of impact is inclusive.
+    let above = Ray::new(Point3::new(0.0, top + 1.0, 0.0), down);
+
+    for &solid in [ true, false ].iter() {
+        assert_eq!(shape.toi_and_normal_with_ray_and_max_toi(&m, &above, 1.0, solid).map(|i| i.toi), Some(1.0));
+        assert!(shape.toi_and_normal_with_ray_and_max_toi(&m, &above, 0.5, solid).is_none());
+        assert_eq!(shape.toi_and_normal_with_ray_and_max_toi(&m, &Ray::new(on, up), 0.0, solid).map(|i| i.toi),
+                   Some(0.0));
+    }
+}
+
+#[test]
+fn ball_ray_boundaries() {
+    check_boundaries(&Ball::new(1.0), 1.0, Some(2.0));
+}
+
+#[test]
+fn cuboid_ray_boundaries() {
+    check_boundaries(&Cuboid::new(Vector3::new(1.0, 1.0, 1.0)), 1.0, Some(2.0));
+}
+
+#[test]
+fn cone_ray_boundaries() {
+    check_boundaries(&Cone::new(1.0, 1.0), 1.0, Some(2.0));
+}
+
+#[test]
+fn trimesh_ray_boundaries() {
+    // A single triangle, on both of its faces.
+    let vertices = vec![ Point3::new(-1.0, 1.0, -1.0), Point3::new(1.0, 1.0, -1.0), Point3::new(0.0, 1.0, 1.0) ];
+
+    for indices in [ Point3::new(0usize, 1, 2), Point3::new(0usize, 2, 1) ].iter() {
+        let mesh = TriMesh::new(Arc::new(vertices.clone()), Arc::new(vec![ *indices ]), None, None);
+        check_boundaries(&mesh, 1.0, None);
+    }
+}
@@ -0,0 +1,67 @@
+extern crate rand;
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use rand::{Rng, SeedableRng, StdRng};
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Compound, Cuboid, Ball, ShapeHandle};
+use ncollide::query::{Ray, RayCast};
+
+// Hundreds of small boxes and balls, some of them overlapping.
+fn compound(rng: &mut StdRng) -> Compound<Point3<f64>, Isometry3<f64>> {
+    let shapes = (0 .. 300).map(|i| {
+        let pos = Isometry3::new(Vector3::new(rng.gen_range(-5.0, 5.0), rng.gen_range(-5.0, 5.0), rng.gen_range(-5.0, 5.0)),
+                                 Vector3::new(rng.gen_range(-1.0, 1.0), rng.gen_range(-1.0, 1.0), rng.gen_range(-1.0, 1.0)));
+
+        let shape = if i % 2 == 0 {
+            ShapeHandle::new(Cuboid::new(Vector3::new(0.3, 0.2, 0.1)))
+        }
+        else {
+            ShapeHandle::new(Ball::new(0.25))
+        };
+
+        (pos, shape)
+    }).collect();
+
+    Compound::new(shapes)
+}
+
+#[test]
+fn ray_against_compound_matches_brute_force() {
+    let mut rng  = StdRng::from_seed(&[42]);
+    let compound = compound(&mut rng);
+    let m        = Isometry3::new(Vector3::new(1.0, -2.0, 0.5), Vector3::new(0.1, 0.2, 0.3));
+
+    for i in 0 .. 200 {
+        // Half of the rays start inside of the compound's bounding volumes.
+        let extent = if i % 2 == 0 { 3.0 } else { 10.0 };
+        let origin = Point3::new(rng.gen_range(-extent, extent), rng.gen_range(-extent, extent), rng.gen_range(-extent, extent));
+        let dir    = Vector3::new(rng.gen_range(-1.0, 1.0), rng.gen_range(-1.0, 1.0), rng.gen_range(-1.0, 1.0));
+        let ray    = Ray::new(m * origin, dir);
+
+        for &solid in [ true, false ].iter() {
+            let expected = compound.shapes().iter().enumerate().filter_map(|(i, &(ref delta, ref shape))| {
+                shape.as_ray_cast().unwrap().toi_with_ray(&(m * *delta), &ray, solid).map(|toi| (i, toi))
+            }).fold(None, |best: Option<(usize, f64)>, hit| {
+                match best {
+                    Some(b) if b.1 <= hit.1 => Some(b),
+                    _                       => Some(hit)
+                }
+            });
+
+            let toi = compound.toi_with_ray(&m, &ray, solid);
+            let hit = compound.toi_and_part_with_ray(&m, &ray, solid);
+
+            match expected {
+                Some((_, expected_toi)) => {
+                    assert!((toi.unwrap() - expected_toi).abs() < 1.0e-6);
+                    assert!((hit.unwrap().1.toi - expected_toi).abs() < 1.0e-6);
+                },
+                None => {
+                    assert!(toi.is_none());
+                    assert!(hit.is_none());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,50 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::Cone;
+use ncollide::query::{Ray, RayCast};
+
+#[test]
+fn ray_cone_side_hit() {
+    let cone = Cone::new(1.0f64, 1.0);
+    let m    = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), na::zero());
+    let ray  = Ray::new(Point3::new(-4.0, 2.0, 3.0), Vector3::x());
+
+    // At `y = 0`, the cone radius is half its base radius.
+    let inter = cone.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert!((inter.toi - 4.5).abs() < 1.0e-9);
+    assert!(na::norm(&(*inter.normal - na::normalize(&Vector3::new(-1.0, 0.5, 0.0)))) < 1.0e-9);
+}
+
+#[test]
+fn ray_cone_base_hit() {
+    let cone = Cone::new(1.0f64, 1.0);
+    let m    = Isometry3::identity();
+    let ray  = Ray::new(Point3::new(0.2, -5.0, 0.3), Vector3::y());
+
+    let inter = cone.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert!((inter.toi - 4.0).abs() < 1.0e-9);
+    assert!(na::norm(&(*inter.normal + Vector3::y())) < 1.0e-9);
+
+    // From the inside along the axis, the hollow cone is exited at its apex.
+    let ray   = Ray::new(Point3::new(0.0, -0.5, 0.0), Vector3::y());
+    let inter = cone.toi_and_normal_with_ray(&m, &ray, false).unwrap();
+    assert!((inter.toi - 1.5).abs() < 1.0e-9);
+    assert!(cone.toi_with_ray(&m, &ray, true).unwrap() == 0.0);
+}
+
+#[test]
+fn ray_cone_apex_grazing() {
+    let cone = Cone::new(1.0f64, 1.0);
+    let m    = Isometry3::identity();
+    let ray  = Ray::new(Point3::new(-5.0, 1.0, 0.0), Vector3::x());
+
+    let inter = cone.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert!((inter.toi - 5.0).abs() < 1.0e-9);
+    assert!(na::norm(&(*inter.normal - Vector3::y())) < 1.0e-9);
+
+    // Slightly above the apex: no hit.
+    let ray = Ray::new(Point3::new(-5.0, 1.0 + 1.0e-6, 0.0), Vector3::x());
+    assert!(cone.toi_with_ray(&m, &ray, true).is_none());
+}
@@ -0,0 +1,75 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{ConvexHull, Cuboid};
+use ncollide::query::{Ray, RayCast};
+
+fn cube_hull() -> ConvexHull<Point3<f64>> {
+    let mut points = Vec::new();
+
+    for i in 0 .. 8 {
+        let sign = |bit: usize| if i & bit == 0 { -1.0 } else { 1.0 };
+        points.push(Point3::new(sign(1), sign(2), sign(4)));
+    }
+
+    ConvexHull::new(points)
+}
+
+#[test]
+fn cube_hull_ray_casts_match_cuboid() {
+    let hull   = cube_hull();
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m      = Isometry3::new(Vector3::new(0.5, -1.0, 2.0), Vector3::new(0.3, -0.4, 0.2));
+
+    let local_rays = [
+        // Hits on each face.
+        Ray::new(Point3::new(-5.0, 0.2, 0.1), Vector3::x()),
+        Ray::new(Point3::new(0.3, 6.0, -0.4), -Vector3::y()),
+        Ray::new(Point3::new(0.3, -0.2, -4.0), Vector3::new(0.1, 0.05, 1.0)),
+        Ray::new(Point3::new(3.0, 3.0, 3.0), Vector3::new(-1.0, -0.8, -0.9)),
+        // Misses.
+        Ray::new(Point3::new(-5.0, 2.0, 0.0), Vector3::x()),
+        Ray::new(Point3::new(-5.0, 0.0, 0.0), -Vector3::x()),
+        // Starting inside.
+        Ray::new(Point3::new(0.1, 0.2, -0.3), Vector3::new(0.3, 1.0, 0.2))
+    ];
+
+    for local_ray in local_rays.iter() {
+        let ray = local_ray.transform_by(&m);
+
+        for solid in [ true, false ].iter() {
+            let expected = cuboid.toi_and_normal_with_ray(&m, &ray, *solid);
+            let actual   = hull.toi_and_normal_with_ray(&m, &ray, *solid);
+
+            match (expected, actual) {
+                (Some(expected), Some(actual)) => {
+                    assert!((expected.toi - actual.toi).abs() < 1.0e-6,
+                            "toi mismatch: {} != {}", expected.toi, actual.toi);
+
+                    if expected.toi != 0.0 {
+                        assert!(na::norm(&(*expected.normal - *actual.normal)) < 1.0e-6,
+                                "normal mismatch: {} != {}", *expected.normal, *actual.normal);
+                    }
+                },
+                (None, None) => { },
+                (e, a) => panic!("Hit mismatch: {:?} != {:?} {:?} {}", e.map(|i| i.toi), a.map(|i| i.toi), local_ray, solid)
+            }
+        }
+    }
+}
+
+#[test]
+fn ray_tangent_to_cube_hull() {
+    let hull = cube_hull();
+    let m    = Isometry3::identity();
+
+    // Grazing the top face.
+    let ray   = Ray::new(Point3::new(-5.0, 1.0, 0.2), Vector3::x());
+    let inter = hull.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert!((inter.toi - 4.0).abs() < 1.0e-6);
+
+    // Slightly above: no hit.
+    let ray = Ray::new(Point3::new(-5.0, 1.0 + 1.0e-6, 0.2), Vector3::x());
+    assert!(hull.toi_with_ray(&m, &ray, true).is_none());
+}
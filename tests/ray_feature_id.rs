@@ -0,0 +1,100 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+use na::{Isometry2, Isometry3, Point2, Point3, Vector2, Vector3};
+use ncollide::shape::{Ball, Cone, Cuboid, Cylinder, FeatureId, Polyline, SupportMap, TriMesh};
+use ncollide::query::{Ray, RayCast};
+
+#[test]
+fn ray_cuboid_feature_id() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 2.0, 3.0));
+    let m      = Isometry3::new(Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 0.0, std::f64::consts::FRAC_PI_2));
+
+    // The quarter turn maps the local `-y` face to the world `+x` face.
+    let ray   = Ray::new(Point3::new(10.0, 0.0, 0.0), -Vector3::x());
+    let inter = cuboid.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    let face  = [ Point3::new( 1.0, -2.0, -3.0), Point3::new(-1.0, -2.0, -3.0),
+                  Point3::new(-1.0, -2.0,  3.0), Point3::new( 1.0, -2.0,  3.0) ];
+    let face: Vec<_> = face.iter().map(|pt| m * pt).collect();
+
+    assert_eq!(inter.feature, FeatureId::Face((0b101 << 3) | 0b010));
+    assert_eq!(inter.feature, cuboid.support_feature_id(&m, &face));
+    assert_eq!(cuboid.toi_and_normal_and_uv_with_ray(&m, &ray, true).unwrap().feature, inter.feature);
+
+    // The ray starts inside of the solid cuboid.
+    let ray = Ray::new(Point3::new(1.0, 0.0, 0.0), Vector3::x());
+    assert_eq!(cuboid.toi_and_normal_with_ray(&m, &ray, true).unwrap().feature, FeatureId::Unknown);
+    assert_eq!(cuboid.toi_and_normal_with_ray(&m, &ray, false).unwrap().feature, inter.feature);
+}
+
+#[test]
+fn ray_ball_feature_id() {
+    let ball = Ball::new(1.0f64);
+    let ray  = Ray::new(Point2::new(-5.0, 0.0), Vector2::x());
+
+    assert_eq!(ball.toi_and_normal_with_ray(&Isometry2::identity(), &ray, true).unwrap().feature, FeatureId::Face(0));
+}
+
+#[test]
+fn ray_cylinder_feature_id() {
+    let cylinder = Cylinder::new(1.0f64, 1.0);
+    let m        = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), na::zero());
+
+    let cases = [
+        // The lateral surface.
+        (Point3::new(6.0, 2.0, 3.0), -Vector3::x(), FeatureId::Face(0)),
+        // The bottom and top caps.
+        (Point3::new(1.0, -3.0, 3.0), Vector3::y(), FeatureId::Face(1)),
+        (Point3::new(1.0, 7.0, 3.0), -Vector3::y(), FeatureId::Face(2)),
+        // The top rim.
+        (Point3::new(3.0, 4.0, 3.0), Vector3::new(-1.0, -1.0, 0.0), FeatureId::Edge(1))
+    ];
+
+    for &(origin, dir, feature) in cases.iter() {
+        let inter = cylinder.toi_and_normal_with_ray(&m, &Ray::new(origin, dir), true).unwrap();
+        assert_eq!(inter.feature, feature);
+    }
+}
+
+#[test]
+fn ray_cone_feature_id() {
+    let cone = Cone::new(1.0f64, 1.0);
+    let m    = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), na::zero());
+
+    let cases = [
+        // The lateral surface.
+        (Point3::new(6.0, 2.0, 3.0), -Vector3::x(), FeatureId::Face(0)),
+        // The base.
+        (Point3::new(1.0, -3.0, 3.0), Vector3::y(), FeatureId::Face(1)),
+        // The apex.
+        (Point3::new(1.0, 7.0, 3.0), -Vector3::y(), FeatureId::Vertex(0))
+    ];
+
+    for &(origin, dir, feature) in cases.iter() {
+        let inter = cone.toi_and_normal_with_ray(&m, &Ray::new(origin, dir), true).unwrap();
+        assert_eq!(inter.feature, feature);
+    }
+}
+
+#[test]
+fn ray_mesh_feature_id() {
+    let vertices = vec![ Point3::new(0.0f64, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0),
+                         Point3::new(0.0, 1.0, 0.0), Point3::new(1.0, 1.0, 0.0) ];
+    let indices  = vec![ Point3::new(0usize, 1, 2), Point3::new(1, 3, 2) ];
+    let uvs      = vec![ Point2::new(0.0f64, 0.0), Point2::new(1.0, 0.0), Point2::new(0.0, 1.0), Point2::new(1.0, 1.0) ];
+    let mesh     = TriMesh::new(Arc::new(vertices), Arc::new(indices), Some(Arc::new(uvs)), None);
+    let ray      = Ray::new(Point3::new(0.8, 0.8, 1.0), -Vector3::z());
+    let inter    = mesh.toi_and_normal_and_uv_with_ray(&Isometry3::identity(), &ray, true).unwrap();
+
+    assert_eq!(mesh.toi_and_normal_with_ray(&Isometry3::identity(), &ray, true).unwrap().feature, FeatureId::Face(1));
+    assert_eq!(inter.feature, FeatureId::Face(1));
+    assert!(na::distance(&inter.uvs.unwrap(), &Point2::new(0.8, 0.8)) < 1.0e-7);
+
+    let vertices = vec![ Point2::new(0.0f64, 0.0), Point2::new(1.0, 0.0), Point2::new(1.0, 1.0) ];
+    let indices  = vec![ Point2::new(0usize, 1), Point2::new(1, 2) ];
+    let polyline = Polyline::new(Arc::new(vertices), Arc::new(indices), None, None);
+    let ray      = Ray::new(Point2::new(5.0, 0.5), -Vector2::x());
+
+    assert_eq!(polyline.toi_and_normal_with_ray(&Isometry2::identity(), &ray, true).unwrap().feature, FeatureId::Edge(1));
+}
@@ -0,0 +1,42 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Ball, Cuboid};
+use ncollide::query::{Ray, RayCast, PointQuery};
+
+fn check_hit_points_on_surface<S>(shape: &S, m: &Isometry3<f64>)
+    where S: RayCast<Point3<f64>, Isometry3<f64>> + PointQuery<Point3<f64>, Isometry3<f64>> {
+    let center = m.translation.vector;
+    let rays: Vec<Ray<Point3<f64>>> = (0 .. 20).map(|i| {
+        let t = i as f64 * 0.3;
+        let origin = Point3::from_coordinates(center) + Vector3::new(t.cos(), 0.5 * t.sin(), t.sin()) * 5.0;
+
+        Ray::new(origin, na::normalize(&(Point3::from_coordinates(center) - origin)))
+    }).collect();
+
+    let hits = shape.cast_rays_with_points(m, &rays[..], true);
+
+    for (ray, hit) in rays.iter().zip(hits.iter()) {
+        let &(ref inter, ref pt) = hit.as_ref().unwrap();
+        assert_eq!(*pt, inter.point(ray));
+
+        // The hit point is between a point outside of the shape and a point inside of it.
+        assert!(!shape.contains_point(m, &(*pt + ray.dir * -1.0e-6)), "Hit point {} inside of the shape.", pt);
+        assert!(shape.contains_point(m, &(*pt + ray.dir * 1.0e-6)), "Hit point {} outside of the shape.", pt);
+    }
+}
+
+#[test]
+fn ray_hit_points_lie_on_the_surface() {
+    let m = Isometry3::new(Vector3::new(1.0, -2.0, 0.5), Vector3::new(0.3, 0.8, -0.2));
+
+    check_hit_points_on_surface(&Ball::new(1.5), &m);
+
+    let ray = Ray::new(Point3::new(5.0, -2.0, 0.5), -Vector3::x());
+    let pt  = Ball::new(1.5).toi_with_ray(&m, &ray, true).map(|toi| ray.origin + ray.dir * toi).unwrap();
+    let hit = Ball::new(1.5).cast_rays_with_points(&m, &[ ray ], true)[0].as_ref().unwrap().1;
+    assert!(na::distance(&pt, &hit) < 1.0e-7);
+    assert!(na::distance(&Ball::new(1.5).project_point(&m, &hit, false).point, &hit) < 1.0e-7);
+    check_hit_points_on_surface(&Cuboid::new(Vector3::new(1.0, 0.5, 2.0)), &m);
+}
@@ -0,0 +1,46 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Ball, Cuboid, TriMesh};
+use ncollide::query::{Ray, RayCast};
+
+fn assert_normal<S: RayCast<Point3<f64>, Isometry3<f64>>>(shape: &S, ray: &Ray<Point3<f64>>, solid: bool,
+                                                          facing: Vector3<f64>) {
+    let m = Isometry3::new(na::zero(), na::zero());
+
+    let default = shape.toi_and_normal_with_ray(&m, ray, solid).unwrap();
+    let faced   = shape.toi_and_oriented_normal_with_ray(&m, ray, solid, true).unwrap();
+    let outward = shape.toi_and_oriented_normal_with_ray(&m, ray, solid, false).unwrap();
+
+    assert!(na::norm(&(*default.normal - facing)) < 1.0e-7, "Wrong normal: {}", *default.normal);
+    assert!(na::norm(&(*faced.normal - facing)) < 1.0e-7, "Wrong normal: {}", *faced.normal);
+    assert!(na::norm(&(*outward.normal + facing)) < 1.0e-7, "Wrong normal: {}", *outward.normal);
+    assert_eq!(default.toi, outward.toi);
+}
+
+#[test]
+fn flipped_normal_of_triangle_mesh_back_face_hit() {
+    // The outward side of the triangle is along `+y`.
+    let vertices = vec![ Point3::new(-1.0, 0.0, -1.0), Point3::new(0.0, 0.0, 1.0), Point3::new(1.0, 0.0, -1.0) ];
+    let mesh     = TriMesh::new(Arc::new(vertices), Arc::new(vec![ Point3::new(0usize, 1, 2) ]), None, None);
+    let m        = Isometry3::new(na::zero(), na::zero());
+
+    // Front face hit: both orientations agree.
+    let front = Ray::new(Point3::new(0.0, 1.0, 0.0), -Vector3::y());
+    let inter = mesh.toi_and_oriented_normal_with_ray(&m, &front, true, false).unwrap();
+    assert!(na::norm(&(*inter.normal - Vector3::y())) < 1.0e-7);
+
+    // Back face hit.
+    let back = Ray::new(Point3::new(0.0, -1.0, 0.0), Vector3::y());
+    assert_normal(&mesh, &back, true, -Vector3::y());
+}
+
+#[test]
+fn outward_normal_of_rays_leaving_solids() {
+    let ray = Ray::new(Point3::new(0.0, 0.0, 0.0), Vector3::x());
+
+    assert_normal(&Ball::new(1.0), &ray, false, -Vector3::x());
+    assert_normal(&Cuboid::new(Vector3::new(1.0, 1.0, 1.0)), &ray, false, -Vector3::x());
+}
@@ -0,0 +1,66 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Ball, Capsule};
+use ncollide::query::{Ray, RayCast};
+
+#[test]
+fn ray_inside_ball() {
+    let ball = Ball::new(2.0f64);
+    let m    = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), na::zero());
+    let ray  = Ray::new(Point3::new(1.5, 2.0, 3.0), Vector3::x());
+
+    let solid = ball.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert_eq!(solid.toi, 0.0);
+
+    let hollow = ball.toi_and_normal_with_ray(&m, &ray, false).unwrap();
+    assert!((hollow.toi - 1.5).abs() < 1.0e-9);
+    assert!(na::norm(&(*hollow.normal + Vector3::x())) < 1.0e-9);
+}
+
+#[test]
+fn ray_inside_capsule() {
+    let capsule = Capsule::new(1.0f64, 0.5);
+    let m       = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), na::zero());
+
+    // Through the cylindrical body.
+    let ray = Ray::new(Point3::new(1.0, 2.5, 3.0), Vector3::x());
+
+    let solid = capsule.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert_eq!(solid.toi, 0.0);
+
+    let hollow = capsule.toi_and_normal_with_ray(&m, &ray, false).unwrap();
+    assert!((hollow.toi - 0.5).abs() < 1.0e-9);
+    assert!(na::norm(&(*hollow.normal + Vector3::x())) < 1.0e-9);
+
+    // Through the top cap.
+    let ray = Ray::new(Point3::new(1.0, 2.0, 3.0), Vector3::y());
+
+    let hollow = capsule.toi_and_normal_with_ray(&m, &ray, false).unwrap();
+    assert!((hollow.toi - 1.5).abs() < 1.0e-9);
+    assert!(na::norm(&(*hollow.normal + Vector3::y())) < 1.0e-9);
+}
+
+#[test]
+fn ray_tangent_to_ball_and_capsule() {
+    let m = Isometry3::identity();
+
+    let ball = Ball::new(1.0f64);
+    let ray  = Ray::new(Point3::new(-5.0, 1.0, 0.0), Vector3::x());
+
+    let inter = ball.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert!((inter.toi - 5.0).abs() < 1.0e-9);
+    assert!(na::norm(&(*inter.normal - Vector3::y())) < 1.0e-9);
+
+    let capsule = Capsule::new(1.0f64, 0.5);
+    let ray     = Ray::new(Point3::new(-5.0, 0.3, 0.5), Vector3::x());
+
+    let inter = capsule.toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert!((inter.toi - 5.0).abs() < 1.0e-9);
+    assert!(na::norm(&(*inter.normal - Vector3::z())) < 1.0e-9);
+
+    // Slightly further away: no hit.
+    let ray = Ray::new(Point3::new(-5.0, 1.0 + 1.0e-6, 0.0), Vector3::x());
+    assert!(ball.toi_with_ray(&m, &ray, true).is_none());
+}
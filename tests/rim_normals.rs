@@ -0,0 +1,66 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Cone, Cylinder, FeatureId, SupportMap};
+use ncollide::query::{Ray, RayCast};
+
+#[test]
+fn ray_hitting_the_rim_of_a_cone() {
+    let cone = Cone::new(1.0f64, 1.0);
+    let m    = Isometry3::new(Vector3::new(0.5, -1.0, 2.0), Vector3::new(0.3, 0.1, -0.4));
+
+    // The rim contains the point `(1, -1, 0)`, where the lateral normal is `(2, 1, 0) / sqrt(5)`
+    // and the base normal is `(0, -1, 0)`.
+    let ls_ray = Ray::new(Point3::new(2.0, -2.0, 0.0), Vector3::new(-1.0, 1.0, 0.0));
+    let ray    = Ray::new(m * ls_ray.origin, m * ls_ray.dir);
+    let inter  = cone.toi_and_normal_with_ray(&m, &ray, true).expect("Missing cone ray hit.");
+
+    let lateral  = Vector3::new(2.0, 1.0, 0.0).normalize();
+    let expected = (lateral - Vector3::y()).normalize();
+
+    assert!((inter.toi - 1.0).abs() < 1.0e-9);
+    assert!(na::norm(&(*inter.normal - m * expected)) < 1.0e-9);
+
+    let pt = ray.origin + ray.dir * inter.toi;
+    assert_eq!(cone.support_feature_id(&m, &[ pt ]), FeatureId::Edge(0));
+
+    // Away from the rim, the normals are those of the lateral surface and of the base.
+    let side = Ray::new(m * Point3::new(2.0, -0.5, 0.0), m * -Vector3::x());
+    let base = Ray::new(m * Point3::new(0.2, -3.0, 0.1), m * Vector3::y());
+
+    let inter = cone.toi_and_normal_with_ray(&m, &side, true).unwrap();
+    assert!(na::norm(&(*inter.normal - m * lateral)) < 1.0e-9);
+    assert_eq!(cone.support_feature_id(&m, &[ side.origin + side.dir * inter.toi ]), FeatureId::Face(0));
+
+    let inter = cone.toi_and_normal_with_ray(&m, &base, true).unwrap();
+    assert!(na::norm(&(*inter.normal + m * Vector3::y())) < 1.0e-9);
+    assert_eq!(cone.support_feature_id(&m, &[ base.origin + base.dir * inter.toi ]), FeatureId::Face(1));
+}
+
+#[test]
+fn ray_hitting_the_rim_of_a_cylinder() {
+    let cylinder = Cylinder::new(1.0f64, 1.0);
+    let m        = Isometry3::new(Vector3::new(-1.0, 0.5, 0.0), Vector3::new(-0.2, 0.4, 0.3));
+
+    let ls_ray = Ray::new(Point3::new(0.0, 3.0, 3.0), Vector3::new(0.0, -1.0, -1.0));
+    let ray    = Ray::new(m * ls_ray.origin, m * ls_ray.dir);
+    let inter  = cylinder.toi_and_normal_with_ray(&m, &ray, true).expect("Missing cylinder ray hit.");
+
+    let expected = Vector3::new(0.0, 1.0, 1.0).normalize();
+
+    assert!((inter.toi - 2.0).abs() < 1.0e-9);
+    assert!(na::norm(&(*inter.normal - m * expected)) < 1.0e-9);
+
+    let pt = ray.origin + ray.dir * inter.toi;
+    assert_eq!(cylinder.support_feature_id(&m, &[ pt ]), FeatureId::Edge(1));
+
+    // The bottom rim, and the hollow exit through the bottom cap.
+    let bottom = m * Point3::new(-1.0, -1.0, 0.0);
+    assert_eq!(cylinder.support_feature_id(&m, &[ bottom ]), FeatureId::Edge(0));
+
+    let exit  = Ray::new(m * Point3::new(0.1, 0.0, 0.2), m * -Vector3::y());
+    let inter = cylinder.toi_and_normal_with_ray(&m, &exit, false).unwrap();
+    assert!((inter.toi - 1.0).abs() < 1.0e-9);
+    assert!(na::norm(&(*inter.normal - m * Vector3::y())) < 1.0e-9);
+}
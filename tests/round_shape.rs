@@ -0,0 +1,97 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Ball, Cuboid, RoundShape, Segment, Shape, SupportMap};
+use ncollide::bounding_volume;
+use ncollide::query::{self, PointQuery, Ray, RayCast};
+
+#[test]
+fn round_point_segment_is_a_ball() {
+    let pt    = Point3::<f64>::origin();
+    let round = RoundShape::new(Segment::new(pt, pt), 0.5f64);
+    let ball  = Ball::new(0.5);
+    let m     = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+
+    for dir in [ Vector3::x(), Vector3::new(1.0, -2.0, 0.5), -Vector3::z() ].iter() {
+        assert!(na::distance(&round.support_point(&m, dir), &ball.support_point(&m, dir)) < 1.0e-7);
+    }
+
+    let aabb1 = bounding_volume::aabb(&round, &m);
+    let aabb2 = bounding_volume::aabb(&ball, &m);
+    assert!(na::distance(aabb1.mins(), aabb2.mins()) < 1.0e-7);
+    assert!(na::distance(aabb1.maxs(), aabb2.maxs()) < 1.0e-7);
+
+    let ray  = Ray::new(Point3::new(1.0, 2.0, -3.0), Vector3::z());
+    let toi1 = round.toi_with_ray(&m, &ray, true).unwrap();
+    let toi2 = ball.toi_with_ray(&m, &ray, true).unwrap();
+    assert!((toi1 - toi2).abs() < 1.0e-5);
+
+    let m2 = Isometry3::new(Vector3::new(1.8, 2.0, 3.0), na::zero());
+    let d1 = query::distance(&m, &round, &m2, &ball);
+    let d2 = query::distance(&m, &ball, &m2, &ball);
+    assert!((d1 - d2).abs() < 1.0e-7);
+}
+
+#[test]
+fn round_cuboid_is_loosened() {
+    let round = RoundShape::new(Cuboid::new(Vector3::new(1.0, 2.0, 3.0)), 0.5);
+    let aabb  = bounding_volume::aabb(&round, &Isometry3::identity());
+
+    assert_eq!(*aabb.maxs(), Point3::new(1.5, 2.5, 3.5));
+    let dir     = Vector3::new(1.0, 1.0, 1.0);
+    let support = round.support_point(&Isometry3::identity(), &dir);
+    assert!(na::distance(&support, &(Point3::new(1.0, 2.0, 3.0) + na::normalize(&dir) * 0.5)) < 1.0e-7);
+}
+
+#[test]
+fn round_cuboid_point_projection() {
+    let round = RoundShape::new(Cuboid::new(Vector3::new(1.0f64, 2.0, 3.0)), 0.5);
+    let m     = Isometry3::new(Vector3::new(1.0, 0.0, 0.0), na::zero());
+
+    // Near a vertex, the projection is on the sphere around it.
+    let proj = round.project_point(&m, &Point3::new(4.0, 4.0, 4.0), true);
+    let dir  = na::normalize(&Vector3::new(2.0, 2.0, 1.0));
+    assert!(!proj.is_inside);
+    assert!(na::distance(&proj.point, &(Point3::new(2.0, 2.0, 3.0) + dir * 0.5)) < 1.0e-7);
+
+    // Within the border.
+    let pt = Point3::new(2.2, 0.0, 0.0);
+    assert_eq!(round.project_point(&m, &pt, true).point, pt);
+    let proj = round.project_point(&m, &pt, false);
+    assert!(proj.is_inside);
+    assert!(na::distance(&proj.point, &Point3::new(2.5, 0.0, 0.0)) < 1.0e-7);
+
+    // Inside of the inner cuboid.
+    let proj = round.project_point(&m, &Point3::new(1.5, 0.0, 2.9), false);
+    assert!(proj.is_inside);
+    assert!(na::distance(&proj.point, &Point3::new(1.5, 0.0, 3.5)) < 1.0e-7);
+
+    // The projection is available through the `Shape` trait too.
+    let shape: &Shape<Point3<f64>, Isometry3<f64>> = &round;
+    let dist = shape.as_point_query().unwrap().distance_to_point(&m, &Point3::new(1.0, 5.0, 0.0), true);
+    assert!((dist - 2.5).abs() < 1.0e-7);
+}
+
+#[test]
+fn round_cuboid_point_projection_on_inner_boundary() {
+    let round = RoundShape::new(Cuboid::new(Vector3::new(1.0f64, 2.0, 3.0)), 0.5);
+    let m     = Isometry3::new(Vector3::new(1.0, 0.0, 0.0), na::zero());
+    let pt    = Point3::new(2.0, 0.0, 0.0);
+
+    let proj = round.project_point(&m, &pt, false);
+    assert!(proj.is_inside);
+    assert!(proj.point.x.is_finite() && proj.point.y.is_finite() && proj.point.z.is_finite());
+    assert!(na::distance(&proj.point, &Point3::new(2.5, 0.0, 0.0)) < 1.0e-5);
+}
+
+#[test]
+fn round_cuboids_contact() {
+    let round = RoundShape::new(Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0)), 0.5);
+    let m1    = Isometry3::identity();
+    let m2    = Isometry3::new(Vector3::new(0.0, 2.8, 0.0), na::zero());
+
+    let contact = query::contact(&m1, &round, &m2, &round, 0.0).unwrap();
+    assert!((contact.depth - 0.2).abs() < 1.0e-5);
+    assert!((*contact.normal - Vector3::y()).norm() < 1.0e-5);
+}
@@ -0,0 +1,49 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Vector3};
+use ncollide::shape::{Ball, Cuboid, Ellipsoid, Plane, Scaled, SupportMap};
+use ncollide::bounding_volume;
+use ncollide::query;
+
+#[test]
+fn scaled_cube_against_plane() {
+    let plane  = Plane::new(Vector3::y());
+    let scaled = Scaled::new_uniform(Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0)), 2.0);
+    let cuboid = Cuboid::new(Vector3::new(2.0f64, 2.0, 2.0));
+    let mp     = Isometry3::new(na::zero(), na::zero());
+    let m      = Isometry3::new(Vector3::new(0.5, 1.5, -0.2), Vector3::new(0.2, 0.3, 0.1));
+
+    let c1 = query::contact(&mp, &plane, &m, &scaled, 0.0).unwrap();
+    let c2 = query::contact(&mp, &plane, &m, &cuboid, 0.0).unwrap();
+
+    assert!((c1.depth - c2.depth).abs() < 1.0e-7);
+    assert!(na::norm(&(*c1.normal - *c2.normal)) < 1.0e-7);
+    assert!(na::distance(&c1.world1, &c2.world1) < 1.0e-7);
+    assert!(na::distance(&c1.world2, &c2.world2) < 1.0e-7);
+
+    let aabb1 = bounding_volume::aabb(&scaled, &m);
+    let aabb2 = bounding_volume::aabb(&cuboid, &m);
+    assert!(na::distance(aabb1.mins(), aabb2.mins()) < 1.0e-7);
+    assert!(na::distance(aabb1.maxs(), aabb2.maxs()) < 1.0e-7);
+}
+
+#[test]
+fn non_uniformly_scaled_ball_is_an_ellipsoid() {
+    let radii     = Vector3::new(1.0f64, 2.0, 0.5);
+    let scaled    = Scaled::new(Ball::new(1.0f64), radii);
+    let ellipsoid = Ellipsoid::new(radii);
+    let m         = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, -0.2, 0.3));
+
+    for dir in [ Vector3::x(), Vector3::new(1.0, -2.0, 0.5), Vector3::new(-0.3, 0.1, 1.0) ].iter() {
+        let pt1 = scaled.support_point(&m, dir);
+        let pt2 = ellipsoid.support_point(&m, dir);
+        assert!(na::distance(&pt1, &pt2) < 1.0e-7);
+    }
+
+    let ball = Ball::new(0.5f64);
+    let mb   = Isometry3::new(Vector3::new(1.0, 2.0, 3.0) + m * Vector3::new(0.0, 2.4, 0.0), na::zero());
+    let d1   = query::distance(&m, &scaled, &mb, &ball);
+    let d2   = query::distance(&m, &ellipsoid, &mb, &ball);
+    assert!((d1 - d2).abs() < 1.0e-5);
+}
@@ -0,0 +1,61 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Point3, Vector3, Isometry3};
+use ncollide::shape::{Cone, Ball, Cuboid, Capsule};
+use ncollide::bounding_volume::{self, AABB, BoundingVolume, HasBoundingVolume};
+
+trait Shape3: HasBoundingVolume<Isometry3<f64>, AABB<Point3<f64>>> {
+}
+
+impl<T> Shape3 for T
+    where T: HasBoundingVolume<Isometry3<f64>, AABB<Point3<f64>>> {
+}
+
+#[test]
+fn scene_aabb_encloses_all_shapes() {
+    let ball = Ball::new(0.5);
+    let caps = Capsule::new(0.5, 0.75);
+    let cone = Cone::new(0.5, 0.75);
+    let cube = Cuboid::new(Vector3::new(1.0, 0.5, 1.0));
+
+    let shapes = [
+        &ball as &Shape3,
+        &caps as &Shape3,
+        &cone as &Shape3,
+        &cube as &Shape3
+    ];
+
+    let poss = [
+        Isometry3::new(Vector3::new(0.0, 0.0, 1.0), na::zero()),
+        Isometry3::new(Vector3::new(0.0, 0.0, 2.0), na::zero()),
+        Isometry3::new(Vector3::new(0.0, 0.0, 3.0), na::zero()),
+        Isometry3::new(Vector3::new(0.0, 2.0, 4.0), na::zero())
+    ];
+
+    let scene = bounding_volume::scene_aabb(poss.iter().zip(shapes.iter().map(|s| *s))).unwrap();
+
+    for (m, shape) in poss.iter().zip(shapes.iter()) {
+        assert!(scene.contains(&shape.bounding_volume(m)));
+    }
+
+    assert_eq!(*scene.mins(), Point3::new(-1.0, -1.25, 0.5));
+    assert_eq!(*scene.maxs(), Point3::new(1.0, 2.5, 5.0));
+}
+
+#[test]
+fn scene_aabb_of_no_shape() {
+    let shapes: Vec<(Isometry3<f64>, Ball<f64>)> = Vec::new();
+
+    assert!(bounding_volume::scene_aabb::<Point3<f64>, _, _, _>(shapes.iter().map(|&(ref m, ref s)| (m, s))).is_none());
+}
+
+#[test]
+fn aabb_merge_point() {
+    let mut aabb = AABB::new(Point3::new(0.0f64, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0));
+
+    aabb.merge_point(&Point3::new(2.0, -1.0, 0.5));
+
+    assert_eq!(*aabb.mins(), Point3::new(0.0, -1.0, 0.0));
+    assert_eq!(*aabb.maxs(), Point3::new(2.0, 1.0, 1.0));
+}
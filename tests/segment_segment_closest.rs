@@ -0,0 +1,59 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::Point3;
+use ncollide::query;
+
+fn assert_closest(a1: Point3<f64>, a2: Point3<f64>, b1: Point3<f64>, b2: Point3<f64>,
+                  expected_a: Point3<f64>, expected_b: Point3<f64>) {
+    let (pa, pb) = query::segment_segment_closest(&a1, &a2, &b1, &b2);
+
+    assert!(na::distance(&pa, &expected_a) < 1.0e-12, "{:?} != {:?}", pa, expected_a);
+    assert!(na::distance(&pb, &expected_b) < 1.0e-12, "{:?} != {:?}", pb, expected_b);
+
+    // Swapping the segments swaps the closest points, up to the choice among parallel segments.
+    let (qb, qa) = query::segment_segment_closest(&b1, &b2, &a1, &a2);
+    assert!((na::distance(&qa, &qb) - na::distance(&pa, &pb)).abs() < 1.0e-12);
+}
+
+#[test]
+fn crossing_segments() {
+    assert_closest(Point3::new(-1.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0),
+                   Point3::new(0.0, -1.0, 1.0), Point3::new(0.0, 1.0, 1.0),
+                   Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 0.0, 1.0));
+}
+
+#[test]
+fn endpoint_against_interior() {
+    assert_closest(Point3::new(2.0, -1.0, 0.0), Point3::new(2.0, 1.0, 0.0),
+                   Point3::new(0.0, 0.0, 1.0), Point3::new(1.0, 0.0, 1.0),
+                   Point3::new(2.0, 0.0, 0.0), Point3::new(1.0, 0.0, 1.0));
+}
+
+#[test]
+fn parallel_segments() {
+    // Overlapping.
+    assert_closest(Point3::new(0.0, 0.0, 0.0), Point3::new(2.0, 0.0, 0.0),
+                   Point3::new(1.0, 1.0, 0.0), Point3::new(3.0, 1.0, 0.0),
+                   Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 1.0, 0.0));
+
+    // Disjoint.
+    assert_closest(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0),
+                   Point3::new(3.0, 1.0, 0.0), Point3::new(5.0, 1.0, 0.0),
+                   Point3::new(1.0, 0.0, 0.0), Point3::new(3.0, 1.0, 0.0));
+}
+
+#[test]
+fn degenerate_segments() {
+    let pt = Point3::new(0.5, 2.0, 0.0);
+
+    assert_closest(pt, pt,
+                   Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0),
+                   pt, Point3::new(0.5, 0.0, 0.0));
+
+    assert_closest(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0),
+                   pt, pt,
+                   Point3::new(0.5, 0.0, 0.0), pt);
+
+    assert_closest(pt, pt, Point3::origin(), Point3::origin(), pt, Point3::origin());
+}
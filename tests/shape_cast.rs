@@ -0,0 +1,57 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Vector3};
+use ncollide::bounding_volume::{self, AABB3};
+use ncollide::partitioning::BVT;
+use ncollide::shape::{Ball, Capsule, Cuboid, ShapeHandle3};
+use ncollide::query;
+
+#[test]
+fn capsule_cast_against_cuboid() {
+    let capsule = Capsule::new(0.5f64, 0.25);
+    let cuboid  = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m1      = Isometry3::identity();
+    let m2      = Isometry3::new(Vector3::new(3.0, 0.0, 0.0), na::zero());
+    let vel     = Vector3::new(2.0, 0.0, 0.0);
+
+    let res = query::shape_cast(&m1, &vel, &capsule, &m2, &cuboid, 1.0).expect("The capsule must hit the cuboid.");
+
+    assert!((res.toi - 0.875).abs() < 1.0e-5);
+    assert!((*res.normal - Vector3::x()).norm() < 1.0e-5);
+    assert!((res.witness1.x - 2.0).abs() < 1.0e-5);
+    assert!((res.witness2.x - 2.0).abs() < 1.0e-5);
+
+    // The motion stops before the contact.
+    assert!(query::shape_cast(&m1, &vel, &capsule, &m2, &cuboid, 0.5).is_none());
+    assert!(query::shape_cast(&m1, &-vel, &capsule, &m2, &cuboid, 1.0).is_none());
+}
+
+#[test]
+fn ball_cast_through_bvt_of_shapes() {
+    let shapes = vec![
+        (Isometry3::new(Vector3::new(0.0, 0.0, 2.0), na::zero()), ShapeHandle3::new(Ball::new(0.5f64))),
+        (Isometry3::new(Vector3::new(0.0, 0.0, 4.0), na::zero()), ShapeHandle3::new(Cuboid::new(Vector3::new(1.0f64, 1.0, 0.5)))),
+        (Isometry3::new(Vector3::new(0.0, 5.0, 2.0), na::zero()), ShapeHandle3::new(Ball::new(0.5f64)))
+    ];
+
+    let leaves: Vec<(usize, AABB3<f64>)> =
+        shapes.iter().enumerate().map(|(i, s)| (i, bounding_volume::aabb(s.1.as_ref(), &s.0))).collect();
+    let bvt = BVT::new_balanced(leaves);
+
+    let ball = Ball::new(0.25f64);
+    let vel  = Vector3::z();
+
+    // Along the axis, the first ball is hit first.
+    let m        = Isometry3::identity();
+    let (i, res) = query::bvt_shape_cast(&bvt, &m, &vel, &ball, 10.0, |i| shapes[*i].clone()).unwrap();
+    assert_eq!(*i, 0);
+    assert!((res.toi - 1.25).abs() < 1.0e-5);
+
+    // Next to the first ball, the cuboid is hit, but only if the sweep is long enough.
+    let m        = Isometry3::new(Vector3::new(0.9, 0.0, 0.0), na::zero());
+    let (i, res) = query::bvt_shape_cast(&bvt, &m, &vel, &ball, 10.0, |i| shapes[*i].clone()).unwrap();
+    assert_eq!(*i, 1);
+    assert!((res.toi - 3.25).abs() < 1.0e-5);
+    assert!(query::bvt_shape_cast(&bvt, &m, &vel, &ball, 3.0, |i| shapes[*i].clone()).is_none());
+}
@@ -0,0 +1,156 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry2, Isometry3, Point2, Point3, Vector2, Vector3};
+use ncollide::shape::{Ball, Compound, ConvexHull, ConvexPolygon, Cuboid, Ellipsoid, MinkowskiSum, Plane, RoundShape,
+                      Segment, ShapeHandle2, ShapeHandle3, Torus, WithMargin};
+use ncollide::transformation::{self, ToPolyline, ToTriMesh};
+
+#[test]
+fn convex_hull_discretization() {
+    let points = vec![ Point3::new(0.0f64, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0),
+                       Point3::new(0.0, 0.0, 1.0), Point3::new(0.1, 0.1, 0.1) ];
+    let mesh   = ConvexHull::new(points).to_trimesh(());
+
+    // The inner point is not part of the hull.
+    assert_eq!(mesh.coords.len(), 4);
+    assert_eq!(mesh.num_triangles(), 4);
+
+    let points = vec![ Point2::new(0.0f64, 0.0), Point2::new(1.0, 0.0), Point2::new(0.2, 0.2), Point2::new(0.0, 1.0) ];
+    assert_eq!(ConvexHull::new(points.clone()).to_polyline(()).coords().len(), 3);
+
+    let polygon = ConvexPolygon::from_ccw_points(&[ points[0], points[1], points[3] ]);
+    assert_eq!(polygon.to_polyline(()).coords(), polygon.points());
+}
+
+#[test]
+fn minkowski_sum_discretization() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0));
+    let m1     = Isometry3::new(Vector3::new(1.0, 0.0, 0.0), na::zero());
+    let m2     = Isometry3::new(Vector3::new(0.0, 2.0, 0.0), na::zero());
+    let mesh   = MinkowskiSum::new(&m1, &cuboid, &m2, &cuboid).to_trimesh(((), ()));
+
+    // The sum of two cubes is a cube twice as large, translated by the sum of their translations.
+    assert_eq!(mesh.coords.len(), 8);
+
+    for pt in mesh.coords.iter() {
+        assert!((pt.x - 1.0).abs() == 2.0 && (pt.y - 2.0).abs() == 2.0 && pt.z.abs() == 2.0);
+    }
+
+    let square = Cuboid::new(Vector2::new(1.0f64, 0.5));
+    let m      = Isometry2::new(Vector2::new(3.0, 0.0), na::zero());
+    let poly   = MinkowskiSum::new(&m, &square, &Isometry2::identity(), &square).to_polyline(((), ()));
+
+    assert_eq!(poly.coords().len(), 4);
+    assert!(poly.coords().iter().all(|pt| (pt.x - 3.0).abs() == 2.0 && pt.y.abs() == 1.0));
+}
+
+#[test]
+fn compound_discretization() {
+    let delta    = Isometry3::new(Vector3::new(5.0, 0.0, 0.0), na::zero());
+    let parts    = vec![ (Isometry3::identity(), ShapeHandle3::new(Ball::new(1.0f64))),
+                         (delta, ShapeHandle3::new(Cuboid::new(Vector3::new(1.0, 1.0, 1.0)))) ];
+    let compound = Compound::new(parts.clone());
+
+    let ball       = Ball::new(1.0f64).to_trimesh((8, 8));
+    let mut cuboid = Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0)).to_trimesh(());
+    cuboid.unify_index_buffer();
+
+    let mesh = compound.to_trimesh(8);
+    assert_eq!(mesh.num_triangles(), ball.num_triangles() + cuboid.num_triangles());
+    assert_eq!(mesh.coords.len(), ball.coords.len() + cuboid.coords.len());
+
+    // The vertices of the cuboid are transformed.
+    assert!(mesh.coords[ball.coords.len() ..].iter().all(|pt| (pt.x - 5.0).abs() == 1.0));
+
+    let ncoords = mesh.coords.len();
+    let indices = mesh.indices.unwrap_unified();
+    assert!(indices.iter().all(|t| (t.x as usize) < ncoords && (t.y as usize) < ncoords && (t.z as usize) < ncoords));
+
+    // A plane cannot be discretized.
+    let mut parts = parts;
+    parts.push((delta, ShapeHandle3::new(Plane::new(Vector3::y()))));
+    assert_eq!(transformation::compound_to_trimesh(&Compound::new(parts), 8).err(), Some(2));
+}
+
+#[test]
+fn compound_discretization_2d() {
+    let delta    = Isometry2::new(Vector2::new(5.0, 0.0), na::zero());
+    let square   = ShapeHandle2::new(Cuboid::new(Vector2::new(1.0f64, 1.0)));
+    let inner    = Compound::new(vec![ (Isometry2::identity(), square.clone()), (delta, square.clone()) ]);
+    let parts    = vec![ (Isometry2::identity(), ShapeHandle2::new(Ball::new(1.0f64))),
+                         (delta, ShapeHandle2::new(inner)) ];
+    let compound = Compound::new(parts.clone());
+
+    let polylines = transformation::compound_to_polylines(&compound, 16).unwrap();
+    assert_eq!(polylines.len(), 3);
+    assert_eq!(polylines[0].coords().len(), 16);
+    assert!(polylines[1].coords().iter().all(|pt| (pt.x - 5.0).abs() == 1.0));
+    assert!(polylines[2].coords().iter().all(|pt| (pt.x - 10.0).abs() == 1.0));
+
+    // Each loop is closed by repeating its first point.
+    let polyline = compound.to_polyline(16);
+    assert_eq!(polyline.coords().len(), 17 + 5 + 5);
+    assert_eq!(polyline.coords()[16], polyline.coords()[0]);
+
+    let mut parts = parts;
+    parts.push((delta, ShapeHandle2::new(Plane::new(Vector2::y()))));
+    assert_eq!(transformation::compound_to_polylines(&Compound::new(parts), 16).err(), Some(2));
+}
+
+#[test]
+fn ellipsoid_and_torus_discretization() {
+    let ellipsoid = Ellipsoid::new(Vector3::new(1.0f64, 2.0, 3.0));
+    let mesh      = ellipsoid.to_trimesh((16, 16));
+
+    for (pt, n) in mesh.coords.iter().zip(mesh.normals.as_ref().unwrap().iter()) {
+        // The points are on the ellipsoid, and the normals are the normalized gradients there.
+        let grad = Vector3::new(pt.x, pt.y / 4.0, pt.z / 9.0);
+        assert!((pt.x * pt.x + pt.y * pt.y / 4.0 + pt.z * pt.z / 9.0 - 1.0).abs() < 1.0e-9);
+        assert!((na::normalize(&grad) - *n).norm() < 1.0e-9);
+    }
+
+    let ellipse = Ellipsoid::new(Vector2::new(1.0f64, 2.0)).to_polyline(16);
+    assert!(ellipse.coords().iter().all(|pt| (pt.x * pt.x + pt.y * pt.y / 4.0 - 1.0).abs() < 1.0e-9));
+
+    let torus = Torus::new(2.0f64, 0.5).to_trimesh((16, 8));
+    assert_eq!(torus.coords.len(), 16 * 8);
+    assert_eq!(torus.num_triangles(), 2 * 16 * 8);
+
+    for pt in torus.coords.iter() {
+        let d = (pt.x * pt.x + pt.z * pt.z).sqrt() - 2.0;
+        assert!((d * d + pt.y * pt.y - 0.25).abs() < 1.0e-9);
+    }
+
+    // The triangles are counterclockwise seen from outside.
+    let indices = torus.indices.unwrap_unified();
+    for t in indices.iter() {
+        let (a, b, c) = (torus.coords[t.x as usize], torus.coords[t.y as usize], torus.coords[t.z as usize]);
+        let normal    = (b - a).cross(&(c - a));
+        let center    = Vector3::new(a.x, 0.0, a.z).normalize() * 2.0;
+
+        assert!(normal.dot(&(a.coords - center)) > 0.0);
+    }
+}
+
+#[test]
+fn round_shape_and_segment_discretization() {
+    let round = RoundShape::new(Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0)), 0.5);
+    let mesh  = round.to_trimesh((16, 16));
+
+    // Every vertex is at a distance 0.5 from the cube.
+    for pt in mesh.coords.iter() {
+        let outside = Vector3::new(pt.x.abs() - 1.0, pt.y.abs() - 1.0, pt.z.abs() - 1.0);
+        let outside = Vector3::new(outside.x.max(0.0), outside.y.max(0.0), outside.z.max(0.0));
+        assert!((outside.norm() - 0.5).abs() < 1.0e-9);
+    }
+
+    let square  = ShapeHandle2::new(Cuboid::new(Vector2::new(1.0f64, 1.0)));
+    let margin  = WithMargin::new(square, 0.5).to_polyline(16);
+    assert!(margin.coords().iter().all(|pt| pt.x.abs() <= 1.5 && pt.y.abs() <= 1.5));
+    assert!(margin.coords().iter().any(|pt| pt.x == 1.5));
+
+    let segment = Segment::new(Point3::new(0.0f64, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0)).to_trimesh(());
+    assert_eq!(segment.coords.len(), 2);
+    assert_eq!(segment.num_triangles(), 1);
+}
@@ -0,0 +1,50 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::collections::HashSet;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use na::Vector3;
+use ncollide::shape::{Ball, Cuboid, Cone, Capsule};
+use ncollide::utils;
+
+fn hash<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn equal_balls_hash_identically() {
+    let b1 = Ball::new(0.5f64);
+    let b2 = Ball::new(0.5f64);
+
+    assert_eq!(b1, b2);
+    assert_eq!(hash(&b1), hash(&b2));
+    assert!(b1 != Ball::new(0.5 + 1.0e-12));
+}
+
+#[test]
+fn shapes_as_cache_keys() {
+    let mut cuboids = HashSet::new();
+    assert!(cuboids.insert(Cuboid::new(Vector3::new(1.0f64, 0.0, 2.0))));
+    assert!(!cuboids.insert(Cuboid::new(Vector3::new(1.0f64, -0.0, 2.0))));
+    assert!(cuboids.insert(Cuboid::new(Vector3::new(2.0f64, 0.0, 1.0))));
+
+    assert_eq!(hash(&Cone::new(1.0f64, 0.5)), hash(&Cone::new(1.0f64, 0.5)));
+    assert!(Cone::new(1.0f64, 0.5) != Cone::new(0.5, 1.0));
+    assert_eq!(hash(&Capsule::new(1.0f32, 0.5)), hash(&Capsule::new(1.0f32, 0.5)));
+}
+
+#[test]
+fn real_bits_are_reflexive_and_ignore_the_sign_of_zero() {
+    let nan = ::std::f64::NAN;
+
+    assert_eq!(utils::real_bits(0.0f64), utils::real_bits(-0.0f64));
+    assert_eq!(utils::real_bits(nan), utils::real_bits(nan));
+    assert_eq!(utils::real_bits(nan), utils::real_bits(-nan));
+    assert_eq!(utils::real_bits(0.5f32), utils::real_bits(0.5f64));
+    assert!(utils::real_bits(0.5f64) != utils::real_bits(-0.5f64));
+
+    assert_eq!(Cuboid::new(Vector3::new(1.0f64, 0.0, 2.0)), Cuboid::new(Vector3::new(1.0f64, -0.0, 2.0)));
+}
@@ -0,0 +1,35 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Ball, Compound, ShapeHandle, TriMesh};
+
+#[test]
+fn trimesh_clone_shares_buffers() {
+    let vertices = vec![ Point3::new(0.0f64, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0),
+                         Point3::new(0.0, 1.0, 0.0), Point3::new(0.0, 0.0, 1.0) ];
+    let indices  = vec![ Point3::new(0usize, 1, 2), Point3::new(0, 1, 3), Point3::new(0, 2, 3) ];
+    let mesh     = TriMesh::new(Arc::new(vertices), Arc::new(indices), None, None);
+
+    assert_eq!(Arc::strong_count(mesh.vertices()), 1);
+
+    let clone = mesh.clone();
+
+    assert_eq!(Arc::strong_count(mesh.vertices()), 2);
+    assert_eq!(Arc::strong_count(mesh.indices()), 2);
+    assert!(mesh.bvt() as *const _ == clone.bvt() as *const _);
+    assert!(mesh.bounding_volumes().as_ptr() == clone.bounding_volumes().as_ptr());
+}
+
+#[test]
+fn compound_clone_shares_parts() {
+    let ball   = ShapeHandle::new(Ball::new(1.0f64));
+    let parts  = vec![ (Isometry3::new(na::zero(), na::zero()), ball.clone()),
+                       (Isometry3::new(Vector3::x() * 3.0, na::zero()), ball) ];
+    let shape  = Compound::new(parts);
+    let clone  = shape.clone();
+
+    assert!(shape.shapes().as_ptr() == clone.shapes().as_ptr());
+    assert!(shape.bvt() as *const _ == clone.bvt() as *const _);
+}
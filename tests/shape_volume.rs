@@ -0,0 +1,45 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::f64::consts::PI;
+use na::{Isometry2, Point2, Point3, Vector2, Vector3};
+use ncollide::shape::{Ball, Cone, Cuboid, SupportMap};
+use ncollide::volumetric;
+
+#[test]
+fn inherent_volumes_match_free_functions() {
+    let ball = Ball::new(0.7f64);
+    assert_eq!(ball.volume::<Point2<f64>>(), volumetric::ball_volume(0.7, 2));
+    assert_eq!(ball.volume::<Point3<f64>>(), volumetric::ball_volume(0.7, 3));
+
+    let cuboid2 = Cuboid::new(Vector2::new(1.0f64, 0.5));
+    let cuboid3 = Cuboid::new(Vector3::new(1.0f64, 0.5, 2.0));
+    assert_eq!(cuboid2.volume(), volumetric::cuboid_volume(cuboid2.half_extents()));
+    assert_eq!(cuboid3.volume(), volumetric::cuboid_volume(cuboid3.half_extents()));
+    assert!((cuboid3.volume() - 8.0).abs() < 1.0e-12);
+
+    let cone = Cone::new(1.5f64, 0.5);
+    assert_eq!(cone.volume::<Point2<f64>>(), volumetric::cone_volume(1.5, 0.5, 2));
+    assert_eq!(cone.volume::<Point3<f64>>(), volumetric::cone_volume(1.5, 0.5, 3));
+    assert!((cone.volume::<Point2<f64>>() - 1.5).abs() < 1.0e-12);
+    assert!((cone.volume::<Point3<f64>>() - PI * 0.25).abs() < 1.0e-12);
+}
+
+#[test]
+fn cone_2d_volume_is_triangle_area() {
+    let m = Isometry2::identity();
+
+    for &(half_height, radius) in [ (0.5f64, 0.5), (1.5, 0.25), (0.1, 3.0), (2.0, 2.0) ].iter() {
+        // The triangle described by the support mapping of the cone.
+        let cone  = Cone::new(half_height, radius);
+        let apex  = cone.support_point(&m, &Vector2::y());
+        let left  = cone.support_point(&m, &Vector2::new(-1.0, -1.0));
+        let right = cone.support_point(&m, &Vector2::new(1.0, -1.0));
+
+        assert_eq!(na::distance(&left, &right), 2.0 * radius);
+        assert_eq!(apex.y - left.y, 2.0 * half_height);
+
+        let area = ((right - left).perp(&(apex - left)) / 2.0).abs();
+        assert!((volumetric::cone_volume(half_height, radius, 2) - area).abs() < 1.0e-12);
+    }
+}
@@ -0,0 +1,106 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+
+use na::{Isometry2, Isometry3, Point2, Point3, Vector2, Vector3};
+use ncollide::bounding_volume::AABB;
+use ncollide::shape::{Ball, Compound, Cuboid, ShapeHandle, TriMesh};
+use ncollide::query::{self, SignedDistanceField};
+
+#[test]
+fn signed_distance_to_shapes() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 2.0, 3.0));
+    let m      = Isometry3::new(Vector3::new(1.0, 0.0, 0.0), na::zero());
+
+    assert!((query::signed_distance(&m, &cuboid, &Point3::new(1.5, 0.0, 0.0)) + 0.5).abs() < 1.0e-9);
+    assert!((query::signed_distance(&m, &cuboid, &Point3::new(4.0, 0.0, 0.0)) - 2.0).abs() < 1.0e-9);
+}
+
+#[test]
+fn signed_distance_to_mesh_and_compound() {
+    // The boundary of the cube [-1, 1]^3.
+    let mut vertices = Vec::new();
+
+    for i in 0 .. 8 {
+        vertices.push(Point3::new(if i & 1 != 0 { 1.0f64 } else { -1.0 },
+                                  if i & 2 != 0 { 1.0 } else { -1.0 },
+                                  if i & 4 != 0 { 1.0 } else { -1.0 }));
+    }
+
+    let quads = [ [ 0, 4, 6, 2 ], [ 1, 3, 7, 5 ], [ 0, 1, 5, 4 ], [ 2, 6, 7, 3 ], [ 0, 2, 3, 1 ], [ 4, 5, 7, 6 ] ];
+    let mut indices = Vec::new();
+
+    for q in quads.iter() {
+        indices.push(Point3::new(q[0], q[1], q[2]));
+        indices.push(Point3::new(q[0], q[2], q[3]));
+    }
+
+    let mesh = TriMesh::new(Arc::new(vertices), Arc::new(indices), None, None);
+    let m    = Isometry3::identity();
+
+    assert!((query::signed_distance(&m, &mesh, &Point3::new(0.5, 0.0, 0.0)) + 0.5).abs() < 1.0e-9);
+    assert!((query::signed_distance(&m, &mesh, &Point3::new(3.0, 0.0, 0.0)) - 2.0).abs() < 1.0e-9);
+
+    // The point is inside of the first cuboid, and closest to the boundary of the second one.
+    let cuboid   = ShapeHandle::new(Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0)));
+    let compound = Compound::new(vec![ (Isometry3::identity(), cuboid.clone()),
+                                       (Isometry3::new(Vector3::new(1.5, 0.0, 0.0), na::zero()), cuboid) ]);
+
+    assert!((query::signed_distance(&m, &compound, &Point3::new(0.4, 0.0, 0.0)) + 0.1).abs() < 1.0e-9);
+}
+
+#[test]
+fn dense_signed_distance_field() {
+    let ball   = Ball::new(1.0f64);
+    let m      = Isometry2::new(Vector2::new(0.5, 0.0), na::zero());
+    let region = AABB::new(Point2::new(-2.0, -2.0), Point2::new(2.0, 2.0));
+    let sdf    = SignedDistanceField::new(&m, &ball, &region, 0.5);
+
+    assert!(!sdf.is_sparse());
+    assert_eq!(sdf.num_samples(), &[ 9, 9 ]);
+    assert_eq!(sdf.num_stored_samples(), 81);
+    assert_eq!(sdf.point_at(&[ 5, 4 ]), Point2::new(0.5, 0.0));
+    assert!((sdf.value_at(&[ 5, 4 ]).unwrap() + 1.0).abs() < 1.0e-9);
+    assert!((sdf.value_at(&[ 8, 4 ]).unwrap() - 0.5).abs() < 1.0e-9);
+    assert!(sdf.value_at(&[ 9, 4 ]).is_none());
+
+    // Linear along the axis of the ball.
+    let value = sdf.interpolate(&Point2::new(1.25, 0.0)).unwrap();
+    assert!((value + 0.25).abs() < 1.0e-9);
+    assert!(sdf.interpolate(&Point2::new(2.5, 0.0)).is_none());
+}
+
+#[test]
+fn sparse_signed_distance_field() {
+    let ball   = Ball::new(1.0f64);
+    let m      = Isometry3::identity();
+    let region = AABB::new(Point3::new(-3.0, -3.0, -3.0), Point3::new(3.0, 3.0, 3.0));
+    let dense  = SignedDistanceField::new(&m, &ball, &region, 0.25);
+    let sparse = SignedDistanceField::new_sparse(&m, &ball, &region, 0.25, 0.5);
+
+    assert!(sparse.is_sparse());
+    assert_eq!(sparse.num_samples(), dense.num_samples());
+    assert!(sparse.num_stored_samples() < dense.num_stored_samples() / 4);
+
+    let n = dense.num_samples()[0];
+
+    for i in 0 .. n {
+        for j in 0 .. n {
+            for k in 0 .. n {
+                let idx      = [ i, j, k ];
+                let expected = dense.value_at(&idx).unwrap();
+
+                if expected.abs() <= 0.5 {
+                    assert_eq!(sparse.value_at(&idx), Some(expected));
+                }
+                else {
+                    assert!(sparse.value_at(&idx).is_none());
+                }
+            }
+        }
+    }
+
+    assert!(sparse.interpolate(&Point3::new(1.1, 0.0, 0.0)).is_some());
+    assert!(sparse.interpolate(&Point3::origin()).is_none());
+}
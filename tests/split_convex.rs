@@ -0,0 +1,51 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{ConvexHull, Plane};
+use ncollide::transformation;
+
+fn hull_volume(hull: &ConvexHull<Point3<f64>>) -> f64 {
+    let mesh    = transformation::convex_hull3(hull.points());
+    let mut res = 0.0;
+
+    for t in mesh.indices.unwrap_unified().iter() {
+        let a = mesh.coords[t.x as usize].coords;
+        let b = mesh.coords[t.y as usize].coords;
+        let c = mesh.coords[t.z as usize].coords;
+
+        res += na::dot(&a, &b.cross(&c)) / 6.0;
+    }
+
+    res.abs()
+}
+
+#[test]
+fn split_unit_cube_through_center() {
+    let mut corners = Vec::new();
+
+    for i in 0 .. 8 {
+        corners.push(Point3::new((i & 1) as f64 - 0.5, ((i >> 1) & 1) as f64 - 0.5, ((i >> 2) & 1) as f64 - 0.5));
+    }
+
+    let cube  = ConvexHull::new(corners);
+    let plane = Plane::new(Vector3::new(1.0f64, 1.0, 0.0));
+    let (above, below) = transformation::split_convex(&cube, &Isometry3::identity(), &plane);
+
+    let above = above.expect("The cube should have a part above the plane.");
+    let below = below.expect("The cube should have a part below the plane.");
+
+    assert!((hull_volume(&above) - 0.5).abs() < 1.0e-7);
+    assert!((hull_volume(&below) - 0.5).abs() < 1.0e-7);
+}
+
+#[test]
+fn split_tetrahedron_by_a_plane_not_crossing_it() {
+    let tetra = ConvexHull::new(vec![ Point3::new(0.0f64, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0),
+                                      Point3::new(0.0, 1.0, 0.0), Point3::new(0.0, 0.0, 1.0) ]);
+    let plane = Plane::new(Vector3::x());
+    let (above, below) = transformation::split_convex(&tetra, &Isometry3::identity(), &plane);
+
+    assert!(above.is_some());
+    assert!(below.is_none());
+}
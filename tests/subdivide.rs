@@ -0,0 +1,70 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::collections::HashMap;
+use na::Point3;
+use ncollide::procedural::{TriMesh, IndexBuffer};
+use ncollide::transformation;
+
+fn tetrahedron() -> TriMesh<Point3<f64>> {
+    let coords = vec![
+        Point3::new(1.0, 1.0, 1.0),
+        Point3::new(1.0, -1.0, -1.0),
+        Point3::new(-1.0, 1.0, -1.0),
+        Point3::new(-1.0, -1.0, 1.0)
+    ];
+    let indices = vec![
+        Point3::new(0, 1, 2),
+        Point3::new(0, 3, 1),
+        Point3::new(0, 2, 3),
+        Point3::new(1, 3, 2)
+    ];
+
+    TriMesh::new(coords, None, None, Some(IndexBuffer::Unified(indices)))
+}
+
+// Each directed edge of a closed mesh without cracks appears exactly once, together with its
+// opposite.
+fn assert_closed(mesh: &TriMesh<Point3<f64>>) {
+    let mut edges = HashMap::new();
+
+    for t in mesh.indices.clone().unwrap_unified() {
+        for &(a, b) in [(t.x, t.y), (t.y, t.z), (t.z, t.x)].iter() {
+            *edges.entry((a, b)).or_insert(0) += 1;
+        }
+    }
+
+    for (&(a, b), &count) in edges.iter() {
+        assert_eq!(count, 1);
+        assert_eq!(edges.get(&(b, a)), Some(&1));
+    }
+}
+
+#[test]
+fn subdivide_tetrahedron() {
+    let mesh = tetrahedron();
+
+    let once = transformation::subdivide(&mesh, 1);
+    assert_eq!(once.num_triangles(), 16);
+    assert_eq!(once.coords.len(), 4 + 6);
+    assert_closed(&once);
+
+    let twice = transformation::subdivide(&mesh, 2);
+    assert_eq!(twice.num_triangles(), 64);
+    assert_eq!(twice.coords.len(), 10 + 24);
+    assert_closed(&twice);
+}
+
+#[test]
+fn subdivide_tetrahedron_onto_sphere() {
+    let radius = 3.0f64.sqrt();
+    let mesh   = transformation::subdivide_onto(&tetrahedron(), 2, &mut |pt: &Point3<f64>| {
+        Point3::from_coordinates(na::normalize(&pt.coords) * radius)
+    });
+
+    assert_closed(&mesh);
+
+    for pt in mesh.coords.iter() {
+        assert!((na::norm(&pt.coords) - radius).abs() < 1.0e-10);
+    }
+}
@@ -0,0 +1,18 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Vector3};
+use ncollide::shape::Cuboid;
+use ncollide::bounding_volume::{self, AABB};
+
+#[test]
+fn support_map_aabb_of_cuboid_matches_analytic_aabb() {
+    let cuboid = Cuboid::new(Vector3::new(1.0f64, 2.0, 0.5));
+    let m      = Isometry3::new(Vector3::new(1.0, -2.0, 3.0), Vector3::new(0.3, -0.7, 1.1));
+
+    let analytic: AABB<_> = bounding_volume::aabb(&cuboid, &m);
+    let support           = bounding_volume::support_map_aabb(&m, &cuboid);
+
+    assert!(na::distance(analytic.mins(), support.mins()) < 1.0e-7);
+    assert!(na::distance(analytic.maxs(), support.maxs()) < 1.0e-7);
+}
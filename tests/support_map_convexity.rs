@@ -0,0 +1,58 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{self, Shape, SupportMap, Ball, Cuboid};
+use ncollide::bounding_volume::AABB;
+use ncollide::query;
+
+// Two unit balls centered at `(-2, 0, 0)` and `(2, 0, 0)`, with a buggy support map selecting the
+// right ball only for directions close to the `x` axis instead of maximizing the dot product.
+struct BuggyDumbbell;
+
+impl SupportMap<Point3<f64>, Isometry3<f64>> for BuggyDumbbell {
+    fn support_point(&self, m: &Isometry3<f64>, dir: &Vector3<f64>) -> Point3<f64> {
+        let dir    = na::normalize(dir);
+        let center = if dir.x > 0.9 { 2.0 } else { -2.0 };
+
+        m * (Point3::new(center, 0.0, 0.0) + dir)
+    }
+}
+
+impl Shape<Point3<f64>, Isometry3<f64>> for BuggyDumbbell {
+    fn aabb(&self, m: &Isometry3<f64>) -> AABB<Point3<f64>> {
+        let center = Point3::from_coordinates(m.translation.vector);
+        let radius = Vector3::new(3.0, 3.0, 3.0);
+
+        AABB::new(center + (-radius), center + radius)
+    }
+
+    fn as_support_map(&self) -> Option<&SupportMap<Point3<f64>, Isometry3<f64>>> {
+        Some(self)
+    }
+}
+
+#[test]
+fn convex_support_maps_pass() {
+    let m = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.3, -0.2, 0.1));
+
+    assert!(shape::check_support_map_convexity(&m, &Cuboid::new(Vector3::new(1.0, 2.0, 0.5))).is_ok());
+    assert!(shape::check_support_map_convexity(&m, &Ball::new(1.5f64)).is_ok());
+}
+
+#[test]
+fn non_convex_support_map_fails() {
+    let m = Isometry3::new(na::zero(), na::zero());
+
+    assert!(shape::check_support_map_convexity(&m, &BuggyDumbbell).is_err());
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "appears non-convex in direction")]
+fn distance_to_non_convex_support_map_panics() {
+    let m1 = Isometry3::new(na::zero(), na::zero());
+    let m2 = Isometry3::new(Vector3::new(10.0, 0.0, 0.0), na::zero());
+
+    let _ = query::distance_checked(&m1, &BuggyDumbbell, &m2, &Ball::new(1.0));
+}
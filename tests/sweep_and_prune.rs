@@ -0,0 +1,74 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::collections::HashSet;
+use na::{Point2, Vector2};
+use ncollide::bounding_volume::{BoundingVolume, AABB};
+use ncollide::broad_phase::{BroadPhase, SweepAndPrune};
+
+fn aabb_at(i: usize, t: f64) -> AABB<Point2<f64>> {
+    let fi     = i as f64;
+    let center = Point2::new((fi * 0.7).sin() * 3.0 + (t * (1.0 + fi * 0.1)).cos() * 2.0,
+                             (fi * 1.3).cos() * 3.0 + (t * (0.5 + fi * 0.05)).sin() * 2.0);
+    let half   = Vector2::new(0.3 + (i % 3) as f64 * 0.2, 0.4);
+
+    AABB::new(center + -half, center + half)
+}
+
+fn brute_force_pairs(n: usize, t: f64) -> HashSet<(usize, usize)> {
+    let mut res = HashSet::new();
+
+    for i in 0 .. n {
+        for j in i + 1 .. n {
+            if aabb_at(i, t).intersects(&aabb_at(j, t)) {
+                let _ = res.insert((i, j));
+            }
+        }
+    }
+
+    res
+}
+
+fn update(sap: &mut SweepAndPrune<Point2<f64>, usize>, pairs: &mut HashSet<(usize, usize)>) {
+    sap.update(&mut |_, _| true, &mut |a, b, started| {
+        let pair = if *a < *b { (*a, *b) } else { (*b, *a) };
+
+        if started {
+            assert!(pairs.insert(pair), "Proximity {:?} reported twice.", pair);
+        }
+        else {
+            assert!(pairs.remove(&pair), "Unknown proximity {:?} removed.", pair);
+        }
+    });
+}
+
+#[test]
+fn sweep_and_prune_matches_brute_force() {
+    let n         = 20;
+    let mut sap   = SweepAndPrune::new(0.0, true);
+    let mut pairs = HashSet::new();
+
+    for i in 0 .. n {
+        sap.deferred_add(i, aabb_at(i, 0.0), i);
+    }
+
+    update(&mut sap, &mut pairs);
+    assert_eq!(pairs, brute_force_pairs(n, 0.0));
+
+    for step in 1 .. 100 {
+        let t = step as f64 * 0.05;
+
+        for i in 0 .. n {
+            sap.deferred_set_bounding_volume(i, aabb_at(i, t));
+        }
+
+        update(&mut sap, &mut pairs);
+        assert_eq!(pairs, brute_force_pairs(n, t));
+        assert_eq!(sap.num_interferences(), pairs.len());
+    }
+
+    // Removing an object stops all its proximities.
+    sap.deferred_remove(0);
+    update(&mut sap, &mut pairs);
+    assert!(pairs.iter().all(|&(a, _)| a != 0));
+}
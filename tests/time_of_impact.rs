@@ -0,0 +1,59 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry2, Vector2};
+use ncollide::shape::Cuboid;
+use ncollide::query::{self, QuerySettings, TOIStatus};
+
+#[test]
+fn approaching_cuboids_toi_normal_and_witnesses() {
+    let cuboid = Cuboid::new(Vector2::new(1.0f64, 1.0));
+    let m1     = Isometry2::new(Vector2::new(0.0, 0.0), na::zero());
+    let m2     = Isometry2::new(Vector2::new(6.0, 0.5), na::zero());
+    let vel1   = Vector2::new(2.0, 0.0);
+    let vel2   = Vector2::new(-2.0, 0.0);
+
+    let res = query::time_of_impact(&m1, &vel1, &cuboid, &m2, &vel2, &cuboid).expect("Missing impact.");
+
+    assert_eq!(res.status, TOIStatus::Converged);
+    assert!((res.toi - 1.0).abs() < 1.0e-6);
+    // The normal points from the first cuboid toward the second one, i.e., against the velocity
+    // of the second cuboid relative to the first one.
+    assert!(na::dot(&*res.normal, &(vel2 - vel1)) < 0.0);
+    assert!(na::norm(&(*res.normal - Vector2::x())) < 1.0e-6);
+    assert!((res.witness1.x - 3.0).abs() < 1.0e-6 && (res.witness2.x - 3.0).abs() < 1.0e-6);
+    assert!(res.witness1.y >= -0.5 - 1.0e-6 && res.witness1.y <= 1.0 + 1.0e-6);
+
+    // Already penetrating.
+    let m2  = Isometry2::new(Vector2::new(1.5, 0.0), na::zero());
+    let res = query::time_of_impact(&m1, &vel1, &cuboid, &m2, &vel2, &cuboid).expect("Missing impact.");
+
+    assert_eq!(res.status, TOIStatus::Penetrating);
+    assert_eq!(res.toi, 0.0);
+
+    // Moving apart.
+    let m2 = Isometry2::new(Vector2::new(6.0, 0.5), na::zero());
+    assert!(query::time_of_impact(&m1, &-vel1, &cuboid, &m2, &-vel2, &cuboid).is_none());
+}
+
+#[test]
+fn toi_out_of_iterations() {
+    let cuboid = Cuboid::new(Vector2::new(1.0f64, 1.0));
+    let m1     = Isometry2::new(Vector2::new(0.0, 0.0), na::zero());
+    let m2     = Isometry2::new(Vector2::new(6.0, 0.5), 0.3);
+    let vel1   = Vector2::new(2.0, 0.0);
+    let vel2   = Vector2::new(-2.0, 0.0);
+
+    let settings  = QuerySettings::default();
+    let converged = query::time_of_impact_with_settings(&m1, &vel1, &cuboid, &m2, &vel2, &cuboid, &settings)
+                        .expect("Missing impact.");
+    assert_eq!(converged.status, TOIStatus::Converged);
+
+    // A single iteration only gives a lower bound of the time of impact.
+    let settings = QuerySettings::new(settings.gjk_tolerance, settings.contact_tolerance, settings.parallel_eps, 1);
+    let res      = query::time_of_impact_with_settings(&m1, &vel1, &cuboid, &m2, &vel2, &cuboid, &settings)
+                       .expect("Missing impact.");
+
+    assert_eq!(res.status, TOIStatus::OutOfIterations);
+    assert!(res.toi > 0.0 && res.toi < converged.toi);
+}
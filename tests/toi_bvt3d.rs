@@ -0,0 +1,57 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Point3, Vector3, Isometry3};
+use ncollide::partitioning::BVT;
+use ncollide::shape::{Cone, Ball, Cuboid, Capsule, Shape};
+use ncollide::query;
+use ncollide::bounding_volume::{self, BoundingSphere};
+
+#[test]
+fn first_shape_hit_by_ball_swept_through_bvt_scene() {
+    let ball = Ball::new(0.5);
+    let caps = Capsule::new(0.5, 0.75);
+    let cone = Cone::new(0.5, 0.75);
+    let cube = Cuboid::new(Vector3::new(1.0, 0.5, 1.0));
+
+    let shapes = [
+        &ball as &Shape<Point3<f64>, Isometry3<f64>>,
+        &caps as &Shape<Point3<f64>, Isometry3<f64>>,
+        &cone as &Shape<Point3<f64>, Isometry3<f64>>,
+        &cube as &Shape<Point3<f64>, Isometry3<f64>>
+    ];
+
+    let poss = [
+        Isometry3::new(Vector3::new(0.0, 0.0, 1.0), na::zero()),
+        Isometry3::new(Vector3::new(0.0, 0.0, 2.0), na::zero()),
+        Isometry3::new(Vector3::new(0.0, 0.0, 3.0), na::zero()),
+        Isometry3::new(Vector3::new(0.0, 2.0, 4.0), na::zero())
+    ];
+
+    let idx_and_bounding_spheres: Vec<(usize, BoundingSphere<Point3<f64>>)> =
+        (0 .. 4).map(|i| (i, bounding_volume::bounding_sphere(shapes[i], &poss[i]))).collect();
+    let bvt = BVT::new_balanced(idx_and_bounding_spheres);
+
+    let moving = Ball::new(0.25);
+    let vel    = Vector3::z();
+    let zero   = na::zero();
+
+    // Along the axis of the scene, the ball is hit first.
+    let m = Isometry3::new(na::zero(), na::zero());
+    let (i, res) = query::bvt_time_of_impact(&bvt, &m, &vel, &moving,
+                                             |i| query::time_of_impact(&m, &vel, &moving, &poss[*i], &zero, shapes[*i])).unwrap();
+    assert_eq!(*i, 0);
+    assert!((res.toi - 0.25).abs() < 1.0e-5);
+
+    // Higher, only the cuboid is hit.
+    let m = Isometry3::new(Vector3::new(0.0, 2.0, -2.0), na::zero());
+    let (i, res) = query::bvt_time_of_impact(&bvt, &m, &vel, &moving,
+                                             |i| query::time_of_impact(&m, &vel, &moving, &poss[*i], &zero, shapes[*i])).unwrap();
+    assert_eq!(*i, 3);
+    assert!((res.toi - 4.75).abs() < 1.0e-5);
+
+    // Moving away from the scene.
+    let m = Isometry3::new(na::zero(), na::zero());
+    assert!(query::bvt_time_of_impact(&bvt, &m, &-vel, &moving,
+                                      |i| query::time_of_impact(&m, &-vel, &moving, &poss[*i], &zero, shapes[*i])).is_none());
+}
@@ -0,0 +1,82 @@
+extern crate alga;
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use alga::general::Id;
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Ball, Cuboid, SupportMap, Transformed};
+use ncollide::query::{Ray, RayCast};
+
+fn pose() -> Isometry3<f64> {
+    Isometry3::new(Vector3::new(1.0, -2.0, 0.5), Vector3::new(0.3, -0.7, 1.1))
+}
+
+fn directions() -> Vec<Vector3<f64>> {
+    let mut res = Vec::new();
+
+    for i in 0 .. 27 {
+        let dir = Vector3::new((i % 3) as f64 - 1.0, ((i / 3) % 3) as f64 - 1.1, (i / 9) as f64 - 0.9);
+        res.push(dir);
+    }
+
+    res
+}
+
+fn check_rays<S>(shape: &S, transformed: &Transformed<Point3<f64>, Isometry3<f64>, S>)
+    where S: RayCast<Point3<f64>, Isometry3<f64>> + RayCast<Point3<f64>, Id> {
+    let m = pose();
+
+    for dir in directions().iter() {
+        for solid in [true, false].iter() {
+            let center  = Point3::from_coordinates(m.translation.vector);
+            let ray     = Ray::new(center, *dir);
+            let outside = Ray::new(center + *dir * -10.0, *dir);
+
+            for ray in [ray, outside].iter() {
+                let expected = shape.toi_and_normal_with_ray(&m, ray, *solid);
+                let found    = transformed.toi_and_normal_with_ray(&Id::new(), ray, *solid);
+
+                match (expected, found) {
+                    (Some(expected), Some(found)) => {
+                        assert!((expected.toi - found.toi).abs() < 1.0e-9);
+                        assert!(na::norm(&(*expected.normal - *found.normal)) < 1.0e-9);
+                    },
+                    (None, None) => { },
+                    _ => panic!("Mismatched ray intersections.")
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn transformed_cuboid_matches_the_transformed_queries() {
+    let m           = pose();
+    let cuboid      = Cuboid::new(Vector3::new(1.0, 2.0, 0.5));
+    let transformed = Transformed::new(cuboid.clone(), m);
+
+    for dir in directions().iter() {
+        let expected = cuboid.support_point(&m, dir);
+        let found    = transformed.support_point(&Id::new(), dir);
+
+        assert!(na::distance(&expected, &found) < 1.0e-9);
+    }
+
+    check_rays(&cuboid, &transformed);
+}
+
+#[test]
+fn transformed_ball_matches_the_transformed_queries() {
+    let m           = pose();
+    let ball        = Ball::new(1.5);
+    let transformed = Transformed::new(ball.clone(), m);
+
+    for dir in directions().iter() {
+        let expected = ball.support_point(&m, dir);
+        let found    = transformed.support_point(&Id::new(), dir);
+
+        assert!(na::distance(&expected, &found) < 1.0e-9);
+    }
+
+    check_rays(&ball, &transformed);
+}
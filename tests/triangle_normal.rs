@@ -0,0 +1,26 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Point3, Vector3};
+use ncollide::shape::Triangle;
+
+#[test]
+fn triangle_normal_follows_winding() {
+    let a = Point3::new(0.0f64, 0.0, 0.0);
+    let b = Point3::new(2.0, 0.0, 0.0);
+    let c = Point3::new(0.0, 1.0, 0.0);
+
+    let ccw = Triangle::new(a, b, c);
+    let cw  = Triangle::new(a, c, b);
+
+    assert_eq!(ccw.scaled_normal(), Vector3::new(0.0, 0.0, 2.0));
+    assert_eq!(*ccw.normal().unwrap(), Vector3::z());
+    assert_eq!(*cw.normal().unwrap(), -Vector3::z());
+}
+
+#[test]
+fn degenerate_triangle_normal() {
+    let tri = Triangle::new(Point3::new(0.0f64, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0), Point3::new(2.0, 2.0, 2.0));
+
+    assert!(tri.normal().is_none());
+}
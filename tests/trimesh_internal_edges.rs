@@ -0,0 +1,88 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Cuboid, TriMesh, ShapeHandle};
+use ncollide::query;
+use ncollide::query::contacts_internal;
+use ncollide::world::{CollisionGroups, CollisionWorld3, GeometricQueryType};
+
+// A flat square made of two triangles sharing the diagonal `x = z`.
+fn quad() -> TriMesh<Point3<f64>> {
+    let vertices = vec![Point3::new(-5.0, 0.0, -5.0), Point3::new(5.0, 0.0, -5.0),
+                        Point3::new(5.0, 0.0, 5.0),   Point3::new(-5.0, 0.0, 5.0)];
+    let indices  = vec![Point3::new(0usize, 1, 2), Point3::new(0, 2, 3)];
+
+    TriMesh::new(Arc::new(vertices), Arc::new(indices), None, None)
+}
+
+#[test]
+fn trimesh_edge_adjacency() {
+    let mesh = quad();
+
+    assert_eq!(*mesh.adjacent_triangles(0), [ None, None, Some(1) ]);
+    assert_eq!(*mesh.adjacent_triangles(1), [ Some(0), None, None ]);
+}
+
+#[test]
+fn box_sliding_across_internal_edge_has_upward_normals() {
+    let mesh   = quad();
+    let cube   = Cuboid::new(Vector3::new(0.5, 0.5, 0.5));
+    let origin = Isometry3::identity();
+
+    // The box hovers over the quad, within the prediction distance. Without correction, the
+    // diagonal edge yields tilted contact normals when the box is close to, but not above, one
+    // of the triangles.
+    for i in 0 .. 401 {
+        let x   = -1.5 + i as f64 * 0.0075;
+        let pos = Isometry3::new(Vector3::new(x, 0.52, 0.3), na::zero());
+
+        let mut contacts = Vec::new();
+        contacts_internal::trimesh_against_shape_with_features(&origin, &mesh, &pos, &cube, 0.1, &mut contacts);
+
+        assert!(!contacts.is_empty());
+
+        for &(ref c, _) in contacts.iter() {
+            assert!(na::norm(&(*c.normal - Vector3::y())) < 1.0e-6, "Spurious normal {} at x = {}.", *c.normal, x);
+            assert!((c.depth + 0.02).abs() < 1.0e-6, "Spurious depth {} at x = {}.", c.depth, x);
+        }
+
+        let c = query::contact(&origin, &mesh, &pos, &cube, 0.1).unwrap();
+        assert!(na::norm(&(*c.normal - Vector3::y())) < 1.0e-6);
+        assert!((c.depth + 0.02).abs() < 1.0e-6);
+    }
+}
+
+#[test]
+fn box_sliding_across_internal_edge_in_world_has_upward_normals() {
+    let groups    = CollisionGroups::new();
+    let query     = GeometricQueryType::Contacts(0.1);
+    let mut world = CollisionWorld3::new(0.02, false);
+
+    world.deferred_add(0, Isometry3::identity(), ShapeHandle::new(quad()), groups, query, ());
+    world.deferred_add(1, Isometry3::new(Vector3::new(-1.5, 0.52, 0.3), na::zero()),
+                       ShapeHandle::new(Cuboid::new(Vector3::new(0.5, 0.5, 0.5))), groups, query, ());
+    world.update();
+
+    for i in 0 .. 401 {
+        let x = -1.5 + i as f64 * 0.0075;
+
+        world.deferred_set_position(1, Isometry3::new(Vector3::new(x, 0.52, 0.3), na::zero()));
+        world.update();
+
+        let mut ncontacts = 0;
+
+        for (co1, _, c) in world.contacts() {
+            // The normal is oriented from the first object toward the second one.
+            let normal = if co1.uid == 0 { *c.normal } else { -*c.normal };
+
+            // The contact manifold is generated from slightly rotated copies of the box.
+            assert!(na::dot(&normal, &Vector3::y()) > 0.999, "Spurious normal {} at x = {}.", normal, x);
+            assert!((c.depth + 0.02).abs() < 1.0e-2, "Spurious depth {} at x = {}.", c.depth, x);
+            ncontacts = ncontacts + 1;
+        }
+
+        assert!(ncontacts > 0);
+    }
+}
@@ -0,0 +1,54 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::TriMesh;
+use ncollide::query::RichPointQuery;
+
+// A unit quad on the `xz` plane made of the triangles 0 and 1 sharing the diagonal `(0, 2)`.
+fn quad() -> TriMesh<Point3<f64>> {
+    let vertices = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(0.0, 0.0, 1.0)
+    ];
+    let indices = vec![ Point3::new(0, 1, 2), Point3::new(0, 2, 3) ];
+
+    TriMesh::new(Arc::new(vertices), Arc::new(indices), None, None)
+}
+
+#[test]
+fn trimesh_project_point_above_quad() {
+    let mesh = quad();
+    let m    = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), na::zero());
+
+    // Above the triangle 1.
+    let pt = Point3::new(1.2, 2.5, 3.8);
+    let (proj, info) = mesh.project_point_with_extra_info(&m, &pt, true);
+
+    assert_eq!(info.element_index, 1);
+    assert!(na::distance(&proj.point, &Point3::new(1.2, 2.0, 3.8)) < 1.0e-9);
+
+    // Above the triangle 0.
+    let pt = Point3::new(1.8, 1.5, 3.2);
+    let (proj, info) = mesh.project_point_with_extra_info(&m, &pt, true);
+
+    assert_eq!(info.element_index, 0);
+    assert!(na::distance(&proj.point, &Point3::new(1.8, 2.0, 3.2)) < 1.0e-9);
+}
+
+#[test]
+fn trimesh_project_point_on_shared_edge() {
+    let mesh = quad();
+    let m    = Isometry3::identity();
+
+    // Above the diagonal shared by both triangles.
+    let pt = Point3::new(0.3, 1.0, 0.3);
+    let (proj, info) = mesh.project_point_with_extra_info(&m, &pt, true);
+
+    assert_eq!(info.element_index, 0);
+    assert!(na::distance(&proj.point, &Point3::new(0.3, 0.0, 0.3)) < 1.0e-9);
+}
@@ -0,0 +1,28 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::TriMesh;
+use ncollide::query::Ray;
+
+#[test]
+fn trimesh_ray_attribute_at_centroid() {
+    let vertices = vec![
+        Point3::new(0.0f64, 0.0, 0.0),
+        Point3::new(3.0, 0.0, 0.0),
+        Point3::new(0.0, 3.0, 0.0)
+    ];
+    let indices  = vec![ Point3::new(0usize, 1, 2) ];
+    let mesh     = TriMesh::new(Arc::new(vertices), Arc::new(indices), None, None);
+    let attrs    = [ 1.0f64, 2.0, 6.0 ];
+
+    let m   = Isometry3::new(Vector3::new(0.0, 0.0, 1.0), na::zero());
+    let ray = Ray::new(Point3::new(1.0, 1.0, 5.0), -Vector3::z());
+
+    let (inter, attr) = mesh.toi_and_attr_with_ray(&m, &ray, &attrs).expect("The ray must hit the mesh.");
+
+    assert!((inter.toi - 4.0).abs() < 1.0e-7);
+    assert!((attr - 3.0).abs() < 1.0e-7);
+    assert!(mesh.toi_and_attr_with_ray(&m, &Ray::new(Point3::new(3.0, 3.0, 5.0), -Vector3::z()), &attrs).is_none());
+}
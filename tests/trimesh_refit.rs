@@ -0,0 +1,59 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::TriMesh;
+use ncollide::query::{Ray, RayCast};
+
+// A flat `n x n` grid of quads on the `xz` plane, each split into two triangles.
+fn grid(n: usize) -> TriMesh<Point3<f64>> {
+    let mut vertices = Vec::new();
+    let mut indices  = Vec::new();
+
+    for i in 0 .. n + 1 {
+        for j in 0 .. n + 1 {
+            vertices.push(Point3::new(i as f64, 0.0, j as f64));
+        }
+    }
+
+    for i in 0 .. n {
+        for j in 0 .. n {
+            let a = i * (n + 1) + j;
+
+            indices.push(Point3::new(a, a + n + 1, a + n + 2));
+            indices.push(Point3::new(a, a + n + 2, a + 1));
+        }
+    }
+
+    TriMesh::new(Arc::new(vertices), Arc::new(indices), None, None)
+}
+
+#[test]
+fn deform_and_refit_trimesh() {
+    let mut mesh = grid(4);
+    let original = mesh.clone();
+    let m        = Isometry3::identity();
+    let ray      = Ray::new(Point3::new(2.0, 10.0, 2.0), -Vector3::y());
+
+    assert!((mesh.toi_with_ray(&m, &ray, true).unwrap() - 10.0).abs() < 1.0e-7);
+
+    // Lift the central vertex, shared by six triangles.
+    mesh.vertices_mut()[2 * 5 + 2].y = 2.0;
+    assert_eq!(mesh.refit(0.1), 6);
+    assert_eq!(mesh.refit(0.1), 0);
+
+    assert!(mesh.bvt().is_valid());
+    // The triangle 10 is one of them.
+    assert!((mesh.bounding_volumes()[10].maxs().y - 2.1).abs() < 1.0e-7);
+    assert!((mesh.toi_with_ray(&m, &ray, true).unwrap() - 8.0).abs() < 1.0e-7);
+
+    // Small displacements within the margin do not update anything.
+    mesh.vertices_mut()[2 * 5 + 2].y = 2.05;
+    assert_eq!(mesh.refit(0.1), 0);
+
+    // The clone kept the original vertices and bounding volumes.
+    assert_eq!(original.vertices()[2 * 5 + 2].y, 0.0);
+    assert!((original.toi_with_ray(&m, &ray, true).unwrap() - 10.0).abs() < 1.0e-7);
+}
@@ -0,0 +1,85 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{TriMesh, FeatureId};
+use ncollide::query::{self, contacts_internal};
+use ncollide::narrow_phase::{ContactDispatcher, DefaultContactDispatcher};
+
+fn mesh(vertices: Vec<Point3<f64>>) -> TriMesh<Point3<f64>> {
+    let indices = (0 .. vertices.len() / 3).map(|i| Point3::new(3 * i, 3 * i + 1, 3 * i + 2)).collect();
+    TriMesh::new(Arc::new(vertices), Arc::new(indices), None, None)
+}
+
+fn ground() -> TriMesh<Point3<f64>> {
+    mesh(vec![ Point3::new(-2.0, -2.0, 0.0), Point3::new(2.0, -2.0, 0.0), Point3::new(2.0, 2.0, 0.0),
+               Point3::new(-2.0, -2.0, 0.0), Point3::new(2.0, 2.0, 0.0), Point3::new(-2.0, 2.0, 0.0) ])
+}
+
+#[test]
+fn crossing_triangles() {
+    // Two vertical triangles piercing the ground, the first one through both ground triangles.
+    let fins = mesh(vec![ Point3::new(-0.5, 0.0, -0.1), Point3::new(0.5, 0.0, -0.1), Point3::new(0.0, 0.0, 1.0),
+                          Point3::new(1.5, -1.0, -0.2), Point3::new(1.5, -0.5, -0.2), Point3::new(1.5, -0.75, 1.0) ]);
+    let m1 = Isometry3::identity();
+    let m2 = Isometry3::identity();
+
+    let mut contacts = Vec::new();
+    contacts_internal::trimesh_against_trimesh_with_features(&m1, &ground(), &m2, &fins, 0.0, &mut contacts);
+
+    let features: Vec<_> = contacts.iter().map(|c| c.1).collect();
+    assert_eq!(features, vec![ (FeatureId::Face(0), FeatureId::Face(0)),
+                               (FeatureId::Face(0), FeatureId::Face(1)),
+                               (FeatureId::Face(1), FeatureId::Face(0)) ]);
+
+    for &(ref c, (_, f2)) in contacts.iter() {
+        let depth = if f2 == FeatureId::Face(0) { 0.1 } else { 0.2 };
+
+        assert!((c.depth - depth).abs() < 1.0e-5);
+        assert!((*c.normal - Vector3::z()).norm() < 1.0e-5);
+    }
+
+    // The best contact is the deepest one.
+    let best = query::contact(&m1, &ground(), &m2, &fins, 0.0).unwrap();
+    assert!((best.depth - 0.2).abs() < 1.0e-5);
+
+    // Lifted above the ground, the fins are only in contact within the prediction distance.
+    let m2 = Isometry3::new(Vector3::new(0.0, 0.0, 0.3), na::zero());
+    contacts.clear();
+    contacts_internal::trimesh_against_trimesh_with_features(&m1, &ground(), &m2, &fins, 0.05, &mut contacts);
+    assert!(contacts.is_empty());
+    contacts_internal::trimesh_against_trimesh_with_features(&m1, &ground(), &m2, &fins, 0.25, &mut contacts);
+    assert_eq!(contacts.len(), 3);
+    assert!(contacts.iter().all(|c| (c.0.depth + 0.2).abs() < 1.0e-5 || (c.0.depth + 0.1).abs() < 1.0e-5));
+}
+
+#[test]
+fn coplanar_triangles() {
+    let tile = mesh(vec![ Point3::new(-1.0, -1.0, 0.0), Point3::new(1.0, -1.0, 0.0), Point3::new(1.0, 1.0, 0.0) ]);
+    let m1   = Isometry3::identity();
+    let m2   = Isometry3::new(Vector3::new(-2.5, 1.5, 0.0), na::zero());
+
+    let mut contacts = Vec::new();
+    contacts_internal::trimesh_against_trimesh_with_features(&m1, &ground(), &m2, &tile, 0.0, &mut contacts);
+
+    // The tile only overlaps the second ground triangle.
+    assert_eq!(contacts.len(), 1);
+    let (ref c, features) = contacts[0];
+
+    assert_eq!(features, (FeatureId::Face(1), FeatureId::Face(0)));
+    assert!(c.depth.abs() < 1.0e-5);
+    assert!((*c.normal - Vector3::z()).norm() < 1.0e-5);
+    assert!(c.world1.x > -2.0 && c.world1.y < 2.0 && c.world1.x < c.world1.y);
+}
+
+#[test]
+fn trimesh_trimesh_dispatch() {
+    let dispatcher = DefaultContactDispatcher::new();
+    let fin        = mesh(vec![ Point3::new(-0.5, 0.0, -0.1), Point3::new(0.5, 0.0, -0.1), Point3::new(0.0, 0.0, 1.0) ]);
+    let m          = Isometry3::identity();
+
+    let mut algorithm = dispatcher.get_contact_algorithm(&ground(), &fin).unwrap();
+    assert!(algorithm.update(&dispatcher, &m, &ground(), &m, &fin, 0.0));
+    assert_eq!(algorithm.num_contacts(), 2);
+}
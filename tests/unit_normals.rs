@@ -0,0 +1,68 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Ball, Cone, Cuboid, Cylinder, ConvexHull, Ellipsoid, Plane, Shape, ShapeHandle};
+use ncollide::query::{self, Ray};
+
+fn assert_unit(v: &Vector3<f64>) {
+    assert!((na::norm(v) - 1.0).abs() < 1.0e-7, "Non-unit normal: {:?}", v);
+}
+
+fn shapes() -> Vec<ShapeHandle<Point3<f64>, Isometry3<f64>>> {
+    let hull = ConvexHull::new(vec![ Point3::new(0.0, 0.0, 0.0), Point3::new(2.0, 0.0, 0.0),
+                                     Point3::new(0.0, 3.0, 0.0), Point3::new(0.0, 0.0, 1.0) ]);
+
+    vec![ ShapeHandle::new(Ball::new(1.0f64)),
+          ShapeHandle::new(Cuboid::new(Vector3::new(1.0, 2.0, 3.0))),
+          ShapeHandle::new(Ellipsoid::new(Vector3::new(1.0, 0.5, 2.0))),
+          ShapeHandle::new(Cone::new(1.0, 0.5)),
+          ShapeHandle::new(Cylinder::new(1.0, 0.5)),
+          ShapeHandle::new(hull) ]
+}
+
+#[test]
+fn ray_cast_normals_are_unit() {
+    let m = Isometry3::new(Vector3::new(0.1, 0.2, 0.3), Vector3::new(0.3, 0.2, 0.1));
+
+    for shape in shapes().iter() {
+        let ray_cast = shape.as_ray_cast().unwrap();
+
+        for i in 0 .. 20 {
+            let angle  = i as f64 * 0.3;
+            let dir    = Vector3::new(angle.cos(), 0.2, angle.sin()).normalize();
+            let inside = Ray::new(Point3::new(0.2, 0.3, 0.1), dir);
+            let far    = Ray::new(Point3::origin() - dir * 10.0, dir);
+
+            for ray in [ inside, far ].iter() {
+                for solid in [ true, false ].iter() {
+                    if let Some(inter) = ray_cast.toi_and_normal_with_ray(&m, ray, *solid) {
+                        assert_unit(&inter.normal);
+                    }
+                }
+            }
+        }
+    }
+
+    let plane = Plane::new(Vector3::new(1.0, 1.0, 0.0));
+    let ray   = Ray::new(Point3::new(-1.0, 0.0, 0.0), Vector3::x());
+    let solid = plane.as_ray_cast().unwrap().toi_and_normal_with_ray(&m, &ray, true).unwrap();
+    assert_unit(&solid.normal);
+    assert!(solid.toi == 0.0);
+}
+
+#[test]
+fn contact_normals_are_unit() {
+    let shapes = shapes();
+
+    for (i, shape1) in shapes.iter().enumerate() {
+        for (j, shape2) in shapes.iter().enumerate() {
+            let m1 = Isometry3::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.1, 0.0, 0.0));
+            let m2 = Isometry3::new(Vector3::new(0.5, 0.5 * i as f64, 0.1 * j as f64), Vector3::new(0.0, 0.2, 0.0));
+
+            if let Some(c) = query::contact(&m1, &**shape1, &m2, &**shape2, 1.0) {
+                assert_unit(&c.normal);
+            }
+        }
+    }
+}
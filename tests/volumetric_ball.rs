@@ -0,0 +1,34 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::f64::consts::PI;
+use na::{Matrix3, Point2, Point3};
+use ncollide::shape::Ball;
+use ncollide::volumetric::{self, Volumetric};
+
+#[test]
+fn ball_mass_properties_in_2d_and_3d() {
+    let ball = Ball::new(2.0f64);
+
+    // The same shape type is used for both dimensions.
+    let (mass2, com2, inertia2) = Volumetric::<f64, Point2<f64>, f64>::mass_properties(&ball, 1.0);
+    let (mass3, com3, inertia3) = Volumetric::<f64, Point3<f64>, Matrix3<f64>>::mass_properties(&ball, 1.0);
+
+    assert!((mass2 - PI * 4.0).abs() < 1.0e-12);
+    assert_eq!(com2, Point2::origin());
+    assert!((inertia2 - mass2 * 4.0 / 2.0).abs() < 1.0e-12);
+
+    assert!((mass3 - 4.0 / 3.0 * PI * 8.0).abs() < 1.0e-12);
+    assert_eq!(com3, Point3::origin());
+    assert!((inertia3 - Matrix3::from_diagonal_element(mass3 * 2.0 / 5.0 * 4.0)).norm() < 1.0e-12);
+
+    assert!((Volumetric::<f64, Point2<f64>, f64>::area(&ball) - 2.0 * PI * 2.0).abs() < 1.0e-12);
+    assert!((Volumetric::<f64, Point3<f64>, Matrix3<f64>>::area(&ball) - 4.0 * PI * 4.0).abs() < 1.0e-12);
+}
+
+#[test]
+fn ball_volume_in_other_dimensions() {
+    assert!((volumetric::ball_volume(2.0f64, 1) - 4.0).abs() < 1.0e-12);
+    assert!((volumetric::ball_volume(2.0f64, 4) - PI * PI / 2.0 * 16.0).abs() < 1.0e-10);
+    assert!((volumetric::ball_area(2.0f64, 4) - 2.0 * PI * PI * 8.0).abs() < 1.0e-10);
+}
@@ -0,0 +1,116 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+
+use na::{Isometry2, Isometry3, Matrix3, Point2, Point3, Vector2, Vector3};
+use ncollide::shape::{Ball, Compound, Cuboid, ConvexHull, Polyline, Segment, TriMesh, ShapeHandle2, ShapeHandle3};
+use ncollide::volumetric::{self, Volumetric};
+
+#[test]
+fn compound_of_two_cubes_matches_their_union() {
+    let cube   = ShapeHandle3::new(Cuboid::new(Vector3::new(0.5f64, 0.5, 0.5)));
+    let left   = Isometry3::new(Vector3::new(-0.5, 0.0, 0.0), na::zero());
+    // A rotation by a quarter turn leaves the cube unchanged.
+    let right  = Isometry3::new(Vector3::new(0.5, 0.0, 0.0), Vector3::new(0.0, 0.0, std::f64::consts::FRAC_PI_2));
+    let shifts = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), na::zero());
+    let parts  = vec![ (shifts * left, cube.clone()), (shifts * right, cube) ];
+    let compound = Compound::new(parts);
+    let union    = Cuboid::new(Vector3::new(1.0f64, 0.5, 0.5));
+
+    let (mass, com, inertia) = compound.mass_properties(3.0);
+    let (union_mass, _, union_inertia) = union.mass_properties(3.0);
+
+    assert!((compound.volume() - 2.0).abs() < 1.0e-12);
+    assert!((mass - union_mass).abs() < 1.0e-12);
+    assert!(na::distance(&com, &Point3::new(1.0, 2.0, 3.0)) < 1.0e-12);
+    assert!((inertia - union_inertia).norm() < 1.0e-12);
+}
+
+#[test]
+fn compound_mass_properties_with_densities() {
+    let ball  = ShapeHandle3::new(Ball::new(1.0f64));
+    let parts = vec![
+        (Isometry3::new(Vector3::new(-2.0, 0.0, 0.0), na::zero()), ball.clone()),
+        (Isometry3::new(Vector3::new(2.0, 0.0, 0.0), na::zero()), ball)
+    ];
+    let compound = Compound::new(parts);
+
+    let (mass, com, inertia) = volumetric::compound_mass_properties3(&compound, &[1.0, 3.0]).unwrap();
+    let ball_mass = volumetric::ball_volume(1.0, 3);
+    let ball_inertia = volumetric::ball_unit_angular_inertia(1.0, 3);
+
+    assert!((mass - 4.0 * ball_mass).abs() < 1.0e-12);
+    assert!(na::distance(&com, &Point3::new(1.0, 0.0, 0.0)) < 1.0e-12);
+
+    // Parallel-axis theorem: the balls are at 3 and 1 from the center of mass.
+    let off_x = 4.0 * ball_inertia + (1.0 * 9.0 + 3.0 * 1.0) * ball_mass;
+    let expected = Matrix3::from_diagonal(&Vector3::new(4.0 * ball_inertia, off_x, off_x));
+
+    assert!((inertia - expected).norm() < 1.0e-9);
+}
+
+#[test]
+fn nested_compound_2d() {
+    let square = ShapeHandle2::new(Cuboid::new(Vector2::new(0.5f64, 0.5)));
+    let inner  = Compound::new(vec![ (Isometry2::new(Vector2::new(0.5, 0.0), na::zero()), square.clone()) ]);
+    let outer  = Compound::new(vec![
+        (Isometry2::new(Vector2::new(-0.5, 0.0), na::zero()), square),
+        (Isometry2::identity(), ShapeHandle2::new(inner))
+    ]);
+    let union  = Cuboid::new(Vector2::new(1.0f64, 0.5));
+
+    assert!((outer.volume() - 2.0).abs() < 1.0e-12);
+    assert!(na::distance(&outer.center_of_mass(), &Point2::origin()) < 1.0e-12);
+    assert!((outer.unit_angular_inertia() - union.unit_angular_inertia()).abs() < 1.0e-12);
+}
+
+#[test]
+fn compound_of_convex_hulls_and_meshes() {
+    // A unit cube, as a convex hull and as a closed triangle mesh.
+    let mut vertices = Vec::new();
+
+    for i in 0 .. 8 {
+        vertices.push(Point3::new((i & 1) as f64, ((i >> 1) & 1) as f64, ((i >> 2) & 1) as f64) + Vector3::new(-0.5, -0.5, -0.5));
+    }
+
+    let indices = vec![
+        Point3::new(0, 2, 1), Point3::new(1, 2, 3), Point3::new(4, 5, 6), Point3::new(5, 7, 6),
+        Point3::new(0, 1, 4), Point3::new(1, 5, 4), Point3::new(2, 6, 3), Point3::new(3, 6, 7),
+        Point3::new(0, 4, 2), Point3::new(2, 4, 6), Point3::new(1, 3, 5), Point3::new(3, 7, 5)
+    ];
+
+    let hull = ShapeHandle3::new(ConvexHull::new(vertices.clone()));
+    let mesh = ShapeHandle3::new(TriMesh::new(Arc::new(vertices), Arc::new(indices), None, None));
+    let compound = Compound::new(vec![
+        (Isometry3::new(Vector3::new(-0.5, 0.0, 0.0), na::zero()), hull),
+        (Isometry3::new(Vector3::new(0.5, 0.0, 0.0), na::zero()), mesh)
+    ]);
+    let union = Cuboid::new(Vector3::new(1.0f64, 0.5, 0.5));
+
+    let (mass, com, inertia) = volumetric::compound_mass_properties3(&compound, &[ 2.0, 2.0 ]).unwrap();
+    let (union_mass, _, union_inertia) = union.mass_properties(2.0);
+
+    assert!((mass - union_mass).abs() < 1.0e-9);
+    assert!(na::distance(&com, &Point3::origin()) < 1.0e-9);
+    assert!((inertia - union_inertia).norm() < 1.0e-9);
+}
+
+#[test]
+fn compound_with_unsupported_part_has_no_mass_properties() {
+    let segment  = ShapeHandle2::new(Segment::new(Point2::origin(), Point2::new(1.0f64, 0.0)));
+    let square   = ShapeHandle2::new(Cuboid::new(Vector2::new(0.5f64, 0.5)));
+    let compound = Compound::new(vec![ (Isometry2::identity(), square.clone()), (Isometry2::identity(), segment) ]);
+
+    assert!(volumetric::compound_mass_properties2(&compound, &[ 1.0, 1.0 ]).is_none());
+
+    // A closed polyline is supported.
+    let vertices = vec![ Point2::new(-0.5, -0.5), Point2::new(0.5, -0.5), Point2::new(0.5, 0.5), Point2::new(-0.5, 0.5) ];
+    let indices  = vec![ Point2::new(0, 1), Point2::new(1, 2), Point2::new(2, 3), Point2::new(3, 0) ];
+    let polyline = ShapeHandle2::new(Polyline::new(Arc::new(vertices), Arc::new(indices), None, None));
+    let compound = Compound::new(vec![ (Isometry2::identity(), polyline) ]);
+    let (mass, _, inertia): (f64, _, f64) = volumetric::compound_mass_properties2(&compound, &[ 1.0 ]).unwrap();
+
+    assert!((mass - 1.0).abs() < 1.0e-12);
+    assert!((inertia - Cuboid::new(Vector2::new(0.5f64, 0.5)).unit_angular_inertia()).abs() < 1.0e-12);
+}
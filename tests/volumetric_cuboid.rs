@@ -0,0 +1,40 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Matrix3, Point2, Point3, Vector2, Vector3};
+use ncollide::shape::Cuboid;
+use ncollide::volumetric::Volumetric;
+
+#[test]
+fn unit_cube_mass_properties() {
+    let cube = Cuboid::new(Vector3::new(0.5f64, 0.5, 0.5));
+    let (mass, com, inertia) = cube.mass_properties(2.0);
+
+    assert!((cube.volume() - 1.0).abs() < 1.0e-12);
+    assert!((cube.area() - 6.0).abs() < 1.0e-12);
+    assert!((mass - 2.0).abs() < 1.0e-12);
+    assert_eq!(com, Point3::origin());
+    // m / 12 * (1² + 1²) on each axis.
+    assert!((inertia - Matrix3::from_diagonal_element(2.0 / 6.0)).norm() < 1.0e-12);
+}
+
+#[test]
+fn box_analytic_inertia() {
+    let cuboid  = Cuboid::new(Vector3::new(1.0f64, 2.0, 3.0));
+    let inertia = cuboid.unit_angular_inertia();
+    let m       = 2.0 * 4.0 * 6.0;
+    let expected = Vector3::new(m / 12.0 * (16.0 + 36.0), m / 12.0 * (4.0 + 36.0), m / 12.0 * (4.0 + 16.0));
+
+    assert!((inertia - Matrix3::from_diagonal(&expected)).norm() < 1.0e-9);
+}
+
+#[test]
+fn unit_square_mass_properties() {
+    let square = Cuboid::new(Vector2::new(0.5f64, 0.5));
+    let (mass, com, inertia) = square.mass_properties(1.0);
+
+    assert!((mass - 1.0).abs() < 1.0e-12);
+    assert!((square.area() - 4.0).abs() < 1.0e-12);
+    assert_eq!(com, Point2::origin());
+    assert!((inertia - 1.0 / 6.0).abs() < 1.0e-12);
+}
@@ -0,0 +1,57 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::sync::Arc;
+use na::{Isometry3, Matrix3, Point2, Point3, Vector2, Vector3};
+use ncollide::shape::{Cuboid, Polyline, TriMesh};
+use ncollide::procedural;
+use ncollide::volumetric::Volumetric;
+
+#[test]
+fn closed_trimesh_mass_properties_match_cuboid() {
+    let mut mesh = procedural::cuboid(&Vector3::new(2.0f64, 4.0, 6.0));
+    let rot      = Isometry3::new(Vector3::new(1.0, -2.0, 3.0), Vector3::new(0.0, 0.0, std::f64::consts::FRAC_PI_2));
+    mesh.unify_index_buffer();
+    mesh.transform_by(&rot);
+
+    let indices = mesh.indices.unwrap_unified().into_iter().map(|i| na::convert(i)).collect();
+    let mesh    = TriMesh::new(Arc::new(mesh.coords), Arc::new(indices), None, None);
+    let cuboid  = Cuboid::new(Vector3::new(1.0f64, 2.0, 3.0));
+
+    // The rotation swaps the x and y axes.
+    let expected = cuboid.unit_angular_inertia();
+    let expected = Matrix3::from_diagonal(&Vector3::new(expected[(1, 1)], expected[(0, 0)], expected[(2, 2)]));
+
+    assert!((mesh.volume() - cuboid.volume()).abs() < 1.0e-9);
+    assert!((mesh.area() - cuboid.area()).abs() < 1.0e-9);
+    assert!(na::distance(&mesh.center_of_mass(), &Point3::new(1.0, -2.0, 3.0)) < 1.0e-9);
+    assert!((mesh.unit_angular_inertia() - expected).norm() < 1.0e-9);
+}
+
+#[test]
+fn tetrahedron_center_of_mass() {
+    let vertices = vec![ Point3::new(0.0f64, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0),
+                         Point3::new(0.0, 1.0, 0.0), Point3::new(0.0, 0.0, 1.0) ];
+    let indices  = vec![ Point3::new(0usize, 2, 1), Point3::new(0, 1, 3), Point3::new(0, 3, 2), Point3::new(1, 2, 3) ];
+    let mesh     = TriMesh::new(Arc::new(vertices), Arc::new(indices), None, None);
+
+    assert!((mesh.volume() - 1.0 / 6.0).abs() < 1.0e-12);
+    assert!(na::distance(&mesh.center_of_mass(), &Point3::new(0.25, 0.25, 0.25)) < 1.0e-12);
+}
+
+#[test]
+fn closed_polyline_mass_properties_match_cuboid() {
+    let vertices = vec![ Point2::new(1.0f64, 1.0), Point2::new(5.0, 1.0), Point2::new(5.0, 3.0), Point2::new(1.0, 3.0) ];
+    let ccw      = vec![ Point2::new(0usize, 1), Point2::new(1, 2), Point2::new(2, 3), Point2::new(3, 0) ];
+    let cw       = ccw.iter().map(|idx| Point2::new(idx.y, idx.x)).collect();
+    let cuboid   = Cuboid::new(Vector2::new(2.0f64, 1.0));
+
+    for indices in vec![ ccw, cw ].into_iter() {
+        let polyline = Polyline::new(Arc::new(vertices.clone()), Arc::new(indices), None, None);
+
+        assert!((polyline.volume() - 8.0).abs() < 1.0e-12);
+        assert!((polyline.area() - 12.0).abs() < 1.0e-12);
+        assert!(na::distance(&polyline.center_of_mass(), &Point2::new(3.0, 2.0)) < 1.0e-12);
+        assert!((polyline.unit_angular_inertia() - cuboid.unit_angular_inertia()).abs() < 1.0e-12);
+    }
+}
@@ -0,0 +1,119 @@
+extern crate alga;
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use alga::general::Id;
+use na::{Point2, Point3, Matrix3};
+use ncollide::shape::{Ball, Cone, Capsule, Cylinder, ConvexHull, SupportMap};
+use ncollide::volumetric::{self, Volumetric};
+use ncollide::math::approx_eq_inertia;
+
+#[test]
+fn numerical_cone_mass_properties_3d() {
+    let cone = Cone::new(1.0f64, 0.5);
+    let (volume, com, inertia): (f64, Point3<f64>, Matrix3<f64>) = volumetric::support_map_mass_properties(&cone, 20000);
+    let expected = volumetric::cone_volume(1.0, 0.5, 3);
+
+    assert!((volume - expected).abs() < 0.01 * expected);
+    // The centroid of a cone is at a quarter of its height from its base.
+    assert!(na::distance(&com, &Point3::new(0.0, -0.5, 0.0)) < 0.01);
+    assert!(approx_eq_inertia(&inertia, &Volumetric::<f64, Point3<f64>, Matrix3<f64>>::unit_angular_inertia(&cone),
+                              0.0, 0.02));
+}
+
+#[test]
+fn numerical_mass_properties_2d() {
+    let ball = Ball::new(0.5f64);
+    let (volume, com, inertia): (f64, Point2<f64>, f64) = volumetric::support_map_mass_properties(&ball, 20000);
+    let expected = volumetric::ball_volume(0.5, 2);
+
+    assert!((volume - expected).abs() < 0.01 * expected);
+    assert!(na::distance(&com, &Point2::origin()) < 0.01);
+    assert!((inertia - volumetric::ball_unit_angular_inertia(0.5, 2)).abs() < 0.02 * inertia);
+
+    // The centroid of a triangle is at a third of its height from its base.
+    let cone = Cone::new(1.0f64, 0.5);
+    let (volume, com, inertia): (f64, Point2<f64>, f64) = volumetric::support_map_mass_properties(&cone, 20000);
+    let expected = volumetric::cone_volume(1.0, 0.5, 2);
+
+    assert!((volume - expected).abs() < 0.01 * expected);
+    assert!(na::distance(&com, &Point2::new(0.0, -1.0 / 3.0)) < 0.01);
+    assert!((inertia - Volumetric::<f64, Point2<f64>, f64>::unit_angular_inertia(&cone)).abs() < 0.02 * inertia);
+}
+
+#[test]
+fn cone_volume_in_higher_dimensions() {
+    // The base of a 4D cone is a 3D ball.
+    let expected = volumetric::ball_volume(0.5f64, 3) * 2.0 / 4.0;
+
+    assert!((volumetric::cone_volume(1.0f64, 0.5, 4) - expected).abs() < 1.0e-12);
+}
+
+// Checks the analytic mass properties of a shape against their numerical approximation.
+fn check_mass_properties_3d<G>(g: &G)
+    where G: Volumetric<f64, Point3<f64>, Matrix3<f64>> + SupportMap<Point3<f64>, Id> {
+    let (volume, com, inertia): (f64, Point3<f64>, Matrix3<f64>) = volumetric::support_map_mass_properties(g, 20000);
+
+    assert!((volume - g.volume()).abs() < 0.01 * volume);
+    assert!(na::distance(&com, &g.center_of_mass()) < 0.01);
+    assert!(approx_eq_inertia(&inertia, &g.unit_angular_inertia(), 0.0, 0.02));
+}
+
+fn check_mass_properties_2d<G>(g: &G)
+    where G: Volumetric<f64, Point2<f64>, f64> + SupportMap<Point2<f64>, Id> {
+    let (volume, com, inertia): (f64, Point2<f64>, f64) = volumetric::support_map_mass_properties(g, 20000);
+
+    assert!((volume - g.volume()).abs() < 0.01 * volume);
+    assert!(na::distance(&com, &g.center_of_mass()) < 0.01);
+    assert!((inertia - g.unit_angular_inertia()).abs() < 0.02 * inertia);
+}
+
+#[test]
+fn analytic_mass_properties_3d() {
+    let hull = ConvexHull::new(vec![
+        Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0), Point3::new(0.0, 2.0, 0.0),
+        Point3::new(0.0, 0.0, 1.5), Point3::new(0.2, 0.2, 0.2), Point3::new(0.8, 0.0, 0.7)
+    ]);
+
+    check_mass_properties_3d(&Cone::new(1.0f64, 0.5));
+    check_mass_properties_3d(&Cylinder::new(1.0f64, 0.5));
+    check_mass_properties_3d(&Capsule::new(1.0f64, 0.5));
+    check_mass_properties_3d(&hull);
+}
+
+#[test]
+fn analytic_mass_properties_2d() {
+    let hull = ConvexHull::new(vec![
+        Point2::new(0.0, 0.0), Point2::new(1.0, 0.0), Point2::new(0.0, 2.0),
+        Point2::new(0.2, 0.2), Point2::new(0.8, 0.5)
+    ]);
+
+    check_mass_properties_2d(&Cone::new(1.0f64, 0.5));
+    check_mass_properties_2d(&Cylinder::new(1.0f64, 0.5));
+    check_mass_properties_2d(&Capsule::new(1.0f64, 0.5));
+    check_mass_properties_2d(&hull);
+}
+
+#[test]
+fn convex_hull_volume_and_area_3d() {
+    // A unit cube, with duplicate and interior points.
+    let mut points = Vec::new();
+
+    for i in 0 .. 8 {
+        points.push(Point3::new((i & 1) as f64, ((i >> 1) & 1) as f64, ((i >> 2) & 1) as f64));
+    }
+
+    points.push(Point3::new(0.5, 0.5, 0.5));
+    points.push(Point3::new(1.0, 1.0, 1.0));
+    points.push(Point3::new(0.5, 0.0, 0.5));
+
+    let hull = ConvexHull::new(points);
+
+    assert!((Volumetric::<f64, Point3<f64>, Matrix3<f64>>::volume(&hull) - 1.0).abs() < 1.0e-10);
+    assert!((Volumetric::<f64, Point3<f64>, Matrix3<f64>>::area(&hull) - 6.0).abs() < 1.0e-10);
+
+    let flat = ConvexHull::new(vec![ Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0),
+                                     Point3::new(0.0, 1.0, 0.0), Point3::new(1.0, 1.0, 0.0) ]);
+
+    assert_eq!(Volumetric::<f64, Point3<f64>, Matrix3<f64>>::volume(&flat), 0.0);
+}
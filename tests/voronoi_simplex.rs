@@ -0,0 +1,82 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Point2, Point3, Vector3};
+use ncollide::shape::{self, Cuboid};
+use ncollide::query::QuerySettings;
+use ncollide::query::algorithms::gjk;
+use ncollide::query::algorithms::simplex::Simplex;
+use ncollide::query::algorithms::johnson_simplex::JohnsonSimplex;
+use ncollide::query::algorithms::voronoi_simplex::VoronoiSimplex;
+
+fn assert_coords(actual: &[f64], expected: &[f64]) {
+    assert_eq!(actual.len(), expected.len());
+
+    for (a, e) in actual.iter().zip(expected.iter()) {
+        assert!((a - e).abs() < 1.0e-7, "Wrong barycentric coordinates: {:?}", actual);
+    }
+}
+
+#[test]
+fn origin_projected_inside_triangle() {
+    let mut simplex = VoronoiSimplex::new();
+    simplex.add_point(Point3::new(-1.0, -1.0, 1.0));
+    simplex.add_point(Point3::new(2.0, -1.0, 1.0));
+    simplex.add_point(Point3::new(-1.0, 2.0, 1.0));
+
+    let proj = simplex.project_origin();
+    assert!(na::distance(&proj, &Point3::new(0.0, 0.0, 1.0)) < 1.0e-7);
+    assert_coords(simplex.barycentric_coordinates(), &[ 1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0 ]);
+
+    // Every point is needed.
+    simplex.reduce();
+    assert_eq!(simplex.len(), 3);
+}
+
+#[test]
+fn origin_projected_on_triangle_edge() {
+    let mut simplex = VoronoiSimplex::new();
+    simplex.add_point(Point2::new(1.0, -1.0));
+    simplex.add_point(Point2::new(3.0, 0.0));
+    simplex.add_point(Point2::new(1.0, 1.0));
+
+    let proj = simplex.project_origin();
+    assert!(na::distance(&proj, &Point2::new(1.0, 0.0)) < 1.0e-7);
+    assert_coords(simplex.barycentric_coordinates(), &[ 0.5, 0.0, 0.5 ]);
+
+    simplex.reduce();
+    assert_eq!(simplex.points(), &[ Point2::new(1.0, -1.0), Point2::new(1.0, 1.0) ]);
+}
+
+#[test]
+fn origin_inside_tetrahedron() {
+    let mut simplex = VoronoiSimplex::new();
+    simplex.add_point(Point3::new(-1.0, -1.0, -1.0));
+    simplex.add_point(Point3::new(3.0, -1.0, -1.0));
+    simplex.add_point(Point3::new(-1.0, 3.0, -1.0));
+    simplex.add_point(Point3::new(-1.0, -1.0, 3.0));
+
+    let proj = simplex.project_origin();
+    assert!(na::norm(&proj.coords) < 1.0e-7);
+    assert_coords(simplex.barycentric_coordinates(), &[ 0.25, 0.25, 0.25, 0.25 ]);
+}
+
+#[test]
+fn gjk_with_voronoi_simplex() {
+    let cube = Cuboid::new(Vector3::new(1.0, 1.0, 1.0));
+    let m1   = Isometry3::new(na::zero(), na::zero());
+    let m2   = Isometry3::new(Vector3::new(3.0, 0.5, -0.2), Vector3::new(0.3, 0.2, 0.1));
+    let init = *shape::cso_support_point(&m1, &cube, &m2, &cube, Vector3::x()).point();
+
+    let mut voronoi = VoronoiSimplex::new();
+    voronoi.reset(init);
+    let mut johnson = JohnsonSimplex::new_w_tls();
+    johnson.reset(init);
+
+    let settings = QuerySettings::<f64>::default();
+    let (d1, _)  = gjk::distance(&m1, &cube, &m2, &cube, &mut voronoi, &settings);
+    let (d2, _)  = gjk::distance(&m1, &cube, &m2, &cube, &mut johnson, &settings);
+
+    assert!(d1 > 0.0);
+    assert!((d1 - d2).abs() < 1.0e-6);
+}
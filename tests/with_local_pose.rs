@@ -0,0 +1,52 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::f64::consts::FRAC_PI_2;
+
+use na::{Isometry3, Point3, Vector3};
+use ncollide::shape::{Cone, Shape, WithLocalPose};
+use ncollide::bounding_volume;
+use ncollide::query::{Ray, RayCast};
+
+#[test]
+fn cone_with_local_rotation_casts_and_bounds_as_if_rotated() {
+    // A rotation of 90° around `z` maps the cone axis `y` to `-x`.
+    let local_pose = Isometry3::new(na::zero(), Vector3::z() * FRAC_PI_2);
+    let cone       = Cone::new(1.0f64, 0.5);
+    let wrapped    = WithLocalPose::new(cone.clone(), local_pose);
+    let m          = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    let rotated_m  = m * local_pose;
+
+    let rays = [
+        Ray::new(Point3::new(-5.0, 2.0, 3.0), Vector3::x()),
+        Ray::new(Point3::new(1.0, 8.0, 3.0), -Vector3::y()),
+        Ray::new(Point3::new(1.2, 1.8, -4.0), Vector3::z()),
+        Ray::new(Point3::new(1.0, 2.0, 3.0), Vector3::new(1.0, 1.0, 0.0))
+    ];
+
+    for ray in rays.iter() {
+        let expected = cone.toi_and_normal_with_ray(&rotated_m, ray, true);
+        let actual   = wrapped.toi_and_normal_with_ray(&m, ray, true);
+
+        match (expected, actual) {
+            (Some(expected), Some(actual)) => {
+                assert!((expected.toi - actual.toi).abs() < 1.0e-9);
+                assert!(na::norm(&(*expected.normal - *actual.normal)) < 1.0e-9);
+            },
+            (None, None) => { },
+            _ => panic!("The wrapped cone and the rotated cone ray casts disagree.")
+        }
+    }
+
+    let expected_aabb = bounding_volume::aabb(&cone, &rotated_m);
+    let actual_aabb   = wrapped.aabb(&m);
+
+    assert!(na::distance(expected_aabb.mins(), actual_aabb.mins()) < 1.0e-9);
+    assert!(na::distance(expected_aabb.maxs(), actual_aabb.maxs()) < 1.0e-9);
+
+    // Without transformation, the wrapped cone lies along the `x` axis.
+    let local_aabb = wrapped.aabb(&Isometry3::identity());
+
+    assert!((local_aabb.maxs().x - 1.0).abs() < 1.0e-9);
+    assert!((local_aabb.maxs().y - 0.5).abs() < 1.0e-9);
+}
@@ -0,0 +1,35 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Vector3};
+use ncollide::shape::{Ball, ShapeHandle};
+use ncollide::world::{CollisionGroups, CollisionWorld3, GeometricQueryType};
+
+#[test]
+fn changing_collision_groups_updates_pairs() {
+    let shape = ShapeHandle::new(Ball::new(1.0f64));
+    let query = GeometricQueryType::Contacts(0.0);
+    let at    = |x: f64| Isometry3::new(Vector3::new(x, 0.0, 0.0), na::zero());
+
+    let mut player = CollisionGroups::new();
+    player.set_membership(&[ 1 ]);
+
+    let mut world = CollisionWorld3::new(0.02, true);
+    world.deferred_add(0, at(0.0), shape.clone(), player, query, ());
+    world.deferred_add(1, at(1.5), shape, CollisionGroups::new(), query, ());
+    world.update();
+    assert!(world.contact_pair(0, 1).is_some());
+
+    // The projectile now ignores the player.
+    let mut projectile = CollisionGroups::new();
+    projectile.set_blacklist(&[ 1 ]);
+    world.deferred_set_collision_groups(1, projectile);
+    world.update();
+
+    assert!(world.contact_pair(0, 1).is_none());
+    assert!(world.collision_object(1).unwrap().collision_groups.is_group_blacklisted(1));
+
+    world.deferred_set_collision_groups(1, CollisionGroups::new());
+    world.update();
+    assert!(world.contact_pair(0, 1).is_some());
+}
@@ -0,0 +1,82 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use std::rc::Rc;
+use std::cell::Cell;
+
+use na::{Isometry3, Vector3};
+use ncollide::shape::{Cuboid, ShapeHandle};
+use ncollide::narrow_phase::{ContactAlgorithm3, ContactHandler};
+use ncollide::world::{CollisionGroups, CollisionObject3, CollisionWorld3, GeometricQueryType};
+
+struct ContactCounter {
+    started: Rc<Cell<usize>>,
+    stopped: Rc<Cell<usize>>
+}
+
+impl ContactHandler<na::Point3<f64>, Isometry3<f64>, ()> for ContactCounter {
+    fn handle_contact_started(&mut self, _: &CollisionObject3<f64, ()>, _: &CollisionObject3<f64, ()>,
+                              _: &ContactAlgorithm3<f64>) {
+        self.started.set(self.started.get() + 1);
+    }
+
+    fn handle_contact_stopped(&mut self, _: &CollisionObject3<f64, ()>, _: &CollisionObject3<f64, ()>) {
+        self.stopped.set(self.stopped.get() + 1);
+    }
+}
+
+// The one-shot manifold generator perturbs the cuboids, so the depths are approximate.
+fn max_depth(world: &CollisionWorld3<f64, ()>) -> Option<f64> {
+    world.contact_pair(1, 0).map(|(_, _, algorithm)| {
+        let mut contacts = Vec::new();
+        algorithm.contacts(&mut contacts);
+
+        contacts.iter().fold(0.0, |depth, c| c.depth.max(depth))
+    })
+}
+
+#[test]
+fn contact_pair_created_updated_and_removed() {
+    let started = Rc::new(Cell::new(0));
+    let stopped = Rc::new(Cell::new(0));
+    let shape   = ShapeHandle::new(Cuboid::new(Vector3::new(1.0f64, 1.0, 1.0)));
+    let query   = GeometricQueryType::Contacts(0.0);
+    let groups  = CollisionGroups::new();
+    let at      = |x: f64| Isometry3::new(Vector3::new(x, 0.0, 0.0), na::zero());
+
+    let mut world = CollisionWorld3::new(0.02, true);
+    world.register_contact_handler("counter", ContactCounter { started: started.clone(), stopped: stopped.clone() });
+    world.deferred_add(0, at(0.0), shape.clone(), groups, query, ());
+    world.deferred_add(1, at(5.0), shape.clone(), groups, query, ());
+    world.update();
+
+    // Far apart: no pair.
+    assert!(world.contact_pair(0, 1).is_none());
+    assert_eq!(world.contact_pairs().count(), 0);
+
+    // Overlapping: the pair and its manifold are created.
+    world.deferred_set_position(1, at(1.9));
+    world.update();
+
+    assert_eq!(world.contact_pairs().count(), 1);
+    assert!(world.contact_pair(0, 1).unwrap().2.num_contacts() > 0);
+    assert!((max_depth(&world).unwrap() - 0.1).abs() < 1.0e-2);
+    assert_eq!(started.get(), 1);
+
+    // Still overlapping: the same manifold is updated.
+    world.deferred_set_position(1, at(1.95));
+    world.update();
+
+    assert_eq!(world.contact_pairs().count(), 1);
+    assert!((max_depth(&world).unwrap() - 0.05).abs() < 1.0e-2);
+    assert_eq!(started.get(), 1);
+    assert_eq!(stopped.get(), 0);
+
+    // Separated: the manifold is removed.
+    world.deferred_set_position(1, at(5.0));
+    world.update();
+
+    assert!(world.contact_pair(0, 1).is_none());
+    assert_eq!(world.contact_pairs().count(), 0);
+    assert_eq!(stopped.get(), 1);
+}
@@ -0,0 +1,76 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry3, Vector3};
+use ncollide::shape::{Ball, ShapeHandle};
+use ncollide::query::Proximity;
+use ncollide::narrow_phase::ContactEvent;
+use ncollide::world::{CollisionGroups, CollisionWorld3, GeometricQueryType};
+
+fn at(x: f64) -> Isometry3<f64> {
+    Isometry3::new(Vector3::new(x, 0.0, 0.0), na::zero())
+}
+
+// The uids of the objects of a contact event, in increasing order.
+fn sorted(event: &ContactEvent) -> ContactEvent {
+    match *event {
+        ContactEvent::Started(a, b) => ContactEvent::Started(a.min(b), a.max(b)),
+        ContactEvent::Stopped(a, b) => ContactEvent::Stopped(a.min(b), a.max(b))
+    }
+}
+
+#[test]
+fn contact_events() {
+    let shape  = ShapeHandle::new(Ball::new(1.0f64));
+    let query  = GeometricQueryType::Contacts(0.0);
+    let groups = CollisionGroups::new();
+
+    let mut world = CollisionWorld3::new(0.02, true);
+    world.deferred_add(0, at(0.0), shape.clone(), groups, query, ());
+    world.deferred_add(1, at(5.0), shape.clone(), groups, query, ());
+    world.update();
+    assert!(world.contact_events().is_empty());
+
+    world.deferred_set_position(1, at(1.5));
+    world.update();
+    let events: Vec<_> = world.contact_events().iter().map(sorted).collect();
+    assert_eq!(events, vec![ ContactEvent::Started(0, 1) ]);
+
+    // The events are only reported once.
+    world.deferred_set_position(1, at(1.6));
+    world.update();
+    assert!(world.contact_events().is_empty());
+
+    world.deferred_set_position(1, at(5.0));
+    world.update();
+    let events: Vec<_> = world.contact_events().iter().map(sorted).collect();
+    assert_eq!(events, vec![ ContactEvent::Stopped(0, 1) ]);
+}
+
+#[test]
+fn proximity_events() {
+    let shape  = ShapeHandle::new(Ball::new(1.0f64));
+    let groups = CollisionGroups::new();
+
+    let mut world = CollisionWorld3::new(0.02, true);
+    world.deferred_add(0, at(0.0), shape.clone(), groups, GeometricQueryType::Proximity(0.5), ());
+    world.deferred_add(1, at(5.0), shape.clone(), groups, GeometricQueryType::Proximity(0.5), ());
+    world.update();
+    assert!(world.proximity_events().is_empty());
+
+    let mut statuses = Vec::new();
+
+    for &x in [ 2.3, 1.5, 1.6, 2.3, 5.0 ].iter() {
+        world.deferred_set_position(1, at(x));
+        world.update();
+        statuses.extend(world.proximity_events().iter().map(|e| (e.prev_status, e.new_status)));
+    }
+
+    assert_eq!(statuses, vec![ (Proximity::Disjoint, Proximity::WithinMargin),
+                               (Proximity::WithinMargin, Proximity::Intersecting),
+                               (Proximity::Intersecting, Proximity::WithinMargin),
+                               (Proximity::WithinMargin, Proximity::Disjoint) ]);
+
+    world.clear_events();
+    assert!(world.proximity_events().is_empty());
+}
@@ -0,0 +1,86 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry2, Point2, Vector2};
+use ncollide::shape::{Ball, ShapeHandle};
+use ncollide::query::Ray;
+use ncollide::world::{CollisionGroups, GeometricQueryType, CollisionWorld2};
+
+fn world_with_three_balls() -> CollisionWorld2<f64, ()> {
+    let shape = ShapeHandle::new(Ball::new(0.5f64));
+    let query = GeometricQueryType::Contacts(0.0);
+    let mut world = CollisionWorld2::new(0.02, true);
+
+    for (uid, x) in [ 5.0f64, 2.0, 8.0 ].iter().enumerate() {
+        let mut groups = CollisionGroups::new();
+        groups.set_membership(&[ uid ]);
+
+        world.deferred_add(uid, Isometry2::new(Vector2::new(*x, 0.0), na::zero()), shape.clone(), groups, query, ());
+    }
+
+    world.update();
+    world
+}
+
+#[test]
+fn closest_ray_hit() {
+    let world  = world_with_three_balls();
+    let ray    = Ray::new(Point2::origin(), Vector2::x());
+    let groups = CollisionGroups::new();
+
+    assert_eq!(world.interferences_with_ray(&ray, &groups).count(), 3);
+
+    let (co, inter) = world.interferences_with_ray(&ray, &groups).closest().unwrap();
+    assert_eq!(co.uid, 1);
+    assert!((inter.toi - 1.5).abs() < 1.0e-7);
+}
+
+#[test]
+fn filtered_and_sorted_ray_hits() {
+    let world  = world_with_three_balls();
+    let ray    = Ray::new(Point2::origin(), Vector2::x());
+    let groups = CollisionGroups::new();
+
+    let sorted: Vec<usize> = world.interferences_with_ray(&ray, &groups).sorted_by_toi().iter().map(|h| h.0.uid).collect();
+    assert_eq!(sorted, vec![ 1, 0, 2 ]);
+
+    let (co, _) = world.interferences_with_ray(&ray, &groups).filter_group(2).closest().unwrap();
+    assert_eq!(co.uid, 2);
+}
+
+#[test]
+fn closest_ray_hit_point() {
+    let world  = world_with_three_balls();
+    let ray    = Ray::new(Point2::new(0.0, 0.3), Vector2::x());
+    let groups = CollisionGroups::new();
+
+    let (co, inter, pt) = world.interferences_with_ray(&ray, &groups).closest_with_point().unwrap();
+    assert_eq!(co.uid, 1);
+    assert_eq!(pt, inter.point(&ray));
+    assert!((na::distance(&pt, &Point2::new(2.0, 0.0)) - 0.5).abs() < 1.0e-7);
+}
+
+#[test]
+fn batched_ray_hits() {
+    let world  = world_with_three_balls();
+    let groups = CollisionGroups::new();
+    let rays   = [
+        Ray::new(Point2::origin(), Vector2::x()),
+        Ray::new(Point2::new(0.0, 2.0), Vector2::x()),
+        Ray::new(Point2::new(9.0, 0.0), -Vector2::x())
+    ];
+
+    let mut hits = Vec::new();
+    world.interferences_with_rays(&rays, &groups, &mut hits);
+
+    let mut ids: Vec<(usize, usize)> = hits.iter().map(|h| (h.0, h.1.uid)).collect();
+    ids.sort();
+    assert_eq!(ids, vec![ (0, 0), (0, 1), (0, 2), (2, 0), (2, 1), (2, 2) ]);
+
+    let closest = world.closest_interferences_with_rays(&rays, &groups);
+    assert_eq!(closest.len(), 3);
+    assert_eq!(closest[0].as_ref().unwrap().0.uid, 1);
+    assert!(closest[1].is_none());
+    assert_eq!(closest[2].as_ref().unwrap().0.uid, 2);
+    assert!((closest[2].as_ref().unwrap().1.toi - 0.5).abs() < 1.0e-7);
+}
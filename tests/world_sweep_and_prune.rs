@@ -0,0 +1,94 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Isometry2, Point2, Vector2};
+use ncollide::broad_phase::SweepAndPrune;
+use ncollide::shape::{Ball, ShapeHandle};
+use ncollide::world::{CollisionGroups, CollisionWorld2, GeometricQueryType};
+
+fn at(i: usize, t: f64) -> Isometry2<f64> {
+    let fi = i as f64;
+    Isometry2::new(Vector2::new((fi * 0.7).sin() * 4.0 + (t * (1.0 + fi * 0.1)).cos() * 2.0,
+                                (fi * 1.3).cos() * 4.0 + (t * (0.5 + fi * 0.05)).sin() * 2.0),
+                   na::zero())
+}
+
+fn sorted_pairs(world: &CollisionWorld2<f64, ()>) -> Vec<(usize, usize)> {
+    let mut pairs: Vec<_> = world.contact_pairs().filter(|&(_, _, algorithm)| algorithm.num_contacts() != 0)
+                                                 .map(|(co1, co2, _)| (co1.uid.min(co2.uid), co1.uid.max(co2.uid)))
+                                                 .collect();
+    pairs.sort();
+
+    pairs
+}
+
+#[test]
+fn sweep_and_prune_world() {
+    let n      = 30;
+    let shape  = ShapeHandle::new(Ball::new(0.5f64));
+    let groups = CollisionGroups::new();
+    let query  = GeometricQueryType::Contacts(0.0);
+
+    let mut dbvt = CollisionWorld2::new(0.02, true);
+    let mut sap  = CollisionWorld2::new(0.02, true);
+
+    for i in 0 .. n {
+        dbvt.deferred_add(i, at(i, 0.0), shape.clone(), groups, query, ());
+        sap.deferred_add(i, at(i, 0.0), shape.clone(), groups, query, ());
+    }
+
+    // Switch after the first update so that the existing objects have to be transferred.
+    sap.update();
+    let _ = sap.set_broad_phase(Box::new(SweepAndPrune::new(0.02, true)));
+
+    let mut num_contacts = 0;
+
+    for step in 0 .. 50 {
+        let t = step as f64 * 0.1;
+
+        for i in 0 .. n {
+            dbvt.deferred_set_position(i, at(i, t));
+            sap.deferred_set_position(i, at(i, t));
+        }
+
+        dbvt.update();
+        sap.update();
+
+        let pairs = sorted_pairs(&dbvt);
+        num_contacts += pairs.len();
+        assert_eq!(pairs, sorted_pairs(&sap));
+    }
+
+    assert!(num_contacts != 0);
+
+    // Queries go through the new broad phase too.
+    let center = Point2::from_coordinates(at(3, 4.9).translation.vector);
+    let hits   = sap.interferences_with_point(&center, &groups);
+    assert!(hits.map(|co| co.uid).any(|uid| uid == 3));
+}
+
+#[test]
+fn set_broad_phase_removes_undetected_pairs() {
+    let shape     = ShapeHandle::new(Ball::new(0.5f64));
+    let groups    = CollisionGroups::new();
+    let query     = GeometricQueryType::Contacts(0.0);
+    let mut world = CollisionWorld2::new(0.02, true);
+
+    world.deferred_add(0, Isometry2::new(na::zero(), na::zero()), shape.clone(), groups, query, ());
+    world.deferred_add(1, Isometry2::new(Vector2::new(1.0, 0.0), na::zero()), shape.clone(), groups, query, ());
+    world.update();
+
+    // The pair remains in the narrow phase since the balls stay inside of the loosened bounding
+    // volumes of the DBVT.
+    world.deferred_set_position(1, Isometry2::new(Vector2::new(1.01, 0.0), na::zero()));
+    world.update();
+    assert_eq!(world.contact_pairs().count(), 1);
+
+    // The new broad phase does not detect this pair, which must not remain forever.
+    let _ = world.set_broad_phase(Box::new(SweepAndPrune::new(0.0, true)));
+    assert_eq!(world.contact_pairs().count(), 0);
+
+    world.deferred_set_position(1, Isometry2::new(Vector2::new(10.0, 0.0), na::zero()));
+    world.update();
+    assert_eq!(world.contact_pairs().count(), 0);
+}
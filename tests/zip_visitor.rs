@@ -0,0 +1,55 @@
+extern crate nalgebra as na;
+extern crate ncollide;
+
+use na::{Point3, Vector3, Isometry3};
+use ncollide::partitioning::{BVT, ZipVisitor};
+use ncollide::shape::{Cone, Ball, Cuboid, Capsule};
+use ncollide::query::{RayInterferencesCollector, Ray};
+use ncollide::bounding_volume::{self, BoundingSphere};
+
+fn bvt() -> BVT<usize, BoundingSphere<Point3<f64>>> {
+    let poss = [
+        Isometry3::new(Vector3::new(0.0, 0.0, 1.0), na::zero()),
+        Isometry3::new(Vector3::new(0.0, 0.0, 2.0), na::zero()),
+        Isometry3::new(Vector3::new(0.0, 0.0, 3.0), na::zero()),
+        Isometry3::new(Vector3::new(0.0, 2.0, 4.0), na::zero()),
+        Isometry3::new(Vector3::new(0.0, 0.0, -2.0), na::zero())
+    ];
+
+    BVT::new_balanced(vec![
+        (0usize, bounding_volume::bounding_sphere(&Ball::new(0.5), &poss[0])),
+        (1usize, bounding_volume::bounding_sphere(&Capsule::new(0.5, 0.75), &poss[1])),
+        (2usize, bounding_volume::bounding_sphere(&Cone::new(0.5, 0.75), &poss[2])),
+        (3usize, bounding_volume::bounding_sphere(&Cuboid::new(Vector3::new(1.0, 0.5, 1.0)), &poss[3])),
+        (4usize, bounding_volume::bounding_sphere(&Ball::new(0.5), &poss[4]))
+    ])
+}
+
+#[test]
+fn zipped_ray_collectors_match_separate_traversals() {
+    let bvt    = bvt();
+    let ray_z  = Ray::new(Point3::origin(), Vector3::z());
+    let ray_mz = Ray::new(Point3::origin(), -Vector3::z());
+
+    let mut separate_z  = Vec::new();
+    let mut separate_mz = Vec::new();
+    bvt.visit(&mut RayInterferencesCollector::new(&ray_z, &mut separate_z));
+    bvt.visit(&mut RayInterferencesCollector::new(&ray_mz, &mut separate_mz));
+
+    let mut zipped_z  = Vec::new();
+    let mut zipped_mz = Vec::new();
+
+    {
+        let mut visitor = ZipVisitor::new(RayInterferencesCollector::new(&ray_z, &mut zipped_z),
+                                          RayInterferencesCollector::new(&ray_mz, &mut zipped_mz));
+        bvt.visit(&mut visitor);
+    }
+
+    separate_z.sort();
+    zipped_z.sort();
+
+    assert_eq!(separate_z, vec![ 0, 1, 2 ]);
+    assert_eq!(separate_mz, vec![ 4 ]);
+    assert_eq!(zipped_z, separate_z);
+    assert_eq!(zipped_mz, separate_mz);
+}